@@ -0,0 +1,55 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Command aliases from the `[aliases]` table of config.toml, e.g. `up = "bg start -V latest"`.
+//!
+//! An alias is expanded before the command line is parsed: `frm up --env tls` runs
+//! `frm bg start -V latest --env tls`. Built-in commands take precedence over aliases,
+//! and aliases over external subcommands. Expansions are not expanded again, and their
+//! arguments are split on whitespace, without shell quoting.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+
+use clap::Command;
+
+/// Replaces the command name in `args` (the program name first) with the alias' expansion.
+/// Global flags before the command name, such as `--terse`, are kept.
+pub fn expand(
+    args: Vec<OsString>,
+    aliases: &BTreeMap<String, String>,
+    cli: &Command,
+) -> Vec<OsString> {
+    let Some(position) = args
+        .iter()
+        .skip(1)
+        .position(|arg| !arg.to_string_lossy().starts_with('-'))
+        .map(|i| i + 1)
+    else {
+        return args;
+    };
+    let Some(name) = args[position].to_str() else {
+        return args;
+    };
+    if is_built_in(cli, name) {
+        return args;
+    }
+    let Some(expansion) = aliases.get(name) else {
+        return args;
+    };
+
+    let mut expanded = args[..position].to_vec();
+    expanded.extend(expansion.split_whitespace().map(OsString::from));
+    expanded.extend(args[position + 1..].iter().cloned());
+    expanded
+}
+
+/// Whether an alias name is taken by a built-in command, in which case the alias is never used
+pub fn is_built_in(cli: &Command, name: &str) -> bool {
+    name == "help" || cli.find_subcommand(name).is_some()
+}
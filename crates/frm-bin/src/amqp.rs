@@ -0,0 +1,200 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Just enough of AMQP 0-9-1 to check that a node accepts client connections.
+//!
+//! [`handshake`] sends the protocol header and reads the `connection.start` method
+//! the server responds with. It does not authenticate or open a channel.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+
+use crate::Result;
+use crate::errors::Error;
+
+pub const DEFAULT_PORT: u16 = 5672;
+
+const PROTOCOL_HEADER: &[u8; 8] = b"AMQP\x00\x00\x09\x01";
+const FRAME_METHOD: u8 = 1;
+const FRAME_END: u8 = 0xCE;
+const CONNECTION_CLASS: u16 = 10;
+const CONNECTION_START: u16 = 10;
+const MAX_FRAME_SIZE: u32 = 128 * 1024;
+
+/// What the server reported in `connection.start`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerInfo {
+    pub address: SocketAddr,
+    pub protocol: (u8, u8),
+    pub product: Option<String>,
+    pub version: Option<String>,
+    pub cluster_name: Option<String>,
+    pub mechanisms: Vec<String>,
+    pub elapsed: Duration,
+}
+
+pub fn handshake(host: &str, port: u16, timeout: Duration) -> Result<ServerInfo> {
+    let target = format!("{}:{}", host, port);
+    let failed = |reason: String| Error::PingFailed {
+        address: target.clone(),
+        reason,
+    };
+
+    let addresses: Vec<SocketAddr> = (host, port)
+        .to_socket_addrs()
+        .map_err(|e| failed(e.to_string()))?
+        .collect();
+
+    // "localhost" can resolve to both ::1 and 127.0.0.1, and a node may only listen on one
+    let started = Instant::now();
+    let mut last_error = "host did not resolve to an address".to_string();
+    let mut connected = None;
+    for address in addresses {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => {
+                connected = Some((stream, address));
+                break;
+            }
+            Err(e) => last_error = e.to_string(),
+        }
+    }
+    let (mut stream, address) = connected.ok_or_else(|| failed(last_error))?;
+    stream
+        .set_read_timeout(Some(timeout))
+        .and_then(|_| stream.set_write_timeout(Some(timeout)))
+        .map_err(|e| failed(e.to_string()))?;
+
+    stream
+        .write_all(PROTOCOL_HEADER)
+        .map_err(|e| failed(e.to_string()))?;
+
+    let mut header = [0u8; 7];
+    stream
+        .read_exact(&mut header)
+        .map_err(|e| failed(format!("no response to the protocol header: {}", e)))?;
+    if &header[..4] == b"AMQP" {
+        return Err(failed("the server does not support AMQP 0-9-1".to_string()));
+    }
+
+    let frame_type = header[0];
+    let size = u32::from_be_bytes([header[3], header[4], header[5], header[6]]);
+    if frame_type != FRAME_METHOD || size > MAX_FRAME_SIZE {
+        return Err(failed(
+            "unexpected response, not an AMQP 0-9-1 listener?".to_string(),
+        ));
+    }
+
+    let mut payload = vec![0u8; size as usize + 1];
+    stream
+        .read_exact(&mut payload)
+        .map_err(|e| failed(e.to_string()))?;
+    let elapsed = started.elapsed();
+    if payload.pop() != Some(FRAME_END) {
+        return Err(failed("malformed frame".to_string()));
+    }
+
+    let mut info = parse_connection_start(&payload)
+        .ok_or_else(|| failed("malformed connection.start method".to_string()))?;
+    info.address = address;
+    info.elapsed = elapsed;
+    Ok(info)
+}
+
+fn parse_connection_start(payload: &[u8]) -> Option<ServerInfo> {
+    let mut r = Reader { buf: payload };
+    if r.u16()? != CONNECTION_CLASS || r.u16()? != CONNECTION_START {
+        return None;
+    }
+    let protocol = (r.u8()?, r.u8()?);
+
+    let mut info = ServerInfo {
+        address: SocketAddr::from(([0, 0, 0, 0], 0)),
+        protocol,
+        product: None,
+        version: None,
+        cluster_name: None,
+        mechanisms: Vec::new(),
+        elapsed: Duration::ZERO,
+    };
+
+    let table_len = r.u32()? as usize;
+    let mut table = Reader {
+        buf: r.take(table_len)?,
+    };
+    while !table.buf.is_empty() {
+        let key = table.short_str()?;
+        let value = table.field_value()?;
+        match key.as_str() {
+            "product" => info.product = value,
+            "version" => info.version = value,
+            "cluster_name" => info.cluster_name = value,
+            _ => {}
+        }
+    }
+
+    info.mechanisms = r.long_str()?.split_whitespace().map(String::from).collect();
+
+    Some(info)
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < n {
+            return None;
+        }
+        let (head, tail) = self.buf.split_at(n);
+        self.buf = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let b = self.take(2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let b = self.take(4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn short_str(&mut self) -> Option<String> {
+        let len = self.u8()? as usize;
+        Some(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    fn long_str(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        Some(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    /// Reads a field value, returning it only if it is a string
+    fn field_value(&mut self) -> Option<Option<String>> {
+        let skip = match self.u8()? {
+            b'S' => return Some(Some(self.long_str()?)),
+            b'V' => 0,
+            b't' | b'b' | b'B' => 1,
+            b'U' | b'u' | b's' => 2,
+            b'I' | b'i' | b'f' => 4,
+            b'D' => 5,
+            b'L' | b'l' | b'd' | b'T' => 8,
+            b'F' | b'A' | b'x' => self.u32()? as usize,
+            _ => return None,
+        };
+        self.take(skip)?;
+        Some(None)
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Compressed tar archives: `.tar.xz` (used by the generic UNIX packages), `.tar.gz`, and `.tar.zst`
+//! (used by some mirrors and Tanzu RabbitMQ bundles).
+//!
+//! The format is detected from the first bytes of an archive, so a misnamed file
+//! is extracted just fine.
+//!
+//! RabbitMQ archives have a single `rabbitmq_server-<version>` top-level directory.
+//! Alpha builds have not always followed that, so the distribution is located
+//! by its `sbin/rabbitmq-server` when the directory is named differently.
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use tar::Archive;
+use tracing::debug;
+use xz2::read::XzDecoder;
+
+use crate::Result;
+use crate::common::cli_tools::RABBITMQ_SERVER;
+use crate::errors::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Xz,
+    Gzip,
+    Zstd,
+}
+
+const XZ_MAGIC: &[u8] = &[0xFD, b'7', b'z', b'X', b'Z', 0x00];
+const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+/// The longest of the magic byte sequences above
+pub const MAGIC_LEN: usize = 6;
+/// Added by macOS archivers next to the actual contents
+const IGNORED_DIRS: &[&str] = &["__MACOSX"];
+/// How many paths [`list_contents`] returns
+const LISTED_PATHS: usize = 20;
+
+impl ArchiveFormat {
+    pub const ALL: [ArchiveFormat; 3] =
+        [ArchiveFormat::Xz, ArchiveFormat::Gzip, ArchiveFormat::Zstd];
+
+    fn magic(self) -> &'static [u8] {
+        match self {
+            ArchiveFormat::Xz => XZ_MAGIC,
+            ArchiveFormat::Gzip => GZIP_MAGIC,
+            ArchiveFormat::Zstd => ZSTD_MAGIC,
+        }
+    }
+
+    /// File name extensions, the preferred one first
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            ArchiveFormat::Xz => &[".tar.xz", ".txz"],
+            ArchiveFormat::Gzip => &[".tar.gz", ".tgz"],
+            ArchiveFormat::Zstd => &[".tar.zst", ".tzst"],
+        }
+    }
+
+    pub fn from_magic(bytes: &[u8]) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| bytes.starts_with(f.magic()))
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?;
+        split_extension(name).map(|(_, format)| format)
+    }
+
+    pub fn decoder<'a, R: Read + 'a>(self, reader: R) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(match self {
+            ArchiveFormat::Xz => Box::new(XzDecoder::new(reader)),
+            ArchiveFormat::Gzip => Box::new(GzDecoder::new(reader)),
+            ArchiveFormat::Zstd => Box::new(zstd::Decoder::new(reader)?),
+        })
+    }
+}
+
+/// Splits a file name into its stem and archive format, e.g. `rabbitmq-4.2.3.tar.zst`
+/// into `rabbitmq-4.2.3` and [`ArchiveFormat::Zstd`]
+pub fn split_extension(name: &str) -> Option<(&str, ArchiveFormat)> {
+    ArchiveFormat::ALL.into_iter().find_map(|format| {
+        format
+            .extensions()
+            .iter()
+            .find_map(|ext| name.strip_suffix(ext))
+            .map(|stem| (stem, format))
+    })
+}
+
+/// Sniffs the format of a stream from its first bytes and returns a reader of decompressed data
+pub fn decoder<'a, R: Read + 'a>(mut reader: R) -> io::Result<(ArchiveFormat, Box<dyn Read + 'a>)> {
+    let mut magic = Vec::with_capacity(MAGIC_LEN);
+    (&mut reader)
+        .take(MAGIC_LEN as u64)
+        .read_to_end(&mut magic)?;
+    let format = ArchiveFormat::from_magic(&magic).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unsupported archive format, expected .tar.xz, .tar.gz, or .tar.zst",
+        )
+    })?;
+    let decoder = format.decoder(Cursor::new(magic).chain(reader))?;
+    Ok((format, decoder))
+}
+
+/// Unpacks a compressed tar stream of any supported format into `dest`
+pub fn unpack<R: Read>(reader: R, dest: &Path) -> io::Result<ArchiveFormat> {
+    let (format, decoder) = decoder(reader)?;
+    Archive::new(decoder).unpack(dest)?;
+    Ok(format)
+}
+
+/// Unpacks a compressed tar file of any supported format into `dest`
+pub fn unpack_file(path: &Path, dest: &Path) -> io::Result<ArchiveFormat> {
+    unpack(BufReader::new(File::open(path)?), dest)
+}
+
+/// Where the distribution is in a directory an archive was extracted to: the `expected`
+/// top-level directory, or else the only top-level directory with `sbin/rabbitmq-server`,
+/// or else the directory itself when the archive has no top-level directory
+pub fn distribution_dir(extracted: &Path, expected: &str) -> Result<PathBuf> {
+    let expected_dir = extracted.join(expected);
+    if expected_dir.is_dir() {
+        return Ok(expected_dir);
+    }
+
+    let mut candidates = Vec::new();
+    for entry in fs::read_dir(extracted)? {
+        let path = entry?.path();
+        if path.is_dir() && !ignored(&path) && is_distribution(&path) {
+            candidates.push(path);
+        }
+    }
+    if candidates.len() == 1 {
+        let dir = candidates.remove(0);
+        debug!(expected, actual = %dir.display(), "archive has a differently named top-level directory");
+        return Ok(dir);
+    }
+    if candidates.is_empty() && is_distribution(extracted) {
+        debug!(expected, "archive has no top-level directory");
+        return Ok(extracted.to_path_buf());
+    }
+
+    let mut contents = list_contents(extracted)?;
+    if contents.is_empty() {
+        contents.push("(nothing)".to_string());
+    }
+    Err(Error::UnexpectedArchiveLayout {
+        expected: expected.to_string(),
+        contents,
+    })
+}
+
+fn is_distribution(dir: &Path) -> bool {
+    dir.join("sbin").join(RABBITMQ_SERVER).is_file()
+}
+
+fn ignored(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.') || IGNORED_DIRS.contains(&name))
+}
+
+/// The paths in an extracted archive, two levels deep and relative to `dir`, for error messages.
+/// Directories end with a `/`. Only the first [`LISTED_PATHS`] are listed, followed by a count
+/// of the rest.
+pub fn list_contents(dir: &Path) -> io::Result<Vec<String>> {
+    let mut contents = Vec::new();
+    collect_contents(dir, "", 2, &mut contents)?;
+    contents.sort();
+    if contents.len() > LISTED_PATHS {
+        let rest = contents.len() - LISTED_PATHS;
+        contents.truncate(LISTED_PATHS);
+        contents.push(format!("... and {} more", rest));
+    }
+    Ok(contents)
+}
+
+fn collect_contents(
+    dir: &Path,
+    prefix: &str,
+    depth: usize,
+    contents: &mut Vec<String>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let is_dir = entry.file_type()?.is_dir();
+        let path = format!(
+            "{}{}{}",
+            prefix,
+            entry.file_name().to_string_lossy(),
+            if is_dir { "/" } else { "" }
+        );
+        if is_dir && depth > 1 {
+            collect_contents(&entry.path(), &path, depth - 1, contents)?;
+        }
+        contents.push(path);
+    }
+    Ok(())
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Bisection of alpha builds for `frm alphas bisect`. Alphas are built from successive
+//! rabbitmq-server commits, so the first bad alpha narrows a regression down to the commits
+//! between it and the last good one.
+
+use std::fmt;
+
+use crate::Result;
+use crate::errors::Error;
+use crate::releases::AlphaRelease;
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Good,
+    Bad,
+    /// Cannot be tested, e.g. because of an unrelated problem
+    Skip,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Good => write!(f, "good"),
+            Verdict::Bad => write!(f, "bad"),
+            Verdict::Skip => write!(f, "skipped"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    FirstBad {
+        last_good: Version,
+        first_bad: Version,
+    },
+    /// Skipped builds leave the first bad one undecided between these, oldest first
+    Undecided(Vec<Version>),
+}
+
+pub struct Bisection {
+    /// The good build, the builds in between, and the bad build, oldest first
+    builds: Vec<Version>,
+    good: usize,
+    bad: usize,
+    skipped: Vec<usize>,
+}
+
+impl Bisection {
+    /// `builds` are oldest first: the first one is good and the last one is bad
+    pub fn new(builds: Vec<Version>) -> Self {
+        assert!(
+            builds.len() >= 2,
+            "a bisection needs a good and a bad build"
+        );
+        let bad = builds.len() - 1;
+        Self {
+            builds,
+            good: 0,
+            bad,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// The build to test next, the untested one closest to the middle of the range.
+    /// `None` once the outcome is known.
+    pub fn next(&self) -> Option<&Version> {
+        let middle = (self.good + self.bad) / 2;
+        self.candidates()
+            .min_by_key(|i| i.abs_diff(middle))
+            .map(|i| &self.builds[i])
+    }
+
+    pub fn mark(&mut self, version: &Version, verdict: Verdict) {
+        let Some(i) = self.builds.iter().position(|v| v == version) else {
+            return;
+        };
+        match verdict {
+            Verdict::Good => self.good = i,
+            Verdict::Bad => self.bad = i,
+            Verdict::Skip => self.skipped.push(i),
+        }
+    }
+
+    /// Roughly how many more builds have to be tested
+    pub fn remaining_steps(&self) -> u32 {
+        (self.candidates().count() as u32 + 1)
+            .next_power_of_two()
+            .ilog2()
+    }
+
+    /// `None` while there are builds left to test
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.next().is_some() {
+            return None;
+        }
+        if self.bad == self.good + 1 {
+            return Some(Outcome::FirstBad {
+                last_good: self.builds[self.good].clone(),
+                first_bad: self.builds[self.bad].clone(),
+            });
+        }
+        Some(Outcome::Undecided(
+            self.builds[self.good + 1..=self.bad].to_vec(),
+        ))
+    }
+
+    fn candidates(&self) -> impl Iterator<Item = usize> + '_ {
+        (self.good + 1..self.bad).filter(|i| !self.skipped.contains(i))
+    }
+}
+
+/// The alphas published from `good` to `bad`, both included, oldest first
+pub fn alphas_between(
+    catalog: &[AlphaRelease],
+    good: &Version,
+    bad: &Version,
+) -> Result<Vec<Version>> {
+    let mut releases: Vec<&AlphaRelease> = catalog.iter().collect();
+    releases.sort_by(|a, b| a.published_at.cmp(&b.published_at));
+
+    let position = |version: &Version| {
+        releases
+            .iter()
+            .position(|r| &r.version == version)
+            .ok_or_else(|| Error::ReleaseNotFound(version.to_string()))
+    };
+    let (first, last) = (position(good)?, position(bad)?);
+    if first >= last {
+        return Err(Error::InvalidBisection(format!(
+            "the good alpha {} must be older than the bad alpha {}",
+            good, bad
+        )));
+    }
+
+    Ok(releases[first..=last]
+        .iter()
+        .map(|r| r.version.clone())
+        .collect())
+}
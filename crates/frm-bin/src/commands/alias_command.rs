@@ -0,0 +1,41 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::aliases;
+use crate::cli::build_cli;
+use crate::config::Config;
+use crate::messages::{print_hint, print_info, print_warning};
+use crate::paths::Paths;
+
+pub fn list(paths: &Paths) -> Result<()> {
+    let config = Config::load(paths)?;
+    if config.aliases.is_empty() {
+        print_info("No aliases");
+        print_hint(format!(
+            "Add them to the [aliases] table of {}, e.g. up = \"bg start -V latest\"",
+            paths.config_file().display()
+        ));
+        return Ok(());
+    }
+
+    let cli = build_cli();
+    let width = config.aliases.keys().map(String::len).max().unwrap_or(0);
+    for (name, expansion) in &config.aliases {
+        println!("{:width$} = {}", name, expansion, width = width);
+    }
+    for name in config.aliases.keys() {
+        if aliases::is_built_in(&cli, name) {
+            print_warning(format!(
+                "alias '{}' is never used: 'frm {}' is a built-in command",
+                name, name
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+use std::io;
+use std::process::{Command, Stdio};
+
+use tracing::debug;
+
+use crate::Result;
+use crate::common::env_vars::FRM_DIR;
+use crate::common::prompt;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::messages::{self, MessageMode, print_hint_to_stderr, print_info_to_stderr};
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Whether `use`, `cli`, and `fg node` install a version that is not installed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoInstall {
+    /// Fail with the command that installs the version
+    Never,
+    /// Ask first, with `auto_install = true` in config.toml
+    Ask,
+    /// With `--auto-install`, or with `--yes` and `auto_install = true`
+    Always,
+}
+
+impl AutoInstall {
+    pub fn resolve(paths: &Paths, auto_install: bool, assume_yes: bool) -> Result<Self> {
+        if auto_install {
+            return Ok(AutoInstall::Always);
+        }
+        Ok(match Config::load(paths)?.auto_install {
+            true if assume_yes => AutoInstall::Always,
+            true => AutoInstall::Ask,
+            false => AutoInstall::Never,
+        })
+    }
+}
+
+/// Makes sure a version `use`, `cli`, or `fg node` is about to use is installed. A missing
+/// version is installed first unless `auto_install` is [`AutoInstall::Never`] or the user
+/// declines, otherwise the error comes with the command that installs it.
+pub fn ensure_installed(paths: &Paths, version: &Version, auto_install: AutoInstall) -> Result<()> {
+    if paths.version_installed(version) {
+        return Ok(());
+    }
+    // standard output of `use` is evaluated by the shell, so the notes go to standard error
+    match auto_install {
+        AutoInstall::Always => install(paths, version),
+        AutoInstall::Ask
+            if prompt::confirm(&format!(
+                "RabbitMQ {} is not installed. Install it now?",
+                version
+            ))? =>
+        {
+            install(paths, version)
+        }
+        AutoInstall::Ask => {
+            print_hint_to_stderr(format!(
+                "Install it with: {} (or pass --yes)",
+                install_command(version)
+            ));
+            Err(Error::VersionNotInstalled(version.clone()))
+        }
+        AutoInstall::Never => {
+            print_hint_to_stderr(format!(
+                "Install it with: {} (or pass --auto-install)",
+                install_command(version)
+            ));
+            Err(Error::VersionNotInstalled(version.clone()))
+        }
+    }
+}
+
+/// `frm releases install <version>`, or `frm alphas install <version>` for alphas
+pub fn install_command(version: &Version) -> String {
+    format!("frm {} install {}", command_group(version), version)
+}
+
+fn command_group(version: &Version) -> &'static str {
+    if version.is_distributed_via_server_packages_repository() {
+        "alphas"
+    } else {
+        "releases"
+    }
+}
+
+/// Runs `frm releases install` as a child process with its output on standard error, where
+/// it does not end up in what `use` prints for the shell or in the output of a CLI tool
+fn install(paths: &Paths, version: &Version) -> Result<()> {
+    let exe = env::current_exe()?;
+    let mut cmd = Command::new(&exe);
+    if messages::mode() == MessageMode::Terse {
+        cmd.arg("--terse");
+    }
+    cmd.args([command_group(version), "install", &version.to_string()])
+        .env(FRM_DIR, paths.base_dir())
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(io::stderr()));
+
+    print_info_to_stderr(format!(
+        "RabbitMQ {} is not installed, installing it",
+        version
+    ));
+    debug!(path = %exe.display(), %version, "installing a missing version");
+    let status = cmd
+        .status()
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", exe.display(), e)))?;
+    if !status.success() {
+        return Err(Error::CommandFailed(format!(
+            "could not install RabbitMQ {}: {} exited with code {}",
+            version,
+            install_command(version),
+            status.code().unwrap_or(-1)
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::Result;
+use crate::commands::bg_start::run_log_file;
+use crate::commands::logs::{Follower, LogLocation, TailAmount, tail_file};
+use crate::envs;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Prints the output `rabbitmq-server -detached` produced on the last `frm bg start`,
+/// followed by the node's log file if it exists yet. With `follow`, both are followed,
+/// so a node can be watched from the moment it is started.
+pub fn run(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    lines: usize,
+    follow: bool,
+) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let run_log = run_log_file(paths, version, env);
+    if !run_log.is_file() {
+        let env_flag = env.map(|name| format!(" -e {}", name)).unwrap_or_default();
+        return Err(Error::FileNotFound(format!(
+            "{} (start the node with: frm bg start -V {}{})",
+            run_log.display(),
+            version,
+            env_flag
+        )));
+    }
+    // the node's log file, once the node's file logger creates it
+    let location = LogLocation::resolve(paths, version, env)?;
+    let node_log: Option<PathBuf> = location.find().ok().or(location.file);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let result = print_and_follow(&run_log, node_log, lines, follow, &mut out);
+    match result {
+        // e.g. piped to 'head'
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+fn print_and_follow(
+    run_log: &Path,
+    node_log: Option<PathBuf>,
+    lines: usize,
+    follow: bool,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut followers = Vec::new();
+
+    writeln!(out, "==> {} <==", run_log.display())?;
+    let end = tail_file(run_log, TailAmount::Lines(lines), out)?;
+    followers.push(Follower::new(run_log, end));
+
+    if let Some(node_log) = node_log {
+        if node_log.is_file() {
+            writeln!(out, "\n==> {} <==", node_log.display())?;
+            let end = tail_file(&node_log, TailAmount::Lines(lines), out)?;
+            followers.push(Follower::new(&node_log, end));
+        } else if follow {
+            // printed from its start once the node creates it
+            followers.push(Follower::new(&node_log, 0));
+        }
+    }
+
+    if !follow {
+        return Ok(());
+    }
+    loop {
+        for follower in followers.iter_mut() {
+            follower.poll(out)?;
+        }
+        out.flush()?;
+        thread::sleep(FOLLOW_POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Pausing a node with SIGSTOP makes it unresponsive without closing its connections,
+//! which is how a broker stuck in a long GC pause or on an overloaded host looks to clients.
+
+use crate::Result;
+use crate::errors::Error;
+use crate::node_state::{self, RunningNode};
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Stops the Erlang VM of a running node with SIGSTOP
+pub fn pause(paths: &Paths, version: &Version, node: Option<&str>) -> Result<()> {
+    let node = find_node(paths, version, node)?;
+    platform::pause(version, &node)
+}
+
+/// Resumes a node paused with [`pause`]
+pub fn resume(paths: &Paths, version: &Version, node: Option<&str>) -> Result<()> {
+    let node = find_node(paths, version, node)?;
+    platform::resume(version, &node)
+}
+
+/// The version's running node, the one named `name` if given
+pub(crate) fn find_node(
+    paths: &Paths,
+    version: &Version,
+    name: Option<&str>,
+) -> Result<RunningNode> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    let mut nodes = node_state::running_nodes(paths, version)?;
+    if let Some(name) = name {
+        nodes.retain(|n| n.name == name);
+    }
+    match nodes.len() {
+        0 => Err(Error::NoRunningNode(version.clone())),
+        1 => Ok(nodes.remove(0)),
+        _ => Err(Error::AmbiguousNode {
+            version: version.clone(),
+            nodes: nodes
+                .iter()
+                .map(|n| n.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }),
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use crate::Result;
+    use crate::messages::{print_hint, print_info, print_success};
+    use crate::node_state::{self, RunningNode};
+    use crate::version::Version;
+
+    pub fn pause(version: &Version, node: &RunningNode) -> Result<()> {
+        if node_state::process_stopped(node.pid) {
+            print_info(format!("{} is already paused", node.name));
+            return Ok(());
+        }
+        node_state::send_signal(node.pid, "STOP")?;
+        print_success(format!("Paused {} (PID {})", node.name, node.pid));
+        print_hint(format!(
+            "Clients will see the node as unresponsive. Resume it with 'frm bg resume -V {}'",
+            version
+        ));
+        Ok(())
+    }
+
+    pub fn resume(_version: &Version, node: &RunningNode) -> Result<()> {
+        if !node_state::process_stopped(node.pid) {
+            print_info(format!("{} is not paused", node.name));
+            return Ok(());
+        }
+        node_state::send_signal(node.pid, "CONT")?;
+        print_success(format!("Resumed {} (PID {})", node.name, node.pid));
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use crate::Result;
+    use crate::errors::Error;
+    use crate::node_state::RunningNode;
+    use crate::version::Version;
+
+    pub fn pause(_version: &Version, _node: &RunningNode) -> Result<()> {
+        Err(unsupported())
+    }
+
+    pub fn resume(_version: &Version, _node: &RunningNode) -> Result<()> {
+        Err(unsupported())
+    }
+
+    fn unsupported() -> Error {
+        Error::CommandFailed(
+            "pausing and resuming nodes requires SIGSTOP and SIGCONT, which Windows does not have"
+                .into(),
+        )
+    }
+}
@@ -6,19 +6,32 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::process::Command;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use chrono::Local;
 
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
+use tracing::debug;
 
 use crate::Result;
-use crate::commands::logs::find_log_file;
+use crate::commands::logs::LogLocation;
+use crate::commands::supervise;
 use crate::common::cli_tools::RABBITMQ_SERVER;
-use crate::common::env_vars::{RABBITMQ_CONFIG_FILES, RABBITMQ_HOME};
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::envs;
 use crate::errors::Error;
+use crate::messages::print_success;
 use crate::paths::Paths;
+use crate::shared;
 use crate::version::Version;
 
+const RUN_LOG_FILE_NAME: &str = "bg-start.log";
+
 #[derive(Tabled)]
 struct StartInfo {
     #[tabled(rename = "Property")]
@@ -27,20 +40,46 @@ struct StartInfo {
     value: String,
 }
 
-pub fn run(paths: &Paths, version: &Version) -> Result<()> {
-    if !paths.version_installed(version) {
-        return Err(Error::VersionNotInstalled(version.clone()));
+/// With `supervise`, the node is run by a supervisor that restarts it up to that many times
+/// in a row when it crashes, see [`crate::commands::supervise`]
+pub fn run(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    supervise: Option<u32>,
+) -> Result<()> {
+    match supervise {
+        Some(max_restarts) => {
+            let pid = supervise::spawn(paths, version, env, max_restarts)?;
+            print_success(format!(
+                "Started RabbitMQ {} under a supervisor (PID {}) that restarts it up to {} times in a row",
+                version, pid, max_restarts
+            ));
+        }
+        None => start(paths, version, env)?,
     }
+    print_start_info(paths, version, env);
 
-    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
-    if !server_path.exists() {
-        return Err(Error::FileNotFound(server_path.display().to_string()));
-    }
+    Ok(())
+}
 
-    let status = Command::new(&server_path)
+/// Starts a detached node. `rabbitmq-server -detached` returns before the node has booted.
+pub(crate) fn start(paths: &Paths, version: &Version, env: Option<&str>) -> Result<()> {
+    let server_path = prepare(paths, version, env)?;
+
+    let output = create_run_log(
+        paths,
+        version,
+        env,
+        &format!("{} -detached", server_path.display()),
+    )?;
+
+    debug!(path = %server_path.display(), env, "starting node in background");
+    let status = server_command(paths, version, env, &server_path)?
         .arg("-detached")
-        .env(RABBITMQ_HOME, paths.version_dir(version))
-        .env(RABBITMQ_CONFIG_FILES, paths.version_confd_dir(version))
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(output.try_clone()?))
+        .stderr(Stdio::from(output))
         .status()
         .map_err(|e| {
             Error::CommandFailed(format!(
@@ -51,27 +90,97 @@ pub fn run(paths: &Paths, version: &Version) -> Result<()> {
         })?;
 
     if !status.success() {
+        let env_flag = env.map(|name| format!(" -e {}", name)).unwrap_or_default();
         return Err(Error::CommandFailed(format!(
-            "rabbitmq-server -detached exited with code {}",
-            status.code().unwrap_or(-1)
+            "rabbitmq-server -detached exited with code {}, see its output with: frm bg logs -V {}{}",
+            status.code().unwrap_or(-1),
+            version,
+            env_flag
         )));
     }
 
-    print_start_info(paths, version);
-
     Ok(())
 }
 
-fn print_start_info(paths: &Paths, version: &Version) {
-    let log_path = find_log_file(paths, version)
-        .map(|p| p.display().to_string())
-        .unwrap_or_else(|_| {
-            paths
-                .version_var_log_dir(version)
-                .join("rabbit@<hostname>.log")
-                .display()
-                .to_string()
-        });
+/// Checks that the version (and environment) can run a node and returns the path of `rabbitmq-server`
+pub(crate) fn prepare(paths: &Paths, version: &Version, env: Option<&str>) -> Result<PathBuf> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    shared::prepare(paths, version)?;
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
+    if !server_path.exists() {
+        return Err(Error::FileNotFound(server_path.display().to_string()));
+    }
+    Ok(server_path)
+}
+
+/// `rabbitmq-server` with the environment of the version (or one of its environments)
+pub(crate) fn server_command(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    server_path: &Path,
+) -> Result<Command> {
+    let mut cmd = Command::new(server_path);
+    cmd.env(RABBITMQ_HOME, paths.version_dir(version))
+        .envs(envs::node_env_vars(paths, version, env)?)
+        .envs(cookie::env_vars(paths, version)?)
+        .envs(envs::node_name_env_vars(paths, version, env)?);
+    Ok(cmd)
+}
+
+/// Replaces the run log with a header line naming what writes to it.
+/// What the node prints before its file logger is up, e.g. why it could not boot,
+/// would otherwise be lost with the terminal of 'frm bg start'.
+pub(crate) fn create_run_log(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    writer: &str,
+) -> Result<File> {
+    let run_log = run_log_file(paths, version, env);
+    if let Some(dir) = run_log.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut output = File::create(&run_log)?;
+    writeln!(
+        output,
+        "# {}, started by frm at {}",
+        writer,
+        Local::now().to_rfc3339()
+    )?;
+    Ok(output)
+}
+
+/// Where `frm bg start` writes the output of `rabbitmq-server -detached`
+pub fn run_log_file(paths: &Paths, version: &Version, env: Option<&str>) -> PathBuf {
+    match env {
+        Some(name) => paths.version_env_run_dir(version, name),
+        None => paths.version_run_dir(version),
+    }
+    .join(RUN_LOG_FILE_NAME)
+}
+
+fn print_start_info(paths: &Paths, version: &Version, env: Option<&str>) {
+    let log_dir = match env {
+        Some(name) => paths.version_env_log_dir(version, name),
+        None => paths.version_var_log_dir(version),
+    };
+    let log_path = match LogLocation::resolve(paths, version, env) {
+        Ok(location) => location
+            .find()
+            .ok()
+            .or(location.file)
+            .unwrap_or_else(|| location.dir.join("rabbit@<hostname>.log")),
+        Err(_) => log_dir.join("rabbit@<hostname>.log"),
+    }
+    .display()
+    .to_string();
 
     let tail_cmd = format!("tail -f -n 200 {}", log_path);
     let rows = vec![
@@ -94,6 +203,15 @@ fn print_start_info(paths: &Paths, version: &Version) {
             property: "Tail logs",
             value: tail_cmd,
         },
+        StartInfo {
+            property: "Boot output",
+            value: format!(
+                "frm bg logs --version {}{}",
+                version,
+                env.map(|name| format!(" --env {}", name))
+                    .unwrap_or_default()
+            ),
+        },
     ];
 
     let table = Table::new(rows).with(Style::rounded()).to_string();
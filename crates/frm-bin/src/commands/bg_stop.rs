@@ -7,33 +7,120 @@
 // except according to those terms.
 
 use std::process::Command;
-
-use bel7_cli::print_success;
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::Result;
+use crate::commands::bg_pause::find_node;
+use crate::commands::supervise;
 use crate::common::cli_tools::RABBITMQCTL;
 use crate::common::env_vars::RABBITMQ_HOME;
+use crate::envs;
 use crate::errors::Error;
+use crate::messages::{print_info, print_success, print_warning};
+use crate::node_state::{self, RunningNode};
 use crate::paths::Paths;
 use crate::version::Version;
 
-pub fn run(paths: &Paths, version: &Version) -> Result<()> {
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub struct StopOptions<'a> {
+    pub node: Option<&'a str>,
+    /// How long to wait for the node to stop gracefully, in seconds
+    pub timeout: Option<u64>,
+    /// Kill the node's Erlang VM when it does not stop within the timeout,
+    /// or right away without one
+    pub kill: bool,
+}
+
+pub fn run(paths: &Paths, version: &Version, opts: &StopOptions) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    // a supervisor would start a killed node again
+    let supervisors = match opts.node {
+        Some(name) => match node_env(paths, version, name)? {
+            Some(env) => supervise::stop(paths, version, env.as_deref())?
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        },
+        None => supervise::stop_all(paths, version)?,
+    };
+    for pid in supervisors {
+        print_info(format!("Stopped the supervisor (PID {})", pid));
+    }
 
-    let ctl_path = paths.version_sbin_dir(version).join(RABBITMQCTL);
-    if !ctl_path.exists() {
-        return Err(Error::FileNotFound(ctl_path.display().to_string()));
+    if opts.timeout.is_none() && !opts.kill {
+        shutdown(paths, version, opts.node)?;
+        print_success(format!("RabbitMQ {} stopped", version));
+        return Ok(());
     }
 
-    let status = Command::new(&ctl_path)
-        .arg("shutdown")
-        .env(RABBITMQ_HOME, paths.version_dir(version))
+    // the PID is needed to tell when the node has stopped, and to kill it
+    let node = find_node(paths, version, opts.node)?;
+    let Some(seconds) = opts.timeout else {
+        platform::kill(node.pid)?;
+        print_success(format!(
+            "Killed {} (PID {}) without a graceful stop",
+            node.name, node.pid
+        ));
+        return Ok(());
+    };
+
+    let started = Instant::now();
+    if stop_within(paths, version, &node, Duration::from_secs(seconds))? {
+        print_success(format!(
+            "{} stopped gracefully in {:.1}s",
+            node.name,
+            started.elapsed().as_secs_f64()
+        ));
+        return Ok(());
+    }
+    if !opts.kill {
+        return Err(Error::NodeStopTimedOut {
+            node: node.name,
+            seconds,
+        });
+    }
+
+    print_warning(format!(
+        "{} did not stop gracefully within {} seconds",
+        node.name, seconds
+    ));
+    platform::kill(node.pid)?;
+    print_success(format!("Killed {} (PID {})", node.name, node.pid));
+    Ok(())
+}
+
+/// The environment of the node named `name`, found by its PID file: `Some(None)` for the
+/// version's own configuration, `None` when the node has no PID file
+fn node_env(paths: &Paths, version: &Version, name: &str) -> Result<Option<Option<String>>> {
+    let pid_file_name = format!("{}.pid", name);
+    let own_dirs = [
+        paths.version_data_dir(version),
+        envs::data_dir(paths, version, None)?,
+    ];
+    if own_dirs
+        .iter()
+        .any(|dir| dir.join(&pid_file_name).is_file())
+    {
+        return Ok(Some(None));
+    }
+    Ok(envs::list(paths, version)?.into_iter().find_map(|env| {
+        paths
+            .version_env_data_dir(version, &env)
+            .join(&pid_file_name)
+            .is_file()
+            .then_some(Some(env))
+    }))
+}
+
+/// Stops a node of the version with `rabbitmqctl shutdown`, the default node if `node` is `None`
+pub(crate) fn shutdown(paths: &Paths, version: &Version, node: Option<&str>) -> Result<()> {
+    let status = shutdown_command(paths, version, node)?
         .status()
-        .map_err(|e| {
-            Error::CommandFailed(format!("failed to execute {}: {}", ctl_path.display(), e))
-        })?;
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", RABBITMQCTL, e)))?;
 
     if !status.success() {
         return Err(Error::CommandFailed(format!(
@@ -42,7 +129,93 @@ pub fn run(paths: &Paths, version: &Version) -> Result<()> {
         )));
     }
 
-    print_success(format!("RabbitMQ {} stopped", version));
-
     Ok(())
 }
+
+fn shutdown_command(paths: &Paths, version: &Version, node: Option<&str>) -> Result<Command> {
+    let ctl_path = paths.version_sbin_dir(version).join(RABBITMQCTL);
+    if !ctl_path.exists() {
+        return Err(Error::FileNotFound(ctl_path.display().to_string()));
+    }
+
+    let mut cmd = Command::new(&ctl_path);
+    if let Some(node) = node {
+        cmd.args(["-n", node]);
+    }
+    cmd.arg("shutdown")
+        .env(RABBITMQ_HOME, paths.version_dir(version));
+    Ok(cmd)
+}
+
+/// Runs `rabbitmqctl shutdown` and waits for the node's Erlang VM to exit.
+/// Returns `false` if it is still running when the timeout expires.
+fn stop_within(
+    paths: &Paths,
+    version: &Version,
+    node: &RunningNode,
+    timeout: Duration,
+) -> Result<bool> {
+    let mut child = shutdown_command(paths, version, Some(&node.name))?
+        .spawn()
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", RABBITMQCTL, e)))?;
+
+    let deadline = Instant::now() + timeout;
+    let mut ctl_running = true;
+    while node_state::process_alive(node.pid) {
+        if ctl_running && let Some(status) = child.try_wait()? {
+            ctl_running = false;
+            if !status.success() {
+                print_warning(format!(
+                    "rabbitmqctl shutdown exited with code {}",
+                    status.code().unwrap_or(-1)
+                ));
+            }
+        }
+        if Instant::now() >= deadline {
+            if ctl_running {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+            return Ok(false);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    if ctl_running {
+        let _ = child.wait();
+    }
+    Ok(true)
+}
+
+#[cfg(unix)]
+mod platform {
+    use crate::Result;
+    use crate::node_state;
+
+    pub fn kill(pid: u32) -> Result<()> {
+        node_state::send_signal(pid, "KILL")
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::process::{Command, Stdio};
+
+    use crate::Result;
+    use crate::errors::Error;
+
+    pub fn kill(pid: u32) -> Result<()> {
+        let status = Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .status()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::CommandFailed(format!(
+                "failed to terminate process {}",
+                pid
+            )))
+        }
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+use crate::Result;
+use crate::bisect::{self, Bisection, Outcome, Verdict};
+use crate::commands::{bg_start, bg_stop, install_alpha};
+use crate::common::cli_tools::RABBITMQCTL;
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::node_state;
+use crate::paths::Paths;
+use crate::releases::fetch_alpha_releases;
+use crate::scripts;
+use crate::version::Version;
+
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Like with `git bisect run`, a test command that exits with this code cannot test the build
+const SKIP_EXIT_CODE: i32 = 125;
+
+pub struct BisectOptions<'a> {
+    pub good: &'a Version,
+    pub bad: &'a Version,
+    /// A command that tells a good build (exit code 0) from a bad one. Without it, the user is asked.
+    pub run: Option<&'a str>,
+}
+
+pub async fn run(paths: &Paths, opts: &BisectOptions<'_>) -> Result<()> {
+    for version in [opts.good, opts.bad] {
+        if !version.is_distributed_via_server_packages_repository() {
+            return Err(Error::ExpectedAlphaVersion(version.clone()));
+        }
+    }
+    if opts.run.is_none() && !io::stdin().is_terminal() {
+        return Err(Error::InvalidBisection(
+            "without --run, every build is judged interactively, which needs a terminal".into(),
+        ));
+    }
+
+    let client = reqwest::Client::new();
+    let catalog = fetch_alpha_releases(&client, paths).await?;
+    let builds = bisect::alphas_between(&catalog, opts.good, opts.bad)?;
+    let mut bisection = Bisection::new(builds.clone());
+    print_info(format!(
+        "{} alpha build(s) between {} and {}, roughly {} step(s)",
+        builds.len() - 2,
+        opts.good,
+        opts.bad,
+        bisection.remaining_steps()
+    ));
+
+    let mut installed = Vec::new();
+    while let Some(version) = bisection.next().cloned() {
+        if !paths.version_installed(&version) {
+            install_alpha(paths, &version, false, false, false).await?;
+            installed.push(version.to_string());
+        }
+        let verdict = test(paths, &version, opts.run)?;
+        bisection.mark(&version, verdict);
+        print_info(format!(
+            "{} is {}, roughly {} step(s) left",
+            version,
+            verdict,
+            bisection.remaining_steps()
+        ));
+    }
+
+    match bisection.outcome() {
+        Some(Outcome::FirstBad {
+            last_good,
+            first_bad,
+        }) => {
+            print_success(format!(
+                "The first bad alpha is {} (the last good one is {})",
+                first_bad, last_good
+            ));
+            print_hint(format!(
+                "See the commits between them with: frm alphas diff {} {}",
+                last_good, first_bad
+            ));
+        }
+        Some(Outcome::Undecided(candidates)) => {
+            let candidates: Vec<String> = candidates.iter().map(|v| v.to_string()).collect();
+            print_warning(format!(
+                "Because of skipped builds, the first bad alpha is one of: {}",
+                candidates.join(", ")
+            ));
+        }
+        None => {}
+    }
+    if !installed.is_empty() {
+        print_hint(format!(
+            "Installed for the bisection: {}. Remove them with 'frm alphas uninstall'",
+            installed.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Starts a node of the build, judges it, and stops the node.
+/// A build whose node does not boot is bad.
+fn test(paths: &Paths, version: &Version, run: Option<&str>) -> Result<Verdict> {
+    let was_running = node_state::is_running(paths, version)?;
+    if !was_running {
+        print_info(format!("Starting RabbitMQ {}", version));
+    }
+    let booted = if was_running {
+        Ok(())
+    } else {
+        bg_start::start(paths, version, None)
+    }
+    .and_then(|()| await_startup(paths, version));
+
+    let verdict = match booted {
+        Ok(()) => match run {
+            Some(command) => run_test(paths, version, command)?,
+            None => ask(version)?,
+        },
+        Err(e) => {
+            print_warning(format!("RabbitMQ {} did not boot: {}", version, e));
+            Verdict::Bad
+        }
+    };
+
+    if !was_running && node_state::is_running(paths, version)? {
+        bg_stop::shutdown(paths, version, None)?;
+    }
+    Ok(verdict)
+}
+
+/// `rabbitmq-server -detached` returns right away, and `await_startup` fails until the node's
+/// runtime is reachable, so it is retried
+fn await_startup(paths: &Paths, version: &Version) -> Result<()> {
+    let ctl_path = paths.version_sbin_dir(version).join(RABBITMQCTL);
+    let started = Instant::now();
+    loop {
+        let output = Command::new(&ctl_path)
+            .args(["await_startup", "--timeout", "10"])
+            .env(RABBITMQ_HOME, paths.version_dir(version))
+            .envs(cookie::env_vars(paths, version)?)
+            .envs(envs::node_name_env_vars(paths, version, None)?)
+            .output()
+            .map_err(|e| {
+                Error::CommandFailed(format!("failed to execute {}: {}", ctl_path.display(), e))
+            })?;
+        if output.status.success() {
+            return Ok(());
+        }
+        if started.elapsed() >= STARTUP_TIMEOUT {
+            return Err(Error::CommandFailed(format!(
+                "the node did not finish booting within {} seconds",
+                STARTUP_TIMEOUT.as_secs()
+            )));
+        }
+        debug!("node is not up yet");
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run_test(paths: &Paths, version: &Version, command: &str) -> Result<Verdict> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+
+    let status = cmd
+        .envs(scripts::env_vars(paths, version, None)?)
+        .status()
+        .map_err(|e| Error::CommandFailed(format!("failed to run '{}': {}", command, e)))?;
+    Ok(match status.code() {
+        Some(0) => Verdict::Good,
+        Some(SKIP_EXIT_CODE) => Verdict::Skip,
+        _ => Verdict::Bad,
+    })
+}
+
+fn ask(version: &Version) -> Result<Verdict> {
+    let mut stderr = io::stderr();
+    loop {
+        write!(
+            stderr,
+            "Is RabbitMQ {} good or bad? [g]ood, [b]ad, [s]kip: ",
+            version
+        )?;
+        stderr.flush()?;
+
+        let mut answer = String::new();
+        if io::stdin().lock().read_line(&mut answer)? == 0 {
+            return Err(Error::InvalidBisection("standard input was closed".into()));
+        }
+        match answer.trim().to_ascii_lowercase().as_str() {
+            "g" | "good" => return Ok(Verdict::Good),
+            "b" | "bad" => return Ok(Verdict::Bad),
+            "s" | "skip" => return Ok(Verdict::Skip),
+            _ => {}
+        }
+    }
+}
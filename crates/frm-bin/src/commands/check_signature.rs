@@ -10,15 +10,18 @@ use std::fs::{self, File};
 use std::io::Write;
 use std::path::Path;
 use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use futures_util::StreamExt;
 
 use crate::Result;
 use crate::common::http::USER_AGENT;
-use crate::common::urls::RABBITMQ_SIGNING_KEY_URL;
 use crate::errors::Error;
+use crate::keyring;
+use crate::messages::{print_error, print_warning};
+use crate::metadata::{SignatureCheck, VersionMetadata};
 use crate::paths::Paths;
-use crate::version::Version;
+use crate::version::{Artifact, Version};
 
 pub async fn run(paths: &Paths, version: &Version) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
@@ -32,35 +35,107 @@ pub async fn run(paths: &Paths, version: &Version) -> Result<()> {
     let client = reqwest::Client::new();
 
     paths.ensure_dirs()?;
+    keyring::ensure_built_in(&client, paths).await?;
+    let gpg_home = keyring::gpg_home(paths)?;
+    check(&client, paths, &gpg_home, version).await?;
 
-    let key_path = paths
-        .downloads_dir()
-        .join("rabbitmq-release-signing-key.asc");
-    if !key_path.exists() {
-        download_file(&client, RABBITMQ_SIGNING_KEY_URL, &key_path).await?;
+    println!("OK");
+
+    Ok(())
+}
+
+/// Verifies every installed GA release, continuing past failures
+pub async fn run_all(paths: &Paths) -> Result<()> {
+    let versions: Vec<Version> = paths
+        .installed_versions()?
+        .into_iter()
+        .filter(|v| v.is_ga())
+        .collect();
+    if versions.is_empty() {
+        print_warning("No GA releases installed");
+        return Ok(());
     }
 
-    import_gpg_key(&key_path)?;
+    let client = reqwest::Client::new();
+
+    paths.ensure_dirs()?;
+    keyring::ensure_built_in(&client, paths).await?;
+    let gpg_home = keyring::gpg_home(paths)?;
+
+    let mut failed = Vec::new();
+    let mut unchecked = 0;
+    for version in &versions {
+        match check(&client, paths, &gpg_home, version).await {
+            Ok(()) => println!("{}: OK", version),
+            Err(Error::SignatureVerificationFailed(_)) => {
+                println!("{}: FAILED", version);
+                failed.push(version.to_string());
+            }
+            Err(e) => {
+                print_error(format!("{}: could not check the signature: {}", version, e));
+                unchecked += 1;
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(Error::SignatureVerificationFailed(format!(
+            "{} of {} releases: {}",
+            failed.len(),
+            versions.len(),
+            failed.join(", ")
+        )));
+    }
+    if unchecked > 0 {
+        return Err(Error::ProblemsFound(unchecked));
+    }
+    Ok(())
+}
 
-    let archive_url = version.download_url();
-    let signature_url = format!("{}.asc", archive_url);
-    let archive_path = paths.downloads_dir().join(version.archive_name());
-    let signature_path = paths
-        .downloads_dir()
-        .join(format!("{}.asc", version.archive_name()));
+/// Verifies the release archive of a version and records the result in its metadata.
+/// Failures to download or to run gpg are not recorded.
+async fn check(
+    client: &reqwest::Client,
+    paths: &Paths,
+    gpg_home: &Path,
+    version: &Version,
+) -> Result<()> {
+    let archive = Artifact::generic_unix(version);
+    let signature = archive.detached_signature();
+    let archive_path = paths.downloads_dir().join(archive.file_name());
+    let signature_path = paths.downloads_dir().join(signature.file_name());
 
     if !archive_path.exists() {
-        download_file(&client, &archive_url, &archive_path).await?;
+        download_file(client, archive.url(), &archive_path).await?;
     }
 
-    download_file(&client, &signature_url, &signature_path).await?;
+    download_file(client, signature.url(), &signature_path).await?;
 
-    verify_signature(&archive_path, &signature_path)?;
+    let result = verify_signature(gpg_home, &archive_path, &signature_path);
 
     fs::remove_file(&signature_path).ok();
 
-    println!("OK");
+    let verified = match &result {
+        Ok(()) => true,
+        Err(Error::SignatureVerificationFailed(_)) => false,
+        Err(_) => return result,
+    };
+    record(paths, version, verified)?;
+
+    result
+}
 
+fn record(paths: &Paths, version: &Version, verified: bool) -> Result<()> {
+    let checked_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    VersionMetadata::update(paths, version, |m| {
+        m.signature = Some(SignatureCheck {
+            verified,
+            checked_at,
+        })
+    })?;
     Ok(())
 }
 
@@ -91,28 +166,14 @@ async fn download_file(client: &reqwest::Client, url: &str, dest: &Path) -> Resu
     Ok(())
 }
 
-fn import_gpg_key(key_path: &Path) -> Result<()> {
-    let output = Command::new("gpg")
-        .args(["--import", &key_path.display().to_string()])
-        .output()
-        .map_err(|e| Error::CommandFailed(format!("failed to run gpg --import: {}", e)))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        if !stderr.contains("not changed") {
-            return Err(Error::CommandFailed(format!(
-                "gpg --import failed: {}",
-                stderr
-            )));
-        }
-    }
-
-    Ok(())
-}
-
-fn verify_signature(archive_path: &Path, signature_path: &Path) -> Result<()> {
+/// Verifies against the trusted keys only, see [`keyring::gpg_home`]
+fn verify_signature(gpg_home: &Path, archive_path: &Path, signature_path: &Path) -> Result<()> {
     let output = Command::new("gpg")
+        .arg("--homedir")
+        .arg(gpg_home)
         .args([
+            "--batch",
+            "--no-autostart",
             "--verify",
             &signature_path.display().to_string(),
             &archive_path.display().to_string(),
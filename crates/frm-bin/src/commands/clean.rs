@@ -8,52 +8,106 @@
 
 use std::fs;
 
-use bel7_cli::{print_info, print_success};
 use chrono::{DateTime, Utc};
 use chrono_english::{Dialect, parse_date_string};
 
 use crate::Result;
+use crate::common::fs_utils::{available_space, dir_size, format_size, parse_size};
+use crate::common::prompt::confirm_destructive;
 use crate::config::Config;
+use crate::current;
 use crate::errors::Error;
+use crate::messages::{Message, print_info, print_success, print_warning};
+use crate::metadata::VersionMetadata;
 use crate::paths::Paths;
 use crate::timestamps::Timestamps;
+use crate::version::Version;
 
 pub fn parse_datetime(s: &str) -> Result<DateTime<Utc>> {
     parse_date_string(s, Utc::now(), Dialect::Us).map_err(|e| Error::InvalidDateTime(e.to_string()))
 }
 
-pub fn run(paths: &Paths, older_than: &str) -> Result<()> {
-    let cutoff = parse_datetime(older_than)?;
-    let cutoff_ts = cutoff.timestamp() as u64;
+/// Which alphas `clean` removes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CleanOptions<'a> {
+    /// Only alphas installed before this time
+    pub older_than: Option<&'a str>,
+    /// The oldest alphas, until this much space (e.g. "20GB") is free on the file system
+    /// of the frm directory
+    pub target_free: Option<&'a str>,
+}
+
+pub fn run(paths: &Paths, opts: &CleanOptions, assume_yes: bool) -> Result<()> {
+    let cutoff = opts.older_than.map(parse_datetime).transpose()?;
+    let cutoff_ts = cutoff.map(|c| c.timestamp() as u64);
+    let target_free = opts
+        .target_free
+        .map(|s| parse_size(s).ok_or_else(|| Error::InvalidSize(s.to_string())))
+        .transpose()?;
 
     let versions = paths.installed_versions()?;
     let alphas: Vec<_> = versions
         .into_iter()
         .filter(|v| v.is_distributed_via_server_packages_repository())
+        // shared versions are managed by whoever provisioned the shared directory
+        .filter(|v| !paths.version_shared(v))
         .collect();
 
     if alphas.is_empty() {
-        print_info("No alpha versions installed");
+        print_info(Message::NoAlphasInstalled);
         return Ok(());
     }
 
     let mut timestamps = Timestamps::load(paths)?;
 
-    let to_remove: Vec<_> = alphas
-        .into_iter()
-        .filter(|v| timestamps.get(v).map(|ts| ts < cutoff_ts).unwrap_or(true))
-        .collect();
+    let mut candidates = Vec::new();
+    for version in alphas {
+        let installed_at = timestamps.get(&version);
+        if let Some(cutoff_ts) = cutoff_ts
+            && !installed_at.map(|ts| ts < cutoff_ts).unwrap_or(true)
+        {
+            continue;
+        }
+        if VersionMetadata::is_pinned(paths, &version)? {
+            print_info(Message::KeepingPinned(&version));
+            continue;
+        }
+        candidates.push((installed_at, version));
+    }
+
+    let to_remove = match target_free {
+        Some(target) => {
+            // versions without a timestamp predate timestamps, so they go first
+            candidates.sort();
+            let candidates: Vec<_> = candidates.into_iter().map(|(_, v)| v).collect();
+            match select_for_target_free(paths, candidates, target)? {
+                Some(to_remove) => to_remove,
+                None => return Ok(()),
+            }
+        }
+        None => candidates.into_iter().map(|(_, v)| v).collect(),
+    };
 
     if to_remove.is_empty() {
         print_info("No alpha versions older than the specified time");
         return Ok(());
     }
 
+    let names: Vec<String> = to_remove.iter().map(|v| v.to_string()).collect();
+    let question = format!(
+        "Remove {} alpha version(s): {}?",
+        to_remove.len(),
+        names.join(", ")
+    );
+    if !confirm_destructive(paths, assume_yes, &question)? {
+        return Ok(());
+    }
+
     let mut config = Config::load(paths)?;
     let mut cleared_default = false;
 
     for version in &to_remove {
-        print_info(format!("Removing RabbitMQ {}", version));
+        print_info(Message::Removing(version));
 
         let version_dir = paths.version_dir(version);
         fs::remove_dir_all(&version_dir)?;
@@ -63,12 +117,13 @@ pub fn run(paths: &Paths, older_than: &str) -> Result<()> {
             cleared_default = true;
         }
 
-        let archive = paths.downloads_dir().join(version.archive_name());
+        let archive = paths.version_archive_file(version);
         if archive.exists() {
             fs::remove_file(archive)?;
         }
 
         timestamps.remove(version);
+        VersionMetadata::remove(paths, version)?;
     }
 
     if cleared_default {
@@ -77,16 +132,64 @@ pub fn run(paths: &Paths, older_than: &str) -> Result<()> {
         if default_file.exists() {
             fs::remove_file(default_file)?;
         }
-        print_info("Cleared default version (an alpha was the default)");
+        current::remove(paths)?;
+        print_info(Message::ClearedAlphaDefault);
     }
 
     timestamps.save(paths)?;
 
-    print_success(format!(
-        "Removed {} alpha version(s) older than {}",
-        to_remove.len(),
-        cutoff.format("%Y-%m-%d %H:%M:%S UTC")
-    ));
+    match cutoff {
+        Some(cutoff) => print_success(format!(
+            "Removed {} alpha version(s) older than {}",
+            to_remove.len(),
+            cutoff.format("%Y-%m-%d %H:%M:%S UTC")
+        )),
+        None => print_success(Message::RemovedAlphas(to_remove.len())),
+    }
+    if target_free.is_some() {
+        let free = available_space(paths.base_dir())?;
+        print_info(format!("{} free", format_size(free)));
+    }
 
     Ok(())
 }
+
+/// The oldest of `candidates` that have to go for `target` bytes to be free, or `None`
+/// if that much space is already free. Sizes are estimated with [`dir_size`], which
+/// overestimates what removing a version frees when its files are hard-linked by `dedup`.
+fn select_for_target_free(
+    paths: &Paths,
+    candidates: Vec<Version>,
+    target: u64,
+) -> Result<Option<Vec<Version>>> {
+    let free = available_space(paths.base_dir())?;
+    if free >= target {
+        print_info(format!(
+            "{} free, the target of {} is already met",
+            format_size(free),
+            format_size(target)
+        ));
+        return Ok(None);
+    }
+
+    let mut freed = 0;
+    let mut to_remove = Vec::new();
+    for version in candidates {
+        if free + freed >= target {
+            break;
+        }
+        freed += dir_size(&paths.version_dir(&version))?;
+        let archive = paths.version_archive_file(&version);
+        freed += fs::metadata(archive).map(|m| m.len()).unwrap_or(0);
+        to_remove.push(version);
+    }
+
+    if free + freed < target {
+        print_warning(format!(
+            "Removing every unpinned alpha frees about {}, short of the target of {} free",
+            format_size(freed),
+            format_size(target)
+        ));
+    }
+    Ok(Some(to_remove))
+}
@@ -8,55 +8,99 @@
 
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
 #[cfg(windows)]
 use std::process;
 use std::process::Command;
 
+use tracing::debug;
+
 use crate::Result;
-use crate::common::cli_tools::RABBITMQ_CLI_TOOLS;
+use crate::common::cli_tools::{RABBITMQ_CLI_TOOLS, RABBITMQADMIN, nearest_tool};
+use crate::cookie;
+use crate::envs;
 use crate::errors::Error;
+use crate::history;
 use crate::paths::Paths;
+use crate::toolbox::{self, Tool};
 use crate::version::Version;
 
-#[cfg(unix)]
 pub fn run(paths: &Paths, version: &Version, tool: &str, args: &[String]) -> Result<()> {
-    if !paths.version_installed(version) {
-        return Err(Error::VersionNotInstalled(version.clone()));
-    }
-
-    if !RABBITMQ_CLI_TOOLS.contains(&tool) {
-        return Err(Error::UnknownTool(format!(
-            "'{}'. Valid tools: {}",
-            tool,
-            RABBITMQ_CLI_TOOLS.join(", ")
-        )));
-    }
+    let tool_path = resolve_tool(paths, version, tool)?;
+    // rabbitmqadmin uses the HTTP API, not Erlang distribution
+    let env = if tool == RABBITMQADMIN {
+        Vec::new()
+    } else {
+        let mut env = cookie::env_vars(paths, version)?;
+        env.extend(envs::node_name_env_vars(paths, version, None)?);
+        env
+    };
+    exec(&tool_path, args, &env)
+}
 
-    let tool_path = paths.version_sbin_dir(version).join(tool);
-    if !tool_path.exists() {
-        return Err(Error::FileNotFound(tool_path.display().to_string()));
-    }
+/// Replaces frm with the given program. On Windows, runs it and exits with its exit code.
+#[cfg(unix)]
+pub fn exec(program: &Path, args: &[String], env: &[(&str, String)]) -> Result<()> {
+    debug!(path = %program.display(), ?args, "executing");
+    history::record_before_exec();
 
-    let err = Command::new(&tool_path).args(args).exec();
+    let err = Command::new(program)
+        .args(args)
+        .envs(env.iter().map(|(k, v)| (k, v)))
+        .exec();
 
     Err(Error::CommandFailed(format!(
         "failed to execute {}: {}",
-        tool_path.display(),
+        program.display(),
         err
     )))
 }
 
 #[cfg(windows)]
-pub fn run(paths: &Paths, version: &Version, tool: &str, args: &[String]) -> Result<()> {
+pub fn exec(program: &Path, args: &[String], env: &[(&str, String)]) -> Result<()> {
+    debug!(path = %program.display(), ?args, "executing");
+    history::record_before_exec();
+
+    let status = Command::new(program)
+        .args(args)
+        .envs(env.iter().map(|(k, v)| (k, v)))
+        .status()
+        .map_err(|e| {
+            Error::CommandFailed(format!("failed to execute {}: {}", program.display(), e))
+        })?;
+
+    process::exit(status.code().unwrap_or(1));
+}
+
+/// CLI tools come from the version's sbin directory. rabbitmqadmin v2 is not
+/// a part of the server distribution and is installed with 'frm toolbox'.
+fn resolve_tool(paths: &Paths, version: &Version, tool: &str) -> Result<PathBuf> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
 
+    if tool == RABBITMQADMIN {
+        let bundled = paths.version_sbin_dir(version).join(tool);
+        if bundled.exists() {
+            return Ok(bundled);
+        }
+        return toolbox::find_installed(paths, Tool::RabbitMQAdmin)?.ok_or_else(|| {
+            Error::ToolNotInstalled {
+                tool: RABBITMQADMIN.to_string(),
+            }
+        });
+    }
+
     if !RABBITMQ_CLI_TOOLS.contains(&tool) {
+        let suggestion = nearest_tool(tool)
+            .map(|nearest| format!(" Did you mean {}?", nearest))
+            .unwrap_or_default();
         return Err(Error::UnknownTool(format!(
-            "'{}'. Valid tools: {}",
+            "'{}'.{} Valid tools: {}, {}",
             tool,
-            RABBITMQ_CLI_TOOLS.join(", ")
+            suggestion,
+            RABBITMQ_CLI_TOOLS.join(", "),
+            RABBITMQADMIN
         )));
     }
 
@@ -64,10 +108,5 @@ pub fn run(paths: &Paths, version: &Version, tool: &str, args: &[String]) -> Res
     if !tool_path.exists() {
         return Err(Error::FileNotFound(tool_path.display().to_string()));
     }
-
-    let status = Command::new(&tool_path).args(args).status().map_err(|e| {
-        Error::CommandFailed(format!("failed to execute {}: {}", tool_path.display(), e))
-    })?;
-
-    process::exit(status.code().unwrap_or(1));
+    Ok(tool_path)
 }
@@ -0,0 +1,423 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Dynamic completion: `frm __complete <words...>` prints the candidates for the last word,
+//! given the words before it (without `frm` itself), one per line, as the candidate,
+//! optionally followed by a tab and a description. An empty last word completes
+//! everything that can follow the preceding words.
+//!
+//! Unlike the static scripts generated by `frm shell completions`, candidates can depend
+//! on what is installed (versions, environments, the tools of a version), and `conf get-key` and `conf set-key`
+//! complete `rabbitmq.conf` keys, which is what shell plugins and wrappers built on this
+//! protocol get on top of subcommands and options.
+
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, Write};
+use std::iter;
+
+use clap::{Arg, Command};
+use rabbitmq_conf::keys;
+
+use crate::Result;
+use crate::aliases;
+use crate::cli::build_cli;
+use crate::commands::version_table::flavor;
+use crate::common::cli_tools::{RABBITMQ_CLI_TOOLS, RABBITMQADMIN};
+use crate::config::Config;
+use crate::envs;
+use crate::keyring;
+use crate::paths::Paths;
+use crate::plugins;
+use crate::scripts;
+use crate::version::Version;
+use crate::workspace;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub value: String,
+    pub description: Option<String>,
+}
+
+impl Candidate {
+    fn new(value: impl Into<String>, description: Option<impl ToString>) -> Self {
+        Self {
+            value: value.into(),
+            // one line without tabs, so that the output stays one candidate per line
+            description: description
+                .map(|d| d.to_string())
+                .and_then(|d| d.lines().next().map(|l| l.replace('\t', " ")))
+                .filter(|d| !d.is_empty()),
+        }
+    }
+
+    pub fn to_line(&self) -> String {
+        match &self.description {
+            Some(description) => format!("{}\t{}", self.value, description),
+            None => self.value.clone(),
+        }
+    }
+}
+
+/// What the words before the one being completed select
+struct Context<'a> {
+    command: &'a Command,
+    /// Subcommand names from the top, e.g. ["releases", "uninstall"]
+    path: Vec<&'a str>,
+    /// Option and positional values seen so far, by argument id
+    values: HashMap<&'a str, &'a str>,
+    /// An option that expects a value, such as `--version`, when it is the last word
+    pending: Option<&'a Arg>,
+    positionals: usize,
+}
+
+pub fn run(paths: &Paths, words: &[String]) -> Result<()> {
+    let mut out = io::stdout().lock();
+    for candidate in candidates(paths, words) {
+        if writeln!(out, "{}", candidate.to_line()).is_err() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+pub fn candidates(paths: &Paths, words: &[String]) -> Vec<Candidate> {
+    let mut root = build_cli();
+    root.build();
+
+    let (current, preceding) = match words.split_last() {
+        Some((current, preceding)) => (current.as_str(), preceding),
+        None => ("", &[][..]),
+    };
+    let aliases = Config::load(paths)
+        .map(|config| config.aliases)
+        .unwrap_or_default();
+    let preceding = expand_aliases(preceding, &aliases, &root);
+    let context = walk(&root, &preceding);
+
+    if let Some(arg) = context.pending {
+        return filter(value_candidates(paths, &context, arg, current), current, "");
+    }
+    if let Some((name, value)) = current
+        .strip_prefix("--")
+        .and_then(|option| option.split_once('='))
+        && let Some(arg) = find_long(context.command, name)
+    {
+        let prefix = format!("--{}=", name);
+        return filter(
+            value_candidates(paths, &context, arg, value),
+            value,
+            &prefix,
+        );
+    }
+    if current.starts_with('-') {
+        return filter(option_candidates(context.command), current, "");
+    }
+
+    let mut candidates: Vec<Candidate> = if context.positionals == 0 {
+        context
+            .command
+            .get_subcommands()
+            .filter(|sub| !sub.is_hide_set())
+            .map(|sub| Candidate::new(sub.get_name(), sub.get_about()))
+            .chain(alias_candidates(&context, &aliases))
+            .chain(plugin_candidates(&context))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    if let Some(arg) = positional_arg(context.command, context.positionals) {
+        candidates.extend(value_candidates(paths, &context, arg, current));
+    }
+    filter(candidates, current, "")
+}
+
+fn walk<'a>(root: &'a Command, words: &'a [String]) -> Context<'a> {
+    let mut context = Context {
+        command: root,
+        path: Vec::new(),
+        values: HashMap::new(),
+        pending: None,
+        positionals: 0,
+    };
+    let mut options_ended = false;
+
+    for word in words {
+        let word = word.as_str();
+        if let Some(arg) = context.pending.take() {
+            context.values.insert(arg.get_id().as_str(), word);
+            continue;
+        }
+        if !options_ended && word == "--" {
+            options_ended = true;
+            continue;
+        }
+        if !options_ended && let Some(option) = word.strip_prefix("--") {
+            match option.split_once('=') {
+                Some((name, value)) => {
+                    if let Some(arg) = find_long(context.command, name) {
+                        context.values.insert(arg.get_id().as_str(), value);
+                    }
+                }
+                None => {
+                    context.pending =
+                        find_long(context.command, option).filter(|arg| takes_value(arg));
+                }
+            }
+            continue;
+        }
+        if !options_ended && word.len() > 1 && word.starts_with('-') {
+            // a cluster of flags such as -fy, where only the last one can take a value
+            // unless the value is attached, as in -V4.2.3
+            for (i, c) in word.char_indices().skip(1) {
+                let Some(arg) = find_short(context.command, c).filter(|arg| takes_value(arg))
+                else {
+                    continue;
+                };
+                let attached = &word[i + c.len_utf8()..];
+                if attached.is_empty() {
+                    context.pending = Some(arg);
+                } else {
+                    context.values.insert(arg.get_id().as_str(), attached);
+                }
+                break;
+            }
+            continue;
+        }
+
+        if context.positionals == 0
+            && let Some(sub) = context.command.find_subcommand(word)
+        {
+            context.path.push(sub.get_name());
+            context.command = sub;
+            continue;
+        }
+        if let Some(arg) = positional_arg(context.command, context.positionals) {
+            context.values.insert(arg.get_id().as_str(), word);
+        }
+        context.positionals += 1;
+    }
+
+    context
+}
+
+fn takes_value(arg: &Arg) -> bool {
+    arg.get_action().takes_values()
+}
+
+fn find_long<'a>(command: &'a Command, name: &str) -> Option<&'a Arg> {
+    command.get_arguments().find(|arg| {
+        arg.get_long() == Some(name)
+            || arg
+                .get_all_aliases()
+                .is_some_and(|aliases| aliases.contains(&name))
+    })
+}
+
+fn find_short(command: &Command, c: char) -> Option<&Arg> {
+    command
+        .get_arguments()
+        .find(|arg| arg.get_short() == Some(c))
+}
+
+fn positional_arg(command: &Command, position: usize) -> Option<&Arg> {
+    let mut positionals: Vec<&Arg> = command.get_positionals().collect();
+    positionals.sort_by_key(|arg| arg.get_index());
+    positionals.get(position).copied().or_else(|| {
+        // the last positional can take several values, e.g. arguments passed to a tool
+        positionals
+            .last()
+            .copied()
+            .filter(|arg| arg.get_num_args().is_some_and(|n| n.max_values() > 1))
+    })
+}
+
+fn option_candidates(command: &Command) -> Vec<Candidate> {
+    command
+        .get_arguments()
+        .filter(|arg| !arg.is_positional() && !arg.is_hide_set())
+        .filter_map(|arg| {
+            let name = match (arg.get_long(), arg.get_short()) {
+                (Some(long), _) => format!("--{}", long),
+                (None, Some(short)) => format!("-{}", short),
+                (None, None) => return None,
+            };
+            Some(Candidate::new(name, arg.get_help()))
+        })
+        .collect()
+}
+
+/// `current` is the part of the value typed so far
+fn value_candidates(paths: &Paths, context: &Context, arg: &Arg, current: &str) -> Vec<Candidate> {
+    let possible_values: Vec<Candidate> = arg
+        .get_possible_values()
+        .into_iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| Candidate::new(value.get_name(), value.get_help()))
+        .collect();
+    if !possible_values.is_empty() {
+        return possible_values;
+    }
+
+    match (arg.get_id().as_str(), context.path.as_slice()) {
+        ("version" | "version_opt", path) => version_candidates(paths, path),
+        ("env", _) | ("name", ["envs", "delete"]) => env_candidates(paths, context),
+        ("tool", ["cli"]) => tool_candidates(paths, context),
+        ("name", ["run-script"]) => script_candidates(paths),
+        ("name", ["workspace", "enter" | "delete"]) => workspace_candidates(paths),
+        ("name", ["keys", "remove"]) => signing_key_candidates(paths),
+        ("key", ["conf", "get-key" | "set-key"]) => keys::complete_key(current)
+            .into_iter()
+            .map(|key| Candidate::new(key, None::<&str>))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Installed versions of the kind the command works with, newest first
+fn version_candidates(paths: &Paths, path: &[&str]) -> Vec<Candidate> {
+    let mut versions = paths.installed_versions().unwrap_or_default();
+    match path.first() {
+        Some(&"alphas") => versions.retain(|v| v.is_distributed_via_server_packages_repository()),
+        Some(&"releases") => {
+            versions.retain(|v| !v.is_distributed_via_server_packages_repository())
+        }
+        _ => {}
+    }
+
+    let mut candidates = Vec::new();
+    if path.last() == Some(&"install") {
+        candidates.push(Candidate::new("latest", Some("the most recent release")));
+    }
+    candidates.extend(
+        versions
+            .iter()
+            .rev()
+            .map(|v| Candidate::new(v.to_string(), Some(flavor(v)))),
+    );
+    candidates
+}
+
+/// Tools `frm cli` runs. With `--version`, only those in the version's sbin directory,
+/// as older versions do not ship every tool
+fn tool_candidates(paths: &Paths, context: &Context) -> Vec<Candidate> {
+    let sbin = context
+        .values
+        .get("version")
+        .and_then(|v| v.parse::<Version>().ok())
+        .map(|version| paths.version_sbin_dir(&version))
+        .filter(|sbin| sbin.is_dir());
+
+    let mut candidates: Vec<Candidate> = RABBITMQ_CLI_TOOLS
+        .iter()
+        .filter(|tool| sbin.as_ref().is_none_or(|sbin| sbin.join(tool).exists()))
+        .map(|tool| Candidate::new(*tool, None::<&str>))
+        .collect();
+    candidates.push(Candidate::new(RABBITMQADMIN, Some("the HTTP API client")));
+    candidates
+}
+
+/// Environments of the version given with `--version`
+fn env_candidates(paths: &Paths, context: &Context) -> Vec<Candidate> {
+    let Some(version) = context
+        .values
+        .get("version")
+        .or_else(|| context.values.get("version_opt"))
+        .and_then(|v| v.parse::<Version>().ok())
+    else {
+        return Vec::new();
+    };
+    envs::list(paths, &version)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|name| Candidate::new(name, None::<&str>))
+        .collect()
+}
+
+fn workspace_candidates(paths: &Paths) -> Vec<Candidate> {
+    workspace::list(paths)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|name| Candidate::new(name, None::<&str>))
+        .collect()
+}
+
+/// Keys added with 'frm keys add', the built-in one cannot be removed
+fn signing_key_candidates(paths: &Paths) -> Vec<Candidate> {
+    keyring::list(paths)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|key| !key.built_in)
+        .map(|key| Candidate::new(key.name, Some(key.source)))
+        .collect()
+}
+
+/// Words after an alias are completed as if the alias were expanded
+fn expand_aliases(
+    words: &[String],
+    aliases: &BTreeMap<String, String>,
+    root: &Command,
+) -> Vec<String> {
+    // `aliases::expand` expects the program name first
+    let args = iter::once(OsString::new())
+        .chain(words.iter().map(OsString::from))
+        .collect();
+    aliases::expand(args, aliases, root)
+        .into_iter()
+        .skip(1)
+        .map(|word| word.to_string_lossy().into_owned())
+        .collect()
+}
+
+/// Aliases from config.toml, at the top level only. Built-in commands take precedence.
+fn alias_candidates(context: &Context, aliases: &BTreeMap<String, String>) -> Vec<Candidate> {
+    if !context.path.is_empty() {
+        return Vec::new();
+    }
+    aliases
+        .iter()
+        .filter(|(name, _)| !aliases::is_built_in(context.command, name))
+        .map(|(name, expansion)| Candidate::new(name, Some(format!("alias for '{}'", expansion))))
+        .collect()
+}
+
+/// `frm-<name>` executables in PATH, at the top level only. Built-in commands take precedence.
+fn plugin_candidates(context: &Context) -> Vec<Candidate> {
+    if !context.path.is_empty() {
+        return Vec::new();
+    }
+    let path_var = env::var_os("PATH").unwrap_or_default();
+    plugins::list(&path_var)
+        .into_iter()
+        .filter(|plugin| context.command.find_subcommand(&plugin.name).is_none())
+        .map(|plugin| {
+            let description = format!("plugin at {}", plugin.path.display());
+            Candidate::new(plugin.name, Some(description))
+        })
+        .collect()
+}
+
+fn script_candidates(paths: &Paths) -> Vec<Candidate> {
+    scripts::list(paths)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|script| Candidate::new(script.name, script.description))
+        .collect()
+}
+
+fn filter(candidates: Vec<Candidate>, current: &str, prefix: &str) -> Vec<Candidate> {
+    candidates
+        .into_iter()
+        .filter(|c| c.value.starts_with(current))
+        .map(|c| Candidate {
+            value: format!("{}{}", prefix, c.value),
+            ..c
+        })
+        .collect()
+}
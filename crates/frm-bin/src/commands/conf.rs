@@ -6,49 +6,189 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::collections::BTreeMap;
+use std::env;
 use std::fs;
+use std::path::Path;
+use std::process::{self, Command};
 
-use bel7_cli::{print_info, print_warning};
+use clap::ValueEnum;
 use rabbitmq_conf::{RabbitMQConf, keys};
+use regex::Regex;
 
 use crate::Result;
+use crate::common::env_vars::{EDITOR, VISUAL};
+use crate::common::prompt;
 use crate::errors::Error;
+use crate::etc_backups;
+use crate::messages::{
+    Message, print_hint, print_info, print_success, print_warning, print_warning_to_stderr,
+};
 use crate::paths::Paths;
+use crate::shared;
 use crate::version::Version;
 
-/// Get a configuration key value from rabbitmq.conf
-pub fn get_key(paths: &Paths, version: &Version, key: &str) -> Result<()> {
+const JSON_TREE_VALUE_KEY: &str = "_value";
+
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
+/// How `get-key` prints the keys matched by a pattern
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum KeyFormat {
+    /// One `key = value` line per key
+    #[default]
+    Flat,
+    /// Keys grouped by their common prefixes, one segment per indentation level
+    Tree,
+    /// A nested JSON object with one level per key segment
+    JsonTree,
+}
+
+/// Like RabbitMQ itself, which logs a warning at boot and then uses rabbitmq.config
+/// when both files exist in the configuration directory
+pub fn classic_config_takes_precedence(paths: &Paths, version: &Version) -> bool {
+    let etc_dir = paths.version_etc_dir(version);
+    etc_dir.join("rabbitmq.conf").is_file() && etc_dir.join("rabbitmq.config").is_file()
+}
+
+/// Printed to stderr so that it does not end up in JSON or other machine-readable output
+pub fn warn_if_classic_config_takes_precedence(paths: &Paths, version: &Version) {
+    if classic_config_takes_precedence(paths, version) {
+        print_warning_to_stderr(Message::ClassicConfigTakesPrecedence(version));
+    }
+}
+
+/// Get a configuration key value from rabbitmq.conf. With `regex`, `key` is a regular expression
+/// matched against every key.
+pub fn get_key(
+    paths: &Paths,
+    version: &Version,
+    key: &str,
+    regex: bool,
+    format: KeyFormat,
+) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    shared::prepare(paths, version)?;
 
     let conf_path = paths.version_etc_dir(version).join("rabbitmq.conf");
     if !conf_path.exists() {
         return Err(Error::FileNotFound(conf_path.display().to_string()));
     }
+    warn_if_classic_config_takes_precedence(paths, version);
 
     let conf = RabbitMQConf::load(&conf_path).map_err(|e| Error::Config(e.to_string()))?;
 
-    if RabbitMQConf::is_pattern(key) {
+    let matches: Vec<(&str, &str)> = if regex {
+        let re = Regex::new(key)
+            .map_err(|e| Error::Config(format!("invalid regular expression: {}", e)))?;
+        let matches: Vec<_> = conf.entries().filter(|(k, _)| re.is_match(k)).collect();
+        if matches.is_empty() {
+            return Err(Error::Config(format!(
+                "no keys matching regular expression: {}",
+                key
+            )));
+        }
+        matches
+    } else if RabbitMQConf::is_pattern(key) {
         let matches = conf.get_matching(key);
         if matches.is_empty() {
             return Err(Error::Config(format!("no keys matching pattern: {}", key)));
         }
-        for (k, v) in matches {
-            println!("{} = {}", k, v);
-        }
-        Ok(())
+        matches
     } else {
         match conf.get(key) {
-            Some(value) => {
+            // a single value is printed as is, unless a grouped format was asked for
+            Some(value) if format == KeyFormat::Flat => {
                 println!("{}", value);
-                Ok(())
+                return Ok(());
+            }
+            Some(value) => vec![(key, value)],
+            None => return Err(Error::Config(format!("key not found: {}", key))),
+        }
+    };
+
+    match format {
+        KeyFormat::Flat => {
+            for (k, v) in matches {
+                println!("{} = {}", k, v);
             }
-            None => Err(Error::Config(format!("key not found: {}", key))),
         }
+        KeyFormat::Tree => print!("{}", format_tree(&matches)),
+        KeyFormat::JsonTree => println!("{}", serde_json::to_string_pretty(&json_tree(&matches))?),
+    }
+    Ok(())
+}
+
+/// A key segment: a key can have a value and be the prefix of other keys at the same time,
+/// e.g. `log.file` and `log.file.level`
+#[derive(Default)]
+struct KeyNode<'a> {
+    value: Option<&'a str>,
+    children: BTreeMap<&'a str, KeyNode<'a>>,
+}
+
+impl<'a> KeyNode<'a> {
+    fn build(entries: &[(&'a str, &'a str)]) -> Self {
+        let mut root = KeyNode::default();
+        for (key, value) in entries {
+            let node = key.split('.').fold(&mut root, |node, segment| {
+                node.children.entry(segment).or_default()
+            });
+            node.value = Some(value);
+        }
+        root
     }
 }
 
+/// Keys grouped by prefix, e.g. `log.file.level = info` becomes
+///
+/// ```text
+/// log
+///   file
+///     level = info
+/// ```
+fn format_tree(entries: &[(&str, &str)]) -> String {
+    fn render(node: &KeyNode, depth: usize, out: &mut String) {
+        for (segment, child) in &node.children {
+            let indent = "  ".repeat(depth);
+            match child.value {
+                Some(value) => out.push_str(&format!("{}{} = {}\n", indent, segment, value)),
+                None => out.push_str(&format!("{}{}\n", indent, segment)),
+            }
+            render(child, depth + 1, out);
+        }
+    }
+
+    let mut out = String::new();
+    render(&KeyNode::build(entries), 0, &mut out);
+    out
+}
+
+/// Keys as a nested JSON object. The value of a key that is also the prefix of other keys
+/// is stored under `_value`.
+fn json_tree(entries: &[(&str, &str)]) -> serde_json::Value {
+    fn convert(node: &KeyNode) -> serde_json::Value {
+        if node.children.is_empty() {
+            return node.value.unwrap_or_default().into();
+        }
+        let mut object = serde_json::Map::new();
+        if let Some(value) = node.value {
+            object.insert(JSON_TREE_VALUE_KEY.to_string(), value.into());
+        }
+        for (segment, child) in &node.children {
+            object.insert(segment.to_string(), convert(child));
+        }
+        object.into()
+    }
+
+    convert(&KeyNode::build(entries))
+}
+
 /// Set a configuration key value in rabbitmq.conf
 pub fn set_key(
     paths: &Paths,
@@ -60,6 +200,7 @@ pub fn set_key(
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    shared::prepare(paths, version)?;
 
     // Validate key format
     if !keys::is_valid_key_format(key) {
@@ -103,8 +244,8 @@ pub fn set_key(
     let was_updated = conf.contains_key(key);
     conf.set(key, value);
 
-    conf.save(&conf_path)
-        .map_err(|e| Error::Config(e.to_string()))?;
+    etc_backups::replace(&conf_path, conf.to_string().as_bytes())?;
+    warn_if_classic_config_takes_precedence(paths, version);
 
     if was_updated {
         print_info(format!("updated {} = {}", key, value));
@@ -114,3 +255,120 @@ pub fn set_key(
 
     Ok(())
 }
+
+/// Opens a copy of rabbitmq.conf in `$VISUAL` or `$EDITOR`. The edited copy replaces
+/// rabbitmq.conf only if it parses and, unless `force` is set, has no unknown keys.
+/// The previous file is backed up first.
+pub fn edit(paths: &Paths, version: &Version, force: bool) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    shared::prepare(paths, version)?;
+
+    let conf_path = paths.version_etc_dir(version).join("rabbitmq.conf");
+    let original = if conf_path.exists() {
+        fs::read_to_string(&conf_path)?
+    } else {
+        String::new()
+    };
+    let copy = env::temp_dir().join(format!(
+        "frm-rabbitmq-{}-{}.conf",
+        version.dir_name(),
+        process::id()
+    ));
+    fs::write(&copy, &original)?;
+
+    let edited = loop {
+        run_editor(&copy)?;
+        let edited = fs::read_to_string(&copy)?;
+        if edited == original {
+            fs::remove_file(&copy).ok();
+            print_info("No changes made to rabbitmq.conf");
+            return Ok(());
+        }
+
+        let problems = lint(&edited, force);
+        if problems.is_empty() {
+            break edited;
+        }
+        for problem in &problems {
+            print_warning(problem);
+        }
+        if !prompt::confirm("Edit again?")? {
+            return Err(Error::Config(format!(
+                "rabbitmq.conf was not changed, the edited copy is at {}",
+                copy.display()
+            )));
+        }
+    };
+
+    fs::create_dir_all(paths.version_etc_dir(version))?;
+    let backup = etc_backups::replace(&conf_path, edited.as_bytes())?;
+    fs::remove_file(&copy).ok();
+
+    print_success(format!("Updated {}", conf_path.display()));
+    warn_if_classic_config_takes_precedence(paths, version);
+    if backup.is_some() {
+        print_hint(format!(
+            "The previous version was backed up, restore it with 'frm conf revert -V {}'",
+            version
+        ));
+    }
+    Ok(())
+}
+
+/// Syntax errors and, unless `force` is set, unknown keys. Unknown keys are only
+/// reported as warnings with `force`.
+fn lint(content: &str, force: bool) -> Vec<String> {
+    let conf = match RabbitMQConf::parse(content) {
+        Ok(conf) => conf,
+        Err(e) => return vec![e.to_string()],
+    };
+
+    let mut problems = Vec::new();
+    for key in conf.keys().filter(|k| !keys::is_known_key(k)) {
+        let suggestions = keys::suggest_similar_keys(key);
+        let msg = if suggestions.is_empty() {
+            format!("unknown configuration key: {}", key)
+        } else {
+            format!(
+                "unknown configuration key: {}. Similar keys: {}",
+                key,
+                suggestions.join(", ")
+            )
+        };
+        if force {
+            print_warning(msg);
+        } else {
+            problems.push(msg);
+        }
+    }
+    problems
+}
+
+/// `$VISUAL`, then `$EDITOR`, then a platform default. The variables can include arguments,
+/// e.g. `code --wait`.
+fn run_editor(file: &Path) -> Result<()> {
+    let editor = [VISUAL, EDITOR]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or(DEFAULT_EDITOR);
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(file)
+        .status()
+        .map_err(|e| Error::Config(format!("could not run editor '{}': {}", program, e)))?;
+    if !status.success() {
+        return Err(Error::Config(format!(
+            "editor '{}' exited with {}, the edited copy is at {}",
+            program,
+            status,
+            file.display()
+        )));
+    }
+    Ok(())
+}
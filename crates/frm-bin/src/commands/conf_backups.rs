@@ -0,0 +1,110 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::commands::EtcFile;
+use crate::common::fs_utils::format_size;
+use crate::errors::Error;
+use crate::etc_backups::{self, Backup};
+use crate::messages::{print_hint, print_info, print_success};
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Tabled)]
+struct BackupRow {
+    #[tabled(rename = "#")]
+    number: usize,
+    #[tabled(rename = "Backed Up At")]
+    created_at: String,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+/// Lists the backups of an etc file, most recent first
+pub fn history(paths: &Paths, version: &Version, file: EtcFile) -> Result<()> {
+    let path = etc_file_path(paths, version, file)?;
+    let backups = etc_backups::list(&path)?;
+    if backups.is_empty() {
+        print_info(format!("No backups of {} for RabbitMQ {}", file, version));
+        return Ok(());
+    }
+
+    let rows: Vec<BackupRow> = backups
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, backup)| BackupRow {
+            number: i + 1,
+            created_at: format_created_at(backup),
+            size: fs::metadata(&backup.path)
+                .map(|m| format_size(m.len()))
+                .unwrap_or_else(|_| "?".to_string()),
+        })
+        .collect();
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    print_hint(format!(
+        "Restore one with 'frm conf revert <#> -V {} --file {}'",
+        version, file
+    ));
+    Ok(())
+}
+
+/// Replaces an etc file with one of its backups, 1 being the most recent one.
+/// The current file is backed up first.
+pub fn revert(paths: &Paths, version: &Version, file: EtcFile, number: usize) -> Result<()> {
+    let path = etc_file_path(paths, version, file)?;
+    let backups = etc_backups::list(&path)?;
+    let backup = number
+        .checked_sub(1)
+        .and_then(|i| backups.iter().rev().nth(i))
+        .ok_or_else(|| {
+            Error::FileNotFound(format!(
+                "backup #{} of {} (there are {})",
+                number,
+                file,
+                backups.len()
+            ))
+        })?;
+
+    etc_backups::revert(&path, backup)?;
+    print_success(format!(
+        "Restored {} of RabbitMQ {} from the backup made at {}",
+        file,
+        version,
+        format_created_at(backup)
+    ));
+    print_hint(format!(
+        "The replaced file was backed up, undo with 'frm conf revert 1 -V {} --file {}'",
+        version, file
+    ));
+    Ok(())
+}
+
+fn etc_file_path(paths: &Paths, version: &Version, file: EtcFile) -> Result<PathBuf> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    Ok(paths.version_etc_dir(version).join(file.as_str()))
+}
+
+fn format_created_at(backup: &Backup) -> String {
+    DateTime::from_timestamp_millis(backup.created_at as i64)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,336 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! 'frm conf convert': translates the settings of the classic rabbitmq.config into
+//! rabbitmq.conf keys. Only settings of the `rabbit` application with a known rabbitmq.conf
+//! equivalent are converted, everything else is listed for advanced.config.
+
+use std::fs;
+
+use rabbitmq_conf::erlang_terms::{self, Term};
+use rabbitmq_conf::{RabbitMQConf, keys};
+
+use crate::Result;
+use crate::errors::Error;
+use crate::messages::print_warning_to_stderr;
+use crate::paths::Paths;
+use crate::shared;
+use crate::version::Version;
+
+/// rabbit settings whose rabbitmq.conf key has the same name and a scalar value
+const SAME_NAME_KEYS: &[&str] = &[
+    "background_gc_enabled",
+    "background_gc_target_interval",
+    "channel_max",
+    "cluster_keepalive_interval",
+    "cluster_name",
+    "cluster_partition_handling",
+    "collect_statistics_interval",
+    "connection_max",
+    "consumer_timeout",
+    "default_pass",
+    "default_queue_type",
+    "default_user",
+    "default_vhost",
+    "delegate_count",
+    "frame_max",
+    "handshake_timeout",
+    "heartbeat",
+    "max_message_size",
+    "mnesia_table_loading_retry_limit",
+    "mnesia_table_loading_retry_timeout",
+    "proxy_protocol",
+    "queue_index_embed_msgs_below",
+    "queue_master_locator",
+    "reverse_dns_lookups",
+    "ssl_handshake_timeout",
+    "vm_memory_calculation_strategy",
+    "vm_memory_high_watermark_paging_ratio",
+];
+
+/// `ssl_options` entries with a rabbitmq.conf equivalent
+const SSL_OPTIONS: &[&str] = &[
+    "cacertfile",
+    "certfile",
+    "depth",
+    "fail_if_no_peer_cert",
+    "keyfile",
+    "password",
+    "verify",
+];
+
+type Settings = Vec<(String, String)>;
+
+/// Prints rabbitmq.conf settings equivalent to the version's rabbitmq.config, followed by
+/// a commented-out advanced.config with the settings that could not be converted
+pub fn run(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    shared::prepare(paths, version)?;
+
+    let classic_path = paths.version_etc_dir(version).join("rabbitmq.config");
+    if !classic_path.exists() {
+        return Err(Error::FileNotFound(classic_path.display().to_string()));
+    }
+    let term = erlang_terms::parse(&fs::read_to_string(&classic_path)?).map_err(|e| {
+        Error::InvalidConfigFile {
+            file: "rabbitmq.config".to_string(),
+            reason: e.to_string(),
+        }
+    })?;
+
+    let converted = convert(&term);
+    print!("{}", converted.conf);
+
+    if !converted.advanced.is_empty() {
+        print_warning_to_stderr(format!(
+            "no rabbitmq.conf equivalent for {}, move them to advanced.config \
+            (listed at the end of the output)",
+            converted.unconverted.join(", ")
+        ));
+
+        if !converted.conf.to_string().is_empty() {
+            println!();
+        }
+        let entries: Vec<String> = converted
+            .advanced
+            .iter()
+            .map(|entry| format!("#   {}", entry))
+            .collect();
+        println!("# No rabbitmq.conf equivalent, move to advanced.config:");
+        println!("# [");
+        println!("{}", entries.join(",\n"));
+        println!("# ].");
+    }
+
+    Ok(())
+}
+
+struct Conversion {
+    conf: RabbitMQConf,
+    /// advanced.config entries with the settings that were not converted
+    advanced: Vec<Term>,
+    /// Names of the settings that were not converted, e.g. `rabbit.log`
+    unconverted: Vec<String>,
+}
+
+fn convert(term: &Term) -> Conversion {
+    let mut conversion = Conversion {
+        conf: RabbitMQConf::new(),
+        advanced: Vec::new(),
+        unconverted: Vec::new(),
+    };
+
+    for entry in term.as_list().unwrap_or_default() {
+        let Some((app, Some(settings))) = entry.as_pair().map(|(app, s)| (app, s.as_list())) else {
+            conversion.advanced.push(entry.clone());
+            conversion.unconverted.push(entry.to_string());
+            continue;
+        };
+
+        let mut left = Vec::new();
+        for setting in settings {
+            let converted = match (app, setting.as_pair()) {
+                ("rabbit", Some((key, value))) => rabbit_setting(key, value)
+                    .filter(|settings| settings.iter().all(|(k, _)| keys::is_known_key(k))),
+                _ => None,
+            };
+            match converted {
+                Some(settings) => {
+                    for (k, v) in settings {
+                        conversion.conf.set(&k, &v);
+                    }
+                }
+                None => {
+                    let name = match setting.as_pair() {
+                        Some((key, _)) => key.to_string(),
+                        None => setting.to_string(),
+                    };
+                    conversion.unconverted.push(format!("{}.{}", app, name));
+                    left.push(setting.clone());
+                }
+            }
+        }
+        if !left.is_empty() {
+            conversion.advanced.push(Term::Tuple(vec![
+                Term::Atom(app.to_string()),
+                Term::List(left),
+            ]));
+        }
+    }
+
+    conversion
+}
+
+fn rabbit_setting(key: &str, value: &Term) -> Option<Settings> {
+    let one = |k: &str, v: String| Some(vec![(k.to_string(), v)]);
+    match key {
+        k if SAME_NAME_KEYS.contains(&k) => one(k, scalar(value)?),
+        "tcp_listeners" => listeners("tcp", value),
+        "ssl_listeners" => listeners("ssl", value),
+        "num_tcp_acceptors" => one("num_acceptors.tcp", scalar(value)?),
+        "num_ssl_acceptors" => one("num_acceptors.ssl", scalar(value)?),
+        "vm_memory_high_watermark" => match value {
+            Term::Tuple(items) => match items.as_slice() {
+                [Term::Atom(a), limit] if a == "absolute" => {
+                    one("vm_memory_high_watermark.absolute", scalar(limit)?)
+                }
+                _ => None,
+            },
+            _ => one("vm_memory_high_watermark.relative", scalar(value)?),
+        },
+        "disk_free_limit" => match value {
+            Term::Tuple(items) => match items.as_slice() {
+                [Term::Atom(a), ratio] if a == "mem_relative" => {
+                    one("disk_free_limit.relative", scalar(ratio)?)
+                }
+                _ => None,
+            },
+            _ => one("disk_free_limit.absolute", scalar(value)?),
+        },
+        "default_user_tags" => value
+            .as_list()?
+            .iter()
+            .map(|tag| {
+                Some((
+                    format!("default_user_tags.{}", tag.as_atom()?),
+                    "true".into(),
+                ))
+            })
+            .collect(),
+        "default_permissions" => match value.as_list()? {
+            [configure, write, read] => Some(vec![
+                (
+                    "default_permissions.configure".into(),
+                    configure.as_text()?.into(),
+                ),
+                ("default_permissions.write".into(), write.as_text()?.into()),
+                ("default_permissions.read".into(), read.as_text()?.into()),
+            ]),
+            _ => None,
+        },
+        "loopback_users" => match value.as_list()? {
+            [] => one("loopback_users", "none".into()),
+            users => users
+                .iter()
+                .map(|user| {
+                    let user = user.as_text()?;
+                    // a dot would be read as another key segment
+                    (!user.contains('.'))
+                        .then(|| (format!("loopback_users.{}", user), "true".into()))
+                })
+                .collect(),
+        },
+        "auth_mechanisms" | "auth_backends" => {
+            numbered(key, value, |t| t.as_atom().map(Into::into))
+        }
+        "cluster_nodes" => match value {
+            Term::Tuple(items) => match items.as_slice() {
+                [nodes, Term::Atom(node_type)] => {
+                    let mut settings = vec![(
+                        "cluster_formation.peer_discovery_backend".to_string(),
+                        "classic_config".to_string(),
+                    )];
+                    settings.extend(numbered(
+                        "cluster_formation.classic_config.nodes",
+                        nodes,
+                        |t| t.as_atom().map(Into::into),
+                    )?);
+                    if node_type != "disc" {
+                        settings.push(("cluster_formation.node_type".into(), node_type.clone()));
+                    }
+                    Some(settings)
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        "tcp_listen_options" => value
+            .as_list()?
+            .iter()
+            .map(|option| {
+                let (k, v) = option.as_pair()?;
+                Some((format!("tcp_listen_options.{}", k), scalar(v)?))
+            })
+            .collect(),
+        "ssl_options" => {
+            let mut settings = Vec::new();
+            for option in value.as_list()? {
+                match option.as_pair()? {
+                    ("versions", versions) => {
+                        settings.extend(numbered("ssl_options.versions", versions, |t| {
+                            t.as_atom().map(Into::into)
+                        })?)
+                    }
+                    (k, v) if SSL_OPTIONS.contains(&k) => {
+                        settings.push((format!("ssl_options.{}", k), scalar(v)?))
+                    }
+                    _ => return None,
+                }
+            }
+            Some(settings)
+        }
+        _ => None,
+    }
+}
+
+/// `[a, b]` as `key.1 = a` and `key.2 = b`
+fn numbered(key: &str, value: &Term, f: impl Fn(&Term) -> Option<String>) -> Option<Settings> {
+    value
+        .as_list()?
+        .iter()
+        .enumerate()
+        .map(|(i, item)| Some((format!("{}.{}", key, i + 1), f(item)?)))
+        .collect()
+}
+
+/// `[5672]` or `[{"127.0.0.1", 5672}]`. A single listener is the default one.
+fn listeners(protocol: &str, value: &Term) -> Option<Settings> {
+    let listeners = value.as_list()?;
+    if listeners.is_empty() {
+        return Some(vec![(format!("listeners.{}", protocol), "none".into())]);
+    }
+    let addresses: Option<Vec<String>> = listeners
+        .iter()
+        .map(|listener| match listener {
+            Term::Integer(port) => Some(port.to_string()),
+            Term::Tuple(items) => match items.as_slice() {
+                [ip, Term::Integer(port)] => Some(format!("{}:{}", ip.as_text()?, port)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let addresses = addresses?;
+    if addresses.len() == 1 {
+        return Some(vec![(
+            format!("listeners.{}.default", protocol),
+            addresses[0].clone(),
+        )]);
+    }
+    Some(
+        addresses
+            .into_iter()
+            .enumerate()
+            .map(|(i, address)| (format!("listeners.{}.{}", protocol, i + 1), address))
+            .collect(),
+    )
+}
+
+/// Atoms, numbers, strings, and binaries as rabbitmq.conf values
+fn scalar(value: &Term) -> Option<String> {
+    match value {
+        Term::Atom(a) => Some(a.clone()),
+        Term::Integer(n) => Some(n.to_string()),
+        // cuttlefish float values need a decimal point, e.g. 1.0 and not 1
+        Term::Float(x) => Some(format!("{:?}", x)),
+        Term::String(s) | Term::Binary(s) => Some(s.clone()),
+        _ => None,
+    }
+}
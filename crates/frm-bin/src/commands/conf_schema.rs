@@ -0,0 +1,68 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! 'frm conf schema dump': the rabbitmq.conf keys frm knows about, as JSON for editor
+//! extensions and linters.
+
+use rabbitmq_conf::keys;
+use serde::Serialize;
+
+use crate::Result;
+
+/// Bumped when a field changes meaning or is removed, not when fields are added
+const SCHEMA_FORMAT_VERSION: u32 = 1;
+
+const WILDCARD: &str = "*";
+
+#[derive(Debug, Serialize)]
+struct Schema {
+    format_version: u32,
+    /// Matches a single key segment in patterns
+    wildcard: &'static str,
+    keys: Vec<KeyPattern>,
+}
+
+#[derive(Debug, Serialize)]
+struct KeyPattern {
+    pattern: &'static str,
+    /// The pattern with a conventional name for every wildcard, when there is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<String>,
+}
+
+pub fn dump() -> Result<()> {
+    let schema = Schema {
+        format_version: SCHEMA_FORMAT_VERSION,
+        wildcard: WILDCARD,
+        keys: keys::known_key_patterns()
+            .iter()
+            .map(|pattern| KeyPattern {
+                pattern,
+                example: example(pattern),
+            })
+            .collect(),
+    };
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
+
+fn example(pattern: &str) -> Option<String> {
+    if !pattern.contains(WILDCARD) {
+        return None;
+    }
+    let segments: Vec<&str> = pattern.split('.').collect();
+    let mut key = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.iter().enumerate() {
+        if *segment == WILDCARD {
+            key.push(keys::wildcard_example(&segments[..i].join("."))?);
+        } else {
+            key.push(segment);
+        }
+    }
+    Some(key.join("."))
+}
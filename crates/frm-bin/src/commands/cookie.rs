@@ -0,0 +1,121 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+
+use crate::Result;
+use crate::cookie::{self, Cookie};
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success};
+use crate::paths::Paths;
+use crate::shared;
+use crate::version::Version;
+
+pub fn show(paths: &Paths, version: Option<&Version>) -> Result<()> {
+    let found = match version {
+        Some(v) => {
+            ensure_installed(paths, v)?;
+            cookie::effective(paths, v)?
+        }
+        None => cookie::shared(paths)?,
+    };
+
+    match found {
+        Some(Cookie {
+            value,
+            source,
+            path,
+        }) => {
+            println!("{}", value);
+            print_info(format!("{} cookie from {}", source, path.display()));
+        }
+        None => print_info(format!(
+            "No frm-managed cookie, nodes and CLI tools use {}. Set one with: frm cookie set --generate",
+            cookie::home_cookie_file()?.display()
+        )),
+    }
+    Ok(())
+}
+
+/// Sets the shared cookie, or a version's own one. `None` generates a random cookie.
+pub fn set(paths: &Paths, version: Option<&Version>, value: Option<&str>) -> Result<()> {
+    let value = match value {
+        Some(v) => v.trim().to_string(),
+        None => cookie::generate(),
+    };
+
+    match version {
+        Some(v) => {
+            ensure_installed(paths, v)?;
+            cookie::write(&paths.version_cookie_file(v), &value)?;
+            print_success(format!("Set the Erlang cookie of RabbitMQ {}", v));
+        }
+        None => {
+            cookie::write(&paths.shared_cookie_file(), &value)?;
+            print_success("Set the shared Erlang cookie");
+        }
+    }
+    print_hint("Restart running nodes for the new cookie to take effect");
+    Ok(())
+}
+
+/// Copies the shared cookie to `~/.erlang.cookie`, or with `from_home`, the other way around
+pub fn sync(paths: &Paths, from_home: bool) -> Result<()> {
+    let home_file = cookie::home_cookie_file()?;
+
+    if from_home {
+        let value = cookie::read(&home_file)?
+            .ok_or_else(|| Error::FileNotFound(home_file.display().to_string()))?;
+        cookie::write(&paths.shared_cookie_file(), &value)?;
+        print_success(format!(
+            "Copied the cookie in {} to the shared frm cookie",
+            home_file.display()
+        ));
+        return Ok(());
+    }
+
+    let shared = cookie::shared(paths)?.ok_or_else(|| {
+        Error::FileNotFound(format!(
+            "{} (set it with 'frm cookie set' first)",
+            paths.shared_cookie_file().display()
+        ))
+    })?;
+    if cookie::read(&home_file).ok().flatten().as_deref() == Some(shared.value.as_str()) {
+        print_info(format!("{} is already in sync", home_file.display()));
+        return Ok(());
+    }
+
+    if home_file.exists() {
+        let backup = home_file.with_file_name(".erlang.cookie.frm-backup");
+        fs::copy(&home_file, &backup)?;
+        print_info(format!(
+            "Backed up {} to {}",
+            home_file.display(),
+            backup.display()
+        ));
+        // Erlang makes the cookie file read-only
+        let mut permissions = fs::metadata(&home_file)?.permissions();
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(&home_file, permissions)?;
+    }
+    cookie::write(&home_file, &shared.value)?;
+    print_success(format!(
+        "Copied the shared frm cookie to {}",
+        home_file.display()
+    ));
+    Ok(())
+}
+
+fn ensure_installed(paths: &Paths, version: &Version) -> Result<()> {
+    if paths.version_installed(version) {
+        shared::prepare(paths, version)
+    } else {
+        Err(Error::VersionNotInstalled(version.clone()))
+    }
+}
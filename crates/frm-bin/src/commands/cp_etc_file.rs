@@ -11,11 +11,13 @@ use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 
-use bel7_cli::print_info;
-
 use crate::Result;
+use crate::commands::show;
 use crate::errors::Error;
+use crate::etc_backups;
+use crate::messages::{print_info, print_warning};
 use crate::paths::Paths;
+use crate::shared;
 use crate::version::Version;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -77,11 +79,12 @@ pub fn run_release(
     version: &Version,
     local_path: &Path,
     etc_file: EtcFile,
+    force: bool,
 ) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedNonAlphaVersion(version.clone()));
     }
-    run(paths, version, local_path, etc_file)
+    run(paths, version, local_path, etc_file, force)
 }
 
 pub fn run_alpha(
@@ -89,14 +92,21 @@ pub fn run_alpha(
     version: &Version,
     local_path: &Path,
     etc_file: EtcFile,
+    force: bool,
 ) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedAlphaVersion(version.clone()));
     }
-    run(paths, version, local_path, etc_file)
+    run(paths, version, local_path, etc_file, force)
 }
 
-fn run(paths: &Paths, version: &Version, local_path: &Path, etc_file: EtcFile) -> Result<()> {
+fn run(
+    paths: &Paths,
+    version: &Version,
+    local_path: &Path,
+    etc_file: EtcFile,
+    force: bool,
+) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
@@ -105,13 +115,26 @@ fn run(paths: &Paths, version: &Version, local_path: &Path, etc_file: EtcFile) -
         return Err(Error::FileNotFound(local_path.display().to_string()));
     }
 
+    let content = fs::read(local_path)?;
+    // a node reads Erlang term files only when it boots, so catch syntax errors before that
+    if show::ERLANG_TERM_FILES.contains(&etc_file.as_str()) {
+        let text = String::from_utf8_lossy(&content);
+        if let Err(e) = show::validate(etc_file.as_str(), &text) {
+            if !force {
+                return Err(e);
+            }
+            print_warning(format!("{}, copying anyway because of --force", e));
+        }
+    }
+
+    shared::prepare(paths, version)?;
     let etc_dir = paths.version_etc_dir(version);
     if !etc_dir.exists() {
         fs::create_dir_all(&etc_dir)?;
     }
 
     let dest_path = etc_dir.join(etc_file.as_str());
-    fs::copy(local_path, &dest_path)?;
+    etc_backups::replace(&dest_path, &content)?;
 
     print_info(format!(
         "Copied {} to {}",
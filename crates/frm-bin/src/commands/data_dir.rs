@@ -0,0 +1,111 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! 'frm data-dir': keeps the node data of a version outside of the frm directory,
+//! e.g. on an external volume or a faster disk.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success};
+use crate::metadata::VersionMetadata;
+use crate::node_state;
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub fn show(paths: &Paths, version: &Version) -> Result<()> {
+    ensure_installed(paths, version)?;
+
+    println!("{}", envs::data_dir(paths, version, None)?.display());
+    if VersionMetadata::load(paths, version)?.data_dir.is_none() {
+        print_info(format!(
+            "RabbitMQ {} keeps its node data in the default location",
+            version
+        ));
+    }
+    Ok(())
+}
+
+/// Moves the node data directory of a version to `dir`, which is created if missing.
+/// Existing node data is not moved.
+pub fn set(paths: &Paths, version: &Version, dir: &Path) -> Result<PathBuf> {
+    ensure_installed(paths, version)?;
+    ensure_stopped(paths, version)?;
+
+    fs::create_dir_all(dir)?;
+    let dir = dir.canonicalize()?;
+    let previous = envs::data_dir(paths, version, None)?;
+    if previous == dir {
+        print_info(format!(
+            "RabbitMQ {} already keeps its node data in {}",
+            version,
+            dir.display()
+        ));
+        return Ok(dir);
+    }
+
+    VersionMetadata::update(paths, version, |m| m.data_dir = Some(dir.clone()))?;
+    print_success(format!(
+        "RabbitMQ {} now keeps its node data in {}",
+        version,
+        dir.display()
+    ));
+    hint_about_previous_data(&previous);
+    Ok(dir)
+}
+
+/// Moves the node data directory of a version back to the default location
+pub fn reset(paths: &Paths, version: &Version) -> Result<()> {
+    ensure_installed(paths, version)?;
+
+    let Some(previous) = VersionMetadata::load(paths, version)?.data_dir else {
+        print_info(format!(
+            "RabbitMQ {} already keeps its node data in the default location",
+            version
+        ));
+        return Ok(());
+    };
+    ensure_stopped(paths, version)?;
+
+    VersionMetadata::update(paths, version, |m| m.data_dir = None)?;
+    print_success(format!(
+        "RabbitMQ {} now keeps its node data in {}",
+        version,
+        paths.version_data_dir(version).display()
+    ));
+    hint_about_previous_data(&previous);
+    Ok(())
+}
+
+fn hint_about_previous_data(previous: &Path) {
+    let has_data = fs::read_dir(previous).is_ok_and(|mut entries| entries.next().is_some());
+    if has_data {
+        print_hint(format!(
+            "The existing node data in {} was not moved, stop the node and move it by hand to keep it",
+            previous.display()
+        ));
+    }
+}
+
+/// A running node keeps writing to the directory it was started with
+fn ensure_stopped(paths: &Paths, version: &Version) -> Result<()> {
+    if node_state::is_running(paths, version)? {
+        return Err(Error::NodeRunning(version.clone()));
+    }
+    Ok(())
+}
+
+fn ensure_installed(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::PathBuf;
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::common::fs_utils::{dir_size, format_size};
+use crate::dedup::{self, DedupReport};
+use crate::messages::{Message, print_hint, print_info, print_success};
+use crate::paths::Paths;
+
+#[derive(Tabled)]
+struct UsageRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Size")]
+    size: String,
+}
+
+pub fn run(paths: &Paths, dry_run: bool) -> Result<()> {
+    let report = deduplicate_installed(paths, dry_run)?;
+    if report.files_linked == 0 {
+        print_info("No duplicate files found across installed versions");
+    } else if dry_run {
+        print_info(format!(
+            "Would hard-link {} file(s), saving {}",
+            report.files_linked,
+            format_size(report.bytes_saved)
+        ));
+    } else {
+        print_success(format!(
+            "Hard-linked {} file(s), saved {}",
+            report.files_linked,
+            format_size(report.bytes_saved)
+        ));
+    }
+    Ok(())
+}
+
+/// Deduplicates all installed versions but linked ones
+pub fn deduplicate_installed(paths: &Paths, dry_run: bool) -> Result<DedupReport> {
+    let dirs = version_dirs(paths)?;
+    Ok(dedup::deduplicate(&dirs, dry_run)?)
+}
+
+pub fn du(paths: &Paths, dedup_savings: bool) -> Result<()> {
+    let versions = dedup::candidate_versions(paths)?;
+    if versions.is_empty() {
+        print_info(Message::NoVersionsInstalled);
+        return Ok(());
+    }
+
+    let rows: Vec<UsageRow> = versions
+        .iter()
+        .map(|v| UsageRow {
+            version: v.to_string(),
+            size: dir_size(&paths.version_dir(v))
+                .map(format_size)
+                .unwrap_or_else(|_| "?".to_string()),
+        })
+        .collect();
+    println!("{}", Table::new(rows).with(Style::rounded()));
+
+    let dirs = version_dirs(paths)?;
+    let usage = dedup::disk_usage(&dirs)?;
+    println!(
+        "Total: {}, {} on disk",
+        format_size(usage.apparent),
+        format_size(usage.on_disk)
+    );
+
+    if dedup_savings {
+        println!(
+            "Already shared via hard links: {}",
+            format_size(usage.shared())
+        );
+        let report = dedup::deduplicate(&dirs, true)?;
+        println!(
+            "Deduplication would save: {} ({} file(s))",
+            format_size(report.bytes_saved),
+            report.files_linked
+        );
+        if report.files_linked > 0 {
+            print_hint("Run 'frm dedup' to hard-link duplicate files");
+        }
+    }
+
+    Ok(())
+}
+
+fn version_dirs(paths: &Paths) -> Result<Vec<PathBuf>> {
+    Ok(dedup::candidate_versions(paths)?
+        .iter()
+        .map(|v| paths.version_dir(v))
+        .collect())
+}
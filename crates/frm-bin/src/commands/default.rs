@@ -8,11 +8,11 @@
 
 use std::fs;
 
-use bel7_cli::print_success;
-
 use crate::Result;
 use crate::config::Config;
+use crate::current;
 use crate::errors::Error;
+use crate::messages::{print_success, print_warning};
 use crate::paths::Paths;
 use crate::version::Version;
 
@@ -26,6 +26,15 @@ pub fn run(paths: &Paths, version: &Version) -> Result<()> {
     config.save(paths)?;
 
     fs::write(paths.default_file(), version.to_string())?;
+    // the default is set either way, the link is a convenience
+    if let Err(e) = current::update(paths, version) {
+        print_warning(format!(
+            "Could not point {} at {}: {}",
+            paths.current_link().display(),
+            version,
+            e
+        ));
+    }
 
     print_success(format!("Default version set to {}", version));
 
@@ -0,0 +1,76 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::errors::Error;
+use crate::messages::{print_info, print_warning};
+use crate::releases::{GitHubCommit, compare_alphas};
+use crate::version::Version;
+
+pub async fn run_alphas(base: &Version, head: &Version) -> Result<()> {
+    for version in [base, head] {
+        if !version.is_distributed_via_server_packages_repository() {
+            return Err(Error::ExpectedAlphaVersion(version.clone()));
+        }
+    }
+
+    let client = reqwest::Client::new();
+    let comparison = compare_alphas(&client, base, head).await?;
+
+    match comparison.status.as_str() {
+        "identical" => {
+            print_info(format!(
+                "{} and {} were built from the same commit",
+                base, head
+            ));
+            return Ok(());
+        }
+        "behind" => {
+            print_warning(format!(
+                "{} is older than {}; swap the arguments to see what changed",
+                head, base
+            ));
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    print_info(format!(
+        "{} commit(s) between {} and {}",
+        comparison.total_commits, base, head
+    ));
+    for commit in &comparison.commits {
+        println!("{}", format_commit(commit));
+    }
+
+    let shown = comparison.commits.len() as u64;
+    if comparison.total_commits > shown {
+        print_info(format!(
+            "Showing the first {} of {} commits",
+            shown, comparison.total_commits
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn format_commit(commit: &GitHubCommit) -> String {
+    match commit.commit.author {
+        Some(ref author) => {
+            let date = author.date.split('T').next().unwrap_or_default();
+            format!(
+                "{} {} ({}, {})",
+                commit.short_sha(),
+                commit.summary(),
+                author.name,
+                date
+            )
+        }
+        None => format!("{} {}", commit.short_sha(), commit.summary()),
+    }
+}
@@ -0,0 +1,325 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::commands::conf;
+use crate::common::cli_tools::{RABBITMQ_SERVER, RABBITMQCTL};
+use crate::config::Config;
+use crate::errors::Error;
+use crate::messages::{Message, print_success, print_warning};
+use crate::ownership;
+use crate::paths::Paths;
+use crate::permissions;
+use crate::version::Version;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The version directory exists but `sbin/rabbitmq-server` does not
+    BrokenVersion(Version),
+    /// Scripts in `sbin` or `escript` are not executable
+    NonExecutableScripts(Version, Vec<PathBuf>),
+    /// A temporary directory left behind by an interrupted installation or repair
+    LeftoverDirectory(PathBuf),
+    /// The default version is not installed
+    DefaultNotInstalled(Version),
+    /// Files in the frm directory owned by root while the directory is not,
+    /// left behind by running frm with sudo
+    RootOwnedFiles {
+        base_dir: PathBuf,
+        files: Vec<PathBuf>,
+    },
+    /// A RabbitMQ installation not managed by frm comes before frm's `sbin` directory in `PATH`,
+    /// so its tools run instead of the version frm activated
+    ShadowingInstallation {
+        path: PathBuf,
+        package_manager: Option<PackageManager>,
+        shadowed: Version,
+    },
+    /// Both rabbitmq.conf and the classic rabbitmq.config exist, so rabbitmq.conf is ignored
+    ClassicConfigTakesPrecedence(Version),
+}
+
+/// Where a RabbitMQ installation found in `PATH` comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageManager {
+    Homebrew,
+    MacPorts,
+    Nix,
+    /// apt, dnf, zypper and so on
+    System,
+}
+
+impl PackageManager {
+    /// Guesses by where the tool (or the file it links to) lives
+    pub fn of(tool: &Path) -> Option<Self> {
+        let resolved = fs::canonicalize(tool).unwrap_or_else(|_| tool.to_path_buf());
+        [tool, resolved.as_path()].into_iter().find_map(|path| {
+            if path.components().any(|c| c.as_os_str() == "Cellar")
+                || path.starts_with("/opt/homebrew")
+                || path.starts_with("/home/linuxbrew/.linuxbrew")
+            {
+                Some(PackageManager::Homebrew)
+            } else if path.starts_with("/opt/local") {
+                Some(PackageManager::MacPorts)
+            } else if path.starts_with("/nix") {
+                Some(PackageManager::Nix)
+            } else if path.starts_with("/usr/lib/rabbitmq")
+                || path.starts_with("/usr/sbin")
+                || path.starts_with("/usr/bin")
+                || path.starts_with("/sbin")
+            {
+                Some(PackageManager::System)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn removal(&self) -> &'static str {
+        match self {
+            PackageManager::Homebrew => "brew uninstall rabbitmq",
+            PackageManager::MacPorts => "sudo port uninstall rabbitmq-server",
+            PackageManager::Nix => "remove rabbitmq-server from your Nix profile or configuration",
+            PackageManager::System => {
+                "sudo apt remove rabbitmq-server (or the dnf, zypper equivalent)"
+            }
+        }
+    }
+}
+
+impl fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PackageManager::Homebrew => "Homebrew",
+            PackageManager::MacPorts => "MacPorts",
+            PackageManager::Nix => "Nix",
+            PackageManager::System => "the system package manager",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::BrokenVersion(v) => write!(
+                f,
+                "RabbitMQ {} is broken: sbin/rabbitmq-server is missing. Fix with: frm repair {}",
+                v, v
+            ),
+            Problem::NonExecutableScripts(v, files) => {
+                let names: Vec<String> = files
+                    .iter()
+                    .filter_map(|f| f.file_name())
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .collect();
+                write!(
+                    f,
+                    "RabbitMQ {} has scripts that are not executable: {}. Fix with: frm repair {}",
+                    v,
+                    names.join(", "),
+                    v
+                )
+            }
+            Problem::LeftoverDirectory(path) => write!(
+                f,
+                "{} was left behind by an interrupted operation and can be deleted",
+                path.display()
+            ),
+            Problem::DefaultNotInstalled(v) => write!(
+                f,
+                "default version {} is not installed. Fix with: frm default <version>",
+                v
+            ),
+            Problem::RootOwnedFiles { base_dir, files } => write!(
+                f,
+                "{} file(s) in {} are owned by root (e.g., {}), likely created by running frm \
+                with sudo, and will break installations. Fix with: sudo FRM_DIR={} frm doctor --fix-ownership",
+                files.len(),
+                base_dir.display(),
+                files
+                    .first()
+                    .map(|f| f.display().to_string())
+                    .unwrap_or_default(),
+                base_dir.display()
+            ),
+            Problem::ShadowingInstallation {
+                path,
+                package_manager,
+                shadowed,
+            } => {
+                let dir = path.parent().unwrap_or(path);
+                match package_manager {
+                    Some(pm) => write!(f, "{} (installed with {})", path.display(), pm)?,
+                    None => write!(f, "{}", path.display())?,
+                }
+                write!(
+                    f,
+                    " comes before RabbitMQ {} in PATH and runs instead of it. \
+                    Activate frm after other PATH changes in your shell profile \
+                    so that {} comes later in PATH",
+                    shadowed,
+                    dir.display()
+                )?;
+                match package_manager {
+                    Some(pm) => write!(f, ", or remove it with: {}", pm.removal()),
+                    None => f.write_str(", or remove it"),
+                }
+            }
+            Problem::ClassicConfigTakesPrecedence(v) => {
+                write!(f, "{}", Message::ClassicConfigTakesPrecedence(v))
+            }
+        }
+    }
+}
+
+pub fn diagnose(paths: &Paths) -> Result<Vec<Problem>> {
+    let mut problems = Vec::new();
+
+    for version in paths.installed_versions()? {
+        // shared versions are repaired by whoever provisioned them
+        if paths.version_shared(&version) {
+            continue;
+        }
+        if conf::classic_config_takes_precedence(paths, &version) {
+            problems.push(Problem::ClassicConfigTakesPrecedence(version.clone()));
+        }
+        if paths.version_broken(&version) {
+            problems.push(Problem::BrokenVersion(version));
+        } else if !paths.version_linked(&version) {
+            let files = permissions::non_executable_files(&paths.version_dir(&version))?;
+            if !files.is_empty() {
+                problems.push(Problem::NonExecutableScripts(version, files));
+            }
+        }
+    }
+
+    let versions_dir = paths.versions_dir();
+    if versions_dir.exists() {
+        let mut leftovers = Vec::new();
+        for entry in fs::read_dir(versions_dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.')
+                && (name.ends_with("-extracting") || name.ends_with("-repairing"))
+            {
+                leftovers.push(entry.path());
+            }
+        }
+        leftovers.sort();
+        problems.extend(leftovers.into_iter().map(Problem::LeftoverDirectory));
+    }
+
+    if let Some(default) = Config::load(paths)?.default_version
+        && !paths.version_installed(&default)
+    {
+        problems.push(Problem::DefaultNotInstalled(default));
+    }
+
+    let files = ownership::root_owned_files(paths.base_dir())?;
+    if !files.is_empty() {
+        problems.push(Problem::RootOwnedFiles {
+            base_dir: paths.base_dir().to_path_buf(),
+            files,
+        });
+    }
+
+    if let Some(path_var) = env::var_os("PATH") {
+        problems.extend(shadowing_installations(paths, &path_var));
+    }
+
+    Ok(problems)
+}
+
+/// RabbitMQ tools in `PATH` directories that come before the first `sbin` directory
+/// of a version installed by frm or provided by the shared directory
+pub fn shadowing_installations(paths: &Paths, path_var: &OsStr) -> Vec<Problem> {
+    let dirs: Vec<PathBuf> = env::split_paths(path_var).collect();
+    let versions_dirs: Vec<PathBuf> = [Some(paths.versions_dir()), paths.shared_versions_dir()]
+        .into_iter()
+        .flatten()
+        .collect();
+    let Some((position, shadowed)) = dirs.iter().enumerate().find_map(|(i, dir)| {
+        let version = versions_dirs
+            .iter()
+            .find_map(|versions_dir| dir.strip_prefix(versions_dir).ok())?
+            .components()
+            .next()?
+            .as_os_str()
+            .to_str()?
+            .parse::<Version>()
+            .ok()?;
+        Some((i, version))
+    }) else {
+        return Vec::new();
+    };
+
+    dirs[..position]
+        .iter()
+        .filter_map(|dir| {
+            [RABBITMQCTL, RABBITMQ_SERVER]
+                .iter()
+                .map(|tool| dir.join(tool))
+                .find(|path| path.is_file())
+        })
+        .map(|path| Problem::ShadowingInstallation {
+            package_manager: PackageManager::of(&path),
+            path,
+            shadowed: shadowed.clone(),
+        })
+        .collect()
+}
+
+pub fn run(paths: &Paths, fix_ownership: bool) -> Result<()> {
+    if fix_ownership {
+        return fix_root_owned_files(paths);
+    }
+
+    let problems = diagnose(paths)?;
+    if problems.is_empty() {
+        print_success("No problems found");
+        return Ok(());
+    }
+
+    for problem in &problems {
+        print_warning(problem);
+    }
+
+    Err(Error::ProblemsFound(problems.len()))
+}
+
+/// Gives root-owned files back to the owner of the frm directory
+fn fix_root_owned_files(paths: &Paths) -> Result<()> {
+    let base_dir = paths.base_dir();
+    let files = ownership::root_owned_files(base_dir)?;
+    if files.is_empty() {
+        print_success("No root-owned files found");
+        return Ok(());
+    }
+    if !ownership::running_as_root() {
+        return Err(Error::RootRequired(format!(
+            "changing the owner of {} root-owned file(s)",
+            files.len()
+        )));
+    }
+
+    let changed = ownership::fix(base_dir)?;
+    print_success(format!(
+        "Gave {} file(s) back to the owner of {}",
+        changed,
+        base_dir.display()
+    ));
+    Ok(())
+}
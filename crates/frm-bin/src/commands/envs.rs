@@ -0,0 +1,70 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::common::prompt::confirm_destructive;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success};
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub fn create(paths: &Paths, version: &Version, name: &str) -> Result<()> {
+    let env_dir = envs::create(paths, version, name)?;
+
+    print_success(format!(
+        "Created environment '{}' for RabbitMQ {} at {}",
+        name,
+        version,
+        env_dir.display()
+    ));
+    print_hint(format!(
+        "Start a node with: frm fg node --version {} --env {}",
+        version, name
+    ));
+
+    Ok(())
+}
+
+pub fn list(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    let names = envs::list(paths, version)?;
+    if names.is_empty() {
+        print_info(format!("No environments for RabbitMQ {}", version));
+        return Ok(());
+    }
+
+    for name in names {
+        println!("{}", name);
+    }
+
+    Ok(())
+}
+
+pub fn delete(paths: &Paths, version: &Version, name: &str, assume_yes: bool) -> Result<()> {
+    envs::ensure_exists(paths, version, name)?;
+
+    let question = format!(
+        "Delete environment '{}' of RabbitMQ {}, including its data and logs?",
+        name, version
+    );
+    if !confirm_destructive(paths, assume_yes, &question)? {
+        return Ok(());
+    }
+
+    envs::delete(paths, version, name)?;
+    print_success(format!(
+        "Deleted environment '{}' of RabbitMQ {}",
+        name, version
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::common::erlang::{
+    LocalErlang, built_with_otp_release, local_erlangs, otp_version_in_range,
+    supported_erlang_versions,
+};
+use crate::messages::{Message, print_info, print_warning};
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Tabled)]
+struct ErlangRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Built With")]
+    built_with: String,
+    #[tabled(rename = "Supported Erlang")]
+    supported: String,
+    #[tabled(rename = "Verdict")]
+    verdict: String,
+}
+
+/// Reports the Erlang/OTP release each installed version was compiled with, the Erlang/OTP
+/// installations found locally, and which of them can run each version. Nothing is modified.
+pub fn report(paths: &Paths) -> Result<()> {
+    let local = local_erlangs();
+    if local.is_empty() {
+        print_warning("No Erlang/OTP found on PATH or installed by asdf or mise");
+    } else {
+        println!("Local Erlang/OTP:");
+        for erlang in &local {
+            println!(
+                "  {} ({}, {})",
+                erlang.version,
+                erlang.source,
+                erlang.path.display()
+            );
+        }
+        println!();
+    }
+
+    let versions = paths.installed_versions()?;
+    if versions.is_empty() {
+        print_info(Message::NoVersionsInstalled);
+        return Ok(());
+    }
+
+    let rows: Vec<ErlangRow> = versions
+        .iter()
+        .map(|version| {
+            let built_with = built_with_otp_release(&paths.version_dir(version));
+            ErlangRow {
+                version: version.to_string(),
+                built_with: built_with
+                    .map(|release| format!("OTP {}", release))
+                    .unwrap_or_else(|| "-".to_string()),
+                supported: supported_erlang_versions(version)
+                    .map(|(min, max)| format!("{} - {}", min, max))
+                    .unwrap_or_else(|| "-".to_string()),
+                verdict: verdict(version, built_with, &local),
+            }
+        })
+        .collect();
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    Ok(())
+}
+
+/// A local Erlang can run a version when it is within the supported range and no older
+/// than the release the version was compiled with, since older releases cannot load newer BEAM files
+fn verdict(version: &Version, built_with: Option<u32>, local: &[LocalErlang]) -> String {
+    let Some((min, max)) = supported_erlang_versions(version) else {
+        return "unknown: supported Erlang versions are not known for this series".to_string();
+    };
+    let mut compatible: Vec<&str> = local
+        .iter()
+        .filter(|e| otp_version_in_range(&e.version, min, max))
+        .filter(|e| match (built_with, e.release()) {
+            (Some(built_with), Some(release)) => release >= built_with,
+            _ => true,
+        })
+        .map(|e| e.version.as_str())
+        .collect();
+    compatible.dedup();
+
+    if compatible.is_empty() {
+        let min_release = min.split('.').next().and_then(|r| r.parse::<u32>().ok());
+        let min = match built_with {
+            Some(release) if min_release.is_some_and(|min_release| release > min_release) => {
+                format!("{}.0", release)
+            }
+            _ => min.to_string(),
+        };
+        format!("no compatible Erlang: needs {} - {}", min, max)
+    } else {
+        format!("compatible with {}", compatible.join(", "))
+    }
+}
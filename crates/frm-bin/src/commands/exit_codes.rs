@@ -0,0 +1,60 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use bel7_cli::{ExitCode, ExitCodeExt};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::errors::FailureClass;
+
+/// Used by clap for command lines it cannot parse
+const ARGUMENT_PARSING_EXIT_CODE: i32 = 2;
+
+#[derive(Tabled)]
+struct ExitCodeRow {
+    #[tabled(rename = "Code")]
+    code: i32,
+    #[tabled(rename = "Class")]
+    class: &'static str,
+    #[tabled(rename = "Meaning")]
+    meaning: &'static str,
+}
+
+/// Prints the exit codes frm uses, one per class of failure
+pub fn run(plain: bool) {
+    let rows: Vec<ExitCodeRow> = [
+        ExitCodeRow {
+            code: ExitCode::Ok.to_i32(),
+            class: "ok",
+            meaning: "the command succeeded",
+        },
+        ExitCodeRow {
+            code: ARGUMENT_PARSING_EXIT_CODE,
+            class: "arguments",
+            meaning: "the command line could not be parsed, e.g. an unknown option",
+        },
+    ]
+    .into_iter()
+    .chain(FailureClass::ALL.iter().map(|class| ExitCodeRow {
+        code: class.exit_code().to_i32(),
+        class: class.name(),
+        meaning: class.description(),
+    }))
+    .collect();
+
+    if plain {
+        for row in &rows {
+            println!("{} {} {}", row.code, row.class, row.meaning);
+        }
+        return;
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+}
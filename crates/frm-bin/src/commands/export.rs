@@ -0,0 +1,161 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::Result;
+use crate::commands::ping::configured_port;
+use crate::download::copy_dir_recursive;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success};
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub const DEFAULT_BASE_IMAGE: &str = "erlang:27";
+const IMAGE_RABBITMQ_HOME: &str = "/opt/rabbitmq";
+const CONTEXT_DIST_DIR: &str = "rabbitmq";
+/// Node data, logs, and named environments stay on this machine
+const EXCLUDED_DIRS: &[&str] = &["var", "envs"];
+const MANAGEMENT_PORT: u16 = 15672;
+
+pub struct DockerExportOptions<'a> {
+    pub output: Option<&'a Path>,
+    pub env: Option<&'a str>,
+    pub base_image: &'a str,
+    pub tag: Option<&'a str>,
+    /// Container engine to build the image with, e.g. docker or podman
+    pub build_with: Option<&'a str>,
+}
+
+pub fn docker(paths: &Paths, version: &Version, opts: &DockerExportOptions) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if paths.version_broken(version) {
+        return Err(Error::CommandFailed(format!(
+            "RabbitMQ {} is broken, run 'frm repair {}' first",
+            version, version
+        )));
+    }
+    if let Some(name) = opts.env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let output = opts
+        .output
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(format!("frm-docker-{}", version)));
+    if output.exists() && fs::read_dir(&output)?.next().is_some() {
+        return Err(Error::CommandFailed(format!(
+            "output directory {} is not empty",
+            output.display()
+        )));
+    }
+
+    let dist_dir = output.join(CONTEXT_DIST_DIR);
+    fs::create_dir_all(&dist_dir)?;
+    copy_distribution(&paths.version_dir(version), &dist_dir)?;
+    if let Some(name) = opts.env {
+        let etc_dir = dist_dir.join("etc").join("rabbitmq");
+        fs::remove_dir_all(&etc_dir)?;
+        copy_dir_recursive(&paths.version_env_etc_dir(version, name), &etc_dir)?;
+    }
+
+    let amqp_port = configured_port(paths, version, opts.env)?;
+    fs::write(
+        output.join("Dockerfile"),
+        dockerfile(version, opts.base_image, amqp_port),
+    )?;
+    fs::write(output.join(".dockerignore"), "Dockerfile\n")?;
+
+    print_success(format!(
+        "Exported RabbitMQ {} to {}",
+        version,
+        output.display()
+    ));
+
+    let tag = opts
+        .tag
+        .map(String::from)
+        .unwrap_or_else(|| format!("frm-rabbitmq:{}", version));
+    match opts.build_with {
+        Some(engine) => build_image(engine, &output, &tag),
+        None => {
+            print_hint(format!(
+                "Build an image with: docker build -t {} {}",
+                tag,
+                output.display()
+            ));
+            Ok(())
+        }
+    }
+}
+
+pub fn dockerfile(version: &Version, base_image: &str, amqp_port: u16) -> String {
+    format!(
+        "# Generated by frm {frm_version}: RabbitMQ {version} with its local configuration\n\
+         FROM {base_image}\n\
+         \n\
+         COPY {dist} {home}\n\
+         \n\
+         ENV RABBITMQ_HOME={home} \\\n    \
+         RABBITMQ_CONFIG_FILES={home}/etc/rabbitmq/conf.d \\\n    \
+         PATH={home}/sbin:$PATH\n\
+         \n\
+         EXPOSE {amqp_port} {management_port}\n\
+         \n\
+         CMD [\"rabbitmq-server\"]\n",
+        frm_version = env!("CARGO_PKG_VERSION"),
+        version = version,
+        base_image = base_image,
+        dist = CONTEXT_DIST_DIR,
+        home = IMAGE_RABBITMQ_HOME,
+        amqp_port = amqp_port,
+        management_port = MANAGEMENT_PORT,
+    )
+}
+
+fn copy_distribution(version_dir: &Path, dest: &Path) -> Result<()> {
+    for entry in fs::read_dir(version_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if EXCLUDED_DIRS.iter().any(|d| name == *d) {
+            continue;
+        }
+
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir_recursive(&entry.path(), &dest.join(&name))?;
+        } else if file_type.is_file() {
+            fs::copy(entry.path(), dest.join(&name))?;
+        }
+    }
+    Ok(())
+}
+
+fn build_image(engine: &str, context: &Path, tag: &str) -> Result<()> {
+    print_info(format!("Building image {} with {}...", tag, engine));
+
+    let status = Command::new(engine)
+        .args(["build", "-t", tag])
+        .arg(context)
+        .status()
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", engine, e)))?;
+    if !status.success() {
+        return Err(Error::CommandFailed(format!(
+            "{} build exited with {}",
+            engine, status
+        )));
+    }
+
+    print_success(format!("Built image {}", tag));
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+
+use crate::Result;
+use crate::cli::build_cli;
+use crate::commands::cli_cmd::exec;
+use crate::commands::status::detect_active_version;
+use crate::common::cli_tools::edit_distance;
+use crate::common::env_vars::{FRM_BIN, FRM_DIR};
+use crate::config::Config;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::plugins::{self, PREFIX};
+use crate::scripts;
+use crate::version::Version;
+
+/// Runs `frm-<name>` from `PATH` with the frm directory and the version frm would use
+/// (the active one, or else the default one) passed in environment variables
+pub fn run(paths: &Paths, name: &str, args: &[String]) -> Result<()> {
+    let path_var = env::var_os("PATH").unwrap_or_default();
+    let Some(program) = plugins::find(name, &path_var) else {
+        return Err(unknown_command(name));
+    };
+
+    let mut vars = vec![(FRM_DIR, paths.base_dir().display().to_string())];
+    if let Ok(frm) = env::current_exe() {
+        vars.push((FRM_BIN, frm.display().to_string()));
+    }
+    if let Some(version) = current_version(paths) {
+        vars.extend(
+            scripts::env_vars(paths, &version, None)?
+                .into_iter()
+                .filter(|(name, _)| *name != FRM_DIR),
+        );
+    }
+
+    exec(&program, args, &vars)
+}
+
+fn current_version(paths: &Paths) -> Option<Version> {
+    detect_active_version(paths)
+        .or_else(|| Config::load(paths).ok()?.default_version)
+        .filter(|version| paths.version_installed(version))
+}
+
+fn unknown_command(name: &str) -> Error {
+    let cli = build_cli();
+    let max_distance = (name.len() / 3).max(2);
+    let suggestion = cli
+        .get_subcommands()
+        .filter(|sub| !sub.is_hide_set())
+        .map(|sub| (edit_distance(name, sub.get_name()), sub.get_name()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, nearest)| format!(" Did you mean '{}'?", nearest))
+        .unwrap_or_default();
+
+    Error::UnknownCommand(format!(
+        "'{}' is not a frm command and no {}{} executable was found in PATH.{}",
+        name, PREFIX, name, suggestion
+    ))
+}
@@ -8,31 +8,34 @@
 
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
-#[cfg(windows)]
-use std::process;
-use std::process::Command;
+use std::path::PathBuf;
+use std::process::{self, Command};
+
+use tracing::debug;
 
 use crate::Result;
 use crate::common::cli_tools::RABBITMQ_SERVER;
-use crate::common::env_vars::RABBITMQ_CONFIG_FILES;
+use crate::cookie;
+use crate::envs::{self, FreshNode};
 use crate::errors::Error;
+use crate::history;
+use crate::messages::print_info;
 use crate::paths::Paths;
+use crate::shared;
 use crate::version::Version;
 
 #[cfg(unix)]
-pub fn run(paths: &Paths, version: &Version) -> Result<()> {
-    if !paths.version_installed(version) {
-        return Err(Error::VersionNotInstalled(version.clone()));
-    }
-
-    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
-    if !server_path.exists() {
-        return Err(Error::FileNotFound(server_path.display().to_string()));
-    }
+pub fn run(paths: &Paths, version: &Version, env: Option<&str>) -> Result<()> {
+    let server_path = server_path(paths, version, env)?;
 
-    let err = Command::new(&server_path)
-        .env(RABBITMQ_CONFIG_FILES, paths.version_confd_dir(version))
-        .exec();
+    debug!(path = %server_path.display(), env, "starting node in foreground");
+    let mut command = Command::new(&server_path);
+    command
+        .envs(envs::node_env_vars(paths, version, env)?)
+        .envs(cookie::env_vars(paths, version)?)
+        .envs(envs::node_name_env_vars(paths, version, env)?);
+    history::record_before_exec();
+    let err = command.exec();
 
     Err(Error::CommandFailed(format!(
         "failed to execute {}: {}",
@@ -42,19 +45,48 @@ pub fn run(paths: &Paths, version: &Version) -> Result<()> {
 }
 
 #[cfg(windows)]
-pub fn run(paths: &Paths, version: &Version) -> Result<()> {
-    if !paths.version_installed(version) {
-        return Err(Error::VersionNotInstalled(version.clone()));
-    }
+pub fn run(paths: &Paths, version: &Version, env: Option<&str>) -> Result<()> {
+    let server_path = server_path(paths, version, env)?;
 
-    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
-    if !server_path.exists() {
-        return Err(Error::FileNotFound(server_path.display().to_string()));
-    }
+    debug!(path = %server_path.display(), env, "starting node in foreground");
+    let mut command = Command::new(&server_path);
+    command
+        .envs(envs::node_env_vars(paths, version, env)?)
+        .envs(cookie::env_vars(paths, version)?)
+        .envs(envs::node_name_env_vars(paths, version, env)?);
+    history::record_before_exec();
+    let status = command.status().map_err(|e| {
+        Error::CommandFailed(format!(
+            "failed to execute {}: {}",
+            server_path.display(),
+            e
+        ))
+    })?;
+
+    process::exit(status.code().unwrap_or(1));
+}
+
+/// Runs a node with a temporary data directory and node name, and deletes them when the node stops.
+/// Unlike [`run`], frm keeps running as the parent of the node to clean up after it.
+pub async fn run_fresh(paths: &Paths, version: &Version, env: Option<&str>) -> Result<()> {
+    let server_path = server_path(paths, version, env)?;
+    let fresh = FreshNode::create(version)?;
+    print_info(format!(
+        "Starting node {} with a temporary data directory at {}, it will be deleted when the node stops",
+        fresh.node_name,
+        fresh.dir.display()
+    ));
 
-    let status = Command::new(&server_path)
-        .env(RABBITMQ_CONFIG_FILES, paths.version_confd_dir(version))
-        .status()
+    // Ctrl+C is delivered to the node, frm has to survive it to delete the data directory
+    tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
+
+    debug!(path = %server_path.display(), env, dir = %fresh.dir.display(), "starting fresh node in foreground");
+    let mut child = Command::new(&server_path)
+        .envs(envs::node_env_vars(paths, version, env)?)
+        .envs(cookie::env_vars(paths, version)?)
+        .envs(envs::node_name_env_vars(paths, version, env)?)
+        .envs(fresh.env_vars())
+        .spawn()
         .map_err(|e| {
             Error::CommandFailed(format!(
                 "failed to execute {}: {}",
@@ -62,6 +94,31 @@ pub fn run(paths: &Paths, version: &Version) -> Result<()> {
                 e
             ))
         })?;
+    let status = tokio::task::spawn_blocking(move || child.wait())
+        .await
+        .map_err(|e| Error::CommandFailed(e.to_string()))??;
 
-    process::exit(status.code().unwrap_or(1));
+    drop(fresh);
+    print_info("Deleted the temporary data directory");
+
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+fn server_path(paths: &Paths, version: &Version, env: Option<&str>) -> Result<PathBuf> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    shared::prepare(paths, version)?;
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
+    if !server_path.exists() {
+        return Err(Error::FileNotFound(server_path.display().to_string()));
+    }
+    Ok(server_path)
 }
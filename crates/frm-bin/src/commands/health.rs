@@ -0,0 +1,176 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::process::Command;
+use std::time::Instant;
+
+use clap::ValueEnum;
+use serde::Serialize;
+use tracing::debug;
+
+use crate::Result;
+use crate::commands::bg_pause::find_node;
+use crate::commands::ports::version_listeners;
+use crate::common::cli_tools::RABBITMQ_DIAGNOSTICS;
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_error, print_info, print_success};
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum HealthFormat {
+    /// A line per check
+    #[default]
+    Text,
+    /// An object with the node and an array of checks
+    Json,
+}
+
+pub struct HealthOptions<'a> {
+    pub env: Option<&'a str>,
+    pub node: Option<&'a str>,
+    /// Passed to every check as --timeout, in seconds
+    pub timeout: u64,
+    pub format: HealthFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct HealthReport {
+    version: String,
+    node: String,
+    healthy: bool,
+    checks: Vec<CheckResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckResult {
+    check: String,
+    passed: bool,
+    duration_ms: u128,
+    /// What the check printed, the reason it failed in particular
+    output: String,
+}
+
+/// The `rabbitmq-diagnostics` commands run, in order, and their arguments
+fn checks(amqp_port: Option<u16>) -> Vec<Vec<String>> {
+    let mut checks = vec![vec!["ping".to_string()], vec!["check_running".to_string()]];
+    // not run when the AMQP listener is disabled with `listeners.tcp = none`
+    if let Some(port) = amqp_port {
+        checks.push(vec!["check_port_listener".to_string(), port.to_string()]);
+    }
+    checks.push(vec!["check_virtual_hosts".to_string()]);
+    checks.push(vec!["check_alarms".to_string()]);
+    checks
+}
+
+pub fn run(paths: &Paths, version: &Version, opts: &HealthOptions) -> Result<()> {
+    if let Some(name) = opts.env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+    let node = find_node(paths, version, opts.node)?;
+    let amqp_port = version_listeners(paths, version, opts.env)?
+        .into_iter()
+        .find(|l| l.protocol == "AMQP" && l.enabled)
+        .map(|l| l.port);
+
+    let mut results = Vec::new();
+    for args in checks(amqp_port) {
+        let result = run_check(paths, version, opts, &node.name, &args)?;
+        if opts.format == HealthFormat::Text {
+            report(&result);
+        }
+        results.push(result);
+    }
+
+    let failed = results.iter().filter(|r| !r.passed).count();
+    let total = results.len();
+    match opts.format {
+        HealthFormat::Json => {
+            let report = HealthReport {
+                version: version.to_string(),
+                node: node.name.clone(),
+                healthy: failed == 0,
+                checks: results,
+            };
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        HealthFormat::Text if failed == 0 => print_success(format!(
+            "{} of RabbitMQ {} passed all {} checks",
+            node.name, version, total
+        )),
+        HealthFormat::Text => {}
+    }
+
+    if failed > 0 {
+        return Err(Error::HealthCheckFailed {
+            node: node.name,
+            failed,
+            total,
+        });
+    }
+    Ok(())
+}
+
+fn run_check(
+    paths: &Paths,
+    version: &Version,
+    opts: &HealthOptions,
+    node: &str,
+    args: &[String],
+) -> Result<CheckResult> {
+    let tool_path = paths.version_sbin_dir(version).join(RABBITMQ_DIAGNOSTICS);
+    if !tool_path.exists() {
+        return Err(Error::FileNotFound(tool_path.display().to_string()));
+    }
+
+    let timeout = opts.timeout.to_string();
+    debug!(path = %tool_path.display(), node, ?args, "running health check");
+    let started = Instant::now();
+    let output = Command::new(&tool_path)
+        .args(["-n", node, "--timeout", &timeout, "--quiet"])
+        .args(args)
+        .env(RABBITMQ_HOME, paths.version_dir(version))
+        .envs(envs::node_env_vars(paths, version, opts.env)?)
+        .envs(cookie::env_vars(paths, version)?)
+        .output()
+        .map_err(|e| {
+            Error::CommandFailed(format!("failed to execute {}: {}", tool_path.display(), e))
+        })?;
+    let elapsed = started.elapsed();
+
+    let mut text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if !stderr.trim().is_empty() {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(stderr.trim());
+    }
+    Ok(CheckResult {
+        check: args.join(" "),
+        passed: output.status.success(),
+        duration_ms: elapsed.as_millis(),
+        output: text,
+    })
+}
+
+fn report(result: &CheckResult) {
+    let line = format!("{:<28} {} ms", result.check, result.duration_ms);
+    if result.passed {
+        print_success(line);
+        return;
+    }
+    print_error(line);
+    // the first line is usually enough to tell why, e.g. an alarm or a stopped vhost
+    if let Some(reason) = result.output.lines().find(|l| !l.trim().is_empty()) {
+        print_info(format!("  {}", reason.trim()));
+    }
+}
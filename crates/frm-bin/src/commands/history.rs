@@ -0,0 +1,73 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Local};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::config::Config;
+use crate::history::{self, HistoryEntry, HistoryQuery};
+use crate::messages::print_info;
+use crate::paths::Paths;
+
+#[derive(Tabled)]
+struct HistoryRow {
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Command")]
+    command: String,
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Outcome")]
+    outcome: String,
+}
+
+impl From<&HistoryEntry> for HistoryRow {
+    fn from(entry: &HistoryEntry) -> Self {
+        let time = DateTime::from_timestamp(entry.timestamp as i64, 0)
+            .map(|dt| {
+                dt.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        let outcome = match entry.error {
+            Some(ref e) => format!("{}: {}", entry.outcome, e),
+            None => entry.outcome.to_string(),
+        };
+
+        Self {
+            time,
+            command: format!("frm {}", entry.args.join(" ")),
+            version: entry.version.clone().unwrap_or_default(),
+            outcome,
+        }
+    }
+}
+
+pub fn run(paths: &Paths, query: &HistoryQuery) -> Result<()> {
+    let entries = query.apply(history::load(paths)?);
+
+    if entries.is_empty() {
+        print_info("No matching history entries");
+        if !Config::load(paths)?.record_history {
+            print_info(format!(
+                "History recording is disabled; enable it with 'record_history = true' in {}",
+                paths.config_file().display()
+            ));
+        }
+        return Ok(());
+    }
+
+    let rows: Vec<HistoryRow> = entries.iter().map(HistoryRow::from).collect();
+    let table = Table::new(rows).with(Style::rounded()).to_string();
+    println!("{}", table);
+
+    Ok(())
+}
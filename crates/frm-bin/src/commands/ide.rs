@@ -0,0 +1,203 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! 'frm ide vscode': VS Code settings that put a version's sbin directory on the `PATH`
+//! of integrated terminals, and tasks that run its node with frm. The version is resolved
+//! like 'frm releases use --with-default-fallback' does.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_json::{Map, Value, json};
+
+use crate::Result;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_success};
+use crate::paths::Paths;
+use crate::shell::activation_env_vars;
+use crate::version::Version;
+
+const VSCODE_DIR: &str = ".vscode";
+const SETTINGS_FILE: &str = "settings.json";
+const TASKS_FILE: &str = "tasks.json";
+/// Tasks with labels that start with this are generated by frm and replaced when regenerating
+const TASK_LABEL_PREFIX: &str = "frm: ";
+
+pub struct VscodeOptions<'a> {
+    /// The project directory, `.vscode` is created in it
+    pub dir: &'a Path,
+    /// Print the settings and tasks instead of writing them
+    pub print: bool,
+}
+
+pub fn vscode(paths: &Paths, version: &Version, opts: &VscodeOptions) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    let env = terminal_env(paths, version);
+    let tasks = tasks(version, &env);
+    if opts.print {
+        println!("// {}/{}", VSCODE_DIR, SETTINGS_FILE);
+        println!("{}", pretty(&settings(Map::new(), &env))?);
+        println!();
+        println!("// {}/{}", VSCODE_DIR, TASKS_FILE);
+        println!("{}", pretty(&tasks_document(Map::new(), tasks))?);
+        return Ok(());
+    }
+
+    let vscode_dir = opts.dir.join(VSCODE_DIR);
+    let settings_file = vscode_dir.join(SETTINGS_FILE);
+    let tasks_file = vscode_dir.join(TASKS_FILE);
+    // both files are read before anything is written, so an unreadable one changes nothing
+    let existing_settings = load(&settings_file)?;
+    let existing_tasks = load(&tasks_file)?;
+    fs::create_dir_all(&vscode_dir)?;
+    fs::write(
+        &settings_file,
+        pretty(&settings(existing_settings, &env))? + "\n",
+    )?;
+    fs::write(
+        &tasks_file,
+        pretty(&tasks_document(existing_tasks, tasks))? + "\n",
+    )?;
+
+    print_success(format!(
+        "Wrote {} and {} for RabbitMQ {}",
+        settings_file.display(),
+        tasks_file.display(),
+        version
+    ));
+    print_hint("Run 'frm ide vscode' again to switch the project to another version");
+    Ok(())
+}
+
+/// The variables `frm releases use` sets, with the sbin directory in front of `PATH`
+fn terminal_env(paths: &Paths, version: &Version) -> Map<String, Value> {
+    let separator = if cfg!(windows) { ';' } else { ':' };
+    let mut env = Map::new();
+    env.insert(
+        "PATH".to_string(),
+        Value::String(format!(
+            "{}{}${{env:PATH}}",
+            paths.version_sbin_dir(version).display(),
+            separator
+        )),
+    );
+    for (name, value) in activation_env_vars(paths, version) {
+        env.insert(name.to_string(), Value::String(value));
+    }
+    env
+}
+
+fn settings(mut settings: Map<String, Value>, env: &Map<String, Value>) -> Value {
+    let key = format!("terminal.integrated.env.{}", platform());
+    // variables other than frm's are kept
+    let mut terminal_env = match settings.remove(&key) {
+        Some(Value::Object(existing)) => existing,
+        _ => Map::new(),
+    };
+    terminal_env.extend(env.clone());
+    settings.insert(key, Value::Object(terminal_env));
+    Value::Object(settings)
+}
+
+fn tasks(version: &Version, env: &Map<String, Value>) -> Vec<Value> {
+    let v = version.to_string();
+    let task = |label: &str, args: Vec<&str>, background: bool| {
+        json!({
+            "label": format!("{}{}", TASK_LABEL_PREFIX, label),
+            "detail": format!("RabbitMQ {}", version),
+            "type": "process",
+            "command": "frm",
+            "args": args,
+            "isBackground": background,
+            "options": { "env": env },
+            "problemMatcher": [],
+        })
+    };
+    vec![
+        task("run broker", vec!["fg", "node", "-V", &v], true),
+        task(
+            "start broker in background",
+            vec!["bg", "start", "-V", &v],
+            false,
+        ),
+        task("stop broker", vec!["bg", "stop", "-V", &v], false),
+        task(
+            "broker status",
+            vec!["cli", "rabbitmq-diagnostics", "-V", &v, "--", "status"],
+            false,
+        ),
+    ]
+}
+
+/// The tasks of the document, with frm's tasks replaced
+fn tasks_document(mut document: Map<String, Value>, tasks: Vec<Value>) -> Value {
+    let mut all: Vec<Value> = match document.remove("tasks") {
+        Some(Value::Array(existing)) => existing
+            .into_iter()
+            .filter(|task| {
+                !task["label"]
+                    .as_str()
+                    .is_some_and(|label| label.starts_with(TASK_LABEL_PREFIX))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    all.extend(tasks);
+    document
+        .entry("version")
+        .or_insert_with(|| Value::String("2.0.0".to_string()));
+    document.insert("tasks".to_string(), Value::Array(all));
+    Value::Object(document)
+}
+
+/// An existing VS Code JSON file, which has to be a JSON object without comments
+fn load(file: &Path) -> Result<Map<String, Value>> {
+    let content = match fs::read_to_string(file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Map::new()),
+        Err(e) => {
+            return Err(Error::InvalidConfigFile {
+                file: file.display().to_string(),
+                reason: format!("cannot be read: {}", e),
+            });
+        }
+    };
+    if content.trim().is_empty() {
+        return Ok(Map::new());
+    }
+    let invalid = |reason: String| Error::InvalidConfigFile {
+        file: file.display().to_string(),
+        reason: format!(
+            "{}; merge the output of 'frm ide vscode --print' into it by hand",
+            reason
+        ),
+    };
+    match serde_json::from_str(&content) {
+        Ok(Value::Object(map)) => Ok(map),
+        Ok(_) => Err(invalid("not a JSON object".to_string())),
+        Err(e) => Err(invalid(e.to_string())),
+    }
+}
+
+fn pretty(value: &Value) -> Result<String> {
+    Ok(serde_json::to_string_pretty(value)?)
+}
+
+fn platform() -> &'static str {
+    if cfg!(windows) {
+        "windows"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    }
+}
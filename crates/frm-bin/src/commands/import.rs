@@ -0,0 +1,124 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Adopts existing generic UNIX installations, so that they do not have to be downloaded again.
+
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Result;
+use crate::commands::link::{infer_version, link_dir};
+use crate::common::cli_tools::RABBITMQ_SERVER;
+use crate::download::{copy_default_config, copy_dir_recursive};
+use crate::errors::Error;
+use crate::messages::{Message, print_info, print_success};
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const RABBIT_APP_PREFIX: &str = "rabbit-";
+
+pub struct ImportOptions<'a> {
+    pub name: Option<&'a str>,
+    /// Link the directory instead of copying it
+    pub link: bool,
+}
+
+pub fn run(paths: &Paths, source: &Path, opts: &ImportOptions) -> Result<()> {
+    if !source.join("sbin").join(RABBITMQ_SERVER).is_file() {
+        return Err(Error::InvalidLinkTarget(source.display().to_string()));
+    }
+    let source = source.canonicalize()?;
+
+    let version = match opts.name {
+        Some(name) => name.parse::<Version>()?,
+        None => detect_version(&source)?,
+    };
+
+    if opts.link {
+        link_dir(paths, &source, &version)?;
+        print_success(format!(
+            "Linked RabbitMQ {} to {}",
+            version,
+            source.display()
+        ));
+        return Ok(());
+    }
+
+    if paths.version_installed(&version) || paths.version_linked(&version) {
+        return Err(Error::VersionAlreadyInstalled(version));
+    }
+
+    print_info(format!(
+        "Copying RabbitMQ {} from {}",
+        version,
+        source.display()
+    ));
+    let version_dir = paths.version_dir(&version);
+    if let Err(e) = copy_dir_recursive(&source, &version_dir) {
+        fs::remove_dir_all(&version_dir).ok();
+        return Err(e.into());
+    }
+
+    // Configuration files of the installation are kept as they are
+    if !paths.version_etc_dir(&version).exists() {
+        print_info(Message::CopyingDefaultConfig);
+        copy_default_config(paths, &version)?;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    VersionMetadata::update(paths, &version, |m| {
+        m.installed_at = Some(now);
+        m.imported_from = Some(source.display().to_string());
+        m.signature = None;
+        m.provenance = None;
+    })?;
+
+    print_success(format!("RabbitMQ {} imported", version));
+    print_info(format!(
+        "The original installation at {} was left intact",
+        source.display()
+    ));
+
+    Ok(())
+}
+
+/// Detects the version of an installation from its directory name (`rabbitmq_server-4.1.6`)
+/// or, failing that, from the `rabbit` application in `plugins`
+pub fn detect_version(dir: &Path) -> Result<Version> {
+    if let Ok(version) = infer_version(dir) {
+        return Ok(version);
+    }
+
+    let plugins_dir = dir.join("plugins");
+    if plugins_dir.is_dir() {
+        for entry in fs::read_dir(&plugins_dir)? {
+            let file_name = entry?.file_name();
+            let Some(name) = file_name.to_str() else {
+                continue;
+            };
+            let candidate = name.strip_suffix(".ez").unwrap_or(name);
+            if let Some(version) = candidate
+                .strip_prefix(RABBIT_APP_PREFIX)
+                .and_then(|v| v.parse::<Version>().ok())
+            {
+                return Ok(version);
+            }
+        }
+    }
+
+    Err(Error::LinkNameRequired(
+        dir.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    ))
+}
@@ -0,0 +1,275 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use chrono::{DateTime, Local};
+
+use crate::Result;
+use crate::commands::search::oldest_supported_series;
+use crate::commands::version_table::flavor;
+use crate::common::conf_files;
+use crate::common::erlang::supported_erlang_versions;
+use crate::common::fs_utils::{dir_size, format_size};
+use crate::common::urls::{RABBITMQ_SERVER_REPOSITORY_URL, SERVER_PACKAGES_REPOSITORY_URL};
+use crate::envs;
+use crate::errors::Error;
+use crate::feed_cache::FEED_CACHE_TTL;
+use crate::history;
+use crate::manifest::parse_enabled_plugins;
+use crate::messages::print_warning;
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::releases::{
+    AlphaRelease, GitHubCommit, fetch_alpha_commit, fetch_alpha_releases, fetch_server_releases,
+    parse_version_from_tag,
+};
+use crate::version::{Series, Version};
+
+/// What frm knows about a version, for 'frm releases info'
+#[derive(Debug, Default)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub flavor: &'static str,
+    /// `None` when the version is not installed, the remaining fields are then unset, too
+    pub path: Option<String>,
+    pub linked: bool,
+    pub broken: bool,
+    pub size: Option<u64>,
+    pub installed_at: Option<u64>,
+    pub signature: Option<(bool, u64)>,
+    pub erlang: Option<(&'static str, &'static str)>,
+    /// `None` when the list of releases could not be fetched
+    pub eol: Option<bool>,
+    /// Keys set in rabbitmq.conf and conf.d
+    pub config_keys: usize,
+    /// `None` when there is no enabled_plugins file
+    pub plugins: Option<Vec<String>>,
+    pub data_dir: Option<String>,
+    pub data_dir_size: Option<u64>,
+    pub last_used: Option<u64>,
+}
+
+impl ReleaseInfo {
+    pub fn collect(paths: &Paths, version: &Version, eol: Option<bool>) -> Result<Self> {
+        let mut info = Self {
+            version: version.to_string(),
+            flavor: flavor(version),
+            erlang: supported_erlang_versions(version),
+            eol,
+            ..Self::default()
+        };
+        if !paths.version_installed(version) {
+            return Ok(info);
+        }
+
+        let metadata = VersionMetadata::load(paths, version)?;
+        let version_dir = paths.version_dir(version);
+        let etc_dir = paths.version_etc_dir(version);
+        let data_dir = envs::data_dir(paths, version, None)?;
+
+        info.path = Some(version_dir.display().to_string());
+        info.linked = paths.version_linked(version);
+        info.broken = paths.version_broken(version);
+        info.size = dir_size(&version_dir).ok();
+        info.installed_at = metadata.installed_at;
+        info.signature = metadata
+            .signature
+            .map(|check| (check.verified, check.checked_at));
+        info.config_keys = conf_files::effective_settings(&etc_dir).len();
+        info.plugins = fs::read_to_string(etc_dir.join("enabled_plugins"))
+            .ok()
+            .and_then(|content| parse_enabled_plugins(&content));
+        info.data_dir_size = dir_size(&data_dir).ok();
+        info.data_dir = Some(data_dir.display().to_string());
+        info.last_used = last_used(paths, version)?;
+        Ok(info)
+    }
+}
+
+pub async fn run_release(paths: &Paths, version: &Version) -> Result<()> {
+    let eol = match release_series_eol(paths, version).await {
+        Ok(eol) => Some(eol),
+        Err(e) => {
+            print_warning(format!("Could not list releases on GitHub: {}", e));
+            None
+        }
+    };
+
+    let info = ReleaseInfo::collect(paths, version, eol)?;
+    print!("{}", release_text(&info));
+    Ok(())
+}
+
+/// Uses the same rule as 'frm search': series older than the two most recent ones are EOL
+async fn release_series_eol(paths: &Paths, version: &Version) -> Result<bool> {
+    let client = reqwest::Client::new();
+    let releases = fetch_server_releases(&client, paths, FEED_CACHE_TTL).await?;
+    let versions: Vec<Version> = releases
+        .iter()
+        .filter_map(|r| parse_version_from_tag(&r.tag_name))
+        .collect();
+    Ok(oldest_supported_series(&versions).is_some_and(|oldest| Series::from(version) < oldest))
+}
+
+/// The most recent frm command run against the version, according to the history
+/// (when recorded, see `record_history`), or else the most recent node log write
+fn last_used(paths: &Paths, version: &Version) -> Result<Option<u64>> {
+    let name = version.to_string();
+    let from_history = history::load(paths)?
+        .into_iter()
+        .filter(|entry| entry.version.as_deref() == Some(name.as_str()))
+        .map(|entry| entry.timestamp)
+        .max();
+    Ok(from_history.or_else(|| newest_modification(&paths.version_var_log_dir(version))))
+}
+
+fn newest_modification(dir: &Path) -> Option<u64> {
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+        .filter_map(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|since_epoch| since_epoch.as_secs())
+        .max()
+}
+
+pub fn release_text(info: &ReleaseInfo) -> String {
+    let mut fields = vec![
+        ("Version", info.version.clone()),
+        ("Flavor", info.flavor.to_string()),
+    ];
+    let installed = match &info.path {
+        None => "no",
+        Some(_) if info.broken => "yes, broken",
+        Some(_) if info.linked => "yes, linked",
+        Some(_) => "yes",
+    };
+    fields.push(("Installed", installed.to_string()));
+    if let Some(path) = &info.path {
+        fields.push(("Path", path.clone()));
+        fields.push(("Size", size(info.size)));
+        fields.push(("Installed at", date(info.installed_at)));
+        let signature = match info.signature {
+            Some((verified, checked_at)) => format!(
+                "{} (checked at {})",
+                if verified { "verified" } else { "failed" },
+                date(Some(checked_at))
+            ),
+            None => "unknown".to_string(),
+        };
+        fields.push(("Signature", signature));
+    }
+    fields.push((
+        "Erlang",
+        info.erlang
+            .map(|(min, max)| format!("{} - {}", min, max))
+            .unwrap_or_else(|| "unknown".to_string()),
+    ));
+    fields.push((
+        "EOL",
+        match info.eol {
+            Some(true) => "yes",
+            Some(false) => "no",
+            None => "unknown",
+        }
+        .to_string(),
+    ));
+    if info.path.is_some() {
+        fields.push(("Config keys", info.config_keys.to_string()));
+        let plugins = match &info.plugins {
+            Some(plugins) if !plugins.is_empty() => plugins.join(", "),
+            _ => "none".to_string(),
+        };
+        fields.push(("Plugins", plugins));
+        if let Some(data_dir) = &info.data_dir {
+            fields.push((
+                "Data dir",
+                format!("{} ({})", data_dir, size(info.data_dir_size)),
+            ));
+        }
+        fields.push(("Last used", date(info.last_used)));
+    }
+
+    fields
+        .iter()
+        .map(|(name, value)| format!("{:<13} {}\n", format!("{}:", name), value))
+        .collect()
+}
+
+fn size(bytes: Option<u64>) -> String {
+    bytes.map(format_size).unwrap_or_else(|| "-".to_string())
+}
+
+fn date(seconds: Option<u64>) -> String {
+    seconds
+        .and_then(|ts| DateTime::from_timestamp(ts as i64, 0))
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+pub async fn run_alpha(paths: &Paths, version: &Version) -> Result<()> {
+    if !version.is_distributed_via_server_packages_repository() {
+        return Err(Error::ExpectedAlphaVersion(version.clone()));
+    }
+
+    let client = reqwest::Client::new();
+    let commit = fetch_alpha_commit(&client, version).await?;
+    // the catalog only covers recent alphas, older ones are described by their commit alone
+    let release = fetch_alpha_releases(&client, paths)
+        .await?
+        .into_iter()
+        .find(|r| &r.version == version);
+
+    print!("{}", alpha_text(version, release.as_ref(), &commit));
+    Ok(())
+}
+
+pub fn alpha_text(
+    version: &Version,
+    release: Option<&AlphaRelease>,
+    commit: &GitHubCommit,
+) -> String {
+    let mut fields = vec![("Version", version.to_string())];
+    if let Some(release) = release {
+        fields.push(("Published at", release.published_at.clone()));
+        fields.push((
+            "Release",
+            format!(
+                "{}/releases/tag/{}",
+                SERVER_PACKAGES_REPOSITORY_URL, release.tag
+            ),
+        ));
+    }
+    fields.push(("Commit", commit.sha.clone()));
+    if let Some(author) = &commit.commit.author {
+        fields.push(("Commit date", author.date.clone()));
+        fields.push(("Author", author.name.clone()));
+    }
+    fields.push(("Message", commit.summary().to_string()));
+    fields.push((
+        "Commit URL",
+        format!("{}/commit/{}", RABBITMQ_SERVER_REPOSITORY_URL, commit.sha),
+    ));
+    for number in commit.pull_requests() {
+        fields.push((
+            "Pull request",
+            format!("{}/pull/{}", RABBITMQ_SERVER_REPOSITORY_URL, number),
+        ));
+    }
+
+    fields
+        .iter()
+        .map(|(name, value)| format!("{:<13} {}\n", format!("{}:", name), value))
+        .collect()
+}
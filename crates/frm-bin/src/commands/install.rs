@@ -8,33 +8,84 @@
 
 use std::fs;
 
-use bel7_cli::{print_info, print_success};
+use tracing::instrument;
 
 use crate::Result;
+use crate::commands::dedup::deduplicate_installed;
+use crate::commands::repair::fix_permissions;
+use crate::common::fs_utils::format_size;
+use crate::config::Config;
+use crate::denylist::Denylist;
 use crate::download::{Downloader, copy_default_config};
 use crate::errors::Error;
+use crate::messages::{Message, print_hint, print_info, print_success, print_warning};
 use crate::paths::Paths;
+use crate::profile::{self, Phase};
 use crate::timestamps::Timestamps;
 use crate::version::Version;
 
-pub async fn run_release(paths: &Paths, version: &Version, force: bool) -> Result<()> {
+pub async fn run_release(
+    paths: &Paths,
+    version: &Version,
+    force: bool,
+    allow_denylisted: bool,
+    keep_archive: bool,
+) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedNonAlphaVersion(version.clone()));
     }
-    run(paths, version, force, "releases").await
+    run(
+        paths,
+        version,
+        force,
+        allow_denylisted,
+        keep_archive,
+        "releases",
+    )
+    .await
 }
 
-pub async fn run_alpha(paths: &Paths, version: &Version, force: bool) -> Result<()> {
+pub async fn run_alpha(
+    paths: &Paths,
+    version: &Version,
+    force: bool,
+    allow_denylisted: bool,
+    keep_archive: bool,
+) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedAlphaVersion(version.clone()));
     }
-    run(paths, version, force, "alphas").await
+    run(
+        paths,
+        version,
+        force,
+        allow_denylisted,
+        keep_archive,
+        "alphas",
+    )
+    .await
 }
 
-async fn run(paths: &Paths, version: &Version, force: bool, command_group: &str) -> Result<()> {
+#[instrument(
+    level = "debug",
+    skip_all,
+    fields(version = %version, force, allow_denylisted, keep_archive)
+)]
+async fn run(
+    paths: &Paths,
+    version: &Version,
+    force: bool,
+    allow_denylisted: bool,
+    keep_archive: bool,
+    command_group: &str,
+) -> Result<()> {
+    check_denylist(paths, version, allow_denylisted).await?;
+
     if paths.version_installed(version) {
-        if force {
-            print_info(format!("Removing existing installation of {}", version));
+        if force && paths.version_shared(version) {
+            return Err(Error::VersionIsShared(version.clone()));
+        } else if force {
+            print_info(Message::RemovingExistingInstallation(version));
             fs::remove_dir_all(paths.version_dir(version))?;
         } else {
             return Err(Error::VersionAlreadyInstalled(version.clone()));
@@ -43,25 +94,90 @@ async fn run(paths: &Paths, version: &Version, force: bool, command_group: &str)
 
     paths.ensure_dirs()?;
 
-    print_info(format!("Downloading RabbitMQ {}", version));
-    let downloader = Downloader::new();
-    downloader.download(version, paths).await?;
-
-    print_info("Copying default configuration");
-    copy_default_config(paths, version)?;
+    print_info(Message::Downloading(version));
+    let downloader = Downloader::new().keep_archive(keep_archive);
+    let downloaded = downloader.download(version, paths).await;
+    let _timer = profile::start(Phase::PostInstall);
+    let installed = downloaded.and_then(|provenance| {
+        fix_permissions(paths, version)?;
+        print_info(Message::CopyingDefaultConfig);
+        copy_default_config(paths, version)?;
+        Ok(provenance)
+    });
+    let provenance = match installed {
+        Ok(provenance) => provenance,
+        Err(e) => {
+            remove_partial_installation(paths, version);
+            return Err(e);
+        }
+    };
 
-    print_info("Cleaning up downloaded archive");
-    downloader.cleanup_archive(version, paths)?;
+    if !keep_archive {
+        print_info(Message::CleaningUpArchive);
+        downloader.cleanup_archive(version, paths)?;
+    }
 
     let mut timestamps = Timestamps::load(paths)?;
     timestamps.record(version);
     timestamps.save(paths)?;
+    provenance.record(paths, version)?;
+
+    if Config::load(paths)?.dedup_after_install {
+        let report = deduplicate_installed(paths, false)?;
+        if report.files_linked > 0 {
+            print_info(format!(
+                "Hard-linked {} file(s) shared with other versions, saved {}",
+                report.files_linked,
+                format_size(report.bytes_saved)
+            ));
+        }
+    }
 
     print_success(format!("RabbitMQ {} installed successfully", version));
-    print_info(format!(
+    print_hint(format!(
         "Activate with: eval \"$(frm {} use {})\"",
         command_group, version
     ));
 
     Ok(())
 }
+
+/// Refuses to install a denylisted version, unless `allow_denylisted` is set.
+/// A denylist that cannot be loaded does not block the installation.
+async fn check_denylist(paths: &Paths, version: &Version, allow_denylisted: bool) -> Result<()> {
+    let denylist = match Denylist::fetch(&reqwest::Client::new(), paths).await {
+        Ok(denylist) => denylist,
+        Err(e) => {
+            print_warning(format!("Could not load the denylist: {}", e));
+            return Ok(());
+        }
+    };
+    let Some(denied) = denylist.find(version) else {
+        return Ok(());
+    };
+    if !allow_denylisted {
+        return Err(Error::DeniedRelease {
+            version: Box::new(version.clone()),
+            reason: denied.reason.clone(),
+        });
+    }
+    print_warning(format!(
+        "RabbitMQ {} is denylisted: {}",
+        version, denied.reason
+    ));
+    Ok(())
+}
+
+/// Removes whatever a failed installation left behind, so that the version
+/// does not show up as installed
+fn remove_partial_installation(paths: &Paths, version: &Version) {
+    let version_dir = paths.version_dir(version);
+    if version_dir.exists() {
+        print_info(Message::RemovingPartialInstallation(version));
+        fs::remove_dir_all(version_dir).ok();
+    }
+    let extraction_dir = paths.version_extraction_dir(version);
+    if extraction_dir.exists() {
+        fs::remove_dir_all(extraction_dir).ok();
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::common::prompt::confirm_destructive;
+use crate::errors::Error;
+use crate::keyring;
+use crate::messages::{print_error, print_success};
+use crate::paths::Paths;
+
+pub fn list(paths: &Paths) -> Result<()> {
+    for key in keyring::list(paths)? {
+        let mut notes = Vec::new();
+        if key.built_in {
+            notes.push("built-in");
+        }
+        if !key.downloaded() {
+            notes.push("not downloaded yet");
+        }
+        if notes.is_empty() {
+            println!("{}: {}", key.name, key.source);
+        } else {
+            println!("{}: {} ({})", key.name, key.source, notes.join(", "));
+        }
+    }
+    Ok(())
+}
+
+pub async fn add(paths: &Paths, name: &str, source: &str) -> Result<()> {
+    let client = reqwest::Client::new();
+    keyring::add(&client, paths, name, source).await?;
+    print_success(format!(
+        "Added signing key '{}', signatures made with it are now trusted",
+        name
+    ));
+    Ok(())
+}
+
+pub fn remove(paths: &Paths, name: &str, assume_yes: bool) -> Result<()> {
+    let question = format!(
+        "Remove signing key '{}'? Signatures made with it will no longer verify.",
+        name
+    );
+    if keyring::list(paths)?
+        .iter()
+        .any(|key| key.name == name && !key.built_in)
+        && !confirm_destructive(paths, assume_yes, &question)?
+    {
+        return Ok(());
+    }
+
+    keyring::remove(paths, name)?;
+    print_success(format!("Removed signing key '{}'", name));
+    Ok(())
+}
+
+/// Fetches every key from its source again, continuing past failures
+pub async fn refresh(paths: &Paths) -> Result<()> {
+    let client = reqwest::Client::new();
+    let keys = keyring::list(paths)?;
+    let mut failed = 0;
+    for key in &keys {
+        match keyring::refresh(&client, paths, key).await {
+            Ok(()) => print_success(format!("Refreshed '{}' from {}", key.name, key.source)),
+            Err(e) => {
+                print_error(format!("Could not refresh '{}': {}", key.name, e));
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(Error::ProblemsFound(failed));
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Registers externally built RabbitMQ directories (e.g. built from source)
+//! as versions. A linked version is a symlink under `versions/`,
+//! so every command that works with installed versions works with it, too.
+
+use std::fs;
+use std::path::Path;
+
+use crate::Result;
+use crate::common::fs_utils::{remove_symlink_dir, symlink_dir};
+use crate::errors::Error;
+use crate::messages::{print_hint, print_success};
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const EXTRACTED_DIR_PREFIX: &str = "rabbitmq_server-";
+
+pub fn link(paths: &Paths, target: &Path, name: Option<&str>) -> Result<()> {
+    if !target.join("sbin").join("rabbitmq-server").is_file() {
+        return Err(Error::InvalidLinkTarget(target.display().to_string()));
+    }
+    let target = target.canonicalize()?;
+
+    let version = match name {
+        Some(name) => name.parse::<Version>()?,
+        None => infer_version(&target)?,
+    };
+
+    link_dir(paths, &target, &version)?;
+
+    print_success(format!(
+        "Linked RabbitMQ {} to {}",
+        version,
+        target.display()
+    ));
+    print_hint(format!("Remove the link with 'frm unlink {}'", version));
+
+    Ok(())
+}
+
+pub fn unlink(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_linked(version) {
+        if paths.version_installed(version) {
+            return Err(Error::VersionNotLinked(version.clone()));
+        }
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    remove_symlink_dir(&paths.version_dir(version))?;
+    VersionMetadata::remove(paths, version)?;
+
+    print_success(format!(
+        "Unlinked RabbitMQ {}; the linked directory was left intact",
+        version
+    ));
+
+    Ok(())
+}
+
+/// Links `target` as `version`, refusing to replace an installed or linked version
+pub(crate) fn link_dir(paths: &Paths, target: &Path, version: &Version) -> Result<()> {
+    if paths.version_installed(version) || paths.version_linked(version) {
+        return Err(Error::VersionAlreadyInstalled(version.clone()));
+    }
+
+    fs::create_dir_all(paths.versions_dir())?;
+    symlink_dir(&target.canonicalize()?, &paths.version_dir(version))?;
+    Ok(())
+}
+
+/// Infers a version from a `rabbitmq_server-4.3.0+dev`-style directory name
+pub fn infer_version(target: &Path) -> Result<Version> {
+    let dir_name = target
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+
+    dir_name
+        .strip_prefix(EXTRACTED_DIR_PREFIX)
+        .unwrap_or(dir_name)
+        .parse()
+        .map_err(|_| Error::LinkNameRequired(dir_name.to_string()))
+}
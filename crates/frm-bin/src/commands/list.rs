@@ -6,15 +6,17 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use bel7_cli::{print_info, print_warning};
-
 use crate::Result;
+use crate::commands::status::detect_active_version;
+use crate::commands::version_table::{OutputOptions, print_table, signature_status};
 use crate::config::Config;
+use crate::messages::{print_hint, print_warning};
+use crate::metadata::VersionMetadata;
 use crate::paths::Paths;
 use crate::shell::Shell;
 use crate::version::Version;
 
-pub fn run_releases(paths: &Paths) -> Result<()> {
+pub fn run_releases(paths: &Paths, opts: OutputOptions) -> Result<()> {
     let versions = paths.installed_versions()?;
     let releases: Vec<_> = versions
         .into_iter()
@@ -23,14 +25,14 @@ pub fn run_releases(paths: &Paths) -> Result<()> {
 
     if releases.is_empty() {
         print_warning("No stable RabbitMQ releases installed");
-        print_info("Install a release with: frm releases install <version>");
+        print_hint("Install a release with: frm releases install <version>");
         return Ok(());
     }
 
-    print_versions(paths, &releases)
+    print_versions(paths, &releases, opts)
 }
 
-pub fn run_alphas(paths: &Paths) -> Result<()> {
+pub fn run_alphas(paths: &Paths, opts: OutputOptions) -> Result<()> {
     let versions = paths.installed_versions()?;
     let alphas: Vec<_> = versions
         .into_iter()
@@ -39,24 +41,49 @@ pub fn run_alphas(paths: &Paths) -> Result<()> {
 
     if alphas.is_empty() {
         print_warning("No alpha RabbitMQ releases installed");
-        print_info("Install an alpha with: frm alphas install latest");
+        print_hint("Install an alpha with: frm alphas install latest");
         return Ok(());
     }
 
-    print_versions(paths, &alphas)
+    print_versions(paths, &alphas, opts)
 }
 
-fn print_versions(paths: &Paths, versions: &[Version]) -> Result<()> {
+fn print_versions(paths: &Paths, versions: &[Version], opts: OutputOptions) -> Result<()> {
     let config = Config::load(paths)?;
     let default_version = config.default_version.as_ref();
 
+    if !opts.plain {
+        let active = detect_active_version(paths);
+        return print_table(paths, versions, active.as_ref(), default_version, opts);
+    }
+
     for version in versions {
         let marker = if Some(version) == default_version {
             "[*]"
         } else {
             "[ ]"
         };
-        println!("{} {}", marker, version);
+        let mut notes = Vec::new();
+        let metadata = VersionMetadata::load(paths, version)?;
+        if metadata.pinned {
+            notes.push("pinned");
+        }
+        if paths.version_linked(version) {
+            notes.push("linked");
+        }
+        if paths.version_broken(version) {
+            notes.push("broken");
+        }
+        let signature = format!("signature {}", signature_status(&metadata));
+        if opts.verbose {
+            notes.push(&signature);
+        }
+
+        if notes.is_empty() {
+            println!("{} {}", marker, version);
+        } else {
+            println!("{} {} ({})", marker, version, notes.join(", "));
+        }
     }
 
     Ok(())
@@ -6,13 +6,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::char::REPLACEMENT_CHARACTER;
 use std::fs::{self, File};
-use std::io::{self, BufRead, BufReader};
-use std::path::PathBuf;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
 use crate::Result;
 use crate::common::cli_tools::LOG_FILE_PREFIX;
+use crate::common::conf_files;
+use crate::common::env_conf::{self, ENV_CONF_FILE_NAME};
+use crate::common::fs_utils::file_id;
+use crate::config::Config;
 use crate::errors::Error;
+use crate::messages::print_warning_to_stderr;
 use crate::paths::Paths;
 use crate::version::Version;
 
@@ -30,18 +38,41 @@ pub fn path_alpha(paths: &Paths, version: &Version) -> Result<()> {
     path(paths, version)
 }
 
-pub fn tail_release(paths: &Paths, version: &Version, lines: usize) -> Result<()> {
+/// Logs are read backwards, and copied, in chunks of this size
+const TAIL_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How often `tail --follow` checks for new lines and rotation
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How much of the end of a log file `tail` prints
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailAmount {
+    Lines(usize),
+    Bytes(u64),
+}
+
+pub fn tail_release(
+    paths: &Paths,
+    version: &Version,
+    amount: TailAmount,
+    follow: bool,
+) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedNonAlphaVersion(version.clone()));
     }
-    tail(paths, version, lines)
+    tail(paths, version, amount, follow)
 }
 
-pub fn tail_alpha(paths: &Paths, version: &Version, lines: usize) -> Result<()> {
+pub fn tail_alpha(
+    paths: &Paths,
+    version: &Version,
+    amount: TailAmount,
+    follow: bool,
+) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedAlphaVersion(version.clone()));
     }
-    tail(paths, version, lines)
+    tail(paths, version, amount, follow)
 }
 
 fn path(paths: &Paths, version: &Version) -> Result<()> {
@@ -55,46 +86,298 @@ fn path(paths: &Paths, version: &Version) -> Result<()> {
     Ok(())
 }
 
-fn tail(paths: &Paths, version: &Version, lines: usize) -> Result<()> {
+fn tail(paths: &Paths, version: &Version, amount: TailAmount, follow: bool) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
 
     let log_path = find_log_file(paths, version)?;
-    let file = File::open(&log_path)?;
-    let reader = BufReader::new(file);
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let followed = tail_file(&log_path, amount, &mut out).and_then(|end| {
+        if !follow {
+            return Ok(());
+        }
+        let mut follower = Follower::new(&log_path, end);
+        loop {
+            follower.poll(&mut out)?;
+            out.flush()?;
+            thread::sleep(FOLLOW_POLL_INTERVAL);
+        }
+    });
+    match followed {
+        // e.g. piped to 'head'
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Writes the end of a file to `out` without reading the rest of it. Lines are written
+/// with invalid UTF-8 replaced by U+FFFD, bytes are written as they are.
+///
+/// Returns the offset the output ends at, where following the file continues.
+pub fn tail_file(path: &Path, amount: TailAmount, out: &mut impl Write) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
 
-    let all_lines: Vec<String> = reader.lines().collect::<io::Result<Vec<_>>>()?;
-    let start = all_lines.len().saturating_sub(lines);
+    let start = match amount {
+        TailAmount::Bytes(bytes) => len.saturating_sub(bytes),
+        TailAmount::Lines(lines) => last_lines_offset(&mut file, len, lines)?,
+    };
+    file.seek(SeekFrom::Start(start))?;
+    let mut rest = file.take(len - start);
 
-    for line in &all_lines[start..] {
-        println!("{}", line);
+    match amount {
+        TailAmount::Bytes(_) => io::copy(&mut rest, out).map(drop)?,
+        TailAmount::Lines(_) => copy_lossy(&mut rest, out)?,
     }
+    Ok(len)
+}
 
-    Ok(())
+/// Follows a log file like `tail -F`: the file is reopened when it is rotated, that is,
+/// renamed and replaced with a new one, and read from the start again when it is truncated.
+/// Rotation is detected by comparing inodes, where they are not available only truncation
+/// is detected.
+pub struct Follower {
+    path: PathBuf,
+    file: Option<File>,
+    id: Option<(u64, u64)>,
+    position: u64,
+}
+
+impl Follower {
+    /// Continues at `position` of the file at `path`, e.g. where [`tail_file`] stopped
+    pub fn new(path: &Path, position: u64) -> Self {
+        let file = File::open(path).ok();
+        let id = file
+            .as_ref()
+            .and_then(|f| f.metadata().ok())
+            .and_then(|m| file_id(&m));
+        Self {
+            path: path.to_path_buf(),
+            file,
+            id,
+            position,
+        }
+    }
+
+    /// Writes whatever was appended since the last poll
+    pub fn poll(&mut self, out: &mut impl Write) -> io::Result<()> {
+        if let Some(ref mut file) = self.file {
+            let len = file.metadata()?.len();
+            if len < self.position {
+                print_warning_to_stderr(format!("{} was truncated", self.path.display()));
+                self.position = 0;
+            }
+            self.position += copy_from(file, self.position, out)?;
+        }
+
+        // the path refers to a new file after a rotation, or to no file for a moment
+        let current = fs::metadata(&self.path).ok();
+        let rotated = match (&self.file, &current) {
+            (Some(_), Some(metadata)) => self.id.is_some() && file_id(metadata) != self.id,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        if rotated {
+            self.file = None;
+        }
+        if self.file.is_none()
+            && let Ok(file) = File::open(&self.path)
+        {
+            if rotated {
+                print_warning_to_stderr(format!(
+                    "{} was rotated, following the new file",
+                    self.path.display()
+                ));
+            }
+            self.id = file.metadata().ok().and_then(|m| file_id(&m));
+            self.position = copy_from(&file, 0, out)?;
+            self.file = Some(file);
+        }
+        Ok(())
+    }
+}
+
+/// Copies a file from `offset` to its current end, returns how many bytes were copied
+fn copy_from(mut file: &File, offset: u64, out: &mut impl Write) -> io::Result<u64> {
+    let len = file.metadata()?.len();
+    if len <= offset {
+        return Ok(0);
+    }
+    file.seek(SeekFrom::Start(offset))?;
+    copy_lossy(&mut file.take(len - offset), out)?;
+    Ok(len - offset)
+}
+
+/// The offset at which the last `lines` lines of a file start, found by reading it
+/// backwards in chunks
+fn last_lines_offset(file: &mut File, len: u64, lines: usize) -> io::Result<u64> {
+    if lines == 0 {
+        return Ok(len);
+    }
+
+    let mut buf = vec![0; TAIL_CHUNK_SIZE];
+    let mut pos = len;
+    let mut newlines = 0;
+    while pos > 0 {
+        let size = TAIL_CHUNK_SIZE.min(pos as usize);
+        pos -= size as u64;
+        file.seek(SeekFrom::Start(pos))?;
+        let chunk = &mut buf[..size];
+        file.read_exact(chunk)?;
+
+        for (i, _) in chunk.iter().enumerate().rev().filter(|(_, b)| **b == b'\n') {
+            let offset = pos + i as u64 + 1;
+            // the newline that ends the last line does not start another one
+            if offset == len {
+                continue;
+            }
+            newlines += 1;
+            if newlines == lines {
+                return Ok(offset);
+            }
+        }
+    }
+    Ok(0)
+}
+
+/// Copies `reader` to `out` in chunks, replacing invalid UTF-8 with U+FFFD. A character
+/// split between two chunks is kept intact.
+fn copy_lossy(reader: &mut impl Read, out: &mut impl Write) -> io::Result<()> {
+    let mut buf = vec![0; TAIL_CHUNK_SIZE];
+    // the start of a character that continues in the next chunk, at most 3 bytes
+    let mut carried = 0;
+    loop {
+        let n = reader.read(&mut buf[carried..])?;
+        let end = carried + n;
+        let at_eof = n == 0;
+
+        let mut consumed = 0;
+        for chunk in buf[..end].utf8_chunks() {
+            out.write_all(chunk.valid().as_bytes())?;
+            consumed += chunk.valid().len();
+
+            let invalid = chunk.invalid();
+            if invalid.is_empty() {
+                continue;
+            }
+            if !at_eof && consumed + invalid.len() == end && invalid.len() < 4 {
+                break;
+            }
+            write!(out, "{}", REPLACEMENT_CHARACTER)?;
+            consumed += invalid.len();
+        }
+
+        if at_eof {
+            return Ok(());
+        }
+        buf.copy_within(consumed..end, 0);
+        carried = end - consumed;
+    }
 }
 
 pub fn find_log_file(paths: &Paths, version: &Version) -> Result<PathBuf> {
-    let log_dir = paths.version_var_log_dir(version);
+    LogLocation::resolve(paths, version, None)?.find()
+}
 
-    if !log_dir.exists() {
-        return Err(Error::FileNotFound(format!(
-            "log directory: {}",
-            log_dir.display()
-        )));
+/// Where a node logs to according to its configuration: `log.dir` and `log.file`
+/// in rabbitmq.conf (and conf.d), `NODENAME` and `LOG_BASE` in rabbitmq-env.conf,
+/// and the node name template in frm's config.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLocation {
+    pub dir: PathBuf,
+    /// The log file the configuration points at, if it is known
+    pub file: Option<PathBuf>,
+    /// Set when `log.file = false`
+    pub file_logging_disabled: bool,
+}
+
+impl LogLocation {
+    pub fn resolve(paths: &Paths, version: &Version, env: Option<&str>) -> Result<Self> {
+        let env_conf = env_conf::load(&paths.version_etc_dir(version).join(ENV_CONF_FILE_NAME));
+        let (etc_dir, default_dir) = match env {
+            Some(name) => (
+                paths.version_env_etc_dir(version, name),
+                Some(paths.version_env_log_dir(version, name)),
+            ),
+            None => (paths.version_etc_dir(version), None),
+        };
+        let conf = conf_files::effective_settings(&etc_dir);
+
+        // frm sets RABBITMQ_LOG_BASE for environments, which takes precedence over rabbitmq-env.conf
+        let dir = conf
+            .get("log.dir")
+            .map(PathBuf::from)
+            .or(default_dir)
+            .or_else(|| env_conf.get("LOG_BASE").map(PathBuf::from))
+            .unwrap_or_else(|| paths.version_var_log_dir(version));
+
+        // the same goes for RABBITMQ_NODENAME and the node name template
+        let node_name = Config::load(paths)?
+            .node_name(version, env)
+            .or_else(|| env_conf.get("NODENAME").cloned());
+
+        let (file, file_logging_disabled) = match conf.get("log.file").map(String::as_str) {
+            Some("false") => (None, true),
+            Some(file) => (Some(dir.join(file)), false),
+            None => (
+                node_name.map(|name| dir.join(format!("{}.log", name))),
+                false,
+            ),
+        };
+
+        Ok(Self {
+            dir,
+            file,
+            file_logging_disabled,
+        })
     }
 
-    for entry in fs::read_dir(&log_dir)? {
+    /// The configured log file, or, when the configuration does not determine it, the most recently
+    /// modified node log file in the log directory. A configured file that does not exist is not
+    /// substituted with another one, which could belong to a different node.
+    pub fn find(&self) -> Result<PathBuf> {
+        if self.file_logging_disabled {
+            return Err(Error::Config(
+                "logging to a file is disabled with log.file = false".into(),
+            ));
+        }
+        if let Some(ref file) = self.file {
+            return if file.is_file() {
+                Ok(file.clone())
+            } else {
+                Err(Error::FileNotFound(file.display().to_string()))
+            };
+        }
+        if !self.dir.exists() {
+            return Err(Error::FileNotFound(format!(
+                "log directory: {}",
+                self.dir.display()
+            )));
+        }
+
+        glob_log_file(&self.dir)?.ok_or_else(|| {
+            Error::FileNotFound(format!("no log file found in {}", self.dir.display()))
+        })
+    }
+}
+
+/// Node log files are named after the node, e.g. `rabbit@hostname.log`.
+/// Upgrade logs (`rabbit@hostname_upgrade.log`) are skipped.
+fn glob_log_file(log_dir: &Path) -> Result<Option<PathBuf>> {
+    let mut candidates = Vec::new();
+    for entry in fs::read_dir(log_dir)? {
         let entry = entry?;
         let file_name = entry.file_name();
         let name = file_name.to_string_lossy();
-        if name.starts_with(LOG_FILE_PREFIX) && name.ends_with(".log") {
-            return Ok(entry.path());
+        if name.contains('@') && name.ends_with(".log") && !name.ends_with("_upgrade.log") {
+            let modified = entry.metadata().and_then(|m| m.modified()).ok();
+            let preferred = name.starts_with(LOG_FILE_PREFIX);
+            candidates.push((modified, preferred, entry.path()));
         }
     }
-
-    Err(Error::FileNotFound(format!(
-        "no log file found in {}",
-        log_dir.display()
-    )))
+    // the most recently modified file, the default node's if in doubt
+    candidates.sort();
+    Ok(candidates.pop().map(|(_, _, path)| path))
 }
@@ -0,0 +1,345 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+use std::fs::{self, File};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, ExitStatus, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+use tracing::debug;
+
+use crate::Result;
+use crate::common::cli_tools::{RABBITMQ_SERVER, RABBITMQCTL};
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::errors::Error;
+use crate::matrix::ThrowawayNode;
+use crate::messages::{print_error, print_hint, print_info, print_success, print_warning};
+use crate::paths::Paths;
+use crate::version::Version;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const OUTPUT_FILE: &str = "output.log";
+
+pub struct MatrixOptions<'a> {
+    pub versions: Vec<Version>,
+    pub command: &'a [String],
+    /// How many versions to test at a time
+    pub jobs: usize,
+    pub plugins: Vec<String>,
+    pub boot_timeout: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Passed,
+    Failed(Option<i32>),
+    NodeFailed(String),
+}
+
+struct VersionResult {
+    version: Version,
+    outcome: Outcome,
+    elapsed: Duration,
+    /// Where the command's output went, `None` when it was printed
+    output: Option<PathBuf>,
+}
+
+#[derive(Tabled)]
+struct ResultRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Result")]
+    result: String,
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Output")]
+    output: String,
+}
+
+pub fn run(paths: &Paths, opts: &MatrixOptions) -> Result<()> {
+    let mut versions: Vec<Version> = Vec::new();
+    for version in &opts.versions {
+        if !versions.contains(version) {
+            versions.push(version.clone());
+        }
+    }
+    for version in &versions {
+        if !paths.version_installed(version) {
+            return Err(Error::VersionNotInstalled(version.clone()));
+        }
+    }
+
+    let run_dir = env::temp_dir().join(format!("frm-matrix-{}", process::id()));
+    if run_dir.exists() {
+        fs::remove_dir_all(&run_dir)?;
+    }
+    fs::create_dir_all(&run_dir)?;
+
+    let jobs = opts.jobs.clamp(1, versions.len().max(1));
+    // with one version at a time, the output of the command is printed as it runs
+    let live_output = jobs == 1;
+    print_info(format!(
+        "Running '{}' against {} version(s), {} at a time",
+        opts.command.join(" "),
+        versions.len(),
+        jobs
+    ));
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<VersionResult>> = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                while let Some(version) = versions.get(next.fetch_add(1, Ordering::SeqCst)) {
+                    let result = run_version(paths, version, &run_dir, opts, live_output);
+                    report(&result);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.version.cmp(&b.version));
+    println!();
+    println!(
+        "{}",
+        Table::new(results.iter().map(row)).with(Style::rounded())
+    );
+
+    let failed = results
+        .iter()
+        .filter(|r| r.outcome != Outcome::Passed)
+        .count();
+    if failed == 0 {
+        fs::remove_dir_all(&run_dir).ok();
+        print_success(format!("All {} version(s) passed", results.len()));
+        return Ok(());
+    }
+    print_hint(format!(
+        "Command output and node logs are kept in {}",
+        run_dir.display()
+    ));
+    Err(Error::MatrixRunFailed {
+        failed,
+        total: results.len(),
+    })
+}
+
+fn run_version(
+    paths: &Paths,
+    version: &Version,
+    run_dir: &Path,
+    opts: &MatrixOptions,
+    live_output: bool,
+) -> VersionResult {
+    let started = Instant::now();
+    let mut result = VersionResult {
+        version: version.clone(),
+        outcome: Outcome::Passed,
+        elapsed: Duration::ZERO,
+        output: None,
+    };
+
+    let node = match ThrowawayNode::create(run_dir, version, &opts.plugins) {
+        Ok(node) => node,
+        Err(e) => {
+            result.outcome = Outcome::NodeFailed(e.to_string());
+            result.elapsed = started.elapsed();
+            return result;
+        }
+    };
+    if let Err(e) = start_node(paths, &node, opts.boot_timeout) {
+        // the node may have started but not booted in time
+        stop_node(paths, &node);
+        result.outcome = Outcome::NodeFailed(e.to_string());
+        result.elapsed = started.elapsed();
+        return result;
+    }
+    print_info(format!(
+        "RabbitMQ {} is up on port {}, running the command",
+        version, node.amqp_port
+    ));
+
+    let output = node.dir.join(OUTPUT_FILE);
+    result.outcome = match run_command(
+        paths,
+        &node,
+        opts.command,
+        (!live_output).then_some(&output),
+    ) {
+        Ok(status) if status.success() => Outcome::Passed,
+        Ok(status) => Outcome::Failed(status.code()),
+        Err(e) => Outcome::NodeFailed(e.to_string()),
+    };
+    if !live_output {
+        result.output = Some(output);
+    }
+
+    stop_node(paths, &node);
+    result.elapsed = started.elapsed();
+    result
+}
+
+fn start_node(paths: &Paths, node: &ThrowawayNode, boot_timeout: Duration) -> Result<()> {
+    let version = &node.version;
+    let server_path = paths.version_sbin_dir(version).join(RABBITMQ_SERVER);
+    debug!(path = %server_path.display(), node = node.node_name, "starting throwaway node");
+    let status = Command::new(&server_path)
+        .arg("-detached")
+        .env(RABBITMQ_HOME, paths.version_dir(version))
+        .envs(node.server_env_vars())
+        .envs(cookie::env_vars(paths, version)?)
+        .status()
+        .map_err(|e| {
+            Error::CommandFailed(format!(
+                "failed to execute {}: {}",
+                server_path.display(),
+                e
+            ))
+        })?;
+    if !status.success() {
+        return Err(Error::CommandFailed(format!(
+            "rabbitmq-server -detached exited with code {}",
+            status.code().unwrap_or(-1)
+        )));
+    }
+
+    // `await_startup` fails until the node's runtime is reachable, so it is retried
+    let started = Instant::now();
+    loop {
+        match ctl(paths, node, &["await_startup", "--timeout", "10"]) {
+            Ok(()) => return Ok(()),
+            Err(e) if started.elapsed() >= boot_timeout => {
+                return Err(Error::CommandFailed(format!(
+                    "the node did not boot within {} seconds ({}), see the logs in {}",
+                    boot_timeout.as_secs(),
+                    e,
+                    node.log_dir().display()
+                )));
+            }
+            Err(e) => {
+                debug!(error = %e, "node is not up yet");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn stop_node(paths: &Paths, node: &ThrowawayNode) {
+    if let Err(e) = ctl(paths, node, &["shutdown"]) {
+        print_warning(format!(
+            "could not stop node {} of RabbitMQ {}: {}",
+            node.node_name, node.version, e
+        ));
+    }
+}
+
+fn ctl(paths: &Paths, node: &ThrowawayNode, args: &[&str]) -> Result<()> {
+    let ctl_path = paths.version_sbin_dir(&node.version).join(RABBITMQCTL);
+    let output = Command::new(&ctl_path)
+        .args(args)
+        .env(RABBITMQ_HOME, paths.version_dir(&node.version))
+        .envs(node.server_env_vars())
+        .envs(cookie::env_vars(paths, &node.version)?)
+        .output()
+        .map_err(|e| {
+            Error::CommandFailed(format!("failed to execute {}: {}", ctl_path.display(), e))
+        })?;
+    if !output.status.success() {
+        return Err(Error::CommandFailed(format!(
+            "rabbitmqctl {} exited with code {}",
+            args.first().unwrap_or(&""),
+            output.status.code().unwrap_or(-1)
+        )));
+    }
+    Ok(())
+}
+
+/// Runs the command with the node's connection details in its environment and the
+/// version's sbin directory first in `PATH`
+fn run_command(
+    paths: &Paths,
+    node: &ThrowawayNode,
+    command: &[String],
+    output: Option<&PathBuf>,
+) -> Result<ExitStatus> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| Error::CommandFailed("no command to run".to_string()))?;
+
+    let sbin_dir = paths.version_sbin_dir(&node.version);
+    let path_var = env::var_os("PATH").unwrap_or_default();
+    let path = env::join_paths(iter::once(sbin_dir).chain(env::split_paths(&path_var)))
+        .map_err(|e| Error::CommandFailed(e.to_string()))?;
+
+    let mut cmd = Command::new(program);
+    cmd.args(args)
+        .env("PATH", path)
+        .envs(node.client_env_vars())
+        .envs(cookie::env_vars(paths, &node.version)?);
+    if let Some(file) = output {
+        let stdout = File::create(file)?;
+        let stderr = stdout.try_clone()?;
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::from(stdout))
+            .stderr(Stdio::from(stderr));
+    }
+    debug!(program, ?args, version = %node.version, "running matrix command");
+    cmd.status()
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", program, e)))
+}
+
+fn report(result: &VersionResult) {
+    let seconds = result.elapsed.as_secs_f64();
+    match &result.outcome {
+        Outcome::Passed => print_success(format!(
+            "RabbitMQ {}: passed in {:.1} s",
+            result.version, seconds
+        )),
+        outcome => print_error(format!(
+            "RabbitMQ {}: {} after {:.1} s",
+            result.version,
+            describe(outcome),
+            seconds
+        )),
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Passed => "passed".to_string(),
+        Outcome::Failed(Some(code)) => format!("failed (exit code {})", code),
+        Outcome::Failed(None) => "failed (killed by a signal)".to_string(),
+        Outcome::NodeFailed(reason) => format!("node failed: {}", reason),
+    }
+}
+
+fn row(result: &VersionResult) -> ResultRow {
+    ResultRow {
+        version: result.version.to_string(),
+        result: match &result.outcome {
+            Outcome::NodeFailed(_) => "node failed".to_string(),
+            outcome => describe(outcome),
+        },
+        time: format!("{:.1} s", result.elapsed.as_secs_f64()),
+        output: result
+            .output
+            .as_ref()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|| "-".to_string()),
+    }
+}
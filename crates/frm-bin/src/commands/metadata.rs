@@ -0,0 +1,172 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::process::Command;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::Result;
+use crate::common::cli_tools::RABBITMQCTL;
+use crate::common::fs_utils::format_size;
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info};
+use crate::metadata_store::{self, KHEPRI_DB_FEATURE_FLAG, NodeStore};
+use crate::node_state;
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum MetadataFormat {
+    /// A summary per node
+    #[default]
+    Text,
+    /// An array with an object per node
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+struct NodeReport {
+    #[serde(flatten)]
+    store: NodeStore,
+    running: bool,
+    /// The state of the khepri_db feature flag reported by the running node
+    live_khepri_db_state: Option<String>,
+}
+
+pub fn status(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    format: MetadataFormat,
+) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+    let mnesia_base = envs::data_dir(paths, version, env)?;
+
+    let running = node_state::running_node_names(paths, version)?;
+    let reports: Vec<NodeReport> = metadata_store::inspect(&mnesia_base)?
+        .into_iter()
+        .map(|store| {
+            let is_running = running.contains(&store.node);
+            let live_khepri_db_state = if is_running {
+                live_khepri_db_state(paths, version, &store.node)
+            } else {
+                None
+            };
+            NodeReport {
+                store,
+                running: is_running,
+                live_khepri_db_state,
+            }
+        })
+        .collect();
+
+    if format == MetadataFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+        return Ok(());
+    }
+
+    if reports.is_empty() {
+        print_info(format!(
+            "No node data directories in {}",
+            mnesia_base.display()
+        ));
+        print_hint(format!(
+            "A node creates one when it starts: frm bg start -V {}",
+            version
+        ));
+        return Ok(());
+    }
+
+    for (i, report) in reports.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!("{}", format_report(report));
+    }
+    Ok(())
+}
+
+fn format_report(report: &NodeReport) -> String {
+    let store = &report.store;
+    let mut out = String::new();
+    out.push_str(&format!("Node:            {}\n", store.node));
+    out.push_str(&format!("Data directory:  {}\n", store.data_dir.display()));
+    out.push_str(&format!("Metadata store:  {}\n", store.store));
+
+    let flags = match &store.enabled_feature_flags {
+        Some(flags) => format!(
+            "{} enabled, {} {}",
+            flags.len(),
+            KHEPRI_DB_FEATURE_FLAG,
+            if store.khepri_db_enabled() == Some(true) {
+                "enabled"
+            } else {
+                "disabled"
+            }
+        ),
+        None => "no feature flags file".to_string(),
+    };
+    out.push_str(&format!("Feature flags:   {}\n", flags));
+    if let Some(state) = &report.live_khepri_db_state {
+        out.push_str(&format!(
+            "Live state:      {} is {} on the running node\n",
+            KHEPRI_DB_FEATURE_FLAG, state
+        ));
+    }
+    out.push_str(&format!(
+        "Mnesia files:    {}\n",
+        format_size(store.mnesia_bytes)
+    ));
+    out.push_str(&format!(
+        "Khepri store:    {}\n",
+        format_size(store.khepri_bytes)
+    ));
+    out.push_str(&format!(
+        "Running:         {}\n",
+        if report.running { "yes" } else { "no" }
+    ));
+    out
+}
+
+/// Asks the running node for the state of the khepri_db feature flag, which can be
+/// ahead of the feature flags file while a migration is in progress
+fn live_khepri_db_state(paths: &Paths, version: &Version, node: &str) -> Option<String> {
+    let ctl_path = paths.version_sbin_dir(version).join(RABBITMQCTL);
+    let output = Command::new(ctl_path)
+        .args([
+            "list_feature_flags",
+            "-n",
+            node,
+            "--formatter",
+            "json",
+            "--silent",
+            "name",
+            "state",
+        ])
+        .envs(cookie::env_vars(paths, version).ok()?)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let flags: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).ok()?;
+    flags
+        .iter()
+        .find(|flag| flag["name"] == KHEPRI_DB_FEATURE_FLAG)
+        .and_then(|flag| flag["state"].as_str())
+        .map(str::to_string)
+}
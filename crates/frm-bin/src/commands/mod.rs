@@ -6,65 +6,255 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+mod alias_command;
+mod auto_install;
+mod bg_logs;
+mod bg_pause;
 mod bg_start;
 mod bg_stop;
+mod bisect;
 mod check_signature;
 mod clean;
 mod cli_cmd;
+pub mod complete;
 mod completions;
 mod conf;
+mod conf_backups;
+mod conf_convert;
+mod conf_schema;
+mod cookie;
 mod cp_etc_file;
+mod data_dir;
+mod deactivate;
+mod dedup;
 mod default;
+mod diff;
+mod doctor;
 mod env;
+mod envs;
+mod erlang;
+mod exit_codes;
+mod export;
+mod external;
 mod fg_node;
+mod health;
+mod history;
+mod ide;
+mod import;
+mod info;
 mod install;
+mod keys;
+mod link;
 mod list;
 pub mod logs;
+mod matrix;
+mod metadata;
+mod migrate_data;
 mod path;
+mod paths;
+mod perf;
+mod pin;
+pub mod ping;
+mod ports;
+mod protocols;
+mod provenance;
 mod prune;
 mod reinstall;
+mod remote_shell;
+mod repair;
+mod run_script;
+mod search;
 mod show;
+mod smoke;
+mod state;
 mod status;
+mod supervise;
+mod sync;
 mod tanzu_install;
+mod toolbox;
+mod trash;
+pub mod ui;
 mod uninstall;
+mod upgrade_path;
 mod use_cmd;
+mod verify;
+mod version_table;
+mod watch;
+mod workspace;
 
+pub use alias_command::list as alias_list;
+pub use auto_install::{AutoInstall, ensure_installed};
+pub use bg_logs::run as bg_logs;
+pub use bg_pause::pause as bg_pause;
+pub use bg_pause::resume as bg_resume;
 pub use bg_start::run as bg_start;
+pub use bg_stop::StopOptions;
 pub use bg_stop::run as bg_stop;
+pub use bisect::BisectOptions;
+pub use bisect::run as bisect_alphas;
 pub use check_signature::run as check_signature;
+pub use check_signature::run_all as check_signature_all;
+pub use clean::CleanOptions;
+pub use clean::parse_datetime;
 pub use clean::run as clean_alphas;
 pub use cli_cmd::run as cli;
+pub use complete::run as complete;
 pub use completions::run as completions;
+pub use conf::KeyFormat;
+pub use conf::edit as conf_edit;
 pub use conf::get_key as conf_get_key;
 pub use conf::set_key as conf_set_key;
+pub use conf_backups::history as conf_history;
+pub use conf_backups::revert as conf_revert;
+pub use conf_convert::run as conf_convert;
+pub use conf_schema::dump as conf_schema_dump;
+pub use cookie::set as cookie_set;
+pub use cookie::show as cookie_show;
+pub use cookie::sync as cookie_sync;
 pub use cp_etc_file::EtcFile;
 pub use cp_etc_file::run_alpha as cp_etc_file_alpha;
 pub use cp_etc_file::run_release as cp_etc_file_release;
+pub use data_dir::reset as data_dir_reset;
+pub use data_dir::set as data_dir_set;
+pub use data_dir::show as data_dir_show;
+pub use deactivate::run as deactivate;
+pub use dedup::du;
+pub use dedup::run as dedup;
 pub use default::run as default;
+pub use diff::format_commit;
+pub use diff::run_alphas as diff_alphas;
+pub use doctor::PackageManager;
+pub use doctor::Problem;
+pub use doctor::diagnose;
+pub use doctor::run as doctor;
+pub use doctor::shadowing_installations;
 pub use env::run as env;
+pub use envs::create as envs_create;
+pub use envs::delete as envs_delete;
+pub use envs::list as envs_list;
+pub use erlang::report as erlang_report;
+pub use exit_codes::run as exit_codes;
+pub use export::DEFAULT_BASE_IMAGE;
+pub use export::DockerExportOptions;
+pub use export::docker as export_docker;
+pub use export::dockerfile;
+pub use external::run as external;
 pub use fg_node::run as fg_node;
+pub use fg_node::run_fresh as fg_node_fresh;
+pub use health::HealthFormat;
+pub use health::HealthOptions;
+pub use health::run as health;
+pub use history::run as history;
+pub use ide::VscodeOptions;
+pub use ide::vscode as ide_vscode;
+pub use import::ImportOptions;
+pub use import::detect_version as detect_imported_version;
+pub use import::run as import;
+pub use info::ReleaseInfo;
+pub use info::alpha_text;
+pub use info::release_text;
+pub use info::run_alpha as info_alpha;
+pub use info::run_release as info_release;
 pub use install::run_alpha as install_alpha;
 pub use install::run_release as install_release;
+pub use keys::add as keys_add;
+pub use keys::list as keys_list;
+pub use keys::refresh as keys_refresh;
+pub use keys::remove as keys_remove;
+pub use link::infer_version as infer_linked_version;
+pub use link::link;
+pub use link::unlink;
 pub use list::completions_alphas;
 pub use list::completions_releases;
 pub use list::run_alphas as list_alphas;
 pub use list::run_releases as list_releases;
+pub use logs::TailAmount;
 pub use logs::path_alpha as logs_path_alpha;
 pub use logs::path_release as logs_path_release;
 pub use logs::tail_alpha as logs_tail_alpha;
 pub use logs::tail_release as logs_tail_release;
+pub use matrix::MatrixOptions;
+pub use matrix::run as matrix_run;
+pub use metadata::MetadataFormat;
+pub use metadata::status as metadata_status;
+pub use migrate_data::MigrateOptions;
+pub use migrate_data::definitions_file as migration_definitions_file;
+pub use migrate_data::run as migrate_data;
+pub use migrate_data::shovel_definition;
+pub use migrate_data::vhost_uri;
 pub use path::run_alpha as path_alpha;
 pub use path::run_release as path_release;
+pub use path::{PathKind, PathOptions};
+pub use paths::PathsFormat;
+pub use paths::layout as paths_layout;
+pub use paths::show as paths_show;
+pub use perf::PerfRunOptions;
+pub use perf::perf_test_args;
+pub use perf::run as perf_run;
+pub use pin::pin_alpha;
+pub use pin::pin_release;
+pub use pin::unpin_alpha;
+pub use pin::unpin_release;
+pub use ping::PingOptions;
+pub use ping::run as ping;
+pub use ports::report as ports_report;
+pub use protocols::Protocol;
+pub use protocols::enable as protocols_enable;
+pub use provenance::ProvenanceFormat;
+pub use provenance::run as provenance;
 pub use prune::run as prune_alphas;
 pub use reinstall::run_alpha as reinstall_alpha;
 pub use reinstall::run_release as reinstall_release;
+pub use remote_shell::resolve_node_name;
+pub use remote_shell::run as remote_shell;
+pub use repair::run as repair;
+pub use run_script::list as run_script_list;
+pub use run_script::run as run_script;
+pub use search::run as search;
 pub use show::CONFIG_FILES;
+pub use show::InspectFormat;
 pub use show::run as inspect;
+pub use smoke::StreamSmokeOptions;
+pub use smoke::stream as smoke_stream;
+pub use state::DEFAULT_STATE_FILE;
+pub use state::ExportStateOptions;
+pub use state::ImportStateOptions;
+pub use state::StateManifest;
+pub use state::VersionEntry as StateVersionEntry;
+pub use state::export as export_state;
+pub use state::import as import_state;
+pub use supervise::run as supervise;
+pub use sync::run as sync;
 pub use tanzu_install::run as tanzu_install;
+pub use toolbox::install as toolbox_install;
+pub use toolbox::list as toolbox_list;
+pub use toolbox::uninstall as toolbox_uninstall;
+pub use trash::empty as trash_empty;
+pub use trash::list as trash_list;
+pub use trash::restore as trash_restore;
+pub use ui::open as ui_open;
+pub use uninstall::RunningNodes;
 pub use uninstall::run_alpha as uninstall_alpha;
 pub use uninstall::run_release as uninstall_release;
+pub use upgrade_path::format_path as format_upgrade_path;
+pub use upgrade_path::run as upgrade_path;
 pub use use_cmd::run_alpha as use_alpha_version;
 pub use use_cmd::run_release as use_release_version;
+pub use verify::Check as VerificationCheck;
+pub use verify::Verification;
+pub use verify::run as verify;
+pub use version_table::OutputOptions;
+pub use version_table::VersionRow;
+pub use version_table::render as render_version_table;
+pub use watch::WatchOptions;
+pub use watch::is_new as is_new_alpha;
+pub use watch::record_seen as record_seen_alpha;
+pub use watch::rotation_candidates as alpha_rotation_candidates;
+pub use watch::run as watch_alphas;
+pub use workspace::create as workspace_create;
+pub use workspace::delete as workspace_delete;
+pub use workspace::enter as workspace_enter;
+pub use workspace::list as workspace_list;
 
 pub use status::Status;
 pub use status::run as status;
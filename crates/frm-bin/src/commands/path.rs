@@ -6,30 +6,101 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
 use crate::Result;
+use crate::envs;
 use crate::errors::Error;
 use crate::paths::Paths;
 use crate::version::Version;
 
-pub fn run_release(paths: &Paths, version: &Version) -> Result<()> {
+/// A directory of an installed version that `path --check` prints if it exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PathKind {
+    /// The directory with rabbitmq-server and the CLI tools
+    Sbin,
+    /// The directory with rabbitmq.conf
+    Etc,
+    /// The node's log directory
+    Logs,
+    /// The node's data directory, see 'frm data-dir'
+    Data,
+}
+
+impl PathKind {
+    fn path(self, paths: &Paths, version: &Version) -> Result<PathBuf> {
+        Ok(match self {
+            PathKind::Sbin => paths.version_sbin_dir(version),
+            PathKind::Etc => paths.version_etc_dir(version),
+            PathKind::Logs => paths.version_var_log_dir(version),
+            PathKind::Data => envs::data_dir(paths, version, None)?,
+        })
+    }
+}
+
+impl fmt::Display for PathKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathKind::Sbin => write!(f, "sbin"),
+            PathKind::Etc => write!(f, "etc"),
+            PathKind::Logs => write!(f, "logs"),
+            PathKind::Data => write!(f, "data"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathOptions {
+    /// Print this directory instead of the version's directory, failing if it does not exist
+    pub check: Option<PathKind>,
+    /// Terminate the path with a NUL byte instead of a newline, like `find -print0`
+    pub print0: bool,
+}
+
+pub fn run_release(paths: &Paths, version: &Version, opts: PathOptions) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedNonAlphaVersion(version.clone()));
     }
-    run(paths, version)
+    run(paths, version, opts)
 }
 
-pub fn run_alpha(paths: &Paths, version: &Version) -> Result<()> {
+pub fn run_alpha(paths: &Paths, version: &Version, opts: PathOptions) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedAlphaVersion(version.clone()));
     }
-    run(paths, version)
+    run(paths, version, opts)
 }
 
-fn run(paths: &Paths, version: &Version) -> Result<()> {
+fn run(paths: &Paths, version: &Version, opts: PathOptions) -> Result<()> {
+    let path = resolve(paths, version, opts.check)?;
+
+    let terminator = if opts.print0 { '\0' } else { '\n' };
+    let mut stdout = io::stdout().lock();
+    write!(stdout, "{}{}", path.display(), terminator)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// The version's directory, or the `check`ed directory in it if that exists
+fn resolve(paths: &Paths, version: &Version, check: Option<PathKind>) -> Result<PathBuf> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    let Some(kind) = check else {
+        return Ok(paths.version_dir(version));
+    };
 
-    println!("{}", paths.version_dir(version).display());
-    Ok(())
+    let path = kind.path(paths, version)?;
+    if !path.is_dir() {
+        return Err(Error::MissingPath {
+            version: version.clone(),
+            kind: kind.to_string(),
+            path: path.display().to_string(),
+        });
+    }
+    Ok(path)
 }
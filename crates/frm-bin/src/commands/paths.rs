@@ -0,0 +1,145 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::envs;
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum PathsFormat {
+    /// A table
+    #[default]
+    Text,
+    /// An object with a path per name
+    Json,
+}
+
+#[derive(Tabled)]
+struct PathRow {
+    #[tabled(rename = "Name")]
+    name: &'static str,
+    #[tabled(rename = "Path")]
+    path: String,
+    #[tabled(rename = "Exists")]
+    exists: &'static str,
+}
+
+/// Every directory and file frm uses, for a version (and one of its environments) if given.
+/// The version does not have to be installed: its paths are where it would be.
+pub fn layout(
+    paths: &Paths,
+    version: Option<&Version>,
+    env: Option<&str>,
+) -> Vec<(&'static str, PathBuf)> {
+    let mut entries = vec![("base", paths.base_dir().to_path_buf())];
+    if let Some(shared_dir) = paths.shared_dir() {
+        entries.push(("shared", shared_dir.to_path_buf()));
+    }
+    entries.extend([
+        ("config-file", paths.config_file()),
+        ("versions", paths.versions_dir()),
+        ("current-link", paths.current_link()),
+        ("default-etc", paths.etc_dir()),
+        ("downloads", paths.downloads_dir()),
+        ("metadata", paths.metadata_dir()),
+        ("trash", paths.trash_dir()),
+        ("migrations", paths.migrations_dir()),
+        ("tools", paths.tools_dir()),
+        ("history-file", paths.history_file()),
+        ("shared-cookie-file", paths.shared_cookie_file()),
+        ("cache", paths.cache_dir()),
+        (
+            "server-releases-feed-cache",
+            paths.server_releases_feed_cache_file(),
+        ),
+        (
+            "server-packages-feed-cache",
+            paths.server_packages_feed_cache_file(),
+        ),
+        ("denylist-cache", paths.denylist_cache_file()),
+    ]);
+
+    let Some(version) = version else {
+        return entries;
+    };
+    let (etc_dir, log_dir) = match env {
+        Some(name) => (
+            paths.version_env_etc_dir(version, name),
+            paths.version_env_log_dir(version, name),
+        ),
+        None => (
+            paths.version_etc_dir(version),
+            paths.version_var_log_dir(version),
+        ),
+    };
+    // unreadable metadata is reported by the commands that use it, the layout shows the default
+    let data_dir =
+        envs::data_dir(paths, version, env).unwrap_or_else(|_| paths.version_data_dir(version));
+    entries.extend([
+        ("install", paths.version_dir(version)),
+        ("sbin", paths.version_sbin_dir(version)),
+        ("etc", etc_dir.clone()),
+        ("rabbitmq.conf", etc_dir.join("rabbitmq.conf")),
+        ("conf.d", etc_dir.join("conf.d")),
+        ("advanced.config", etc_dir.join("advanced.config")),
+        ("enabled_plugins", etc_dir.join("enabled_plugins")),
+        // rabbitmq-server writes PID files next to the node data directories
+        ("run", data_dir.clone()),
+        ("data", data_dir),
+        ("logs", log_dir),
+        ("envs", paths.version_envs_dir(version)),
+        ("cookie-file", paths.version_cookie_file(version)),
+        ("metadata-file", paths.version_metadata_file(version)),
+        ("archive", paths.version_archive_file(version)),
+    ]);
+    entries
+}
+
+pub fn show(
+    paths: &Paths,
+    version: Option<&Version>,
+    env: Option<&str>,
+    format: PathsFormat,
+) -> Result<()> {
+    if let (Some(version), Some(name)) = (version, env) {
+        envs::ensure_exists(paths, version, name)?;
+    }
+    let entries = layout(paths, version, env);
+
+    match format {
+        PathsFormat::Text => {
+            let rows: Vec<PathRow> = entries
+                .into_iter()
+                .map(|(name, path)| PathRow {
+                    name,
+                    exists: if path.exists() { "yes" } else { "no" },
+                    path: path.display().to_string(),
+                })
+                .collect();
+            let mut table = Table::new(rows);
+            table.with(Style::rounded());
+            println!("{}", table);
+        }
+        PathsFormat::Json => {
+            let object: Map<String, Value> = entries
+                .into_iter()
+                .map(|(name, path)| (name.to_string(), Value::from(path.display().to_string())))
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&object)?);
+        }
+    }
+    Ok(())
+}
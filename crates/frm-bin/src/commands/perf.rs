@@ -0,0 +1,131 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::Result;
+use crate::amqp;
+use crate::commands::ping::configured_port;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_info, print_success};
+use crate::paths::Paths;
+use crate::perf_test;
+use crate::version::Version;
+
+const DEFAULT_DURATION_SECS: &str = "30";
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+const URI_FLAGS: &[&str] = &["--uri", "-h", "--uris", "-H"];
+const LIMIT_FLAGS: &[&str] = &["--time", "-z", "--pmessages", "-C", "--cmessages", "-D"];
+
+pub struct PerfRunOptions<'a> {
+    pub env: Option<&'a str>,
+    pub perf_test_version: Option<&'a str>,
+    pub update: bool,
+    pub args: &'a [String],
+}
+
+pub async fn run(paths: &Paths, version: &Version, opts: &PerfRunOptions<'_>) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = opts.env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let port = configured_port(paths, version, opts.env)?;
+    // Fail early, with a clear error, when the node is not running
+    amqp::handshake("localhost", port, HANDSHAKE_TIMEOUT)?;
+    let java = perf_test::find_java()?;
+
+    let jar = perf_test::ensure_jar(paths, opts.perf_test_version, opts.update).await?;
+    let args = perf_test_args(port, opts.args);
+
+    print_info(format!(
+        "Running {} against RabbitMQ {}: {}",
+        jar.file_name().unwrap_or_default().to_string_lossy(),
+        version,
+        args.join(" ")
+    ));
+
+    let mut child = Command::new(&java)
+        .arg("-jar")
+        .arg(&jar)
+        .args(&args)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            Error::CommandFailed(format!("failed to execute {}: {}", java.display(), e))
+        })?;
+
+    let mut summary = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            let line = line?;
+            println!("{}", line);
+            if is_summary_line(&line) {
+                summary.push(line);
+            }
+        }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(Error::CommandFailed(format!(
+            "PerfTest exited with {}",
+            status
+        )));
+    }
+
+    if !summary.is_empty() {
+        print_success("Summary:");
+        for line in summary {
+            println!("  {}", summary_rates(&line));
+        }
+    }
+
+    Ok(())
+}
+
+/// Connects to the version's listener and limits the run to 30 seconds,
+/// unless the arguments say otherwise
+pub fn perf_test_args(port: u16, user_args: &[String]) -> Vec<String> {
+    let has_any = |flags: &[&str]| {
+        user_args
+            .iter()
+            .any(|arg| flags.contains(&arg.split('=').next().unwrap_or_default()))
+    };
+
+    let mut args = Vec::new();
+    if !has_any(URI_FLAGS) {
+        args.push("--uri".to_string());
+        args.push(format!("amqp://localhost:{}", port));
+    }
+    if !has_any(LIMIT_FLAGS) {
+        args.push("--time".to_string());
+        args.push(DEFAULT_DURATION_SECS.to_string());
+    }
+    args.extend(user_args.iter().cloned());
+    args
+}
+
+/// PerfTest ends a run with lines such as
+/// `id: test-114115-529, sending rate avg: 21318 msg/s`
+fn is_summary_line(line: &str) -> bool {
+    line.contains("rate avg:")
+}
+
+fn summary_rates(line: &str) -> &str {
+    match line.split_once(", ") {
+        Some((id, rates)) if id.starts_with("id:") => rates,
+        _ => line,
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::errors::Error;
+use crate::messages::{print_info, print_success};
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub fn pin_release(paths: &Paths, version: &Version) -> Result<()> {
+    if version.is_distributed_via_server_packages_repository() {
+        return Err(Error::ExpectedNonAlphaVersion(version.clone()));
+    }
+    set_pinned(paths, version, true)
+}
+
+pub fn pin_alpha(paths: &Paths, version: &Version) -> Result<()> {
+    if !version.is_distributed_via_server_packages_repository() {
+        return Err(Error::ExpectedAlphaVersion(version.clone()));
+    }
+    set_pinned(paths, version, true)
+}
+
+pub fn unpin_release(paths: &Paths, version: &Version) -> Result<()> {
+    if version.is_distributed_via_server_packages_repository() {
+        return Err(Error::ExpectedNonAlphaVersion(version.clone()));
+    }
+    set_pinned(paths, version, false)
+}
+
+pub fn unpin_alpha(paths: &Paths, version: &Version) -> Result<()> {
+    if !version.is_distributed_via_server_packages_repository() {
+        return Err(Error::ExpectedAlphaVersion(version.clone()));
+    }
+    set_pinned(paths, version, false)
+}
+
+fn set_pinned(paths: &Paths, version: &Version, pinned: bool) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    if VersionMetadata::is_pinned(paths, version)? == pinned {
+        if pinned {
+            print_info(format!("RabbitMQ {} is already pinned", version));
+        } else {
+            print_info(format!("RabbitMQ {} is not pinned", version));
+        }
+        return Ok(());
+    }
+
+    VersionMetadata::update(paths, version, |m| m.pinned = pinned)?;
+
+    if pinned {
+        print_success(format!("Pinned RabbitMQ {}", version));
+    } else {
+        print_success(format!("Unpinned RabbitMQ {}", version));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::time::Duration;
+
+use rabbitmq_conf::RabbitMQConf;
+
+use crate::Result;
+use crate::amqp::{self, DEFAULT_PORT};
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_success, print_warning};
+use crate::paths::Paths;
+use crate::ports;
+use crate::version::Version;
+
+const LISTENER_KEY: &str = "listeners.tcp.default";
+
+pub struct PingOptions<'a> {
+    pub host: &'a str,
+    pub port: Option<u16>,
+    pub env: Option<&'a str>,
+    pub timeout: Duration,
+}
+
+pub fn run(paths: &Paths, version: &Version, opts: &PingOptions) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = opts.env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let port = match opts.port {
+        Some(port) => port,
+        None => configured_port(paths, version, opts.env)?,
+    };
+
+    let info = amqp::handshake(opts.host, port, opts.timeout)?;
+
+    let product = info.product.as_deref().unwrap_or("AMQP 0-9-1 server");
+    let reported = info.version.as_deref().unwrap_or("(unknown version)");
+    print_success(format!(
+        "Connected to {} {} at {} in {} ms",
+        product,
+        reported,
+        info.address,
+        info.elapsed.as_millis()
+    ));
+    if let Some(cluster_name) = &info.cluster_name {
+        println!("Cluster name: {}", cluster_name);
+    }
+    println!("Authentication mechanisms: {}", info.mechanisms.join(", "));
+
+    if info
+        .version
+        .as_deref()
+        .is_some_and(|v| v != version.to_string())
+    {
+        print_warning(format!(
+            "expected RabbitMQ {}: a different node is listening on port {}",
+            version, port
+        ));
+    }
+
+    Ok(())
+}
+
+/// The AMQP 0-9-1 port from `listeners.tcp.default` in rabbitmq.conf, if set
+pub fn configured_port(paths: &Paths, version: &Version, env: Option<&str>) -> Result<u16> {
+    let etc_dir = match env {
+        Some(name) => paths.version_env_etc_dir(version, name),
+        None => paths.version_etc_dir(version),
+    };
+    let conf_path = etc_dir.join("rabbitmq.conf");
+    if !conf_path.exists() {
+        return Ok(DEFAULT_PORT);
+    }
+
+    let conf = RabbitMQConf::load(&conf_path).map_err(|e| Error::Config(e.to_string()))?;
+    match conf.get(LISTENER_KEY) {
+        Some(value) => ports::parse_listener_port(value)
+            .ok_or_else(|| Error::Config(format!("invalid {} value: {}", LISTENER_KEY, value))),
+        None => Ok(DEFAULT_PORT),
+    }
+}
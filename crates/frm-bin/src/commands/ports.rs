@@ -0,0 +1,124 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::common::conf_files;
+use crate::common::env_conf::{self, ENV_CONF_FILE_NAME};
+use crate::envs;
+use crate::errors::Error;
+use crate::manifest::parse_enabled_plugins;
+use crate::messages::{print_info, print_success, print_warning};
+use crate::node_state;
+use crate::paths::Paths;
+use crate::ports;
+use crate::version::Version;
+
+/// Listeners of a version's node (or of one of its environments) according to its configuration
+pub(crate) fn version_listeners(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+) -> Result<Vec<ports::Listener>> {
+    let etc_dir = match env {
+        Some(name) => paths.version_env_etc_dir(version, name),
+        None => paths.version_etc_dir(version),
+    };
+
+    let settings = conf_files::effective_settings(&etc_dir);
+    let env_vars = env_conf::load(&paths.version_etc_dir(version).join(ENV_CONF_FILE_NAME));
+    let enabled_plugins = fs::read_to_string(etc_dir.join("enabled_plugins"))
+        .ok()
+        .and_then(|content| parse_enabled_plugins(&content));
+    ports::listeners(&settings, &env_vars, enabled_plugins.as_deref())
+}
+
+#[derive(Tabled)]
+struct PortRow {
+    #[tabled(rename = "Protocol")]
+    protocol: &'static str,
+    #[tabled(rename = "Port")]
+    port: u16,
+    #[tabled(rename = "Configured By")]
+    source: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+pub fn report(paths: &Paths, version: &Version, env: Option<&str>) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let listeners = version_listeners(paths, version, env)?;
+    let running = node_state::is_running(paths, version)?;
+
+    let mut conflicts = Vec::new();
+    let mut rows = Vec::new();
+    for (i, listener) in listeners.iter().enumerate() {
+        let status = if !listener.enabled {
+            format!("{} not enabled", listener.plugin.unwrap_or_default())
+        } else if let Some(other) = listeners[..i]
+            .iter()
+            .find(|l| l.enabled && l.port == listener.port)
+        {
+            conflicts.push(format!(
+                "{} and {} are both configured to use port {}",
+                other.protocol, listener.protocol, listener.port
+            ));
+            format!("conflicts with {}", other.protocol)
+        } else {
+            match ports::port_in_use(listener.port) {
+                Some(true) if running => "in use (node running)".to_string(),
+                Some(true) => {
+                    conflicts.push(format!(
+                        "port {} ({}) is already in use by another process",
+                        listener.port, listener.protocol
+                    ));
+                    "in use".to_string()
+                }
+                Some(false) => "free".to_string(),
+                None => "unknown".to_string(),
+            }
+        };
+        rows.push(PortRow {
+            protocol: listener.protocol,
+            port: listener.port,
+            source: listener
+                .source
+                .clone()
+                .unwrap_or_else(|| "default".to_string()),
+            status,
+        });
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+
+    if running {
+        print_info(format!(
+            "RabbitMQ {} is running, its own listeners are expected to be in use",
+            version
+        ));
+    }
+    if conflicts.is_empty() {
+        print_success("No port conflicts found");
+    } else {
+        for conflict in conflicts {
+            print_warning(conflict);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::fs;
+use std::process::Command;
+
+use clap::ValueEnum;
+use rabbitmq_conf::RabbitMQConf;
+use tracing::debug;
+
+use crate::Result;
+use crate::commands::ports::version_listeners;
+use crate::commands::remote_shell::resolve_node_name;
+use crate::common::cli_tools::RABBITMQ_PLUGINS;
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::etc_backups;
+use crate::manifest::{format_enabled_plugins, parse_enabled_plugins};
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::node_state;
+use crate::paths::Paths;
+use crate::shared;
+use crate::version::Version;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Protocol {
+    /// MQTT 3.1, 3.1.1, and 5.0 (rabbitmq_mqtt)
+    Mqtt,
+    /// STOMP 1.0 through 1.2 (rabbitmq_stomp)
+    Stomp,
+    /// AMQP 1.0 (built in since RabbitMQ 4.0, rabbitmq_amqp1_0 before)
+    #[value(name = "amqp10")]
+    Amqp10,
+    /// RabbitMQ Stream protocol (rabbitmq_stream)
+    Stream,
+}
+
+impl Protocol {
+    /// The plugin that provides the protocol in a given version, `None` if it is built in
+    pub fn plugin(self, version: &Version) -> Option<&'static str> {
+        match self {
+            Protocol::Mqtt => Some("rabbitmq_mqtt"),
+            Protocol::Stomp => Some("rabbitmq_stomp"),
+            Protocol::Amqp10 if version.major < 4 => Some("rabbitmq_amqp1_0"),
+            Protocol::Amqp10 => None,
+            Protocol::Stream => Some("rabbitmq_stream"),
+        }
+    }
+
+    /// The rabbitmq.conf key of the protocol's listener
+    pub fn listener_key(self) -> &'static str {
+        match self {
+            Protocol::Mqtt => "mqtt.listeners.tcp.default",
+            Protocol::Stomp => "stomp.listeners.tcp.default",
+            // AMQP 1.0 clients connect to the AMQP 0-9-1 listener
+            Protocol::Amqp10 => "listeners.tcp.default",
+            Protocol::Stream => "stream.listeners.tcp.default",
+        }
+    }
+
+    /// The name of the listener in [`crate::ports`]
+    fn listener_protocol(self) -> &'static str {
+        match self {
+            Protocol::Mqtt => "MQTT",
+            Protocol::Stomp => "STOMP",
+            Protocol::Amqp10 => "AMQP",
+            Protocol::Stream => "Stream",
+        }
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Protocol::Mqtt => "MQTT",
+            Protocol::Stomp => "STOMP",
+            Protocol::Amqp10 => "AMQP 1.0",
+            Protocol::Stream => "Stream",
+        })
+    }
+}
+
+/// Enables the protocol's plugin, sets its listener key in rabbitmq.conf (to `port`, or else
+/// to the port it already uses), and prints the port clients should connect to
+pub fn enable(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    protocol: Protocol,
+    port: Option<u16>,
+) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    shared::prepare(paths, version)?;
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let running = node_state::is_running(paths, version)?;
+    match protocol.plugin(version) {
+        Some(plugin) => {
+            if !enable_plugin(paths, version, env, plugin, running)? {
+                print_info(format!("{} is already enabled", plugin));
+            }
+        }
+        None => print_info(format!(
+            "{} is built into RabbitMQ {}, no plugin to enable",
+            protocol, version
+        )),
+    }
+
+    let listeners = version_listeners(paths, version, env)?;
+    let current = listeners
+        .iter()
+        .find(|l| l.protocol == protocol.listener_protocol());
+    let Some(port) = port.or(current.map(|l| l.port)) else {
+        return Err(Error::Config(format!(
+            "the {} listener is disabled, pass --port to enable it",
+            protocol
+        )));
+    };
+    // a listener configured as e.g. mqtt.listeners.tcp.1 keeps its key
+    let configured_key = current.and_then(|l| l.source.as_deref());
+    let listener_changed = match configured_key {
+        Some(key) if current.is_some_and(|l| l.port == port) => {
+            print_info(format!("{} is already {}", key, port));
+            false
+        }
+        key => set_listener(
+            paths,
+            version,
+            env,
+            key.unwrap_or(protocol.listener_key()),
+            port,
+        )?,
+    };
+
+    let conflicts: Vec<&str> = listeners
+        .iter()
+        .filter(|l| l.enabled && l.port == port && l.protocol != protocol.listener_protocol())
+        .map(|l| l.protocol)
+        .collect();
+    if !conflicts.is_empty() {
+        print_warning(format!(
+            "port {} is also used by the {} listener",
+            port,
+            conflicts.join(", ")
+        ));
+    }
+
+    print_success(format!(
+        "{} is enabled for RabbitMQ {} on port {}",
+        protocol, version, port
+    ));
+    if protocol == Protocol::Amqp10 {
+        print_hint("AMQP 1.0 shares the listener with AMQP 0-9-1");
+    }
+    if running && listener_changed {
+        print_hint(format!(
+            "Restart the node for the listener change to take effect: frm bg stop -V {} && frm bg start -V {}",
+            version, version
+        ));
+    } else if !running {
+        print_hint(format!("Start the node with: frm bg start -V {}", version));
+    }
+    Ok(())
+}
+
+/// Sets the listener key unless it already has the port. Returns `true` if rabbitmq.conf changed.
+fn set_listener(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    key: &str,
+    port: u16,
+) -> Result<bool> {
+    let etc_dir = match env {
+        Some(name) => paths.version_env_etc_dir(version, name),
+        None => paths.version_etc_dir(version),
+    };
+    let conf_path = etc_dir.join("rabbitmq.conf");
+    let mut conf = if conf_path.exists() {
+        RabbitMQConf::load(&conf_path).map_err(|e| Error::Config(e.to_string()))?
+    } else {
+        RabbitMQConf::new()
+    };
+
+    // `mqtt.listeners.tcp = none` disables every MQTT listener
+    let disabled_by = key
+        .rsplit_once('.')
+        .map(|(prefix, _)| prefix)
+        .filter(|prefix| conf.get(prefix).is_some_and(|v| v.trim() == "none"));
+    let value = port.to_string();
+    if conf.get(key) == Some(value.as_str()) && disabled_by.is_none() {
+        print_info(format!("{} is already {}", key, value));
+        return Ok(false);
+    }
+    if let Some(prefix) = disabled_by {
+        conf.remove(prefix);
+        print_info(format!("removed {} = none", prefix));
+    }
+    conf.set(key, &value);
+    fs::create_dir_all(&etc_dir)?;
+    etc_backups::replace(&conf_path, conf.to_string().as_bytes())?;
+    print_info(format!("set {} = {}", key, value));
+    Ok(true)
+}
+
+/// Adds a plugin to enabled_plugins, with `rabbitmq-plugins` if the node is running so that
+/// it starts right away. Returns `false` if the plugin was already enabled.
+pub(crate) fn enable_plugin(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    plugin: &str,
+    running: bool,
+) -> Result<bool> {
+    let etc_dir = match env {
+        Some(name) => paths.version_env_etc_dir(version, name),
+        None => paths.version_etc_dir(version),
+    };
+    let plugins_file = etc_dir.join("enabled_plugins");
+    let mut plugins = match fs::read_to_string(&plugins_file) {
+        Ok(content) => parse_enabled_plugins(&content).ok_or_else(|| {
+            Error::Config(format!(
+                "{} is not a list of plugins",
+                plugins_file.display()
+            ))
+        })?,
+        Err(_) => Vec::new(),
+    };
+    if plugins.iter().any(|p| p == plugin) {
+        return Ok(false);
+    }
+
+    print_info(format!("Enabling {}", plugin));
+    if running {
+        let tool_path = paths.version_sbin_dir(version).join(RABBITMQ_PLUGINS);
+        let node = resolve_node_name(paths, version)?;
+        debug!(path = %tool_path.display(), node, "enabling plugin");
+        let output = Command::new(&tool_path)
+            .args(["-n", &node, "enable", plugin])
+            .env(RABBITMQ_HOME, paths.version_dir(version))
+            .envs(envs::node_env_vars(paths, version, env)?)
+            .envs(cookie::env_vars(paths, version)?)
+            .output()
+            .map_err(|e| {
+                Error::CommandFailed(format!("failed to execute {}: {}", tool_path.display(), e))
+            })?;
+        if !output.status.success() {
+            return Err(Error::CommandFailed(format!(
+                "{} enable {} exited with code {}: {}",
+                RABBITMQ_PLUGINS,
+                plugin,
+                output.status.code().unwrap_or(-1),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+    } else {
+        plugins.push(plugin.to_string());
+        fs::create_dir_all(&etc_dir)?;
+        fs::write(&plugins_file, format_enabled_plugins(&plugins))?;
+    }
+    Ok(true)
+}
@@ -0,0 +1,170 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, SecondsFormat};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::Result;
+use crate::errors::Error;
+use crate::metadata::{SignatureCheck, VersionMetadata};
+use crate::paths::Paths;
+use crate::provenance::{FRM_VERSION, Provenance};
+use crate::version::Version;
+
+const PACKAGE_NAME: &str = "rabbitmq-server";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ProvenanceFormat {
+    /// One field per line
+    #[default]
+    Text,
+    /// A JSON object
+    Json,
+    /// An SPDX 2.3 document with the SPDX Lite package fields, in the tag-value format
+    SpdxLite,
+}
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    name: &'static str,
+    version: String,
+    path: String,
+    source: &'a str,
+    file_name: &'a str,
+    sha256: &'a str,
+    signature: Signature,
+    installed_by: String,
+    installed_at: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Signature {
+    /// "verified", "failed", or "unknown" when the signature was never checked
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checked_at: Option<String>,
+}
+
+impl From<Option<SignatureCheck>> for Signature {
+    fn from(check: Option<SignatureCheck>) -> Self {
+        Self {
+            status: match check {
+                Some(check) if check.verified => "verified",
+                Some(_) => "failed",
+                None => "unknown",
+            },
+            checked_at: check.map(|check| timestamp(check.checked_at)),
+        }
+    }
+}
+
+pub fn run(paths: &Paths, version: &Version, format: ProvenanceFormat) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    let metadata = VersionMetadata::load(paths, version)?;
+    let Some(provenance) = &metadata.provenance else {
+        return Err(Error::NoProvenance(version.clone()));
+    };
+
+    let record = Record {
+        name: PACKAGE_NAME,
+        version: version.to_string(),
+        path: paths.version_dir(version).display().to_string(),
+        source: &provenance.source,
+        file_name: &provenance.file_name,
+        sha256: &provenance.sha256,
+        signature: metadata.signature.into(),
+        installed_by: format!("frm {}", provenance.frm_version),
+        installed_at: timestamp(provenance.recorded_at),
+    };
+    match format {
+        ProvenanceFormat::Text => print!("{}", text(&record)),
+        ProvenanceFormat::Json => println!("{}", serde_json::to_string_pretty(&record)?),
+        ProvenanceFormat::SpdxLite => print!("{}", spdx_lite(&record, provenance)),
+    }
+    Ok(())
+}
+
+fn text(record: &Record) -> String {
+    let signature = match &record.signature.checked_at {
+        Some(checked_at) => format!("{} (checked at {})", record.signature.status, checked_at),
+        None => record.signature.status.to_string(),
+    };
+    [
+        ("Version", record.version.as_str()),
+        ("Path", &record.path),
+        ("Source", record.source),
+        ("File", record.file_name),
+        ("SHA-256", record.sha256),
+        ("Signature", &signature),
+        ("Installed by", &record.installed_by),
+        ("Installed at", &record.installed_at),
+    ]
+    .iter()
+    .map(|(name, value)| format!("{:<13} {}\n", format!("{}:", name), value))
+    .collect()
+}
+
+/// The document is generated from the record, so it is the same every time it is printed
+fn spdx_lite(record: &Record, provenance: &Provenance) -> String {
+    let package_id = format!("SPDXRef-Package-{}", PACKAGE_NAME);
+    let mut comment = format!("Signature: {}", record.signature.status);
+    if let Some(checked_at) = &record.signature.checked_at {
+        comment.push_str(&format!(", checked at {}", checked_at));
+    }
+    comment.push_str(&format!(". Installed to {}.", record.path));
+
+    let lines = [
+        "SPDXVersion: SPDX-2.3".to_string(),
+        "DataLicense: CC0-1.0".to_string(),
+        "SPDXID: SPDXRef-DOCUMENT".to_string(),
+        format!("DocumentName: {}-{}", PACKAGE_NAME, record.version),
+        format!(
+            "DocumentNamespace: https://spdx.org/spdxdocs/frm-{}-{}-{}",
+            PACKAGE_NAME, record.version, provenance.sha256
+        ),
+        format!("Creator: Tool: frm-{}", FRM_VERSION),
+        format!("Created: {}", record.installed_at),
+        String::new(),
+        format!("PackageName: {}", PACKAGE_NAME),
+        format!("SPDXID: {}", package_id),
+        format!("PackageVersion: {}", record.version),
+        format!("PackageFileName: {}", record.file_name),
+        "PackageSupplier: NOASSERTION".to_string(),
+        format!(
+            "PackageDownloadLocation: {}",
+            download_location(record.source)
+        ),
+        "FilesAnalyzed: false".to_string(),
+        format!("PackageChecksum: SHA256: {}", record.sha256),
+        "PackageLicenseConcluded: NOASSERTION".to_string(),
+        "PackageLicenseDeclared: NOASSERTION".to_string(),
+        "PackageCopyrightText: NOASSERTION".to_string(),
+        format!("PackageComment: <text>{}</text>", comment),
+        String::new(),
+        format!("Relationship: SPDXRef-DOCUMENT DESCRIBES {}", package_id),
+    ];
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// SPDX only takes URLs here, a local file has no download location
+fn download_location(source: &str) -> &str {
+    if source.starts_with("https://") || source.starts_with("http://") {
+        source
+    } else {
+        "NOASSERTION"
+    }
+}
+
+fn timestamp(seconds: u64) -> String {
+    DateTime::from_timestamp(seconds as i64, 0)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .unwrap_or_else(|| seconds.to_string())
+}
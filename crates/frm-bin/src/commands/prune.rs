@@ -8,22 +8,50 @@
 
 use std::fs;
 
-use bel7_cli::{print_info, print_success};
-
 use crate::Result;
+use crate::common::prompt::confirm_destructive;
 use crate::config::Config;
+use crate::current;
+use crate::messages::{Message, print_info, print_success};
+use crate::metadata::VersionMetadata;
 use crate::paths::Paths;
 use crate::timestamps::Timestamps;
 
-pub fn run(paths: &Paths) -> Result<()> {
+pub fn run(paths: &Paths, assume_yes: bool) -> Result<()> {
     let versions = paths.installed_versions()?;
     let alphas: Vec<_> = versions
         .into_iter()
         .filter(|v| v.is_distributed_via_server_packages_repository())
+        // shared versions are managed by whoever provisioned the shared directory
+        .filter(|v| !paths.version_shared(v))
         .collect();
 
     if alphas.is_empty() {
-        print_info("No alpha versions installed");
+        print_info(Message::NoAlphasInstalled);
+        return Ok(());
+    }
+
+    let mut to_remove = Vec::with_capacity(alphas.len());
+    for version in alphas {
+        if VersionMetadata::is_pinned(paths, &version)? {
+            print_info(Message::KeepingPinned(&version));
+        } else {
+            to_remove.push(version);
+        }
+    }
+
+    if to_remove.is_empty() {
+        print_info("All installed alpha versions are pinned");
+        return Ok(());
+    }
+
+    let names: Vec<String> = to_remove.iter().map(|v| v.to_string()).collect();
+    let question = format!(
+        "Remove {} alpha version(s): {}?",
+        to_remove.len(),
+        names.join(", ")
+    );
+    if !confirm_destructive(paths, assume_yes, &question)? {
         return Ok(());
     }
 
@@ -31,8 +59,8 @@ pub fn run(paths: &Paths) -> Result<()> {
     let mut timestamps = Timestamps::load(paths)?;
     let mut cleared_default = false;
 
-    for version in &alphas {
-        print_info(format!("Removing RabbitMQ {}", version));
+    for version in &to_remove {
+        print_info(Message::Removing(version));
 
         let version_dir = paths.version_dir(version);
         fs::remove_dir_all(&version_dir)?;
@@ -42,12 +70,13 @@ pub fn run(paths: &Paths) -> Result<()> {
             cleared_default = true;
         }
 
-        let archive = paths.downloads_dir().join(version.archive_name());
+        let archive = paths.version_archive_file(version);
         if archive.exists() {
             fs::remove_file(archive)?;
         }
 
         timestamps.remove(version);
+        VersionMetadata::remove(paths, version)?;
     }
 
     if cleared_default {
@@ -56,12 +85,13 @@ pub fn run(paths: &Paths) -> Result<()> {
         if default_file.exists() {
             fs::remove_file(default_file)?;
         }
-        print_info("Cleared default version (an alpha was the default)");
+        current::remove(paths)?;
+        print_info(Message::ClearedAlphaDefault);
     }
 
     timestamps.save(paths)?;
 
-    print_success(format!("Removed {} alpha version(s)", alphas.len()));
+    print_success(Message::RemovedAlphas(to_remove.len()));
 
     Ok(())
 }
@@ -8,12 +8,12 @@
 
 use std::fs;
 
-use bel7_cli::{print_info, print_success};
-
 use crate::Result;
 use crate::download::{Downloader, copy_default_config};
 use crate::errors::Error;
+use crate::messages::{Message, print_info, print_success};
 use crate::paths::Paths;
+use crate::profile::{self, Phase};
 use crate::timestamps::Timestamps;
 use crate::version::Version;
 
@@ -35,30 +35,38 @@ async fn run(paths: &Paths, version: &Version) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    if paths.version_linked(version) {
+        return Err(Error::VersionIsLinked(version.clone()));
+    }
+    if paths.version_shared(version) {
+        return Err(Error::VersionIsShared(version.clone()));
+    }
 
-    print_info(format!("Removing RabbitMQ {}", version));
+    print_info(Message::Removing(version));
     fs::remove_dir_all(paths.version_dir(version))?;
 
-    let archive = paths.downloads_dir().join(version.archive_name());
+    let archive = paths.version_archive_file(version);
     if archive.exists() {
         fs::remove_file(&archive)?;
     }
 
     paths.ensure_dirs()?;
 
-    print_info(format!("Downloading RabbitMQ {}", version));
+    print_info(Message::Downloading(version));
     let downloader = Downloader::new();
-    downloader.download(version, paths).await?;
+    let provenance = downloader.download(version, paths).await?;
+    let _timer = profile::start(Phase::PostInstall);
 
-    print_info("Copying default configuration");
+    print_info(Message::CopyingDefaultConfig);
     copy_default_config(paths, version)?;
 
-    print_info("Cleaning up downloaded archive");
+    print_info(Message::CleaningUpArchive);
     downloader.cleanup_archive(version, paths)?;
 
     let mut timestamps = Timestamps::load(paths)?;
     timestamps.record(version);
     timestamps.save(paths)?;
+    provenance.record(paths, version)?;
 
     print_success(format!("RabbitMQ {} reinstalled successfully", version));
 
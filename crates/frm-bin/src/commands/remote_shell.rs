@@ -0,0 +1,58 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::commands::cli_cmd::exec;
+use crate::cookie;
+use crate::errors::Error;
+use crate::messages::print_info;
+use crate::node_state;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const RABBITMQ_DIAGNOSTICS: &str = "rabbitmq-diagnostics";
+
+/// Attaches an Erlang shell to a running node with `rabbitmq-diagnostics remote_shell`
+pub fn run(paths: &Paths, version: &Version, node: Option<&str>) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+
+    let tool_path = paths.version_sbin_dir(version).join(RABBITMQ_DIAGNOSTICS);
+    if !tool_path.exists() {
+        return Err(Error::FileNotFound(tool_path.display().to_string()));
+    }
+
+    let node = match node {
+        Some(node) => node.to_string(),
+        None => resolve_node_name(paths, version)?,
+    };
+
+    print_info(format!(
+        "Attaching to {}. Press Ctrl+G, then q and Enter to detach: q() and init:stop() stop the node",
+        node
+    ));
+    exec(
+        &tool_path,
+        &["remote_shell".to_string(), "-n".to_string(), node],
+        &cookie::env_vars(paths, version)?,
+    )
+}
+
+/// The name of the version's only running node
+pub fn resolve_node_name(paths: &Paths, version: &Version) -> Result<String> {
+    let mut names = node_state::running_node_names(paths, version)?;
+    match names.len() {
+        0 => Err(Error::NoRunningNode(version.clone())),
+        1 => Ok(names.remove(0)),
+        _ => Err(Error::AmbiguousNode {
+            version: version.clone(),
+            nodes: names.join(", "),
+        }),
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::Path;
+
+use crate::Result;
+use crate::download::{Downloader, copy_default_config};
+use crate::errors::Error;
+use crate::messages::{Message, print_info, print_success};
+use crate::paths::Paths;
+use crate::permissions;
+use crate::timestamps::Timestamps;
+use crate::version::Version;
+
+/// Directories under the version directory that hold configuration and node data
+const PRESERVED_DIRS: &[&str] = &["etc", "var"];
+
+pub async fn run(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if paths.version_shared(version) {
+        return Err(Error::VersionIsShared(version.clone()));
+    }
+
+    // a version that is only missing executable bits does not have to be re-downloaded
+    if !paths.version_broken(version)
+        && !paths.version_linked(version)
+        && !permissions::non_executable_files(&paths.version_dir(version))?.is_empty()
+    {
+        fix_permissions(paths, version)?;
+        print_success(Message::Repaired(version));
+        return Ok(());
+    }
+
+    let version_dir = paths.version_dir(version);
+    let repair_dir = paths.version_repair_dir(version);
+    if repair_dir.exists() {
+        fs::remove_dir_all(&repair_dir)?;
+    }
+    fs::create_dir_all(&repair_dir)?;
+
+    let mut preserved = Vec::new();
+    for name in PRESERVED_DIRS {
+        let src = version_dir.join(name);
+        if src.exists() {
+            fs::rename(&src, repair_dir.join(name))?;
+            preserved.push(*name);
+        }
+    }
+
+    print_info(Message::Removing(version));
+    fs::remove_dir_all(&version_dir)?;
+
+    paths.ensure_dirs()?;
+
+    print_info(Message::Downloading(version));
+    let downloader = Downloader::new();
+    let provenance = match downloader.download(version, paths).await {
+        Ok(provenance) => provenance,
+        Err(e) => {
+            // Put the configuration and data back so that nothing is lost
+            fs::create_dir_all(&version_dir)?;
+            restore_preserved(&repair_dir, &version_dir, &preserved)?;
+            return Err(e);
+        }
+    };
+
+    restore_preserved(&repair_dir, &version_dir, &preserved)?;
+    fix_permissions(paths, version)?;
+    if !preserved.contains(&"etc") {
+        print_info(Message::CopyingDefaultConfig);
+        copy_default_config(paths, version)?;
+    }
+
+    downloader.cleanup_archive(version, paths)?;
+
+    let mut timestamps = Timestamps::load(paths)?;
+    timestamps.record(version);
+    timestamps.save(paths)?;
+    provenance.record(paths, version)?;
+
+    print_success(Message::Repaired(version));
+
+    Ok(())
+}
+
+/// Makes the scripts in sbin and escript executable, reports the ones that were not
+pub fn fix_permissions(paths: &Paths, version: &Version) -> Result<()> {
+    let fixed = permissions::fix(&paths.version_dir(version))?;
+    if !fixed.is_empty() {
+        print_info(format!(
+            "Restored the executable bit on {} file(s) in sbin and escript",
+            fixed.len()
+        ));
+    }
+    Ok(())
+}
+
+fn restore_preserved(repair_dir: &Path, version_dir: &Path, preserved: &[&str]) -> Result<()> {
+    for name in preserved {
+        let dest = version_dir.join(name);
+        if dest.exists() {
+            fs::remove_dir_all(&dest)?;
+        }
+        fs::rename(repair_dir.join(name), dest)?;
+    }
+    fs::remove_dir_all(repair_dir)?;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::commands::cli_cmd::exec;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info};
+use crate::paths::Paths;
+use crate::scripts;
+use crate::shared;
+use crate::version::Version;
+
+pub fn list(paths: &Paths) -> Result<()> {
+    let scripts = scripts::list(paths)?;
+    if scripts.is_empty() {
+        print_info(format!(
+            "No scripts found in {}",
+            paths.scripts_dir().display()
+        ));
+        print_hint(
+            "Add an executable file there, with a comment line after the shebang describing it",
+        );
+        return Ok(());
+    }
+
+    let width = scripts.iter().map(|s| s.name.len()).max().unwrap_or(0);
+    for script in scripts {
+        match script.description {
+            Some(description) => println!("{:width$}  {}", script.name, description),
+            None => println!("{}", script.name),
+        }
+    }
+    Ok(())
+}
+
+pub fn run(
+    paths: &Paths,
+    name: &str,
+    version: &Version,
+    env: Option<&str>,
+    args: &[String],
+) -> Result<()> {
+    let script = scripts::find(paths, name)?;
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    match env {
+        Some(env) => envs::ensure_exists(paths, version, env)?,
+        None => shared::prepare(paths, version)?,
+    }
+
+    let vars = scripts::env_vars(paths, version, env)?;
+    exec(&script.path, args, &vars)
+}
@@ -0,0 +1,208 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::commands::version_table::flavor;
+use crate::feed_cache::FEED_CACHE_TTL;
+use crate::messages::{print_info, print_warning};
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::releases::{
+    GitHubRelease, fetch_server_packages_releases, fetch_server_releases,
+    parse_version_from_release_name, parse_version_from_tag,
+};
+use crate::version::{Series, Version};
+
+/// How many of the most recent GA release series are not considered end of life
+const SUPPORTED_SERIES: usize = 2;
+
+#[derive(Debug, Default)]
+struct Entry {
+    installed: bool,
+    /// Whether the generic UNIX package has a detached signature, if the version is listed
+    signed: Option<bool>,
+    eol: bool,
+    yanked: bool,
+}
+
+#[derive(Tabled)]
+struct SearchRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Flavor")]
+    flavor: String,
+    #[tabled(rename = "Installed")]
+    installed: String,
+    #[tabled(rename = "Signed")]
+    signed: String,
+    #[tabled(rename = "Notes")]
+    notes: String,
+}
+
+/// Lists the versions matching `query`, e.g. `4.2` or `4.3.0-alpha`, among the releases
+/// on GitHub (from the feed cache when it is fresh) and installed versions
+pub async fn run(paths: &Paths, query: &str, plain: bool) -> Result<()> {
+    let client = reqwest::Client::new();
+    let releases = match fetch_server_releases(&client, paths, FEED_CACHE_TTL).await {
+        Ok(releases) => Some(releases),
+        Err(e) => {
+            print_warning(format!("Could not list releases on GitHub: {}", e));
+            None
+        }
+    };
+    let alphas = match fetch_server_packages_releases(&client, paths, FEED_CACHE_TTL).await {
+        Ok(releases) => Some(releases),
+        Err(e) => {
+            print_warning(format!("Could not list alpha releases on GitHub: {}", e));
+            None
+        }
+    };
+
+    let mut entries: BTreeMap<Version, Entry> = BTreeMap::new();
+    for (release, version) in releases.iter().flatten().filter_map(|r| {
+        parse_version_from_tag(&r.tag_name)
+            .filter(|v| !v.is_distributed_via_server_packages_repository())
+            .map(|v| (r, v))
+    }) {
+        add_remote(&mut entries, release, version);
+    }
+    for (release, version) in alphas.iter().flatten().filter_map(|r| {
+        parse_version_from_release_name(&r.name)
+            .filter(|v| v.is_distributed_via_server_packages_repository())
+            .map(|v| (r, v))
+    }) {
+        add_remote(&mut entries, release, version);
+    }
+
+    // anything older than the oldest listed release could have dropped off the feed
+    let oldest_listed = entries.keys().find(|v| !v.is_alpha()).cloned();
+    for version in paths.installed_versions()? {
+        let listed = entries.contains_key(&version);
+        let entry = entries.entry(version.clone()).or_default();
+        entry.installed = true;
+        entry.yanked = !listed
+            && releases.is_some()
+            && !version.is_distributed_via_server_packages_repository()
+            && version.build.is_none()
+            && oldest_listed
+                .as_ref()
+                .is_some_and(|oldest| &version > oldest)
+            && !paths.version_linked(&version)
+            && VersionMetadata::load(paths, &version)?
+                .imported_from
+                .is_none();
+    }
+
+    mark_eol(&mut entries);
+
+    let matches: Vec<(Version, Entry)> = entries
+        .into_iter()
+        .rev()
+        .filter(|(version, _)| matches_query(version, query))
+        .collect();
+    if matches.is_empty() {
+        print_info(format!("No versions matching '{}'", query));
+        return Ok(());
+    }
+
+    if plain {
+        for (version, entry) in &matches {
+            let mut notes = Vec::new();
+            if entry.installed {
+                notes.push("installed");
+            }
+            if entry.signed == Some(true) {
+                notes.push("signed");
+            }
+            notes.extend(status_notes(entry));
+            println!("{} {} {}", version, flavor(version), notes.join(" "));
+        }
+        return Ok(());
+    }
+
+    let rows: Vec<SearchRow> = matches
+        .iter()
+        .map(|(version, entry)| SearchRow {
+            version: version.to_string(),
+            flavor: flavor(version).to_string(),
+            installed: if entry.installed { "yes" } else { "-" }.to_string(),
+            signed: match entry.signed {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "-",
+            }
+            .to_string(),
+            notes: status_notes(entry).join(", "),
+        })
+        .collect();
+    let mut table = Table::new(rows);
+    table.with(Style::rounded());
+    println!("{}", table);
+
+    Ok(())
+}
+
+fn add_remote(entries: &mut BTreeMap<Version, Entry>, release: &GitHubRelease, version: Version) {
+    let signature = format!("{}.asc", version.archive_name());
+    entries.entry(version).or_default().signed = Some(release.has_asset(&signature));
+}
+
+fn mark_eol(entries: &mut BTreeMap<Version, Entry>) {
+    let Some(oldest_supported) = oldest_supported_series(entries.keys()) else {
+        return;
+    };
+    for (version, entry) in entries.iter_mut() {
+        entry.eol = Series::from(version) < oldest_supported;
+    }
+}
+
+/// GA releases of series older than the [`SUPPORTED_SERIES`] most recent ones
+/// no longer get patch releases
+pub(crate) fn oldest_supported_series<'a>(
+    versions: impl IntoIterator<Item = &'a Version>,
+) -> Option<Series> {
+    let mut ga_series: Vec<Series> = versions
+        .into_iter()
+        .filter(|v| v.is_ga())
+        .map(Series::from)
+        .collect();
+    ga_series.sort();
+    ga_series.dedup();
+    ga_series.iter().rev().nth(SUPPORTED_SERIES - 1).copied()
+}
+
+fn status_notes(entry: &Entry) -> Vec<&'static str> {
+    let mut notes = Vec::new();
+    if entry.eol {
+        notes.push("EOL");
+    }
+    if entry.yanked {
+        notes.push("yanked");
+    }
+    notes
+}
+
+/// `4.2` matches 4.2.0 and 4.2.3 but not 4.20.0; `4.3.0-alpha` matches every 4.3.0 alpha
+fn matches_query(version: &Version, query: &str) -> bool {
+    let query = query.trim();
+    let version = version.to_string();
+    match version.strip_prefix(query) {
+        Some(rest) => {
+            query.is_empty()
+                || rest.is_empty()
+                || rest.starts_with(['.', '-', '+'])
+                || query.ends_with(['.', '-', '+'])
+        }
+        None => false,
+    }
+}
@@ -8,8 +8,16 @@
 
 use std::fs;
 
+use clap::ValueEnum;
+use rabbitmq_conf::{RabbitMQConf, erlang_terms};
+use serde_json::{Map, Value};
+
 use crate::Result;
+use crate::commands::conf::warn_if_classic_config_takes_precedence;
+use crate::common::env_conf;
 use crate::errors::Error;
+use crate::manifest::parse_enabled_plugins;
+use crate::messages::print_success;
 use crate::paths::Paths;
 use crate::version::Version;
 
@@ -17,10 +25,29 @@ pub const CONFIG_FILES: &[&str] = &[
     "rabbitmq.conf",
     "rabbitmq-env.conf",
     "advanced.config",
+    "rabbitmq.config",
     "enabled_plugins",
 ];
 
-pub fn run(paths: &Paths, version: &Version, file: &str) -> Result<()> {
+/// Files in the Erlang term format, read by the node with `file:consult/1`
+pub const ERLANG_TERM_FILES: &[&str] = &["advanced.config", "rabbitmq.config", "enabled_plugins"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum InspectFormat {
+    /// The file as is
+    #[default]
+    Text,
+    /// rabbitmq.conf and rabbitmq-env.conf as an object, enabled_plugins as an array
+    Json,
+}
+
+pub fn run(
+    paths: &Paths,
+    version: &Version,
+    file: &str,
+    format: InspectFormat,
+    validate_only: bool,
+) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
@@ -38,8 +65,69 @@ pub fn run(paths: &Paths, version: &Version, file: &str) -> Result<()> {
         return Err(Error::FileNotFound(file_path.display().to_string()));
     }
 
+    if file == "rabbitmq.conf" || file == "rabbitmq.config" {
+        warn_if_classic_config_takes_precedence(paths, version);
+    }
+
     let content = fs::read_to_string(&file_path)?;
-    print!("{}", content);
+    if validate_only {
+        validate(file, &content)?;
+        print_success(format!("{} is valid", file_path.display()));
+        return Ok(());
+    }
+    match format {
+        InspectFormat::Text => print!("{}", content),
+        InspectFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&to_json(file, &content)?)?
+        ),
+    }
 
     Ok(())
 }
+
+/// Checks the syntax of Erlang term files and that rabbitmq.conf parses.
+/// rabbitmq-env.conf is a shell script and is not checked.
+pub fn validate(file: &str, content: &str) -> Result<()> {
+    let result = if ERLANG_TERM_FILES.contains(&file) {
+        erlang_terms::validate(content)
+    } else if file == "rabbitmq.conf" {
+        RabbitMQConf::parse(content).map(|_| ())
+    } else {
+        return Err(Error::Config(format!(
+            "--validate is not supported for {}",
+            file
+        )));
+    };
+    result.map_err(|e| Error::InvalidConfigFile {
+        file: file.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Settings are ordered by key and their values are strings, as in the file.
+/// The `RABBITMQ_` prefix of rabbitmq-env.conf variables is removed.
+pub fn to_json(file: &str, content: &str) -> Result<Value> {
+    match file {
+        "rabbitmq.conf" => {
+            let conf = RabbitMQConf::parse(content).map_err(|e| Error::Config(e.to_string()))?;
+            Ok(conf
+                .entries()
+                .map(|(k, v)| (k.to_string(), Value::from(v)))
+                .collect::<Map<_, _>>()
+                .into())
+        }
+        "rabbitmq-env.conf" => Ok(env_conf::parse(content)
+            .into_iter()
+            .map(|(k, v)| (k, Value::from(v)))
+            .collect::<Map<_, _>>()
+            .into()),
+        "enabled_plugins" => parse_enabled_plugins(content)
+            .map(Value::from)
+            .ok_or_else(|| Error::Config(format!("could not parse {}", file))),
+        _ => Err(Error::Config(format!(
+            "JSON output is not supported for {}, only for rabbitmq.conf, rabbitmq-env.conf, and enabled_plugins",
+            file
+        ))),
+    }
+}
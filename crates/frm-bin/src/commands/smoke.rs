@@ -0,0 +1,167 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+use crate::Result;
+use crate::commands::bg_start;
+use crate::commands::ports::version_listeners;
+use crate::commands::protocols::enable_plugin;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::node_state;
+use crate::paths::Paths;
+use crate::stream::{self, DEFAULT_PORT, LatencySummary, SmokeTestOptions};
+use crate::version::Version;
+
+const STREAM_PLUGIN: &str = "rabbitmq_stream";
+const STREAM_PROTOCOL: &str = "Stream";
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct StreamSmokeOptions<'a> {
+    pub host: &'a str,
+    pub port: Option<u16>,
+    pub env: Option<&'a str>,
+    pub username: &'a str,
+    pub password: &'a str,
+    pub count: usize,
+    /// How long to wait for the node and its stream listener, and then for the messages
+    pub timeout: Duration,
+}
+
+pub fn stream(paths: &Paths, version: &Version, opts: &StreamSmokeOptions) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = opts.env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let running = node_state::is_running(paths, version)?;
+    let newly_enabled = enable_plugin(paths, version, opts.env, STREAM_PLUGIN, running)?;
+    if !running {
+        print_info(format!("Starting RabbitMQ {}", version));
+        bg_start::start(paths, version, opts.env)?;
+    }
+
+    let port = match opts.port {
+        Some(port) => port,
+        None => stream_port(paths, version, opts.env)?,
+    };
+    await_listener(opts.host, port, opts.timeout)?;
+
+    print_info(format!(
+        "Publishing and consuming {} message(s) over the stream protocol on port {}",
+        opts.count, port
+    ));
+    let report = stream::smoke_test(&SmokeTestOptions {
+        host: opts.host,
+        port,
+        username: opts.username,
+        password: opts.password,
+        virtual_host: "/",
+        count: opts.count,
+        timeout: opts.timeout,
+    })?;
+
+    let product = report.product.as_deref().unwrap_or("stream server");
+    let reported = report.version.as_deref().unwrap_or("(unknown version)");
+    print_success(format!(
+        "Published, confirmed, and consumed {} message(s) on {} {} at {}",
+        report.delivery_latencies.len(),
+        product,
+        reported,
+        report.address
+    ));
+    println!(
+        "Connected in:             {}",
+        format_ms(report.connected_in)
+    );
+    if let Some(summary) = stream::summarize(&report.confirm_latencies) {
+        println!("Publish to confirm:       {}", format_summary(&summary));
+    }
+    if let Some(summary) = stream::summarize(&report.delivery_latencies) {
+        println!("Publish to consume:       {}", format_summary(&summary));
+    }
+
+    if report
+        .version
+        .as_deref()
+        .is_some_and(|v| v != version.to_string())
+    {
+        print_warning(format!(
+            "expected RabbitMQ {}: a different node is listening on port {}",
+            version, port
+        ));
+    }
+    if newly_enabled {
+        print_hint(format!(
+            "{} is now enabled for RabbitMQ {}",
+            STREAM_PLUGIN, version
+        ));
+    }
+    if !running {
+        print_hint(format!(
+            "RabbitMQ {} was started for the test, stop it with: frm bg stop -V {}",
+            version, version
+        ));
+    }
+    Ok(())
+}
+
+/// The first stream listener port from the configuration, 5552 if not set
+fn stream_port(paths: &Paths, version: &Version, env: Option<&str>) -> Result<u16> {
+    Ok(version_listeners(paths, version, env)?
+        .into_iter()
+        .find(|l| l.protocol == STREAM_PROTOCOL)
+        .map(|l| l.port)
+        .unwrap_or(DEFAULT_PORT))
+}
+
+/// A node that was just started, or a plugin that was just enabled, takes a few seconds
+/// to open its listener
+fn await_listener(host: &str, port: u16, timeout: Duration) -> Result<()> {
+    let started = Instant::now();
+    loop {
+        match TcpStream::connect((host, port)) {
+            Ok(_) => return Ok(()),
+            Err(e) if started.elapsed() >= timeout => {
+                return Err(Error::PingFailed {
+                    address: format!("{}:{}", host, port),
+                    reason: format!(
+                        "nothing is listening after {} seconds: {}",
+                        timeout.as_secs(),
+                        e
+                    ),
+                });
+            }
+            Err(e) => {
+                debug!(error = %e, port, "the stream listener is not up yet");
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+fn format_summary(summary: &LatencySummary) -> String {
+    format!(
+        "min {}, median {}, max {}",
+        format_ms(summary.min),
+        format_ms(summary.median),
+        format_ms(summary.max)
+    )
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
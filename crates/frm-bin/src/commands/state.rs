@@ -0,0 +1,421 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Moves the state of an `frm` directory to another machine.
+//!
+//! A state archive always contains `config.toml`, the default version, the shared `etc` directory,
+//! per-version metadata, and the configuration files of every installed version
+//! (including those of named environments). Installed versions themselves are only included
+//! with `--include-versions`; otherwise they are downloaded again on import.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use tar::{Archive, Builder};
+
+use crate::Result;
+use crate::archive::ArchiveFormat;
+use crate::commands::{install_alpha, install_release};
+use crate::download::copy_dir_recursive;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub const DEFAULT_STATE_FILE: &str = "frm-state.tar.zst";
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+const IMPORT_DIR_NAME: &str = ".state-import";
+const METADATA_LOCK_FILE_NAME: &str = ".lock";
+/// Node data and logs stay on this machine
+const EXCLUDED_VERSION_DIRS: &[&str] = &["var"];
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateManifest {
+    pub frm_version: String,
+    /// When the archive was created, in seconds since the Unix epoch
+    pub created_at: u64,
+    pub versions: Vec<VersionEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionEntry {
+    pub version: Version,
+    /// Whether the archive contains the whole version directory and not just its configuration
+    #[serde(default)]
+    pub included: bool,
+    /// Linked versions (see `frm link`) point to directories outside of the `frm` directory
+    /// and are never included
+    #[serde(default)]
+    pub linked: bool,
+}
+
+pub struct ExportStateOptions<'a> {
+    pub file: &'a Path,
+    pub include_versions: bool,
+}
+
+pub struct ImportStateOptions<'a> {
+    pub file: &'a Path,
+    /// Do not download versions that are neither installed nor included in the archive
+    pub download: bool,
+    /// Overwrite files that already exist in the `frm` directory
+    pub force: bool,
+}
+
+/// How an exported archive is compressed, chosen by its file name. Imports detect
+/// the format from the first bytes instead, so a renamed archive can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compressor {
+    Zstd,
+    Gzip,
+    None,
+}
+
+impl Compressor {
+    fn for_file(path: &Path) -> Self {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if name.ends_with(".gz") || name.ends_with(".tgz") {
+            Compressor::Gzip
+        } else if name.ends_with(".tar") {
+            Compressor::None
+        } else {
+            Compressor::Zstd
+        }
+    }
+}
+
+pub fn export(paths: &Paths, opts: &ExportStateOptions) -> Result<()> {
+    let mut versions = Vec::new();
+    for version in paths.installed_versions()? {
+        let linked = paths.version_linked(&version);
+        versions.push(VersionEntry {
+            included: opts.include_versions && !linked,
+            linked,
+            version,
+        });
+    }
+    let manifest = StateManifest {
+        frm_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        versions,
+    };
+
+    let file = BufWriter::new(File::create(opts.file)?);
+    let written = match Compressor::for_file(opts.file) {
+        Compressor::Zstd => {
+            let encoder = zstd::Encoder::new(file, ZSTD_LEVEL)?;
+            write_archive(paths, &manifest, encoder)?.finish()?;
+            Ok(())
+        }
+        Compressor::Gzip => {
+            let encoder = GzEncoder::new(file, Compression::default());
+            write_archive(paths, &manifest, encoder)?.finish()?;
+            Ok(())
+        }
+        Compressor::None => write_archive(paths, &manifest, file).map(|_| ()),
+    };
+    if let Err(e) = written {
+        fs::remove_file(opts.file).ok();
+        return Err(e);
+    }
+
+    print_success(format!(
+        "Exported the state of {} to {}",
+        paths.base_dir().display(),
+        opts.file.display()
+    ));
+    for entry in &manifest.versions {
+        if entry.linked {
+            print_warning(format!(
+                "RabbitMQ {} is linked to an external directory and was not included",
+                entry.version
+            ));
+        }
+    }
+    if !opts.include_versions && !manifest.versions.is_empty() {
+        print_info(
+            "Installed versions were not included and will be downloaded on import; \
+            use --include-versions to include them",
+        );
+    }
+
+    Ok(())
+}
+
+fn write_archive<W: Write>(paths: &Paths, manifest: &StateManifest, writer: W) -> Result<W> {
+    let mut builder = Builder::new(writer);
+    builder.follow_symlinks(false);
+
+    let manifest_json = serde_json::to_vec_pretty(manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest_json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(manifest.created_at);
+    header.set_cksum();
+    builder.append_data(&mut header, MANIFEST_FILE_NAME, manifest_json.as_slice())?;
+
+    for file in [paths.config_file(), paths.default_file()] {
+        if file.is_file() {
+            builder.append_path_with_name(&file, file.strip_prefix(paths.base_dir()).unwrap())?;
+        }
+    }
+    if paths.etc_dir().is_dir() {
+        builder.append_dir_all("etc", paths.etc_dir())?;
+    }
+    if paths.metadata_dir().is_dir() {
+        for entry in fs::read_dir(paths.metadata_dir())? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() && entry.file_name() != METADATA_LOCK_FILE_NAME {
+                builder.append_path_with_name(
+                    entry.path(),
+                    Path::new("metadata").join(entry.file_name()),
+                )?;
+            }
+        }
+    }
+
+    for entry in &manifest.versions {
+        if entry.linked {
+            continue;
+        }
+        let version_dir = paths.version_dir(&entry.version);
+        let archive_dir = Path::new("versions").join(entry.version.dir_name());
+        if entry.included {
+            for child in fs::read_dir(&version_dir)? {
+                let child = child?;
+                let name = child.file_name();
+                if EXCLUDED_VERSION_DIRS.iter().any(|d| name == *d) {
+                    continue;
+                }
+                if child.file_type()?.is_dir() {
+                    builder.append_dir_all(archive_dir.join(&name), child.path())?;
+                } else {
+                    builder.append_path_with_name(child.path(), archive_dir.join(&name))?;
+                }
+            }
+        } else {
+            for (dir, relative) in config_dirs(paths, &entry.version)? {
+                builder.append_dir_all(archive_dir.join(relative), dir)?;
+            }
+        }
+    }
+
+    Ok(builder.into_inner()?)
+}
+
+/// Configuration directories of a version and its named environments,
+/// along with their paths relative to the version directory
+fn config_dirs(paths: &Paths, version: &Version) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let version_dir = paths.version_dir(version);
+    let mut dirs = Vec::new();
+
+    let etc_dir = paths.version_etc_dir(version);
+    if etc_dir.is_dir() {
+        dirs.push((
+            etc_dir.clone(),
+            etc_dir.strip_prefix(&version_dir).unwrap().to_path_buf(),
+        ));
+    }
+
+    let envs_dir = paths.version_envs_dir(version);
+    if envs_dir.is_dir() {
+        for entry in fs::read_dir(&envs_dir)? {
+            let entry = entry?;
+            let Some(name) = entry.file_name().to_str().map(String::from) else {
+                continue;
+            };
+            let env_etc_dir = paths.version_env_etc_dir(version, &name);
+            if env_etc_dir.is_dir() {
+                let relative = env_etc_dir
+                    .strip_prefix(&version_dir)
+                    .unwrap()
+                    .to_path_buf();
+                dirs.push((env_etc_dir, relative));
+            }
+        }
+    }
+
+    Ok(dirs)
+}
+
+pub async fn import(paths: &Paths, opts: &ImportStateOptions<'_>) -> Result<()> {
+    if !opts.file.is_file() {
+        return Err(Error::InvalidStateArchive(format!(
+            "{} does not exist",
+            opts.file.display()
+        )));
+    }
+
+    fs::create_dir_all(paths.base_dir())?;
+    let staging = paths.base_dir().join(IMPORT_DIR_NAME);
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    fs::create_dir_all(&staging)?;
+
+    let result = import_from(paths, &staging, opts).await;
+    fs::remove_dir_all(&staging).ok();
+    result
+}
+
+async fn import_from(paths: &Paths, staging: &Path, opts: &ImportStateOptions<'_>) -> Result<()> {
+    let mut file = BufReader::new(File::open(opts.file)?);
+    // an uncompressed tar has no magic bytes at the start
+    let unpacked = match ArchiveFormat::from_magic(file.fill_buf()?) {
+        Some(format) => format
+            .decoder(file)
+            .and_then(|decoder| unpack(decoder, staging)),
+        None => unpack(file, staging),
+    };
+    unpacked.map_err(|e| Error::InvalidStateArchive(e.to_string()))?;
+
+    let manifest_path = staging.join(MANIFEST_FILE_NAME);
+    if !manifest_path.is_file() {
+        return Err(Error::InvalidStateArchive(format!(
+            "{} not found",
+            MANIFEST_FILE_NAME
+        )));
+    }
+    let manifest: StateManifest = serde_json::from_str(&fs::read_to_string(manifest_path)?)?;
+
+    for file in [paths.config_file(), paths.default_file()] {
+        let relative = file.strip_prefix(paths.base_dir()).unwrap();
+        let src = staging.join(relative);
+        if src.is_file() {
+            merge_file(&src, &file, opts.force)?;
+        }
+    }
+    for dir in ["etc", "metadata"] {
+        let src = staging.join(dir);
+        if src.is_dir() {
+            merge_dir(&src, &paths.base_dir().join(dir), opts.force)?;
+        }
+    }
+
+    let mut not_installed = Vec::new();
+    // one failed installation does not stop the others
+    let mut failed = Vec::new();
+    for entry in &manifest.versions {
+        let version = &entry.version;
+        let src = staging.join("versions").join(version.dir_name());
+
+        if entry.linked {
+            print_warning(format!(
+                "RabbitMQ {} was linked to an external directory; link it again with 'frm link'",
+                version
+            ));
+            continue;
+        }
+
+        let mut overwrite_config = opts.force;
+        if !paths.version_installed(version) {
+            if entry.included {
+                print_info(format!("Restoring RabbitMQ {}", version));
+                copy_dir_recursive(&src, &paths.version_dir(version))?;
+                continue;
+            } else if opts.download {
+                let installed = if version.is_distributed_via_server_packages_repository() {
+                    install_alpha(paths, version, false, false, false).await
+                } else {
+                    install_release(paths, version, false, false, false).await
+                };
+                if let Err(e) = installed {
+                    print_warning(format!("Could not install RabbitMQ {}: {}", version, e));
+                    failed.push(version.to_string());
+                    continue;
+                }
+                overwrite_config = true;
+            } else {
+                not_installed.push(version);
+                continue;
+            }
+        }
+
+        if src.is_dir() {
+            merge_dir(&src, &paths.version_dir(version), overwrite_config)?;
+        }
+    }
+
+    if failed.is_empty() {
+        print_success(format!(
+            "Imported the state exported by frm {} from {}",
+            manifest.frm_version,
+            opts.file.display()
+        ));
+    }
+
+    if !not_installed.is_empty() {
+        print_hint("These versions are not installed, install them with:");
+        for version in not_installed {
+            let group = if version.is_distributed_via_server_packages_repository() {
+                "alphas"
+            } else {
+                "releases"
+            };
+            println!("  frm {} install {}", group, version);
+        }
+        print_hint("then run 'frm import state' again to restore their configuration files");
+    }
+
+    if !failed.is_empty() {
+        print_hint("Run 'frm import state' again to retry installing them");
+        return Err(Error::StateImportIncomplete {
+            versions: failed.join(", "),
+        });
+    }
+
+    Ok(())
+}
+
+fn unpack<R: Read>(reader: R, dest: &Path) -> io::Result<()> {
+    let mut archive = Archive::new(reader);
+    archive.set_preserve_permissions(true);
+    archive.unpack(dest)
+}
+
+fn merge_file(src: &Path, dest: &Path, overwrite: bool) -> Result<()> {
+    if dest.exists() && !overwrite {
+        print_warning(format!(
+            "Keeping existing {} (use --force to overwrite it)",
+            dest.display()
+        ));
+        return Ok(());
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(src, dest)?;
+    Ok(())
+}
+
+fn merge_dir(src: &Path, dest: &Path, overwrite: bool) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let dest_path = dest.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            merge_dir(&entry.path(), &dest_path, overwrite)?;
+        } else if file_type.is_file() {
+            merge_file(&entry.path(), &dest_path, overwrite)?;
+        }
+    }
+    Ok(())
+}
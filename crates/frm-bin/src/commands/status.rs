@@ -10,8 +10,11 @@ use std::env;
 use std::io::{self, Write};
 
 use crate::Result;
-use crate::common::env_vars::RABBITMQ_HOME;
+use crate::commands::version_table::{OutputOptions, print_table};
+use crate::common::env_vars::{FRM_ACTIVE_SBIN, RABBITMQ_HOME};
 use crate::config::Config;
+use crate::denylist::Denylist;
+use crate::messages::print_warning;
 use crate::paths::Paths;
 use crate::version::Version;
 
@@ -21,6 +24,8 @@ pub struct Status {
     pub default: Option<Version>,
     pub releases: Vec<Version>,
     pub alphas: Vec<Version>,
+    /// Installed versions that are missing `sbin/rabbitmq-server`
+    pub broken: Vec<Version>,
 }
 
 impl Status {
@@ -30,6 +35,11 @@ impl Status {
         let default = config.default_version;
 
         let all_versions = paths.installed_versions()?;
+        let broken = all_versions
+            .iter()
+            .filter(|v| paths.version_broken(v))
+            .cloned()
+            .collect();
         let (alphas, releases): (Vec<_>, Vec<_>) = all_versions
             .into_iter()
             .partition(|v| v.is_distributed_via_server_packages_repository());
@@ -39,10 +49,12 @@ impl Status {
             default,
             releases,
             alphas,
+            broken,
         })
     }
 
-    pub fn format(&self) -> String {
+    /// The active and default versions, one per line
+    pub fn format_summary(&self) -> String {
         let mut out = String::new();
 
         match (&self.active, &self.default) {
@@ -62,6 +74,17 @@ impl Status {
             (None, None) => {}
         }
 
+        out
+    }
+
+    /// Installed versions, newest releases first, then alphas
+    pub fn installed(&self) -> impl Iterator<Item = &Version> {
+        self.releases.iter().rev().chain(self.alphas.iter().rev())
+    }
+
+    pub fn format(&self) -> String {
+        let mut out = self.format_summary();
+
         if self.releases.is_empty() && self.alphas.is_empty() {
             if out.is_empty() {
                 out.push_str("No RabbitMQ versions installed\n");
@@ -75,14 +98,13 @@ impl Status {
 
         out.push_str("Installed:\n\n");
 
-        for version in self.releases.iter().rev() {
+        for version in self.installed() {
             let marker = self.version_marker(version);
-            out.push_str(&format!("  {} {}\n", marker, version));
-        }
-
-        for version in self.alphas.iter().rev() {
-            let marker = self.version_marker(version);
-            out.push_str(&format!("  {} {}\n", marker, version));
+            if self.broken.contains(version) {
+                out.push_str(&format!("  {} {} (broken)\n", marker, version));
+            } else {
+                out.push_str(&format!("  {} {}\n", marker, version));
+            }
         }
 
         out
@@ -100,8 +122,13 @@ impl Status {
     }
 }
 
-fn detect_active_version(paths: &Paths) -> Option<Version> {
-    let rabbitmq_home = env::var(RABBITMQ_HOME).ok()?;
+pub(crate) fn detect_active_version(paths: &Paths) -> Option<Version> {
+    // With 'use --sbin-only', RABBITMQ_HOME is not set
+    let rabbitmq_home = env::var(RABBITMQ_HOME).ok().or_else(|| {
+        env::var(FRM_ACTIVE_SBIN)
+            .ok()
+            .and_then(|sbin| sbin.strip_suffix("/sbin").map(String::from))
+    })?;
     let versions_dir = paths.versions_dir();
     let versions_prefix = versions_dir.to_string_lossy();
 
@@ -116,9 +143,33 @@ fn detect_active_version(paths: &Paths) -> Option<Version> {
     version_str.parse().ok()
 }
 
-pub fn run(paths: &Paths) -> Result<()> {
+pub fn run(paths: &Paths, opts: OutputOptions) -> Result<()> {
     let status = Status::collect(paths)?;
-    let output = status.format();
-    io::stdout().write_all(output.as_bytes())?;
+    if opts.plain || (status.releases.is_empty() && status.alphas.is_empty()) {
+        io::stdout().write_all(status.format().as_bytes())?;
+    } else {
+        let summary = status.format_summary();
+        if !summary.is_empty() {
+            println!("{}", summary);
+        }
+        let installed: Vec<Version> = status.installed().cloned().collect();
+        print_table(
+            paths,
+            &installed,
+            status.active.as_ref(),
+            status.default.as_ref(),
+            opts,
+        )?;
+    }
+
+    let denylist = Denylist::load_cached(paths)?;
+    for version in status.installed() {
+        if let Some(denied) = denylist.find(version) {
+            print_warning(format!(
+                "RabbitMQ {} is denylisted: {}",
+                version, denied.reason
+            ));
+        }
+    }
     Ok(())
 }
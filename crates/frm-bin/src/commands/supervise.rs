@@ -0,0 +1,265 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! 'frm bg start --supervise': a detached frm process (`frm __supervise`) runs the node
+//! in the foreground and starts it again when it exits abnormally, waiting longer after
+//! every restart. A node that exits with code 0, e.g. after 'frm bg stop', is not restarted.
+//! The supervisor reports restarts in the run log, see 'frm bg logs'.
+
+use std::env;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+use tracing::debug;
+
+use crate::Result;
+use crate::commands::bg_start;
+use crate::common::env_vars::FRM_DIR;
+use crate::envs;
+use crate::errors::Error;
+use crate::node_state;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const SUPERVISOR_PID_FILE_NAME: &str = "supervisor.pid";
+/// A node that ran at least this long before it crashed is restarted as if it had not crashed before
+const STABLE_RUN: Duration = Duration::from_secs(600);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Starts a detached supervisor for the node of the version (or environment) and returns its PID
+pub(crate) fn spawn(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    max_restarts: u32,
+) -> Result<u32> {
+    bg_start::prepare(paths, version, env)?;
+    if let Some(pid) = running_supervisor(paths, version, env) {
+        return Err(Error::CommandFailed(format!(
+            "RabbitMQ {} already has a supervisor (PID {}), stop it with 'frm bg stop -V {}'",
+            version, pid, version
+        )));
+    }
+
+    let output = bg_start::create_run_log(
+        paths,
+        version,
+        env,
+        &format!(
+            "supervisor restarting the node up to {} times in a row",
+            max_restarts
+        ),
+    )?;
+
+    let exe = env::current_exe()?;
+    let mut cmd = Command::new(&exe);
+    cmd.args([
+        "__supervise",
+        "--version",
+        &version.to_string(),
+        "--max-restarts",
+        &max_restarts.to_string(),
+    ]);
+    if let Some(name) = env {
+        cmd.args(["--env", name]);
+    }
+    cmd.env(FRM_DIR, paths.base_dir())
+        .stdin(Stdio::null())
+        .stdout(Stdio::from(output.try_clone()?))
+        .stderr(Stdio::from(output));
+    platform::detach(&mut cmd);
+
+    debug!(path = %exe.display(), %version, env, "starting a node supervisor");
+    let child = cmd
+        .spawn()
+        .map_err(|e| Error::CommandFailed(format!("failed to execute {}: {}", exe.display(), e)))?;
+    Ok(child.id())
+}
+
+/// The supervisor itself: runs the node until it exits with code 0 or has crashed
+/// `max_restarts` times in a row. Its output goes to the run log.
+pub fn run(paths: &Paths, version: &Version, env: Option<&str>, max_restarts: u32) -> Result<()> {
+    let server_path = bg_start::prepare(paths, version, env)?;
+    let pid_file = pid_file(paths, version, env);
+    if let Some(dir) = pid_file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&pid_file, process::id().to_string())?;
+
+    let result = supervise(paths, version, env, &server_path, max_restarts);
+    let _ = fs::remove_file(&pid_file);
+    result
+}
+
+fn supervise(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+    server_path: &Path,
+    max_restarts: u32,
+) -> Result<()> {
+    let mut restarts = 0;
+    loop {
+        let started = Instant::now();
+        let status = bg_start::server_command(paths, version, env, server_path)?
+            .stdin(Stdio::null())
+            .status()
+            .map_err(|e| {
+                Error::CommandFailed(format!(
+                    "failed to execute {}: {}",
+                    server_path.display(),
+                    e
+                ))
+            })?;
+
+        if status.success() {
+            log("the node stopped, exiting");
+            return Ok(());
+        }
+        if started.elapsed() >= STABLE_RUN {
+            restarts = 0;
+        }
+        if restarts >= max_restarts {
+            log(format!(
+                "the node {}, giving up after {} restarts in a row",
+                describe(status),
+                restarts
+            ));
+            return Err(Error::CommandFailed(format!(
+                "RabbitMQ {} crashed {} times in a row",
+                version,
+                restarts + 1
+            )));
+        }
+
+        restarts += 1;
+        let delay = backoff(restarts);
+        log(format!(
+            "the node {}, restart {} of {} in {}s",
+            describe(status),
+            restarts,
+            max_restarts,
+            delay.as_secs()
+        ));
+        thread::sleep(delay);
+    }
+}
+
+/// 1s before the first restart, then twice as long before every next one, up to a minute
+fn backoff(restart: u32) -> Duration {
+    Duration::from_secs(1u64 << (restart - 1).min(6)).min(MAX_BACKOFF)
+}
+
+fn log(message: impl AsRef<str>) {
+    println!(
+        "# {} frm supervisor: {}",
+        Local::now().to_rfc3339(),
+        message.as_ref()
+    );
+}
+
+/// Stops the supervisors of the version and its environments, so that they do not restart
+/// nodes that are being stopped. Returns their PIDs.
+pub(crate) fn stop_all(paths: &Paths, version: &Version) -> Result<Vec<u32>> {
+    let mut envs: Vec<Option<String>> = vec![None];
+    envs.extend(envs::list(paths, version)?.into_iter().map(Some));
+
+    let mut stopped = Vec::new();
+    for env in envs {
+        stopped.extend(stop(paths, version, env.as_deref())?);
+    }
+    Ok(stopped)
+}
+
+/// Stops the supervisor of the version's own node, or of an environment's node.
+/// Returns its PID if it was running.
+pub(crate) fn stop(paths: &Paths, version: &Version, env: Option<&str>) -> Result<Option<u32>> {
+    let running = running_supervisor(paths, version, env);
+    if let Some(pid) = running {
+        platform::terminate(pid)?;
+    }
+    let _ = fs::remove_file(pid_file(paths, version, env));
+    Ok(running)
+}
+
+fn running_supervisor(paths: &Paths, version: &Version, env: Option<&str>) -> Option<u32> {
+    node_state::read_pid(&pid_file(paths, version, env))
+        .filter(|pid| node_state::process_alive(*pid))
+}
+
+fn pid_file(paths: &Paths, version: &Version, env: Option<&str>) -> PathBuf {
+    bg_start::run_log_file(paths, version, env).with_file_name(SUPERVISOR_PID_FILE_NAME)
+}
+
+#[cfg(unix)]
+fn describe(status: ExitStatus) -> String {
+    match (status.code(), status.signal()) {
+        (Some(code), _) => format!("exited with code {}", code),
+        (None, Some(signal)) => format!("was killed by signal {}", signal),
+        (None, None) => "exited".to_string(),
+    }
+}
+
+#[cfg(windows)]
+fn describe(status: ExitStatus) -> String {
+    format!("exited with code {}", status.code().unwrap_or(-1))
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::os::unix::process::CommandExt;
+    use std::process::Command;
+
+    use crate::Result;
+    use crate::node_state;
+
+    /// In its own process group, the supervisor does not get the terminal's Ctrl+C
+    pub fn detach(cmd: &mut Command) {
+        cmd.process_group(0);
+    }
+
+    pub fn terminate(pid: u32) -> Result<()> {
+        node_state::send_signal(pid, "TERM")
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::os::windows::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    use crate::Result;
+    use crate::errors::Error;
+
+    const DETACHED_PROCESS: u32 = 0x0000_0008;
+
+    pub fn detach(cmd: &mut Command) {
+        cmd.creation_flags(DETACHED_PROCESS);
+    }
+
+    pub fn terminate(pid: u32) -> Result<()> {
+        let status = Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .status()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::CommandFailed(format!(
+                "failed to terminate process {}",
+                pid
+            )))
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::Path;
+
+use crate::Result;
+use crate::commands::{
+    RunningNodes, conf_set_key, default, install_alpha, install_release, uninstall_alpha,
+    uninstall_release,
+};
+use crate::etc_backups;
+use crate::manifest::{Manifest, SyncAction, format_enabled_plugins};
+use crate::messages::{print_info, print_success};
+use crate::paths::Paths;
+
+pub async fn run(paths: &Paths, manifest_path: &Path, dry_run: bool) -> Result<()> {
+    let manifest = Manifest::load(manifest_path)?;
+    let actions = manifest.plan(paths)?;
+
+    if actions.is_empty() {
+        print_success(format!("Already in sync with {}", manifest_path.display()));
+        return Ok(());
+    }
+
+    if dry_run {
+        print_info(format!(
+            "{} change(s) needed to match {}:",
+            actions.len(),
+            manifest_path.display()
+        ));
+        for action in &actions {
+            println!("{}", action);
+        }
+        return Ok(());
+    }
+
+    for action in &actions {
+        apply(paths, action).await?;
+    }
+
+    print_success(format!(
+        "Synced with {}: {} change(s) made",
+        manifest_path.display(),
+        actions.len()
+    ));
+
+    Ok(())
+}
+
+async fn apply(paths: &Paths, action: &SyncAction) -> Result<()> {
+    match action {
+        SyncAction::Install(version) => {
+            if version.is_distributed_via_server_packages_repository() {
+                install_alpha(paths, version, false, false, false).await
+            } else {
+                install_release(paths, version, false, false, false).await
+            }
+        }
+        SyncAction::SetPlugins { version, plugins } => {
+            let etc_dir = paths.version_etc_dir(version);
+            fs::create_dir_all(&etc_dir)?;
+            etc_backups::replace(
+                &etc_dir.join("enabled_plugins"),
+                format_enabled_plugins(plugins).as_bytes(),
+            )?;
+            print_info(format!(
+                "enabled plugins for {}: {}",
+                version,
+                plugins.join(", ")
+            ));
+            Ok(())
+        }
+        SyncAction::SetConfKey {
+            version,
+            key,
+            value,
+        } => conf_set_key(paths, version, key, value, false),
+        SyncAction::SetDefault(version) => default(paths, version),
+        SyncAction::Uninstall(version) => {
+            if version.is_distributed_via_server_packages_repository() {
+                uninstall_alpha(paths, version, true, false, RunningNodes::Refuse)
+            } else {
+                uninstall_release(paths, version, true, false, RunningNodes::Refuse)
+            }
+        }
+    }
+}
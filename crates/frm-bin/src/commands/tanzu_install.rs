@@ -9,12 +9,14 @@
 use std::fs;
 use std::path::Path;
 
-use bel7_cli::{print_info, print_success};
-
 use crate::Result;
+use crate::commands::repair::fix_permissions;
 use crate::download::copy_default_config;
 use crate::errors::Error;
+use crate::messages::{Message, print_hint, print_info, print_success};
 use crate::paths::Paths;
+use crate::profile::{self, Phase};
+use crate::provenance::{self, Provenance};
 use crate::tanzu::{extract_tarball, extract_version_from_tarball_name, verify_extracted_version};
 use crate::timestamps::Timestamps;
 use crate::version::Version;
@@ -33,17 +35,14 @@ pub fn run(
         && &detected_version != expected_version
     {
         return Err(Error::TanzuVersionMismatch {
-            expected: expected_version.clone(),
-            detected: detected_version,
+            expected: Box::new(expected_version.clone()),
+            detected: Box::new(detected_version),
         });
     }
 
     if paths.version_installed(expected_version) {
         if force {
-            print_info(format!(
-                "Removing existing installation of {}",
-                expected_version
-            ));
+            print_info(Message::RemovingExistingInstallation(expected_version));
             fs::remove_dir_all(paths.version_dir(expected_version))?;
         } else {
             return Err(Error::VersionAlreadyInstalled(expected_version.clone()));
@@ -57,23 +56,42 @@ pub fn run(
         expected_version,
         tarball_path.display()
     ));
-    extract_tarball(tarball_path, expected_version, paths)?;
+    {
+        let _timer = profile::start(Phase::Extract);
+        extract_tarball(tarball_path, expected_version, paths)?;
+    }
 
-    print_info("Verifying extracted content");
-    verify_extracted_version(paths, expected_version)?;
+    print_info(Message::VerifyingExtractedContent);
+    {
+        let _timer = profile::start(Phase::Verify);
+        verify_extracted_version(paths, expected_version)?;
+    }
+
+    let _timer = profile::start(Phase::PostInstall);
+    fix_permissions(paths, expected_version)?;
 
-    print_info("Copying default configuration");
+    print_info(Message::CopyingDefaultConfig);
     copy_default_config(paths, expected_version)?;
 
     let mut timestamps = Timestamps::load(paths)?;
     timestamps.record(expected_version);
     timestamps.save(paths)?;
+    let source = fs::canonicalize(tarball_path)?;
+    Provenance::new(
+        source.display().to_string(),
+        source
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        provenance::sha256_file(&source)?,
+    )
+    .record(paths, expected_version)?;
 
     print_success(format!(
         "Tanzu RabbitMQ {} installed successfully",
         expected_version
     ));
-    print_info(format!(
+    print_hint(format!(
         "Activate with: eval \"$(frm tanzu use {})\"",
         expected_version
     ));
@@ -0,0 +1,54 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::messages::{print_info, print_success};
+use crate::paths::Paths;
+use crate::toolbox::{self, Tool};
+
+pub async fn install(paths: &Paths, tool: Tool, tool_version: Option<&str>) -> Result<()> {
+    if let Some(v) = tool_version
+        && toolbox::tool_path(paths, tool, v).is_file()
+    {
+        print_info(format!("{} {} is already installed", tool, v));
+        return Ok(());
+    }
+
+    print_info(format!("Downloading {}...", tool));
+    let (installed_version, path) = toolbox::install(paths, tool, tool_version).await?;
+    print_success(format!(
+        "Installed {} {} to {}",
+        tool,
+        installed_version,
+        path.display()
+    ));
+
+    Ok(())
+}
+
+pub fn list(paths: &Paths) -> Result<()> {
+    let mut any = false;
+    for &tool in Tool::ALL {
+        for v in toolbox::installed_versions(paths, tool)? {
+            println!("{} {}", tool, v);
+            any = true;
+        }
+    }
+
+    if !any {
+        print_info("No tools installed");
+    }
+
+    Ok(())
+}
+
+pub fn uninstall(paths: &Paths, tool: Tool, tool_version: &str) -> Result<()> {
+    toolbox::uninstall(paths, tool, tool_version)?;
+    print_success(format!("Uninstalled {} {}", tool, tool_version));
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Local};
+
+use crate::Result;
+use crate::commands::parse_datetime;
+use crate::common::prompt::confirm_destructive;
+use crate::errors::Error;
+use crate::messages::{print_info, print_success};
+use crate::paths::Paths;
+use crate::trash;
+use crate::version::Version;
+
+pub fn list(paths: &Paths) -> Result<()> {
+    let entries = trash::list(paths)?;
+    if entries.is_empty() {
+        print_info("Trash is empty");
+        return Ok(());
+    }
+
+    for entry in entries {
+        let trashed_at = DateTime::from_timestamp(entry.trashed_at as i64, 0)
+            .map(|dt| {
+                dt.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        println!("{} (uninstalled {})", entry.version, trashed_at);
+    }
+
+    Ok(())
+}
+
+pub fn restore(paths: &Paths, version: &Version) -> Result<()> {
+    let entry = trash::find(paths, version)?.ok_or_else(|| Error::NotInTrash(version.clone()))?;
+
+    trash::restore(paths, &entry)?;
+
+    print_success(format!("Restored RabbitMQ {}", version));
+
+    Ok(())
+}
+
+pub fn empty(paths: &Paths, older_than: Option<&str>, assume_yes: bool) -> Result<()> {
+    let cutoff = match older_than {
+        Some(s) => Some(parse_datetime(s)?.timestamp() as u64),
+        None => None,
+    };
+
+    let to_delete: Vec<_> = trash::list(paths)?
+        .into_iter()
+        .filter(|e| cutoff.is_none_or(|ts| e.trashed_at < ts))
+        .collect();
+
+    if to_delete.is_empty() {
+        print_info("Nothing to remove from the trash");
+        return Ok(());
+    }
+
+    let question = format!("Permanently delete {} trashed version(s)?", to_delete.len());
+    if !confirm_destructive(paths, assume_yes, &question)? {
+        return Ok(());
+    }
+
+    for entry in &to_delete {
+        trash::delete(entry)?;
+    }
+
+    print_success(format!(
+        "Permanently deleted {} trashed version(s)",
+        to_delete.len()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::env;
+use std::error;
+use std::io;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use rabbitmq_conf::RabbitMQConf;
+
+use crate::Result;
+use crate::common::env_vars::BROWSER;
+use crate::common::http::USER_AGENT;
+use crate::envs;
+use crate::errors::Error;
+use crate::messages::{print_info, print_success};
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub const DEFAULT_MANAGEMENT_PORT: u16 = 15672;
+
+const TCP_PORT_KEY: &str = "management.tcp.port";
+const SSL_PORT_KEY: &str = "management.ssl.port";
+const PATH_PREFIX_KEY: &str = "management.path_prefix";
+const DEFAULT_USER_KEY: &str = "default_user";
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Where the management UI of a version listens according to its rabbitmq.conf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagementListener {
+    pub url: String,
+    /// `default_user` from rabbitmq.conf, if set; `guest` is used otherwise
+    pub default_user: Option<String>,
+}
+
+impl ManagementListener {
+    pub fn resolve(paths: &Paths, version: &Version, env: Option<&str>) -> Result<Self> {
+        let etc_dir = match env {
+            Some(name) => paths.version_env_etc_dir(version, name),
+            None => paths.version_etc_dir(version),
+        };
+        let conf_path = etc_dir.join("rabbitmq.conf");
+        let conf = if conf_path.exists() {
+            Some(RabbitMQConf::load(&conf_path).map_err(|e| Error::Config(e.to_string()))?)
+        } else {
+            None
+        };
+        let get = |key: &str| conf.as_ref().and_then(|c| c.get(key));
+
+        let port = |key: &str| -> Result<Option<u16>> {
+            get(key)
+                .map(|value| {
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| Error::Config(format!("invalid {} value: {}", key, value)))
+                })
+                .transpose()
+        };
+        // the HTTP listener is preferred when both are configured
+        let (scheme, port) = match (port(TCP_PORT_KEY)?, port(SSL_PORT_KEY)?) {
+            (Some(port), _) => ("http", port),
+            (None, Some(port)) => ("https", port),
+            (None, None) => ("http", DEFAULT_MANAGEMENT_PORT),
+        };
+        let prefix = get(PATH_PREFIX_KEY)
+            .map(|p| p.trim().trim_matches('/'))
+            .filter(|p| !p.is_empty())
+            .map(|p| format!("{}/", p))
+            .unwrap_or_default();
+
+        Ok(Self {
+            url: format!("{}://localhost:{}/{}", scheme, port, prefix),
+            default_user: get(DEFAULT_USER_KEY).map(str::to_string),
+        })
+    }
+
+    /// The management UI logs in with the credentials in a `#/login/<user>/<password>` fragment
+    pub fn guest_login_url(&self) -> Option<String> {
+        match self.default_user {
+            None => Some(format!("{}#/login/guest/guest", self.url)),
+            Some(_) => None,
+        }
+    }
+}
+
+pub async fn open(paths: &Paths, version: &Version, env: Option<&str>, print: bool) -> Result<()> {
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if let Some(name) = env {
+        envs::ensure_exists(paths, version, name)?;
+    }
+
+    let listener = ManagementListener::resolve(paths, version, env)?;
+    check_responding(&listener.url).await?;
+
+    if print {
+        println!("{}", listener.url);
+    } else {
+        open_in_browser(&listener.url).map_err(|e| {
+            Error::Config(format!(
+                "could not open a browser ({}), the management UI is at {}",
+                e, listener.url
+            ))
+        })?;
+        print_success(format!("Opened {}", listener.url));
+    }
+
+    match (&listener.default_user, listener.guest_login_url()) {
+        (Some(user), _) => print_info(format!(
+            "Log in as '{}' (default_user in rabbitmq.conf)",
+            user
+        )),
+        (None, Some(login_url)) => print_info(format!(
+            "Log in as guest/guest, or use {} to log in right away",
+            login_url
+        )),
+        (None, None) => {}
+    }
+
+    Ok(())
+}
+
+/// Any HTTP response means the node is up: the UI is served without authentication
+async fn check_responding(url: &str) -> Result<()> {
+    let unavailable = |reason: String| Error::ManagementUnavailable {
+        url: url.to_string(),
+        reason,
+    };
+    // local nodes commonly use self-signed certificates
+    let client = reqwest::Client::builder()
+        .timeout(CHECK_TIMEOUT)
+        .tls_danger_accept_invalid_certs(true)
+        .build()?;
+    client
+        .get(url)
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .await
+        .map_err(|e| unavailable(root_cause(&e)))?;
+    Ok(())
+}
+
+/// reqwest errors only say "error sending request", the cause is at the end of the chain
+fn root_cause(e: &dyn error::Error) -> String {
+    let mut cause = e;
+    while let Some(source) = cause.source() {
+        cause = source;
+    }
+    cause.to_string()
+}
+
+/// Uses `$BROWSER` if set, otherwise the platform's default
+fn open_in_browser(url: &str) -> io::Result<()> {
+    let mut cmd = match env::var(BROWSER) {
+        Ok(browser) if !browser.trim().is_empty() => Command::new(browser.trim()),
+        _ if cfg!(target_os = "macos") => Command::new("open"),
+        _ if cfg!(windows) => {
+            let mut cmd = Command::new("cmd");
+            cmd.args(["/C", "start", ""]);
+            cmd
+        }
+        _ => Command::new("xdg-open"),
+    };
+    let status = cmd.arg(url).stdin(Stdio::null()).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "the browser exited with {}",
+            status
+        )))
+    }
+}
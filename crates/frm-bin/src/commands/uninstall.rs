@@ -8,60 +8,157 @@
 
 use std::fs;
 
-use bel7_cli::{print_info, print_success};
-
 use crate::Result;
+use crate::commands::{bg_stop, default, supervise};
+use crate::common::prompt::{confirm, confirm_destructive};
 use crate::config::Config;
+use crate::current;
 use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::node_state;
 use crate::paths::Paths;
-use crate::timestamps::Timestamps;
+use crate::trash;
 use crate::version::Version;
 
-pub fn run_release(paths: &Paths, version: &Version) -> Result<()> {
+/// What to do when a node of the version being uninstalled is running
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RunningNodes {
+    /// Refuse to uninstall
+    #[default]
+    Refuse,
+    /// Stop them first
+    Stop,
+    /// Uninstall anyway, leaving them running without their files
+    Ignore,
+}
+
+/// With `promote_next`, the highest remaining GA version becomes the default if the uninstalled
+/// version was the default. Without it, that is offered when run interactively.
+pub fn run_release(
+    paths: &Paths,
+    version: &Version,
+    assume_yes: bool,
+    promote_next: bool,
+    running: RunningNodes,
+) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedNonAlphaVersion(version.clone()));
     }
-    run(paths, version)
+    run(paths, version, assume_yes, promote_next, running)
 }
 
-pub fn run_alpha(paths: &Paths, version: &Version) -> Result<()> {
+pub fn run_alpha(
+    paths: &Paths,
+    version: &Version,
+    assume_yes: bool,
+    promote_next: bool,
+    running: RunningNodes,
+) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ExpectedAlphaVersion(version.clone()));
     }
-    run(paths, version)
+    run(paths, version, assume_yes, promote_next, running)
 }
 
-fn run(paths: &Paths, version: &Version) -> Result<()> {
+fn run(
+    paths: &Paths,
+    version: &Version,
+    assume_yes: bool,
+    promote_next: bool,
+    running: RunningNodes,
+) -> Result<()> {
     if !paths.version_installed(version) {
         return Err(Error::VersionNotInstalled(version.clone()));
     }
+    if paths.version_linked(version) {
+        return Err(Error::VersionIsLinked(version.clone()));
+    }
+    if paths.version_shared(version) {
+        return Err(Error::VersionIsShared(version.clone()));
+    }
 
-    let version_dir = paths.version_dir(version);
-    fs::remove_dir_all(&version_dir)?;
+    // the Erlang VM of a running node would be left behind without its files
+    let nodes = node_state::running_nodes(paths, version)?;
+    if !nodes.is_empty() && running == RunningNodes::Refuse {
+        print_hint("Use --stop to stop it as part of uninstalling");
+        return Err(Error::NodeRunning(version.clone()));
+    }
+
+    let question = format!("Uninstall RabbitMQ {}?", version);
+    if !confirm_destructive(paths, assume_yes, &question)? {
+        return Ok(());
+    }
+
+    if running == RunningNodes::Stop {
+        supervise::stop_all(paths, version)?;
+    }
+    for node in &nodes {
+        match running {
+            RunningNodes::Stop => {
+                bg_stop::shutdown(paths, version, Some(&node.name))?;
+                print_info(format!("Stopped node {}", node.name));
+            }
+            _ => print_warning(format!(
+                "Node {} (OS process {}) keeps running, uninstalling anyway because of --force",
+                node.name, node.pid
+            )),
+        }
+    }
+
+    trash::move_to_trash(paths, version)?;
 
     let mut config = Config::load(paths)?;
     if config.default_version.as_ref() == Some(version) {
-        config.clear_default();
-        config.save(paths)?;
+        let next = next_default(paths)?;
+        let promote = match &next {
+            Some(next) if promote_next => true,
+            // --yes answers the uninstall question, not this one
+            Some(next) if !assume_yes => confirm(&format!(
+                "RabbitMQ {} was the default. Make RabbitMQ {} the default?",
+                version, next
+            ))?,
+            _ => false,
+        };
 
-        let default_file = paths.default_file();
-        if default_file.exists() {
-            fs::remove_file(default_file)?;
-        }
+        match next {
+            Some(next) if promote => default::run(paths, &next)?,
+            _ => {
+                config.clear_default();
+                config.save(paths)?;
+
+                let default_file = paths.default_file();
+                if default_file.exists() {
+                    fs::remove_file(default_file)?;
+                }
 
-        print_info("Cleared default version (uninstalled version was the default)");
+                current::remove(paths)?;
+                print_info("Cleared default version (uninstalled version was the default)");
+                if promote_next {
+                    print_info("No other GA version is installed to make the default");
+                }
+            }
+        }
     }
 
-    let archive = paths.downloads_dir().join(version.archive_name());
+    let archive = paths.version_archive_file(version);
     if archive.exists() {
         fs::remove_file(archive)?;
     }
 
-    let mut timestamps = Timestamps::load(paths)?;
-    timestamps.remove(version);
-    timestamps.save(paths)?;
-
     print_success(format!("RabbitMQ {} uninstalled", version));
+    print_hint(format!(
+        "Moved to trash; restore it with 'frm trash restore {}'",
+        version
+    ));
 
     Ok(())
 }
+
+/// The highest installed GA version that is not broken
+fn next_default(paths: &Paths) -> Result<Option<Version>> {
+    Ok(paths
+        .installed_versions()?
+        .into_iter()
+        .rev()
+        .find(|v| v.is_ga() && !paths.version_broken(v)))
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::iter;
+
+use crate::Result;
+use crate::errors::Error;
+use crate::messages::{print_info, print_success, print_warning};
+use crate::version::{Series, Version, upgrade_path};
+
+pub fn run(from: &Version, to: &Version) -> Result<()> {
+    let path = upgrade_path(from, to).ok_or_else(|| Error::UnsupportedUpgrade {
+        from: Box::new(from.clone()),
+        to: Box::new(to.clone()),
+    })?;
+
+    if path.is_empty() {
+        print_success(format!(
+            "{} can be upgraded to {} directly (same release series)",
+            from, to
+        ));
+        return Ok(());
+    }
+
+    print_info(format!(
+        "Upgrade path from {} to {}: {}",
+        from,
+        to,
+        format_path(Series::from(from), &path)
+    ));
+
+    if path.len() == 1 {
+        print_success(format!("{} can be upgraded to {} directly", from, to));
+    } else {
+        let intermediate: Vec<String> = path[..path.len() - 1]
+            .iter()
+            .map(|s| format!("{}.x", s))
+            .collect();
+        print_warning(format!(
+            "Upgrading from {} to {} directly is not supported: upgrade to the latest {} first, in this order",
+            from,
+            to,
+            intermediate.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn format_path(from: Series, path: &[Series]) -> String {
+    iter::once(from)
+        .chain(path.iter().copied())
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+        .join(" → ")
+}
@@ -7,16 +7,38 @@
 // except according to those terms.
 
 use crate::Result;
+use crate::commands::auto_install::{AutoInstall, ensure_installed};
+use crate::commands::link::link_dir;
+use crate::current;
 use crate::errors::Error;
 use crate::paths::Paths;
+use crate::providers::Provider;
+use crate::shared;
 use crate::shell::Shell;
 use crate::version::Version;
 
-pub fn run_release(paths: &Paths, version: &Version, shell: Option<Shell>) -> Result<()> {
+pub fn run_release(
+    paths: &Paths,
+    version: &Version,
+    shell: Option<Shell>,
+    sbin_only: bool,
+    provider: Option<Provider>,
+    auto_install: AutoInstall,
+    link_current: bool,
+) -> Result<()> {
     if version.is_distributed_via_server_packages_repository() {
         return Err(Error::AlphaVersionNotSupported);
     }
 
+    if !paths.version_installed(version)
+        && let Some(provider) = provider
+    {
+        link_from_provider(paths, version, provider)?;
+    }
+    if auto_install != AutoInstall::Never {
+        ensure_installed(paths, version, auto_install)?;
+    }
+
     if !paths.version_installed(version) {
         let versions = paths.installed_versions()?;
 
@@ -35,16 +57,33 @@ pub fn run_release(paths: &Paths, version: &Version, shell: Option<Shell>) -> Re
         return Err(Error::VersionNotInstalled(version.clone()));
     }
 
+    warn_if_broken(paths, version);
+    shared::prepare(paths, version)?;
+
+    if link_current {
+        point_current_link(paths, version)?;
+    }
+
     let shell = shell.or_else(Shell::detect).unwrap_or(Shell::Bash);
-    print!("{}", shell.env_script(paths, version));
+    print!("{}", env_script(shell, paths, version, sbin_only));
 
     Ok(())
 }
 
-pub fn run_alpha(paths: &Paths, version: &Version, shell: Option<Shell>) -> Result<()> {
+pub fn run_alpha(
+    paths: &Paths,
+    version: &Version,
+    shell: Option<Shell>,
+    sbin_only: bool,
+    auto_install: AutoInstall,
+    link_current: bool,
+) -> Result<()> {
     if !version.is_distributed_via_server_packages_repository() {
         return Err(Error::ReleaseVersionNotSupported);
     }
+    if auto_install != AutoInstall::Never {
+        ensure_installed(paths, version, auto_install)?;
+    }
 
     if !paths.version_installed(version) {
         let versions = paths.installed_alpha_versions()?;
@@ -64,8 +103,73 @@ pub fn run_alpha(paths: &Paths, version: &Version, shell: Option<Shell>) -> Resu
         return Err(Error::VersionNotInstalled(version.clone()));
     }
 
+    warn_if_broken(paths, version);
+
+    if link_current {
+        point_current_link(paths, version)?;
+    }
+
     let shell = shell.or_else(Shell::detect).unwrap_or(Shell::Bash);
-    print!("{}", shell.env_script(paths, version));
+    print!("{}", env_script(shell, paths, version, sbin_only));
 
     Ok(())
 }
+
+// Standard output is evaluated by the shell, so the note goes to standard error
+fn point_current_link(paths: &Paths, version: &Version) -> Result<()> {
+    current::update(paths, version)?;
+    eprintln!(
+        "{} now points at RabbitMQ {}",
+        paths.current_link().display(),
+        version
+    );
+    Ok(())
+}
+
+fn env_script(shell: Shell, paths: &Paths, version: &Version, sbin_only: bool) -> String {
+    if sbin_only {
+        shell.sbin_only_env_script(paths, version)
+    } else {
+        shell.env_script(paths, version)
+    }
+}
+
+// Standard output is evaluated by the shell, so notes go to standard error
+fn link_from_provider(paths: &Paths, version: &Version, provider: Provider) -> Result<()> {
+    let Some(dir) = provider.version_dir(version) else {
+        let versions = provider.installed_versions()?;
+        if !versions.is_empty() {
+            eprintln!("Versions installed by {}:", provider);
+            for v in &versions {
+                eprintln!("  {}", v);
+            }
+        }
+        return Err(Error::NotInstalledByProvider {
+            provider: provider.to_string(),
+            version: version.clone(),
+        });
+    };
+
+    link_dir(paths, &dir, version)?;
+    eprintln!(
+        "Using RabbitMQ {} installed by {} ({}); remove it from frm with 'frm unlink {}'",
+        version,
+        provider,
+        dir.display(),
+        version
+    );
+    Ok(())
+}
+
+// Standard output is evaluated by the shell, so warnings go to standard error
+fn warn_if_broken(paths: &Paths, version: &Version) {
+    if paths.version_broken(version) {
+        eprintln!(
+            "Warning: RabbitMQ {} is broken: {} is missing",
+            version,
+            paths.version_server_script(version).display()
+        );
+        eprintln!("Repair it with:");
+        eprintln!("  frm repair {}", version);
+    }
+}
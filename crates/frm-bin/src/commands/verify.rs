@@ -0,0 +1,221 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::common::cli_tools::{RABBITMQ_DIAGNOSTICS, RABBITMQ_PLUGINS, RABBITMQCTL};
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success, print_warning};
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::permissions;
+use crate::provenance;
+use crate::version::Version;
+
+/// Scripts every installation has in addition to `rabbitmq-server`
+const REQUIRED_SCRIPTS: &[&str] = &[RABBITMQCTL, RABBITMQ_PLUGINS, RABBITMQ_DIAGNOSTICS];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Passed(String),
+    Failed(String),
+    /// There is nothing to check against, e.g. the archive is no longer cached
+    Skipped(String),
+}
+
+impl Check {
+    fn failed(&self) -> bool {
+        matches!(self, Check::Failed(_))
+    }
+
+    fn cell(&self) -> String {
+        match self {
+            Check::Passed(detail) => detail.clone(),
+            Check::Failed(detail) => format!("FAILED: {}", detail),
+            Check::Skipped(detail) => format!("skipped: {}", detail),
+        }
+    }
+}
+
+/// The results of verifying one installation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verification {
+    pub version: Version,
+    /// The scripts in sbin are present and executable
+    pub layout: Check,
+    /// The cached archive matches the checksum recorded when the version was installed
+    pub checksum: Check,
+    /// The result of the most recent 'releases check-signature'
+    pub signature: Check,
+}
+
+impl Verification {
+    pub fn collect(paths: &Paths, version: &Version) -> Self {
+        Self {
+            version: version.clone(),
+            layout: layout(paths, version),
+            checksum: checksum(paths, version),
+            signature: signature(paths, version),
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.layout.failed() || self.checksum.failed() || self.signature.failed()
+    }
+}
+
+#[derive(Tabled)]
+struct VerificationRow {
+    #[tabled(rename = "Version")]
+    version: String,
+    #[tabled(rename = "Layout")]
+    layout: String,
+    #[tabled(rename = "Archive Checksum")]
+    checksum: String,
+    #[tabled(rename = "Signature")]
+    signature: String,
+    #[tabled(rename = "Result")]
+    result: &'static str,
+}
+
+/// Verifies the versions, `jobs` at a time, and fails if any of them does not verify
+pub fn run(paths: &Paths, versions: &[Version], jobs: usize) -> Result<()> {
+    for version in versions {
+        if !paths.version_installed(version) {
+            return Err(Error::VersionNotInstalled(version.clone()));
+        }
+    }
+    if versions.is_empty() {
+        print_warning("No versions installed");
+        return Ok(());
+    }
+
+    let jobs = jobs.clamp(1, versions.len());
+    if versions.len() > 1 {
+        print_info(format!(
+            "Verifying {} installation(s), {} at a time",
+            versions.len(),
+            jobs
+        ));
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Verification>> = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| {
+                while let Some(version) = versions.get(next.fetch_add(1, Ordering::SeqCst)) {
+                    let verification = Verification::collect(paths, version);
+                    results.lock().unwrap().push(verification);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.version.cmp(&b.version));
+    println!(
+        "{}",
+        Table::new(results.iter().map(row)).with(Style::rounded())
+    );
+
+    let failed = results.iter().filter(|v| v.failed()).count();
+    if failed == 0 {
+        print_success(format!("All {} installation(s) verified", results.len()));
+        return Ok(());
+    }
+    if results
+        .iter()
+        .any(|v| v.layout.failed() || v.checksum.failed())
+    {
+        print_hint("Use 'frm repair <version>' to re-download a damaged installation");
+    }
+    Err(Error::VerificationFailed {
+        failed,
+        total: results.len(),
+    })
+}
+
+fn row(verification: &Verification) -> VerificationRow {
+    VerificationRow {
+        version: verification.version.to_string(),
+        layout: verification.layout.cell(),
+        checksum: verification.checksum.cell(),
+        signature: verification.signature.cell(),
+        result: if verification.failed() {
+            "FAILED"
+        } else {
+            "OK"
+        },
+    }
+}
+
+fn layout(paths: &Paths, version: &Version) -> Check {
+    if paths.version_broken(version) {
+        return Check::Failed("sbin/rabbitmq-server is missing".to_string());
+    }
+    let sbin = paths.version_sbin_dir(version);
+    let missing: Vec<&str> = REQUIRED_SCRIPTS
+        .iter()
+        .copied()
+        .filter(|script| !sbin.join(script).is_file())
+        .collect();
+    if !missing.is_empty() {
+        return Check::Failed(format!("missing {}", missing.join(", ")));
+    }
+    match permissions::non_executable_files(&paths.version_dir(version)) {
+        Ok(files) if files.is_empty() => Check::Passed("complete".to_string()),
+        Ok(files) => Check::Failed(format!("{} file(s) not executable", files.len())),
+        Err(e) => Check::Failed(e.to_string()),
+    }
+}
+
+fn checksum(paths: &Paths, version: &Version) -> Check {
+    let record = match provenance::Provenance::load(paths, version) {
+        Ok(Some(record)) => record,
+        Ok(None) => return Check::Skipped("no provenance record".to_string()),
+        Err(e) => return Check::Failed(e.to_string()),
+    };
+    let Some(archive) = cached_archive(paths, &record) else {
+        return Check::Skipped("archive not cached".to_string());
+    };
+    match provenance::sha256_file(&archive) {
+        Ok(sha256) if sha256 == record.sha256 => Check::Passed("matches".to_string()),
+        Ok(_) => Check::Failed(format!("{} does not match", archive.display())),
+        Err(e) => Check::Failed(e.to_string()),
+    }
+}
+
+/// The archive in the downloads directory, or the local file the version was installed from
+fn cached_archive(paths: &Paths, record: &provenance::Provenance) -> Option<PathBuf> {
+    [
+        paths.downloads_dir().join(&record.file_name),
+        PathBuf::from(&record.source),
+    ]
+    .into_iter()
+    .find(|path| Path::is_file(path))
+}
+
+fn signature(paths: &Paths, version: &Version) -> Check {
+    if version.is_distributed_via_server_packages_repository() {
+        return Check::Skipped("not signed".to_string());
+    }
+    match VersionMetadata::load(paths, version).map(|m| m.signature) {
+        Ok(Some(check)) if check.verified => Check::Passed("verified".to_string()),
+        Ok(Some(_)) => Check::Failed("did not verify".to_string()),
+        Ok(None) => Check::Skipped("not checked".to_string()),
+        Err(e) => Check::Failed(e.to_string()),
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, IsTerminal};
+use std::iter;
+
+use bel7_cli::{should_colorize, terminal_width};
+use chrono::{DateTime, Local};
+use tabled::settings::object::Cell;
+use tabled::settings::peaker::Priority;
+use tabled::settings::{Color, Style, Width};
+use tabled::{Table, Tabled};
+
+use crate::Result;
+use crate::common::erlang::supported_erlang_versions;
+use crate::common::fs_utils::{dir_size, format_size};
+use crate::denylist::Denylist;
+use crate::metadata::VersionMetadata;
+use crate::node_state;
+use crate::paths::Paths;
+use crate::version::Version;
+
+const FLAGS_COLUMN: usize = 5;
+const STATE_COLUMN: usize = 6;
+
+/// How installed versions are displayed by 'list' and 'status'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// One version per line, without a table
+    pub plain: bool,
+    pub color: bool,
+    /// Include the result of the most recent signature check
+    pub verbose: bool,
+}
+
+#[derive(Tabled)]
+pub struct VersionRow {
+    #[tabled(rename = "Version")]
+    pub version: String,
+    #[tabled(rename = "Flavor")]
+    pub flavor: String,
+    #[tabled(rename = "Erlang")]
+    pub erlang: String,
+    #[tabled(rename = "Size")]
+    pub size: String,
+    #[tabled(rename = "Installed")]
+    pub installed: String,
+    #[tabled(rename = "Flags")]
+    pub flags: String,
+    #[tabled(rename = "State")]
+    pub state: String,
+    /// Only shown with `--verbose`
+    #[tabled(skip)]
+    pub signature: String,
+}
+
+impl VersionRow {
+    pub fn collect(
+        paths: &Paths,
+        version: &Version,
+        active: Option<&Version>,
+        default: Option<&Version>,
+        denylist: &Denylist,
+    ) -> Result<Self> {
+        let metadata = VersionMetadata::load(paths, version)?;
+        let broken = paths.version_broken(version);
+
+        let mut flags = Vec::new();
+        if active == Some(version) {
+            flags.push("active");
+        }
+        if default == Some(version) {
+            flags.push("default");
+        }
+        if metadata.pinned {
+            flags.push("pinned");
+        }
+        if paths.version_linked(version) {
+            flags.push("linked");
+        }
+        if broken {
+            flags.push("broken");
+        }
+        if denylist.find(version).is_some() {
+            flags.push("denylisted");
+        }
+
+        let state = if broken {
+            "-"
+        } else if node_state::is_running(paths, version)? {
+            "running"
+        } else {
+            "stopped"
+        };
+
+        Ok(Self {
+            version: version.to_string(),
+            flavor: flavor(version).to_string(),
+            erlang: supported_erlang_versions(version)
+                .map(|(min, max)| format!("{} - {}", min, max))
+                .unwrap_or_else(|| "-".to_string()),
+            size: dir_size(&paths.version_dir(version))
+                .map(format_size)
+                .unwrap_or_else(|_| "-".to_string()),
+            installed: metadata
+                .installed_at
+                .and_then(|ts| DateTime::from_timestamp(ts as i64, 0))
+                .map(|dt| dt.with_timezone(&Local).format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            flags: flags.join(", "),
+            state: state.to_string(),
+            signature: signature_status(&metadata).to_string(),
+        })
+    }
+}
+
+/// ✓ or ✗ for the most recent 'releases check-signature', or "unknown" when there was none
+pub fn signature_status(metadata: &VersionMetadata) -> &'static str {
+    match metadata.signature {
+        Some(check) if check.verified => "✓",
+        Some(_) => "✗",
+        None => "unknown",
+    }
+}
+
+pub fn flavor(version: &Version) -> &'static str {
+    if version.is_alpha() {
+        "alpha"
+    } else if version.is_beta() {
+        "beta"
+    } else if version.is_rc() {
+        "rc"
+    } else {
+        "GA"
+    }
+}
+
+pub fn render(rows: Vec<VersionRow>, color: bool, verbose: bool) -> String {
+    let colors: Vec<(Option<Color>, Option<Color>)> = rows
+        .iter()
+        .map(|row| {
+            let flags = if row.flags.contains("broken") || row.flags.contains("denylisted") {
+                Some(Color::FG_RED)
+            } else if row.flags.contains("active") {
+                Some(Color::FG_GREEN)
+            } else {
+                None
+            };
+            let state = (row.state == "running").then_some(Color::FG_GREEN);
+            (flags, state)
+        })
+        .collect();
+
+    let signatures: Vec<String> = rows.iter().map(|row| row.signature.clone()).collect();
+    let mut builder = Table::builder(rows);
+    if verbose {
+        // the last column, so that the colored columns keep their positions
+        builder.push_column(iter::once("Signature".to_string()).chain(signatures));
+    }
+    let mut table = builder.build();
+    table.with(Style::rounded());
+
+    if color && should_colorize() {
+        // Row 0 is the header
+        for (i, (flags, state)) in colors.into_iter().enumerate() {
+            if let Some(c) = flags {
+                table.modify(Cell::new(i + 1, FLAGS_COLUMN), c);
+            }
+            if let Some(c) = state {
+                table.modify(Cell::new(i + 1, STATE_COLUMN), c);
+            }
+        }
+    }
+
+    if io::stdout().is_terminal() {
+        table.with(
+            Width::truncate(terminal_width())
+                .suffix("…")
+                .priority(Priority::max(true)),
+        );
+    }
+
+    table.to_string()
+}
+
+pub fn print_table(
+    paths: &Paths,
+    versions: &[Version],
+    active: Option<&Version>,
+    default: Option<&Version>,
+    opts: OutputOptions,
+) -> Result<()> {
+    let denylist = Denylist::load_cached(paths)?;
+    let rows = versions
+        .iter()
+        .map(|v| VersionRow::collect(paths, v, active, default, &denylist))
+        .collect::<Result<Vec<_>>>()?;
+    println!("{}", render(rows, opts.color, opts.verbose));
+    Ok(())
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Reverse;
+use std::fs;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::Result;
+use crate::commands::{RunningNodes, install_alpha, uninstall_alpha};
+use crate::common::env_vars::{FRM_ALPHA_PUBLISHED_AT, FRM_ALPHA_TAG, FRM_ALPHA_VERSION};
+use crate::errors::Error;
+use crate::messages::{print_info, print_success, print_warning};
+use crate::metadata::VersionMetadata;
+use crate::node_state;
+use crate::paths::Paths;
+use crate::releases::{AlphaRelease, find_latest_alpha};
+use crate::version::Version;
+
+pub struct WatchOptions<'a> {
+    /// Check once and exit, e.g. when run from cron
+    pub once: bool,
+    pub interval: Duration,
+    /// Shell command to run when a new alpha appears
+    pub exec: Option<&'a str>,
+    pub install: bool,
+    /// Number of installed alphas to keep after installing a new one
+    pub keep: Option<usize>,
+}
+
+pub async fn run(paths: &Paths, opts: &WatchOptions<'_>) -> Result<()> {
+    let client = reqwest::Client::new();
+
+    if !opts.once {
+        print_info(format!(
+            "Checking for new alpha releases every {} minute(s), press Ctrl+C to stop",
+            opts.interval.as_secs() / 60
+        ));
+    }
+
+    loop {
+        let checked = check(paths, &client, opts).await;
+        if opts.once {
+            return checked;
+        }
+        if let Err(e) = checked {
+            print_warning(format!("Check failed: {}", e));
+        }
+        tokio::time::sleep(opts.interval).await;
+    }
+}
+
+async fn check(paths: &Paths, client: &reqwest::Client, opts: &WatchOptions<'_>) -> Result<()> {
+    let latest = find_latest_alpha(client, paths).await?;
+    let version = &latest.version;
+
+    if !is_new(paths, version) {
+        print_info(format!("No new alpha releases (latest: {})", version));
+        return Ok(());
+    }
+
+    print_success(format!(
+        "New alpha release: {} (published at {})",
+        version, latest.published_at
+    ));
+
+    if opts.install {
+        install_alpha(paths, version, false, false, false).await?;
+        if let Some(keep) = opts.keep {
+            for old in rotation_candidates(paths, keep)? {
+                if node_state::is_running(paths, &old)? {
+                    print_warning(format!("Keeping RabbitMQ {}, it has a running node", old));
+                    continue;
+                }
+                uninstall_alpha(paths, &old, true, false, RunningNodes::Refuse)?;
+            }
+        }
+    }
+    if let Some(command) = opts.exec {
+        run_hook(command, &latest)?;
+    }
+
+    record_seen(paths, version)
+}
+
+/// An alpha is new when it is not installed and was not reported by an earlier check
+pub fn is_new(paths: &Paths, version: &Version) -> bool {
+    if paths.version_installed(version) {
+        return false;
+    }
+    last_seen(paths).as_ref() != Some(version)
+}
+
+pub fn last_seen(paths: &Paths) -> Option<Version> {
+    fs::read_to_string(paths.alphas_watch_state_file())
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+}
+
+pub fn record_seen(paths: &Paths, version: &Version) -> Result<()> {
+    fs::create_dir_all(paths.cache_dir())?;
+    fs::write(paths.alphas_watch_state_file(), version.to_string())?;
+    Ok(())
+}
+
+/// Installed alphas beyond the `keep` most recently installed ones.
+/// Pinned and linked alphas are never rotated out and do not count towards `keep`.
+pub fn rotation_candidates(paths: &Paths, keep: usize) -> Result<Vec<Version>> {
+    let mut alphas = Vec::new();
+    for version in paths.installed_versions()? {
+        if !version.is_distributed_via_server_packages_repository()
+            || paths.version_linked(&version)
+        {
+            continue;
+        }
+        let metadata = VersionMetadata::load(paths, &version)?;
+        if !metadata.pinned {
+            alphas.push((metadata.installed_at, version));
+        }
+    }
+
+    alphas.sort_by_key(|(installed_at, _)| Reverse(*installed_at));
+    Ok(alphas.into_iter().skip(keep).map(|(_, v)| v).collect())
+}
+
+fn run_hook(command: &str, release: &AlphaRelease) -> Result<()> {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+
+    let status = cmd
+        .env(FRM_ALPHA_VERSION, release.version.to_string())
+        .env(FRM_ALPHA_TAG, &release.tag)
+        .env(FRM_ALPHA_PUBLISHED_AT, &release.published_at)
+        .status()
+        .map_err(|e| Error::CommandFailed(format!("failed to run '{}': {}", command, e)))?;
+
+    if !status.success() {
+        return Err(Error::CommandFailed(format!(
+            "'{}' exited with code {}",
+            command,
+            status.code().unwrap_or(-1)
+        )));
+    }
+    Ok(())
+}
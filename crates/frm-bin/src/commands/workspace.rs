@@ -0,0 +1,112 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Result;
+use crate::common::env_vars::{FRM_ENV, FRM_WORKSPACE, FRM_WORKSPACE_VERSIONS};
+use crate::common::prompt::confirm_destructive;
+use crate::errors::Error;
+use crate::messages::{print_hint, print_info, print_success};
+use crate::paths::Paths;
+use crate::shell::Shell;
+use crate::workspace::{self, Workspace};
+
+pub fn create(
+    paths: &Paths,
+    name: &str,
+    versions: &[String],
+    env: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    workspace::validate_name(name)?;
+    if workspace::exists(paths, name) && !force {
+        return Err(Error::WorkspaceAlreadyExists(name.to_string()));
+    }
+
+    let definition = Workspace {
+        versions: versions.iter().map(|v| v.trim().to_string()).collect(),
+        env: env.map(String::from),
+    };
+    // fails early on versions that are not installed and on missing environments
+    let resolved = definition.resolve(paths)?;
+    workspace::save(paths, name, &definition)?;
+
+    print_success(format!(
+        "Created workspace '{}' with {}",
+        name,
+        definition.versions.join(", ")
+    ));
+    for (spec, version) in definition.specs()?.iter().zip(&resolved) {
+        if spec.to_string() != version.to_string() {
+            print_info(format!("{} is currently {}", spec, version));
+        }
+    }
+    print_hint(format!(
+        "Enter it with: eval \"$(frm workspace enter {})\"",
+        name
+    ));
+    Ok(())
+}
+
+/// Generates the workspace's shims and prints the shell commands that put them first in `PATH`
+pub fn enter(paths: &Paths, name: &str, shell: Option<Shell>) -> Result<()> {
+    let definition = workspace::load(paths, name)?;
+    let versions = definition.resolve(paths)?;
+    let bin_dir = workspace::write_shims(paths, name, &versions, definition.env.as_deref())?;
+
+    let mut vars = vec![
+        (FRM_WORKSPACE, name.to_string()),
+        (
+            FRM_WORKSPACE_VERSIONS,
+            versions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+    ];
+    if let Some(env) = &definition.env {
+        vars.push((FRM_ENV, env.clone()));
+    }
+
+    let shell = shell.or_else(Shell::detect).unwrap_or(Shell::Bash);
+    print!("{}", shell.workspace_env_script(paths, &bin_dir, &vars));
+    Ok(())
+}
+
+pub fn list(paths: &Paths) -> Result<()> {
+    let names = workspace::list(paths)?;
+    if names.is_empty() {
+        print_info("No workspaces");
+        return Ok(());
+    }
+
+    for name in names {
+        let definition = workspace::load(paths, &name)?;
+        match &definition.env {
+            Some(env) => println!("{}: {} (env {})", name, definition.versions.join(", "), env),
+            None => println!("{}: {}", name, definition.versions.join(", ")),
+        }
+    }
+    Ok(())
+}
+
+pub fn delete(paths: &Paths, name: &str, assume_yes: bool) -> Result<()> {
+    workspace::load(paths, name)?;
+
+    let question = format!(
+        "Delete workspace '{}'? Its versions and environments are kept.",
+        name
+    );
+    if !confirm_destructive(paths, assume_yes, &question)? {
+        return Ok(());
+    }
+
+    workspace::delete(paths, name)?;
+    print_success(format!("Deleted workspace '{}'", name));
+    Ok(())
+}
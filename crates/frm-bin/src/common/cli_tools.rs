@@ -8,13 +8,47 @@
 
 pub const RABBITMQ_SERVER: &str = "rabbitmq-server";
 pub const RABBITMQCTL: &str = "rabbitmqctl";
+pub const RABBITMQADMIN: &str = "rabbitmqadmin";
+pub const RABBITMQ_PLUGINS: &str = "rabbitmq-plugins";
+pub const RABBITMQ_DIAGNOSTICS: &str = "rabbitmq-diagnostics";
 pub const LOG_FILE_PREFIX: &str = "rabbit@";
 
 pub const RABBITMQ_CLI_TOOLS: &[&str] = &[
     RABBITMQCTL,
-    "rabbitmq-diagnostics",
-    "rabbitmq-plugins",
+    RABBITMQ_DIAGNOSTICS,
+    RABBITMQ_PLUGINS,
     "rabbitmq-queues",
     "rabbitmq-streams",
     "rabbitmq-upgrade",
 ];
+
+/// The tool name closest to `name`, for "did you mean" hints: `diagnostics` suggests
+/// `rabbitmq-diagnostics`, `rabbitmqctrl` suggests `rabbitmqctl`
+pub fn nearest_tool(name: &str) -> Option<&'static str> {
+    let tools = || RABBITMQ_CLI_TOOLS.iter().chain([&RABBITMQADMIN]).copied();
+    if let Some(tool) = tools().find(|tool| tool.strip_prefix("rabbitmq-") == Some(name)) {
+        return Some(tool);
+    }
+
+    let max_distance = (name.len() / 3).max(2);
+    tools()
+        .map(|tool| (edit_distance(name, tool), tool))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, tool)| tool)
+}
+
+/// Levenshtein distance
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
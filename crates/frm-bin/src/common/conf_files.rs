@@ -0,0 +1,51 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A node reads rabbitmq.conf and then the files in conf.d in alphabetical order,
+//! so a key set in a later file overrides the same key set in earlier ones.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rabbitmq_conf::RabbitMQConf;
+use tracing::debug;
+
+/// rabbitmq.conf followed by the `.conf` files in conf.d, those that exist
+pub fn files(etc_dir: &Path) -> Vec<PathBuf> {
+    let mut files = vec![etc_dir.join("rabbitmq.conf")];
+    if let Ok(entries) = fs::read_dir(etc_dir.join("conf.d")) {
+        let mut confd: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "conf"))
+            .collect();
+        confd.sort();
+        files.extend(confd);
+    }
+    files.retain(|f| f.is_file());
+    files
+}
+
+/// The settings a node started with the configuration in `etc_dir` uses.
+/// Files that cannot be parsed are skipped.
+pub fn effective_settings(etc_dir: &Path) -> BTreeMap<String, String> {
+    let mut settings = BTreeMap::new();
+    for file in files(etc_dir) {
+        match RabbitMQConf::load(&file) {
+            Ok(conf) => {
+                for (key, value) in conf.entries() {
+                    settings.insert(key.to_string(), value.to_string());
+                }
+            }
+            Err(e) => {
+                debug!(file = %file.display(), error = %e, "skipping unparseable configuration file")
+            }
+        }
+    }
+    settings
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `rabbitmq-env.conf` is a shell script sourced by `rabbitmq-env`. Only simple
+//! `KEY=value` assignments are understood, which is what it contains in practice.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+pub const ENV_CONF_FILE_NAME: &str = "rabbitmq-env.conf";
+
+/// Variables set by `rabbitmq-env.conf`, with the optional `RABBITMQ_` prefix removed
+/// (`NODENAME` and `RABBITMQ_NODENAME` are equivalent)
+pub fn parse(content: &str) -> BTreeMap<String, String> {
+    let mut vars = BTreeMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            continue;
+        }
+        let key = key.strip_prefix("RABBITMQ_").unwrap_or(key);
+        vars.insert(key.to_string(), unquote(value.trim()).to_string());
+    }
+    vars
+}
+
+pub fn load(path: &Path) -> BTreeMap<String, String> {
+    fs::read_to_string(path)
+        .map(|content| parse(&content))
+        .unwrap_or_default()
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(rest) = value.strip_prefix(quote)
+            && let Some(end) = rest.find(quote)
+        {
+            return &rest[..end];
+        }
+    }
+    match value.find(" #") {
+        Some(idx) => value[..idx].trim_end(),
+        None => value,
+    }
+}
@@ -8,5 +8,47 @@
 
 pub const RABBITMQ_CONFIG_FILES: &str = "RABBITMQ_CONFIG_FILES";
 pub const RABBITMQ_HOME: &str = "RABBITMQ_HOME";
+pub const RABBITMQ_CONFIG_FILE: &str = "RABBITMQ_CONFIG_FILE";
+pub const RABBITMQ_ENABLED_PLUGINS_FILE: &str = "RABBITMQ_ENABLED_PLUGINS_FILE";
+pub const RABBITMQ_LOG_BASE: &str = "RABBITMQ_LOG_BASE";
+pub const RABBITMQ_MNESIA_BASE: &str = "RABBITMQ_MNESIA_BASE";
+pub const RABBITMQ_ADVANCED_CONFIG_FILE: &str = "RABBITMQ_ADVANCED_CONFIG_FILE";
+pub const RABBITMQ_NODENAME: &str = "RABBITMQ_NODENAME";
+pub const RABBITMQ_ERLANG_COOKIE: &str = "RABBITMQ_ERLANG_COOKIE";
+pub const RABBITMQ_DIST_PORT: &str = "RABBITMQ_DIST_PORT";
+pub const RABBITMQ_CONF_ENV_FILE: &str = "RABBITMQ_CONF_ENV_FILE";
+/// Set for commands run with 'frm matrix run'
+pub const RABBITMQ_HOST: &str = "RABBITMQ_HOST";
+pub const RABBITMQ_PORT: &str = "RABBITMQ_PORT";
+pub const RABBITMQ_URL: &str = "RABBITMQ_URL";
 pub const FRM_DIR: &str = "FRM_DIR";
+/// Set for external subcommands (`frm-<name>` in PATH) to the frm executable that runs them
+pub const FRM_BIN: &str = "FRM_BIN";
+/// A read-only installation root shared by several users, see [`crate::paths::Paths`]
+pub const FRM_SHARED_DIR: &str = "FRM_SHARED_DIR";
+/// Treats the frm directory as read-only even when it can be written to
+pub const FRM_READ_ONLY: &str = "FRM_READ_ONLY";
 pub const FRM_SHELL: &str = "FRM_SHELL";
+pub const FRM_LOG: &str = "FRM_LOG";
+/// Set for scripts run with 'frm run-script'
+pub const FRM_VERSION: &str = "FRM_VERSION";
+pub const FRM_VERSION_DIR: &str = "FRM_VERSION_DIR";
+pub const FRM_SBIN_DIR: &str = "FRM_SBIN_DIR";
+pub const FRM_ETC_DIR: &str = "FRM_ETC_DIR";
+pub const FRM_DATA_DIR: &str = "FRM_DATA_DIR";
+pub const FRM_LOG_DIR: &str = "FRM_LOG_DIR";
+pub const FRM_ENV: &str = "FRM_ENV";
+pub const FRM_ALPHA_VERSION: &str = "FRM_ALPHA_VERSION";
+pub const FRM_ALPHA_TAG: &str = "FRM_ALPHA_TAG";
+pub const FRM_ALPHA_PUBLISHED_AT: &str = "FRM_ALPHA_PUBLISHED_AT";
+pub const ASDF_DATA_DIR: &str = "ASDF_DATA_DIR";
+pub const MISE_DATA_DIR: &str = "MISE_DATA_DIR";
+pub const XDG_DATA_HOME: &str = "XDG_DATA_HOME";
+pub const VISUAL: &str = "VISUAL";
+pub const EDITOR: &str = "EDITOR";
+pub const BROWSER: &str = "BROWSER";
+/// Set by the shell scripts frm emits to the sbin directory they added to PATH
+pub const FRM_ACTIVE_SBIN: &str = "FRM_ACTIVE_SBIN";
+/// Set by 'frm workspace enter' to the workspace's name and its versions, separated by spaces
+pub const FRM_WORKSPACE: &str = "FRM_WORKSPACE";
+pub const FRM_WORKSPACE_VERSIONS: &str = "FRM_WORKSPACE_VERSIONS";
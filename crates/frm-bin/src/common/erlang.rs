@@ -0,0 +1,205 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use clap::ValueEnum;
+use flate2::read::GzDecoder;
+
+use crate::providers::Provider;
+use crate::version::Version;
+
+/// Supported Erlang/OTP versions per RabbitMQ release series,
+/// see https://www.rabbitmq.com/docs/which-erlang
+const ERLANG_REQUIREMENTS: &[((u32, u32), &str, &str)] = &[
+    ((3, 11), "25.0", "25.3.x"),
+    ((3, 12), "25.0", "26.2.x"),
+    ((3, 13), "26.0", "26.2.x"),
+    ((4, 0), "26.2", "27.x"),
+    ((4, 1), "26.2", "27.x"),
+    ((4, 2), "27.0", "28.x"),
+    ((4, 3), "27.0", "28.x"),
+];
+
+/// The minimum and maximum supported Erlang versions for a RabbitMQ version, if known
+pub fn supported_erlang_versions(version: &Version) -> Option<(&'static str, &'static str)> {
+    ERLANG_REQUIREMENTS
+        .iter()
+        .find(|(series, _, _)| *series == (version.major, version.minor))
+        .map(|(_, min, max)| (*min, *max))
+}
+
+/// Erlang/OTP releases by the version of the compiler application they ship with
+const COMPILER_RELEASES: &[(&str, u32)] = &[
+    ("7.6", 23),
+    ("8.0", 24),
+    ("8.1", 24),
+    ("8.2", 25),
+    ("8.3", 26),
+    ("8.4", 26),
+    ("8.5", 27),
+    ("9.0", 28),
+];
+
+/// The OTP release a compiler application version shipped with, e.g. 27 for 8.5.4
+pub fn otp_release_of_compiler(compiler_version: &str) -> Option<u32> {
+    COMPILER_RELEASES
+        .iter()
+        .find(|(prefix, _)| {
+            compiler_version
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+        .map(|(_, release)| *release)
+}
+
+/// The version of the compiler that produced a BEAM file, from its `CInf` chunk
+pub fn beam_compiler_version(bytes: &[u8]) -> Option<String> {
+    let mut decompressed = Vec::new();
+    let bytes = if bytes.starts_with(&[0x1f, 0x8b]) {
+        GzDecoder::new(bytes).read_to_end(&mut decompressed).ok()?;
+        &decompressed
+    } else {
+        bytes
+    };
+    if bytes.get(0..4)? != b"FOR1" || bytes.get(8..12)? != b"BEAM" {
+        return None;
+    }
+
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32::from_be_bytes(bytes[offset + 4..offset + 8].try_into().ok()?) as usize;
+        let data = bytes.get(offset + 8..offset + 8 + size)?;
+        if id == b"CInf" {
+            return compile_info_version(data);
+        }
+        // chunks are padded to a multiple of four bytes
+        offset += 8 + size.div_ceil(4) * 4;
+    }
+    None
+}
+
+/// `{version, "8.5.4"}` in compile info encoded in the external term format:
+/// the `version` atom followed by a string (`STRING_EXT`, a 16-bit length and the bytes)
+fn compile_info_version(data: &[u8]) -> Option<String> {
+    const STRING_EXT: u8 = 107;
+    let key = b"version";
+    let start = data.windows(key.len()).position(|w| w == key)? + key.len();
+    let rest = data.get(start..)?;
+    if *rest.first()? != STRING_EXT {
+        return None;
+    }
+    let len = u16::from_be_bytes([*rest.get(1)?, *rest.get(2)?]) as usize;
+    String::from_utf8(rest.get(3..3 + len)?.to_vec()).ok()
+}
+
+/// The OTP release a RabbitMQ distribution was compiled with, from the `rabbit` application's BEAM files
+pub fn built_with_otp_release(version_dir: &Path) -> Option<u32> {
+    let plugins = fs::read_dir(version_dir.join("plugins")).ok()?;
+    let rabbit_dir = plugins.filter_map(|e| e.ok()).find(|e| {
+        e.file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix("rabbit-"))
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+    })?;
+    let bytes = fs::read(rabbit_dir.path().join("ebin").join("rabbit.beam")).ok()?;
+    otp_release_of_compiler(&beam_compiler_version(&bytes)?)
+}
+
+/// Whether an Erlang/OTP version such as 27.3.4 is within a supported range such as 26.2 to 27.x
+pub fn otp_version_in_range(otp_version: &str, min: &str, max: &str) -> bool {
+    let (Some(version), Some(min), Some(max)) = (
+        otp_version_parts(otp_version),
+        otp_version_parts(min),
+        otp_version_parts(max),
+    ) else {
+        return false;
+    };
+    version >= min && version[..max.len().min(version.len())] <= max[..]
+}
+
+/// 27.3.4 is [27, 3, 4], 28.x is [28]
+fn otp_version_parts(version: &str) -> Option<Vec<u32>> {
+    version
+        .trim_end_matches(".x")
+        .split('.')
+        .map(|p| p.parse().ok())
+        .collect()
+}
+
+/// An Erlang/OTP installation found on this machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalErlang {
+    pub version: String,
+    /// Where it was found: `PATH` or the version manager that installed it
+    pub source: String,
+    pub path: PathBuf,
+}
+
+impl LocalErlang {
+    pub fn release(&self) -> Option<u32> {
+        self.version.split('.').next()?.parse().ok()
+    }
+}
+
+/// Erlang/OTP installations on `PATH` and those installed by asdf or mise
+pub fn local_erlangs() -> Vec<LocalErlang> {
+    let mut found = Vec::new();
+    if let Some(version) = path_erl_version() {
+        found.push(LocalErlang {
+            version,
+            source: "PATH".to_string(),
+            path: PathBuf::from("erl"),
+        });
+    }
+    for provider in Provider::value_variants() {
+        found.extend(provider_erlangs(provider));
+    }
+    found
+}
+
+/// `erl` reports only the major release, the full version is in the `OTP_VERSION` file
+fn path_erl_version() -> Option<String> {
+    const EVAL: &str = "{ok, V} = file:read_file(filename:join([code:root_dir(), \"releases\", \
+        erlang:system_info(otp_release), \"OTP_VERSION\"])), io:put_chars(string:trim(V)), halt().";
+    let output = Command::new("erl")
+        .args(["-noshell", "-eval", EVAL])
+        .output()
+        .ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !version.is_empty()).then_some(version)
+}
+
+fn provider_erlangs(provider: &Provider) -> Vec<LocalErlang> {
+    let Some(installs_dir) = provider.tool_installs_dir("erlang") else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(installs_dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<LocalErlang> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().join("bin").join("erl").exists())
+        .filter_map(|e| {
+            let version = e.file_name().to_str()?.to_string();
+            version
+                .starts_with(|c: char| c.is_ascii_digit())
+                .then(|| LocalErlang {
+                    version,
+                    source: provider.name().to_string(),
+                    path: e.path(),
+                })
+        })
+        .collect();
+    found.sort_by_key(|e| otp_version_parts(&e.version));
+    found
+}
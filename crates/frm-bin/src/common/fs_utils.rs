@@ -0,0 +1,136 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs::{self, Metadata};
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::{MetadataExt, symlink};
+#[cfg(windows)]
+use std::os::windows::fs::symlink_dir as windows_symlink_dir;
+use std::path::Path;
+#[cfg(unix)]
+use std::process::Command;
+
+/// Total size of the files in a directory tree, in bytes. Symbolic links are not followed.
+pub fn dir_size(path: &Path) -> io::Result<u64> {
+    let mut total = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.is_dir() {
+            total += dir_size(&entry.path())?;
+        } else {
+            total += metadata.len();
+        }
+    }
+    Ok(total)
+}
+
+/// The device and inode of a file, which identify it regardless of its path
+#[cfg(unix)]
+pub fn file_id(metadata: &Metadata) -> Option<(u64, u64)> {
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+pub fn file_id(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Parses a size such as "20GB", "500 MiB", "2G", or "1024". KB, MB, GB, and TB are
+/// decimal units; KiB, MiB, GiB, TiB, and the single-letter K, M, G, T are binary units.
+pub fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" => 1000,
+        "MB" => 1000u64.pow(2),
+        "GB" => 1000u64.pow(3),
+        "TB" => 1000u64.pow(4),
+        "K" | "KIB" => 1 << 10,
+        "M" | "MIB" => 1 << 20,
+        "G" | "GIB" => 1 << 30,
+        "T" | "TIB" => 1 << 40,
+        _ => return None,
+    };
+    let bytes = number * multiplier as f64;
+    (bytes.is_finite() && bytes < u64::MAX as f64).then_some(bytes as u64)
+}
+
+/// Space available to unprivileged users on the file system that contains `path`, in bytes
+#[cfg(unix)]
+pub fn available_space(path: &Path) -> io::Result<u64> {
+    // POSIX output format: a header line, then "filesystem blocks used available capacity mount"
+    let output = Command::new("df").arg("-Pk").arg(path).output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "df failed for {}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .nth(1)
+        .and_then(|line| line.split_whitespace().nth(3))
+        .and_then(|kib| kib.parse::<u64>().ok())
+        .map(|kib| kib * 1024)
+        .ok_or_else(|| io::Error::other("could not parse the output of df"))
+}
+
+#[cfg(windows)]
+pub fn available_space(_path: &Path) -> io::Result<u64> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "checking free disk space is not supported on Windows",
+    ))
+}
+
+/// Formats a size in bytes using binary units, e.g. "41.3 MiB"
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// A symbolic link to a directory; on Windows, creating one may require Developer Mode
+#[cfg(unix)]
+pub fn symlink_dir(target: &Path, link: &Path) -> io::Result<()> {
+    symlink(target, link)
+}
+
+#[cfg(windows)]
+pub fn symlink_dir(target: &Path, link: &Path) -> io::Result<()> {
+    windows_symlink_dir(target, link)
+}
+
+#[cfg(unix)]
+pub fn remove_symlink_dir(link: &Path) -> io::Result<()> {
+    fs::remove_file(link)
+}
+
+#[cfg(windows)]
+pub fn remove_symlink_dir(link: &Path) -> io::Result<()> {
+    fs::remove_dir(link)
+}
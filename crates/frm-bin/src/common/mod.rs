@@ -7,6 +7,11 @@
 // except according to those terms.
 
 pub mod cli_tools;
+pub mod conf_files;
+pub mod env_conf;
 pub mod env_vars;
+pub mod erlang;
+pub mod fs_utils;
 pub mod http;
+pub mod prompt;
 pub mod urls;
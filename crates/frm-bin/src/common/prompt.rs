@@ -0,0 +1,55 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::Result;
+use crate::config::Config;
+use crate::messages::print_info;
+use crate::paths::Paths;
+
+/// Asks the user to confirm a destructive operation.
+///
+/// No prompt is shown when `assume_yes` is set, when `confirm_destructive = false`
+/// is set in `config.toml`, or when standard input is not a terminal.
+pub fn confirm_destructive(paths: &Paths, assume_yes: bool, question: &str) -> Result<bool> {
+    if assume_yes || !io::stdin().is_terminal() {
+        return Ok(true);
+    }
+    if !Config::load(paths)?.confirm_destructive {
+        return Ok(true);
+    }
+
+    let confirmed = ask(question)?;
+    if !confirmed {
+        print_info("Aborted");
+    }
+    Ok(confirmed)
+}
+
+/// Asks a yes/no question. The answer is "no" when standard input is not a terminal.
+pub fn confirm(question: &str) -> Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    ask(question)
+}
+
+fn ask(question: &str) -> Result<bool> {
+    let mut stderr = io::stderr();
+    write!(stderr, "{} [y/N] ", question)?;
+    stderr.flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(is_affirmative(&answer))
+}
+
+pub fn is_affirmative(answer: &str) -> bool {
+    matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
@@ -12,4 +12,20 @@ pub const SERVER_PACKAGES_API_URL: &str =
 pub const RABBITMQ_SERVER_API_URL: &str =
     "https://api.github.com/repos/rabbitmq/rabbitmq-server/releases";
 
+pub const RABBITMQ_SERVER_COMPARE_API_URL: &str =
+    "https://api.github.com/repos/rabbitmq/rabbitmq-server/compare";
+
+pub const RABBITMQ_SERVER_COMMITS_API_URL: &str =
+    "https://api.github.com/repos/rabbitmq/rabbitmq-server/commits";
+
+pub const RABBITMQ_SERVER_REPOSITORY_URL: &str = "https://github.com/rabbitmq/rabbitmq-server";
+
+pub const SERVER_PACKAGES_REPOSITORY_URL: &str = "https://github.com/rabbitmq/server-packages";
+
+pub const RABBITMQADMIN_API_URL: &str =
+    "https://api.github.com/repos/rabbitmq/rabbitmqadmin-ng/releases";
+
+pub const PERF_TEST_API_URL: &str =
+    "https://api.github.com/repos/rabbitmq/rabbitmq-perf-test/releases";
+
 pub const RABBITMQ_SIGNING_KEY_URL: &str = "https://github.com/rabbitmq/signing-keys/releases/download/3.0/rabbitmq-release-signing-key.asc";
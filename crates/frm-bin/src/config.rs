@@ -6,7 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::collections::BTreeMap;
 use std::fs;
+use std::ops::Not;
 
 use serde::{Deserialize, Serialize};
 
@@ -14,10 +16,59 @@ use crate::Result;
 use crate::paths::Paths;
 use crate::version::Version;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_version: Option<Version>,
+    /// Opt-in: record executed commands to `history.jsonl`
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub record_history: bool,
+    /// Ask for confirmation before removing installed versions
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub confirm_destructive: bool,
+    /// Opt-in: hard-link files shared with other installed versions after an installation
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub dedup_after_install: bool,
+    /// Node name of nodes started by frm, e.g. `rabbit-{version}@localhost`,
+    /// see [`render_node_name`] for the supported placeholders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_name_template: Option<String>,
+    /// A denylist of releases with known critical regressions to fetch, see [`crate::denylist`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denylist_url: Option<String>,
+    /// Opt-in: `use` without a version uses the default version instead of failing
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub use_default_fallback: bool,
+    /// Opt-in: `use`, `cli`, and `fg node` offer to install a version that is not installed
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub auto_install: bool,
+    /// Command aliases, e.g. `up = "bg start -V latest"`, see [`crate::aliases`]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_version: None,
+            record_history: false,
+            confirm_destructive: true,
+            dedup_after_install: false,
+            node_name_template: None,
+            denylist_url: None,
+            use_default_fallback: false,
+            auto_install: false,
+            aliases: BTreeMap::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
 }
 
 impl Config {
@@ -46,4 +97,19 @@ impl Config {
     pub fn clear_default(&mut self) {
         self.default_version = None;
     }
+
+    /// The node name of a version (and environment) when `node_name_template` is set
+    pub fn node_name(&self, version: &Version, env: Option<&str>) -> Option<String> {
+        self.node_name_template
+            .as_deref()
+            .map(|template| render_node_name(template, version, env))
+    }
+}
+
+/// Replaces `{version}` with the version, and `{env}` with the environment name,
+/// or "default" for the version's own configuration
+pub fn render_node_name(template: &str, version: &Version, env: Option<&str>) -> String {
+    template
+        .replace("{version}", &version.to_string())
+        .replace("{env}", env.unwrap_or("default"))
 }
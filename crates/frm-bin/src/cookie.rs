@@ -0,0 +1,172 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Erlang cookie of nodes started by frm and of the CLI tools it runs.
+//!
+//! By default, both use `~/.erlang.cookie`, which breaks as soon as `HOME` differs between
+//! the two (sudo, containers, IDE terminals). A cookie managed with `frm cookie set`,
+//! either shared or specific to a version, is passed to both via `RABBITMQ_ERLANG_COOKIE`.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::hash::{BuildHasher, RandomState};
+#[cfg(unix)]
+use std::io::Read;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::common::env_vars::RABBITMQ_ERLANG_COOKIE;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Erlang limits atoms, and thus cookies, to 255 characters
+const MAX_COOKIE_LEN: usize = 255;
+/// Same as the cookies Erlang generates
+const GENERATED_COOKIE_LEN: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieSource {
+    Version,
+    Shared,
+}
+
+impl fmt::Display for CookieSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CookieSource::Version => write!(f, "version-specific"),
+            CookieSource::Shared => write!(f, "shared"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cookie {
+    pub value: String,
+    pub source: CookieSource,
+    pub path: PathBuf,
+}
+
+pub fn validate(value: &str) -> Result<()> {
+    if value.is_empty() {
+        return Err(Error::InvalidCookie("the cookie is empty".into()));
+    }
+    if value.len() > MAX_COOKIE_LEN {
+        return Err(Error::InvalidCookie(format!(
+            "the cookie is longer than {} characters",
+            MAX_COOKIE_LEN
+        )));
+    }
+    if !value.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(Error::InvalidCookie(
+            "use printable ASCII characters without spaces".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// A random cookie of uppercase letters, like the ones Erlang generates
+pub fn generate() -> String {
+    let bytes = random_bytes(GENERATED_COOKIE_LEN);
+    bytes
+        .into_iter()
+        .map(|b| char::from(b'A' + b % 26))
+        .collect()
+}
+
+#[cfg(unix)]
+fn random_bytes(n: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; n];
+    match fs::File::open("/dev/urandom").and_then(|mut f| f.read_exact(&mut buf)) {
+        Ok(()) => buf,
+        Err(_) => hashed_bytes(n),
+    }
+}
+
+#[cfg(not(unix))]
+fn random_bytes(n: usize) -> Vec<u8> {
+    hashed_bytes(n)
+}
+
+/// Falls back to the randomly seeded keys of the standard library's hasher
+fn hashed_bytes(n: usize) -> Vec<u8> {
+    (0..n)
+        .map(|i| RandomState::new().hash_one(i) as u8)
+        .collect()
+}
+
+pub fn read(path: &Path) -> Result<Option<String>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let value = fs::read_to_string(path)?.trim().to_string();
+    validate(&value)?;
+    Ok(Some(value))
+}
+
+/// Writes a cookie file that only its owner can read, as Erlang requires
+pub fn write(path: &Path, value: &str) -> Result<()> {
+    validate(value)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // created with its final permissions and renamed over the old cookie,
+    // so the value is never readable by others, not even briefly
+    let staged = path.with_extension("cookie.tmp");
+    if staged.exists() {
+        fs::remove_file(&staged)?;
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    options.open(&staged)?.write_all(value.as_bytes())?;
+    fs::rename(staged, path)?;
+    Ok(())
+}
+
+pub fn shared(paths: &Paths) -> Result<Option<Cookie>> {
+    let path = paths.shared_cookie_file();
+    Ok(read(&path)?.map(|value| Cookie {
+        value,
+        source: CookieSource::Shared,
+        path,
+    }))
+}
+
+/// The version's own cookie if it has one, otherwise the shared one
+pub fn effective(paths: &Paths, version: &Version) -> Result<Option<Cookie>> {
+    let path = paths.version_cookie_file(version);
+    match read(&path)? {
+        Some(value) => Ok(Some(Cookie {
+            value,
+            source: CookieSource::Version,
+            path,
+        })),
+        None => shared(paths),
+    }
+}
+
+/// Environment variables for nodes and CLI tools of a version. Empty without
+/// a frm-managed cookie, in which case `~/.erlang.cookie` is used as usual.
+pub fn env_vars(paths: &Paths, version: &Version) -> Result<Vec<(&'static str, String)>> {
+    Ok(effective(paths, version)?
+        .map(|cookie| vec![(RABBITMQ_ERLANG_COOKIE, cookie.value)])
+        .unwrap_or_default())
+}
+
+/// `~/.erlang.cookie`, used by Erlang nodes and tools that do not run via frm
+pub fn home_cookie_file() -> Result<PathBuf> {
+    dirs::home_dir()
+        .map(|home| home.join(".erlang.cookie"))
+        .ok_or_else(|| Error::Config("cannot find home directory".into()))
+}
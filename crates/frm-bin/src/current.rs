@@ -0,0 +1,76 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `FRM_DIR/current`: a symlink to the directory of the chosen version, updated by
+//! `frm default` and `frm releases use --link`, so that IDE run configurations and
+//! Docker bind mounts can use a path that does not change with the version. It is removed
+//! when the default version is uninstalled.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::common::fs_utils::{remove_symlink_dir, symlink_dir};
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Points the link at the version's directory, replacing an existing link
+pub fn update(paths: &Paths, version: &Version) -> Result<()> {
+    let link = paths.current_link();
+    if let Ok(metadata) = fs::symlink_metadata(&link)
+        && !metadata.file_type().is_symlink()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a symlink", link.display()),
+        )
+        .into());
+    }
+    fs::create_dir_all(paths.base_dir())?;
+
+    // a new link is renamed over the old one, so the path never goes missing
+    let staged = paths.base_dir().join(".current.tmp");
+    if fs::symlink_metadata(&staged).is_ok() {
+        remove_symlink_dir(&staged)?;
+    }
+    symlink_dir(&paths.version_dir(version), &staged)?;
+    replace(&staged, &link)?;
+    Ok(())
+}
+
+/// Removes the link, leaving anything that is not a symlink in place
+pub fn remove(paths: &Paths) -> Result<()> {
+    let link = paths.current_link();
+    match fs::symlink_metadata(&link) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            remove_symlink_dir(&link)?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Where the link points, if it exists
+pub fn target(paths: &Paths) -> Option<PathBuf> {
+    fs::read_link(paths.current_link()).ok()
+}
+
+#[cfg(unix)]
+fn replace(staged: &Path, link: &Path) -> io::Result<()> {
+    fs::rename(staged, link)
+}
+
+/// Renaming over a directory symlink fails on Windows, so the old one is removed first
+#[cfg(windows)]
+fn replace(staged: &Path, link: &Path) -> io::Result<()> {
+    if fs::symlink_metadata(link).is_ok() {
+        remove_symlink_dir(link)?;
+    }
+    fs::rename(staged, link)
+}
@@ -0,0 +1,229 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Consecutive patch releases share most of their files. Deduplication replaces
+//! files with the same content in different installed versions with hard links
+//! to a single copy.
+//!
+//! Only the files shipped in the release archive are considered: `etc`, `var`, and `envs`
+//! are modified at runtime and a hard link would make a change to one version affect the others.
+//! Linked versions (see `frm link`) are never touched.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, Metadata};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::common::fs_utils::file_id;
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Top-level version directories that are modified at runtime and are never deduplicated
+pub const MUTABLE_DIRS: &[&str] = &["etc", "var", "envs"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DedupReport {
+    pub files_linked: usize,
+    pub bytes_saved: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiskUsage {
+    /// Total size of all files, counting every hard link separately
+    pub apparent: u64,
+    /// Total size of all files, counting hard-linked files once
+    pub on_disk: u64,
+}
+
+impl DiskUsage {
+    /// Bytes already saved by hard links
+    pub fn shared(&self) -> u64 {
+        self.apparent.saturating_sub(self.on_disk)
+    }
+}
+
+#[derive(Debug)]
+struct FileEntry {
+    path: PathBuf,
+    len: u64,
+    mode: u32,
+    id: Option<(u64, u64)>,
+}
+
+/// Installed versions that can be deduplicated, that is, all but linked and shared ones
+pub fn candidate_versions(paths: &Paths) -> Result<Vec<Version>> {
+    Ok(paths
+        .installed_versions()?
+        .into_iter()
+        .filter(|v| !paths.version_linked(v) && !paths.version_shared(v))
+        .collect())
+}
+
+/// Hard-links files with the same content across the given version directories.
+/// With `dry_run`, only computes what would be saved.
+pub fn deduplicate(version_dirs: &[PathBuf], dry_run: bool) -> io::Result<DedupReport> {
+    let mut files = Vec::new();
+    for dir in version_dirs {
+        collect_immutable_files(dir, &mut files)?;
+    }
+
+    let mut report = DedupReport::default();
+    for group in same_content_groups(files)? {
+        let (canonical, duplicates) = group.split_first().expect("groups are never empty");
+        let canonical_content = fs::read(&canonical.path)?;
+        let mut freed = HashSet::new();
+        for duplicate in duplicates {
+            if duplicate.id.is_some() && duplicate.id == canonical.id {
+                continue;
+            }
+            // hash collisions are possible, so contents are compared before linking
+            if fs::read(&duplicate.path)? != canonical_content {
+                continue;
+            }
+            if !dry_run {
+                replace_with_hard_link(&canonical.path, &duplicate.path)?;
+            }
+            report.files_linked += 1;
+            if duplicate.id.is_none_or(|id| freed.insert(id)) {
+                report.bytes_saved += duplicate.len;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Disk usage of the given version directories, including their mutable directories
+pub fn disk_usage(version_dirs: &[PathBuf]) -> io::Result<DiskUsage> {
+    let mut files = Vec::new();
+    for dir in version_dirs {
+        collect_files(dir, &mut files)?;
+    }
+
+    let mut usage = DiskUsage::default();
+    let mut seen = HashSet::new();
+    for file in files {
+        usage.apparent += file.len;
+        if file.id.is_none_or(|id| seen.insert(id)) {
+            usage.on_disk += file.len;
+        }
+    }
+    Ok(usage)
+}
+
+/// Groups files that are likely to have the same content: the same size, permissions, and content hash.
+/// Only groups with more than one distinct file are returned.
+fn same_content_groups(files: Vec<FileEntry>) -> io::Result<Vec<Vec<FileEntry>>> {
+    let mut by_size: HashMap<(u64, u32), Vec<FileEntry>> = HashMap::new();
+    for file in files.into_iter().filter(|f| f.len > 0) {
+        by_size.entry((file.len, file.mode)).or_default().push(file);
+    }
+
+    let mut groups = Vec::new();
+    for (_, candidates) in by_size {
+        if !has_distinct_files(&candidates) {
+            continue;
+        }
+        let mut by_hash: HashMap<u64, Vec<FileEntry>> = HashMap::new();
+        for file in candidates {
+            let hash = content_hash(&file.path)?;
+            by_hash.entry(hash).or_default().push(file);
+        }
+        groups.extend(
+            by_hash
+                .into_values()
+                .filter(|group| has_distinct_files(group)),
+        );
+    }
+
+    for group in &mut groups {
+        group.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+    groups.sort_by(|a, b| a[0].path.cmp(&b[0].path));
+    Ok(groups)
+}
+
+fn has_distinct_files(files: &[FileEntry]) -> bool {
+    match files.first() {
+        Some(first) => files.iter().any(|f| f.id.is_none() || f.id != first.id),
+        None => false,
+    }
+}
+
+fn content_hash(path: &Path) -> io::Result<u64> {
+    let mut hasher = DefaultHasher::new();
+    fs::read(path)?.hash(&mut hasher);
+    Ok(hasher.finish())
+}
+
+/// Links `target` to `source` by creating the link next to it and renaming it over `target`,
+/// so that `target` is never missing
+fn replace_with_hard_link(source: &Path, target: &Path) -> io::Result<()> {
+    let file_name = target.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = target.with_file_name(format!(".{}.frm-dedup", file_name));
+    if tmp.exists() {
+        fs::remove_file(&tmp)?;
+    }
+    fs::hard_link(source, &tmp)?;
+    if let Err(e) = fs::rename(&tmp, target) {
+        fs::remove_file(&tmp).ok();
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn collect_immutable_files(version_dir: &Path, files: &mut Vec<FileEntry>) -> io::Result<()> {
+    for entry in fs::read_dir(version_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if MUTABLE_DIRS.iter().any(|d| name == *d) {
+            continue;
+        }
+        visit(&entry.path(), entry.metadata()?, files)?;
+    }
+    Ok(())
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<FileEntry>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        visit(&entry.path(), entry.metadata()?, files)?;
+    }
+    Ok(())
+}
+
+/// Symbolic links are not followed
+fn visit(path: &Path, metadata: Metadata, files: &mut Vec<FileEntry>) -> io::Result<()> {
+    if metadata.is_dir() {
+        collect_files(path, files)
+    } else {
+        if metadata.is_file() {
+            files.push(FileEntry {
+                path: path.to_path_buf(),
+                len: metadata.len(),
+                mode: file_mode(&metadata),
+                id: file_id(&metadata),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn file_mode(metadata: &Metadata) -> u32 {
+    metadata.mode()
+}
+
+#[cfg(not(unix))]
+fn file_mode(metadata: &Metadata) -> u32 {
+    u32::from(metadata.permissions().readonly())
+}
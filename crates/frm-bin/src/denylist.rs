@@ -0,0 +1,95 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Releases with known critical regressions.
+//!
+//! Entries come from `denylist.toml` in the frm directory and, when `denylist_url` is set
+//! in `config.toml`, from a list of the same format fetched from that URL, e.g. one maintained
+//! by a team. The fetched list is cached like release feeds, see [`crate::feed_cache`].
+//!
+//! ```toml
+//! [[release]]
+//! version = "4.1.0"
+//! reason = "quorum queue leader elections can stall after a network partition"
+//! ```
+
+use std::fs;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::Result;
+use crate::config::Config;
+use crate::errors::Error;
+use crate::feed_cache::{self, CachedFeed};
+use crate::messages::print_warning;
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// How long a fetched denylist is used before it is revalidated
+pub const DENYLIST_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DeniedRelease {
+    pub version: Version,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Denylist {
+    #[serde(default, rename = "release")]
+    pub releases: Vec<DeniedRelease>,
+}
+
+impl Denylist {
+    pub fn parse(content: &str) -> Result<Self> {
+        toml::from_str(content).map_err(|e| Error::Config(format!("invalid denylist: {}", e)))
+    }
+
+    /// The local denylist and the last fetched one, without network access
+    pub fn load_cached(paths: &Paths) -> Result<Self> {
+        let mut denylist = Self::load_local(paths)?;
+        if let Some(url) = Config::load(paths)?.denylist_url
+            && let Some(cached) =
+                CachedFeed::load(&paths.denylist_cache_file()).filter(|c| c.url == url)
+        {
+            denylist.extend(Self::parse(&cached.body)?);
+        }
+        Ok(denylist)
+    }
+
+    /// The local denylist and the one at `denylist_url`, if set. When the latter
+    /// cannot be fetched and was never cached, only the local one is used.
+    pub async fn fetch(client: &reqwest::Client, paths: &Paths) -> Result<Self> {
+        let mut denylist = Self::load_local(paths)?;
+        if let Some(url) = Config::load(paths)?.denylist_url {
+            match feed_cache::fetch(client, &url, &paths.denylist_cache_file(), DENYLIST_TTL).await
+            {
+                Ok(body) => denylist.extend(Self::parse(&body)?),
+                Err(e) => print_warning(format!("Could not fetch the denylist: {}", e)),
+            }
+        }
+        Ok(denylist)
+    }
+
+    fn load_local(paths: &Paths) -> Result<Self> {
+        let file = paths.denylist_file();
+        if !file.exists() {
+            return Ok(Self::default());
+        }
+        Self::parse(&fs::read_to_string(file)?)
+    }
+
+    fn extend(&mut self, other: Denylist) {
+        self.releases.extend(other.releases);
+    }
+
+    pub fn find(&self, version: &Version) -> Option<&DeniedRelease> {
+        self.releases.iter().find(|r| &r.version == version)
+    }
+}
@@ -7,20 +7,26 @@
 // except according to those terms.
 
 use std::fs::{self, File};
-use std::io::{self, BufReader, Write};
-use std::path::Path;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use futures_util::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
-use tar::Archive;
-use xz2::read::XzDecoder;
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, instrument};
 
 use crate::Result;
+use crate::archive::{self, ArchiveFormat};
 use crate::common::http::USER_AGENT;
 use crate::errors::Error;
 use crate::paths::Paths;
+use crate::profile::{self, Phase};
+use crate::provenance::{self, Provenance};
 use crate::releases::find_server_packages_release_tag;
-use crate::version::Version;
+use crate::version::{Artifact, Version};
 
 const TEMPLATE_RABBITMQ_CONF: &str =
     include_str!("../templates/etc/rabbitmq/template.rabbitmq.conf");
@@ -29,39 +35,63 @@ const TEMPLATE_ENABLED_PLUGINS: &str =
 const TEMPLATE_LOGGING_CONF: &str =
     include_str!("../templates/etc/rabbitmq/conf.d/90-logging.conf");
 
+/// How many downloaded chunks can wait for the extractor before the download slows down
+const STREAMING_BUFFER_CHUNKS: usize = 64;
+
 pub struct Downloader {
     client: reqwest::Client,
+    keep_archive: bool,
 }
 
 impl Downloader {
     pub fn new() -> Self {
         Self {
             client: reqwest::Client::new(),
+            keep_archive: false,
         }
     }
 
-    pub async fn download(&self, version: &Version, paths: &Paths) -> Result<()> {
-        let url = if version.is_distributed_via_server_packages_repository() {
-            let tag = find_server_packages_release_tag(&self.client, version).await?;
-            version.download_url_with_tag(&tag)
+    /// Also saves downloaded archives to the downloads directory, where later
+    /// installations of the same version will find them
+    pub fn keep_archive(mut self, keep: bool) -> Self {
+        self.keep_archive = keep;
+        self
+    }
+
+    /// Downloads and extracts a version. The archive is extracted while it is being downloaded,
+    /// unless it was downloaded earlier. Returns where the archive came from, to be recorded
+    /// once the installation is complete.
+    #[instrument(level = "debug", skip_all, fields(version = %version))]
+    pub async fn download(&self, version: &Version, paths: &Paths) -> Result<Provenance> {
+        let artifact = if version.is_distributed_via_server_packages_repository() {
+            let tag = find_server_packages_release_tag(&self.client, paths, version).await?;
+            Artifact::generic_unix(version).in_server_packages_release(&tag)
         } else {
-            version.download_url()
+            Artifact::generic_unix(version)
         };
 
-        let archive_path = paths.downloads_dir().join(version.archive_name());
+        let archive_path = paths.version_archive_file(version);
 
         paths.ensure_dirs()?;
 
-        if !archive_path.exists() {
-            self.fetch_archive(&url, &archive_path).await?;
-        }
-
-        self.extract_archive(&archive_path, version, paths)?;
+        let sha256 = if archive_path.exists() {
+            debug!(path = %archive_path.display(), "using previously downloaded archive");
+            self.extract_archive(&archive_path, version, paths)?;
+            provenance::sha256_file(&archive_path)?
+        } else {
+            let save_to = self.keep_archive.then_some(archive_path.as_path());
+            self.fetch_and_extract(artifact.url(), save_to, version, paths)
+                .await?
+        };
 
-        Ok(())
+        Ok(Provenance::new(
+            artifact.url(),
+            artifact.file_name(),
+            sha256,
+        ))
     }
 
-    async fn fetch_archive(&self, url: &str, dest: &Path) -> Result<()> {
+    async fn get(&self, url: &str) -> Result<reqwest::Response> {
         let response = self
             .client
             .get(url)
@@ -77,21 +107,16 @@ impl Downloader {
                 url
             )));
         }
+        debug!(status = %response.status(), total_size = response.content_length(), "response received");
 
-        let total_size = response.content_length().unwrap_or(0);
-        let progress = if total_size > 0 {
-            let pb = ProgressBar::new(total_size);
-            pb.set_style(
-                ProgressStyle::with_template(
-                    "{elapsed_precise:.dim} {wide_bar:.cyan} {bytes}/{total_bytes} ({bytes_per_sec})",
-                )
-                .unwrap()
-                .progress_chars("█▉▊▋▌▍▎▏  "),
-            );
-            Some(pb)
-        } else {
-            None
-        };
+        Ok(response)
+    }
+
+    #[instrument(level = "debug", skip(self, dest))]
+    pub(crate) async fn fetch_archive(&self, url: &str, dest: &Path) -> Result<()> {
+        let _timer = profile::start(Phase::Download);
+        let response = self.get(url).await?;
+        let progress = progress_bar(response.content_length());
 
         let mut file = File::create(dest)?;
         let mut stream = response.bytes_stream();
@@ -107,47 +132,137 @@ impl Downloader {
         if let Some(pb) = progress {
             pb.finish_and_clear();
         }
+        debug!(path = %dest.display(), "archive downloaded");
 
         Ok(())
     }
 
-    fn extract_archive(&self, archive_path: &Path, version: &Version, paths: &Paths) -> Result<()> {
-        let file = File::open(archive_path)?;
-        let reader = BufReader::new(file);
-        let decoder = XzDecoder::new(reader);
-        let mut archive = Archive::new(decoder);
+    /// Extracts an archive as it is downloaded: downloaded chunks are passed to an extractor
+    /// running on a blocking thread through a bounded channel. With `save_to`, the archive
+    /// is also saved, and only renamed to `save_to` once it is complete.
+    ///
+    /// Time spent waiting for the extractor to accept chunks is attributed to extraction,
+    /// the rest of the transfer to the download. Returns the SHA-256 checksum of the archive.
+    #[instrument(level = "debug", skip(self, save_to, version, paths))]
+    async fn fetch_and_extract(
+        &self,
+        url: &str,
+        save_to: Option<&Path>,
+        version: &Version,
+        paths: &Paths,
+    ) -> Result<String> {
+        let transfer_started = Instant::now();
+        let response = self.get(url).await?;
+        let progress = progress_bar(response.content_length());
+
+        let temp_dir = prepare_extraction_dir(paths, version)?;
+        let (tx, rx) = mpsc::channel(STREAMING_BUFFER_CHUNKS);
+        let extraction_dir = temp_dir.clone();
+        let mut extractor = tokio::task::spawn_blocking(move || {
+            archive::unpack(ChannelReader::new(rx), &extraction_dir)
+        });
+
+        let partial_path = save_to.map(partial_file_path);
+        let mut file = match partial_path {
+            Some(ref path) => Some(File::create(path)?),
+            None => None,
+        };
 
-        let temp_dir = paths
-            .versions_dir()
-            .join(format!(".{}-extracting", version));
-        if temp_dir.exists() {
-            fs::remove_dir_all(&temp_dir)?;
+        let mut stream = response.bytes_stream();
+        let mut hasher = Sha256::new();
+        let mut downloaded = Ok(());
+        let mut extracted = None;
+        let mut waited_for_extractor = Duration::ZERO;
+        while let Some(chunk) = stream.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    downloaded = Err(Error::DownloadFailed(e.to_string()));
+                    break;
+                }
+            };
+            if let Some(ref mut file) = file
+                && let Err(e) = file.write_all(&chunk)
+            {
+                downloaded = Err(e.into());
+                break;
+            }
+            hasher.update(&chunk);
+            if let Some(ref pb) = progress {
+                pb.inc(chunk.len() as u64);
+            }
+            // the extractor stops reading at the end of the tar archive, or when it fails.
+            // After the end, the rest is still downloaded for the checksum.
+            if extracted.is_none() {
+                let sending = Instant::now();
+                let sent = tx.send(chunk).await;
+                waited_for_extractor += sending.elapsed();
+                if sent.is_err() {
+                    let result = extraction_result(&mut extractor).await;
+                    let failed = result.is_err();
+                    extracted = Some(result);
+                    if failed {
+                        break;
+                    }
+                }
+            }
         }
-        fs::create_dir_all(&temp_dir)?;
+        drop(tx);
+        profile::record(
+            Phase::Download,
+            transfer_started
+                .elapsed()
+                .saturating_sub(waited_for_extractor),
+        );
+        profile::record(Phase::Extract, waited_for_extractor);
+        let _timer = profile::start(Phase::Extract);
 
-        archive
-            .unpack(&temp_dir)
-            .map_err(|e| Error::ExtractionFailed(e.to_string()))?;
+        if let Some(pb) = progress {
+            pb.finish_and_clear();
+        }
 
-        let extracted_name = version.extracted_dir_name();
-        let extracted_path = temp_dir.join(&extracted_name);
-        let final_path = paths.version_dir(version);
+        let extracted = match extracted {
+            Some(result) => result,
+            None => extraction_result(&mut extractor).await,
+        };
+        if let Err(e) = downloaded.and(extracted) {
+            debug!(error = %e, "streaming extraction failed");
+            fs::remove_dir_all(&temp_dir).ok();
+            if let Some(ref path) = partial_path {
+                fs::remove_file(path).ok();
+            }
+            return Err(e);
+        }
 
-        if final_path.exists() {
-            fs::remove_dir_all(&final_path)?;
+        if let (Some(partial), Some(dest)) = (partial_path, save_to) {
+            fs::rename(&partial, dest)?;
+            debug!(path = %dest.display(), "archive saved");
         }
 
-        fs::rename(&extracted_path, &final_path).map_err(|e| {
-            Error::ExtractionFailed(format!("failed to move extracted directory: {}", e))
-        })?;
+        finish_extraction(&temp_dir, version, paths)?;
+        Ok(provenance::sha256_hex(hasher.finalize()))
+    }
 
-        fs::remove_dir_all(&temp_dir)?;
+    #[instrument(level = "debug", skip_all, fields(archive = %archive_path.display()))]
+    fn extract_archive(&self, archive_path: &Path, version: &Version, paths: &Paths) -> Result<()> {
+        let _timer = profile::start(Phase::Extract);
+        let temp_dir = prepare_extraction_dir(paths, version)?;
 
-        Ok(())
+        if let Err(e) = archive::unpack_file(archive_path, &temp_dir) {
+            debug!(error = %e, "extraction failed");
+            fs::remove_dir_all(&temp_dir).ok();
+            return Err(Error::ExtractionFailed(e.to_string()));
+        }
+
+        finish_extraction(&temp_dir, version, paths)
     }
 
+    /// Removes the downloaded archive of a version, unless archives are kept
     pub fn cleanup_archive(&self, version: &Version, paths: &Paths) -> Result<()> {
-        let archive_path = paths.downloads_dir().join(version.archive_name());
+        if self.keep_archive {
+            return Ok(());
+        }
+        let archive_path = paths.version_archive_file(version);
         if archive_path.exists() {
             fs::remove_file(archive_path)?;
         }
@@ -155,6 +270,114 @@ impl Downloader {
     }
 }
 
+/// Waits for the extractor of [`Downloader::fetch_and_extract`] to finish
+async fn extraction_result(extractor: &mut JoinHandle<io::Result<ArchiveFormat>>) -> Result<()> {
+    extractor
+        .await
+        .map_err(|e| Error::ExtractionFailed(e.to_string()))
+        .and_then(|r| {
+            r.map(|format| debug!(?format, "archive extracted while downloading"))
+                .map_err(|e| Error::ExtractionFailed(e.to_string()))
+        })
+}
+
+fn progress_bar(total_size: Option<u64>) -> Option<ProgressBar> {
+    let total_size = total_size.filter(|size| *size > 0)?;
+    let pb = ProgressBar::new(total_size);
+    pb.set_style(
+        ProgressStyle::with_template(
+            "{elapsed_precise:.dim} {wide_bar:.cyan} {bytes}/{total_bytes} ({bytes_per_sec})",
+        )
+        .unwrap()
+        .progress_chars("█▉▊▋▌▍▎▏  "),
+    );
+    Some(pb)
+}
+
+fn partial_file_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    path.with_file_name(name)
+}
+
+pub(crate) fn prepare_extraction_dir(paths: &Paths, version: &Version) -> Result<PathBuf> {
+    let temp_dir = paths.version_extraction_dir(version);
+    if temp_dir.exists() {
+        fs::remove_dir_all(&temp_dir)?;
+    }
+    fs::create_dir_all(&temp_dir)?;
+    Ok(temp_dir)
+}
+
+/// Moves the extracted distribution into place and removes the extraction directory
+pub(crate) fn finish_extraction(temp_dir: &Path, version: &Version, paths: &Paths) -> Result<()> {
+    let extracted_path = match archive::distribution_dir(temp_dir, &version.extracted_dir_name()) {
+        Ok(path) => path,
+        Err(e) => {
+            fs::remove_dir_all(temp_dir).ok();
+            return Err(e);
+        }
+    };
+    let final_path = paths.version_dir(version);
+
+    if final_path.exists() {
+        fs::remove_dir_all(&final_path)?;
+    }
+
+    fs::rename(&extracted_path, &final_path).map_err(|e| {
+        Error::ExtractionFailed(format!("failed to move extracted directory: {}", e))
+    })?;
+
+    // an archive without a top-level directory was moved into place as a whole
+    if temp_dir.exists() {
+        fs::remove_dir_all(temp_dir)?;
+    }
+    debug!(path = %final_path.display(), "archive extracted");
+
+    Ok(())
+}
+
+/// Reads chunks sent over a channel, e.g. from an HTTP response body, as one stream
+pub struct ChannelReader<T> {
+    rx: mpsc::Receiver<T>,
+    current: Option<T>,
+    offset: usize,
+}
+
+impl<T: AsRef<[u8]>> ChannelReader<T> {
+    pub fn new(rx: mpsc::Receiver<T>) -> Self {
+        Self {
+            rx,
+            current: None,
+            offset: 0,
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>> Read for ChannelReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            if let Some(ref chunk) = self.current {
+                let remaining = &chunk.as_ref()[self.offset..];
+                if !remaining.is_empty() {
+                    let n = remaining.len().min(buf.len());
+                    buf[..n].copy_from_slice(&remaining[..n]);
+                    self.offset += n;
+                    return Ok(n);
+                }
+            }
+            // blocks the extractor thread until the next chunk arrives; None means end of stream
+            match self.rx.blocking_recv() {
+                Some(chunk) => {
+                    self.current = Some(chunk);
+                    self.offset = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+    }
+}
+
 impl Default for Downloader {
     fn default() -> Self {
         Self::new()
@@ -205,7 +428,7 @@ pub fn copy_default_config(paths: &Paths, version: &Version) -> Result<()> {
     Ok(())
 }
 
-fn copy_dir_recursive(src: &Path, dest: &Path) -> io::Result<()> {
+pub(crate) fn copy_dir_recursive(src: &Path, dest: &Path) -> io::Result<()> {
     fs::create_dir_all(dest)?;
 
     for entry in fs::read_dir(src)? {
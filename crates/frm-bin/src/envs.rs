@@ -0,0 +1,223 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Named environments: multiple configurations of the same installed version.
+//!
+//! An environment lives under `versions/<version>/envs/<name>` (for a shared version,
+//! `shared-versions/<version>/envs/<name>`) and has its own copy of `etc/rabbitmq`,
+//! its own data directory, and its own log directory.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use crate::Result;
+use crate::common::env_vars::{
+    RABBITMQ_ADVANCED_CONFIG_FILE, RABBITMQ_CONFIG_FILE, RABBITMQ_CONFIG_FILES,
+    RABBITMQ_ENABLED_PLUGINS_FILE, RABBITMQ_LOG_BASE, RABBITMQ_MNESIA_BASE, RABBITMQ_NODENAME,
+};
+use crate::config::Config;
+use crate::download::copy_dir_recursive;
+use crate::errors::Error;
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::shared;
+use crate::version::Version;
+
+pub fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidEnvName(name.to_string()))
+    }
+}
+
+pub fn exists(paths: &Paths, version: &Version, name: &str) -> bool {
+    paths.version_env_dir(version, name).is_dir()
+}
+
+/// Creates an environment by cloning the version's `etc/rabbitmq`
+pub fn create(paths: &Paths, version: &Version, name: &str) -> Result<PathBuf> {
+    validate_name(name)?;
+    if !paths.version_installed(version) {
+        return Err(Error::VersionNotInstalled(version.clone()));
+    }
+    if exists(paths, version, name) {
+        return Err(Error::EnvAlreadyExists {
+            version: version.clone(),
+            name: name.to_string(),
+        });
+    }
+    shared::prepare(paths, version)?;
+
+    let etc_src = paths.version_etc_dir(version);
+    let etc_dest = paths.version_env_etc_dir(version, name);
+    if etc_src.exists() {
+        copy_dir_recursive(&etc_src, &etc_dest)?;
+    } else {
+        fs::create_dir_all(&etc_dest)?;
+    }
+    fs::create_dir_all(paths.version_env_data_dir(version, name))?;
+    fs::create_dir_all(paths.version_env_log_dir(version, name))?;
+
+    Ok(paths.version_env_dir(version, name))
+}
+
+pub fn list(paths: &Paths, version: &Version) -> Result<Vec<String>> {
+    let envs_dir = paths.version_envs_dir(version);
+    if !envs_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(envs_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && let Some(name) = entry.file_name().to_str()
+            && validate_name(name).is_ok()
+        {
+            names.push(name.to_string());
+        }
+    }
+
+    names.sort();
+    Ok(names)
+}
+
+pub fn delete(paths: &Paths, version: &Version, name: &str) -> Result<()> {
+    ensure_exists(paths, version, name)?;
+    fs::remove_dir_all(paths.version_env_dir(version, name))?;
+    Ok(())
+}
+
+pub fn ensure_exists(paths: &Paths, version: &Version, name: &str) -> Result<()> {
+    validate_name(name)?;
+    if exists(paths, version, name) {
+        Ok(())
+    } else {
+        Err(Error::EnvNotFound {
+            version: version.clone(),
+            name: name.to_string(),
+        })
+    }
+}
+
+/// The node data directory (`RABBITMQ_MNESIA_BASE`) of the version's own configuration,
+/// which can be moved out of the version directory with `frm data-dir`, or of a named environment
+pub fn data_dir(paths: &Paths, version: &Version, env: Option<&str>) -> Result<PathBuf> {
+    Ok(match env {
+        Some(name) => paths.version_env_data_dir(version, name),
+        None => VersionMetadata::load(paths, version)?
+            .data_dir
+            .unwrap_or_else(|| paths.version_data_dir(version)),
+    })
+}
+
+/// Environment variables for `rabbitmq-server`: the version's own configuration,
+/// or that of a named environment
+pub fn node_env_vars(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+) -> Result<Vec<(&'static str, PathBuf)>> {
+    let (etc_dir, data_dir, log_dir) = match env {
+        Some(name) => (
+            paths.version_env_etc_dir(version, name),
+            paths.version_env_data_dir(version, name),
+            paths.version_env_log_dir(version, name),
+        ),
+        // a shared version is read-only, so its defaults under RABBITMQ_HOME cannot be used
+        None if paths.version_shared(version) => (
+            paths.version_etc_dir(version),
+            data_dir(paths, version, None)?,
+            paths.version_var_log_dir(version),
+        ),
+        None => {
+            let mut vars = vec![(RABBITMQ_CONFIG_FILES, paths.version_confd_dir(version))];
+            if let Some(dir) = VersionMetadata::load(paths, version)?.data_dir {
+                vars.push((RABBITMQ_MNESIA_BASE, dir));
+            }
+            return Ok(vars);
+        }
+    };
+
+    Ok(vec![
+        (RABBITMQ_CONFIG_FILE, etc_dir.join("rabbitmq.conf")),
+        (RABBITMQ_CONFIG_FILES, etc_dir.join("conf.d")),
+        (
+            RABBITMQ_ADVANCED_CONFIG_FILE,
+            etc_dir.join("advanced.config"),
+        ),
+        (
+            RABBITMQ_ENABLED_PLUGINS_FILE,
+            etc_dir.join("enabled_plugins"),
+        ),
+        (RABBITMQ_MNESIA_BASE, data_dir),
+        (RABBITMQ_LOG_BASE, log_dir),
+    ])
+}
+
+/// `RABBITMQ_NODENAME` for nodes and CLI tools when a node name template is configured
+pub fn node_name_env_vars(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+) -> Result<Vec<(&'static str, String)>> {
+    Ok(Config::load(paths)?
+        .node_name(version, env)
+        .map(|name| vec![(RABBITMQ_NODENAME, name)])
+        .unwrap_or_default())
+}
+
+/// A node started with `frm fg node --fresh`: a temporary data and log directory
+/// and a generated node name. The directory is deleted when this value is dropped.
+#[derive(Debug)]
+pub struct FreshNode {
+    pub dir: PathBuf,
+    pub node_name: String,
+}
+
+impl FreshNode {
+    pub fn create(version: &Version) -> Result<Self> {
+        let id = process::id();
+        let dir = env::temp_dir().join(format!("frm-fresh-{}-{}", version, id));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(dir.join("data"))?;
+        fs::create_dir_all(dir.join("log"))?;
+
+        Ok(Self {
+            dir,
+            // the host name is appended by RabbitMQ
+            node_name: format!("rabbit-fresh-{}", id),
+        })
+    }
+
+    /// Overrides the data directory, log directory, and node name of [`node_env_vars`]
+    pub fn env_vars(&self) -> Vec<(&'static str, OsString)> {
+        vec![
+            (RABBITMQ_MNESIA_BASE, self.dir.join("data").into_os_string()),
+            (RABBITMQ_LOG_BASE, self.dir.join("log").into_os_string()),
+            (RABBITMQ_NODENAME, OsString::from(&self.node_name)),
+        ]
+    }
+}
+
+impl Drop for FreshNode {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
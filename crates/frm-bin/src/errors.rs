@@ -21,6 +21,14 @@ pub enum Error {
     #[error("version {0} is already installed")]
     VersionAlreadyInstalled(Version),
 
+    #[error(
+        "RabbitMQ {version} is denylisted: {reason} (use --allow-denylisted to install it anyway)"
+    )]
+    DeniedRelease {
+        version: Box<Version>,
+        reason: String,
+    },
+
     #[error("invalid version format: {0}")]
     InvalidVersion(String),
 
@@ -33,12 +41,24 @@ pub enum Error {
     #[error("extraction failed: {0}")]
     ExtractionFailed(String),
 
+    #[error(
+        "unexpected archive layout: expected a top-level {expected} directory or another one with sbin/rabbitmq-server, the archive contains:\n  {}",
+        .contents.join("\n  ")
+    )]
+    UnexpectedArchiveLayout {
+        expected: String,
+        contents: Vec<String>,
+    },
+
     #[error("configuration error: {0}")]
     Config(String),
 
     #[error("unknown tool: {0}")]
     UnknownTool(String),
 
+    #[error("unknown command: {0}")]
+    UnknownCommand(String),
+
     #[error("unknown config file: {0}")]
     UnknownConfigFile(String),
 
@@ -87,45 +107,365 @@ pub enum Error {
     #[error("invalid date/time: {0}")]
     InvalidDateTime(String),
 
+    #[error("invalid size: {0} (expected e.g. 20GB, 500MiB, or a number of bytes)")]
+    InvalidSize(String),
+
     #[error("version mismatch: expected {expected}, detected {detected} in tarball filename")]
     TanzuVersionMismatch {
-        expected: Version,
-        detected: Version,
+        expected: Box<Version>,
+        detected: Box<Version>,
     },
 
     #[error("signature verification failed: {0}")]
     SignatureVerificationFailed(String),
+
+    #[error("version {0} is not in the trash")]
+    NotInTrash(Version),
+
+    #[error("found {0} problem(s)")]
+    ProblemsFound(usize),
+
+    #[error("invalid environment name '{0}': use letters, digits, '-', and '_'")]
+    InvalidEnvName(String),
+
+    #[error("environment '{name}' does not exist for version {version}")]
+    EnvNotFound { version: Version, name: String },
+
+    #[error("environment '{name}' already exists for version {version}")]
+    EnvAlreadyExists { version: Version, name: String },
+
+    #[error("{dir} is read-only, and 'frm {command}' needs to write to it")]
+    ReadOnly { dir: String, command: String },
+
+    #[error("invalid workspace name '{0}': use letters, digits, '-', and '_'")]
+    InvalidWorkspaceName(String),
+
+    #[error("workspace '{0}' does not exist")]
+    WorkspaceNotFound(String),
+
+    #[error("workspace '{0}' already exists (use --force to replace it)")]
+    WorkspaceAlreadyExists(String),
+
+    #[error("no installed version matches {0}")]
+    NoMatchingVersion(String),
+
+    #[error(
+        "RabbitMQ {0} has no provenance record: it was installed by an earlier frm release, \
+        imported, or linked (reinstall it to record one)"
+    )]
+    NoProvenance(Version),
+
+    #[error("invalid key name '{0}': use letters, digits, '-', and '_'")]
+    InvalidKeyName(String),
+
+    #[error("signing key '{0}' is not in the keyring")]
+    KeyNotFound(String),
+
+    #[error("signing key '{0}' is already in the keyring (use 'frm keys refresh' to update it)")]
+    KeyAlreadyExists(String),
+
+    #[error("'{0}' is the RabbitMQ release signing key, which cannot be removed")]
+    BuiltInKey(String),
+
+    #[error("{0} is not an ASCII-armored PGP public key")]
+    InvalidSigningKey(String),
+
+    #[error("{file} is not valid: {reason}")]
+    InvalidConfigFile { file: String, reason: String },
+
+    #[error("could not connect to {address}: {reason}")]
+    PingFailed { address: String, reason: String },
+
+    #[error("stream smoke test against {address} failed: {reason}")]
+    SmokeTestFailed { address: String, reason: String },
+
+    #[error("{failed} of {total} version(s) failed")]
+    MatrixRunFailed { failed: usize, total: usize },
+
+    #[error("{failed} of {total} installation(s) failed verification")]
+    VerificationFailed { failed: usize, total: usize },
+
+    #[error("the state was imported, but these versions could not be installed: {versions}")]
+    StateImportIncomplete { versions: String },
+
+    #[error("{failed} of {total} health check(s) failed for {node}")]
+    HealthCheckFailed {
+        node: String,
+        failed: usize,
+        total: usize,
+    },
+
+    #[error("java not found: install a JDK or JRE, or set JAVA_HOME")]
+    JavaNotFound,
+
+    #[error("{tool} is not installed (use 'frm toolbox install {tool}' first)")]
+    ToolNotInstalled { tool: String },
+
+    #[error("no {tool} release artifact found for this platform in release {release}")]
+    ToolArtifactNotFound { tool: String, release: String },
+
+    #[error("{0} does not look like a RabbitMQ installation: sbin/rabbitmq-server not found")]
+    InvalidLinkTarget(String),
+
+    #[error("cannot infer a version from '{0}': use --name (e.g., --name 4.3.0+dev.local)")]
+    LinkNameRequired(String),
+
+    #[error("RabbitMQ {0} is linked to an external directory (use 'frm unlink {0}' instead)")]
+    VersionIsLinked(Version),
+
+    #[error("RabbitMQ {0} is installed in the shared directory, which frm does not modify")]
+    VersionIsShared(Version),
+
+    #[error("RabbitMQ {0} is not a linked version")]
+    VersionNotLinked(Version),
+
+    #[error("RabbitMQ {version} is not installed by {provider}")]
+    NotInstalledByProvider { provider: String, version: Version },
+
+    #[error("invalid manifest: {0}")]
+    InvalidManifest(String),
+
+    #[error("invalid state archive: {0}")]
+    InvalidStateArchive(String),
+
+    #[error("no running node of RabbitMQ {0} found (start one with 'frm bg start -V {0}')")]
+    NoRunningNode(Version),
+
+    #[error(
+        "the management UI at {url} is not responding: {reason} (is the node running and the rabbitmq_management plugin enabled?)"
+    )]
+    ManagementUnavailable { url: String, reason: String },
+
+    #[error("RabbitMQ {version} has several running nodes ({nodes}), pick one with --node")]
+    AmbiguousNode { version: Version, nodes: String },
+
+    #[error("RabbitMQ {0} has a running node, stop it first with 'frm bg stop -V {0}'")]
+    NodeRunning(Version),
+
+    #[error("cannot bisect: {0}")]
+    InvalidBisection(String),
+
+    #[error("the {kind} directory of RabbitMQ {version} does not exist: {path}")]
+    MissingPath {
+        version: Version,
+        kind: String,
+        path: String,
+    },
+
+    #[error(
+        "{node} did not stop within {seconds} seconds (use --kill to kill it after the timeout)"
+    )]
+    NodeStopTimedOut { node: String, seconds: u64 },
+
+    #[error("invalid Erlang cookie: {0}")]
+    InvalidCookie(String),
+
+    #[error("{0} is locked by another frm process")]
+    Locked(String),
+
+    #[error("{0} requires root, run it with sudo")]
+    RootRequired(String),
+
+    #[error(
+        "no executable script named '{name}' in {dir} (list them with 'frm run-script --list')"
+    )]
+    ScriptNotFound { name: String, dir: String },
+
+    #[error("cannot migrate: {0}")]
+    MigrationNotPossible(String),
+
+    #[error("no supported upgrade path from {from} to {to}")]
+    UnsupportedUpgrade {
+        from: Box<Version>,
+        to: Box<Version>,
+    },
+}
+
+impl Error {
+    pub fn class(&self) -> FailureClass {
+        match self {
+            Error::VersionNotInstalled(_) => FailureClass::NotFound,
+            Error::VersionAlreadyInstalled(_) => FailureClass::AlreadyExists,
+            Error::DeniedRelease { .. } => FailureClass::Validation,
+            Error::InvalidVersion(_) => FailureClass::Usage,
+            Error::DownloadFailed(_) => FailureClass::Network,
+            Error::ReleaseNotFound(_) => FailureClass::NotFound,
+            Error::ExtractionFailed(_) => FailureClass::Other,
+            Error::UnexpectedArchiveLayout { .. } => FailureClass::Other,
+            Error::Config(_) => FailureClass::Config,
+            Error::UnknownTool(_) => FailureClass::Usage,
+            Error::UnknownCommand(_) => FailureClass::Usage,
+            Error::UnknownConfigFile(_) => FailureClass::Usage,
+            Error::FileNotFound(_) => FailureClass::NotFound,
+            Error::CommandFailed(_) => FailureClass::ChildProcess,
+            Error::Io(_) => FailureClass::Io,
+            Error::Http(_) => FailureClass::Network,
+            Error::TomlParse(_) => FailureClass::Validation,
+            Error::TomlSerialize(_) => FailureClass::Other,
+            Error::Json(_) => FailureClass::Validation,
+            Error::ExpectedAlphaVersion(_) => FailureClass::Usage,
+            Error::ExpectedNonAlphaVersion(_) => FailureClass::Usage,
+            Error::AlphaVersionNotSupported => FailureClass::Usage,
+            Error::ReleaseVersionNotSupported => FailureClass::Usage,
+            Error::NoAlphaReleasesFound => FailureClass::NotFound,
+            Error::NoGAVersionsInstalled => FailureClass::NotFound,
+            Error::NoAlphaVersionsInstalled => FailureClass::NotFound,
+            Error::InvalidDateTime(_) => FailureClass::Usage,
+            Error::InvalidSize(_) => FailureClass::Usage,
+            Error::TanzuVersionMismatch { .. } => FailureClass::Validation,
+            Error::SignatureVerificationFailed(_) => FailureClass::Validation,
+            Error::NotInTrash(_) => FailureClass::NotFound,
+            Error::ProblemsFound(_) => FailureClass::Other,
+            Error::InvalidEnvName(_) => FailureClass::Usage,
+            Error::EnvNotFound { .. } => FailureClass::NotFound,
+            Error::EnvAlreadyExists { .. } => FailureClass::AlreadyExists,
+            Error::ReadOnly { .. } => FailureClass::Io,
+            Error::InvalidWorkspaceName(_) => FailureClass::Usage,
+            Error::WorkspaceNotFound(_) => FailureClass::NotFound,
+            Error::WorkspaceAlreadyExists(_) => FailureClass::AlreadyExists,
+            Error::NoMatchingVersion(_) => FailureClass::NotFound,
+            Error::NoProvenance(_) => FailureClass::NotFound,
+            Error::InvalidKeyName(_) => FailureClass::Usage,
+            Error::KeyNotFound(_) => FailureClass::NotFound,
+            Error::KeyAlreadyExists(_) => FailureClass::AlreadyExists,
+            Error::BuiltInKey(_) => FailureClass::Usage,
+            Error::InvalidSigningKey(_) => FailureClass::Validation,
+            Error::InvalidConfigFile { .. } => FailureClass::Validation,
+            Error::PingFailed { .. } => FailureClass::Unavailable,
+            Error::SmokeTestFailed { .. } => FailureClass::Unavailable,
+            Error::MatrixRunFailed { .. } => FailureClass::Other,
+            Error::VerificationFailed { .. } => FailureClass::Validation,
+            Error::StateImportIncomplete { .. } => FailureClass::Other,
+            Error::HealthCheckFailed { .. } => FailureClass::Unavailable,
+            Error::JavaNotFound => FailureClass::Unavailable,
+            Error::ToolNotInstalled { .. } => FailureClass::NotFound,
+            Error::ToolArtifactNotFound { .. } => FailureClass::NotFound,
+            Error::UnsupportedUpgrade { .. } => FailureClass::Usage,
+            Error::InvalidLinkTarget(_) => FailureClass::Validation,
+            Error::LinkNameRequired(_) => FailureClass::Usage,
+            Error::VersionIsLinked(_) => FailureClass::Usage,
+            Error::VersionNotLinked(_) => FailureClass::Usage,
+            Error::VersionIsShared(_) => FailureClass::Usage,
+            Error::NotInstalledByProvider { .. } => FailureClass::NotFound,
+            Error::InvalidStateArchive(_) => FailureClass::Validation,
+            Error::InvalidManifest(_) => FailureClass::Validation,
+            Error::NoRunningNode(_) => FailureClass::Unavailable,
+            Error::AmbiguousNode { .. } => FailureClass::Usage,
+            Error::NodeRunning(_) => FailureClass::Usage,
+            Error::InvalidBisection(_) => FailureClass::Usage,
+            Error::MissingPath { .. } => FailureClass::MissingPath,
+            Error::NodeStopTimedOut { .. } => FailureClass::Other,
+            Error::InvalidCookie(_) => FailureClass::Validation,
+            Error::ManagementUnavailable { .. } => FailureClass::Unavailable,
+            Error::Locked(_) => FailureClass::Locked,
+            Error::RootRequired(_) => FailureClass::Usage,
+            Error::ScriptNotFound { .. } => FailureClass::NotFound,
+            Error::MigrationNotPossible(_) => FailureClass::Usage,
+        }
+    }
 }
 
 impl ExitCodeProvider for Error {
     fn exit_code(&self) -> ExitCode {
+        self.class().exit_code()
+    }
+}
+
+/// Classes of failures, each with its own exit code so that scripts can tell them apart.
+/// Codes follow the BSD `sysexits.h` conventions, see 'frm exit-codes'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureClass {
+    Usage,
+    Validation,
+    NotFound,
+    Unavailable,
+    Other,
+    ChildProcess,
+    MissingPath,
+    AlreadyExists,
+    Io,
+    Locked,
+    Network,
+    Config,
+}
+
+impl FailureClass {
+    /// In exit code order
+    pub const ALL: [FailureClass; 12] = [
+        FailureClass::Usage,
+        FailureClass::Validation,
+        FailureClass::NotFound,
+        FailureClass::Unavailable,
+        FailureClass::Other,
+        FailureClass::ChildProcess,
+        FailureClass::MissingPath,
+        FailureClass::AlreadyExists,
+        FailureClass::Io,
+        FailureClass::Locked,
+        FailureClass::Network,
+        FailureClass::Config,
+    ];
+
+    pub fn exit_code(&self) -> ExitCode {
+        match self {
+            FailureClass::Usage => ExitCode::Usage,
+            FailureClass::Validation => ExitCode::DataErr,
+            FailureClass::NotFound => ExitCode::NoInput,
+            FailureClass::Unavailable => ExitCode::Unavailable,
+            FailureClass::Other => ExitCode::Software,
+            FailureClass::ChildProcess => ExitCode::OsErr,
+            FailureClass::MissingPath => ExitCode::OsFile,
+            FailureClass::AlreadyExists => ExitCode::CantCreat,
+            FailureClass::Io => ExitCode::IoErr,
+            FailureClass::Locked => ExitCode::TempFail,
+            FailureClass::Network => ExitCode::Protocol,
+            FailureClass::Config => ExitCode::Config,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FailureClass::Usage => "usage",
+            FailureClass::Validation => "validation",
+            FailureClass::NotFound => "not-found",
+            FailureClass::Unavailable => "unavailable",
+            FailureClass::Other => "other",
+            FailureClass::ChildProcess => "child-process",
+            FailureClass::MissingPath => "missing-path",
+            FailureClass::AlreadyExists => "already-exists",
+            FailureClass::Io => "io",
+            FailureClass::Locked => "locked",
+            FailureClass::Network => "network",
+            FailureClass::Config => "config",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
         match self {
-            Error::VersionNotInstalled(_) => ExitCode::NoInput,
-            Error::VersionAlreadyInstalled(_) => ExitCode::CantCreat,
-            Error::InvalidVersion(_) => ExitCode::Usage,
-            Error::DownloadFailed(_) => ExitCode::Unavailable,
-            Error::ReleaseNotFound(_) => ExitCode::NoInput,
-            Error::ExtractionFailed(_) => ExitCode::Software,
-            Error::Config(_) => ExitCode::Config,
-            Error::UnknownTool(_) => ExitCode::Usage,
-            Error::UnknownConfigFile(_) => ExitCode::Usage,
-            Error::FileNotFound(_) => ExitCode::NoInput,
-            Error::CommandFailed(_) => ExitCode::Software,
-            Error::Io(_) => ExitCode::IoErr,
-            Error::Http(_) => ExitCode::Protocol,
-            Error::TomlParse(_) => ExitCode::DataErr,
-            Error::TomlSerialize(_) => ExitCode::Software,
-            Error::Json(_) => ExitCode::DataErr,
-            Error::ExpectedAlphaVersion(_) => ExitCode::Usage,
-            Error::ExpectedNonAlphaVersion(_) => ExitCode::Usage,
-            Error::AlphaVersionNotSupported => ExitCode::Usage,
-            Error::ReleaseVersionNotSupported => ExitCode::Usage,
-            Error::NoAlphaReleasesFound => ExitCode::NoInput,
-            Error::NoGAVersionsInstalled => ExitCode::NoInput,
-            Error::NoAlphaVersionsInstalled => ExitCode::NoInput,
-            Error::InvalidDateTime(_) => ExitCode::Usage,
-            Error::TanzuVersionMismatch { .. } => ExitCode::DataErr,
-            Error::SignatureVerificationFailed(_) => ExitCode::DataErr,
+            FailureClass::Usage => {
+                "invalid arguments, e.g. a malformed version or size, or an unsupported combination"
+            }
+            FailureClass::Validation => {
+                "invalid data: a corrupt state archive or manifest, a failed signature check, a denylisted release"
+            }
+            FailureClass::NotFound => {
+                "a version, environment, tool, release, or file is not installed or does not exist"
+            }
+            FailureClass::Unavailable => {
+                "no running node, an unresponsive node, or Java is missing"
+            }
+            FailureClass::Other => "problems found by 'frm doctor', extraction and internal errors",
+            FailureClass::ChildProcess => {
+                "a program frm ran (rabbitmqctl, gpg, docker, a node) could not be started or failed"
+            }
+            FailureClass::MissingPath => {
+                "a directory of an installed version checked with 'path --check' does not exist"
+            }
+            FailureClass::AlreadyExists => "a version or environment already exists",
+            FailureClass::Io => "reading or writing files failed",
+            FailureClass::Locked => "another frm process holds the metadata lock, try again later",
+            FailureClass::Network => "a download or a GitHub API request failed",
+            FailureClass::Config => "frm's configuration is invalid",
         }
     }
 }
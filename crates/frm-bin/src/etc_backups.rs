@@ -0,0 +1,133 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Before frm replaces a configuration file, the current file is copied to
+//! `.frm-backups/<file name>.<timestamp>` next to it, so that the change can be rolled back.
+//! RabbitMQ does not read that directory. Only the [`MAX_BACKUPS`] most recent backups
+//! of every file are kept.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Result;
+
+pub const BACKUPS_DIR_NAME: &str = ".frm-backups";
+pub const MAX_BACKUPS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backup {
+    pub path: PathBuf,
+    /// Milliseconds since the Unix epoch
+    pub created_at: u64,
+}
+
+pub fn backups_dir(file: &Path) -> PathBuf {
+    file.parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(BACKUPS_DIR_NAME)
+}
+
+/// Copies `file` to its backups directory. Returns `None` if `file` does not exist.
+pub fn create(file: &Path) -> Result<Option<Backup>> {
+    if !file.is_file() {
+        return Ok(None);
+    }
+
+    let dir = backups_dir(file);
+    fs::create_dir_all(&dir)?;
+    // two backups made within the same millisecond must not overwrite each other
+    let mut created_at = now_millis();
+    while dir.join(backup_file_name(file, created_at)).exists() {
+        created_at += 1;
+    }
+    let path = dir.join(backup_file_name(file, created_at));
+    fs::copy(file, &path)?;
+
+    let backups = list(file)?;
+    let excess = backups.len().saturating_sub(MAX_BACKUPS);
+    for old in &backups[..excess] {
+        fs::remove_file(&old.path)?;
+    }
+
+    Ok(Some(Backup { path, created_at }))
+}
+
+/// Backups of `file`, oldest first
+pub fn list(file: &Path) -> Result<Vec<Backup>> {
+    let dir = backups_dir(file);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("{}.", file_name(file));
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if let Some(name) = entry.file_name().to_str()
+            && let Some(created_at) = name
+                .strip_prefix(&prefix)
+                .and_then(|ts| ts.parse::<u64>().ok())
+        {
+            backups.push(Backup {
+                path: entry.path(),
+                created_at,
+            });
+        }
+    }
+
+    backups.sort_by_key(|b| b.created_at);
+    Ok(backups)
+}
+
+/// Replaces `file` with the content of `backup`. The current file is backed up first,
+/// so a revert can be reverted, too.
+pub fn revert(file: &Path, backup: &Backup) -> Result<Option<Backup>> {
+    let content = fs::read(&backup.path)?;
+    let current = create(file)?;
+    write_atomically(file, &content)?;
+    Ok(current)
+}
+
+/// Backs up `file`, then writes `content` to it atomically
+pub fn replace(file: &Path, content: &[u8]) -> Result<Option<Backup>> {
+    let backup = create(file)?;
+    write_atomically(file, content)?;
+    Ok(backup)
+}
+
+/// Writes `content` to a temporary file next to `path` and renames it over `path`,
+/// so that `path` never has partial content
+pub fn write_atomically(path: &Path, content: &[u8]) -> io::Result<()> {
+    let tmp = path.with_file_name(format!(".{}.frm-tmp", file_name(path)));
+    fs::write(&tmp, content)?;
+    if let Err(e) = fs::rename(&tmp, path) {
+        fs::remove_file(&tmp).ok();
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn backup_file_name(file: &Path, created_at: u64) -> String {
+    format!("{}.{}", file_name(file), created_at)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A cache for GitHub release feeds.
+//!
+//! A feed fetched less than [`FEED_CACHE_TTL`] ago is used as is. An older one is revalidated
+//! with `If-None-Match`, which does not count against GitHub's API rate limit when the feed
+//! has not changed, and is used when GitHub cannot be reached.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+
+use crate::Result;
+use crate::common::http::USER_AGENT;
+use crate::errors::Error;
+use crate::messages::print_warning;
+
+pub const FEED_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedFeed {
+    pub url: String,
+    pub etag: Option<String>,
+    /// When the feed was last fetched or revalidated, in seconds since the Unix epoch
+    pub fetched_at: u64,
+    pub body: String,
+}
+
+impl CachedFeed {
+    /// Returns `None` when there is no cached feed or it cannot be read
+    pub fn load(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn age(&self, now: u64) -> Duration {
+        Duration::from_secs(now.saturating_sub(self.fetched_at))
+    }
+
+    pub fn is_fresh(&self, now: u64, ttl: Duration) -> bool {
+        self.fetched_at <= now && self.age(now) < ttl
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Returns the body of a feed, from the cache at `cache_file` when it is younger than `ttl`.
+/// A zero `ttl` always revalidates the cached feed.
+#[instrument(level = "debug", skip(client, cache_file))]
+pub async fn fetch(
+    client: &reqwest::Client,
+    url: &str,
+    cache_file: &Path,
+    ttl: Duration,
+) -> Result<String> {
+    let now = now();
+    let cached = CachedFeed::load(cache_file).filter(|c| c.url == url);
+
+    if let Some(ref cached) = cached
+        && cached.is_fresh(now, ttl)
+    {
+        debug!(age_secs = cached.age(now).as_secs(), "using cached feed");
+        return Ok(cached.body.clone());
+    }
+
+    let mut request = client.get(url).header("User-Agent", USER_AGENT);
+    if let Some(etag) = cached.as_ref().and_then(|c| c.etag.as_deref()) {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => return fall_back(cached, now, e.to_string()),
+    };
+    let status = response.status();
+    debug!(status = %status, "feed response received");
+
+    if status == StatusCode::NOT_MODIFIED
+        && let Some(mut cached) = cached
+    {
+        cached.fetched_at = now;
+        cached.save(cache_file).ok();
+        return Ok(cached.body);
+    }
+    if !status.is_success() {
+        return fall_back(cached, now, format!("HTTP {}: {}", status, url));
+    }
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let body = match response.text().await {
+        Ok(body) => body,
+        Err(e) => return fall_back(cached, now, e.to_string()),
+    };
+
+    let fetched = CachedFeed {
+        url: url.to_string(),
+        etag,
+        fetched_at: now,
+        body,
+    };
+    if let Err(e) = fetched.save(cache_file) {
+        debug!(error = %e, "failed to cache feed");
+    }
+    Ok(fetched.body)
+}
+
+fn fall_back(cached: Option<CachedFeed>, now: u64, error: String) -> Result<String> {
+    match cached {
+        Some(cached) => {
+            print_warning(format!(
+                "Could not fetch the release feed ({}), using the one cached {} minute(s) ago",
+                error,
+                cached.age(now).as_secs() / 60
+            ));
+            Ok(cached.body)
+        }
+        None => Err(Error::DownloadFailed(error)),
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An opt-in, local-only log of executed commands stored as JSON lines.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::paths::Paths;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Ok,
+    Failed,
+    /// The command replaced the `frm` process (e.g. `cli`, `fg node`),
+    /// so its final outcome is not known.
+    Started,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok => write!(f, "ok"),
+            Outcome::Failed => write!(f, "failed"),
+            Outcome::Started => write!(f, "started"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub command: String,
+    pub args: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub outcome: Outcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn new(command: impl Into<String>, args: Vec<String>, version: Option<String>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            timestamp,
+            command: command.into(),
+            args,
+            version,
+            outcome: Outcome::Started,
+            error: None,
+        }
+    }
+
+    pub fn with_outcome(mut self, result: &Result<()>) -> Self {
+        match result {
+            Ok(()) => {
+                self.outcome = Outcome::Ok;
+                self.error = None;
+            }
+            Err(e) => {
+                self.outcome = Outcome::Failed;
+                self.error = Some(e.to_string());
+            }
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HistoryQuery {
+    pub version: Option<String>,
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+impl HistoryQuery {
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        if let Some(ref v) = self.version
+            && entry.version.as_deref() != Some(v.as_str())
+        {
+            return false;
+        }
+        if self.since.is_some_and(|ts| entry.timestamp < ts) {
+            return false;
+        }
+        if self.until.is_some_and(|ts| entry.timestamp > ts) {
+            return false;
+        }
+        true
+    }
+
+    /// Returns the matching entries in chronological order, keeping only the most recent `limit` ones
+    pub fn apply(&self, entries: Vec<HistoryEntry>) -> Vec<HistoryEntry> {
+        let matching: Vec<_> = entries.into_iter().filter(|e| self.matches(e)).collect();
+        match self.limit {
+            Some(limit) => {
+                let start = matching.len().saturating_sub(limit);
+                matching[start..].to_vec()
+            }
+            None => matching,
+        }
+    }
+}
+
+pub fn append(paths: &Paths, entry: &HistoryEntry) -> Result<()> {
+    fs::create_dir_all(paths.base_dir())?;
+
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    // arguments can carry secrets, so only the owner can read the file
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(paths.history_file())?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// The entry of the running command, until it is recorded
+static PENDING: Mutex<Option<(Paths, HistoryEntry)>> = Mutex::new(None);
+
+/// Holds the entry of the running command until it is recorded with [`take_pending`]
+/// or, by a command that replaces the `frm` process, with [`record_before_exec`]
+pub fn set_pending(paths: &Paths, entry: HistoryEntry) {
+    *PENDING.lock().unwrap() = Some((paths.clone(), entry));
+}
+
+/// The entry of the running command, unless [`record_before_exec`] already recorded it
+pub fn take_pending() -> Option<HistoryEntry> {
+    PENDING.lock().unwrap().take().map(|(_, entry)| entry)
+}
+
+/// Records the entry of the running command as started. The outcome of a command that
+/// replaces the `frm` process is never observed, so this is done right before it does.
+pub fn record_before_exec() {
+    if let Some((paths, entry)) = PENDING.lock().unwrap().take() {
+        append(&paths, &entry).ok();
+    }
+}
+
+/// Loads all entries, skipping lines that cannot be parsed (e.g. a line cut short by a crash)
+pub fn load(paths: &Paths) -> Result<Vec<HistoryEntry>> {
+    let path = paths.history_file();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
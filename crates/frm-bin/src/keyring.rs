@@ -0,0 +1,263 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Trusted signing keys used to verify release signatures.
+//!
+//! Keys live under `keys/` of the frm directory as `<name>.asc` files. The RabbitMQ release
+//! signing key, `rabbitmq`, is always trusted and is downloaded the first time it is needed.
+//! Keys added with 'frm keys add', e.g. the key an organization signs its internal builds
+//! with, are recorded in `keys/keyring.toml` with where they came from, a URL or a local file,
+//! so that 'frm keys refresh' can fetch them again.
+//!
+//! Verification uses a GnuPG home directory of its own, `keys/.gnupg`, rebuilt from the trusted
+//! keys every time, so that the user's own keyring is neither used nor modified.
+
+use std::collections::BTreeMap;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::common::http::USER_AGENT;
+use crate::common::urls::RABBITMQ_SIGNING_KEY_URL;
+use crate::envs;
+use crate::errors::Error;
+use crate::paths::Paths;
+
+/// The name of the RabbitMQ release signing key in the keyring
+pub const RABBITMQ_KEY_NAME: &str = "rabbitmq";
+
+const SOURCES_FILE: &str = "keyring.toml";
+const GPG_HOME_DIR: &str = ".gnupg";
+const KEY_FILE_EXTENSION: &str = "asc";
+const ARMORED_KEY_HEADER: &str = "-----BEGIN PGP PUBLIC KEY BLOCK-----";
+/// Where earlier releases kept the RabbitMQ release signing key
+const LEGACY_KEY_FILE: &str = "rabbitmq-release-signing-key.asc";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedKey {
+    pub name: String,
+    /// A URL or a local file
+    pub source: String,
+    pub path: PathBuf,
+    /// The RabbitMQ release signing key
+    pub built_in: bool,
+}
+
+impl TrustedKey {
+    /// The built-in key is downloaded the first time a signature is checked
+    pub fn downloaded(&self) -> bool {
+        self.path.is_file()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Sources {
+    #[serde(default)]
+    keys: BTreeMap<String, String>,
+}
+
+impl Sources {
+    fn load(paths: &Paths) -> Result<Self> {
+        let file = paths.keys_dir().join(SOURCES_FILE);
+        if !file.exists() {
+            return Ok(Self::default());
+        }
+        Ok(toml::from_str(&fs::read_to_string(file)?)?)
+    }
+
+    fn save(&self, paths: &Paths) -> Result<()> {
+        fs::create_dir_all(paths.keys_dir())?;
+        fs::write(
+            paths.keys_dir().join(SOURCES_FILE),
+            toml::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+}
+
+pub fn validate_name(name: &str) -> Result<()> {
+    envs::validate_name(name).map_err(|_| Error::InvalidKeyName(name.to_string()))
+}
+
+pub fn key_file(paths: &Paths, name: &str) -> PathBuf {
+    paths
+        .keys_dir()
+        .join(format!("{}.{}", name, KEY_FILE_EXTENSION))
+}
+
+/// The RabbitMQ release signing key first, then the added keys by name
+pub fn list(paths: &Paths) -> Result<Vec<TrustedKey>> {
+    let mut keys = vec![TrustedKey {
+        name: RABBITMQ_KEY_NAME.to_string(),
+        source: RABBITMQ_SIGNING_KEY_URL.to_string(),
+        path: key_file(paths, RABBITMQ_KEY_NAME),
+        built_in: true,
+    }];
+    keys.extend(
+        Sources::load(paths)?
+            .keys
+            .into_iter()
+            .map(|(name, source)| TrustedKey {
+                path: key_file(paths, &name),
+                name,
+                source,
+                built_in: false,
+            }),
+    );
+    Ok(keys)
+}
+
+/// Adds a key from a URL or a local file
+pub async fn add(client: &reqwest::Client, paths: &Paths, name: &str, source: &str) -> Result<()> {
+    validate_name(name)?;
+    let mut sources = Sources::load(paths)?;
+    if name == RABBITMQ_KEY_NAME || sources.keys.contains_key(name) {
+        return Err(Error::KeyAlreadyExists(name.to_string()));
+    }
+
+    // local files are recorded with absolute paths, so that refreshing works from any directory
+    let source = if is_url(source) {
+        source.to_string()
+    } else {
+        fs::canonicalize(source)
+            .map_err(|_| Error::FileNotFound(source.to_string()))?
+            .display()
+            .to_string()
+    };
+    let key = fetch(client, &source).await?;
+    write_key(paths, name, &key)?;
+    sources.keys.insert(name.to_string(), source);
+    sources.save(paths)
+}
+
+pub fn remove(paths: &Paths, name: &str) -> Result<()> {
+    if name == RABBITMQ_KEY_NAME {
+        return Err(Error::BuiltInKey(name.to_string()));
+    }
+    let mut sources = Sources::load(paths)?;
+    if sources.keys.remove(name).is_none() {
+        return Err(Error::KeyNotFound(name.to_string()));
+    }
+    let file = key_file(paths, name);
+    if file.exists() {
+        fs::remove_file(file)?;
+    }
+    sources.save(paths)
+}
+
+/// Fetches a key from its source again. The current copy is kept if the source
+/// cannot be fetched or no longer has a key.
+pub async fn refresh(client: &reqwest::Client, paths: &Paths, key: &TrustedKey) -> Result<()> {
+    let content = fetch(client, &key.source).await?;
+    write_key(paths, &key.name, &content)
+}
+
+/// Downloads the RabbitMQ release signing key if it is not in the keyring yet
+pub async fn ensure_built_in(client: &reqwest::Client, paths: &Paths) -> Result<()> {
+    let file = key_file(paths, RABBITMQ_KEY_NAME);
+    if file.exists() {
+        return Ok(());
+    }
+    let legacy = paths.downloads_dir().join(LEGACY_KEY_FILE);
+    if legacy.exists() {
+        fs::create_dir_all(paths.keys_dir())?;
+        fs::rename(legacy, file)?;
+        return Ok(());
+    }
+    let content = fetch(client, RABBITMQ_SIGNING_KEY_URL).await?;
+    write_key(paths, RABBITMQ_KEY_NAME, &content)
+}
+
+/// Rebuilds the GnuPG home directory used for verification from the trusted keys
+/// and returns it, to be passed to `gpg --homedir`
+pub fn gpg_home(paths: &Paths) -> Result<PathBuf> {
+    let dir = paths.keys_dir().join(GPG_HOME_DIR);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    // gpg warns about a home directory other users can read
+    #[cfg(unix)]
+    fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+
+    for key in list(paths)?.iter().filter(|key| key.downloaded()) {
+        import(&dir, &key.path)?;
+    }
+    Ok(dir)
+}
+
+fn import(gpg_home: &Path, key_file: &Path) -> Result<()> {
+    let output = Command::new("gpg")
+        .arg("--homedir")
+        .arg(gpg_home)
+        // public keys do not need an agent, which would outlive the directory
+        .args(["--batch", "--quiet", "--no-autostart", "--import"])
+        .arg(key_file)
+        .output()
+        .map_err(|e| Error::CommandFailed(format!("failed to run gpg --import: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(Error::CommandFailed(format!(
+            "gpg --import of {} failed: {}",
+            key_file.display(),
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    Ok(())
+}
+
+fn is_url(source: &str) -> bool {
+    source.starts_with("https://") || source.starts_with("http://")
+}
+
+async fn fetch(client: &reqwest::Client, source: &str) -> Result<String> {
+    let content = if is_url(source) {
+        let response = client
+            .get(source)
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await
+            .map_err(|e| Error::DownloadFailed(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(Error::DownloadFailed(format!(
+                "HTTP {}: {}",
+                response.status(),
+                source
+            )));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| Error::DownloadFailed(e.to_string()))?
+    } else {
+        let path = Path::new(source);
+        if !path.is_file() {
+            return Err(Error::FileNotFound(source.to_string()));
+        }
+        fs::read_to_string(path).map_err(|_| Error::InvalidSigningKey(source.to_string()))?
+    };
+
+    if !content.contains(ARMORED_KEY_HEADER) {
+        return Err(Error::InvalidSigningKey(source.to_string()));
+    }
+    Ok(content)
+}
+
+fn write_key(paths: &Paths, name: &str, content: &str) -> Result<()> {
+    fs::create_dir_all(paths.keys_dir())?;
+    let file = key_file(paths, name);
+    let tmp = file.with_extension("asc.tmp");
+    fs::write(&tmp, content)?;
+    fs::rename(tmp, file)?;
+    Ok(())
+}
@@ -10,17 +10,51 @@
 //!
 //! A tool for managing multiple RabbitMQ installations from the generic UNIX packages.
 
+pub mod aliases;
+pub mod amqp;
+pub mod archive;
+pub mod bisect;
 pub mod cli;
 pub mod commands;
 pub mod common;
 pub mod config;
+pub mod cookie;
+pub mod current;
+pub mod dedup;
+pub mod denylist;
 pub mod download;
+pub mod envs;
 pub mod errors;
+pub mod etc_backups;
+pub mod feed_cache;
+pub mod history;
+pub mod keyring;
+pub mod logging;
+pub mod manifest;
+pub mod matrix;
+pub mod messages;
+pub mod metadata;
+pub mod metadata_store;
+pub mod node_state;
+pub mod ownership;
 pub mod paths;
+pub mod perf_test;
+pub mod permissions;
+pub mod plugins;
+pub mod ports;
+pub mod profile;
+pub mod provenance;
+pub mod providers;
 pub mod releases;
+pub mod scripts;
+pub mod shared;
 pub mod shell;
+pub mod stream;
 pub mod tanzu;
 pub mod timestamps;
+pub mod toolbox;
+pub mod trash;
+pub mod workspace;
 
 pub use errors::Error;
 pub use rabbitmq_versioning as version;
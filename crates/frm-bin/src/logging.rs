@@ -0,0 +1,49 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Diagnostic logging for bug reports, enabled with `--debug` or `FRM_LOG`.
+//!
+//! Logs go to standard error, so they never mix with the output of commands such as `use`
+//! that is meant to be evaluated by a shell.
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::common::env_vars::FRM_LOG;
+
+const DEBUG_FILTER: &str = "frm=debug";
+
+/// The filter to use, if any: `FRM_LOG` takes precedence over `--debug`
+pub fn filter_directives(debug: bool, frm_log: Option<&str>) -> Option<String> {
+    match frm_log.map(str::trim) {
+        Some(directives) if !directives.is_empty() => Some(directives.to_string()),
+        _ if debug => Some(DEBUG_FILTER.to_string()),
+        _ => None,
+    }
+}
+
+/// Installs a subscriber that reports events and span timings (on span close) to standard error
+pub fn init(debug: bool) {
+    let frm_log = env::var(FRM_LOG).ok();
+    let Some(directives) = filter_directives(debug, frm_log.as_deref()) else {
+        return;
+    };
+
+    let filter = EnvFilter::try_new(&directives).unwrap_or_else(|_| EnvFilter::new(DEBUG_FILTER));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_target(false)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .try_init()
+        .ok();
+}
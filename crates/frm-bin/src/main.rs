@@ -6,49 +6,218 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::env;
 use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use bel7_cli::{ExitCode, ExitCodeProvider, print_error, print_info};
+use bel7_cli::{ExitCode, ExitCodeProvider};
+use clap::ArgMatches;
+use tracing::debug;
 
-use frm::cli::{CompletionShell, build_cli, get_version_arg};
+use frm::aliases;
+use frm::cli::{
+    CompletionShell, build_cli, get_running_nodes_arg, get_version_arg, subcommand_path,
+    writes_to_frm_dir,
+};
 use frm::commands;
+use frm::common::env_vars::{FRM_DIR, FRM_READ_ONLY};
+use frm::config::Config;
 use frm::errors::Error;
+use frm::history::{self, HistoryEntry, HistoryQuery};
+use frm::logging;
+use frm::messages::{self, MessageMode, print_error, print_hint, print_info};
+use frm::ownership;
 use frm::paths::Paths;
+use frm::profile;
+use frm::providers::Provider;
 use frm::releases::{find_latest_alpha, find_latest_ga_release};
 use frm::shell::Shell;
+use frm::toolbox::Tool;
 use frm::version::Version;
 
 fn resolve_version(paths: &Paths, version_arg: Option<&String>) -> Result<Version, Error> {
     if let Some(v) = version_arg {
         let v = v.trim();
-        if v.eq_ignore_ascii_case("latest") {
-            return paths
+        let resolved = if v.eq_ignore_ascii_case("latest") {
+            paths
                 .latest_ga_version()?
-                .ok_or(Error::NoGAVersionsInstalled);
-        }
-        return v.parse().map_err(Into::into);
+                .ok_or(Error::NoGAVersionsInstalled)
+        } else {
+            v.parse().map_err(Into::into)
+        };
+        debug!(
+            argument = v,
+            resolved = resolved.as_ref().ok().map(ToString::to_string),
+            "resolved version"
+        );
+        return resolved;
     }
 
     Err(Error::InvalidVersion("no version specified".into()))
 }
 
+fn output_options(matches: &ArgMatches) -> commands::OutputOptions {
+    commands::OutputOptions {
+        plain: matches.get_flag("plain"),
+        color: !matches.get_flag("no_color"),
+        verbose: matches
+            .try_get_one::<bool>("verbose")
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(false),
+    }
+}
+
+/// The value of `--file`, validated by clap
+fn etc_file(matches: &ArgMatches) -> Result<commands::EtcFile, Error> {
+    matches.get_one::<String>("file").unwrap().parse()
+}
+
+/// `--bytes`, or else `--lines`
+fn tail_amount(matches: &ArgMatches) -> commands::TailAmount {
+    match matches.get_one::<u64>("bytes") {
+        Some(bytes) => commands::TailAmount::Bytes(*bytes),
+        None => commands::TailAmount::Lines(*matches.get_one::<usize>("lines").unwrap()),
+    }
+}
+
 fn resolve_alpha_version(paths: &Paths, version_arg: Option<&String>) -> Result<Version, Error> {
     if let Some(v) = version_arg {
         let v = v.trim();
-        if v.eq_ignore_ascii_case("latest") {
-            return paths
+        let resolved = if v.eq_ignore_ascii_case("latest") {
+            paths
                 .latest_alpha_version()?
-                .ok_or(Error::NoAlphaVersionsInstalled);
-        }
-        return v.parse().map_err(Into::into);
+                .ok_or(Error::NoAlphaVersionsInstalled)
+        } else {
+            v.parse().map_err(Into::into)
+        };
+        debug!(
+            argument = v,
+            resolved = resolved.as_ref().ok().map(ToString::to_string),
+            "resolved alpha version"
+        );
+        return resolved;
     }
 
     Err(Error::InvalidVersion("no version specified".into()))
 }
 
+/// The version for `use`: without a version argument, the default version when `--with-default-fallback`
+/// or `use_default_fallback = true` in config.toml is set, so that a new shell gets a working PATH
+fn resolve_use_version(
+    paths: &Paths,
+    version_arg: Option<&String>,
+    with_default_fallback: bool,
+    resolve: fn(&Paths, Option<&String>) -> Result<Version, Error>,
+) -> Result<Version, Error> {
+    if version_arg.is_none() {
+        let config = Config::load(paths)?;
+        if (with_default_fallback || config.use_default_fallback)
+            && let Some(version) = config.default_version
+        {
+            // standard output is evaluated by the shell
+            messages::print_info_to_stderr(format!(
+                "No version specified, using the default version {}",
+                version
+            ));
+            return Ok(version);
+        }
+    }
+    resolve(paths, version_arg)
+}
+
+const REDACTED: &str = "<redacted>";
+
+fn history_entry(paths: &Paths, matches: &ArgMatches) -> Option<HistoryEntry> {
+    let (names, leaf) = subcommand_path(matches);
+    let command = names.join(" ");
+    // completion runs on every key press
+    if command.is_empty()
+        || command == "history"
+        || command == "__complete"
+        || command == "__supervise"
+    {
+        return None;
+    }
+
+    let raw_version = leaf
+        .try_get_one::<String>("version_opt")
+        .ok()
+        .flatten()
+        .or_else(|| leaf.try_get_one::<String>("version").ok().flatten());
+
+    // 'install latest' resolves against GitHub, not the local installations
+    let version = raw_version.map(|raw| {
+        if !raw.trim().eq_ignore_ascii_case("latest") || names.last() == Some(&"install") {
+            return raw.trim().to_string();
+        }
+        let resolved = if names.first() == Some(&"alphas") {
+            resolve_alpha_version(paths, Some(raw))
+        } else {
+            resolve_version(paths, Some(raw))
+        };
+        resolved
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| raw.clone())
+    });
+
+    // the history file must never contain the Erlang cookie
+    let secret = (command == "cookie set")
+        .then(|| leaf.get_one::<String>("cookie"))
+        .flatten();
+    let args = env::args_os()
+        .skip(1)
+        .map(|a| a.to_string_lossy().into_owned())
+        .map(|a| {
+            if secret == Some(&a) {
+                REDACTED.to_string()
+            } else {
+                a
+            }
+        })
+        .collect();
+
+    Some(HistoryEntry::new(command, args, version))
+}
+
+fn history_query(sub: &ArgMatches) -> Result<HistoryQuery, Error> {
+    let since = match sub.get_one::<String>("since") {
+        Some(s) => Some(commands::parse_datetime(s)?.timestamp() as u64),
+        None => None,
+    };
+    let until = match sub.get_one::<String>("until") {
+        Some(s) => Some(commands::parse_datetime(s)?.timestamp() as u64),
+        None => None,
+    };
+
+    Ok(HistoryQuery {
+        version: sub
+            .get_one::<String>("version")
+            .map(|v| v.trim().to_string()),
+        since,
+        until,
+        limit: sub.get_one::<usize>("limit").copied(),
+    })
+}
+
 #[tokio::main]
 async fn main() -> ExitCode {
-    let matches = build_cli().get_matches();
+    let cli = build_cli();
+    let args = match Paths::new() {
+        Ok(paths) => aliases::expand(
+            env::args_os().collect(),
+            &Config::load(&paths).unwrap_or_default().aliases,
+            &cli,
+        ),
+        Err(_) => env::args_os().collect(),
+    };
+    let matches = cli.get_matches_from(args);
+    logging::init(matches.get_flag("debug"));
+    if matches.get_flag("terse") {
+        messages::set_mode(MessageMode::Terse);
+    }
 
     let paths = match Paths::new() {
         Ok(p) => p,
@@ -58,11 +227,53 @@ async fn main() -> ExitCode {
         }
     };
 
+    let config = Config::load(&paths).unwrap_or_default();
+    if config.record_history
+        && let Some(entry) = history_entry(&paths, &matches)
+    {
+        history::set_pending(&paths, entry);
+    }
+
+    let command = subcommand_path(&matches).0.join(" ");
+    let started = Instant::now();
+    debug!(command, base_dir = %paths.base_dir().display(), "running");
+
+    // doctor reports and fixes this itself
+    if command != "doctor"
+        && command != "__complete"
+        && let Some(owner) = ownership::root_in_user_dir(paths.base_dir())
+    {
+        messages::print_warning_to_stderr(format!(
+            "frm is running as root but {} belongs to user {}: files created now will be owned by root \
+            and break later commands run as that user. Run frm without sudo, or afterwards fix with: \
+            sudo FRM_DIR={} frm doctor --fix-ownership",
+            paths.base_dir().display(),
+            owner.uid,
+            paths.base_dir().display()
+        ));
+    }
+
+    let (_, leaf) = subcommand_path(&matches);
+    if writes_to_frm_dir(&command, leaf) && !paths.writable() {
+        let e = Error::ReadOnly {
+            dir: paths.base_dir().display().to_string(),
+            command,
+        };
+        // history cannot be recorded either
+        print_error(e.to_string());
+        print_hint(format!(
+            "Commands that only read, such as 'frm releases list', 'frm releases use', and 'frm cli', \
+            still work. To make changes, point {} to a writable directory and unset {}.",
+            FRM_DIR, FRM_READ_ONLY
+        ));
+        return e.exit_code();
+    }
+
     let result = match matches.subcommand() {
-        Some(("status", _)) => commands::status(&paths),
+        Some(("status", sub)) => commands::status(&paths, output_options(sub)),
 
         Some(("releases", sub)) => match sub.subcommand() {
-            Some(("list", _)) => commands::list_releases(&paths),
+            Some(("list", list_sub)) => commands::list_releases(&paths, output_options(list_sub)),
             Some(("completions", completions_sub)) => {
                 let shell = completions_sub.get_one::<Shell>("shell").copied();
                 commands::completions_releases(&paths, shell)
@@ -70,14 +281,21 @@ async fn main() -> ExitCode {
             Some(("path", path_sub)) => {
                 let version_arg = path_sub.get_one::<String>("version");
 
+                let opts = commands::PathOptions {
+                    check: path_sub.get_one::<commands::PathKind>("check").copied(),
+                    print0: path_sub.get_flag("print0"),
+                };
+
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::path_release(&paths, &version),
+                    Ok(version) => commands::path_release(&paths, &version, opts),
                     Err(e) => Err(e),
                 }
             }
             Some(("install", install_sub)) => {
                 let version_arg = get_version_arg(install_sub);
                 let force = install_sub.get_flag("force");
+                let allow_denylisted = install_sub.get_flag("allow_denylisted");
+                let keep_archive = install_sub.get_flag("keep_archive");
 
                 match version_arg {
                     Some(v) if v.trim().eq_ignore_ascii_case("latest") => {
@@ -86,13 +304,29 @@ async fn main() -> ExitCode {
                         match find_latest_ga_release(&client).await {
                             Ok(v) => {
                                 print_info(format!("Found latest GA release: {}", v));
-                                commands::install_release(&paths, &v, force).await
+                                commands::install_release(
+                                    &paths,
+                                    &v,
+                                    force,
+                                    allow_denylisted,
+                                    keep_archive,
+                                )
+                                .await
                             }
                             Err(e) => Err(e),
                         }
                     }
                     Some(v) => match v.parse::<Version>() {
-                        Ok(version) => commands::install_release(&paths, &version, force).await,
+                        Ok(version) => {
+                            commands::install_release(
+                                &paths,
+                                &version,
+                                force,
+                                allow_denylisted,
+                                keep_archive,
+                            )
+                            .await
+                        }
                         Err(e) => Err(e.into()),
                     },
                     None => Err(Error::InvalidVersion("no version specified".into())),
@@ -108,9 +342,14 @@ async fn main() -> ExitCode {
             }
             Some(("uninstall", uninstall_sub)) => {
                 let version_arg = get_version_arg(uninstall_sub);
+                let yes = uninstall_sub.get_flag("yes");
+                let promote_next = uninstall_sub.get_flag("promote_next");
+                let running = get_running_nodes_arg(uninstall_sub);
 
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::uninstall_release(&paths, &version),
+                    Ok(version) => {
+                        commands::uninstall_release(&paths, &version, yes, promote_next, running)
+                    }
                     Err(e) => Err(e),
                 }
             }
@@ -125,10 +364,13 @@ async fn main() -> ExitCode {
                 }
                 Some(("tail", tail_sub)) => {
                     let version_arg = tail_sub.get_one::<String>("version");
-                    let lines = *tail_sub.get_one::<usize>("lines").unwrap();
+                    let amount = tail_amount(tail_sub);
+                    let follow = tail_sub.get_flag("follow");
 
                     match resolve_version(&paths, version_arg) {
-                        Ok(version) => commands::logs_tail_release(&paths, &version, lines),
+                        Ok(version) => {
+                            commands::logs_tail_release(&paths, &version, amount, follow)
+                        }
                         Err(e) => Err(e),
                     }
                 }
@@ -141,12 +383,17 @@ async fn main() -> ExitCode {
                     .unwrap();
                 let etc_file_str = cp_sub.get_one::<String>("etc_file").unwrap();
                 let version_arg = cp_sub.get_one::<String>("version");
+                let force = cp_sub.get_flag("force");
 
                 match etc_file_str.parse::<commands::EtcFile>() {
                     Ok(etc_file) => match resolve_version(&paths, version_arg) {
-                        Ok(version) => {
-                            commands::cp_etc_file_release(&paths, &version, &local_path, etc_file)
-                        }
+                        Ok(version) => commands::cp_etc_file_release(
+                            &paths,
+                            &version,
+                            &local_path,
+                            etc_file,
+                            force,
+                        ),
                         Err(e) => Err(e),
                     },
                     Err(e) => Err(e),
@@ -155,12 +402,68 @@ async fn main() -> ExitCode {
             Some(("use", use_sub)) => {
                 let version_arg = get_version_arg(use_sub);
                 let shell = use_sub.get_one::<Shell>("shell").copied();
+                let sbin_only = use_sub.get_flag("sbin_only");
+                let provider = use_sub.get_one::<Provider>("provider").copied();
+                let fallback = use_sub.get_flag("with_default_fallback");
+                let auto_install = commands::AutoInstall::resolve(
+                    &paths,
+                    use_sub.get_flag("auto_install"),
+                    use_sub.get_flag("yes"),
+                );
+
+                match resolve_use_version(&paths, version_arg, fallback, resolve_version)
+                    .and_then(|version| auto_install.map(|auto_install| (version, auto_install)))
+                {
+                    Ok((version, auto_install)) => commands::use_release_version(
+                        &paths,
+                        &version,
+                        shell,
+                        sbin_only,
+                        provider,
+                        auto_install,
+                        use_sub.get_flag("link"),
+                    ),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("pin", pin_sub)) => {
+                let version_arg = get_version_arg(pin_sub);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::pin_release(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("unpin", unpin_sub)) => {
+                let version_arg = get_version_arg(unpin_sub);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::unpin_release(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("info", info_sub)) => {
+                let version_arg = get_version_arg(info_sub);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::info_release(&paths, &version).await,
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("provenance", provenance_sub)) => {
+                let version_arg = get_version_arg(provenance_sub);
+                let format = *provenance_sub
+                    .get_one::<commands::ProvenanceFormat>("format")
+                    .unwrap();
 
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::use_release_version(&paths, &version, shell),
+                    Ok(version) => commands::provenance(&paths, &version, format),
                     Err(e) => Err(e),
                 }
             }
+            Some(("check-signature", check_sub)) if check_sub.get_flag("all") => {
+                commands::check_signature_all(&paths).await
+            }
             Some(("check-signature", check_sub)) => {
                 let version_arg = check_sub.get_one::<String>("version");
 
@@ -169,11 +472,20 @@ async fn main() -> ExitCode {
                     Err(e) => Err(e),
                 }
             }
+            Some(("upgrade-path", upgrade_sub)) => {
+                let from = upgrade_sub.get_one::<String>("from").unwrap();
+                let to = upgrade_sub.get_one::<String>("to").unwrap();
+
+                match (from.parse::<Version>(), to.parse::<Version>()) {
+                    (Ok(from), Ok(to)) => commands::upgrade_path(&from, &to),
+                    (Err(e), _) | (_, Err(e)) => Err(e.into()),
+                }
+            }
             _ => Ok(()),
         },
 
         Some(("alphas", sub)) => match sub.subcommand() {
-            Some(("list", _)) => commands::list_alphas(&paths),
+            Some(("list", list_sub)) => commands::list_alphas(&paths, output_options(list_sub)),
             Some(("completions", completions_sub)) => {
                 let shell = completions_sub.get_one::<Shell>("shell").copied();
                 commands::completions_alphas(&paths, shell)
@@ -181,29 +493,52 @@ async fn main() -> ExitCode {
             Some(("path", path_sub)) => {
                 let version_arg = path_sub.get_one::<String>("version");
 
+                let opts = commands::PathOptions {
+                    check: path_sub.get_one::<commands::PathKind>("check").copied(),
+                    print0: path_sub.get_flag("print0"),
+                };
+
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::path_alpha(&paths, &version),
+                    Ok(version) => commands::path_alpha(&paths, &version, opts),
                     Err(e) => Err(e),
                 }
             }
             Some(("install", install_sub)) => {
                 let version_arg = get_version_arg(install_sub);
                 let force = install_sub.get_flag("force");
+                let allow_denylisted = install_sub.get_flag("allow_denylisted");
+                let keep_archive = install_sub.get_flag("keep_archive");
 
                 match version_arg {
                     Some(v) if v.trim().eq_ignore_ascii_case("latest") => {
                         print_info("Fetching latest alpha release...");
                         let client = reqwest::Client::new();
-                        match find_latest_alpha(&client).await {
+                        match find_latest_alpha(&client, &paths).await {
                             Ok(alpha) => {
                                 print_info(format!("Found: {}", alpha.version));
-                                commands::install_alpha(&paths, &alpha.version, force).await
+                                commands::install_alpha(
+                                    &paths,
+                                    &alpha.version,
+                                    force,
+                                    allow_denylisted,
+                                    keep_archive,
+                                )
+                                .await
                             }
                             Err(e) => Err(e),
                         }
                     }
                     Some(v) => match v.parse::<Version>() {
-                        Ok(version) => commands::install_alpha(&paths, &version, force).await,
+                        Ok(version) => {
+                            commands::install_alpha(
+                                &paths,
+                                &version,
+                                force,
+                                allow_denylisted,
+                                keep_archive,
+                            )
+                            .await
+                        }
                         Err(e) => Err(e.into()),
                     },
                     None => Err(Error::InvalidVersion("no version specified".into())),
@@ -219,18 +554,39 @@ async fn main() -> ExitCode {
             }
             Some(("uninstall", uninstall_sub)) => {
                 let version_arg = get_version_arg(uninstall_sub);
+                let yes = uninstall_sub.get_flag("yes");
+                let promote_next = uninstall_sub.get_flag("promote_next");
+                let running = get_running_nodes_arg(uninstall_sub);
 
                 match resolve_alpha_version(&paths, version_arg) {
-                    Ok(version) => commands::uninstall_alpha(&paths, &version),
+                    Ok(version) => {
+                        commands::uninstall_alpha(&paths, &version, yes, promote_next, running)
+                    }
                     Err(e) => Err(e),
                 }
             }
             Some(("use", use_sub)) => {
                 let version_arg = get_version_arg(use_sub);
                 let shell = use_sub.get_one::<Shell>("shell").copied();
+                let sbin_only = use_sub.get_flag("sbin_only");
+                let fallback = use_sub.get_flag("with_default_fallback");
+                let auto_install = commands::AutoInstall::resolve(
+                    &paths,
+                    use_sub.get_flag("auto_install"),
+                    use_sub.get_flag("yes"),
+                );
 
-                match resolve_alpha_version(&paths, version_arg) {
-                    Ok(version) => commands::use_alpha_version(&paths, &version, shell),
+                match resolve_use_version(&paths, version_arg, fallback, resolve_alpha_version)
+                    .and_then(|version| auto_install.map(|auto_install| (version, auto_install)))
+                {
+                    Ok((version, auto_install)) => commands::use_alpha_version(
+                        &paths,
+                        &version,
+                        shell,
+                        sbin_only,
+                        auto_install,
+                        use_sub.get_flag("link"),
+                    ),
                     Err(e) => Err(e),
                 }
             }
@@ -241,21 +597,105 @@ async fn main() -> ExitCode {
                     .unwrap();
                 let etc_file_str = cp_sub.get_one::<String>("etc_file").unwrap();
                 let version_arg = cp_sub.get_one::<String>("version");
+                let force = cp_sub.get_flag("force");
 
                 match etc_file_str.parse::<commands::EtcFile>() {
                     Ok(etc_file) => match resolve_alpha_version(&paths, version_arg) {
-                        Ok(version) => {
-                            commands::cp_etc_file_alpha(&paths, &version, &local_path, etc_file)
-                        }
+                        Ok(version) => commands::cp_etc_file_alpha(
+                            &paths,
+                            &version,
+                            &local_path,
+                            etc_file,
+                            force,
+                        ),
                         Err(e) => Err(e),
                     },
                     Err(e) => Err(e),
                 }
             }
-            Some(("prune", _)) => commands::prune_alphas(&paths),
+            Some(("pin", pin_sub)) => {
+                let version_arg = get_version_arg(pin_sub);
+
+                match resolve_alpha_version(&paths, version_arg) {
+                    Ok(version) => commands::pin_alpha(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("unpin", unpin_sub)) => {
+                let version_arg = get_version_arg(unpin_sub);
+
+                match resolve_alpha_version(&paths, version_arg) {
+                    Ok(version) => commands::unpin_alpha(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("prune", prune_sub)) => {
+                let yes = prune_sub.get_flag("yes");
+                commands::prune_alphas(&paths, yes)
+            }
             Some(("clean", clean_sub)) => {
-                let older_than = clean_sub.get_one::<String>("older_than").unwrap();
-                commands::clean_alphas(&paths, older_than)
+                let opts = commands::CleanOptions {
+                    older_than: clean_sub
+                        .get_one::<String>("older_than")
+                        .map(String::as_str),
+                    target_free: clean_sub
+                        .get_one::<String>("target_free")
+                        .map(String::as_str),
+                };
+                let yes = clean_sub.get_flag("yes");
+                commands::clean_alphas(&paths, &opts, yes)
+            }
+            Some(("watch", watch_sub)) => {
+                let opts = commands::WatchOptions {
+                    once: watch_sub.get_flag("once"),
+                    interval: Duration::from_secs(
+                        watch_sub.get_one::<u64>("interval").copied().unwrap() * 60,
+                    ),
+                    exec: watch_sub.get_one::<String>("exec").map(String::as_str),
+                    install: watch_sub.get_flag("install"),
+                    keep: watch_sub.get_one::<u64>("keep").map(|n| *n as usize),
+                };
+
+                commands::watch_alphas(&paths, &opts).await
+            }
+            Some(("diff", diff_sub)) => {
+                let base = diff_sub.get_one::<String>("base").unwrap();
+                let head = diff_sub.get_one::<String>("head").unwrap();
+
+                match (
+                    base.trim().parse::<Version>(),
+                    head.trim().parse::<Version>(),
+                ) {
+                    (Ok(base), Ok(head)) => commands::diff_alphas(&base, &head).await,
+                    (Err(e), _) | (_, Err(e)) => Err(e.into()),
+                }
+            }
+            Some(("info", info_sub)) => {
+                let version = info_sub.get_one::<String>("version").unwrap();
+
+                match version.trim().parse::<Version>() {
+                    Ok(version) => commands::info_alpha(&paths, &version).await,
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Some(("bisect", bisect_sub)) => {
+                let good = bisect_sub.get_one::<String>("good").unwrap();
+                let bad = bisect_sub.get_one::<String>("bad").unwrap();
+
+                match (
+                    good.trim().parse::<Version>(),
+                    bad.trim().parse::<Version>(),
+                ) {
+                    (Ok(good), Ok(bad)) => {
+                        let opts = commands::BisectOptions {
+                            good: &good,
+                            bad: &bad,
+                            run: bisect_sub.get_one::<String>("run").map(|s| s.as_str()),
+                        };
+                        commands::bisect_alphas(&paths, &opts).await
+                    }
+                    (Err(e), _) | (_, Err(e)) => Err(e.into()),
+                }
             }
             Some(("logs", logs_sub)) => match logs_sub.subcommand() {
                 Some(("path", path_sub)) => {
@@ -268,10 +708,11 @@ async fn main() -> ExitCode {
                 }
                 Some(("tail", tail_sub)) => {
                     let version_arg = tail_sub.get_one::<String>("version");
-                    let lines = *tail_sub.get_one::<usize>("lines").unwrap();
+                    let amount = tail_amount(tail_sub);
+                    let follow = tail_sub.get_flag("follow");
 
                     match resolve_version(&paths, version_arg) {
-                        Ok(version) => commands::logs_tail_alpha(&paths, &version, lines),
+                        Ok(version) => commands::logs_tail_alpha(&paths, &version, amount, follow),
                         Err(e) => Err(e),
                     }
                 }
@@ -297,9 +738,19 @@ async fn main() -> ExitCode {
             Some(("use", use_sub)) => {
                 let version_arg = get_version_arg(use_sub);
                 let shell = use_sub.get_one::<Shell>("shell").copied();
+                let sbin_only = use_sub.get_flag("sbin_only");
+                let fallback = use_sub.get_flag("with_default_fallback");
 
-                match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::use_release_version(&paths, &version, shell),
+                match resolve_use_version(&paths, version_arg, fallback, resolve_version) {
+                    Ok(version) => commands::use_release_version(
+                        &paths,
+                        &version,
+                        shell,
+                        sbin_only,
+                        None,
+                        commands::AutoInstall::Never,
+                        false,
+                    ),
                     Err(e) => Err(e),
                 }
             }
@@ -310,9 +761,11 @@ async fn main() -> ExitCode {
             Some(("get-key", get_sub)) => {
                 let key = get_sub.get_one::<String>("key").unwrap();
                 let version_arg = get_sub.get_one::<String>("version");
+                let regex = get_sub.get_flag("regex");
+                let format = *get_sub.get_one::<commands::KeyFormat>("format").unwrap();
 
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::conf_get_key(&paths, &version, key),
+                    Ok(version) => commands::conf_get_key(&paths, &version, key, regex, format),
                     Err(e) => Err(e),
                 }
             }
@@ -327,6 +780,47 @@ async fn main() -> ExitCode {
                     Err(e) => Err(e),
                 }
             }
+            Some(("edit", edit_sub)) => {
+                let version_arg = edit_sub.get_one::<String>("version");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => {
+                        commands::conf_edit(&paths, &version, edit_sub.get_flag("force"))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("history", history_sub)) => {
+                let version_arg = history_sub.get_one::<String>("version");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => etc_file(history_sub)
+                        .and_then(|file| commands::conf_history(&paths, &version, file)),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("revert", revert_sub)) => {
+                let version_arg = revert_sub.get_one::<String>("version");
+                let number = *revert_sub.get_one::<usize>("number").unwrap();
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => etc_file(revert_sub)
+                        .and_then(|file| commands::conf_revert(&paths, &version, file, number)),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("convert", convert_sub)) => {
+                let version_arg = convert_sub.get_one::<String>("version");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::conf_convert(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("schema", schema_sub)) => match schema_sub.subcommand() {
+                Some(("dump", _)) => commands::conf_schema_dump(),
+                _ => Ok(()),
+            },
             _ => Ok(()),
         },
 
@@ -347,7 +841,15 @@ async fn main() -> ExitCode {
                 .map(|v| v.cloned().collect())
                 .unwrap_or_default();
 
-            match resolve_version(&paths, version_arg) {
+            let auto_install = commands::AutoInstall::resolve(
+                &paths,
+                sub.get_flag("auto_install"),
+                sub.get_flag("yes"),
+            );
+
+            match resolve_version(&paths, version_arg).and_then(|version| {
+                commands::ensure_installed(&paths, &version, auto_install?).map(|()| version)
+            }) {
                 Ok(version) => commands::cli(&paths, &version, tool, &args),
                 Err(e) => Err(e),
             }
@@ -356,9 +858,26 @@ async fn main() -> ExitCode {
         Some(("fg", sub)) => match sub.subcommand() {
             Some(("node", fg_sub)) => {
                 let version_arg = fg_sub.get_one::<String>("version");
+                let env = fg_sub.get_one::<String>("env").map(String::as_str);
+                let auto_install = commands::AutoInstall::resolve(
+                    &paths,
+                    fg_sub.get_flag("auto_install"),
+                    fg_sub.get_flag("yes"),
+                );
 
-                match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::fg_node(&paths, &version),
+                let data_dir = fg_sub.get_one::<PathBuf>("data_dir");
+
+                match resolve_version(&paths, version_arg).and_then(|version| {
+                    commands::ensure_installed(&paths, &version, auto_install?)?;
+                    if let Some(dir) = data_dir {
+                        commands::data_dir_set(&paths, &version, dir)?;
+                    }
+                    Ok(version)
+                }) {
+                    Ok(version) if fg_sub.get_flag("fresh") => {
+                        commands::fg_node_fresh(&paths, &version, env).await
+                    }
+                    Ok(version) => commands::fg_node(&paths, &version, env),
                     Err(e) => Err(e),
                 }
             }
@@ -368,9 +887,20 @@ async fn main() -> ExitCode {
         Some(("bg", sub)) => match sub.subcommand() {
             Some(("start", start_sub)) => {
                 let version_arg = start_sub.get_one::<String>("version");
+                let env = start_sub.get_one::<String>("env");
+                let data_dir = start_sub.get_one::<PathBuf>("data_dir");
+                let supervise = start_sub
+                    .get_flag("supervise")
+                    .then(|| *start_sub.get_one::<u32>("max_restarts").unwrap());
 
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::bg_start(&paths, &version),
+                    Ok(version) => match data_dir {
+                        Some(dir) => commands::data_dir_set(&paths, &version, dir).map(|_| ()),
+                        None => Ok(()),
+                    }
+                    .and_then(|()| {
+                        commands::bg_start(&paths, &version, env.map(String::as_str), supervise)
+                    }),
                     Err(e) => Err(e),
                 }
             }
@@ -378,7 +908,43 @@ async fn main() -> ExitCode {
                 let version_arg = stop_sub.get_one::<String>("version");
 
                 match resolve_version(&paths, version_arg) {
-                    Ok(version) => commands::bg_stop(&paths, &version),
+                    Ok(version) => {
+                        let opts = commands::StopOptions {
+                            node: stop_sub.get_one::<String>("node").map(String::as_str),
+                            timeout: stop_sub.get_one::<u64>("timeout").copied(),
+                            kill: stop_sub.get_flag("kill"),
+                        };
+                        commands::bg_stop(&paths, &version, &opts)
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("logs", logs_sub)) => {
+                let version_arg = logs_sub.get_one::<String>("version");
+                let env = logs_sub.get_one::<String>("env").map(String::as_str);
+                let lines = *logs_sub.get_one::<usize>("lines").unwrap();
+                let follow = logs_sub.get_flag("follow");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::bg_logs(&paths, &version, env, lines, follow),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("pause", pause_sub)) => {
+                let version_arg = pause_sub.get_one::<String>("version");
+                let node = pause_sub.get_one::<String>("node").map(String::as_str);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::bg_pause(&paths, &version, node),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("resume", resume_sub)) => {
+                let version_arg = resume_sub.get_one::<String>("version");
+                let node = resume_sub.get_one::<String>("node").map(String::as_str);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::bg_resume(&paths, &version, node),
                     Err(e) => Err(e),
                 }
             }
@@ -388,9 +954,11 @@ async fn main() -> ExitCode {
         Some(("inspect", sub)) => {
             let file = sub.get_one::<String>("file").unwrap();
             let version_arg = sub.get_one::<String>("version");
+            let format = *sub.get_one::<commands::InspectFormat>("format").unwrap();
+            let validate = sub.get_flag("validate");
 
             match resolve_version(&paths, version_arg) {
-                Ok(version) => commands::inspect(&paths, &version, file),
+                Ok(version) => commands::inspect(&paths, &version, file, format, validate),
                 Err(e) => Err(e),
             }
         }
@@ -410,9 +978,590 @@ async fn main() -> ExitCode {
             _ => Ok(()),
         },
 
+        Some(("deactivate", sub)) => {
+            let shell = sub.get_one::<Shell>("shell").copied();
+            commands::deactivate(shell)
+        }
+
+        Some(("doctor", sub)) => commands::doctor(&paths, sub.get_flag("fix_ownership")),
+
+        Some(("verify", sub)) => {
+            let jobs = sub
+                .get_one::<u16>("jobs")
+                .map(|j| usize::from(*j))
+                .unwrap_or_else(|| thread::available_parallelism().map_or(1, usize::from));
+            let versions = if sub.get_flag("all") {
+                paths.installed_versions()
+            } else {
+                resolve_version(&paths, sub.get_one::<String>("version")).map(|v| vec![v])
+            };
+
+            match versions {
+                Ok(versions) => commands::verify(&paths, &versions, jobs),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("repair", sub)) => match get_version_arg(sub) {
+            Some(v) => match v.trim().parse::<Version>() {
+                Ok(version) => commands::repair(&paths, &version).await,
+                Err(e) => Err(e.into()),
+            },
+            None => Err(Error::InvalidVersion("no version specified".into())),
+        },
+
+        Some(("ping", sub)) => {
+            let version_arg = sub.get_one::<String>("version");
+            let opts = commands::PingOptions {
+                host: sub.get_one::<String>("host").unwrap(),
+                port: sub.get_one::<u16>("port").copied(),
+                env: sub.get_one::<String>("env").map(String::as_str),
+                timeout: Duration::from_secs(*sub.get_one::<u64>("timeout").unwrap()),
+            };
+
+            match resolve_version(&paths, version_arg) {
+                Ok(version) => commands::ping(&paths, &version, &opts),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("protocols", sub)) => match sub.subcommand() {
+            Some(("enable", enable_sub)) => {
+                let version_arg = enable_sub.get_one::<String>("version");
+                let env = enable_sub.get_one::<String>("env").map(String::as_str);
+                let protocol = *enable_sub
+                    .get_one::<commands::Protocol>("protocol")
+                    .unwrap();
+                let port = enable_sub.get_one::<u16>("port").copied();
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => {
+                        commands::protocols_enable(&paths, &version, env, protocol, port)
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("health", sub)) => {
+            let version_arg = sub.get_one::<String>("version");
+            let opts = commands::HealthOptions {
+                env: sub.get_one::<String>("env").map(String::as_str),
+                node: sub.get_one::<String>("node").map(String::as_str),
+                timeout: *sub.get_one::<u64>("timeout").unwrap(),
+                format: *sub.get_one::<commands::HealthFormat>("format").unwrap(),
+            };
+
+            match resolve_version(&paths, version_arg) {
+                Ok(version) => commands::health(&paths, &version, &opts),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("smoke", sub)) => match sub.subcommand() {
+            Some(("stream", stream_sub)) => {
+                let version_arg = stream_sub.get_one::<String>("version");
+                let opts = commands::StreamSmokeOptions {
+                    host: stream_sub.get_one::<String>("host").unwrap(),
+                    port: stream_sub.get_one::<u16>("port").copied(),
+                    env: stream_sub.get_one::<String>("env").map(String::as_str),
+                    username: stream_sub.get_one::<String>("username").unwrap(),
+                    password: stream_sub.get_one::<String>("password").unwrap(),
+                    count: usize::from(*stream_sub.get_one::<u16>("count").unwrap()),
+                    timeout: Duration::from_secs(*stream_sub.get_one::<u64>("timeout").unwrap()),
+                };
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::smoke_stream(&paths, &version, &opts),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("import", sub)) => match sub.subcommand() {
+            Some(("state", state_sub)) => {
+                let opts = commands::ImportStateOptions {
+                    file: state_sub.get_one::<PathBuf>("file").unwrap(),
+                    download: !state_sub.get_flag("no_download"),
+                    force: state_sub.get_flag("force"),
+                };
+
+                commands::import_state(&paths, &opts).await
+            }
+            _ => {
+                let path = sub.get_one::<PathBuf>("path").unwrap();
+                let opts = commands::ImportOptions {
+                    name: sub.get_one::<String>("name").map(String::as_str),
+                    link: sub.get_flag("link"),
+                };
+
+                commands::import(&paths, path, &opts)
+            }
+        },
+
+        Some(("sync", sub)) => {
+            let file = sub.get_one::<PathBuf>("file").unwrap();
+            commands::sync(&paths, file, sub.get_flag("dry_run")).await
+        }
+
+        Some(("remote-shell", sub)) => {
+            let version_arg = sub.get_one::<String>("version");
+            let node = sub.get_one::<String>("node").map(String::as_str);
+
+            match resolve_version(&paths, version_arg) {
+                Ok(version) => commands::remote_shell(&paths, &version, node),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("ui", sub)) => match sub.subcommand() {
+            Some(("open", open_sub)) => {
+                let version_arg = open_sub.get_one::<String>("version");
+                let env = open_sub.get_one::<String>("env").map(String::as_str);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => {
+                        commands::ui_open(&paths, &version, env, open_sub.get_flag("print")).await
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("ports", sub)) => match sub.subcommand() {
+            Some(("report", report_sub)) => {
+                let version_arg = report_sub.get_one::<String>("version");
+                let env = report_sub.get_one::<String>("env").map(String::as_str);
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::ports_report(&paths, &version, env),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("cookie", sub)) => match sub.subcommand() {
+            Some(("show", show_sub)) => match show_sub.get_one::<String>("version") {
+                Some(v) => match resolve_version(&paths, Some(v)) {
+                    Ok(version) => commands::cookie_show(&paths, Some(&version)),
+                    Err(e) => Err(e),
+                },
+                None => commands::cookie_show(&paths, None),
+            },
+            Some(("set", set_sub)) => {
+                let value = set_sub.get_one::<String>("cookie").map(String::as_str);
+                match set_sub.get_one::<String>("version") {
+                    Some(v) => match resolve_version(&paths, Some(v)) {
+                        Ok(version) => commands::cookie_set(&paths, Some(&version), value),
+                        Err(e) => Err(e),
+                    },
+                    None => commands::cookie_set(&paths, None, value),
+                }
+            }
+            Some(("sync", sync_sub)) => {
+                commands::cookie_sync(&paths, sync_sub.get_flag("from_home"))
+            }
+            _ => Ok(()),
+        },
+
+        Some(("data-dir", sub)) => match sub.subcommand() {
+            Some(("show", show_sub)) => {
+                match resolve_version(&paths, show_sub.get_one::<String>("version")) {
+                    Ok(version) => commands::data_dir_show(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("set", set_sub)) => {
+                let dir = set_sub.get_one::<PathBuf>("path").unwrap();
+                match resolve_version(&paths, set_sub.get_one::<String>("version")) {
+                    Ok(version) => commands::data_dir_set(&paths, &version, dir).map(|_| ()),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("reset", reset_sub)) => {
+                match resolve_version(&paths, reset_sub.get_one::<String>("version")) {
+                    Ok(version) => commands::data_dir_reset(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("du", sub)) => commands::du(&paths, sub.get_flag("dedup_savings")),
+
+        Some(("dedup", sub)) => commands::dedup(&paths, sub.get_flag("dry_run")),
+
+        Some(("link", sub)) => {
+            let path = sub.get_one::<PathBuf>("path").unwrap();
+            let name = sub.get_one::<String>("name").map(String::as_str);
+
+            commands::link(&paths, path, name)
+        }
+
+        Some(("unlink", sub)) => {
+            let version_arg = get_version_arg(sub);
+
+            match resolve_version(&paths, version_arg) {
+                Ok(version) => commands::unlink(&paths, &version),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("perf", sub)) => match sub.subcommand() {
+            Some(("run", run_sub)) => {
+                let version_arg = run_sub.get_one::<String>("version");
+                let args: Vec<String> = run_sub
+                    .get_many::<String>("args")
+                    .map(|vals| vals.cloned().collect())
+                    .unwrap_or_default();
+                let opts = commands::PerfRunOptions {
+                    env: run_sub.get_one::<String>("env").map(String::as_str),
+                    perf_test_version: run_sub
+                        .get_one::<String>("perf_test_version")
+                        .map(String::as_str),
+                    update: run_sub.get_flag("update"),
+                    args: &args,
+                };
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::perf_run(&paths, &version, &opts).await,
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("ide", sub)) => match sub.subcommand() {
+            Some(("vscode", vscode_sub)) => {
+                let version_arg = vscode_sub.get_one::<String>("version");
+                let opts = commands::VscodeOptions {
+                    dir: vscode_sub.get_one::<PathBuf>("dir").unwrap(),
+                    print: vscode_sub.get_flag("print"),
+                };
+
+                match resolve_use_version(&paths, version_arg, true, resolve_version) {
+                    Ok(version) => commands::ide_vscode(&paths, &version, &opts),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+        Some(("export", sub)) => match sub.subcommand() {
+            Some(("docker", docker_sub)) => {
+                let version_arg = docker_sub.get_one::<String>("version");
+                let opts = commands::DockerExportOptions {
+                    output: docker_sub
+                        .get_one::<PathBuf>("output")
+                        .map(PathBuf::as_path),
+                    env: docker_sub.get_one::<String>("env").map(String::as_str),
+                    base_image: docker_sub.get_one::<String>("base_image").unwrap(),
+                    tag: docker_sub.get_one::<String>("tag").map(String::as_str),
+                    build_with: docker_sub.get_one::<String>("build").map(String::as_str),
+                };
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::export_docker(&paths, &version, &opts),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("state", state_sub)) => {
+                let opts = commands::ExportStateOptions {
+                    file: state_sub.get_one::<PathBuf>("file").unwrap(),
+                    include_versions: state_sub.get_flag("include_versions"),
+                };
+
+                commands::export_state(&paths, &opts)
+            }
+            _ => Ok(()),
+        },
+
+        Some(("toolbox", sub)) => match sub.subcommand() {
+            Some(("install", install_sub)) => {
+                let tool = *install_sub.get_one::<Tool>("tool").unwrap();
+                let tool_version = install_sub.get_one::<String>("tool_version");
+                commands::toolbox_install(&paths, tool, tool_version.map(String::as_str)).await
+            }
+            Some(("list", _)) => commands::toolbox_list(&paths),
+            Some(("uninstall", uninstall_sub)) => {
+                let tool = *uninstall_sub.get_one::<Tool>("tool").unwrap();
+                let tool_version = uninstall_sub.get_one::<String>("tool_version").unwrap();
+                commands::toolbox_uninstall(&paths, tool, tool_version)
+            }
+            _ => Ok(()),
+        },
+
+        Some(("envs", sub)) => match sub.subcommand() {
+            Some(("create", create_sub)) => {
+                let name = create_sub.get_one::<String>("name").unwrap();
+                let version_arg = create_sub.get_one::<String>("version");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::envs_create(&paths, &version, name),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("list", list_sub)) => {
+                let version_arg = list_sub.get_one::<String>("version");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::envs_list(&paths, &version),
+                    Err(e) => Err(e),
+                }
+            }
+            Some(("delete", delete_sub)) => {
+                let name = delete_sub.get_one::<String>("name").unwrap();
+                let version_arg = delete_sub.get_one::<String>("version");
+                let yes = delete_sub.get_flag("yes");
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::envs_delete(&paths, &version, name, yes),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("keys", sub)) => match sub.subcommand() {
+            Some(("list", _)) => commands::keys_list(&paths),
+            Some(("add", add_sub)) => {
+                let name = add_sub.get_one::<String>("name").unwrap();
+                let source = add_sub.get_one::<String>("source").unwrap();
+
+                commands::keys_add(&paths, name, source).await
+            }
+            Some(("remove", remove_sub)) => {
+                let name = remove_sub.get_one::<String>("name").unwrap();
+                let yes = remove_sub.get_flag("yes");
+
+                commands::keys_remove(&paths, name, yes)
+            }
+            Some(("refresh", _)) => commands::keys_refresh(&paths).await,
+            _ => Ok(()),
+        },
+
+        Some(("workspace", sub)) => match sub.subcommand() {
+            Some(("create", create_sub)) => {
+                let name = create_sub.get_one::<String>("name").unwrap();
+                let versions: Vec<String> = create_sub
+                    .get_many::<String>("versions")
+                    .unwrap()
+                    .cloned()
+                    .collect();
+                let env = create_sub.get_one::<String>("env").map(String::as_str);
+                let force = create_sub.get_flag("force");
+
+                commands::workspace_create(&paths, name, &versions, env, force)
+            }
+            Some(("enter", enter_sub)) => {
+                let name = enter_sub.get_one::<String>("name").unwrap();
+                let shell = enter_sub.get_one::<Shell>("shell").copied();
+
+                commands::workspace_enter(&paths, name, shell)
+            }
+            Some(("list", _)) => commands::workspace_list(&paths),
+            Some(("delete", delete_sub)) => {
+                let name = delete_sub.get_one::<String>("name").unwrap();
+                let yes = delete_sub.get_flag("yes");
+
+                commands::workspace_delete(&paths, name, yes)
+            }
+            _ => Ok(()),
+        },
+
+        Some(("trash", sub)) => match sub.subcommand() {
+            Some(("list", _)) => commands::trash_list(&paths),
+            Some(("restore", restore_sub)) => {
+                let version_str = restore_sub.get_one::<String>("version").unwrap();
+
+                match version_str.trim().parse::<Version>() {
+                    Ok(version) => commands::trash_restore(&paths, &version),
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Some(("empty", empty_sub)) => {
+                let older_than = empty_sub.get_one::<String>("older_than");
+                let yes = empty_sub.get_flag("yes");
+                commands::trash_empty(&paths, older_than.map(String::as_str), yes)
+            }
+            _ => Ok(()),
+        },
+
+        Some(("erlang", sub)) => match sub.subcommand() {
+            Some(("report", _)) => commands::erlang_report(&paths),
+            _ => Ok(()),
+        },
+
+        Some(("__supervise", sub)) => {
+            let env = sub.get_one::<String>("env").map(String::as_str);
+            let max_restarts = *sub.get_one::<u32>("max_restarts").unwrap();
+            match resolve_version(&paths, sub.get_one::<String>("version")) {
+                Ok(version) => commands::supervise(&paths, &version, env, max_restarts),
+                Err(e) => Err(e),
+            }
+        }
+
+        Some(("__complete", sub)) => {
+            let words: Vec<String> = sub
+                .get_many::<String>("words")
+                .map(|words| words.cloned().collect())
+                .unwrap_or_default();
+            commands::complete(&paths, &words)
+        }
+
+        Some(("alias-command", sub)) => match sub.subcommand() {
+            Some(("list", _)) => commands::alias_list(&paths),
+            _ => Ok(()),
+        },
+
+        Some(("exit-codes", sub)) => {
+            commands::exit_codes(sub.get_flag("plain"));
+            Ok(())
+        }
+
+        Some(("run-script", sub)) => {
+            if sub.get_flag("list") {
+                commands::run_script_list(&paths)
+            } else {
+                let name = sub.get_one::<String>("name").unwrap();
+                let env = sub.get_one::<String>("env").map(String::as_str);
+                let args: Vec<String> = sub
+                    .get_many::<String>("args")
+                    .map(|v| v.cloned().collect())
+                    .unwrap_or_default();
+
+                resolve_version(&paths, sub.get_one::<String>("version"))
+                    .and_then(|version| commands::run_script(&paths, name, &version, env, &args))
+            }
+        }
+
+        Some(("migrate-data", sub)) => {
+            let from = resolve_version(&paths, sub.get_one::<String>("from"));
+            let to = resolve_version(&paths, sub.get_one::<String>("to"));
+            match (from, to) {
+                (Ok(from), Ok(to)) => commands::migrate_data(
+                    &paths,
+                    &commands::MigrateOptions {
+                        from,
+                        to,
+                        shovel: sub.get_flag("shovel"),
+                        source_uri: sub.get_one::<String>("source_uri").cloned(),
+                    },
+                ),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            }
+        }
+
+        Some(("matrix", sub)) => match sub.subcommand() {
+            Some(("run", run_sub)) => {
+                let versions: Result<Vec<Version>, Error> = run_sub
+                    .get_many::<String>("versions")
+                    .unwrap()
+                    .map(|v| resolve_version(&paths, Some(v)))
+                    .collect();
+                let command: Vec<String> = run_sub
+                    .get_many::<String>("command")
+                    .unwrap()
+                    .cloned()
+                    .collect();
+
+                versions.and_then(|versions| {
+                    let opts = commands::MatrixOptions {
+                        jobs: run_sub
+                            .get_one::<u16>("jobs")
+                            .map(|j| usize::from(*j))
+                            .unwrap_or(versions.len()),
+                        versions,
+                        command: &command,
+                        plugins: run_sub
+                            .get_many::<String>("plugins")
+                            .unwrap()
+                            .filter(|p| !p.is_empty())
+                            .cloned()
+                            .collect(),
+                        boot_timeout: Duration::from_secs(
+                            *run_sub.get_one::<u64>("boot_timeout").unwrap(),
+                        ),
+                    };
+                    commands::matrix_run(&paths, &opts)
+                })
+            }
+            _ => Ok(()),
+        },
+
+        Some(("metadata", sub)) => match sub.subcommand() {
+            Some(("status", status_sub)) => {
+                let version_arg = status_sub.get_one::<String>("version");
+                let env = status_sub.get_one::<String>("env").map(String::as_str);
+                let format = *status_sub
+                    .get_one::<commands::MetadataFormat>("format")
+                    .unwrap();
+
+                match resolve_version(&paths, version_arg) {
+                    Ok(version) => commands::metadata_status(&paths, &version, env, format),
+                    Err(e) => Err(e),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("paths", sub)) => match sub.subcommand() {
+            Some(("show", show_sub)) => {
+                let format = *show_sub.get_one::<commands::PathsFormat>("format").unwrap();
+                let env = show_sub.get_one::<String>("env").map(String::as_str);
+                match show_sub.get_one::<String>("version") {
+                    Some(raw) => resolve_version(&paths, Some(raw)).and_then(|version| {
+                        commands::paths_show(&paths, Some(&version), env, format)
+                    }),
+                    None => commands::paths_show(&paths, None, env, format),
+                }
+            }
+            _ => Ok(()),
+        },
+
+        Some(("search", sub)) => {
+            let query = sub.get_one::<String>("query").unwrap();
+            commands::search(&paths, query, sub.get_flag("plain")).await
+        }
+
+        Some(("history", sub)) => match history_query(sub) {
+            Ok(query) => commands::history(&paths, &query),
+            Err(e) => Err(e),
+        },
+
+        // clap only gets here for names that are not built-in commands
+        Some((name, sub)) => {
+            let args: Vec<String> = sub
+                .get_many::<String>("")
+                .map(|v| v.cloned().collect())
+                .unwrap_or_default();
+            commands::external(&paths, name, &args)
+        }
+
         _ => Ok(()),
     };
 
+    debug!(
+        command,
+        elapsed_ms = started.elapsed().as_millis() as u64,
+        error = result.as_ref().err().map(ToString::to_string),
+        "finished"
+    );
+
+    if matches.get_flag("profile") {
+        eprint!(
+            "{}",
+            profile::format_report(&profile::timings(), started.elapsed())
+        );
+    }
+
+    if let Some(entry) = history::take_pending() {
+        history::append(&paths, &entry.with_outcome(&result)).ok();
+    }
+
     match result {
         Ok(()) => ExitCode::Ok,
         Err(e) => {
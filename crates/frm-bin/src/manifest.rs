@@ -0,0 +1,243 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Declarative provisioning manifests, `frm.manifest.toml`, used by `frm sync`.
+//!
+//! ```toml
+//! versions = ["4.2.3", "4.1.6"]
+//! default = "4.2.3"
+//! plugins = ["rabbitmq_management", "rabbitmq_shovel"]
+//! # uninstall versions that are not listed
+//! prune = false
+//!
+//! [conf]
+//! "listeners.tcp.default" = 5673
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use rabbitmq_conf::{RabbitMQConf, keys};
+use serde::Deserialize;
+
+use crate::Result;
+use crate::errors::Error;
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub const MANIFEST_FILE_NAME: &str = "frm.manifest.toml";
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// Versions that must be installed
+    #[serde(default)]
+    pub versions: Vec<Version>,
+    pub default: Option<Version>,
+    /// Plugins enabled for every listed version; when empty, `enabled_plugins` files are left alone
+    #[serde(default)]
+    pub plugins: Vec<String>,
+    /// `rabbitmq.conf` keys set for every listed version
+    #[serde(default)]
+    pub conf: BTreeMap<String, toml::Value>,
+    /// Uninstall versions that are not listed. Pinned and linked versions are never uninstalled
+    #[serde(default)]
+    pub prune: bool,
+}
+
+/// A single change `frm sync` makes to match a manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncAction {
+    Install(Version),
+    SetPlugins {
+        version: Version,
+        plugins: Vec<String>,
+    },
+    SetConfKey {
+        version: Version,
+        key: String,
+        value: String,
+    },
+    SetDefault(Version),
+    Uninstall(Version),
+}
+
+impl fmt::Display for SyncAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncAction::Install(v) => write!(f, "+ install {}", v),
+            SyncAction::SetPlugins { version, plugins } => {
+                write!(
+                    f,
+                    "~ {}: enabled_plugins = [{}]",
+                    version,
+                    plugins.join(",")
+                )
+            }
+            SyncAction::SetConfKey {
+                version,
+                key,
+                value,
+            } => write!(f, "~ {}: {} = {}", version, key, value),
+            SyncAction::SetDefault(v) => write!(f, "~ default = {}", v),
+            SyncAction::Uninstall(v) => write!(f, "- uninstall {}", v),
+        }
+    }
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.is_file() {
+            return Err(Error::FileNotFound(path.display().to_string()));
+        }
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let manifest: Manifest =
+            toml::from_str(content).map_err(|e| Error::InvalidManifest(e.message().to_string()))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(default) = &self.default
+            && !self.versions.contains(default)
+        {
+            return Err(Error::InvalidManifest(format!(
+                "default version {} is not listed in 'versions'",
+                default
+            )));
+        }
+        for key in self.conf.keys() {
+            if !keys::is_valid_key_format(key) || !keys::is_known_key(key) {
+                return Err(Error::InvalidManifest(format!(
+                    "unknown configuration key: {}",
+                    key
+                )));
+            }
+        }
+        for (key, value) in &self.conf {
+            conf_value(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Computes the changes needed for the `frm` directory to match this manifest.
+    /// Installations come first and uninstallations last.
+    pub fn plan(&self, paths: &Paths) -> Result<Vec<SyncAction>> {
+        let mut actions = Vec::new();
+
+        for version in &self.versions {
+            let installed = paths.version_installed(version);
+            if !installed {
+                actions.push(SyncAction::Install(version.clone()));
+            }
+
+            if !self.plugins.is_empty() {
+                let wanted: BTreeSet<&str> = self.plugins.iter().map(String::as_str).collect();
+                let current = if installed {
+                    enabled_plugins(paths, version)?
+                } else {
+                    None
+                };
+                let up_to_date = current.as_ref().is_some_and(|c| {
+                    c.iter().map(String::as_str).collect::<BTreeSet<_>>() == wanted
+                });
+                if !up_to_date {
+                    actions.push(SyncAction::SetPlugins {
+                        version: version.clone(),
+                        plugins: self.plugins.clone(),
+                    });
+                }
+            }
+
+            let conf_path = paths.version_etc_dir(version).join("rabbitmq.conf");
+            let conf = if installed && conf_path.is_file() {
+                Some(RabbitMQConf::load(&conf_path).map_err(|e| Error::Config(e.to_string()))?)
+            } else {
+                None
+            };
+            for (key, value) in &self.conf {
+                let value = conf_value(key, value)?;
+                if conf.as_ref().and_then(|c| c.get(key)) != Some(value.as_str()) {
+                    actions.push(SyncAction::SetConfKey {
+                        version: version.clone(),
+                        key: key.clone(),
+                        value,
+                    });
+                }
+            }
+        }
+
+        if let Some(default) = &self.default {
+            let current = fs::read_to_string(paths.default_file()).ok();
+            if current.as_deref().map(str::trim) != Some(default.to_string().as_str()) {
+                actions.push(SyncAction::SetDefault(default.clone()));
+            }
+        }
+
+        if self.prune {
+            for version in paths.installed_versions()? {
+                if self.versions.contains(&version)
+                    || paths.version_linked(&version)
+                    || paths.version_shared(&version)
+                    || VersionMetadata::is_pinned(paths, &version)?
+                {
+                    continue;
+                }
+                actions.push(SyncAction::Uninstall(version));
+            }
+        }
+
+        Ok(actions)
+    }
+}
+
+/// Parses an `enabled_plugins` file, e.g. `[rabbitmq_management,rabbitmq_shovel].`
+pub fn parse_enabled_plugins(content: &str) -> Option<Vec<String>> {
+    let list = content.trim().strip_suffix('.')?.trim();
+    let inner = list.strip_prefix('[')?.strip_suffix(']')?;
+    Some(
+        inner
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from)
+            .collect(),
+    )
+}
+
+pub fn format_enabled_plugins(plugins: &[String]) -> String {
+    format!("[{}].\n", plugins.join(","))
+}
+
+fn enabled_plugins(paths: &Paths, version: &Version) -> Result<Option<Vec<String>>> {
+    let path = paths.version_etc_dir(version).join("enabled_plugins");
+    if !path.is_file() {
+        return Ok(None);
+    }
+    Ok(parse_enabled_plugins(&fs::read_to_string(path)?))
+}
+
+fn conf_value(key: &str, value: &toml::Value) -> Result<String> {
+    match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        toml::Value::Float(f) => Ok(f.to_string()),
+        toml::Value::Boolean(b) => Ok(b.to_string()),
+        _ => Err(Error::InvalidManifest(format!(
+            "value of {} must be a string, a number, or a boolean",
+            key
+        ))),
+    }
+}
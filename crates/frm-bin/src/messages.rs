@@ -0,0 +1,165 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! User-facing messages.
+//!
+//! Every status line frm prints goes through the `print_*` functions in this module,
+//! so that their format can be switched in one place: by default messages are decorated
+//! with a (colored) symbol, in terse mode (`--terse`) they are plain lines prefixed with
+//! their kind, e.g. `error:` or `hint:`, which is easier to match in scripts.
+//!
+//! Messages that several commands print are defined once in [`Message`], the place
+//! to translate them.
+
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::version::Version;
+
+static TERSE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageMode {
+    /// Messages are decorated with a symbol, colored when printing to a terminal
+    #[default]
+    Verbose,
+    /// Plain messages prefixed with their kind: `info:`, `ok:`, `warning:`, `error:`, or `hint:`
+    Terse,
+}
+
+pub fn set_mode(mode: MessageMode) {
+    TERSE.store(mode == MessageMode::Terse, Ordering::Relaxed);
+}
+
+pub fn mode() -> MessageMode {
+    if TERSE.load(Ordering::Relaxed) {
+        MessageMode::Terse
+    } else {
+        MessageMode::Verbose
+    }
+}
+
+/// The line a message is printed as in terse mode
+pub fn terse_line(kind: &str, message: impl Display) -> String {
+    format!("{}: {}", kind, message)
+}
+
+pub fn print_info(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => bel7_cli::print_info(message),
+        MessageMode::Terse => println!("{}", terse_line("info", message)),
+    }
+}
+
+pub fn print_success(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => bel7_cli::print_success(message),
+        MessageMode::Terse => println!("{}", terse_line("ok", message)),
+    }
+}
+
+pub fn print_warning(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => bel7_cli::print_warning(message),
+        MessageMode::Terse => println!("{}", terse_line("warning", message)),
+    }
+}
+
+/// Printed to stderr
+pub fn print_error(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => bel7_cli::print_error(message),
+        MessageMode::Terse => eprintln!("{}", terse_line("error", message)),
+    }
+}
+
+/// A warning printed to stderr, so that it is seen even when the output of a command
+/// is evaluated by a shell or piped
+pub fn print_warning_to_stderr(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => eprintln!("! {}", message),
+        MessageMode::Terse => eprintln!("{}", terse_line("warning", message)),
+    }
+}
+
+/// A suggested next step, such as a command to run
+pub fn print_hint(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => bel7_cli::print_info(message),
+        MessageMode::Terse => println!("{}", terse_line("hint", message)),
+    }
+}
+
+/// Like [`print_info`] but on stderr, for commands whose standard output is evaluated by a shell
+pub fn print_info_to_stderr(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => eprintln!("→ {}", message),
+        MessageMode::Terse => eprintln!("{}", terse_line("info", message)),
+    }
+}
+
+/// Like [`print_hint`] but on stderr, for commands whose standard output is evaluated by a shell
+pub fn print_hint_to_stderr(message: impl Display) {
+    match mode() {
+        MessageMode::Verbose => eprintln!("→ {}", message),
+        MessageMode::Terse => eprintln!("{}", terse_line("hint", message)),
+    }
+}
+
+/// Messages printed by more than one command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Message<'a> {
+    NoVersionsInstalled,
+    NoAlphasInstalled,
+    Downloading(&'a Version),
+    CopyingDefaultConfig,
+    CleaningUpArchive,
+    VerifyingExtractedContent,
+    Removing(&'a Version),
+    RemovingExistingInstallation(&'a Version),
+    RemovingPartialInstallation(&'a Version),
+    Repaired(&'a Version),
+    KeepingPinned(&'a Version),
+    ClearedAlphaDefault,
+    RemovedAlphas(usize),
+    ClassicConfigTakesPrecedence(&'a Version),
+}
+
+impl Display for Message<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Message::NoVersionsInstalled => f.write_str("No versions installed"),
+            Message::NoAlphasInstalled => f.write_str("No alpha versions installed"),
+            Message::Downloading(v) => write!(f, "Downloading RabbitMQ {}", v),
+            Message::CopyingDefaultConfig => f.write_str("Copying default configuration"),
+            Message::CleaningUpArchive => f.write_str("Cleaning up downloaded archive"),
+            Message::VerifyingExtractedContent => f.write_str("Verifying extracted content"),
+            Message::Removing(v) => write!(f, "Removing RabbitMQ {}", v),
+            Message::RemovingExistingInstallation(v) => {
+                write!(f, "Removing existing installation of {}", v)
+            }
+            Message::RemovingPartialInstallation(v) => {
+                write!(f, "Removing partial installation of {}", v)
+            }
+            Message::Repaired(v) => write!(f, "RabbitMQ {} repaired", v),
+            Message::KeepingPinned(v) => write!(f, "Keeping pinned RabbitMQ {}", v),
+            Message::ClearedAlphaDefault => {
+                f.write_str("Cleared default version (an alpha was the default)")
+            }
+            Message::RemovedAlphas(n) => write!(f, "Removed {} alpha version(s)", n),
+            Message::ClassicConfigTakesPrecedence(v) => write!(
+                f,
+                "RabbitMQ {} has both rabbitmq.conf and the classic rabbitmq.config: rabbitmq.config \
+                takes precedence and rabbitmq.conf is ignored. Move the rabbitmq.config settings to \
+                rabbitmq.conf (or advanced.config where rabbitmq.conf has no equivalent) and delete it, \
+                'frm conf convert --from rabbitmq.config -V {}' translates them",
+                v, v
+            ),
+        }
+    }
+}
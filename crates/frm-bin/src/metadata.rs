@@ -0,0 +1,176 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Per-version metadata, stored outside of the version directory
+//! so that it survives reinstallation.
+//!
+//! Each version has its own file, and all modifications go through [`VersionMetadata::update`],
+//! which holds an exclusive lock, so concurrent `frm` processes do not lose each other's changes.
+
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::ops::Not;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::provenance::Provenance;
+use crate::version::Version;
+
+const LOCK_FILE_NAME: &str = ".lock";
+/// Updates hold the lock briefly, so waiting longer than this means another process is stuck
+const LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionMetadata {
+    /// Pinned versions are never removed by `alphas prune` and `alphas clean`
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub pinned: bool,
+    /// When the version was installed, in seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installed_at: Option<u64>,
+    /// The directory the version was copied from by `frm import`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imported_from: Option<String>,
+    /// The result of the most recent `releases check-signature`, cleared when
+    /// the version is reinstalled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<SignatureCheck>,
+    /// Where the installed archive came from, replaced when the version is reinstalled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
+    /// Where the node data (`RABBITMQ_MNESIA_BASE`) of the version's own configuration is kept
+    /// when it is not under the version directory, e.g. on a faster disk. Set with `frm data-dir`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureCheck {
+    pub verified: bool,
+    /// In seconds since the Unix epoch
+    pub checked_at: u64,
+}
+
+impl VersionMetadata {
+    pub fn load(paths: &Paths, version: &Version) -> Result<Self> {
+        let path = paths.version_metadata_file(version);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        let metadata: VersionMetadata = toml::from_str(&content)?;
+        Ok(metadata)
+    }
+
+    /// Loads the metadata of every version that has any
+    pub fn load_all(paths: &Paths) -> Result<Vec<(Version, Self)>> {
+        let metadata_dir = paths.metadata_dir();
+        if !metadata_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut result = Vec::new();
+        for entry in fs::read_dir(metadata_dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "toml")
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+                && let Ok(version) = stem.parse::<Version>()
+            {
+                let metadata = Self::load(paths, &version)?;
+                result.push((version, metadata));
+            }
+        }
+
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(result)
+    }
+
+    pub fn save(&self, paths: &Paths, version: &Version) -> Result<()> {
+        fs::create_dir_all(paths.metadata_dir())?;
+        let path = paths.version_metadata_file(version);
+        let tmp_path = path.with_extension("toml.tmp");
+        let content = toml::to_string_pretty(self)?;
+        fs::write(&tmp_path, content)?;
+        fs::rename(tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Atomically loads, modifies, and saves the metadata of a version.
+    ///
+    /// The file is removed when nothing is left in it.
+    pub fn update<F>(paths: &Paths, version: &Version, f: F) -> Result<Self>
+    where
+        F: FnOnce(&mut Self),
+    {
+        let _lock = lock(paths)?;
+
+        let mut metadata = Self::load(paths, version)?;
+        f(&mut metadata);
+        if metadata == Self::default() {
+            remove_file(paths, version)?;
+        } else {
+            metadata.save(paths, version)?;
+        }
+        Ok(metadata)
+    }
+
+    pub fn remove(paths: &Paths, version: &Version) -> Result<()> {
+        let _lock = lock(paths)?;
+        remove_file(paths, version)
+    }
+
+    pub fn is_pinned(paths: &Paths, version: &Version) -> Result<bool> {
+        Ok(Self::load(paths, version)?.pinned)
+    }
+}
+
+/// Takes an exclusive, process-wide lock on the metadata directory.
+/// The lock is released when the returned file is dropped.
+pub fn lock(paths: &Paths) -> Result<File> {
+    lock_with_timeout(paths, LOCK_TIMEOUT)
+}
+
+/// Like [`lock`] but gives up with [`Error::Locked`] after waiting for `timeout`
+pub fn lock_with_timeout(paths: &Paths, timeout: Duration) -> Result<File> {
+    fs::create_dir_all(paths.metadata_dir())?;
+    let path = paths.metadata_dir().join(LOCK_FILE_NAME);
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&path)?;
+
+    let started_at = Instant::now();
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Ok(file),
+            Err(TryLockError::WouldBlock) if started_at.elapsed() < timeout => {
+                thread::sleep(LOCK_POLL_INTERVAL)
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(Error::Locked(path.display().to_string()));
+            }
+            Err(TryLockError::Error(e)) => return Err(e.into()),
+        }
+    }
+}
+
+fn remove_file(paths: &Paths, version: &Version) -> Result<()> {
+    let path = paths.version_metadata_file(version);
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
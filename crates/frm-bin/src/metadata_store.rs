@@ -0,0 +1,168 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Which metadata store, Mnesia or Khepri, a node's data directory uses, told from the files
+//! `rabbitmq-server` writes under `RABBITMQ_MNESIA_BASE`:
+//!
+//! * `<node>/`: the node's data directory, with Mnesia's `schema.DAT`, `*.DCD`, and `*.DCL` files
+//! * `<node>/coordination/`: the Ra system Khepri stores metadata in (RabbitMQ 3.13 and later)
+//! * `<node>-feature_flags`: the enabled feature flags as an Erlang list, including
+//!   `khepri_db` once metadata has been migrated to Khepri
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::common::fs_utils::dir_size;
+
+/// The feature flag that switches the metadata store from Mnesia to Khepri
+pub const KHEPRI_DB_FEATURE_FLAG: &str = "khepri_db";
+
+const MNESIA_FILE_EXTENSIONS: &[&str] = &["DAT", "DCD", "DCL", "LOG"];
+const KHEPRI_DIR: &str = "coordination";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreKind {
+    Mnesia,
+    Khepri,
+    /// The node has not been started yet
+    Uninitialized,
+}
+
+impl fmt::Display for StoreKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            StoreKind::Mnesia => "Mnesia",
+            StoreKind::Khepri => "Khepri",
+            StoreKind::Uninitialized => "none (the node has not been started yet)",
+        })
+    }
+}
+
+/// The metadata store of one node data directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NodeStore {
+    pub node: String,
+    pub data_dir: PathBuf,
+    pub store: StoreKind,
+    /// `None` when the feature flags file does not exist
+    pub enabled_feature_flags: Option<Vec<String>>,
+    pub mnesia_bytes: u64,
+    pub khepri_bytes: u64,
+}
+
+impl NodeStore {
+    pub fn khepri_db_enabled(&self) -> Option<bool> {
+        self.enabled_feature_flags.as_deref().map(has_khepri_db)
+    }
+}
+
+fn has_khepri_db(flags: &[String]) -> bool {
+    flags.iter().any(|f| f == KHEPRI_DB_FEATURE_FLAG)
+}
+
+/// The node data directories under `mnesia_base`, such as `rabbit@hostname`, sorted by node name
+pub fn inspect(mnesia_base: &Path) -> Result<Vec<NodeStore>> {
+    if !mnesia_base.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut nodes = Vec::new();
+    for entry in fs::read_dir(mnesia_base)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let Some(node) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        // a node name always has a host part; this skips the -plugins-expand directories
+        if !node.contains('@') || node.ends_with("-plugins-expand") {
+            continue;
+        }
+        nodes.push(inspect_node(mnesia_base, &node)?);
+    }
+
+    nodes.sort_by(|a, b| a.node.cmp(&b.node));
+    Ok(nodes)
+}
+
+pub fn inspect_node(mnesia_base: &Path, node: &str) -> Result<NodeStore> {
+    let data_dir = mnesia_base.join(node);
+    let enabled_feature_flags = fs::read_to_string(feature_flags_file(mnesia_base, node))
+        .ok()
+        .map(|content| parse_feature_flags(&content));
+
+    let mnesia_bytes = mnesia_size(&data_dir)?;
+    let khepri_dir = data_dir.join(KHEPRI_DIR);
+    let khepri_bytes = if khepri_dir.is_dir() {
+        dir_size(&khepri_dir)?
+    } else {
+        0
+    };
+
+    let store = match enabled_feature_flags.as_deref().map(has_khepri_db) {
+        Some(true) => StoreKind::Khepri,
+        Some(false) => StoreKind::Mnesia,
+        None if data_dir.join("schema.DAT").is_file() => StoreKind::Mnesia,
+        None if khepri_dir.is_dir() => StoreKind::Khepri,
+        None => StoreKind::Uninitialized,
+    };
+
+    Ok(NodeStore {
+        node: node.to_string(),
+        data_dir,
+        store,
+        enabled_feature_flags,
+        mnesia_bytes,
+        khepri_bytes,
+    })
+}
+
+pub fn feature_flags_file(mnesia_base: &Path, node: &str) -> PathBuf {
+    mnesia_base.join(format!("{}-feature_flags", node))
+}
+
+/// Parses the feature flags file, an Erlang list of atoms such as
+/// `[classic_queue_type_delivery_support,khepri_db,'quorum_queue'].`
+pub fn parse_feature_flags(content: &str) -> Vec<String> {
+    let list = content
+        .trim()
+        .trim_end_matches('.')
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    list.split(',')
+        .map(|atom| atom.trim().trim_matches('\'').to_string())
+        .filter(|atom| !atom.is_empty())
+        .collect()
+}
+
+/// Mnesia keeps its tables and transaction log at the top of the node data directory
+fn mnesia_size(data_dir: &Path) -> Result<u64> {
+    if !data_dir.is_dir() {
+        return Ok(0);
+    }
+    let mut total = 0;
+    for entry in fs::read_dir(data_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let is_mnesia_file = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| MNESIA_FILE_EXTENSIONS.contains(&ext));
+        if is_mnesia_file && entry.file_type()?.is_file() {
+            total += entry.metadata()?.len();
+        }
+    }
+    Ok(total)
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Detects running nodes using the PID files `rabbitmq-server` writes
+//! next to the node's data directory, e.g. `var/lib/rabbitmq/mnesia/rabbit@hostname.pid`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+#[cfg(unix)]
+use std::process::Stdio;
+
+use crate::Result;
+use crate::envs;
+#[cfg(unix)]
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// PID files of a version's nodes, including those of its named environments
+pub fn pid_files(paths: &Paths, version: &Version) -> Result<Vec<PathBuf>> {
+    let mut data_dirs = vec![paths.version_data_dir(version)];
+    let own_data_dir = envs::data_dir(paths, version, None)?;
+    if !data_dirs.contains(&own_data_dir) {
+        data_dirs.push(own_data_dir);
+    }
+    for name in envs::list(paths, version)? {
+        data_dirs.push(paths.version_env_data_dir(version, &name));
+    }
+
+    let mut result = Vec::new();
+    for dir in data_dirs {
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "pid") && path.is_file() {
+                result.push(path);
+            }
+        }
+    }
+
+    result.sort();
+    Ok(result)
+}
+
+pub fn read_pid(pid_file: &Path) -> Option<u32> {
+    fs::read_to_string(pid_file).ok()?.trim().parse().ok()
+}
+
+/// OS process IDs of the version's running nodes. Stale PID files are ignored.
+pub fn running_pids(paths: &Paths, version: &Version) -> Result<Vec<u32>> {
+    Ok(pid_files(paths, version)?
+        .iter()
+        .filter_map(|f| read_pid(f))
+        .filter(|pid| process_alive(*pid))
+        .collect())
+}
+
+/// A running node of a version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningNode {
+    /// The node name, e.g. `rabbit@hostname`, taken from the PID file name
+    pub name: String,
+    /// The OS process ID of the node's Erlang VM
+    pub pid: u32,
+}
+
+/// The version's running nodes, including those of its named environments
+pub fn running_nodes(paths: &Paths, version: &Version) -> Result<Vec<RunningNode>> {
+    let mut nodes: Vec<RunningNode> = pid_files(paths, version)?
+        .iter()
+        .filter_map(|f| {
+            let pid = read_pid(f).filter(|pid| process_alive(*pid))?;
+            let name = f.file_stem()?.to_string_lossy().into_owned();
+            Some(RunningNode { name, pid })
+        })
+        .collect();
+    nodes.dedup_by(|a, b| a.name == b.name);
+    Ok(nodes)
+}
+
+/// Names of the version's running nodes, e.g. `rabbit@hostname`, taken from their PID file names
+pub fn running_node_names(paths: &Paths, version: &Version) -> Result<Vec<String>> {
+    Ok(running_nodes(paths, version)?
+        .into_iter()
+        .map(|node| node.name)
+        .collect())
+}
+
+pub fn is_running(paths: &Paths, version: &Version) -> Result<bool> {
+    Ok(!running_pids(paths, version)?.is_empty())
+}
+
+#[cfg(unix)]
+pub fn process_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+#[cfg(windows)]
+pub fn process_alive(pid: u32) -> bool {
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+}
+
+/// Sends a signal, e.g. `STOP` or `CONT`, to a process
+#[cfg(unix)]
+pub fn send_signal(pid: u32, signal: &str) -> Result<()> {
+    let status = Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg(pid.to_string())
+        .stderr(Stdio::null())
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::CommandFailed(format!(
+            "failed to send SIG{} to process {}",
+            signal, pid
+        )))
+    }
+}
+
+/// Whether a process is stopped with SIGSTOP
+#[cfg(unix)]
+pub fn process_stopped(pid: u32) -> bool {
+    Command::new("ps")
+        .args(["-o", "stat=", "-p", &pid.to_string()])
+        .output()
+        .is_ok_and(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .trim_start()
+                .starts_with('T')
+        })
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Running frm with sudo against a user's FRM_DIR leaves root-owned files behind,
+//! which later break installations and upgrades run as that user.
+
+use std::fs;
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::{MetadataExt, lchown};
+use std::path::{Path, PathBuf};
+
+/// The owner of a file: user and group IDs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Owner {
+    pub uid: u32,
+    pub gid: u32,
+}
+
+impl Owner {
+    pub fn is_root(&self) -> bool {
+        self.uid == 0
+    }
+}
+
+/// Whether frm runs as root (or with sudo)
+#[cfg(unix)]
+pub fn running_as_root() -> bool {
+    // SAFETY: geteuid has no preconditions and cannot fail
+    unsafe { libc::geteuid() == 0 }
+}
+
+#[cfg(not(unix))]
+pub fn running_as_root() -> bool {
+    false
+}
+
+#[cfg(unix)]
+pub fn owner(path: &Path) -> io::Result<Owner> {
+    let metadata = fs::symlink_metadata(path)?;
+    Ok(Owner {
+        uid: metadata.uid(),
+        gid: metadata.gid(),
+    })
+}
+
+#[cfg(not(unix))]
+pub fn owner(_path: &Path) -> io::Result<Owner> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "file ownership is not supported on this platform",
+    ))
+}
+
+/// The owner of `base_dir` when frm runs as root and the directory belongs to another user,
+/// that is, when the files frm is about to create would end up owned by root
+pub fn root_in_user_dir(base_dir: &Path) -> Option<Owner> {
+    if !running_as_root() {
+        return None;
+    }
+    owner(base_dir).ok().filter(|owner| !owner.is_root())
+}
+
+/// Files and directories under `base_dir` owned by root when `base_dir` itself is not.
+/// Symlinks (e.g. linked versions) are not followed.
+pub fn root_owned_files(base_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    match owner(base_dir) {
+        Ok(owner) if !owner.is_root() => collect_root_owned(base_dir, &mut files)?,
+        _ => {}
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn collect_root_owned(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if owner(&path)?.is_root() {
+            files.push(path.clone());
+        }
+        if fs::symlink_metadata(&path)?.is_dir() {
+            collect_root_owned(&path, files)?;
+        }
+    }
+    Ok(())
+}
+
+/// Gives the files back to the owner of `base_dir`, returns how many were changed
+pub fn fix(base_dir: &Path) -> io::Result<usize> {
+    let owner = owner(base_dir)?;
+    let files = root_owned_files(base_dir)?;
+    for file in &files {
+        change_owner(file, owner)?;
+    }
+    Ok(files.len())
+}
+
+#[cfg(unix)]
+fn change_owner(path: &Path, owner: Owner) -> io::Result<()> {
+    lchown(path, Some(owner.uid), Some(owner.gid))
+}
+
+#[cfg(not(unix))]
+fn change_owner(_path: &Path, _owner: Owner) -> io::Result<()> {
+    Ok(())
+}
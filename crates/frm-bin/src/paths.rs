@@ -7,27 +7,53 @@
 // except according to those terms.
 
 use std::env;
-use std::fs;
+use std::fs::{self, File};
+use std::io;
 use std::path::{Path, PathBuf};
+use std::process;
 
 use crate::Result;
-use crate::common::env_vars::FRM_DIR;
+use crate::common::cli_tools::RABBITMQ_SERVER;
+use crate::common::env_vars::{FRM_DIR, FRM_READ_ONLY, FRM_SHARED_DIR};
 use crate::errors::Error;
 use crate::version::Version;
 
+/// Where frm keeps versions, configuration, and state.
+///
+/// Everything frm writes goes to the base directory (`FRM_DIR`, `~/.local/frm` by default).
+/// An optional shared directory (`FRM_SHARED_DIR`), e.g. `/opt/frm` provisioned by an
+/// administrator on a build server, provides versions in `versions/` that frm only reads.
+/// A version is resolved from the base directory first and from the shared one second.
+/// The configuration, data, logs, and environments of a shared version live in
+/// `shared-versions/<version>` of the base directory.
 #[derive(Debug, Clone)]
 pub struct Paths {
     base_dir: PathBuf,
+    shared_dir: Option<PathBuf>,
 }
 
 impl Paths {
     pub fn new() -> Result<Self> {
         let base_dir = Self::detect_base_dir()?;
-        Ok(Self { base_dir })
+        let shared_dir = env::var_os(FRM_SHARED_DIR)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        Ok(Self {
+            base_dir,
+            shared_dir,
+        })
     }
 
     pub fn with_base_dir(base_dir: PathBuf) -> Self {
-        Self { base_dir }
+        Self {
+            base_dir,
+            shared_dir: None,
+        }
+    }
+
+    pub fn with_shared_dir(mut self, shared_dir: PathBuf) -> Self {
+        self.shared_dir = Some(shared_dir);
+        self
     }
 
     fn detect_base_dir() -> Result<PathBuf> {
@@ -45,20 +71,81 @@ impl Paths {
         &self.base_dir
     }
 
+    pub fn shared_dir(&self) -> Option<&Path> {
+        self.shared_dir.as_deref()
+    }
+
     pub fn versions_dir(&self) -> PathBuf {
         self.base_dir.join("versions")
     }
 
+    /// Versions provisioned in the shared directory, if one is configured
+    pub fn shared_versions_dir(&self) -> Option<PathBuf> {
+        self.shared_dir.as_ref().map(|dir| dir.join("versions"))
+    }
+
+    /// The version's directory in the base directory or else, when only the shared
+    /// directory has the version, there
     pub fn version_dir(&self, version: &Version) -> PathBuf {
+        let own = self.own_version_dir(version);
+        if fs::symlink_metadata(&own).is_err()
+            && let Some(shared) = self.shared_version_dir(version)
+            && shared.exists()
+        {
+            return shared;
+        }
+        own
+    }
+
+    fn own_version_dir(&self, version: &Version) -> PathBuf {
         self.versions_dir().join(version.dir_name())
     }
 
+    fn shared_version_dir(&self, version: &Version) -> Option<PathBuf> {
+        self.shared_versions_dir()
+            .map(|dir| dir.join(version.dir_name()))
+    }
+
+    /// Whether a version comes from the shared directory, which frm does not modify
+    pub fn version_shared(&self, version: &Version) -> bool {
+        fs::symlink_metadata(self.own_version_dir(version)).is_err()
+            && self
+                .shared_version_dir(version)
+                .is_some_and(|dir| dir.exists())
+    }
+
+    /// Holds `etc`, `var`, and `envs` of a version: the version directory itself,
+    /// or for a shared version, a writable directory of the current user
+    pub fn version_state_dir(&self, version: &Version) -> PathBuf {
+        if self.version_shared(version) {
+            self.base_dir
+                .join("shared-versions")
+                .join(version.dir_name())
+        } else {
+            self.version_dir(version)
+        }
+    }
+
     pub fn version_sbin_dir(&self, version: &Version) -> PathBuf {
         self.version_dir(version).join("sbin")
     }
 
+    /// Temporary directory an archive is extracted to before it is moved into place
+    pub fn version_extraction_dir(&self, version: &Version) -> PathBuf {
+        self.versions_dir().join(format!(".{}-extracting", version))
+    }
+
+    /// Where `frm repair` keeps the configuration and data of a version while it is re-downloaded
+    pub fn version_repair_dir(&self, version: &Version) -> PathBuf {
+        self.versions_dir().join(format!(".{}-repairing", version))
+    }
+
+    pub fn version_server_script(&self, version: &Version) -> PathBuf {
+        self.version_sbin_dir(version).join(RABBITMQ_SERVER)
+    }
+
     pub fn version_etc_dir(&self, version: &Version) -> PathBuf {
-        self.version_dir(version).join("etc").join("rabbitmq")
+        self.version_state_dir(version).join("etc").join("rabbitmq")
     }
 
     pub fn version_confd_dir(&self, version: &Version) -> PathBuf {
@@ -66,12 +153,65 @@ impl Paths {
     }
 
     pub fn version_var_log_dir(&self, version: &Version) -> PathBuf {
-        self.version_dir(version)
+        self.version_state_dir(version)
+            .join("var")
+            .join("log")
+            .join("rabbitmq")
+    }
+
+    /// Run state of the version's default node, e.g. the output of `frm bg start`
+    pub fn version_run_dir(&self, version: &Version) -> PathBuf {
+        self.version_state_dir(version)
+            .join("var")
+            .join("run")
+            .join("rabbitmq")
+    }
+
+    pub fn version_data_dir(&self, version: &Version) -> PathBuf {
+        self.version_state_dir(version)
+            .join("var")
+            .join("lib")
+            .join("rabbitmq")
+            .join("mnesia")
+    }
+
+    pub fn version_envs_dir(&self, version: &Version) -> PathBuf {
+        self.version_state_dir(version).join("envs")
+    }
+
+    /// A named environment: a separate copy of etc/rabbitmq, data, and logs for a version
+    pub fn version_env_dir(&self, version: &Version, name: &str) -> PathBuf {
+        self.version_envs_dir(version).join(name)
+    }
+
+    pub fn version_env_etc_dir(&self, version: &Version, name: &str) -> PathBuf {
+        self.version_env_dir(version, name)
+            .join("etc")
+            .join("rabbitmq")
+    }
+
+    pub fn version_env_data_dir(&self, version: &Version, name: &str) -> PathBuf {
+        self.version_env_dir(version, name)
+            .join("var")
+            .join("lib")
+            .join("rabbitmq")
+            .join("mnesia")
+    }
+
+    pub fn version_env_log_dir(&self, version: &Version, name: &str) -> PathBuf {
+        self.version_env_dir(version, name)
             .join("var")
             .join("log")
             .join("rabbitmq")
     }
 
+    pub fn version_env_run_dir(&self, version: &Version, name: &str) -> PathBuf {
+        self.version_env_dir(version, name)
+            .join("var")
+            .join("run")
+            .join("rabbitmq")
+    }
+
     pub fn etc_dir(&self) -> PathBuf {
         self.base_dir.join("etc").join("rabbitmq")
     }
@@ -80,18 +220,145 @@ impl Paths {
         self.base_dir.join("downloads")
     }
 
+    /// Where the archive of a version is kept between installations, see `--keep-archive`
+    pub fn version_archive_file(&self, version: &Version) -> PathBuf {
+        self.downloads_dir().join(version.archive_name())
+    }
+
     pub fn config_file(&self) -> PathBuf {
         self.base_dir.join("config.toml")
     }
 
+    /// Releases with known critical regressions, see [`crate::denylist`]
+    pub fn denylist_file(&self) -> PathBuf {
+        self.base_dir.join("denylist.toml")
+    }
+
     pub fn default_file(&self) -> PathBuf {
         self.base_dir.join("default")
     }
 
+    /// A symlink to the directory of the default version, see [`crate::current`]
+    pub fn current_link(&self) -> PathBuf {
+        self.base_dir.join("current")
+    }
+
+    /// Where installation timestamps were kept before they moved to per-version metadata
     pub fn timestamps_file(&self) -> PathBuf {
         self.base_dir.join("version_timestamps.json")
     }
 
+    pub fn metadata_dir(&self) -> PathBuf {
+        self.base_dir.join("metadata")
+    }
+
+    pub fn version_metadata_file(&self, version: &Version) -> PathBuf {
+        self.metadata_dir()
+            .join(format!("{}.toml", version.dir_name()))
+    }
+
+    /// Definitions exported by 'frm migrate-data'
+    pub fn migrations_dir(&self) -> PathBuf {
+        self.base_dir.join("migrations")
+    }
+
+    pub fn trash_dir(&self) -> PathBuf {
+        self.base_dir.join("trash")
+    }
+
+    /// Named sets of versions created with 'frm workspace create', see [`crate::workspace`]
+    pub fn workspaces_dir(&self) -> PathBuf {
+        self.base_dir.join("workspaces")
+    }
+
+    pub fn workspace_dir(&self, name: &str) -> PathBuf {
+        self.workspaces_dir().join(name)
+    }
+
+    /// Trusted signing keys managed with 'frm keys', see [`crate::keyring`]
+    pub fn keys_dir(&self) -> PathBuf {
+        self.base_dir.join("keys")
+    }
+
+    /// Companion tools installed with 'frm toolbox', such as rabbitmqadmin v2 and PerfTest
+    pub fn tools_dir(&self) -> PathBuf {
+        self.base_dir.join("tools")
+    }
+
+    pub fn tool_dir(&self, tool: &str) -> PathBuf {
+        self.tools_dir().join(tool)
+    }
+
+    pub fn tool_version_dir(&self, tool: &str, tool_version: &str) -> PathBuf {
+        self.tool_dir(tool).join(tool_version)
+    }
+
+    /// User scripts run with 'frm run-script'
+    pub fn scripts_dir(&self) -> PathBuf {
+        self.base_dir.join("scripts")
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.base_dir.join("cache")
+    }
+
+    /// Cached release feed of the rabbitmq/server-packages repository, used for alphas
+    pub fn server_packages_feed_cache_file(&self) -> PathBuf {
+        self.cache_dir().join("server-packages-releases.json")
+    }
+
+    /// Cached release feed of the rabbitmq/rabbitmq-server repository, used by 'frm search'
+    pub fn server_releases_feed_cache_file(&self) -> PathBuf {
+        self.cache_dir().join("rabbitmq-server-releases.json")
+    }
+
+    /// The last fetched denylist, see [`crate::denylist`]
+    pub fn denylist_cache_file(&self) -> PathBuf {
+        self.cache_dir().join("denylist.json")
+    }
+
+    /// The last alpha reported by 'frm alphas watch'
+    pub fn alphas_watch_state_file(&self) -> PathBuf {
+        self.cache_dir().join("alphas-watch-last-seen")
+    }
+
+    /// The Erlang cookie shared by all versions, see 'frm cookie'
+    pub fn shared_cookie_file(&self) -> PathBuf {
+        self.base_dir.join("erlang.cookie")
+    }
+
+    /// A version-specific Erlang cookie, takes precedence over the shared one
+    pub fn version_cookie_file(&self, version: &Version) -> PathBuf {
+        self.version_etc_dir(version).join("erlang.cookie")
+    }
+
+    pub fn history_file(&self) -> PathBuf {
+        self.base_dir.join("history.jsonl")
+    }
+
+    /// Whether frm can write to the base directory. It cannot on a read-only filesystem,
+    /// e.g. a mounted shared cache, without permission, or when `FRM_READ_ONLY` is set.
+    /// Other errors are left to the command to report.
+    pub fn writable(&self) -> bool {
+        if env::var(FRM_READ_ONLY).is_ok_and(|v| !matches!(v.as_str(), "" | "0" | "false")) {
+            return false;
+        }
+        let probe = self
+            .base_dir
+            .join(format!(".write-probe-{}", process::id()));
+        let result = fs::create_dir_all(&self.base_dir).and_then(|()| File::create_new(&probe));
+        match result {
+            Ok(_) => {
+                fs::remove_file(&probe).ok();
+                true
+            }
+            Err(e) => !matches!(
+                e.kind(),
+                io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem
+            ),
+        }
+    }
+
     pub fn ensure_dirs(&self) -> Result<()> {
         fs::create_dir_all(self.versions_dir())?;
         fs::create_dir_all(self.downloads_dir())?;
@@ -103,24 +370,42 @@ impl Paths {
         self.version_dir(version).exists()
     }
 
-    pub fn installed_versions(&self) -> Result<Vec<Version>> {
-        let versions_dir = self.versions_dir();
-        if !versions_dir.exists() {
-            return Ok(Vec::new());
-        }
+    /// Whether a version is a link to an externally built directory, see `frm link`
+    pub fn version_linked(&self, version: &Version) -> bool {
+        fs::symlink_metadata(self.own_version_dir(version))
+            .is_ok_and(|m| m.file_type().is_symlink())
+    }
+
+    /// A version is broken when its directory exists but `sbin/rabbitmq-server` does not,
+    /// e.g. after an interrupted installation
+    pub fn version_broken(&self, version: &Version) -> bool {
+        self.version_installed(version) && !self.version_server_script(version).exists()
+    }
 
+    /// Versions in the base directory and the shared one
+    pub fn installed_versions(&self) -> Result<Vec<Version>> {
         let mut versions = Vec::new();
-        for entry in fs::read_dir(versions_dir)? {
-            let entry = entry?;
-            if entry.file_type()?.is_dir()
-                && let Some(name) = entry.file_name().to_str()
-                && let Ok(version) = name.parse::<Version>()
-            {
-                versions.push(version);
+        for versions_dir in [Some(self.versions_dir()), self.shared_versions_dir()]
+            .into_iter()
+            .flatten()
+        {
+            if !versions_dir.exists() {
+                continue;
+            }
+            for entry in fs::read_dir(versions_dir)? {
+                let entry = entry?;
+                // follows symlinks, so linked versions are included
+                if entry.path().is_dir()
+                    && let Some(name) = entry.file_name().to_str()
+                    && let Ok(version) = name.parse::<Version>()
+                {
+                    versions.push(version);
+                }
             }
         }
 
         versions.sort();
+        versions.dedup();
         Ok(versions)
     }
 
@@ -0,0 +1,61 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Running RabbitMQ PerfTest (rabbitmq-perf-test), a Java load testing tool.
+
+use std::env;
+use std::path::PathBuf;
+
+use crate::Result;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::toolbox::{self, Tool};
+
+/// Returns the path to a PerfTest JAR, downloading it if needed.
+///
+/// Without a requested version, the newest installed JAR is used, unless `update` is set
+/// or nothing is installed, in which case the latest release is downloaded.
+pub async fn ensure_jar(paths: &Paths, requested: Option<&str>, update: bool) -> Result<PathBuf> {
+    match requested {
+        Some(v) => {
+            let jar = toolbox::tool_path(paths, Tool::PerfTest, v);
+            if jar.is_file() {
+                return Ok(jar);
+            }
+        }
+        None if !update => {
+            if let Some(jar) = toolbox::find_installed(paths, Tool::PerfTest)? {
+                return Ok(jar);
+            }
+        }
+        None => {}
+    }
+
+    let (_, jar) = toolbox::install(paths, Tool::PerfTest, requested).await?;
+    Ok(jar)
+}
+
+/// Finds `java` in `JAVA_HOME` or on `PATH`
+pub fn find_java() -> Result<PathBuf> {
+    let exe = if cfg!(windows) { "java.exe" } else { "java" };
+
+    if let Some(java_home) = env::var_os("JAVA_HOME") {
+        let java = PathBuf::from(java_home).join("bin").join(exe);
+        if java.is_file() {
+            return Ok(java);
+        }
+    }
+
+    env::var_os("PATH")
+        .and_then(|path| {
+            env::split_paths(&path)
+                .map(|dir| dir.join(exe))
+                .find(|java| java.is_file())
+        })
+        .ok_or(Error::JavaNotFound)
+}
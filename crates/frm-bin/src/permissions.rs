@@ -0,0 +1,72 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Archives extracted on some filesystems (or repackaged by hand) lose the executable bit
+//! on the scripts in `sbin` and `escript`, which makes the version unusable.
+
+use std::fs;
+use std::io;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Directories of a version where every file is expected to be executable
+pub const EXECUTABLE_DIRS: &[&str] = &["sbin", "escript"];
+
+/// Files in `sbin` and `escript` of a version directory that are not executable
+pub fn non_executable_files(version_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for name in EXECUTABLE_DIRS {
+        let dir = version_dir.join(name);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let metadata = fs::metadata(entry.path())?;
+            if metadata.is_file() && !is_executable(&metadata) {
+                files.push(entry.path());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Makes the files in `sbin` and `escript` of a version directory executable,
+/// returns the files that had to be fixed
+pub fn fix(version_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let files = non_executable_files(version_dir)?;
+    for file in &files {
+        make_executable(file)?;
+    }
+    Ok(files)
+}
+
+#[cfg(unix)]
+pub fn is_executable(metadata: &fs::Metadata) -> bool {
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+pub fn is_executable(_metadata: &fs::Metadata) -> bool {
+    true
+}
+
+/// Adds the executable bit wherever the read bit is set, e.g. 0644 becomes 0755
+#[cfg(unix)]
+fn make_executable(path: &Path) -> io::Result<()> {
+    let mode = fs::metadata(path)?.permissions().mode();
+    let mode = mode | ((mode & 0o444) >> 2) | 0o100;
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
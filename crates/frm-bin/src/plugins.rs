@@ -0,0 +1,91 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! External subcommands, git style: an executable named `frm-foo` in `PATH`
+//! runs as `frm foo`. Built-in commands always take precedence.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::permissions;
+
+pub const PREFIX: &str = "frm-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plugin {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// The first `frm-<name>` executable in `PATH`
+pub fn find(name: &str, path_var: &OsStr) -> Option<PathBuf> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return None;
+    }
+    env::split_paths(path_var).find_map(|dir| {
+        candidate_files(&dir, name)
+            .into_iter()
+            .find(|path| is_executable_file(path))
+    })
+}
+
+/// Every `frm-<name>` executable in `PATH`, sorted by name.
+/// When several directories have one with the same name, the first one wins, as in [`find`].
+pub fn list(path_var: &OsStr) -> Vec<Plugin> {
+    let mut plugins = BTreeMap::new();
+    for dir in env::split_paths(path_var) {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = plugin_name(&path) else {
+                continue;
+            };
+            if !plugins.contains_key(&name) && is_executable_file(&path) {
+                plugins.insert(name, path);
+            }
+        }
+    }
+
+    plugins
+        .into_iter()
+        .map(|(name, path)| Plugin { name, path })
+        .collect()
+}
+
+fn plugin_name(path: &Path) -> Option<String> {
+    let file_name = if cfg!(windows) {
+        path.file_stem()?
+    } else {
+        path.file_name()?
+    };
+    let name = file_name.to_str()?.strip_prefix(PREFIX)?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(windows)]
+fn candidate_files(dir: &Path, name: &str) -> Vec<PathBuf> {
+    ["exe", "cmd", "bat"]
+        .iter()
+        .map(|ext| dir.join(format!("{}{}.{}", PREFIX, name, ext)))
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn candidate_files(dir: &Path, name: &str) -> Vec<PathBuf> {
+    vec![dir.join(format!("{}{}", PREFIX, name))]
+}
+
+fn is_executable_file(path: &Path) -> bool {
+    fs::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && permissions::is_executable(&metadata))
+}
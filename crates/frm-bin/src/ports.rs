@@ -0,0 +1,237 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The ports a node listens on according to its configuration.
+//!
+//! Plugin listeners (MQTT, STOMP, stream, management, etc.) only run when their
+//! plugin is enabled. Listeners over TLS only run when configured explicitly.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::net::TcpListener;
+
+use crate::Result;
+use crate::errors::Error;
+
+/// Erlang distribution listens on the AMQP port + 20000 unless `RABBITMQ_DIST_PORT` is set
+pub const DIST_PORT_OFFSET: u16 = 20000;
+pub const DIST_PORT_ENV_VAR: &str = "DIST_PORT";
+
+#[derive(Debug, Clone, Copy)]
+enum ListenerKeys {
+    /// `prefix.default`, `prefix.1`, etc., each an `interface:port` or a port;
+    /// `prefix = none` disables the listener
+    Listeners(&'static str),
+    /// A single port key
+    Port(&'static str),
+}
+
+struct ListenerSpec {
+    protocol: &'static str,
+    keys: ListenerKeys,
+    default_port: Option<u16>,
+    plugin: Option<&'static str>,
+}
+
+const LISTENERS: &[ListenerSpec] = &[
+    ListenerSpec {
+        protocol: "AMQP",
+        keys: ListenerKeys::Listeners("listeners.tcp"),
+        default_port: Some(5672),
+        plugin: None,
+    },
+    ListenerSpec {
+        protocol: "AMQPS",
+        keys: ListenerKeys::Listeners("listeners.ssl"),
+        default_port: None,
+        plugin: None,
+    },
+    ListenerSpec {
+        protocol: "MQTT",
+        keys: ListenerKeys::Listeners("mqtt.listeners.tcp"),
+        default_port: Some(1883),
+        plugin: Some("rabbitmq_mqtt"),
+    },
+    ListenerSpec {
+        protocol: "MQTTS",
+        keys: ListenerKeys::Listeners("mqtt.listeners.ssl"),
+        default_port: None,
+        plugin: Some("rabbitmq_mqtt"),
+    },
+    ListenerSpec {
+        protocol: "STOMP",
+        keys: ListenerKeys::Listeners("stomp.listeners.tcp"),
+        default_port: Some(61613),
+        plugin: Some("rabbitmq_stomp"),
+    },
+    ListenerSpec {
+        protocol: "STOMPS",
+        keys: ListenerKeys::Listeners("stomp.listeners.ssl"),
+        default_port: None,
+        plugin: Some("rabbitmq_stomp"),
+    },
+    ListenerSpec {
+        protocol: "Stream",
+        keys: ListenerKeys::Listeners("stream.listeners.tcp"),
+        default_port: Some(5552),
+        plugin: Some("rabbitmq_stream"),
+    },
+    ListenerSpec {
+        protocol: "Stream over TLS",
+        keys: ListenerKeys::Listeners("stream.listeners.ssl"),
+        default_port: None,
+        plugin: Some("rabbitmq_stream"),
+    },
+    ListenerSpec {
+        protocol: "Management",
+        keys: ListenerKeys::Port("management.tcp.port"),
+        default_port: Some(15672),
+        plugin: Some("rabbitmq_management"),
+    },
+    ListenerSpec {
+        protocol: "Management over TLS",
+        keys: ListenerKeys::Port("management.ssl.port"),
+        default_port: None,
+        plugin: Some("rabbitmq_management"),
+    },
+    ListenerSpec {
+        protocol: "Web MQTT",
+        keys: ListenerKeys::Port("web_mqtt.tcp.port"),
+        default_port: Some(15675),
+        plugin: Some("rabbitmq_web_mqtt"),
+    },
+    ListenerSpec {
+        protocol: "Web STOMP",
+        keys: ListenerKeys::Port("web_stomp.tcp.port"),
+        default_port: Some(15674),
+        plugin: Some("rabbitmq_web_stomp"),
+    },
+    ListenerSpec {
+        protocol: "Prometheus metrics",
+        keys: ListenerKeys::Port("prometheus.tcp.port"),
+        default_port: Some(15692),
+        plugin: Some("rabbitmq_prometheus"),
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Listener {
+    pub protocol: &'static str,
+    pub port: u16,
+    /// The configuration key or environment variable the port comes from, `None` for defaults
+    pub source: Option<String>,
+    /// The plugin that provides the listener, if any
+    pub plugin: Option<&'static str>,
+    /// Whether the listener runs, that is, whether its plugin is enabled
+    pub enabled: bool,
+}
+
+/// Listeners of a node with the given effective rabbitmq.conf settings,
+/// rabbitmq-env.conf variables, and enabled plugins (`None` if unknown).
+pub fn listeners(
+    settings: &BTreeMap<String, String>,
+    env_conf: &BTreeMap<String, String>,
+    enabled_plugins: Option<&[String]>,
+) -> Result<Vec<Listener>> {
+    let mut result = Vec::new();
+    for spec in LISTENERS {
+        let enabled = spec
+            .plugin
+            .is_none_or(|p| enabled_plugins.is_some_and(|ps| ps.iter().any(|e| e == p)));
+        let mut push = |port: u16, source: Option<String>| {
+            result.push(Listener {
+                protocol: spec.protocol,
+                port,
+                source,
+                plugin: spec.plugin,
+                enabled,
+            })
+        };
+
+        let configured = match spec.keys {
+            ListenerKeys::Listeners(prefix) => {
+                if settings.get(prefix).is_some_and(|v| v.trim() == "none") {
+                    continue;
+                }
+                let key_prefix = format!("{}.", prefix);
+                settings
+                    .iter()
+                    .filter(|(k, _)| k.starts_with(&key_prefix))
+                    .map(|(k, v)| {
+                        let port = parse_listener_port(v).ok_or_else(|| invalid(k, v))?;
+                        Ok((k.clone(), port))
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            ListenerKeys::Port(key) => match settings.get(key) {
+                Some(v) => vec![(
+                    key.to_string(),
+                    v.trim().parse().map_err(|_| invalid(key, v))?,
+                )],
+                None => Vec::new(),
+            },
+        };
+
+        if configured.is_empty() {
+            if let Some(port) = spec.default_port {
+                push(port, None);
+            }
+        } else {
+            for (key, port) in configured {
+                push(port, Some(key));
+            }
+        }
+    }
+
+    let dist = match env_conf.get(DIST_PORT_ENV_VAR) {
+        Some(v) => {
+            let var = format!("RABBITMQ_{}", DIST_PORT_ENV_VAR);
+            (v.trim().parse().map_err(|_| invalid(&var, v))?, Some(var))
+        }
+        None => {
+            let amqp = result
+                .iter()
+                .find(|l| l.protocol == "AMQP")
+                .map(|l| l.port)
+                .unwrap_or(5672);
+            (amqp.saturating_add(DIST_PORT_OFFSET), None)
+        }
+    };
+    result.push(Listener {
+        protocol: "Erlang distribution",
+        port: dist.0,
+        source: dist.1,
+        plugin: None,
+        enabled: true,
+    });
+
+    Ok(result)
+}
+
+fn invalid(key: &str, value: &str) -> Error {
+    Error::Config(format!("invalid {} value: {}", key, value))
+}
+
+/// Listeners are configured either as a port or as `interface:port`
+pub fn parse_listener_port(value: &str) -> Option<u16> {
+    let value = value.trim();
+    value
+        .parse()
+        .ok()
+        .or_else(|| value.rsplit_once(':')?.1.parse().ok())
+}
+
+/// Whether something already listens on the port, `None` if that cannot be determined
+/// (e.g. binding to ports below 1024 requires privileges)
+pub fn port_in_use(port: u16) -> Option<bool> {
+    match TcpListener::bind(("0.0.0.0", port)) {
+        Ok(_) => Some(false),
+        Err(e) if e.kind() == io::ErrorKind::AddrInUse => Some(true),
+        Err(_) => None,
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Time spent in each phase of long commands such as `install`, reported with `--profile`.
+//!
+//! Phases are timed whether or not `--profile` is used, the breakdown is only printed with it.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    /// Finding out what to install, e.g. the latest release or the release tag of an alpha
+    Resolve,
+    /// Network transfers
+    Download,
+    /// Checks of what was downloaded or extracted
+    Verify,
+    /// Unpacking archives and moving the result into place
+    Extract,
+    /// Permissions, default configuration, archive cleanup, deduplication
+    PostInstall,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 5] = [
+        Phase::Resolve,
+        Phase::Download,
+        Phase::Verify,
+        Phase::Extract,
+        Phase::PostInstall,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Resolve => "resolve",
+            Phase::Download => "download",
+            Phase::Verify => "verify",
+            Phase::Extract => "extract",
+            Phase::PostInstall => "post-install",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+static TIMINGS: Mutex<[Duration; Phase::ALL.len()]> =
+    Mutex::new([Duration::ZERO; Phase::ALL.len()]);
+
+/// Adds `elapsed` to the time spent in `phase`
+pub fn record(phase: Phase, elapsed: Duration) {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings[phase as usize] += elapsed;
+    }
+}
+
+/// Times `phase` until the returned timer is dropped
+pub fn start(phase: Phase) -> PhaseTimer {
+    PhaseTimer {
+        phase,
+        started: Instant::now(),
+    }
+}
+
+pub struct PhaseTimer {
+    phase: Phase,
+    started: Instant,
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        record(self.phase, self.started.elapsed());
+    }
+}
+
+/// The phases this process spent time in, in the order they happen during an installation
+pub fn timings() -> Vec<(Phase, Duration)> {
+    let Ok(timings) = TIMINGS.lock() else {
+        return Vec::new();
+    };
+    Phase::ALL
+        .into_iter()
+        .filter(|phase| !timings[*phase as usize].is_zero())
+        .map(|phase| (phase, timings[phase as usize]))
+        .collect()
+}
+
+/// A breakdown of `total` by phase. Whatever was not spent in a phase, e.g. parsing
+/// arguments and reading local state, is reported as `other`.
+pub fn format_report(timings: &[(Phase, Duration)], total: Duration) -> String {
+    let percent = |d: Duration| {
+        if total.is_zero() {
+            0.0
+        } else {
+            d.as_secs_f64() * 100.0 / total.as_secs_f64()
+        }
+    };
+    let accounted: Duration = timings.iter().map(|(_, d)| *d).sum();
+
+    let mut out = String::from("Timing breakdown:\n");
+    for (phase, elapsed) in timings {
+        out.push_str(&format!(
+            "  {:<14}{:>9.3}s {:>5.1}%\n",
+            phase.name(),
+            elapsed.as_secs_f64(),
+            percent(*elapsed)
+        ));
+    }
+    if !timings.is_empty() {
+        let other = total.saturating_sub(accounted);
+        out.push_str(&format!(
+            "  {:<14}{:>9.3}s {:>5.1}%\n",
+            "other",
+            other.as_secs_f64(),
+            percent(other)
+        ));
+    }
+    out.push_str(&format!("  {:<14}{:>9.3}s\n", "total", total.as_secs_f64()));
+    out
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Provenance records: where an installed version came from.
+//!
+//! Installing, reinstalling, and repairing a version record the archive it was extracted from,
+//! its SHA-256 checksum, and the frm version that installed it in the version's metadata,
+//! see [`crate::metadata`]. The signature status is not part of the record: it comes from
+//! the most recent 'releases check-signature'.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::Result;
+use crate::metadata::VersionMetadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+pub const FRM_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// The URL the archive was downloaded from, or the local file it was installed from
+    pub source: String,
+    pub file_name: String,
+    /// Of the archive, in lowercase hex
+    pub sha256: String,
+    pub frm_version: String,
+    /// In seconds since the Unix epoch
+    pub recorded_at: u64,
+}
+
+impl Provenance {
+    pub fn new(source: impl Into<String>, file_name: impl Into<String>, sha256: String) -> Self {
+        Self {
+            source: source.into(),
+            file_name: file_name.into(),
+            sha256,
+            frm_version: FRM_VERSION.to_string(),
+            recorded_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        }
+    }
+
+    /// Replaces the provenance record of a version
+    pub fn record(&self, paths: &Paths, version: &Version) -> Result<()> {
+        VersionMetadata::update(paths, version, |m| m.provenance = Some(self.clone()))?;
+        Ok(())
+    }
+
+    pub fn load(paths: &Paths, version: &Version) -> Result<Option<Self>> {
+        Ok(VersionMetadata::load(paths, version)?.provenance)
+    }
+}
+
+pub fn sha256_hex(digest: impl AsRef<[u8]>) -> String {
+    digest
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(sha256_hex(hasher.finalize()))
+}
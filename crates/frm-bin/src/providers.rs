@@ -0,0 +1,106 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Other version managers frm can fall back to for versions it has not installed itself.
+//!
+//! Their installations are never modified: frm links them under `versions/`
+//! (see `frm link`), so they can be activated but not uninstalled or reinstalled.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use crate::Result;
+use crate::common::cli_tools::RABBITMQ_SERVER;
+use crate::common::env_vars::{ASDF_DATA_DIR, MISE_DATA_DIR, XDG_DATA_HOME};
+use crate::version::Version;
+
+const PLUGIN_NAME: &str = "rabbitmq";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Provider {
+    /// asdf, versions in ~/.asdf/installs/rabbitmq
+    Asdf,
+    /// mise, versions in ~/.local/share/mise/installs/rabbitmq
+    Mise,
+}
+
+impl Provider {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Provider::Asdf => "asdf",
+            Provider::Mise => "mise",
+        }
+    }
+
+    /// The provider's data directory, e.g. ~/.asdf
+    pub fn data_dir(&self) -> Option<PathBuf> {
+        match self {
+            Provider::Asdf => env::var_os(ASDF_DATA_DIR)
+                .map(PathBuf::from)
+                .or_else(|| dirs::home_dir().map(|h| h.join(".asdf"))),
+            Provider::Mise => env::var_os(MISE_DATA_DIR)
+                .map(PathBuf::from)
+                .or_else(|| env::var_os(XDG_DATA_HOME).map(|d| PathBuf::from(d).join("mise")))
+                .or_else(|| dirs::home_dir().map(|h| h.join(".local").join("share").join("mise"))),
+        }
+    }
+
+    /// The directory the provider installs versions of a tool (plugin) into
+    pub fn tool_installs_dir(&self, tool: &str) -> Option<PathBuf> {
+        self.data_dir().map(|d| d.join("installs").join(tool))
+    }
+
+    /// The directory the provider installs RabbitMQ versions into
+    pub fn installs_dir(&self) -> Option<PathBuf> {
+        self.tool_installs_dir(PLUGIN_NAME)
+    }
+
+    /// The RabbitMQ directory (the one with `sbin`) of an installed version, if any.
+    /// Some plugins extract the generic UNIX archive as is, into a `rabbitmq_server-<version>` subdirectory.
+    pub fn version_dir(&self, version: &Version) -> Option<PathBuf> {
+        let install_dir = self.installs_dir()?.join(version.dir_name());
+        [
+            install_dir.clone(),
+            install_dir.join(version.extracted_dir_name()),
+        ]
+        .into_iter()
+        .find(|dir| dir.join("sbin").join(RABBITMQ_SERVER).is_file())
+    }
+
+    /// Versions installed by the provider, oldest first
+    pub fn installed_versions(&self) -> Result<Vec<Version>> {
+        let Some(installs_dir) = self.installs_dir().filter(|d| d.is_dir()) else {
+            return Ok(Vec::new());
+        };
+
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(installs_dir)? {
+            if let Some(version) = entry?
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<Version>().ok())
+                && self.version_dir(&version).is_some()
+            {
+                versions.push(version);
+            }
+        }
+
+        versions.sort();
+        Ok(versions)
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -6,12 +6,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::time::Duration;
+
 use serde::Deserialize;
+use tracing::{debug, instrument};
 
 use crate::Result;
 use crate::common::http::USER_AGENT;
-use crate::common::urls::{RABBITMQ_SERVER_API_URL, SERVER_PACKAGES_API_URL};
+use crate::common::urls::{
+    RABBITMQ_SERVER_API_URL, RABBITMQ_SERVER_COMMITS_API_URL, RABBITMQ_SERVER_COMPARE_API_URL,
+    SERVER_PACKAGES_API_URL,
+};
 use crate::errors::Error;
+use crate::feed_cache::{self, FEED_CACHE_TTL};
+use crate::paths::Paths;
+use crate::profile::{self, Phase};
 use crate::version::Version;
 
 #[derive(Debug, Deserialize)]
@@ -19,6 +28,68 @@ pub struct GitHubRelease {
     pub tag_name: String,
     pub name: String,
     pub published_at: String,
+    #[serde(default)]
+    pub assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitHubAsset {
+    pub name: String,
+}
+
+impl GitHubRelease {
+    pub fn has_asset(&self, name: &str) -> bool {
+        self.assets.iter().any(|asset| asset.name == name)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitComparison {
+    /// One of "ahead", "behind", "identical", or "diverged"
+    pub status: String,
+    pub total_commits: u64,
+    pub commits: Vec<GitHubCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitHubCommit {
+    pub sha: String,
+    pub commit: CommitDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitDetails {
+    pub message: String,
+    pub author: Option<CommitAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub date: String,
+}
+
+impl GitHubCommit {
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(8)]
+    }
+
+    pub fn summary(&self) -> &str {
+        self.commit.message.lines().next().unwrap_or_default()
+    }
+
+    /// Pull requests referenced by the summary, e.g. "Merge pull request #14321 from ..."
+    /// or "Fix a leak (#14321)"
+    pub fn pull_requests(&self) -> Vec<u64> {
+        self.summary()
+            .split('#')
+            .skip(1)
+            .filter_map(|rest| {
+                let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+                digits.parse().ok()
+            })
+            .collect()
+    }
 }
 
 pub struct AlphaRelease {
@@ -27,39 +98,69 @@ pub struct AlphaRelease {
     pub published_at: String,
 }
 
+const SERVER_PACKAGES_FEED_PAGE_SIZE: &str = "100";
+const SERVER_RELEASES_FEED_PAGE_SIZE: &str = "100";
+
+/// Lists releases of the rabbitmq/server-packages repository, see [`crate::feed_cache`]
+pub async fn fetch_server_packages_releases(
+    client: &reqwest::Client,
+    paths: &Paths,
+    ttl: Duration,
+) -> Result<Vec<GitHubRelease>> {
+    let url = format!(
+        "{}?per_page={}",
+        SERVER_PACKAGES_API_URL, SERVER_PACKAGES_FEED_PAGE_SIZE
+    );
+    let body =
+        feed_cache::fetch(client, &url, &paths.server_packages_feed_cache_file(), ttl).await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Lists GA, RC, and beta releases of the rabbitmq/rabbitmq-server repository,
+/// see [`crate::feed_cache`]
+pub async fn fetch_server_releases(
+    client: &reqwest::Client,
+    paths: &Paths,
+    ttl: Duration,
+) -> Result<Vec<GitHubRelease>> {
+    let url = format!(
+        "{}?per_page={}",
+        RABBITMQ_SERVER_API_URL, SERVER_RELEASES_FEED_PAGE_SIZE
+    );
+    let body =
+        feed_cache::fetch(client, &url, &paths.server_releases_feed_cache_file(), ttl).await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+#[instrument(level = "debug", skip(client, paths), fields(version = %version))]
 pub async fn find_server_packages_release_tag(
     client: &reqwest::Client,
+    paths: &Paths,
     version: &Version,
 ) -> Result<String> {
+    let _timer = profile::start(Phase::Resolve);
     let version_str = version.to_string();
 
-    let releases: Vec<GitHubRelease> = client
-        .get(SERVER_PACKAGES_API_URL)
-        .query(&[("per_page", "100")])
-        .header("User-Agent", USER_AGENT)
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    for release in releases {
-        if release.name.contains(&version_str) {
-            return Ok(release.tag_name);
+    // a cached feed may predate the release, so it is revalidated before giving up
+    for ttl in [FEED_CACHE_TTL, Duration::ZERO] {
+        let releases = fetch_server_packages_releases(client, paths, ttl).await?;
+        for release in releases {
+            if release.name.contains(&version_str) {
+                debug!(tag = %release.tag_name, "release found");
+                return Ok(release.tag_name);
+            }
         }
     }
 
     Err(Error::ReleaseNotFound(version_str))
 }
 
-pub async fn fetch_alpha_releases(client: &reqwest::Client) -> Result<Vec<AlphaRelease>> {
-    let releases: Vec<GitHubRelease> = client
-        .get(SERVER_PACKAGES_API_URL)
-        .query(&[("per_page", "100")])
-        .header("User-Agent", USER_AGENT)
-        .send()
-        .await?
-        .json()
-        .await?;
+#[instrument(level = "debug", skip_all)]
+pub async fn fetch_alpha_releases(
+    client: &reqwest::Client,
+    paths: &Paths,
+) -> Result<Vec<AlphaRelease>> {
+    let releases = fetch_server_packages_releases(client, paths, FEED_CACHE_TTL).await?;
 
     let mut alpha_releases = Vec::new();
 
@@ -75,11 +176,13 @@ pub async fn fetch_alpha_releases(client: &reqwest::Client) -> Result<Vec<AlphaR
         }
     }
 
+    debug!(count = alpha_releases.len(), "alpha releases listed");
     Ok(alpha_releases)
 }
 
-pub async fn find_latest_alpha(client: &reqwest::Client) -> Result<AlphaRelease> {
-    let releases = fetch_alpha_releases(client).await?;
+pub async fn find_latest_alpha(client: &reqwest::Client, paths: &Paths) -> Result<AlphaRelease> {
+    let _timer = profile::start(Phase::Resolve);
+    let releases = fetch_alpha_releases(client, paths).await?;
 
     releases
         .into_iter()
@@ -98,7 +201,9 @@ pub fn parse_version_from_release_name(name: &str) -> Option<Version> {
     }
 }
 
+#[instrument(level = "debug", skip_all)]
 pub async fn find_latest_ga_release(client: &reqwest::Client) -> Result<Version> {
+    let _timer = profile::start(Phase::Resolve);
     let releases: Vec<GitHubRelease> = client
         .get(RABBITMQ_SERVER_API_URL)
         .query(&[("per_page", "50")])
@@ -112,6 +217,7 @@ pub async fn find_latest_ga_release(client: &reqwest::Client) -> Result<Version>
         if let Some(version) = parse_version_from_tag(&release.tag_name)
             && version.is_ga()
         {
+            debug!(version = %version, "latest GA release found");
             return Ok(version);
         }
     }
@@ -119,7 +225,70 @@ pub async fn find_latest_ga_release(client: &reqwest::Client) -> Result<Version>
     Err(Error::ReleaseNotFound("no GA releases found".to_string()))
 }
 
+/// Alpha builds are identified by the rabbitmq-server commit they were produced from,
+/// e.g. 4.3.0-alpha.132057c7 was built from commit 132057c7
+pub fn alpha_commit(version: &Version) -> Option<&str> {
+    let prerelease = version.prerelease.as_ref().filter(|p| p.is_alpha())?;
+    let identifier = prerelease.identifier();
+    let is_sha = identifier.len() >= 7 && identifier.chars().all(|c| c.is_ascii_hexdigit());
+    is_sha.then_some(identifier)
+}
+
+/// Lists the rabbitmq-server commits between the commits two alpha builds were produced from
+pub async fn compare_alphas(
+    client: &reqwest::Client,
+    base: &Version,
+    head: &Version,
+) -> Result<CommitComparison> {
+    let base_sha = alpha_commit(base).ok_or_else(|| Error::ExpectedAlphaVersion(base.clone()))?;
+    let head_sha = alpha_commit(head).ok_or_else(|| Error::ExpectedAlphaVersion(head.clone()))?;
+
+    let url = format!(
+        "{}/{}...{}",
+        RABBITMQ_SERVER_COMPARE_API_URL, base_sha, head_sha
+    );
+    let comparison = client
+        .get(url)
+        .query(&[("per_page", "100")])
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    Ok(comparison)
+}
+
+/// Fetches the rabbitmq-server commit an alpha build was produced from
+pub async fn fetch_alpha_commit(
+    client: &reqwest::Client,
+    version: &Version,
+) -> Result<GitHubCommit> {
+    let sha = alpha_commit(version).ok_or_else(|| Error::ExpectedAlphaVersion(version.clone()))?;
+
+    let response = client
+        .get(format!("{}/{}", RABBITMQ_SERVER_COMMITS_API_URL, sha))
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND
+        || response.status() == reqwest::StatusCode::UNPROCESSABLE_ENTITY
+    {
+        return Err(Error::ReleaseNotFound(format!(
+            "rabbitmq-server commit {} of {}",
+            sha, version
+        )));
+    }
+
+    Ok(response.error_for_status()?.json().await?)
+}
+
+/// Parses `v4.2.3`-style tags as well as the `rabbitmq_v3_6_16` tags used up to RabbitMQ 3.6.x
 pub fn parse_version_from_tag(tag: &str) -> Option<Version> {
-    let version_str = tag.strip_prefix('v')?;
-    version_str.parse().ok()
+    if tag.starts_with('v') || tag.starts_with("rabbitmq_v") {
+        tag.parse().ok()
+    } else {
+        None
+    }
 }
@@ -0,0 +1,158 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! User maintenance scripts: executables in `scripts/` of the frm directory,
+//! run against a version with `frm run-script`.
+//!
+//! A script describes itself with the first comment line after the shebang, e.g.
+//!
+//! ```text
+//! #!/bin/sh
+//! # Declares the queues the integration tests expect
+//! ```
+
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+use crate::common::env_vars::{
+    FRM_DATA_DIR, FRM_DIR, FRM_ENV, FRM_ETC_DIR, FRM_LOG_DIR, FRM_SBIN_DIR, FRM_VERSION,
+    FRM_VERSION_DIR,
+};
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::permissions;
+use crate::version::Version;
+
+/// Only this many lines are read looking for a description
+const HEADER_LINES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    pub name: String,
+    pub path: PathBuf,
+    pub description: Option<String>,
+}
+
+/// Executable files in the scripts directory, sorted by name
+pub fn list(paths: &Paths) -> Result<Vec<Script>> {
+    let dir = paths.scripts_dir();
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut scripts = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = fs::metadata(&path)?;
+        if !metadata.is_file() || !permissions::is_executable(&metadata) {
+            continue;
+        }
+        let Some(name) = entry.file_name().to_str().map(str::to_string) else {
+            continue;
+        };
+        if name.starts_with('.') {
+            continue;
+        }
+        scripts.push(Script {
+            name,
+            description: description(&path),
+            path,
+        });
+    }
+
+    scripts.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(scripts)
+}
+
+pub fn find(paths: &Paths, name: &str) -> Result<Script> {
+    list(paths)?
+        .into_iter()
+        .find(|script| script.name == name)
+        .ok_or_else(|| Error::ScriptNotFound {
+            name: name.to_string(),
+            dir: paths.scripts_dir().display().to_string(),
+        })
+}
+
+/// The first comment line after the shebang, without the comment marker
+pub fn description(path: &Path) -> Option<String> {
+    let file = fs::File::open(path).ok()?;
+    BufReader::new(file)
+        .lines()
+        .take(HEADER_LINES)
+        .map_while(|line| line.ok())
+        .filter(|line| !line.starts_with("#!"))
+        .find_map(|line| {
+            let text = line
+                .trim()
+                .strip_prefix('#')
+                .or_else(|| line.trim().strip_prefix("//"))?
+                .trim();
+            (!text.is_empty()).then(|| text.to_string())
+        })
+}
+
+/// Environment variables a script is run with: the version's directories, its
+/// `rabbitmq-server` configuration, cookie, and node name, and `PATH` with its `sbin` first
+pub fn env_vars(
+    paths: &Paths,
+    version: &Version,
+    env: Option<&str>,
+) -> Result<Vec<(&'static str, String)>> {
+    let (etc_dir, log_dir) = match env {
+        Some(name) => (
+            paths.version_env_etc_dir(version, name),
+            paths.version_env_log_dir(version, name),
+        ),
+        None => (
+            paths.version_etc_dir(version),
+            paths.version_var_log_dir(version),
+        ),
+    };
+    let data_dir = envs::data_dir(paths, version, env)?;
+    let sbin_dir = paths.version_sbin_dir(version);
+
+    let mut vars = vec![
+        (FRM_DIR, paths.base_dir().display().to_string()),
+        (FRM_VERSION, version.to_string()),
+        (
+            FRM_VERSION_DIR,
+            paths.version_dir(version).display().to_string(),
+        ),
+        (FRM_SBIN_DIR, sbin_dir.display().to_string()),
+        (FRM_ETC_DIR, etc_dir.display().to_string()),
+        (FRM_DATA_DIR, data_dir.display().to_string()),
+        (FRM_LOG_DIR, log_dir.display().to_string()),
+    ];
+    if let Some(name) = env {
+        vars.push((FRM_ENV, name.to_string()));
+    }
+    vars.extend(
+        envs::node_env_vars(paths, version, env)?
+            .into_iter()
+            .map(|(name, path)| (name, path.display().to_string())),
+    );
+    vars.extend(cookie::env_vars(paths, version)?);
+    vars.extend(envs::node_name_env_vars(paths, version, env)?);
+
+    let mut path_dirs = vec![sbin_dir];
+    if let Some(path_var) = env::var_os("PATH") {
+        path_dirs.extend(env::split_paths(&path_var));
+    }
+    if let Ok(path_var) = env::join_paths(path_dirs) {
+        vars.push(("PATH", path_var.to_string_lossy().into_owned()));
+    }
+
+    Ok(vars)
+}
@@ -0,0 +1,40 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Versions provisioned in a shared, read-only directory (`FRM_SHARED_DIR`), e.g. by
+//! an administrator of a build server. frm never writes there: the configuration,
+//! data, and logs of a shared version are kept in the current user's frm directory,
+//! see [`Paths::version_state_dir`].
+
+use std::fs;
+
+use crate::Result;
+use crate::download::{copy_default_config, copy_dir_recursive};
+use crate::paths::Paths;
+use crate::version::Version;
+
+/// Sets up the per-user configuration, data, and log directories of a shared version
+/// on first use, starting with a copy of the configuration it was provisioned with
+pub fn prepare(paths: &Paths, version: &Version) -> Result<()> {
+    if !paths.version_shared(version) {
+        return Ok(());
+    }
+    let etc_dir = paths.version_etc_dir(version);
+    if etc_dir.exists() {
+        return Ok(());
+    }
+
+    let provisioned_etc_dir = paths.version_dir(version).join("etc").join("rabbitmq");
+    if provisioned_etc_dir.is_dir() {
+        copy_dir_recursive(&provisioned_etc_dir, &etc_dir)?;
+    }
+    copy_default_config(paths, version)?;
+    fs::create_dir_all(paths.version_data_dir(version))?;
+    fs::create_dir_all(paths.version_var_log_dir(version))?;
+    Ok(())
+}
@@ -8,12 +8,18 @@
 
 use std::env;
 use std::fmt;
+use std::path::Path;
 use std::str::FromStr;
 
 use clap::ValueEnum;
 
-use crate::common::env_vars::FRM_SHELL;
+use crate::common::env_vars::{
+    FRM_ACTIVE_SBIN, FRM_SHELL, RABBITMQ_ADVANCED_CONFIG_FILE, RABBITMQ_CONFIG_FILE,
+    RABBITMQ_CONFIG_FILES, RABBITMQ_ENABLED_PLUGINS_FILE, RABBITMQ_HOME, RABBITMQ_LOG_BASE,
+    RABBITMQ_MNESIA_BASE,
+};
 use crate::errors::Error;
+use crate::metadata::VersionMetadata;
 use crate::paths::Paths;
 use crate::version::Version;
 
@@ -28,6 +34,10 @@ const ENV_BASH_TEMPLATE: &str = include_str!("../shells/env/bash.template");
 const ENV_ZSH_TEMPLATE: &str = include_str!("../shells/env/zsh.template");
 const ENV_NU_TEMPLATE: &str = include_str!("../shells/env/nu.template");
 
+const DEACTIVATE_BASH_TEMPLATE: &str = include_str!("../shells/deactivate/bash.template");
+const DEACTIVATE_ZSH_TEMPLATE: &str = include_str!("../shells/deactivate/zsh.template");
+const DEACTIVATE_NU_TEMPLATE: &str = include_str!("../shells/deactivate/nu.template");
+
 const INIT_BASH_TEMPLATE: &str = include_str!("../shells/init/bash.template");
 const INIT_ZSH_TEMPLATE: &str = include_str!("../shells/init/zsh.template");
 const INIT_NU_TEMPLATE: &str = include_str!("../shells/init/nu.template");
@@ -56,9 +66,79 @@ impl Shell {
     }
 
     pub fn env_script(&self, paths: &Paths, version: &Version) -> String {
+        let exports: String = activation_env_vars(paths, version)
+            .iter()
+            .map(|(name, value)| self.export_var(name, value))
+            .collect();
+
+        format!(
+            "{}{}{}",
+            self.render_env_template(paths, version),
+            exports,
+            self.deactivate_function()
+        )
+    }
+
+    /// Like [`Shell::env_script`] but only modifies `PATH`, leaving `RABBITMQ_HOME`
+    /// and other RABBITMQ_* variables alone
+    pub fn sbin_only_env_script(&self, paths: &Paths, version: &Version) -> String {
         let sbin_path = paths.version_sbin_dir(version).display().to_string();
+
+        format!(
+            "{}{}{}",
+            self.render_env_template(paths, version),
+            self.export_var(FRM_ACTIVE_SBIN, &sbin_path),
+            self.deactivate_function()
+        )
+    }
+
+    /// Puts a workspace's shim directory first in `PATH`, see [`crate::workspace`]
+    pub fn workspace_env_script(
+        &self,
+        paths: &Paths,
+        bin_dir: &Path,
+        vars: &[(&str, String)],
+    ) -> String {
+        let exports: String = vars
+            .iter()
+            .map(|(name, value)| self.export_var(name, value))
+            .collect();
+
+        format!(
+            "{}{}{}{}",
+            self.render_path_template(paths, bin_dir),
+            self.export_var(FRM_ACTIVE_SBIN, &bin_dir.display().to_string()),
+            exports,
+            self.deactivate_function()
+        )
+    }
+
+    /// Serializes an environment variable assignment in this shell's syntax
+    pub fn export_var(&self, name: &str, value: &str) -> String {
+        match self {
+            Shell::Bash | Shell::Zsh => {
+                let escaped = value
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('$', "\\$")
+                    .replace('`', "\\`");
+                format!("export {}=\"{}\"\n", name, escaped)
+            }
+            Shell::Nu => {
+                let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+                format!("$env.{} = \"{}\"\n", name, escaped)
+            }
+        }
+    }
+
+    fn render_env_template(&self, paths: &Paths, version: &Version) -> String {
+        self.render_path_template(paths, &paths.version_sbin_dir(version))
+    }
+
+    /// Puts `sbin_dir` first in `PATH` in place of directories added by frm before
+    fn render_path_template(&self, paths: &Paths, sbin_dir: &Path) -> String {
+        let sbin_path = sbin_dir.display().to_string();
         let base_dir = paths.base_dir().display().to_string();
-        let version_dir = paths.version_dir(version).display().to_string();
 
         let template = match self {
             Shell::Bash => ENV_BASH_TEMPLATE,
@@ -69,7 +149,20 @@ impl Shell {
         template
             .replace("{{sbin_path}}", &sbin_path)
             .replace("{{base_dir}}", &base_dir)
-            .replace("{{version_dir}}", &version_dir)
+    }
+
+    /// Defines `frm-deactivate`, which removes the `sbin` directory added by frm
+    /// (tracked in `FRM_ACTIVE_SBIN`) from `PATH`
+    pub fn deactivate_function(&self) -> &'static str {
+        match self {
+            Shell::Bash => DEACTIVATE_BASH_TEMPLATE,
+            Shell::Zsh => DEACTIVATE_ZSH_TEMPLATE,
+            Shell::Nu => DEACTIVATE_NU_TEMPLATE,
+        }
+    }
+
+    pub fn deactivate_script(&self) -> String {
+        format!("{}frm-deactivate\n", self.deactivate_function())
     }
 
     pub fn init_script(&self, paths: &Paths) -> String {
@@ -81,8 +174,63 @@ impl Shell {
             Shell::Nu => INIT_NU_TEMPLATE,
         };
 
-        template.replace("{{base_dir}}", &base_dir)
+        let script = template.replace("{{base_dir}}", &base_dir);
+        format!("{}\n{}", script, self.deactivate_function())
+    }
+}
+
+/// Environment variables set by `use`, pointing into the version directory
+pub fn activation_env_vars(paths: &Paths, version: &Version) -> Vec<(&'static str, String)> {
+    let etc_dir = paths.version_etc_dir(version);
+    let mut vars = vec![
+        (
+            FRM_ACTIVE_SBIN,
+            paths.version_sbin_dir(version).display().to_string(),
+        ),
+        (
+            RABBITMQ_HOME,
+            paths.version_dir(version).display().to_string(),
+        ),
+        (
+            RABBITMQ_CONFIG_FILE,
+            etc_dir.join("rabbitmq.conf").display().to_string(),
+        ),
+        (
+            RABBITMQ_ENABLED_PLUGINS_FILE,
+            etc_dir.join("enabled_plugins").display().to_string(),
+        ),
+        (
+            RABBITMQ_LOG_BASE,
+            paths.version_var_log_dir(version).display().to_string(),
+        ),
+    ];
+    // a shared version is read-only, so its defaults under RABBITMQ_HOME cannot be used
+    if paths.version_shared(version) {
+        vars.extend([
+            (
+                RABBITMQ_CONFIG_FILES,
+                paths.version_confd_dir(version).display().to_string(),
+            ),
+            (
+                RABBITMQ_ADVANCED_CONFIG_FILE,
+                etc_dir.join("advanced.config").display().to_string(),
+            ),
+        ]);
+    }
+    // set by 'frm data-dir', the version's own data directory is not under RABBITMQ_HOME
+    let data_dir = VersionMetadata::load(paths, version)
+        .ok()
+        .and_then(|metadata| metadata.data_dir);
+    if paths.version_shared(version) || data_dir.is_some() {
+        vars.push((
+            RABBITMQ_MNESIA_BASE,
+            data_dir
+                .unwrap_or_else(|| paths.version_data_dir(version))
+                .display()
+                .to_string(),
+        ));
     }
+    vars
 }
 
 impl fmt::Display for Shell {
@@ -0,0 +1,613 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Just enough of the RabbitMQ Stream protocol to check that a node stores and delivers
+//! stream messages.
+//!
+//! [`smoke_test`] authenticates with `PLAIN`, creates a stream, subscribes to it, publishes
+//! a few messages, and waits for both their confirms and their deliveries before deleting
+//! the stream. A frame is a 4-byte size followed by a command key, a command version, and,
+//! for requests and responses, a correlation ID. Responses set the high bit of the key.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::process;
+use std::result::Result as StdResult;
+use std::str;
+use std::time::{Duration, Instant};
+
+use tracing::debug;
+
+use crate::Result;
+use crate::errors::Error;
+
+pub const DEFAULT_PORT: u16 = 5552;
+
+const COMMAND_VERSION: u16 = 1;
+const RESPONSE_FLAG: u16 = 0x8000;
+const RESPONSE_CODE_OK: u16 = 1;
+
+const DECLARE_PUBLISHER: u16 = 0x0001;
+const PUBLISH: u16 = 0x0002;
+const PUBLISH_CONFIRM: u16 = 0x0003;
+const PUBLISH_ERROR: u16 = 0x0004;
+const SUBSCRIBE: u16 = 0x0007;
+const DELIVER: u16 = 0x0008;
+const CREDIT: u16 = 0x0009;
+const CREATE: u16 = 0x000d;
+const DELETE: u16 = 0x000e;
+const PEER_PROPERTIES: u16 = 0x0011;
+const SASL_HANDSHAKE: u16 = 0x0012;
+const SASL_AUTHENTICATE: u16 = 0x0013;
+const TUNE: u16 = 0x0014;
+const OPEN: u16 = 0x0015;
+const CLOSE: u16 = 0x0016;
+const HEARTBEAT: u16 = 0x0017;
+
+const OFFSET_FIRST: u16 = 1;
+const CHUNK_TYPE_USER: u8 = 0;
+const PUBLISHER_ID: u8 = 1;
+const SUBSCRIPTION_ID: u8 = 1;
+const INITIAL_CREDIT: u16 = 10;
+const MAX_FRAME_SIZE: u32 = 1024 * 1024;
+
+pub struct SmokeTestOptions<'a> {
+    pub host: &'a str,
+    pub port: u16,
+    pub username: &'a str,
+    pub password: &'a str,
+    pub virtual_host: &'a str,
+    pub count: usize,
+    pub timeout: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct SmokeTestReport {
+    pub address: SocketAddr,
+    pub product: Option<String>,
+    pub version: Option<String>,
+    pub stream: String,
+    /// From the TCP connection to the response to `open`
+    pub connected_in: Duration,
+    /// From publishing each message to its confirm, in publishing order
+    pub confirm_latencies: Vec<Duration>,
+    /// From publishing each message to its delivery, in publishing order
+    pub delivery_latencies: Vec<Duration>,
+}
+
+/// Minimum, median, and maximum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencySummary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+pub fn summarize(latencies: &[Duration]) -> Option<LatencySummary> {
+    let mut sorted = latencies.to_vec();
+    sorted.sort();
+    Some(LatencySummary {
+        min: *sorted.first()?,
+        median: sorted[sorted.len() / 2],
+        max: *sorted.last()?,
+    })
+}
+
+/// The message body: an AMQP 1.0 data section, so that other stream clients can decode it
+pub fn message_body(index: usize) -> Vec<u8> {
+    let payload = format!("frm smoke test message {}", index);
+    let mut body = vec![0x00, 0x53, 0x75, 0xA0, payload.len() as u8];
+    body.extend_from_slice(payload.as_bytes());
+    body
+}
+
+/// The index of a message published by [`smoke_test`], `None` for any other message
+pub fn message_index(body: &[u8]) -> Option<usize> {
+    let payload = str::from_utf8(body.get(5..)?).ok()?;
+    let index = payload
+        .strip_prefix("frm smoke test message ")?
+        .parse()
+        .ok()?;
+    (message_body(index) == body).then_some(index)
+}
+
+pub fn smoke_test(opts: &SmokeTestOptions) -> Result<SmokeTestReport> {
+    let target = format!("{}:{}", opts.host, opts.port);
+    let started = Instant::now();
+    let mut conn = Connection::open(opts.host, opts.port, opts.timeout).map_err(|reason| {
+        Error::SmokeTestFailed {
+            address: target.clone(),
+            reason,
+        }
+    })?;
+    let address = conn.address;
+
+    let result = run(&mut conn, opts, started);
+    result.map_err(|reason| Error::SmokeTestFailed {
+        address: address.to_string(),
+        reason,
+    })
+}
+
+type ProtocolResult<T> = StdResult<T, String>;
+
+fn run(
+    conn: &mut Connection,
+    opts: &SmokeTestOptions,
+    started: Instant,
+) -> ProtocolResult<SmokeTestReport> {
+    let (product, version) = conn.handshake(opts)?;
+    let connected_in = started.elapsed();
+
+    let stream = format!("frm-smoke-test-{}", process::id());
+    conn.request(CREATE, |w| {
+        w.str(&stream);
+        // no arguments
+        w.u32(0);
+    })?;
+    let exchanged = exchange_messages(conn, &stream, opts);
+    // the stream is deleted even when the exchange fails
+    let deleted = conn.request(DELETE, |w| w.str(&stream));
+    let (confirm_latencies, delivery_latencies) = exchanged?;
+    deleted?;
+    let _ = conn.request(CLOSE, |w| {
+        w.u16(RESPONSE_CODE_OK);
+        w.str("OK");
+    });
+
+    Ok(SmokeTestReport {
+        address: conn.address,
+        product,
+        version,
+        stream,
+        connected_in,
+        confirm_latencies,
+        delivery_latencies,
+    })
+}
+
+fn exchange_messages(
+    conn: &mut Connection,
+    stream: &str,
+    opts: &SmokeTestOptions,
+) -> ProtocolResult<(Vec<Duration>, Vec<Duration>)> {
+    conn.request(DECLARE_PUBLISHER, |w| {
+        w.u8(PUBLISHER_ID);
+        // no publisher reference, so no deduplication
+        w.str("");
+        w.str(stream);
+    })?;
+    conn.request(SUBSCRIBE, |w| {
+        w.u8(SUBSCRIPTION_ID);
+        w.str(stream);
+        w.u16(OFFSET_FIRST);
+        w.u16(INITIAL_CREDIT);
+        // no properties
+        w.u32(0);
+    })?;
+
+    let mut published_at = Vec::with_capacity(opts.count);
+    for index in 0..opts.count {
+        conn.send(PUBLISH, |w| {
+            w.u8(PUBLISHER_ID);
+            w.u32(1);
+            w.u64(index as u64);
+            w.bytes(&message_body(index));
+        })?;
+        published_at.push(Instant::now());
+    }
+
+    let mut confirmed: Vec<Option<Duration>> = vec![None; opts.count];
+    let mut delivered: Vec<Option<Duration>> = vec![None; opts.count];
+    let deadline = Instant::now() + opts.timeout;
+    while confirmed.iter().chain(&delivered).any(Option::is_none) {
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "timed out after {} seconds with {} of {} messages confirmed and {} delivered",
+                opts.timeout.as_secs(),
+                confirmed.iter().flatten().count(),
+                opts.count,
+                delivered.iter().flatten().count()
+            ));
+        }
+
+        let frame = conn.read_frame()?;
+        let mut r = Reader { buf: &frame.body };
+        match frame.key {
+            PUBLISH_CONFIRM => {
+                let now = Instant::now();
+                r.u8().ok_or_else(malformed)?;
+                for _ in 0..r.u32().ok_or_else(malformed)? {
+                    let id = r.u64().ok_or_else(malformed)? as usize;
+                    if let Some(at) = published_at.get(id) {
+                        confirmed[id] = Some(now - *at);
+                    }
+                }
+            }
+            PUBLISH_ERROR => {
+                r.u8().ok_or_else(malformed)?;
+                r.u32().ok_or_else(malformed)?;
+                let id = r.u64().ok_or_else(malformed)?;
+                let code = r.u16().ok_or_else(malformed)?;
+                return Err(format!(
+                    "message {} was not published: {}",
+                    id,
+                    response_code_name(code)
+                ));
+            }
+            DELIVER => {
+                let now = Instant::now();
+                r.u8().ok_or_else(malformed)?;
+                if frame.version >= 2 {
+                    // the committed chunk ID
+                    r.u64().ok_or_else(malformed)?;
+                }
+                for entry in chunk_entries(r.buf).ok_or_else(malformed)? {
+                    if let Some(index) = message_index(entry).filter(|i| *i < opts.count) {
+                        delivered[index] = Some(now - published_at[index]);
+                    }
+                }
+                conn.send(CREDIT, |w| {
+                    w.u8(SUBSCRIPTION_ID);
+                    w.u16(1);
+                })?;
+            }
+            key if key == CREDIT | RESPONSE_FLAG => {
+                let code = r.u16().ok_or_else(malformed)?;
+                return Err(format!("credit was refused: {}", response_code_name(code)));
+            }
+            _ => conn.handle_unsolicited(&frame)?,
+        }
+    }
+
+    Ok((
+        confirmed.into_iter().flatten().collect(),
+        delivered.into_iter().flatten().collect(),
+    ))
+}
+
+/// The messages in a chunk of user data: simple entries are returned, sub-batches
+/// (only published by clients that batch and compress) are skipped
+fn chunk_entries(chunk: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut r = Reader { buf: chunk };
+    // magic and version
+    r.u8()?;
+    let chunk_type = r.u8()?;
+    let num_entries = r.u16()?;
+    // number of records, timestamp, epoch, first offset, CRC
+    r.take(4 + 8 + 8 + 8 + 4)?;
+    let data_length = r.u32()? as usize;
+    let trailer_length = r.u32()? as usize;
+    // reserved
+    r.take(4)?;
+    if chunk_type != CHUNK_TYPE_USER {
+        return Some(Vec::new());
+    }
+
+    // a bloom filter, if any, sits between the header and the data
+    let data_start = r.buf.len().checked_sub(data_length + trailer_length)?;
+    r.take(data_start)?;
+    let mut data = Reader {
+        buf: r.take(data_length)?,
+    };
+    let mut entries = Vec::new();
+    for _ in 0..num_entries {
+        if *data.buf.first()? & 0x80 == 0 {
+            let size = data.u32()? as usize;
+            entries.push(data.take(size)?);
+        } else {
+            // entry type and compression, record count, uncompressed length
+            data.take(1 + 2 + 4)?;
+            let length = data.u32()? as usize;
+            data.take(length)?;
+        }
+    }
+    Some(entries)
+}
+
+fn malformed() -> String {
+    "malformed frame".to_string()
+}
+
+fn response_code_name(code: u16) -> String {
+    let name = match code {
+        2 => "stream does not exist",
+        5 => "stream already exists",
+        6 => "stream not available",
+        7 => "SASL mechanism not supported",
+        8 => "authentication failure",
+        11 => "authentication failure (guest can only connect via localhost)",
+        12 => "virtual host access failure",
+        16 => "access refused",
+        17 => "precondition failed",
+        _ => return format!("response code {}", code),
+    };
+    format!("{} (response code {})", name, code)
+}
+
+struct Frame {
+    key: u16,
+    version: u16,
+    body: Vec<u8>,
+}
+
+struct Connection {
+    socket: TcpStream,
+    address: SocketAddr,
+    correlation_id: u32,
+}
+
+impl Connection {
+    fn open(host: &str, port: u16, timeout: Duration) -> ProtocolResult<Connection> {
+        let addresses: Vec<SocketAddr> = (host, port)
+            .to_socket_addrs()
+            .map_err(|e| e.to_string())?
+            .collect();
+
+        // "localhost" can resolve to both ::1 and 127.0.0.1, and a node may only listen on one
+        let mut last_error = "host did not resolve to an address".to_string();
+        for address in addresses {
+            match TcpStream::connect_timeout(&address, timeout) {
+                Ok(socket) => {
+                    socket
+                        .set_read_timeout(Some(timeout))
+                        .and_then(|_| socket.set_write_timeout(Some(timeout)))
+                        .map_err(|e| e.to_string())?;
+                    return Ok(Connection {
+                        socket,
+                        address,
+                        correlation_id: 0,
+                    });
+                }
+                Err(e) => last_error = e.to_string(),
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Exchanges peer properties, authenticates, accepts the server's tuning, and opens
+    /// the virtual host. Returns the product and version the server reports.
+    fn handshake(
+        &mut self,
+        opts: &SmokeTestOptions,
+    ) -> ProtocolResult<(Option<String>, Option<String>)> {
+        let body = self.request(PEER_PROPERTIES, |w| {
+            w.u32(2);
+            w.str("product");
+            w.str("frm");
+            w.str("connection_name");
+            w.str("frm smoke stream");
+        })?;
+        let mut r = Reader { buf: &body };
+        let (mut product, mut version) = (None, None);
+        for _ in 0..r.u32().ok_or_else(malformed)? {
+            let key = r.str().ok_or_else(malformed)?;
+            let value = r.str().ok_or_else(malformed)?;
+            match key.as_str() {
+                "product" => product = Some(value),
+                "version" => version = Some(value),
+                _ => {}
+            }
+        }
+
+        let body = self.request(SASL_HANDSHAKE, |_| {})?;
+        let mut r = Reader { buf: &body };
+        let mechanisms: Vec<String> = (0..r.u32().ok_or_else(malformed)?)
+            .map(|_| r.str())
+            .collect::<Option<_>>()
+            .ok_or_else(malformed)?;
+        if !mechanisms.iter().any(|m| m == "PLAIN") {
+            return Err(format!(
+                "the server does not offer the PLAIN mechanism, only: {}",
+                mechanisms.join(", ")
+            ));
+        }
+        self.request(SASL_AUTHENTICATE, |w| {
+            w.str("PLAIN");
+            w.bytes(format!("\0{}\0{}", opts.username, opts.password).as_bytes());
+        })?;
+
+        // the server proposes a frame size and heartbeat before it accepts open
+        loop {
+            let frame = self.read_frame()?;
+            self.handle_unsolicited(&frame)?;
+            if frame.key == TUNE {
+                break;
+            }
+        }
+
+        self.request(OPEN, |w| w.str(opts.virtual_host))?;
+        Ok((product, version))
+    }
+
+    /// Sends a request and returns the body of its response after the response code
+    fn request(&mut self, key: u16, body: impl FnOnce(&mut Writer)) -> ProtocolResult<Vec<u8>> {
+        self.correlation_id += 1;
+        let correlation_id = self.correlation_id;
+        self.send(key, |w| {
+            w.u32(correlation_id);
+            body(w);
+        })?;
+
+        loop {
+            let frame = self.read_frame()?;
+            if frame.key != key | RESPONSE_FLAG {
+                self.handle_unsolicited(&frame)?;
+                continue;
+            }
+            let mut r = Reader { buf: &frame.body };
+            if r.u32().ok_or_else(malformed)? != correlation_id {
+                continue;
+            }
+            let code = r.u16().ok_or_else(malformed)?;
+            if code != RESPONSE_CODE_OK {
+                return Err(format!(
+                    "{} was refused: {}",
+                    command_name(key),
+                    response_code_name(code)
+                ));
+            }
+            return Ok(r.buf.to_vec());
+        }
+    }
+
+    fn send(&mut self, key: u16, body: impl FnOnce(&mut Writer)) -> ProtocolResult<()> {
+        let mut w = Writer(Vec::new());
+        w.u32(0);
+        w.u16(key);
+        w.u16(COMMAND_VERSION);
+        body(&mut w);
+        let size = (w.0.len() - 4) as u32;
+        w.0[..4].copy_from_slice(&size.to_be_bytes());
+        self.socket.write_all(&w.0).map_err(|e| e.to_string())
+    }
+
+    fn read_frame(&mut self) -> ProtocolResult<Frame> {
+        let mut size = [0u8; 4];
+        self.socket
+            .read_exact(&mut size)
+            .map_err(|e| format!("no response: {}", e))?;
+        let size = u32::from_be_bytes(size);
+        if !(4..=MAX_FRAME_SIZE).contains(&size) {
+            return Err("unexpected response, not a stream protocol listener?".to_string());
+        }
+        let mut frame = vec![0u8; size as usize];
+        self.socket
+            .read_exact(&mut frame)
+            .map_err(|e| e.to_string())?;
+        let body = frame.split_off(4);
+        Ok(Frame {
+            key: u16::from_be_bytes([frame[0], frame[1]]),
+            version: u16::from_be_bytes([frame[2], frame[3]]),
+            body,
+        })
+    }
+
+    /// Server-initiated frames that can arrive at any point
+    fn handle_unsolicited(&mut self, frame: &Frame) -> ProtocolResult<()> {
+        match frame.key {
+            TUNE => {
+                let mut r = Reader { buf: &frame.body };
+                let frame_max = r.u32().ok_or_else(malformed)?;
+                let heartbeat = r.u32().ok_or_else(malformed)?;
+                let frame_max = match frame_max {
+                    0 => MAX_FRAME_SIZE,
+                    n => n.min(MAX_FRAME_SIZE),
+                };
+                self.send(TUNE, |w| {
+                    w.u32(frame_max);
+                    w.u32(heartbeat);
+                })
+            }
+            CLOSE => {
+                let mut r = Reader { buf: &frame.body };
+                r.u32().ok_or_else(malformed)?;
+                let code = r.u16().ok_or_else(malformed)?;
+                let reason = r.str().unwrap_or_default();
+                Err(format!(
+                    "the server closed the connection: {} ({})",
+                    reason,
+                    response_code_name(code)
+                ))
+            }
+            HEARTBEAT => Ok(()),
+            key => {
+                debug!(key, "ignoring an unexpected stream protocol frame");
+                Ok(())
+            }
+        }
+    }
+}
+
+fn command_name(key: u16) -> &'static str {
+    match key {
+        DECLARE_PUBLISHER => "declare_publisher",
+        SUBSCRIBE => "subscribe",
+        CREATE => "create",
+        DELETE => "delete",
+        PEER_PROPERTIES => "peer_properties",
+        SASL_HANDSHAKE => "sasl_handshake",
+        SASL_AUTHENTICATE => "sasl_authenticate",
+        OPEN => "open",
+        CLOSE => "close",
+        _ => "request",
+    }
+}
+
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, n: u8) {
+        self.0.push(n);
+    }
+
+    fn u16(&mut self, n: u16) {
+        self.0.extend_from_slice(&n.to_be_bytes());
+    }
+
+    fn u32(&mut self, n: u32) {
+        self.0.extend_from_slice(&n.to_be_bytes());
+    }
+
+    fn u64(&mut self, n: u64) {
+        self.0.extend_from_slice(&n.to_be_bytes());
+    }
+
+    fn str(&mut self, s: &str) {
+        self.u16(s.len() as u16);
+        self.0.extend_from_slice(s.as_bytes());
+    }
+
+    fn bytes(&mut self, b: &[u8]) {
+        self.u32(b.len() as u32);
+        self.0.extend_from_slice(b);
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < n {
+            return None;
+        }
+        let (head, tail) = self.buf.split_at(n);
+        self.buf = tail;
+        Some(head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let b = self.take(2)?;
+        Some(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let b = self.take(4)?;
+        Some(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let b = self.take(8)?;
+        Some(u64::from_be_bytes(b.try_into().ok()?))
+    }
+
+    /// A string with a 2-byte length, where -1 is null
+    fn str(&mut self) -> Option<String> {
+        let len = self.u16()?;
+        if len == u16::MAX {
+            return Some(String::new());
+        }
+        Some(String::from_utf8_lossy(self.take(len as usize)?).into_owned())
+    }
+}
@@ -6,45 +6,22 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::fs::{self, File};
-use std::io::BufReader;
-use std::path::{Path, PathBuf};
+use std::fs;
+use std::path::Path;
 
-use flate2::read::GzDecoder;
-use tar::Archive;
-use xz2::read::XzDecoder;
+use tracing::instrument;
 
 use crate::Result;
+use crate::archive;
+use crate::download;
 use crate::errors::Error;
 use crate::paths::Paths;
 use crate::version::Version;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum CompressionFormat {
-    Xz,
-    Gzip,
-}
-
-impl CompressionFormat {
-    pub fn from_path(path: &Path) -> Option<Self> {
-        let name = path.file_name()?.to_str()?;
-        if name.ends_with(".tar.xz") {
-            Some(CompressionFormat::Xz)
-        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
-            Some(CompressionFormat::Gzip)
-        } else {
-            None
-        }
-    }
-}
-
 pub fn extract_version_from_tarball_name(path: &Path) -> Option<Version> {
     let name = path.file_name()?.to_str()?;
 
-    let name_without_ext = name
-        .strip_suffix(".tar.xz")
-        .or_else(|| name.strip_suffix(".tar.gz"))
-        .or_else(|| name.strip_suffix(".tgz"))?;
+    let (name_without_ext, _) = archive::split_extension(name)?;
 
     extract_version_from_stem(name_without_ext)
 }
@@ -64,78 +41,20 @@ fn extract_version_from_stem(stem: &str) -> Option<Version> {
     None
 }
 
+#[instrument(level = "debug", skip_all, fields(tarball = %tarball_path.display(), version = %version))]
 pub fn extract_tarball(tarball_path: &Path, version: &Version, paths: &Paths) -> Result<()> {
-    let format = CompressionFormat::from_path(tarball_path).ok_or_else(|| {
-        Error::ExtractionFailed(format!(
-            "unsupported archive format: {}",
-            tarball_path.display()
-        ))
-    })?;
-
-    let file = File::open(tarball_path)?;
-    let reader = BufReader::new(file);
-
-    let temp_dir = paths
-        .versions_dir()
-        .join(format!(".{}-extracting", version));
-    if temp_dir.exists() {
-        fs::remove_dir_all(&temp_dir)?;
-    }
-    fs::create_dir_all(&temp_dir)?;
-
-    match format {
-        CompressionFormat::Xz => {
-            let decoder = XzDecoder::new(reader);
-            let mut archive = Archive::new(decoder);
-            archive
-                .unpack(&temp_dir)
-                .map_err(|e| Error::ExtractionFailed(e.to_string()))?;
-        }
-        CompressionFormat::Gzip => {
-            let decoder = GzDecoder::new(reader);
-            let mut archive = Archive::new(decoder);
-            archive
-                .unpack(&temp_dir)
-                .map_err(|e| Error::ExtractionFailed(e.to_string()))?;
-        }
-    }
+    let temp_dir = download::prepare_extraction_dir(paths, version)?;
 
-    let extracted_dir = find_extracted_rabbitmq_dir(&temp_dir)?;
-    let final_path = paths.version_dir(version);
-
-    if final_path.exists() {
-        fs::remove_dir_all(&final_path)?;
-    }
-
-    fs::rename(&extracted_dir, &final_path).map_err(|e| {
-        Error::ExtractionFailed(format!("failed to move extracted directory: {}", e))
-    })?;
-
-    fs::remove_dir_all(&temp_dir)?;
-
-    Ok(())
-}
-
-fn find_extracted_rabbitmq_dir(temp_dir: &Path) -> Result<PathBuf> {
-    let mut fallback = None;
-
-    for entry in fs::read_dir(temp_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_dir() {
-            let name = entry.file_name();
-            let name_str = name.to_string_lossy();
-            if name_str.contains("rabbitmq") {
-                return Ok(path);
-            }
-            if fallback.is_none() {
-                fallback = Some(path);
-            }
-        }
+    if let Err(e) = archive::unpack_file(tarball_path, &temp_dir) {
+        fs::remove_dir_all(&temp_dir).ok();
+        return Err(Error::ExtractionFailed(format!(
+            "{}: {}",
+            tarball_path.display(),
+            e
+        )));
     }
 
-    fallback
-        .ok_or_else(|| Error::ExtractionFailed("no directory found in extracted archive".into()))
+    download::finish_extraction(&temp_dir, version, paths)
 }
 
 pub fn verify_extracted_version(paths: &Paths, expected: &Version) -> Result<()> {
@@ -6,38 +6,66 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+//! Installation timestamps.
+//!
+//! Timestamps are stored in per-version metadata files. [`Timestamps::save`] only
+//! writes the versions that were recorded or removed since [`Timestamps::load`],
+//! so concurrent installations of different versions do not overwrite each other.
+//!
+//! Earlier releases kept all timestamps in `version_timestamps.json`. That file is migrated
+//! to the per-version layout and removed on first load, unless `FRM_DIR` is read-only.
+
 use std::collections::HashMap;
 use std::fs;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use serde::{Deserialize, Serialize};
-
 use crate::Result;
+use crate::metadata::{self, VersionMetadata};
 use crate::paths::Paths;
 use crate::version::Version;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default)]
 pub struct Timestamps {
-    #[serde(flatten)]
     entries: HashMap<String, u64>,
+    changed: HashMap<String, Version>,
 }
 
 impl Timestamps {
     pub fn load(paths: &Paths) -> Result<Self> {
-        let path = paths.timestamps_file();
-        if !path.exists() {
-            return Ok(Self::default());
+        // a read-only FRM_DIR keeps the legacy file, its timestamps are only read
+        let read_only = paths.timestamps_file().exists() && !paths.writable();
+        if !read_only {
+            migrate_legacy_file(paths)?;
+        }
+
+        let mut entries: HashMap<String, u64> = VersionMetadata::load_all(paths)?
+            .into_iter()
+            .filter_map(|(version, metadata)| {
+                metadata.installed_at.map(|ts| (version.to_string(), ts))
+            })
+            .collect();
+        if read_only {
+            for (version, timestamp) in load_legacy_file(paths)? {
+                entries.entry(version.to_string()).or_insert(timestamp);
+            }
         }
 
-        let content = fs::read_to_string(&path)?;
-        let timestamps: Timestamps = serde_json::from_str(&content)?;
-        Ok(timestamps)
+        Ok(Self {
+            entries,
+            changed: HashMap::new(),
+        })
     }
 
     pub fn save(&self, paths: &Paths) -> Result<()> {
-        let path = paths.timestamps_file();
-        let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content)?;
+        for (key, version) in &self.changed {
+            let installed_at = self.entries.get(key).copied();
+            // neither a signature check nor a provenance record applies to a different installation
+            VersionMetadata::update(paths, version, |m| {
+                m.installed_at = installed_at;
+                m.signature = None;
+                m.provenance = None;
+            })?;
+        }
         Ok(())
     }
 
@@ -47,13 +75,51 @@ impl Timestamps {
             .map(|d| d.as_secs())
             .unwrap_or(0);
         self.entries.insert(version.to_string(), timestamp);
+        self.changed.insert(version.to_string(), version.clone());
     }
 
     pub fn remove(&mut self, version: &Version) {
         self.entries.remove(&version.to_string());
+        self.changed.insert(version.to_string(), version.clone());
     }
 
     pub fn get(&self, version: &Version) -> Option<u64> {
         self.entries.get(&version.to_string()).copied()
     }
 }
+
+/// Moves timestamps from `version_timestamps.json` into per-version metadata.
+/// Timestamps already present in metadata files take precedence.
+pub fn migrate_legacy_file(paths: &Paths) -> Result<()> {
+    let legacy_file = paths.timestamps_file();
+    if !legacy_file.exists() {
+        return Ok(());
+    }
+
+    let _lock = metadata::lock(paths)?;
+    // Another process may have completed the migration while we were waiting for the lock
+    if !legacy_file.exists() {
+        return Ok(());
+    }
+
+    for (version, timestamp) in load_legacy_file(paths)? {
+        let mut metadata = VersionMetadata::load(paths, &version)?;
+        if metadata.installed_at.is_none() {
+            metadata.installed_at = Some(timestamp);
+            metadata.save(paths, &version)?;
+        }
+    }
+
+    fs::remove_file(legacy_file)?;
+    Ok(())
+}
+
+/// The timestamps in `version_timestamps.json`, skipping keys that are not versions
+fn load_legacy_file(paths: &Paths) -> Result<Vec<(Version, u64)>> {
+    let content = fs::read_to_string(paths.timestamps_file())?;
+    let legacy: HashMap<String, u64> = serde_json::from_str(&content)?;
+    Ok(legacy
+        .into_iter()
+        .filter_map(|(key, timestamp)| Some((key.parse::<Version>().ok()?, timestamp)))
+        .collect())
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Companion tools that are not part of the server distribution,
+//! downloaded from their GitHub releases into `tools/<name>/<version>`.
+
+use std::cmp::Reverse;
+use std::env::consts::{ARCH, OS};
+use std::fmt;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::Result;
+use crate::common::http::USER_AGENT;
+use crate::common::urls::{PERF_TEST_API_URL, RABBITMQADMIN_API_URL};
+use crate::download::Downloader;
+use crate::errors::Error;
+use crate::paths::Paths;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Tool {
+    /// rabbitmqadmin v2, a native binary
+    #[value(name = "rabbitmqadmin")]
+    RabbitMQAdmin,
+    /// RabbitMQ PerfTest, a Java application
+    #[value(name = "perf-test")]
+    PerfTest,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolRelease {
+    tag_name: String,
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+impl Tool {
+    pub const ALL: &[Tool] = &[Tool::RabbitMQAdmin, Tool::PerfTest];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tool::RabbitMQAdmin => "rabbitmqadmin",
+            Tool::PerfTest => "perf-test",
+        }
+    }
+
+    fn releases_api_url(&self) -> &'static str {
+        match self {
+            Tool::RabbitMQAdmin => RABBITMQADMIN_API_URL,
+            Tool::PerfTest => PERF_TEST_API_URL,
+        }
+    }
+
+    /// The name of the file the tool is stored as in its version directory
+    pub fn file_name(&self, tool_version: &str) -> String {
+        match self {
+            Tool::RabbitMQAdmin if cfg!(windows) => "rabbitmqadmin.exe".to_string(),
+            Tool::RabbitMQAdmin => "rabbitmqadmin".to_string(),
+            Tool::PerfTest => format!("perf-test-{}.jar", tool_version),
+        }
+    }
+
+    /// The name of the release asset to download for this platform
+    pub fn asset_name(&self, tool_version: &str) -> Option<String> {
+        match self {
+            Tool::RabbitMQAdmin => {
+                let target = platform_target()?;
+                let ext = if cfg!(windows) { ".exe" } else { "" };
+                Some(format!("rabbitmqadmin-{}-{}{}", tool_version, target, ext))
+            }
+            Tool::PerfTest => Some(format!("perf-test-{}.jar", tool_version)),
+        }
+    }
+}
+
+impl fmt::Display for Tool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The Rust target triple rabbitmqadmin v2 binaries are built for
+fn platform_target() -> Option<String> {
+    let os = match OS {
+        "linux" => "unknown-linux-gnu",
+        "macos" => "apple-darwin",
+        "windows" => "pc-windows-msvc",
+        _ => return None,
+    };
+    let arch = match ARCH {
+        arch @ ("x86_64" | "aarch64") => arch,
+        _ => return None,
+    };
+    Some(format!("{}-{}", arch, os))
+}
+
+pub fn tool_path(paths: &Paths, tool: Tool, tool_version: &str) -> PathBuf {
+    paths
+        .tool_version_dir(tool.name(), tool_version)
+        .join(tool.file_name(tool_version))
+}
+
+/// Installed versions of a tool, newest first
+pub fn installed_versions(paths: &Paths, tool: Tool) -> Result<Vec<String>> {
+    let tool_dir = paths.tool_dir(tool.name());
+    if !tool_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut versions = Vec::new();
+    for entry in fs::read_dir(tool_dir)? {
+        let entry = entry?;
+        if let Some(version) = entry.file_name().to_str()
+            && tool_path(paths, tool, version).is_file()
+        {
+            versions.push(version.to_string());
+        }
+    }
+
+    versions.sort_by_key(|v| Reverse(version_key(v)));
+    Ok(versions)
+}
+
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split(['.', '-'])
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// The newest installed version of a tool, if any
+pub fn find_installed(paths: &Paths, tool: Tool) -> Result<Option<PathBuf>> {
+    Ok(installed_versions(paths, tool)?
+        .first()
+        .map(|v| tool_path(paths, tool, v)))
+}
+
+/// Downloads a tool release (the latest one unless a version is given).
+/// Returns the installed version and the path to the tool.
+pub async fn install(
+    paths: &Paths,
+    tool: Tool,
+    tool_version: Option<&str>,
+) -> Result<(String, PathBuf)> {
+    let client = reqwest::Client::new();
+    let url = match tool_version {
+        Some(v) => format!(
+            "{}/tags/v{}",
+            tool.releases_api_url(),
+            v.trim_start_matches('v')
+        ),
+        None => format!("{}/latest", tool.releases_api_url()),
+    };
+    let release: ToolRelease = client
+        .get(url)
+        .header("User-Agent", USER_AGENT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let installed_version = release
+        .tag_name
+        .strip_prefix('v')
+        .unwrap_or(&release.tag_name)
+        .to_string();
+    let path = tool_path(paths, tool, &installed_version);
+    if path.is_file() {
+        return Ok((installed_version, path));
+    }
+
+    let asset = tool
+        .asset_name(&installed_version)
+        .and_then(|name| release.assets.iter().find(|a| a.name == name))
+        .ok_or_else(|| Error::ToolArtifactNotFound {
+            tool: tool.name().to_string(),
+            release: release.tag_name.clone(),
+        })?;
+
+    fs::create_dir_all(paths.tool_version_dir(tool.name(), &installed_version))?;
+    let tmp = path.with_extension("tmp");
+    if let Err(e) = Downloader::new()
+        .fetch_archive(&asset.browser_download_url, &tmp)
+        .await
+    {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+    make_executable(&tmp)?;
+    fs::rename(&tmp, &path)?;
+
+    Ok((installed_version, path))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+pub fn uninstall(paths: &Paths, tool: Tool, tool_version: &str) -> Result<()> {
+    let dir = paths.tool_version_dir(tool.name(), tool_version);
+    if !tool_path(paths, tool, tool_version).is_file() {
+        return Err(Error::ToolNotInstalled {
+            tool: format!("{} {}", tool.name(), tool_version),
+        });
+    }
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,144 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Uninstalled versions are moved to `trash/<timestamp>-<version>`
+//! so that they can be restored until the trash is emptied.
+//!
+//! The version's metadata, including its installation time, is kept next to it
+//! in `trash/<timestamp>-<version>.toml` and restored with it.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Result;
+use crate::errors::Error;
+use crate::metadata;
+use crate::paths::Paths;
+use crate::version::Version;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashEntry {
+    pub version: Version,
+    /// Seconds since the Unix epoch
+    pub trashed_at: u64,
+    pub path: PathBuf,
+}
+
+impl TrashEntry {
+    pub fn dir_name(&self) -> String {
+        entry_dir_name(self.trashed_at, &self.version)
+    }
+
+    /// Where the version's metadata is kept while it is in the trash
+    pub fn metadata_file(&self) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.toml", self.dir_name()))
+    }
+}
+
+fn entry_dir_name(trashed_at: u64, version: &Version) -> String {
+    format!("{}-{}", trashed_at, version.dir_name())
+}
+
+fn parse_entry_dir_name(name: &str) -> Option<(u64, Version)> {
+    let (timestamp, version) = name.split_once('-')?;
+    Some((timestamp.parse().ok()?, version.parse().ok()?))
+}
+
+/// Moves an installed version to the trash
+pub fn move_to_trash(paths: &Paths, version: &Version) -> Result<TrashEntry> {
+    let trashed_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    fs::create_dir_all(paths.trash_dir())?;
+    let path = paths.trash_dir().join(entry_dir_name(trashed_at, version));
+    if path.exists() {
+        fs::remove_dir_all(&path)?;
+    }
+    fs::rename(paths.version_dir(version), &path)?;
+
+    let entry = TrashEntry {
+        version: version.clone(),
+        trashed_at,
+        path,
+    };
+    let _lock = metadata::lock(paths)?;
+    let metadata_file = paths.version_metadata_file(version);
+    if metadata_file.exists() {
+        fs::rename(metadata_file, entry.metadata_file())?;
+    }
+    Ok(entry)
+}
+
+/// Returns trashed versions, oldest first
+pub fn list(paths: &Paths) -> Result<Vec<TrashEntry>> {
+    let trash_dir = paths.trash_dir();
+    if !trash_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(trash_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir()
+            && let Some(name) = entry.file_name().to_str()
+            && let Some((trashed_at, version)) = parse_entry_dir_name(name)
+        {
+            entries.push(TrashEntry {
+                version,
+                trashed_at,
+                path: entry.path(),
+            });
+        }
+    }
+
+    entries.sort_by(|a, b| {
+        a.trashed_at
+            .cmp(&b.trashed_at)
+            .then_with(|| a.version.cmp(&b.version))
+    });
+    Ok(entries)
+}
+
+/// Returns the most recently trashed copy of the given version
+pub fn find(paths: &Paths, version: &Version) -> Result<Option<TrashEntry>> {
+    Ok(list(paths)?
+        .into_iter()
+        .rev()
+        .find(|e| &e.version == version))
+}
+
+/// Moves a trashed version back into the versions directory
+pub fn restore(paths: &Paths, entry: &TrashEntry) -> Result<()> {
+    if paths.version_installed(&entry.version) {
+        return Err(Error::VersionAlreadyInstalled(entry.version.clone()));
+    }
+
+    fs::create_dir_all(paths.versions_dir())?;
+    fs::rename(&entry.path, paths.version_dir(&entry.version))?;
+
+    let metadata_file = entry.metadata_file();
+    if metadata_file.exists() {
+        let _lock = metadata::lock(paths)?;
+        fs::rename(metadata_file, paths.version_metadata_file(&entry.version))?;
+    }
+    Ok(())
+}
+
+/// Permanently deletes a trashed version
+pub fn delete(entry: &TrashEntry) -> Result<()> {
+    fs::remove_dir_all(&entry.path)?;
+    let metadata_file = entry.metadata_file();
+    if metadata_file.exists() {
+        fs::remove_file(metadata_file)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,242 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Workspaces: named sets of versions, optionally with a named environment of each.
+//!
+//! A workspace lives under `workspaces/<name>`: `workspace.toml` has its definition,
+//! and `bin/` has the shims generated by 'frm workspace enter', e.g. `rabbitmqctl-4.1`
+//! for the highest installed 4.1.x. Versions are given as series (`4.1.x`) or exact
+//! versions, and series are resolved each time the workspace is entered.
+
+use std::fmt;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::common::cli_tools::RABBITMQ_CLI_TOOLS;
+use crate::common::env_vars::RABBITMQ_HOME;
+use crate::cookie;
+use crate::envs;
+use crate::errors::Error;
+use crate::paths::Paths;
+use crate::version::{Series, Version};
+
+const DEFINITION_FILE: &str = "workspace.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workspace {
+    /// As given to 'frm workspace create', e.g. `4.1.x` or `4.2.3`
+    pub versions: Vec<String>,
+    /// A named environment every version has, see [`crate::envs`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+}
+
+/// A version of a workspace: a release series or an exact version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSpec {
+    Series(Series),
+    Exact(Version),
+}
+
+impl VersionSpec {
+    /// The highest installed GA version of a series, or the exact version if it is installed
+    pub fn resolve(&self, paths: &Paths) -> Result<Version> {
+        match self {
+            VersionSpec::Exact(version) if paths.version_installed(version) => Ok(version.clone()),
+            VersionSpec::Exact(version) => Err(Error::VersionNotInstalled(version.clone())),
+            VersionSpec::Series(series) => paths
+                .installed_versions()?
+                .into_iter()
+                .filter(|v| v.is_ga() && Series::from(v) == *series)
+                .max()
+                .ok_or_else(|| Error::NoMatchingVersion(self.to_string())),
+        }
+    }
+}
+
+impl FromStr for VersionSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let series = s.strip_suffix(".x").unwrap_or(s);
+        if let Some((major, minor)) = series.split_once('.')
+            && let (Ok(major), Ok(minor)) = (major.parse(), minor.parse())
+        {
+            return Ok(VersionSpec::Series(Series::new(major, minor)));
+        }
+        Ok(VersionSpec::Exact(s.parse()?))
+    }
+}
+
+impl fmt::Display for VersionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionSpec::Series(series) => write!(f, "{}.x", series),
+            VersionSpec::Exact(version) => write!(f, "{}", version),
+        }
+    }
+}
+
+impl Workspace {
+    pub fn specs(&self) -> Result<Vec<VersionSpec>> {
+        self.versions.iter().map(|v| v.parse()).collect()
+    }
+
+    /// The installed versions the workspace uses, lowest first, without duplicates.
+    /// Every version has to have the workspace's environment.
+    pub fn resolve(&self, paths: &Paths) -> Result<Vec<Version>> {
+        let mut versions = self
+            .specs()?
+            .iter()
+            .map(|spec| spec.resolve(paths))
+            .collect::<Result<Vec<_>>>()?;
+        versions.sort();
+        versions.dedup();
+        if let Some(name) = &self.env {
+            for version in &versions {
+                envs::ensure_exists(paths, version, name)?;
+            }
+        }
+        Ok(versions)
+    }
+}
+
+pub fn validate_name(name: &str) -> Result<()> {
+    envs::validate_name(name).map_err(|_| Error::InvalidWorkspaceName(name.to_string()))
+}
+
+pub fn exists(paths: &Paths, name: &str) -> bool {
+    paths.workspace_dir(name).join(DEFINITION_FILE).is_file()
+}
+
+pub fn load(paths: &Paths, name: &str) -> Result<Workspace> {
+    validate_name(name)?;
+    if !exists(paths, name) {
+        return Err(Error::WorkspaceNotFound(name.to_string()));
+    }
+    let content = fs::read_to_string(paths.workspace_dir(name).join(DEFINITION_FILE))?;
+    Ok(toml::from_str(&content)?)
+}
+
+pub fn save(paths: &Paths, name: &str, workspace: &Workspace) -> Result<()> {
+    let dir = paths.workspace_dir(name);
+    fs::create_dir_all(&dir)?;
+    fs::write(
+        dir.join(DEFINITION_FILE),
+        toml::to_string_pretty(workspace)?,
+    )?;
+    Ok(())
+}
+
+pub fn list(paths: &Paths) -> Result<Vec<String>> {
+    let dir = paths.workspaces_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.file_name().to_str().map(String::from))
+        .filter(|name| exists(paths, name))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+pub fn delete(paths: &Paths, name: &str) -> Result<()> {
+    fs::remove_dir_all(paths.workspace_dir(name))?;
+    Ok(())
+}
+
+pub fn bin_dir(paths: &Paths, name: &str) -> PathBuf {
+    paths.workspace_dir(name).join("bin")
+}
+
+/// Replaces the shims in the workspace's `bin/`: `<tool>-<version>` and `<tool>-<series>`
+/// for every version, and `<tool>` for the highest one. Each shim runs the tool of its
+/// version with the workspace's environment.
+pub fn write_shims(
+    paths: &Paths,
+    name: &str,
+    versions: &[Version],
+    env: Option<&str>,
+) -> Result<PathBuf> {
+    let dir = bin_dir(paths, name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+
+    // versions are sorted, so the highest version of a series comes last and wins
+    for (i, version) in versions.iter().enumerate() {
+        let series = Series::from(version);
+        let is_highest = i == versions.len() - 1;
+        for tool in RABBITMQ_CLI_TOOLS {
+            let script = shim(paths, version, env, tool)?;
+            let mut names = vec![
+                format!("{}-{}", tool, version),
+                format!("{}-{}", tool, series),
+            ];
+            if is_highest {
+                names.push(tool.to_string());
+            }
+            for shim_name in names {
+                write_executable(&dir.join(shim_name), &script)?;
+            }
+        }
+    }
+    Ok(dir)
+}
+
+fn shim(paths: &Paths, version: &Version, env: Option<&str>, tool: &str) -> Result<String> {
+    let mut vars = vec![(
+        RABBITMQ_HOME,
+        paths.version_dir(version).display().to_string(),
+    )];
+    vars.extend(
+        envs::node_env_vars(paths, version, env)?
+            .into_iter()
+            .map(|(name, path)| (name, path.display().to_string())),
+    );
+    vars.extend(envs::node_name_env_vars(paths, version, env)?);
+    vars.extend(cookie::env_vars(paths, version)?);
+
+    let mut script = format!("#!/bin/sh\n# RabbitMQ {}, generated by frm\n", version);
+    for (name, value) in vars {
+        script.push_str(&format!("export {}={}\n", name, quote(&value)));
+    }
+    script.push_str(&format!(
+        "exec {} \"$@\"\n",
+        quote(
+            &paths
+                .version_sbin_dir(version)
+                .join(tool)
+                .display()
+                .to_string()
+        )
+    ));
+    Ok(script)
+}
+
+/// Single-quotes a value for `sh`
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn write_executable(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content)?;
+    #[cfg(unix)]
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::aliases;
+use frm::cli::build_cli;
+use frm::commands::complete::candidates;
+use frm::paths::Paths;
+
+const CONFIG: &str = r#"
+[aliases]
+rls = "releases list"
+up = "bg start -V latest"
+status = "releases list"
+"#;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn setup() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("config.toml"), CONFIG).unwrap();
+    let sbin = temp.path().join("versions").join("4.2.3").join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    temp
+}
+
+fn expand(args: &[&str]) -> Vec<String> {
+    let aliases = BTreeMap::from([
+        ("up".to_string(), "bg start  -V latest".to_string()),
+        ("status".to_string(), "releases list".to_string()),
+    ]);
+    let args = args.iter().map(OsString::from).collect();
+    aliases::expand(args, &aliases, &build_cli())
+        .into_iter()
+        .map(|arg| arg.into_string().unwrap())
+        .collect()
+}
+
+#[test]
+fn expand_replaces_the_command_name() {
+    assert_eq!(
+        expand(&["frm", "up", "--env", "tls"]),
+        ["frm", "bg", "start", "-V", "latest", "--env", "tls"]
+    );
+    assert_eq!(
+        expand(&["frm", "--terse", "up"]),
+        ["frm", "--terse", "bg", "start", "-V", "latest"]
+    );
+}
+
+#[test]
+fn expand_leaves_other_commands_alone() {
+    // built-in commands take precedence
+    assert_eq!(expand(&["frm", "status"]), ["frm", "status"]);
+    assert_eq!(expand(&["frm", "unknown"]), ["frm", "unknown"]);
+    // only the command name is expanded
+    assert_eq!(expand(&["frm", "cli", "up"]), ["frm", "cli", "up"]);
+    assert_eq!(expand(&["frm", "--debug"]), ["frm", "--debug"]);
+}
+
+#[test]
+fn cli_runs_an_alias() {
+    let temp = setup();
+    frm_cmd(&temp)
+        .args(["rls"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3"));
+    frm_cmd(&temp)
+        .args(["--terse", "rls", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3"));
+}
+
+#[test]
+fn cli_alias_command_list() {
+    let temp = setup();
+    frm_cmd(&temp)
+        .args(["alias-command", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rls    = releases list\n"))
+        .stdout(predicate::str::contains("up     = bg start -V latest\n"))
+        .stdout(predicate::str::contains(
+            "alias 'status' is never used: 'frm status' is a built-in command",
+        ));
+
+    let empty = TempDir::new().unwrap();
+    frm_cmd(&empty)
+        .args(["alias-command", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No aliases"));
+}
+
+#[test]
+fn complete_aliases_and_their_options() {
+    let temp = setup();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let values = |words: &[&str]| -> Vec<String> {
+        let words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+        candidates(&paths, &words)
+            .into_iter()
+            .map(|c| c.value)
+            .collect()
+    };
+
+    assert_eq!(values(&["rl"]), ["rls"]);
+    assert_eq!(values(&["up", "--en"]), ["--env"]);
+}
@@ -0,0 +1,262 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use tempfile::TempDir;
+use xz2::write::XzEncoder;
+
+use frm::archive::{self, ArchiveFormat, split_extension};
+
+fn tar(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, content.as_bytes())
+            .unwrap();
+    }
+    builder.into_inner().unwrap()
+}
+
+fn compress(format: ArchiveFormat, data: &[u8]) -> Vec<u8> {
+    match format {
+        ArchiveFormat::Xz => {
+            let mut encoder = XzEncoder::new(Vec::new(), 6);
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+        ArchiveFormat::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+        ArchiveFormat::Zstd => zstd::encode_all(data, 3).unwrap(),
+    }
+}
+
+fn write_archive(dir: &Path, name: &str, format: ArchiveFormat) -> PathBuf {
+    let path = dir.join(name);
+    let data = tar(&[("rabbitmq_server-4.2.3/sbin/rabbitmq-server", "#!/bin/sh\n")]);
+    fs::write(&path, compress(format, &data)).unwrap();
+    path
+}
+
+#[test]
+fn format_from_magic() {
+    for format in ArchiveFormat::ALL {
+        let data = compress(format, b"data");
+        assert_eq!(ArchiveFormat::from_magic(&data), Some(format));
+    }
+    assert_eq!(ArchiveFormat::from_magic(b"PK\x03\x04"), None);
+    assert_eq!(ArchiveFormat::from_magic(b""), None);
+    assert_eq!(ArchiveFormat::from_magic(&tar(&[("a", "b")])), None);
+}
+
+#[test]
+fn format_from_path() {
+    let cases = [
+        (
+            "rabbitmq-server-generic-unix-4.2.3.tar.xz",
+            Some(ArchiveFormat::Xz),
+        ),
+        ("rabbitmq.txz", Some(ArchiveFormat::Xz)),
+        ("tanzu-rabbitmq-4.2.3.tar.gz", Some(ArchiveFormat::Gzip)),
+        ("tanzu-rabbitmq-4.2.3.tgz", Some(ArchiveFormat::Gzip)),
+        ("tanzu-rabbitmq-4.2.3.tar.zst", Some(ArchiveFormat::Zstd)),
+        ("tanzu-rabbitmq-4.2.3.tzst", Some(ArchiveFormat::Zstd)),
+        ("tanzu-rabbitmq-4.2.3.tar", None),
+        ("tanzu-rabbitmq-4.2.3.zip", None),
+    ];
+    for (name, expected) in cases {
+        assert_eq!(
+            ArchiveFormat::from_path(&PathBuf::from(name)),
+            expected,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn split_extension_returns_stem() {
+    assert_eq!(
+        split_extension("tanzu-rabbitmq-4.2.3.tar.zst"),
+        Some(("tanzu-rabbitmq-4.2.3", ArchiveFormat::Zstd))
+    );
+    assert_eq!(
+        split_extension("rabbitmq-server-4.2.3.tgz"),
+        Some(("rabbitmq-server-4.2.3", ArchiveFormat::Gzip))
+    );
+    assert_eq!(split_extension("rabbitmq-server-4.2.3"), None);
+}
+
+#[test]
+fn unpack_file_supports_all_formats() {
+    for format in ArchiveFormat::ALL {
+        let temp = TempDir::new().unwrap();
+        let path = write_archive(
+            temp.path(),
+            format.extensions()[0].trim_start_matches('.'),
+            format,
+        );
+        let dest = temp.path().join("out");
+        fs::create_dir_all(&dest).unwrap();
+
+        assert_eq!(archive::unpack_file(&path, &dest).unwrap(), format);
+        assert!(
+            dest.join("rabbitmq_server-4.2.3")
+                .join("sbin")
+                .join("rabbitmq-server")
+                .exists()
+        );
+    }
+}
+
+#[test]
+fn unpack_file_detects_misnamed_archives() {
+    let temp = TempDir::new().unwrap();
+    let path = write_archive(temp.path(), "rabbitmq.tar.xz", ArchiveFormat::Zstd);
+    let dest = temp.path().join("out");
+    fs::create_dir_all(&dest).unwrap();
+
+    assert_eq!(
+        archive::unpack_file(&path, &dest).unwrap(),
+        ArchiveFormat::Zstd
+    );
+}
+
+#[test]
+fn unpack_file_rejects_unsupported_formats() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbitmq.tar.gz");
+    fs::write(&path, tar(&[("a", "b")])).unwrap();
+
+    let err = archive::unpack_file(&path, temp.path()).unwrap_err();
+    assert!(err.to_string().contains("unsupported archive format"));
+
+    fs::write(&path, "").unwrap();
+    assert!(archive::unpack_file(&path, temp.path()).is_err());
+}
+
+fn unpack_tar(dir: &Path, files: &[(&str, &str)]) -> PathBuf {
+    let extracted = dir.join("extracted");
+    fs::create_dir_all(&extracted).unwrap();
+    archive::unpack(
+        compress(ArchiveFormat::Xz, &tar(files)).as_slice(),
+        &extracted,
+    )
+    .unwrap();
+    extracted
+}
+
+#[test]
+fn distribution_dir_uses_the_expected_top_level_directory() {
+    let temp = TempDir::new().unwrap();
+    let extracted = unpack_tar(
+        temp.path(),
+        &[("rabbitmq_server-4.2.3/sbin/rabbitmq-server", "#!/bin/sh\n")],
+    );
+
+    assert_eq!(
+        archive::distribution_dir(&extracted, "rabbitmq_server-4.2.3").unwrap(),
+        extracted.join("rabbitmq_server-4.2.3")
+    );
+}
+
+#[test]
+fn distribution_dir_finds_a_differently_named_top_level_directory() {
+    let temp = TempDir::new().unwrap();
+    let extracted = unpack_tar(
+        temp.path(),
+        &[
+            ("rabbitmq-4.3.0-alpha/sbin/rabbitmq-server", "#!/bin/sh\n"),
+            ("__MACOSX/._rabbitmq-4.3.0-alpha", ""),
+            ("README.md", "hello"),
+        ],
+    );
+
+    assert_eq!(
+        archive::distribution_dir(&extracted, "rabbitmq_server-4.3.0-alpha.1").unwrap(),
+        extracted.join("rabbitmq-4.3.0-alpha")
+    );
+}
+
+#[test]
+fn distribution_dir_accepts_an_archive_without_a_top_level_directory() {
+    let temp = TempDir::new().unwrap();
+    let extracted = unpack_tar(
+        temp.path(),
+        &[
+            ("sbin/rabbitmq-server", "#!/bin/sh\n"),
+            ("plugins/rabbit-4.3.0.ez", ""),
+        ],
+    );
+
+    assert_eq!(
+        archive::distribution_dir(&extracted, "rabbitmq_server-4.3.0").unwrap(),
+        extracted
+    );
+}
+
+#[test]
+fn distribution_dir_lists_the_contents_of_an_unexpected_layout() {
+    let temp = TempDir::new().unwrap();
+    let extracted = unpack_tar(
+        temp.path(),
+        &[
+            ("server/bin/rabbitmq-server", "#!/bin/sh\n"),
+            ("server/plugins/rabbit-4.3.0.ez", ""),
+            ("NOTICE", ""),
+        ],
+    );
+
+    let message = archive::distribution_dir(&extracted, "rabbitmq_server-4.3.0")
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("expected a top-level rabbitmq_server-4.3.0 directory"));
+    for path in ["NOTICE", "server/", "server/bin/", "server/plugins/"] {
+        assert!(message.contains(&format!("\n  {}", path)), "{}", message);
+    }
+    // only two levels deep
+    assert!(!message.contains("rabbit-4.3.0.ez"));
+}
+
+#[test]
+fn distribution_dir_rejects_several_candidate_directories() {
+    let temp = TempDir::new().unwrap();
+    let extracted = unpack_tar(
+        temp.path(),
+        &[
+            ("a/sbin/rabbitmq-server", "#!/bin/sh\n"),
+            ("b/sbin/rabbitmq-server", "#!/bin/sh\n"),
+        ],
+    );
+
+    assert!(archive::distribution_dir(&extracted, "rabbitmq_server-4.3.0").is_err());
+}
+
+#[test]
+fn list_contents_truncates_long_listings() {
+    let temp = TempDir::new().unwrap();
+    let names: Vec<String> = (0..25).map(|i| format!("file-{:02}", i)).collect();
+    let files: Vec<(&str, &str)> = names.iter().map(|n| (n.as_str(), "")).collect();
+    let extracted = unpack_tar(temp.path(), &files);
+
+    let contents = archive::list_contents(&extracted).unwrap();
+    assert_eq!(contents.len(), 21);
+    assert_eq!(contents[0], "file-00");
+    assert_eq!(contents[20], "... and 5 more");
+}
@@ -0,0 +1,195 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// A local denylist entry makes an installation of 4.1.0 fail before anything is downloaded
+const DENYLIST: &str = r#"
+[[release]]
+version = "4.1.0"
+reason = "quorum queue leader elections can stall"
+"#;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+#[test]
+fn cli_prints_the_install_command_for_a_missing_version() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.2.2", "--", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Install it with: frm releases install 4.2.2 (or pass --auto-install)",
+        ))
+        .stderr(predicate::str::contains("version 4.2.2 is not installed"));
+}
+
+#[test]
+fn cli_install_hint_follows_terse_mode() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args([
+            "--terse",
+            "cli",
+            "rabbitmqctl",
+            "-V",
+            "4.2.2",
+            "--",
+            "status",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "hint: Install it with: frm releases install 4.2.2",
+        ));
+}
+
+#[test]
+fn fg_node_prints_the_install_command_for_a_missing_alpha() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["fg", "node", "-V", "4.3.0-alpha.abc123"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Install it with: frm alphas install 4.3.0-alpha.abc123",
+        ));
+}
+
+#[test]
+fn cli_auto_install_installs_a_missing_version_first() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("denylist.toml"), DENYLIST).unwrap();
+
+    frm_cmd(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.1.0", "--auto-install", "--"])
+        .arg("status")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "RabbitMQ 4.1.0 is not installed, installing it",
+        ))
+        .stderr(predicate::str::contains("RabbitMQ 4.1.0 is denylisted"))
+        .stderr(predicate::str::contains(
+            "could not install RabbitMQ 4.1.0: frm releases install 4.1.0 exited with code",
+        ));
+}
+
+#[test]
+fn use_auto_install_keeps_the_installation_output_off_stdout() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("denylist.toml"), DENYLIST).unwrap();
+
+    frm_cmd(&temp)
+        .args([
+            "releases",
+            "use",
+            "4.1.0",
+            "--shell",
+            "bash",
+            "--auto-install",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("installing it"));
+}
+
+#[test]
+fn use_auto_install_conflicts_with_provider() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args([
+            "releases",
+            "use",
+            "4.1.0",
+            "--auto-install",
+            "--provider",
+            "asdf",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn configured_auto_install_does_not_install_without_an_answer() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("denylist.toml"), DENYLIST).unwrap();
+    fs::write(temp.path().join("config.toml"), "auto_install = true\n").unwrap();
+
+    // standard input of the test is not a terminal, so the question counts as declined
+    frm_cmd(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.1.0", "--", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Install it with: frm releases install 4.1.0 (or pass --yes)",
+        ))
+        .stderr(predicate::str::contains("installing it").not());
+}
+
+#[test]
+fn configured_auto_install_installs_with_yes() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("denylist.toml"), DENYLIST).unwrap();
+    fs::write(temp.path().join("config.toml"), "auto_install = true\n").unwrap();
+
+    frm_cmd(&temp)
+        .args(["fg", "node", "-V", "4.1.0", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "RabbitMQ 4.1.0 is not installed, installing it",
+        ))
+        .stderr(predicate::str::contains("RabbitMQ 4.1.0 is denylisted"));
+}
+
+#[test]
+fn yes_alone_does_not_enable_auto_install() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["releases", "use", "4.1.0", "--shell", "bash", "--yes"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("frm releases install 4.1.0"))
+        .stderr(predicate::str::contains("installing it").not());
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_auto_install_leaves_installed_versions_alone() {
+    let temp = TempDir::new().unwrap();
+    let sbin = temp.path().join("versions").join("4.2.2").join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    let tool = sbin.join("rabbitmqctl");
+    fs::write(&tool, "#!/bin/sh\necho \"rabbitmqctl $*\"\n").unwrap();
+    fs::set_permissions(&tool, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.2.2", "--auto-install", "--"])
+        .arg("status")
+        .assert()
+        .success()
+        .stdout("rabbitmqctl status\n")
+        .stderr(predicate::str::contains("installing").not());
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+use std::process::{self, Child};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::node_state;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn write_pid_file(paths: &Paths, version: &Version, node: &str, pid: u32) {
+    let data_dir = paths.version_data_dir(version);
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(data_dir.join(format!("{}.pid", node)), pid.to_string()).unwrap();
+}
+
+/// A process standing in for a node's Erlang VM, killed on drop
+struct FakeNode(Child);
+
+impl FakeNode {
+    fn spawn() -> Self {
+        Self(process::Command::new("sleep").arg("60").spawn().unwrap())
+    }
+
+    fn pid(&self) -> u32 {
+        self.0.id()
+    }
+}
+
+impl Drop for FakeNode {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+#[test]
+fn running_nodes_from_pid_files() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let node = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid());
+
+    let nodes = node_state::running_nodes(&paths, &version).unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].name, "rabbit@localhost");
+    assert_eq!(nodes[0].pid, node.pid());
+}
+
+#[test]
+fn pause_and_resume() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let node = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid());
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "pause", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Paused rabbit@localhost"));
+    assert!(node_state::process_stopped(node.pid()));
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "pause", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already paused"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "resume", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Resumed rabbit@localhost"));
+    assert!(!node_state::process_stopped(node.pid()));
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "resume", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is not paused"));
+}
+
+#[test]
+fn pause_picks_a_node_by_name() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let first = FakeNode::spawn();
+    let second = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit-1@localhost", first.pid());
+    write_pid_file(&paths, &version, "rabbit-2@localhost", second.pid());
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "pause", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pick one with --node"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "pause", "-V", "4.2.3", "--node", "rabbit-2@localhost"])
+        .assert()
+        .success();
+    assert!(!node_state::process_stopped(first.pid()));
+    assert!(node_state::process_stopped(second.pid()));
+}
+
+#[test]
+fn pause_without_running_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "pause", "-V", "4.2.3"])
+        .assert()
+        .code(69)
+        .stderr(predicate::str::contains("no running node"));
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{self, ExitStatus};
+use std::thread::{self, JoinHandle};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// Installs a version whose rabbitmqctl runs `script`
+fn install_with_rabbitmqctl(paths: &Paths, version: &Version, script: &str) {
+    install(paths, version);
+    let ctl = paths.version_sbin_dir(version).join("rabbitmqctl");
+    fs::write(&ctl, format!("#!/bin/sh\n{}\n", script)).unwrap();
+    fs::set_permissions(&ctl, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+fn write_pid_file(paths: &Paths, version: &Version, node: &str, pid: u32) {
+    let data_dir = paths.version_data_dir(version);
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(data_dir.join(format!("{}.pid", node)), pid.to_string()).unwrap();
+}
+
+/// A process standing in for a node's Erlang VM. It is reaped as soon as it exits,
+/// so that frm does not see a zombie as a running node.
+struct FakeNode {
+    pid: u32,
+    reaper: Option<JoinHandle<ExitStatus>>,
+}
+
+impl FakeNode {
+    fn spawn() -> Self {
+        let mut child = process::Command::new("sleep").arg("60").spawn().unwrap();
+        Self {
+            pid: child.id(),
+            reaper: Some(thread::spawn(move || child.wait().unwrap())),
+        }
+    }
+
+    fn exit_status(&mut self) -> ExitStatus {
+        self.reaper.take().unwrap().join().unwrap()
+    }
+}
+
+impl Drop for FakeNode {
+    fn drop(&mut self) {
+        if self.reaper.is_some() {
+            let _ = process::Command::new("kill")
+                .args(["-KILL", &self.pid.to_string()])
+                .status();
+        }
+    }
+}
+
+#[test]
+fn cli_bg_stop_kill_without_timeout() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_rabbitmqctl(&paths, &version, "exit 1");
+    let mut node = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "stop", "-V", "4.2.3", "--kill"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Killed rabbit@localhost (PID"))
+        .stdout(predicate::str::contains("without a graceful stop"));
+    assert_eq!(node.exit_status().signal(), Some(9));
+}
+
+#[test]
+fn cli_bg_stop_graceful_within_timeout() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    let mut node = FakeNode::spawn();
+    install_with_rabbitmqctl(&paths, &version, &format!("kill {}", node.pid));
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "stop", "-V", "4.2.3", "--timeout", "10", "--kill"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "rabbit@localhost stopped gracefully",
+        ));
+    assert_eq!(node.exit_status().signal(), Some(15));
+}
+
+#[test]
+fn cli_bg_stop_timeout_without_kill_fails() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_rabbitmqctl(&paths, &version, "exit 0");
+    let node = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "stop", "-V", "4.2.3", "--timeout", "1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "rabbit@localhost did not stop within 1 seconds",
+        ));
+    assert!(frm::node_state::process_alive(node.pid));
+}
+
+#[test]
+fn cli_bg_stop_kills_after_timeout() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_rabbitmqctl(&paths, &version, "exec sleep 30");
+    let mut node = FakeNode::spawn();
+    write_pid_file(&paths, &version, "rabbit@localhost", node.pid);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "stop", "-V", "4.2.3", "--timeout", "1", "--kill"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "did not stop gracefully within 1 seconds",
+        ))
+        .stdout(predicate::str::contains("Killed rabbit@localhost"));
+    assert_eq!(node.exit_status().signal(), Some(9));
+}
+
+#[test]
+fn cli_bg_stop_kill_requires_a_running_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_rabbitmqctl(&paths, &version, "exit 0");
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "stop", "-V", "4.2.3", "--kill"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no running node of RabbitMQ 4.2.3",
+        ));
+}
+
+#[test]
+fn cli_bg_stop_node_stops_only_its_own_supervisor() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_rabbitmqctl(&paths, &version, "exit 1");
+
+    let mut node = FakeNode::spawn();
+    let data_dir = paths.version_env_data_dir(&version, "alpha");
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(data_dir.join("alpha@localhost.pid"), node.pid.to_string()).unwrap();
+
+    let mut supervisors = Vec::new();
+    for env in ["alpha", "beta"] {
+        let supervisor = FakeNode::spawn();
+        let run_dir = paths.version_env_run_dir(&version, env);
+        fs::create_dir_all(&run_dir).unwrap();
+        fs::write(run_dir.join("supervisor.pid"), supervisor.pid.to_string()).unwrap();
+        supervisors.push(supervisor);
+    }
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "bg",
+            "stop",
+            "-V",
+            "4.2.3",
+            "--node",
+            "alpha@localhost",
+            "--kill",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Stopped the supervisor (PID {})",
+            supervisors[0].pid
+        )))
+        .stdout(
+            predicate::str::contains(format!(
+                "Stopped the supervisor (PID {})",
+                supervisors[1].pid
+            ))
+            .not(),
+        );
+    assert_eq!(node.exit_status().signal(), Some(9));
+    assert_eq!(supervisors[0].exit_status().signal(), Some(15));
+    assert!(
+        paths
+            .version_env_run_dir(&version, "beta")
+            .join("supervisor.pid")
+            .is_file()
+    );
+    assert!(
+        process::Command::new("kill")
+            .args(["-0", &supervisors[1].pid.to_string()])
+            .status()
+            .unwrap()
+            .success()
+    );
+}
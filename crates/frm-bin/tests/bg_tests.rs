@@ -216,3 +216,109 @@ fn cli_bg_stop_sbin_dir_empty() {
         .failure()
         .stderr(predicate::str::contains("file not found"));
 }
+
+#[test]
+fn cli_bg_logs_before_the_first_start() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3").join("sbin")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "logs", "--version", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("bg-start.log"))
+        .stderr(predicate::str::contains("frm bg start -V 4.2.3"));
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    use predicates::prelude::*;
+    use tempfile::TempDir;
+
+    use frm::paths::Paths;
+    use frm::version::Version;
+
+    use super::frm_cmd_with_dir;
+
+    /// Installs 4.2.3 with a rabbitmq-server that prints `output` and exits with `code`
+    fn install(temp: &TempDir, output: &str, code: i32) -> Paths {
+        let paths = Paths::with_base_dir(temp.path().to_path_buf());
+        let sbin = paths.version_sbin_dir(&Version::new(4, 2, 3));
+        fs::create_dir_all(&sbin).unwrap();
+        let script = sbin.join("rabbitmq-server");
+        fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho '{}'\necho 'on stderr' >&2\nexit {}\n",
+                output, code
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        paths
+    }
+
+    #[test]
+    fn cli_bg_start_keeps_the_output_for_bg_logs() {
+        let temp = TempDir::new().unwrap();
+        let paths = install(&temp, "BOOT FAILED: eaddrinuse", 1);
+
+        frm_cmd_with_dir(&temp)
+            .args(["bg", "start", "--version", "4.2.3"])
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains("BOOT FAILED").not())
+            .stderr(predicate::str::contains(
+                "see its output with: frm bg logs -V 4.2.3",
+            ));
+
+        let run_log = paths
+            .version_run_dir(&Version::new(4, 2, 3))
+            .join("bg-start.log");
+        let content = fs::read_to_string(&run_log).unwrap();
+        assert!(content.starts_with("# "), "{}", content);
+        assert!(content.contains("BOOT FAILED: eaddrinuse\non stderr\n"));
+
+        frm_cmd_with_dir(&temp)
+            .args(["bg", "logs", "--version", "4.2.3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!(
+                "==> {} <==",
+                run_log.display()
+            )))
+            .stdout(predicate::str::contains("BOOT FAILED: eaddrinuse"));
+    }
+
+    #[test]
+    fn cli_bg_logs_shows_the_node_log_after_the_boot_output() {
+        let temp = TempDir::new().unwrap();
+        let paths = install(&temp, "starting", 0);
+        let version = Version::new(4, 2, 3);
+        let log_dir = paths.version_var_log_dir(&version);
+        fs::create_dir_all(&log_dir).unwrap();
+        fs::write(
+            log_dir.join("rabbit@localhost.log"),
+            "line 1\nline 2\nline 3\n",
+        )
+        .unwrap();
+
+        frm_cmd_with_dir(&temp)
+            .args(["bg", "start", "--version", "4.2.3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("frm bg logs --version 4.2.3"));
+
+        frm_cmd_with_dir(&temp)
+            .args(["bg", "logs", "--version", "4.2.3", "-n", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("starting\non stderr\n"))
+            .stdout(predicate::str::contains(
+                "rabbit@localhost.log <==\nline 2\nline 3\n",
+            ));
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::bisect::{Bisection, Outcome, Verdict, alphas_between};
+use frm::errors::Error;
+use frm::releases::AlphaRelease;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn alpha(n: usize) -> Version {
+    format!("4.3.0-alpha.{:08x}", n).parse().unwrap()
+}
+
+fn alphas(count: usize) -> Vec<Version> {
+    (0..count).map(alpha).collect()
+}
+
+/// Tests the builds the bisection picks until it is done, the builds from `first_bad` on are bad
+fn bisect(builds: Vec<Version>, first_bad: usize, skipped: &[usize]) -> (Option<Outcome>, usize) {
+    let mut bisection = Bisection::new(builds);
+    let mut tested = 0;
+    while let Some(version) = bisection.next().cloned() {
+        let n = (0..).find(|n| alpha(*n) == version).unwrap();
+        let verdict = if skipped.contains(&n) {
+            Verdict::Skip
+        } else if n >= first_bad {
+            Verdict::Bad
+        } else {
+            Verdict::Good
+        };
+        bisection.mark(&version, verdict);
+        tested += 1;
+    }
+    (bisection.outcome(), tested)
+}
+
+#[test]
+fn bisection_finds_the_first_bad_build() {
+    for first_bad in 1..16 {
+        let (outcome, tested) = bisect(alphas(16), first_bad, &[]);
+        assert_eq!(
+            outcome,
+            Some(Outcome::FirstBad {
+                last_good: alpha(first_bad - 1),
+                first_bad: alpha(first_bad),
+            })
+        );
+        assert!(tested <= 4, "{} builds tested", tested);
+    }
+}
+
+#[test]
+fn bisection_of_adjacent_builds_tests_nothing() {
+    let bisection = Bisection::new(alphas(2));
+    assert_eq!(bisection.next(), None);
+    assert_eq!(bisection.remaining_steps(), 0);
+    assert_eq!(
+        bisection.outcome(),
+        Some(Outcome::FirstBad {
+            last_good: alpha(0),
+            first_bad: alpha(1),
+        })
+    );
+}
+
+#[test]
+fn bisection_remaining_steps() {
+    assert_eq!(Bisection::new(alphas(3)).remaining_steps(), 1);
+    assert_eq!(Bisection::new(alphas(10)).remaining_steps(), 4);
+    assert_eq!(Bisection::new(alphas(100)).remaining_steps(), 7);
+}
+
+#[test]
+fn bisection_works_around_skipped_builds() {
+    let (outcome, _) = bisect(alphas(10), 6, &[4]);
+    assert_eq!(
+        outcome,
+        Some(Outcome::FirstBad {
+            last_good: alpha(5),
+            first_bad: alpha(6),
+        })
+    );
+}
+
+#[test]
+fn bisection_with_skipped_builds_can_be_undecided() {
+    let (outcome, _) = bisect(alphas(6), 4, &[3]);
+    assert_eq!(outcome, Some(Outcome::Undecided(vec![alpha(3), alpha(4)])));
+}
+
+fn catalog() -> Vec<AlphaRelease> {
+    // newest first, like the feed
+    (0..5)
+        .rev()
+        .map(|n| AlphaRelease {
+            version: alpha(n),
+            tag: format!("v{}", alpha(n)),
+            published_at: format!("2026-01-0{}T00:00:00Z", n + 1),
+        })
+        .collect()
+}
+
+#[test]
+fn alphas_between_is_oldest_first_and_inclusive() {
+    let builds = alphas_between(&catalog(), &alpha(1), &alpha(3)).unwrap();
+    assert_eq!(builds, vec![alpha(1), alpha(2), alpha(3)]);
+}
+
+#[test]
+fn alphas_between_requires_an_older_good_alpha() {
+    let err = alphas_between(&catalog(), &alpha(3), &alpha(1)).unwrap_err();
+    assert!(matches!(err, Error::InvalidBisection(_)));
+    assert!(err.to_string().contains("must be older than"));
+}
+
+#[test]
+fn alphas_between_requires_alphas_from_the_catalog() {
+    let err = alphas_between(&catalog(), &alpha(1), &alpha(9)).unwrap_err();
+    assert!(matches!(err, Error::ReleaseNotFound(_)));
+}
+
+#[test]
+fn cli_alphas_bisect_requires_alpha_versions() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "alphas",
+            "bisect",
+            "--good",
+            "4.2.3",
+            "--bad",
+            "4.3.0-alpha.abcd1234",
+            "--run",
+            "true",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("4.2.3"));
+}
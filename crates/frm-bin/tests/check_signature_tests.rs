@@ -6,12 +6,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+mod common;
+
 use std::fs;
 
 use assert_cmd::Command;
 use predicates::prelude::*;
 use tempfile::TempDir;
 
+use frm::metadata::{SignatureCheck, VersionMetadata};
+use frm::paths::Paths;
+use frm::timestamps::Timestamps;
+use frm::version::Version;
+
+use common::install_in;
+
 #[allow(deprecated)]
 fn frm_cmd_with_dir(dir: &TempDir) -> Command {
     let mut cmd = Command::cargo_bin("frm").unwrap();
@@ -121,3 +130,79 @@ fn cli_releases_check_signature_accepts_rc() {
                 .or(predicate::str::is_empty()),
         );
 }
+
+#[test]
+fn cli_releases_check_signature_all_without_ga_releases() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.0-rc.1")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "check-signature", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No GA releases installed"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "check-signature", "--all", "--version", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+fn record_signature(paths: &Paths, version: &Version, verified: bool) {
+    VersionMetadata::update(paths, version, |m| {
+        m.signature = Some(SignatureCheck {
+            verified,
+            checked_at: 1_700_000_000,
+        })
+    })
+    .unwrap();
+}
+
+#[test]
+fn cli_releases_list_verbose_shows_signature_status() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.5");
+    install_in(temp.path(), "4.2.2");
+    let paths = install_in(temp.path(), "4.2.3");
+    record_signature(&paths, &Version::new(4, 1, 5), true);
+    record_signature(&paths, &Version::new(4, 2, 2), false);
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--verbose", "--no-color"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Signature"))
+        .stdout(predicate::str::is_match(r"4\.1\.5 .*✓").unwrap())
+        .stdout(predicate::str::is_match(r"4\.2\.2 .*✗").unwrap())
+        .stdout(predicate::str::is_match(r"4\.2\.3 .*unknown").unwrap());
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--plain", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[ ] 4.1.5 (signature ✓)"))
+        .stdout(predicate::str::contains("[ ] 4.2.3 (signature unknown)"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Signature").not());
+}
+
+#[test]
+fn reinstallation_clears_the_signature_status() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_in(temp.path(), "4.2.3");
+    let version = Version::new(4, 2, 3);
+    record_signature(&paths, &version, true);
+
+    let mut timestamps = Timestamps::load(&paths).unwrap();
+    timestamps.record(&version);
+    timestamps.save(&paths).unwrap();
+
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert!(metadata.installed_at.is_some());
+    assert_eq!(metadata.signature, None);
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::common::fs_utils::{available_space, parse_size};
+
+const OLDEST: &str = "4.3.0-alpha.aaa111";
+const OLDER: &str = "4.3.0-alpha.bbb222";
+const NEWEST: &str = "4.3.0-alpha.ccc333";
+
+const MIB: u64 = 1 << 20;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// Three alphas of `size` bytes each, installed a day apart
+fn install_alphas(temp: &TempDir, size: u64) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let mut timestamps = Vec::new();
+    for (age_in_days, version) in [(3, OLDEST), (2, OLDER), (1, NEWEST)] {
+        let dir = temp.path().join("versions").join(version);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("payload"), vec![0u8; size as usize]).unwrap();
+        timestamps.push(format!(r#""{}":{}"#, version, now - age_in_days * 86400));
+    }
+    fs::write(
+        temp.path().join("version_timestamps.json"),
+        format!("{{{}}}", timestamps.join(",")),
+    )
+    .unwrap();
+}
+
+fn installed(temp: &TempDir, version: &str) -> bool {
+    temp.path().join("versions").join(version).exists()
+}
+
+#[test]
+fn parse_size_units() {
+    assert_eq!(parse_size("1024"), Some(1024));
+    assert_eq!(parse_size("20GB"), Some(20_000_000_000));
+    assert_eq!(parse_size("20 gb"), Some(20_000_000_000));
+    assert_eq!(parse_size("500MiB"), Some(500 * MIB));
+    assert_eq!(parse_size("2G"), Some(2 << 30));
+    assert_eq!(parse_size("1.5K"), Some(1536));
+    assert_eq!(parse_size("10XB"), None);
+    assert_eq!(parse_size("GB"), None);
+    assert_eq!(parse_size(""), None);
+}
+
+#[test]
+fn available_space_of_a_temp_dir() {
+    let temp = TempDir::new().unwrap();
+    assert!(available_space(temp.path()).unwrap() > 0);
+}
+
+#[test]
+fn cli_alphas_clean_target_free_already_met() {
+    let temp = TempDir::new().unwrap();
+    install_alphas(&temp, 1024);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", "1KB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is already met"));
+
+    assert!(installed(&temp, OLDEST));
+    assert!(installed(&temp, NEWEST));
+}
+
+#[test]
+fn cli_alphas_clean_target_free_removes_oldest_first() {
+    let temp = TempDir::new().unwrap();
+    install_alphas(&temp, 8 * MIB);
+
+    // removing the oldest alpha is enough to get 4 MiB more than is free now
+    let target = available_space(temp.path()).unwrap() + 4 * MIB;
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", &target.to_string()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 alpha version(s)"))
+        .stdout(predicate::str::contains("free"));
+
+    assert!(!installed(&temp, OLDEST));
+    assert!(installed(&temp, OLDER));
+    assert!(installed(&temp, NEWEST));
+}
+
+#[test]
+fn cli_alphas_clean_target_free_out_of_reach_keeps_pinned() {
+    let temp = TempDir::new().unwrap();
+    install_alphas(&temp, 1024);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", OLDEST])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", "1000000TB"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("short of the target"))
+        .stdout(predicate::str::contains("Removed 2 alpha version(s)"));
+
+    assert!(installed(&temp, OLDEST));
+    assert!(!installed(&temp, OLDER));
+    assert!(!installed(&temp, NEWEST));
+}
+
+#[test]
+fn cli_alphas_clean_target_free_with_older_than() {
+    let temp = TempDir::new().unwrap();
+    install_alphas(&temp, 1024);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", "1000000TB"])
+        .args(["--older-than", "36 hours ago"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Removed 2 alpha version(s) older than",
+        ));
+
+    assert!(!installed(&temp, OLDEST));
+    assert!(!installed(&temp, OLDER));
+    assert!(installed(&temp, NEWEST));
+}
+
+#[test]
+fn cli_alphas_clean_invalid_target_free() {
+    let temp = TempDir::new().unwrap();
+    install_alphas(&temp, 1024);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", "lots"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid size: lots"));
+}
@@ -6,12 +6,28 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+mod common;
+
 use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(unix)]
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use assert_cmd::Command;
+use clap::Command as ClapCommand;
 use predicates::prelude::*;
 use tempfile::TempDir;
 
+use frm::cli::build_cli;
+#[cfg(unix)]
+use frm::paths::Paths;
+#[cfg(unix)]
+use frm::version::Version;
+
+use common::install_in;
+
 #[allow(deprecated)]
 fn frm_cmd() -> Command {
     Command::cargo_bin("frm").unwrap()
@@ -218,6 +234,93 @@ fn cli_releases_use_installed() {
         .stdout(predicate::str::contains("4.2.3"));
 }
 
+#[test]
+fn cli_releases_use_without_version_fails_without_fallback() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "--shell", "bash"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no version specified"));
+}
+
+#[test]
+fn cli_releases_use_with_default_fallback_flag() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "releases",
+            "use",
+            "--shell",
+            "bash",
+            "--with-default-fallback",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export PATH="))
+        .stdout(predicate::str::contains("4.2.3"))
+        .stderr(predicate::str::contains(
+            "No version specified, using the default version 4.2.3",
+        ));
+}
+
+#[test]
+fn cli_releases_use_with_default_fallback_config() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+    install_in(temp.path(), "4.1.0");
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.1.0"])
+        .assert()
+        .success();
+    let config = temp.path().join("config.toml");
+    let content = fs::read_to_string(&config).unwrap();
+    fs::write(&config, format!("{}use_default_fallback = true\n", content)).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.1.0"));
+    // an explicit version wins
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3"))
+        .stderr(predicate::str::contains("default version").not());
+}
+
+#[test]
+fn cli_releases_use_with_default_fallback_without_default() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "releases",
+            "use",
+            "--shell",
+            "bash",
+            "--with-default-fallback",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no version specified"));
+}
+
 #[test]
 fn cli_releases_use_with_shell_flag() {
     let temp = TempDir::new().unwrap();
@@ -280,9 +383,7 @@ fn cli_default_updates_config() {
         .success();
 
     let config_content = fs::read_to_string(temp.path().join("config.toml")).unwrap();
-    assert!(config_content.contains("major = 4"));
-    assert!(config_content.contains("minor = 2"));
-    assert!(config_content.contains("patch = 3"));
+    assert!(config_content.contains("default_version = \"4.2.3\""));
 
     let default_content = fs::read_to_string(temp.path().join("default")).unwrap();
     assert_eq!(default_content.trim(), "4.2.3");
@@ -301,13 +402,36 @@ fn cli_releases_list_marks_default() {
         .success();
 
     frm_cmd_with_dir(&temp)
-        .args(["releases", "list"])
+        .args(["releases", "list", "--plain"])
         .assert()
         .success()
         .stdout(predicate::str::contains("[ ] 4.0.0"))
         .stdout(predicate::str::contains("[*] 4.2.3"));
 }
 
+#[test]
+fn cli_deactivate_bash() {
+    frm_cmd()
+        .args(["deactivate", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm-deactivate() {"))
+        .stdout(predicate::str::ends_with("frm-deactivate\n"));
+}
+
+#[test]
+fn cli_releases_use_sbin_only() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3").join("sbin")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash", "--sbin-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export FRM_ACTIVE_SBIN="))
+        .stdout(predicate::str::contains("export RABBITMQ_HOME=").not());
+}
+
 #[test]
 fn cli_releases_uninstall_not_installed() {
     let temp = TempDir::new().unwrap();
@@ -333,6 +457,21 @@ fn cli_releases_uninstall_installed() {
     assert!(!version_dir.exists());
 }
 
+#[test]
+fn cli_releases_uninstall_with_yes() {
+    let temp = TempDir::new().unwrap();
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    fs::create_dir_all(&version_dir).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "--yes", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("RabbitMQ 4.2.3 uninstalled"));
+
+    assert!(!version_dir.exists());
+}
+
 #[test]
 fn cli_releases_uninstall_alias() {
     let temp = TempDir::new().unwrap();
@@ -347,6 +486,67 @@ fn cli_releases_uninstall_alias() {
     assert!(!version_dir.exists());
 }
 
+#[cfg(unix)]
+#[test]
+fn cli_releases_uninstall_refuses_a_version_with_a_running_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    let sbin = paths.version_sbin_dir(&version);
+    fs::create_dir_all(&sbin).unwrap();
+    // the test process stands in for the node's Erlang VM
+    let data_dir = paths.version_data_dir(&version);
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(
+        data_dir.join("rabbit@localhost.pid"),
+        process::id().to_string(),
+    )
+    .unwrap();
+    let shutdown_log = temp.path().join("shutdown.log");
+    let ctl = sbin.join("rabbitmqctl");
+    fs::write(
+        &ctl,
+        format!(
+            "#!/bin/sh\nprintf '%s\\n' \"$*\" >> {}\n",
+            shutdown_log.display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&ctl, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "--yes", "4.2.3"])
+        .assert()
+        .failure()
+        .code(64)
+        .stderr(predicate::str::contains("has a running node"));
+    assert!(paths.version_installed(&version));
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "releases",
+            "uninstall",
+            "--yes",
+            "--stop",
+            "--force",
+            "4.2.3",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "--yes", "--stop", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Stopped node rabbit@localhost"));
+    assert!(!paths.version_installed(&version));
+    assert_eq!(
+        fs::read_to_string(&shutdown_log).unwrap().trim(),
+        "-n rabbit@localhost shutdown"
+    );
+}
+
 #[test]
 fn cli_releases_uninstall_rejects_alpha() {
     let temp = TempDir::new().unwrap();
@@ -380,6 +580,99 @@ fn cli_releases_uninstall_clears_default() {
     assert!(!temp.path().join("default").exists());
 }
 
+#[test]
+fn cli_releases_uninstall_promote_next() {
+    let temp = TempDir::new().unwrap();
+    for version in ["4.1.0", "4.2.3", "4.3.0-alpha.abc123"] {
+        install_in(temp.path(), version);
+    }
+    // broken, so it is skipped
+    fs::create_dir_all(temp.path().join("versions").join("4.2.1")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3", "--promote-next"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default version set to 4.1.0"))
+        .stdout(predicate::str::contains("Cleared default version").not());
+
+    assert_eq!(
+        fs::read_to_string(temp.path().join("default")).unwrap(),
+        "4.1.0"
+    );
+}
+
+#[test]
+fn cli_releases_uninstall_promote_next_without_other_versions() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3", "--promote-next"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cleared default version"))
+        .stdout(predicate::str::contains("No other GA version is installed"));
+
+    assert!(!temp.path().join("default").exists());
+}
+
+#[test]
+fn cli_releases_uninstall_promote_next_keeps_another_default() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.0");
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.1.0"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3", "--promote-next"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default version set").not());
+
+    assert_eq!(
+        fs::read_to_string(temp.path().join("default")).unwrap(),
+        "4.1.0"
+    );
+}
+
+#[test]
+fn cli_alphas_uninstall_promote_next() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+    install_in(temp.path(), "4.3.0-alpha.abc123");
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.3.0-alpha.abc123"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "alphas",
+            "uninstall",
+            "4.3.0-alpha.abc123",
+            "--promote-next",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default version set to 4.2.3"));
+}
+
 #[test]
 fn cli_releases_reinstall_not_installed() {
     let temp = TempDir::new().unwrap();
@@ -621,6 +914,21 @@ fn cli_cli_unknown_tool() {
         .stderr(predicate::str::contains("unknown tool"));
 }
 
+#[test]
+fn cli_cli_unknown_tool_suggests_the_nearest_one() {
+    let temp = TempDir::new().unwrap();
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    fs::create_dir_all(version_dir.join("sbin")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cli", "rabbitmq-diagnostic", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Did you mean rabbitmq-diagnostics?",
+        ));
+}
+
 #[test]
 fn cli_cli_tool_not_found() {
     let temp = TempDir::new().unwrap();
@@ -689,6 +997,40 @@ management.tcp.port = 15672
         .stdout(predicate::str::contains("vm_memory_high_watermark"));
 }
 
+#[test]
+fn cli_inspect_validate() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("advanced.config"),
+        "[\n  {rabbit, [{log_level, Info}]}\n].\n",
+    )
+    .unwrap();
+    fs::write(etc_dir.join("rabbitmq.config"), "[{rabbit, []}].\n").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["inspect", "advanced.config", "--validate", "-V", "4.2.3"])
+        .assert()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "advanced.config is not valid: syntax error at line 2, column 25",
+        ))
+        .stderr(predicate::str::contains("'Info'"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["inspect", "rabbitmq.config", "--validate", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rabbitmq.config is valid"))
+        .stdout(predicate::str::contains("{rabbit").not());
+}
+
 #[test]
 fn cli_inspect_enabled_plugins() {
     let temp = TempDir::new().unwrap();
@@ -730,6 +1072,69 @@ fn cli_inspect_advanced_config() {
         .stdout(predicate::str::contains("inet_dist_listen_min"));
 }
 
+#[test]
+fn cli_inspect_json_format() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "# comment\nlisteners.tcp.default = 5673\nlog.file.level = debug\n",
+    )
+    .unwrap();
+    fs::write(
+        etc_dir.join("enabled_plugins"),
+        "[rabbitmq_management,\n rabbitmq_stream].\n",
+    )
+    .unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq-env.conf"),
+        "NODENAME=hare@localhost\n",
+    )
+    .unwrap();
+    fs::write(etc_dir.join("advanced.config"), "[].\n").unwrap();
+
+    let json = |file: &str| -> serde_json::Value {
+        let output = frm_cmd_with_dir(&temp)
+            .args(["inspect", file, "-V", "4.2.3", "--format", "json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+
+    assert_eq!(
+        json("rabbitmq.conf"),
+        serde_json::json!({"listeners.tcp.default": "5673", "log.file.level": "debug"})
+    );
+    assert_eq!(
+        json("enabled_plugins"),
+        serde_json::json!(["rabbitmq_management", "rabbitmq_stream"])
+    );
+    assert_eq!(
+        json("rabbitmq-env.conf"),
+        serde_json::json!({"NODENAME": "hare@localhost"})
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "inspect",
+            "advanced.config",
+            "-V",
+            "4.2.3",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("JSON output is not supported"));
+}
+
 #[test]
 fn cli_releases_logs_path_not_installed() {
     let temp = TempDir::new().unwrap();
@@ -1149,6 +1554,21 @@ fn cli_alphas_prune_removes_alphas() {
     assert!(release.exists());
 }
 
+#[test]
+fn cli_alphas_prune_with_yes() {
+    let temp = TempDir::new().unwrap();
+    let alpha = temp.path().join("versions").join("4.3.0-alpha.132057c7");
+    fs::create_dir_all(&alpha).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "prune", "-y"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 alpha version(s)"));
+
+    assert!(!alpha.exists());
+}
+
 #[test]
 fn cli_alphas_prune_clears_default_if_alpha() {
     let temp = TempDir::new().unwrap();
@@ -1221,7 +1641,7 @@ fn cli_alphas_list_marks_default() {
         .success();
 
     frm_cmd_with_dir(&temp)
-        .args(["alphas", "list"])
+        .args(["alphas", "list", "--plain"])
         .assert()
         .success()
         .stdout(predicate::str::contains("[ ] 4.3.0-alpha.abc123"))
@@ -1311,6 +1731,23 @@ fn cli_alphas_completions_with_shell_nu() {
         .stdout(predicate::str::contains("4.3.0-alpha.abc123"));
 }
 
+#[test]
+fn cli_alphas_diff_rejects_non_alpha() {
+    frm_cmd()
+        .args(["alphas", "diff", "4.2.3", "4.3.0-alpha.132057c7"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected an alpha version"));
+}
+
+#[test]
+fn cli_alphas_diff_requires_two_versions() {
+    frm_cmd()
+        .args(["alphas", "diff", "4.3.0-alpha.132057c7"])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn cli_alphas_clean_no_alphas() {
     let temp = TempDir::new().unwrap();
@@ -1328,8 +1765,8 @@ fn cli_alphas_clean_none_old_enough() {
     fs::create_dir_all(versions_dir.join("4.3.0-alpha.abc123")).unwrap();
 
     let timestamps_file = temp.path().join("version_timestamps.json");
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_secs();
     fs::write(
@@ -1353,8 +1790,8 @@ fn cli_alphas_clean_removes_old() {
     fs::create_dir_all(versions_dir.join("4.3.0-alpha.def456")).unwrap();
 
     let timestamps_file = temp.path().join("version_timestamps.json");
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
         .unwrap()
         .as_secs();
     let old_ts = now - 60 * 60 * 24 * 30;
@@ -1567,6 +2004,94 @@ fn cli_releases_path_rejects_alpha() {
         .stderr(predicate::str::contains("expected a non-alpha version"));
 }
 
+#[test]
+fn cli_releases_path_check_existing_directory() {
+    let temp = TempDir::new().unwrap();
+    let sbin = temp.path().join("versions").join("4.2.3").join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--check", "sbin"])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", sbin.display()));
+}
+
+#[test]
+fn cli_releases_path_check_missing_directory() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--check", "logs"])
+        .assert()
+        .code(72)
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "the logs directory of RabbitMQ 4.2.3 does not exist",
+        ));
+}
+
+#[test]
+fn cli_releases_path_check_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--check", "etc"])
+        .assert()
+        .code(66);
+}
+
+#[test]
+fn cli_releases_path_check_rejects_unknown_directories() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--check", "plugins"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("sbin, etc, logs, data"));
+}
+
+#[test]
+fn cli_releases_path_print0() {
+    let temp = TempDir::new().unwrap();
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    fs::create_dir_all(&version_dir).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--print0"])
+        .assert()
+        .success()
+        .stdout(format!("{}\0", version_dir.display()));
+}
+
+#[test]
+fn cli_alphas_path_check_etc_print0() {
+    let temp = TempDir::new().unwrap();
+    let etc = temp
+        .path()
+        .join("versions")
+        .join("4.3.0-alpha.abc123")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "alphas",
+            "path",
+            "-V",
+            "4.3.0-alpha.abc123",
+            "--check",
+            "etc",
+            "-0",
+        ])
+        .assert()
+        .success()
+        .stdout(format!("{}\0", etc.display()));
+}
+
 #[test]
 fn cli_alphas_path_no_version() {
     let temp = TempDir::new().unwrap();
@@ -1743,6 +2268,61 @@ fn cli_conf_get_key_found() {
         .stdout(predicate::str::contains("5672"));
 }
 
+#[test]
+fn cli_conf_warns_when_classic_config_takes_precedence() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "listeners.tcp.default = 5672\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "get-key", "listeners.tcp.default", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("rabbitmq.config").not());
+
+    fs::write(
+        etc_dir.join("rabbitmq.config"),
+        "[{rabbit, [{tcp_listeners, [5673]}]}].\n",
+    )
+    .unwrap();
+    let warning = "rabbitmq.config takes precedence and rabbitmq.conf is ignored";
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "get-key", "listeners.tcp.default", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("5672"))
+        .stderr(predicate::str::contains(warning));
+    frm_cmd_with_dir(&temp)
+        .args([
+            "inspect",
+            "rabbitmq.conf",
+            "-V",
+            "4.2.3",
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(warning).not())
+        .stderr(predicate::str::contains(warning));
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "set-key", "heartbeat", "30", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(warning));
+}
+
 #[test]
 fn cli_conf_get_key_not_found() {
     let temp = TempDir::new().unwrap();
@@ -1930,6 +2510,176 @@ fn cli_conf_get_key_pattern_no_match() {
         .stderr(predicate::str::contains("no keys matching pattern"));
 }
 
+#[test]
+fn cli_conf_get_key_deep_pattern_match() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "log.file = false\nlog.console.level = debug\nlog.console.formatter.json.field_map = x\nloopback_users.guest = false\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "get-key", "log.**", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("log.file = false"))
+        .stdout(predicate::str::contains("log.console.level = debug"))
+        .stdout(predicate::str::contains(
+            "log.console.formatter.json.field_map = x",
+        ))
+        .stdout(predicate::str::contains("loopback_users").not());
+}
+
+#[test]
+fn cli_conf_get_key_regex() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "listeners.tcp.default = 5672\nlisteners.ssl.default = 5671\nheartbeat = 60\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "conf",
+            "get-key",
+            "--regex",
+            r"^listeners\.(tcp|ssl)\.",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("listeners.tcp.default = 5672"))
+        .stdout(predicate::str::contains("listeners.ssl.default = 5671"))
+        .stdout(predicate::str::contains("heartbeat").not());
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "get-key", "--regex", "^vm_memory", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no keys matching regular expression",
+        ));
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "get-key", "--regex", "(", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid regular expression"));
+}
+
+#[test]
+fn cli_conf_get_key_tree_format() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "ssl_options.verify = verify_peer\nssl_options.cacertfile = /tmp/ca.pem\nlog.file = rabbit.log\nlog.file.level = info\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "conf",
+            "get-key",
+            "ssl_options.*",
+            "--format",
+            "tree",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .success()
+        .stdout("ssl_options\n  cacertfile = /tmp/ca.pem\n  verify = verify_peer\n");
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "conf", "get-key", "log.**", "--format", "tree", "-V", "4.2.3",
+        ])
+        .assert()
+        .success()
+        .stdout("log\n  file = rabbit.log\n    level = info\n");
+}
+
+#[test]
+fn cli_conf_get_key_json_tree_format() {
+    let temp = TempDir::new().unwrap();
+    let etc_dir = temp
+        .path()
+        .join("versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(
+        etc_dir.join("rabbitmq.conf"),
+        "log.file = rabbit.log\nlog.file.level = info\nlog.console.level = debug\nheartbeat = 60\n",
+    )
+    .unwrap();
+
+    let output = frm_cmd_with_dir(&temp)
+        .args([
+            "conf",
+            "get-key",
+            "log.**",
+            "--format",
+            "json-tree",
+            "-V",
+            "4.2.3",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "log": {
+                "console": {"level": "debug"},
+                "file": {"_value": "rabbit.log", "level": "info"}
+            }
+        })
+    );
+
+    // a single key is nested, too
+    let output = frm_cmd_with_dir(&temp)
+        .args([
+            "conf",
+            "get-key",
+            "heartbeat",
+            "--format",
+            "json-tree",
+            "-V",
+            "4.2.3",
+        ])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json, serde_json::json!({"heartbeat": "60"}));
+}
+
 #[test]
 fn cli_releases_use_latest_no_versions() {
     let temp = TempDir::new().unwrap();
@@ -2088,9 +2838,7 @@ fn cli_default_latest_updates_config_and_file() {
         .success();
 
     let config_content = fs::read_to_string(temp.path().join("config.toml")).unwrap();
-    assert!(config_content.contains("major = 4"));
-    assert!(config_content.contains("minor = 2"));
-    assert!(config_content.contains("patch = 3"));
+    assert!(config_content.contains("default_version = \"4.2.3\""));
 
     let default_content = fs::read_to_string(temp.path().join("default")).unwrap();
     assert_eq!(default_content.trim(), "4.2.3");
@@ -2405,3 +3153,47 @@ fn cli_releases_install_invalid_version() {
         .failure()
         .stderr(predicate::str::contains("invalid version format"));
 }
+
+#[test]
+fn cli_exit_codes() {
+    frm_cmd()
+        .args(["exit-codes", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 ok "))
+        .stdout(predicate::str::contains("66 not-found "))
+        .stdout(predicate::str::contains("71 child-process "))
+        .stdout(predicate::str::contains("75 locked "))
+        .stdout(predicate::str::contains("76 network "));
+}
+
+#[test]
+fn cli_not_installed_exit_code() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3"])
+        .assert()
+        .code(66);
+}
+
+fn assert_no_indented_long_help(command: &ClapCommand, name: &str) {
+    if let Some(about) = command.get_long_about() {
+        for line in about.to_string().lines() {
+            assert!(
+                !line.starts_with("    "),
+                "the long help of '{}' has an indented line, use \\n\\ continuations: {:?}",
+                name,
+                line
+            );
+        }
+    }
+    for sub in command.get_subcommands() {
+        let sub_name = format!("{} {}", name, sub.get_name());
+        assert_no_indented_long_help(sub, sub_name.trim());
+    }
+}
+
+#[test]
+fn long_help_texts_have_no_stray_indentation() {
+    assert_no_indented_long_help(&build_cli(), "");
+}
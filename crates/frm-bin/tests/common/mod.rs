@@ -0,0 +1,38 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixtures shared by the integration tests.
+
+// every test crate uses a different subset of these
+#![allow(dead_code)]
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+/// A fake installation: an executable `sbin/rabbitmq-server` and an empty `etc/rabbitmq`
+pub fn install(paths: &Paths, version: &Version) {
+    let sbin = paths.version_sbin_dir(version);
+    fs::create_dir_all(&sbin).unwrap();
+    let server = sbin.join("rabbitmq-server");
+    fs::write(&server, "#!/bin/sh\n").unwrap();
+    #[cfg(unix)]
+    fs::set_permissions(&server, fs::Permissions::from_mode(0o755)).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(version)).unwrap();
+}
+
+/// [`install`] into the `frm` directory at `base_dir`, returning its paths
+pub fn install_in(base_dir: &Path, version: &str) -> Paths {
+    let paths = Paths::with_base_dir(base_dir.to_path_buf());
+    install(&paths, &version.parse().unwrap());
+    paths
+}
@@ -0,0 +1,235 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::complete::candidates;
+use frm::common::cli_tools::nearest_tool;
+use frm::envs;
+use frm::paths::Paths;
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn setup() -> (TempDir, Paths) {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.4");
+    install_in(temp.path(), "4.2.3");
+    install_in(temp.path(), "4.3.0-alpha.132057c7");
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    (temp, paths)
+}
+
+fn values(paths: &Paths, words: &[&str]) -> Vec<String> {
+    let words: Vec<String> = words.iter().map(|w| w.to_string()).collect();
+    candidates(paths, &words)
+        .into_iter()
+        .map(|c| c.value)
+        .collect()
+}
+
+#[test]
+fn complete_subcommands_by_prefix() {
+    let (_temp, paths) = setup();
+    assert_eq!(values(&paths, &["rel"]), ["releases"]);
+    assert_eq!(values(&paths, &["releases", "un"]), ["uninstall", "unpin"]);
+}
+
+#[test]
+fn complete_hides_hidden_subcommands() {
+    let (_temp, paths) = setup();
+    assert!(!values(&paths, &[""]).contains(&"__complete".to_string()));
+}
+
+#[test]
+fn complete_subcommands_have_descriptions() {
+    let (_temp, paths) = setup();
+    let words = vec!["statu".to_string()];
+    let candidates = candidates(&paths, &words);
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].value, "status");
+    assert!(candidates[0].description.is_some());
+    assert!(candidates[0].to_line().starts_with("status\t"));
+}
+
+#[test]
+fn complete_positional_versions_of_the_command_group() {
+    let (_temp, paths) = setup();
+    assert_eq!(
+        values(&paths, &["releases", "uninstall", ""]),
+        ["4.2.3", "4.1.4"]
+    );
+    assert_eq!(
+        values(&paths, &["alphas", "uninstall", ""]),
+        ["4.3.0-alpha.132057c7"]
+    );
+}
+
+#[test]
+fn complete_option_values() {
+    let (_temp, paths) = setup();
+    assert_eq!(
+        values(&paths, &["releases", "path", "-V", "4.2"]),
+        ["4.2.3"]
+    );
+    assert_eq!(
+        values(&paths, &["releases", "path", "--version", ""]),
+        ["4.2.3", "4.1.4"]
+    );
+    assert_eq!(
+        values(&paths, &["releases", "path", "--version=4.1"]),
+        ["--version=4.1.4"]
+    );
+}
+
+#[test]
+fn complete_latest_for_install() {
+    let (_temp, paths) = setup();
+    assert!(values(&paths, &["alphas", "install", ""]).contains(&"latest".to_string()));
+    assert!(!values(&paths, &["alphas", "uninstall", ""]).contains(&"latest".to_string()));
+}
+
+#[test]
+fn complete_options_including_global_ones() {
+    let (_temp, paths) = setup();
+    let options = values(&paths, &["releases", "path", "--"]);
+    assert!(options.contains(&"--version".to_string()));
+    assert!(options.contains(&"--debug".to_string()));
+    assert_eq!(values(&paths, &["status", "--te"]), ["--terse"]);
+}
+
+#[test]
+fn complete_possible_values() {
+    let (_temp, paths) = setup();
+    assert_eq!(values(&paths, &["shell", "completions", "ba"]), ["bash"]);
+}
+
+#[test]
+fn complete_environments_of_the_given_version() {
+    let (_temp, paths) = setup();
+    let version = "4.2.3".parse().unwrap();
+    envs::create(&paths, &version, "tls").unwrap();
+    envs::create(&paths, &version, "cluster-a").unwrap();
+
+    let mut names = values(&paths, &["fg", "node", "-V", "4.2.3", "--env", ""]);
+    names.sort();
+    assert_eq!(names, ["cluster-a", "tls"]);
+    assert!(values(&paths, &["fg", "node", "--env", ""]).is_empty());
+}
+
+#[test]
+fn complete_cli_tools() {
+    let (temp, paths) = setup();
+    let sbin = temp.path().join("versions").join("4.2.3").join("sbin");
+    fs::write(sbin.join("rabbitmqctl"), "").unwrap();
+    fs::write(sbin.join("rabbitmq-diagnostics"), "").unwrap();
+
+    assert_eq!(
+        values(&paths, &["cli", "rabbitmq-d"]),
+        ["rabbitmq-diagnostics"]
+    );
+    assert_eq!(
+        values(&paths, &["cli", "-V", "4.2.3", ""]),
+        ["rabbitmqctl", "rabbitmq-diagnostics", "rabbitmqadmin"]
+    );
+    assert!(values(&paths, &["cli", "rabbitmqctl", ""]).is_empty());
+}
+
+#[test]
+fn nearest_tool_names() {
+    assert_eq!(
+        nearest_tool("rabbitmq-diagnostic"),
+        Some("rabbitmq-diagnostics")
+    );
+    assert_eq!(nearest_tool("diagnostics"), Some("rabbitmq-diagnostics"));
+    assert_eq!(nearest_tool("rabbitmqctrl"), Some("rabbitmqctl"));
+    assert_eq!(nearest_tool("rabitmqadmin"), Some("rabbitmqadmin"));
+    assert_eq!(nearest_tool("erl"), None);
+}
+
+#[test]
+fn complete_conf_keys() {
+    let (_temp, paths) = setup();
+    let keys = values(&paths, &["conf", "set-key", "lis"]);
+    assert!(keys.contains(&"listeners.tcp.default".to_string()));
+    assert!(keys.iter().all(|k| k.starts_with("lis")));
+    assert_eq!(
+        values(
+            &paths,
+            &["conf", "get-key", "-V", "4.2.3", "vm_memory_high_w"]
+        ),
+        values(&paths, &["conf", "set-key", "vm_memory_high_w"])
+    );
+    assert!(values(&paths, &["conf", "set-key", "heartbeat", ""]).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn complete_script_names() {
+    let (_temp, paths) = setup();
+    fs::create_dir_all(paths.scripts_dir()).unwrap();
+    let script = paths.scripts_dir().join("seed-queues");
+    fs::write(&script, "#!/bin/sh\n").unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    assert_eq!(values(&paths, &["run-script", "se"]), ["seed-queues"]);
+}
+
+#[test]
+fn complete_nothing_after_unknown_words() {
+    let (_temp, paths) = setup();
+    assert!(values(&paths, &["status", "extra", ""]).is_empty());
+}
+
+#[test]
+fn cli_complete_prints_candidates_with_descriptions() {
+    let (temp, _paths) = setup();
+    frm_cmd_with_dir(&temp)
+        .args(["__complete", "releases", "uninstall", ""])
+        .assert()
+        .success()
+        .stdout("4.2.3\tGA\n4.1.4\tGA\n");
+}
+
+#[test]
+fn cli_complete_accepts_options_being_completed() {
+    let (temp, _paths) = setup();
+    frm_cmd_with_dir(&temp)
+        .args(["__complete", "releases", "path", "--ver"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("--version\t"));
+}
+
+#[test]
+fn cli_complete_is_not_recorded_in_history() {
+    let (temp, _paths) = setup();
+    frm_cmd_with_dir(&temp)
+        .args(["__complete", "rel"])
+        .assert()
+        .success();
+    frm_cmd_with_dir(&temp)
+        .args(["history"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("__complete").not());
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::etc_backups::{self, MAX_BACKUPS};
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn setup() -> (TempDir, PathBuf) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.version_sbin_dir(&version)).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(&version)).unwrap();
+    let etc_dir = paths.version_etc_dir(&version);
+    fs::write(etc_dir.join("rabbitmq.conf"), "heartbeat = 60\n").unwrap();
+    (temp, etc_dir)
+}
+
+fn set_key(temp: &TempDir, key: &str, value: &str) {
+    frm_cmd_with_dir(temp)
+        .args(["conf", "set-key", key, value, "-V", "4.2.3"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn backups_are_rotated() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("rabbitmq.conf");
+    for i in 0..MAX_BACKUPS + 3 {
+        etc_backups::replace(&file, format!("heartbeat = {}\n", i).as_bytes()).unwrap();
+    }
+
+    let backups = etc_backups::list(&file).unwrap();
+    assert_eq!(backups.len(), MAX_BACKUPS);
+    // the oldest remaining backup has the content written before the 3 pruned ones
+    assert_eq!(
+        fs::read_to_string(&backups[0].path).unwrap(),
+        "heartbeat = 2\n"
+    );
+}
+
+#[test]
+fn set_key_backs_up_rabbitmq_conf() {
+    let (temp, etc_dir) = setup();
+    let conf = etc_dir.join("rabbitmq.conf");
+
+    set_key(&temp, "heartbeat", "30");
+    set_key(&temp, "heartbeat", "10");
+    assert_eq!(etc_backups::list(&conf).unwrap().len(), 2);
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "history", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Backed Up At"))
+        .stdout(predicate::str::contains("frm conf revert"));
+
+    // the second most recent backup is the original file
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "revert", "2", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Restored rabbitmq.conf"));
+    assert_eq!(fs::read_to_string(&conf).unwrap(), "heartbeat = 60\n");
+
+    // the reverted file was backed up, so the revert can be undone
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "revert", "-V", "4.2.3"])
+        .assert()
+        .success();
+    assert!(
+        fs::read_to_string(&conf)
+            .unwrap()
+            .contains("heartbeat = 10")
+    );
+}
+
+#[test]
+fn revert_reports_missing_backups() {
+    let (temp, _etc_dir) = setup();
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "history", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No backups of rabbitmq.conf"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "revert", "3", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("backup #3 of rabbitmq.conf"));
+}
+
+#[test]
+fn cp_etc_file_backs_up_the_replaced_file() {
+    let (temp, etc_dir) = setup();
+    let plugins = etc_dir.join("enabled_plugins");
+    fs::write(&plugins, "[rabbitmq_management].\n").unwrap();
+    let local = temp.path().join("plugins");
+    fs::write(&local, "[rabbitmq_stream].\n").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "releases",
+            "cp-etc-file",
+            "--local-file-path",
+            local.to_str().unwrap(),
+            "--etc-file",
+            "enabled_plugins",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&plugins).unwrap(),
+        "[rabbitmq_stream].\n"
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "conf",
+            "rollback",
+            "-V",
+            "4.2.3",
+            "--file",
+            "enabled_plugins",
+        ])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&plugins).unwrap(),
+        "[rabbitmq_management].\n"
+    );
+}
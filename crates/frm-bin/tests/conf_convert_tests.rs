@@ -0,0 +1,186 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use rabbitmq_conf::{RabbitMQConf, erlang_terms, keys};
+use tempfile::TempDir;
+
+const CLASSIC_CONFIG: &str = r#"
+%% a classic rabbitmq.config
+[
+  {rabbit, [
+    {tcp_listeners, [{"127.0.0.1", 5672}, {"::1", 5672}]},
+    {ssl_listeners, [5671]},
+    {ssl_options, [{cacertfile, "/etc/tls/ca.pem"}, {verify, verify_peer},
+                   {versions, ['tlsv1.2', 'tlsv1.3']}]},
+    {default_user, <<"admin">>},
+    {default_pass, <<"s3cr#t">>},
+    {default_permissions, [<<".*">>, <<"^amq\\.">>, <<".*">>]},
+    {default_user_tags, [administrator]},
+    {loopback_users, []},
+    {vm_memory_high_watermark, 0.6},
+    {disk_free_limit, {mem_relative, 1.0}},
+    {heartbeat, 30},
+    {cluster_nodes, {['rabbit@a', 'rabbit@b'], disc}},
+    {log, [{file, [{level, debug}]}]},
+    {tcp_listen_options, [{backlog, 128}, {linger, {true, 0}}]}
+  ]},
+  {rabbitmq_management, [{listener, [{port, 15672}]}]}
+].
+"#;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn setup(classic_config: &str) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    let etc_dir = version_dir.join("etc").join("rabbitmq");
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::create_dir_all(version_dir.join("sbin")).unwrap();
+    fs::write(
+        version_dir.join("sbin").join("rabbitmq-server"),
+        "#!/bin/sh\n",
+    )
+    .unwrap();
+    fs::write(etc_dir.join("rabbitmq.config"), classic_config).unwrap();
+    temp
+}
+
+fn convert(temp: &TempDir) -> (String, String) {
+    let output = frm_cmd(temp)
+        .args([
+            "conf",
+            "convert",
+            "--from",
+            "rabbitmq.config",
+            "-V",
+            "4.2.3",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    (
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn cli_conf_convert_translates_rabbit_settings() {
+    let temp = setup(CLASSIC_CONFIG);
+    let (stdout, _) = convert(&temp);
+
+    let conf = RabbitMQConf::parse(&stdout).unwrap();
+    let expected = [
+        ("listeners.tcp.1", "127.0.0.1:5672"),
+        ("listeners.tcp.2", "::1:5672"),
+        ("listeners.ssl.default", "5671"),
+        ("ssl_options.cacertfile", "/etc/tls/ca.pem"),
+        ("ssl_options.verify", "verify_peer"),
+        ("ssl_options.versions.2", "tlsv1.3"),
+        ("default_user", "admin"),
+        ("default_pass", "s3cr#t"),
+        ("default_permissions.write", "^amq\\."),
+        ("default_user_tags.administrator", "true"),
+        ("loopback_users", "none"),
+        ("vm_memory_high_watermark.relative", "0.6"),
+        ("disk_free_limit.relative", "1.0"),
+        ("heartbeat", "30"),
+        ("cluster_formation.peer_discovery_backend", "classic_config"),
+        ("cluster_formation.classic_config.nodes.2", "rabbit@b"),
+    ];
+    for (key, value) in expected {
+        assert_eq!(conf.get(key), Some(value), "{}", key);
+    }
+    for key in conf.keys() {
+        assert!(keys::is_known_key(key), "{}", key);
+    }
+}
+
+#[test]
+fn cli_conf_convert_lists_unconvertible_settings() {
+    let temp = setup(CLASSIC_CONFIG);
+    let (stdout, stderr) = convert(&temp);
+
+    assert!(stderr.contains(
+        "no rabbitmq.conf equivalent for rabbit.log, rabbit.tcp_listen_options, \
+        rabbitmq_management.listener"
+    ));
+    // the leftovers are a valid advanced.config once uncommented
+    let (_, advanced) = stdout
+        .split_once("# No rabbitmq.conf equivalent, move to advanced.config:\n")
+        .unwrap();
+    let advanced: String = advanced
+        .lines()
+        .map(|line| line.strip_prefix('#').unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let term = erlang_terms::parse(&advanced).unwrap();
+    assert_eq!(
+        term.to_string(),
+        "[{rabbit, [{log, [{file, [{level, debug}]}]}, \
+        {tcp_listen_options, [{backlog, 128}, {linger, {true, 0}}]}]}, \
+        {rabbitmq_management, [{listener, [{port, 15672}]}]}]"
+    );
+}
+
+#[test]
+fn cli_conf_convert_without_leftovers() {
+    let temp = setup("[{rabbit, [{heartbeat, 60}, {tcp_listeners, []}]}].\n");
+    let (stdout, stderr) = convert(&temp);
+
+    assert_eq!(stdout, "heartbeat = 60\nlisteners.tcp = none\n");
+    assert!(stderr.is_empty());
+}
+
+#[test]
+fn cli_conf_convert_errors() {
+    let temp = setup("[{rabbit, [{heartbeat, 60}]}\n");
+    frm_cmd(&temp)
+        .args([
+            "conf",
+            "convert",
+            "--from",
+            "rabbitmq.config",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .code(65)
+        .stderr(predicate::str::contains("rabbitmq.config is not valid"));
+
+    fs::remove_file(
+        temp.path()
+            .join("versions")
+            .join("4.2.3")
+            .join("etc")
+            .join("rabbitmq")
+            .join("rabbitmq.config"),
+    )
+    .unwrap();
+    frm_cmd(&temp)
+        .args([
+            "conf",
+            "convert",
+            "--from",
+            "rabbitmq.config",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .code(66)
+        .stderr(predicate::str::contains("file not found"));
+}
@@ -0,0 +1,196 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::etc_backups;
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd.env_remove("VISUAL");
+    // rejected copies are kept in the temporary directory
+    cmd.env("TMPDIR", dir.path());
+    cmd
+}
+
+fn setup(conf: &str) -> (TempDir, PathBuf) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.version_sbin_dir(&version)).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(&version)).unwrap();
+    let conf_path = paths.version_etc_dir(&version).join("rabbitmq.conf");
+    fs::write(&conf_path, conf).unwrap();
+    (temp, conf_path)
+}
+
+/// An "editor" that appends a line to the file it is given
+#[cfg(unix)]
+fn appending_editor(dir: &Path, line: &str) -> PathBuf {
+    let script = dir.join(format!("editor-{}.sh", line.len()));
+    fs::write(&script, format!("#!/bin/sh\necho '{}' >> \"$1\"\n", line)).unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    script
+}
+
+#[test]
+fn backups_create_list_and_restore() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("rabbitmq.conf");
+    assert_eq!(etc_backups::create(&file).unwrap(), None);
+
+    fs::write(&file, "heartbeat = 10\n").unwrap();
+    let first = etc_backups::create(&file).unwrap().unwrap();
+    fs::write(&file, "heartbeat = 20\n").unwrap();
+    let second = etc_backups::create(&file).unwrap().unwrap();
+    fs::write(&file, "heartbeat = 30\n").unwrap();
+
+    assert!(first.created_at < second.created_at);
+    assert!(
+        first
+            .path
+            .starts_with(temp.path().join(etc_backups::BACKUPS_DIR_NAME))
+    );
+    assert_eq!(
+        etc_backups::list(&file).unwrap(),
+        vec![first.clone(), second]
+    );
+    // backups of other files are not listed
+    assert!(
+        etc_backups::list(&temp.path().join("advanced.config"))
+            .unwrap()
+            .is_empty()
+    );
+
+    let replaced = etc_backups::revert(&file, &first).unwrap().unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "heartbeat = 10\n");
+    assert_eq!(
+        fs::read_to_string(&replaced.path).unwrap(),
+        "heartbeat = 30\n"
+    );
+    assert_eq!(etc_backups::list(&file).unwrap().len(), 3);
+}
+
+#[cfg(unix)]
+#[test]
+fn conf_edit_replaces_the_file_and_keeps_a_backup() {
+    let (temp, conf_path) = setup("heartbeat = 60\n");
+    let editor = appending_editor(temp.path(), "channel_max = 128");
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", &editor)
+        .args(["conf", "edit", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm conf revert -V 4.2.3"));
+
+    assert_eq!(
+        fs::read_to_string(&conf_path).unwrap(),
+        "heartbeat = 60\nchannel_max = 128\n"
+    );
+    assert_eq!(etc_backups::list(&conf_path).unwrap().len(), 1);
+
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "rollback", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Restored rabbitmq.conf"));
+    assert_eq!(fs::read_to_string(&conf_path).unwrap(), "heartbeat = 60\n");
+
+    // the rolled back file was backed up, so rolling back again restores the edit
+    frm_cmd_with_dir(&temp)
+        .args(["conf", "rollback", "-V", "4.2.3"])
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&conf_path).unwrap(),
+        "heartbeat = 60\nchannel_max = 128\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn conf_edit_rejects_unknown_keys() {
+    let (temp, conf_path) = setup("heartbeat = 60\n");
+    let editor = appending_editor(temp.path(), "hearbeat = 30");
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", &editor)
+        .args(["conf", "edit", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "unknown configuration key: hearbeat",
+        ))
+        .stderr(predicate::str::contains("rabbitmq.conf was not changed"));
+    assert_eq!(fs::read_to_string(&conf_path).unwrap(), "heartbeat = 60\n");
+    assert!(etc_backups::list(&conf_path).unwrap().is_empty());
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", &editor)
+        .args(["conf", "edit", "-V", "4.2.3", "--force"])
+        .assert()
+        .success();
+    assert!(
+        fs::read_to_string(&conf_path)
+            .unwrap()
+            .contains("hearbeat = 30")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn conf_edit_rejects_syntax_errors() {
+    let (temp, conf_path) = setup("heartbeat = 60\n");
+    let editor = appending_editor(temp.path(), "this is not a setting");
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", &editor)
+        .args(["conf", "edit", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("rabbitmq.conf was not changed"));
+    assert_eq!(fs::read_to_string(&conf_path).unwrap(), "heartbeat = 60\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn conf_edit_without_changes() {
+    let (temp, conf_path) = setup("heartbeat = 60\n");
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", "true")
+        .args(["conf", "edit", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No changes made"));
+    assert!(etc_backups::list(&conf_path).unwrap().is_empty());
+}
+
+#[test]
+fn conf_edit_requires_installed_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .env("EDITOR", "true")
+        .args(["conf", "edit", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
@@ -0,0 +1,39 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use serde_json::{Value, json};
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+#[test]
+fn conf_schema_dump_prints_key_patterns_as_json() {
+    // no version has to be installed
+    let temp = TempDir::new().unwrap();
+    let output = frm_cmd_with_dir(&temp)
+        .args(["conf", "schema", "dump"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let schema: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["format_version"], 1);
+    assert_eq!(schema["wildcard"], "*");
+    let keys = schema["keys"].as_array().unwrap();
+    assert!(
+        keys.contains(&json!({"pattern": "listeners.tcp.*", "example": "listeners.tcp.default"}))
+    );
+    assert!(keys.contains(&json!({"pattern": "heartbeat"})));
+    assert!(keys.contains(&json!({"pattern": "default_users.*.password"})));
+}
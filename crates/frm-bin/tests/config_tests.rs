@@ -10,6 +10,7 @@ use std::fs;
 
 use tempfile::TempDir;
 
+use frm::common::prompt::is_affirmative;
 use frm::config::Config;
 use frm::paths::Paths;
 use frm::version::{Prerelease, Version};
@@ -47,6 +48,27 @@ fn config_save_and_load() {
     assert_eq!(loaded.default_version, Some(version));
 }
 
+#[test]
+fn config_loads_legacy_default_version() {
+    let (temp, paths) = setup_temp_paths();
+    fs::write(
+        temp.path().join("config.toml"),
+        "[default_version]\nmajor = 4\nminor = 3\npatch = 0\n\n[default_version.prerelease]\nAlpha = \"132057c7\"\n",
+    )
+    .unwrap();
+
+    let config = Config::load(&paths).unwrap();
+    assert_eq!(
+        config.default_version,
+        Some(Version::with_prerelease(
+            4,
+            3,
+            0,
+            Prerelease::alpha("132057c7")
+        ))
+    );
+}
+
 #[test]
 fn config_set_default() {
     let mut config = Config::default();
@@ -66,6 +88,40 @@ fn config_clear_default() {
     assert!(config.default_version.is_none());
 }
 
+#[test]
+fn config_confirm_destructive_defaults_to_true() {
+    let (temp, paths) = setup_temp_paths();
+    fs::create_dir_all(temp.path()).unwrap();
+
+    assert!(Config::default().confirm_destructive);
+
+    fs::write(paths.config_file(), "record_history = true\n").unwrap();
+    assert!(Config::load(&paths).unwrap().confirm_destructive);
+
+    fs::write(paths.config_file(), "confirm_destructive = false\n").unwrap();
+    assert!(!Config::load(&paths).unwrap().confirm_destructive);
+}
+
+#[test]
+fn config_confirm_destructive_omitted_when_default() {
+    let (temp, paths) = setup_temp_paths();
+    fs::create_dir_all(temp.path()).unwrap();
+
+    Config::default().save(&paths).unwrap();
+    let content = fs::read_to_string(paths.config_file()).unwrap();
+    assert!(!content.contains("confirm_destructive"));
+}
+
+#[test]
+fn prompt_is_affirmative() {
+    assert!(is_affirmative("y"));
+    assert!(is_affirmative("Yes\n"));
+    assert!(is_affirmative("  Y  "));
+    assert!(!is_affirmative(""));
+    assert!(!is_affirmative("n"));
+    assert!(!is_affirmative("yep"));
+}
+
 #[test]
 fn config_serialization_format() {
     let (temp, paths) = setup_temp_paths();
@@ -77,10 +133,7 @@ fn config_serialization_format() {
     config.save(&paths).unwrap();
 
     let content = fs::read_to_string(paths.config_file()).unwrap();
-    assert!(content.contains("default_version"));
-    assert!(content.contains("major = 4"));
-    assert!(content.contains("minor = 2"));
-    assert!(content.contains("patch = 3"));
+    assert!(content.contains("default_version = \"4.2.3\""));
 }
 
 #[test]
@@ -97,7 +150,7 @@ fn config_prerelease_save_and_load() {
     assert_eq!(loaded.default_version, Some(version));
 
     let content = fs::read_to_string(paths.config_file()).unwrap();
-    assert!(content.contains("Alpha"));
+    assert!(content.contains("default_version = \"4.2.4-alpha.2\""));
 }
 
 #[test]
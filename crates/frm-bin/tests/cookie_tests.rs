@@ -0,0 +1,258 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::cookie::{self, CookieSource};
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn cookie_validate() {
+    assert!(cookie::validate("ABCDEFGHIJKLMNOPQRST").is_ok());
+    assert!(cookie::validate("secret-cookie_42").is_ok());
+    assert!(cookie::validate("").is_err());
+    assert!(cookie::validate("with space").is_err());
+    assert!(cookie::validate(&"A".repeat(256)).is_err());
+}
+
+#[test]
+fn cookie_generate() {
+    let generated = cookie::generate();
+    assert_eq!(generated.len(), 20);
+    assert!(generated.chars().all(|c| c.is_ascii_uppercase()));
+    assert!(cookie::validate(&generated).is_ok());
+}
+
+#[test]
+fn cookie_version_takes_precedence() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    assert_eq!(cookie::effective(&paths, &version).unwrap(), None);
+    assert!(cookie::env_vars(&paths, &version).unwrap().is_empty());
+
+    cookie::write(&paths.shared_cookie_file(), "SHARED").unwrap();
+    let effective = cookie::effective(&paths, &version).unwrap().unwrap();
+    assert_eq!(effective.value, "SHARED");
+    assert_eq!(effective.source, CookieSource::Shared);
+
+    // trailing newlines, e.g. from editors, are ignored
+    fs::write(paths.version_cookie_file(&version), "OWN\n").unwrap();
+    let effective = cookie::effective(&paths, &version).unwrap().unwrap();
+    assert_eq!(effective.value, "OWN");
+    assert_eq!(effective.source, CookieSource::Version);
+    assert_eq!(
+        cookie::env_vars(&paths, &version).unwrap(),
+        vec![("RABBITMQ_ERLANG_COOKIE", "OWN".to_string())]
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn cookie_write_restricts_permissions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("erlang.cookie");
+    cookie::write(&path, "SECRET").unwrap();
+    assert_eq!(
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn cookie_write_replaces_a_readable_cookie() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("erlang.cookie");
+    fs::write(&path, "OLD").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+    cookie::write(&path, "NEW").unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "NEW");
+    assert_eq!(
+        fs::metadata(&path).unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+    assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn cli_cookie_set_and_show() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No frm-managed cookie"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "SHAREDCOOKIE"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Set the shared Erlang cookie"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "VERSIONCOOKIE", "-V", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("SHAREDCOOKIE"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "show", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("VERSIONCOOKIE"))
+        .stdout(predicate::str::contains("version-specific cookie"));
+}
+
+#[test]
+fn cli_cookie_set_generate() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "--generate"])
+        .assert()
+        .success();
+    assert!(cookie::shared(&paths).unwrap().is_some());
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set"])
+        .assert()
+        .failure();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "has space"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid Erlang cookie"));
+}
+
+#[test]
+fn cli_cookie_set_for_missing_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "COOKIE", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_cookie_passed_to_cli_tools() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let ctl = paths.version_sbin_dir(&version).join("rabbitmqctl");
+    fs::write(&ctl, "#!/bin/sh\necho \"cookie=$RABBITMQ_ERLANG_COOKIE\"\n").unwrap();
+    fs::set_permissions(&ctl, fs::Permissions::from_mode(0o755)).unwrap();
+    cookie::write(&paths.shared_cookie_file(), "SHAREDCOOKIE").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .env_remove("RABBITMQ_ERLANG_COOKIE")
+        .args(["cli", "rabbitmqctl", "-V", "4.2.3", "--", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cookie=SHAREDCOOKIE"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_cookie_sync() {
+    let temp = TempDir::new().unwrap();
+    let home = TempDir::new().unwrap();
+    let home_cookie = home.path().join(".erlang.cookie");
+
+    frm_cmd_with_dir(&temp)
+        .env("HOME", home.path())
+        .args(["cookie", "sync"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("frm cookie set"));
+
+    cookie::write(&home_cookie, "HOMECOOKIE").unwrap();
+    frm_cmd_with_dir(&temp)
+        .env("HOME", home.path())
+        .args(["cookie", "sync", "--from-home"])
+        .assert()
+        .success();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    assert_eq!(cookie::shared(&paths).unwrap().unwrap().value, "HOMECOOKIE");
+
+    frm_cmd_with_dir(&temp)
+        .env("HOME", home.path())
+        .args(["cookie", "sync"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already in sync"));
+
+    cookie::write(&paths.shared_cookie_file(), "NEWCOOKIE").unwrap();
+    frm_cmd_with_dir(&temp)
+        .env("HOME", home.path())
+        .args(["cookie", "sync"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Backed up"));
+    assert_eq!(fs::read_to_string(&home_cookie).unwrap(), "NEWCOOKIE");
+    assert_eq!(
+        fs::read_to_string(home.path().join(".erlang.cookie.frm-backup")).unwrap(),
+        "HOMECOOKIE"
+    );
+}
+
+#[test]
+fn cli_cookie_set_is_redacted_in_history() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("config.toml"), "record_history = true\n").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cookie", "set", "SECRETCOOKIEVALUE"])
+        .assert()
+        .success();
+
+    let history = fs::read_to_string(temp.path().join("history.jsonl")).unwrap();
+    assert!(history.contains("cookie set"));
+    assert!(!history.contains("SECRETCOOKIEVALUE"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["history"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm cookie set <redacted>"));
+}
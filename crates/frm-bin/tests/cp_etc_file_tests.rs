@@ -837,3 +837,43 @@ fn cli_releases_cp_etc_file_with_beta_version() {
         .join("rabbitmq.conf");
     assert!(dest_file.exists());
 }
+
+#[test]
+fn cli_releases_cp_etc_file_rejects_invalid_erlang_terms() {
+    let temp = TempDir::new().unwrap();
+    let src_file = temp.path().join("advanced.config");
+    fs::write(&src_file, "[\n  {rabbit, [{channel_max, 128}}\n].\n").unwrap();
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    fs::create_dir_all(&version_dir).unwrap();
+    let dest_file = version_dir
+        .join("etc")
+        .join("rabbitmq")
+        .join("advanced.config");
+    let args = [
+        "releases",
+        "cp-etc-file",
+        "--local-file-path",
+        src_file.to_str().unwrap(),
+        "--etc-file",
+        "advanced.config",
+        "-V",
+        "4.2.3",
+    ];
+
+    frm_cmd_with_dir(&temp)
+        .args(args)
+        .assert()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "'}' does not match '[' at line 2, column 12",
+        ));
+    assert!(!dest_file.exists());
+
+    frm_cmd_with_dir(&temp)
+        .args(args)
+        .arg("--force")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("copying anyway"));
+    assert!(dest_file.exists());
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::current;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn current_update_replaces_the_link() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let (v1, v2) = (Version::new(4, 1, 0), Version::new(4, 2, 3));
+    install(&paths, &v1);
+    install(&paths, &v2);
+
+    assert_eq!(current::target(&paths), None);
+    current::update(&paths, &v1).unwrap();
+    assert_eq!(current::target(&paths), Some(paths.version_dir(&v1)));
+    current::update(&paths, &v2).unwrap();
+    assert_eq!(current::target(&paths), Some(paths.version_dir(&v2)));
+    assert!(
+        paths
+            .current_link()
+            .join("sbin")
+            .join("rabbitmq-server")
+            .is_file()
+    );
+}
+
+#[test]
+fn current_update_does_not_replace_a_directory() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    fs::create_dir_all(paths.current_link()).unwrap();
+
+    let err = current::update(&paths, &version).unwrap_err();
+    assert!(err.to_string().contains("is not a symlink"));
+    assert!(paths.current_link().is_dir());
+}
+
+#[test]
+fn current_remove_removes_only_the_link() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    current::remove(&paths).unwrap();
+    current::update(&paths, &version).unwrap();
+    current::remove(&paths).unwrap();
+    assert_eq!(current::target(&paths), None);
+    assert!(paths.version_installed(&version));
+
+    fs::create_dir_all(paths.current_link()).unwrap();
+    current::remove(&paths).unwrap();
+    assert!(paths.current_link().is_dir());
+}
+
+#[test]
+fn cli_default_points_current_at_the_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 1, 0));
+    install(&paths, &Version::new(4, 2, 3));
+
+    for version in ["4.1.0", "4.2.3"] {
+        frm_cmd_with_dir(&temp)
+            .args(["default", version])
+            .assert()
+            .success();
+    }
+    assert_eq!(
+        current::target(&paths),
+        Some(paths.version_dir(&Version::new(4, 2, 3)))
+    );
+}
+
+#[test]
+fn cli_default_warns_when_current_cannot_be_updated() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+    fs::create_dir_all(paths.current_link()).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default version set to 4.2.3"))
+        .stdout(predicate::str::contains("is not a symlink"));
+}
+
+#[test]
+fn cli_uninstall_of_the_default_removes_current() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "--yes", "4.2.3"])
+        .assert()
+        .success();
+    assert_eq!(current::target(&paths), None);
+    assert!(fs::symlink_metadata(paths.current_link()).is_err());
+}
+
+#[test]
+fn cli_releases_use_link() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash", "--link"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export RABBITMQ_HOME="))
+        .stdout(predicate::str::contains("current").not())
+        .stderr(predicate::str::contains("now points at RabbitMQ 4.2.3"));
+    assert_eq!(current::target(&paths), Some(paths.version_dir(&version)));
+}
+
+#[test]
+fn cli_releases_use_leaves_current_alone_without_link() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash"])
+        .assert()
+        .success();
+    assert_eq!(current::target(&paths), None);
+}
+
+#[test]
+fn cli_alphas_use_link() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    install(&paths, &version);
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "alphas",
+            "use",
+            "4.3.0-alpha.132057c7",
+            "--shell",
+            "bash",
+            "--link",
+        ])
+        .assert()
+        .success();
+    assert_eq!(current::target(&paths), Some(paths.version_dir(&version)));
+}
+
+#[test]
+fn cli_releases_use_link_is_refused_in_read_only_mode() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .env("FRM_READ_ONLY", "1")
+        .args(["releases", "use", "4.2.3", "--shell", "bash", "--link"])
+        .assert()
+        .code(74);
+    frm_cmd_with_dir(&temp)
+        .env("FRM_READ_ONLY", "1")
+        .args(["releases", "use", "4.2.3", "--shell", "bash"])
+        .assert()
+        .success();
+}
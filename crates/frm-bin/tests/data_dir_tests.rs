@@ -0,0 +1,123 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use frm::common::env_vars::RABBITMQ_MNESIA_BASE;
+use frm::envs;
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn cli_data_dir_set_and_reset() {
+    let temp = TempDir::new().unwrap();
+    let volume = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let dir = volume.path().join("rabbitmq").join("data");
+
+    frm_cmd_with_dir(&temp)
+        .args(["data-dir", "set", dir.to_str().unwrap(), "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("now keeps its node data in"));
+
+    let dir = dir.canonicalize().unwrap();
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert_eq!(metadata.data_dir.as_deref(), Some(dir.as_path()));
+    assert_eq!(envs::data_dir(&paths, &version, None).unwrap(), dir);
+
+    frm_cmd_with_dir(&temp)
+        .args(["data-dir", "show", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(dir.to_str().unwrap()));
+
+    let output = frm_cmd_with_dir(&temp)
+        .args(["paths", "show", "-V", "4.2.3", "--format", "json"])
+        .output()
+        .unwrap();
+    let layout: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(layout["data"], Value::from(dir.display().to_string()));
+
+    frm_cmd_with_dir(&temp)
+        .args(["data-dir", "reset", "-V", "4.2.3"])
+        .assert()
+        .success();
+    assert_eq!(
+        VersionMetadata::load(&paths, &version).unwrap().data_dir,
+        None
+    );
+    assert_eq!(
+        envs::data_dir(&paths, &version, None).unwrap(),
+        paths.version_data_dir(&version)
+    );
+}
+
+#[test]
+fn node_env_vars_use_the_data_dir_override() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    let vars = envs::node_env_vars(&paths, &version, None).unwrap();
+    assert!(vars.iter().all(|(name, _)| *name != RABBITMQ_MNESIA_BASE));
+
+    let dir = temp.path().join("elsewhere");
+    VersionMetadata::update(&paths, &version, |m| m.data_dir = Some(dir.clone())).unwrap();
+    let vars = envs::node_env_vars(&paths, &version, None).unwrap();
+    assert!(vars.contains(&(RABBITMQ_MNESIA_BASE, dir)));
+}
+
+#[test]
+fn cli_data_dir_requires_an_installed_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["data-dir", "set", "/tmp/frm-data", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_bg_start_data_dir_conflicts_with_env() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "bg",
+            "start",
+            "-V",
+            "4.2.3",
+            "--env",
+            "tls",
+            "--data-dir",
+            "/tmp/frm-data",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
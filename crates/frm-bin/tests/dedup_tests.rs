@@ -0,0 +1,189 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::dedup::{self, DedupReport};
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+const BEAM: &str = "a shared compiled module that is the same in both patch releases";
+
+fn install_fake_version(paths: &Paths, version: &Version, changed: &str) {
+    let dir = paths.version_dir(version);
+    let sbin = dir.join("sbin");
+    let ebin = dir.join("plugins").join("rabbit").join("ebin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::create_dir_all(&ebin).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(version)).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    fs::write(ebin.join("rabbit_shared.beam"), BEAM).unwrap();
+    fs::write(ebin.join("rabbit_changed.beam"), changed).unwrap();
+    fs::write(
+        paths.version_etc_dir(version).join("rabbitmq.conf"),
+        "listeners.tcp.default = 5672\n",
+    )
+    .unwrap();
+}
+
+fn inode(path: &Path) -> u64 {
+    fs::metadata(path).unwrap().ino()
+}
+
+fn setup() -> (TempDir, Paths, Version, Version) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let v1 = Version::new(4, 2, 2);
+    let v2 = Version::new(4, 2, 3);
+    install_fake_version(&paths, &v1, "4.2.2");
+    install_fake_version(&paths, &v2, "4.2.3");
+    (temp, paths, v1, v2)
+}
+
+#[test]
+fn deduplicate_links_identical_files() {
+    let (_temp, paths, v1, v2) = setup();
+    let dirs = [paths.version_dir(&v1), paths.version_dir(&v2)];
+
+    let report = dedup::deduplicate(&dirs, false).unwrap();
+    // the server script and the shared module
+    assert_eq!(report.files_linked, 2);
+    assert_eq!(
+        report.bytes_saved,
+        (BEAM.len() + "#!/bin/sh\n".len()) as u64
+    );
+
+    let shared = |v: &Version| {
+        paths
+            .version_dir(v)
+            .join("plugins/rabbit/ebin/rabbit_shared.beam")
+    };
+    let changed = |v: &Version| {
+        paths
+            .version_dir(v)
+            .join("plugins/rabbit/ebin/rabbit_changed.beam")
+    };
+    assert_eq!(inode(&shared(&v1)), inode(&shared(&v2)));
+    assert_ne!(inode(&changed(&v1)), inode(&changed(&v2)));
+    assert_eq!(fs::read_to_string(shared(&v2)).unwrap(), BEAM);
+
+    // running again finds nothing new
+    assert_eq!(
+        dedup::deduplicate(&dirs, false).unwrap(),
+        DedupReport::default()
+    );
+}
+
+#[test]
+fn deduplicate_skips_mutable_dirs() {
+    let (_temp, paths, v1, v2) = setup();
+    let dirs = [paths.version_dir(&v1), paths.version_dir(&v2)];
+    dedup::deduplicate(&dirs, false).unwrap();
+
+    let conf = |v: &Version| paths.version_etc_dir(v).join("rabbitmq.conf");
+    assert_ne!(inode(&conf(&v1)), inode(&conf(&v2)));
+}
+
+#[test]
+fn deduplicate_dry_run_changes_nothing() {
+    let (_temp, paths, v1, v2) = setup();
+    let dirs = [paths.version_dir(&v1), paths.version_dir(&v2)];
+
+    let report = dedup::deduplicate(&dirs, true).unwrap();
+    assert_eq!(report.files_linked, 2);
+
+    let script = |v: &Version| paths.version_server_script(v);
+    assert_ne!(inode(&script(&v1)), inode(&script(&v2)));
+}
+
+#[test]
+fn disk_usage_counts_hard_links_once() {
+    let (_temp, paths, v1, v2) = setup();
+    let dirs = [paths.version_dir(&v1), paths.version_dir(&v2)];
+
+    let before = dedup::disk_usage(&dirs).unwrap();
+    assert_eq!(before.apparent, before.on_disk);
+    assert_eq!(before.shared(), 0);
+
+    let report = dedup::deduplicate(&dirs, false).unwrap();
+    let after = dedup::disk_usage(&dirs).unwrap();
+    assert_eq!(after.apparent, before.apparent);
+    assert_eq!(after.shared(), report.bytes_saved);
+}
+
+#[test]
+fn cli_du_with_dedup_savings() {
+    let (temp, _paths, _v1, _v2) = setup();
+
+    frm_cmd_with_dir(&temp)
+        .args(["du", "--dedup-savings"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.2"))
+        .stdout(predicate::str::contains("4.2.3"))
+        .stdout(predicate::str::contains("on disk"))
+        .stdout(predicate::str::contains("Deduplication would save"))
+        .stdout(predicate::str::contains("2 file(s)"));
+}
+
+#[test]
+fn cli_dedup() {
+    let (temp, paths, v1, v2) = setup();
+
+    frm_cmd_with_dir(&temp)
+        .args(["dedup", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would hard-link 2 file(s)"));
+    assert_ne!(
+        inode(&paths.version_server_script(&v1)),
+        inode(&paths.version_server_script(&v2))
+    );
+
+    frm_cmd_with_dir(&temp)
+        .arg("dedup")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hard-linked 2 file(s)"));
+    assert_eq!(
+        inode(&paths.version_server_script(&v1)),
+        inode(&paths.version_server_script(&v2))
+    );
+
+    frm_cmd_with_dir(&temp)
+        .arg("dedup")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No duplicate files"));
+}
+
+#[test]
+fn cli_du_without_versions() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .arg("du")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No versions installed"));
+}
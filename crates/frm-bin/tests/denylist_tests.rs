@@ -0,0 +1,207 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::VersionRow;
+use frm::denylist::Denylist;
+use frm::feed_cache::CachedFeed;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+const DENYLIST: &str = r#"
+[[release]]
+version = "4.1.0"
+reason = "quorum queue leader elections can stall"
+"#;
+
+const TEAM_DENYLIST_URL: &str = "https://example.com/frm-denylist.toml";
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd.env_remove("RABBITMQ_HOME");
+    cmd.env_remove("FRM_ACTIVE_SBIN");
+    cmd
+}
+
+fn paths_for(dir: &TempDir) -> Paths {
+    Paths::with_base_dir(dir.path().to_path_buf())
+}
+
+#[test]
+fn parse_and_find() {
+    let denylist = Denylist::parse(DENYLIST).unwrap();
+    let denied = denylist.find(&Version::new(4, 1, 0)).unwrap();
+    assert_eq!(denied.reason, "quorum queue leader elections can stall");
+    assert!(denylist.find(&Version::new(4, 1, 1)).is_none());
+
+    assert_eq!(Denylist::parse("").unwrap(), Denylist::default());
+    assert!(Denylist::parse("[[release]]\nversion = \"4.1.0\"").is_err());
+}
+
+#[test]
+fn load_cached_merges_local_and_fetched_lists() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    fs::write(paths.denylist_file(), DENYLIST).unwrap();
+    fs::write(
+        paths.config_file(),
+        format!("denylist_url = \"{}\"\n", TEAM_DENYLIST_URL),
+    )
+    .unwrap();
+    CachedFeed {
+        url: TEAM_DENYLIST_URL.to_string(),
+        etag: None,
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        body: "[[release]]\nversion = \"4.0.5\"\nreason = \"memory leak\"\n".to_string(),
+    }
+    .save(&paths.denylist_cache_file())
+    .unwrap();
+
+    let denylist = Denylist::load_cached(&paths).unwrap();
+    assert!(denylist.find(&Version::new(4, 1, 0)).is_some());
+    assert_eq!(
+        denylist.find(&Version::new(4, 0, 5)).unwrap().reason,
+        "memory leak"
+    );
+}
+
+#[test]
+fn load_cached_ignores_a_list_fetched_from_another_url() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    CachedFeed {
+        url: "https://example.com/old.toml".to_string(),
+        etag: None,
+        fetched_at: 0,
+        body: "[[release]]\nversion = \"4.0.5\"\nreason = \"memory leak\"\n".to_string(),
+    }
+    .save(&paths.denylist_cache_file())
+    .unwrap();
+
+    assert_eq!(Denylist::load_cached(&paths).unwrap(), Denylist::default());
+}
+
+#[test]
+fn version_row_flags_denylisted_versions() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    let version = Version::new(4, 1, 0);
+    install(&paths, &version);
+
+    let denylist = Denylist::parse(DENYLIST).unwrap();
+    let row = VersionRow::collect(&paths, &version, None, None, &denylist).unwrap();
+    assert_eq!(row.flags, "denylisted");
+}
+
+#[test]
+fn cli_install_refuses_denylisted_version() {
+    let temp = TempDir::new().unwrap();
+    fs::write(paths_for(&temp).denylist_file(), DENYLIST).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "install", "4.1.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("RabbitMQ 4.1.0 is denylisted"))
+        .stderr(predicate::str::contains("quorum queue leader elections"))
+        .stderr(predicate::str::contains("--allow-denylisted"));
+
+    assert!(
+        !paths_for(&temp)
+            .version_dir(&Version::new(4, 1, 0))
+            .exists()
+    );
+}
+
+#[test]
+fn cli_install_force_does_not_allow_denylisted_version() {
+    let temp = TempDir::new().unwrap();
+    fs::write(paths_for(&temp).denylist_file(), DENYLIST).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "install", "4.1.0", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("RabbitMQ 4.1.0 is denylisted"));
+}
+
+#[test]
+fn cli_install_allow_denylisted_warns_and_continues() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    fs::write(paths.denylist_file(), DENYLIST).unwrap();
+    install(&paths, &Version::new(4, 1, 0));
+
+    // the installation goes past the denylist check and stops at the existing version
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "install", "4.1.0", "--allow-denylisted"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "RabbitMQ 4.1.0 is denylisted: quorum queue leader elections can stall",
+        ))
+        .stderr(predicate::str::contains(
+            "version 4.1.0 is already installed",
+        ));
+}
+
+#[test]
+fn cli_install_continues_when_denylist_cannot_be_loaded() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    fs::write(paths.denylist_file(), "[[release]]\nversion = \"4.1.0\"").unwrap();
+    install(&paths, &Version::new(4, 1, 0));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "install", "4.1.0"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Could not load the denylist"))
+        .stderr(predicate::str::contains(
+            "version 4.1.0 is already installed",
+        ));
+}
+
+#[test]
+fn cli_status_flags_installed_denylisted_versions() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    fs::write(paths.denylist_file(), DENYLIST).unwrap();
+    install(&paths, &Version::new(4, 1, 0));
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["status", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "RabbitMQ 4.1.0 is denylisted: quorum queue leader elections can stall",
+        ))
+        .stdout(predicate::str::contains("4.2.3 is denylisted").not());
+
+    frm_cmd_with_dir(&temp)
+        .arg("status")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("denylisted"));
+}
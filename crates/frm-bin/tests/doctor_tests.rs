@@ -0,0 +1,434 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::env;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::{PermissionsExt, chown};
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::{PackageManager, Problem, Status, diagnose, shadowing_installations};
+use frm::ownership;
+use frm::paths::Paths;
+use frm::permissions;
+use frm::version::Version;
+
+use common::install;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[cfg(unix)]
+fn set_mode(path: &Path, mode: u32) {
+    fs::set_permissions(path, fs::Permissions::from_mode(mode)).unwrap();
+}
+
+#[cfg(not(unix))]
+fn set_mode(_path: &Path, _mode: u32) {}
+
+#[cfg(unix)]
+fn mode(path: &Path) -> u32 {
+    fs::metadata(path).unwrap().permissions().mode() & 0o777
+}
+
+fn install_broken(paths: &Paths, version: &Version) {
+    fs::create_dir_all(paths.version_etc_dir(version)).unwrap();
+}
+
+#[test]
+fn paths_version_broken() {
+    let (_temp, paths) = setup_temp_paths();
+    let healthy = Version::new(4, 2, 3);
+    let broken = Version::new(4, 1, 0);
+    install(&paths, &healthy);
+    install_broken(&paths, &broken);
+
+    assert!(!paths.version_broken(&healthy));
+    assert!(paths.version_broken(&broken));
+    assert!(!paths.version_broken(&Version::new(4, 0, 0)));
+}
+
+#[test]
+fn diagnose_healthy_installation() {
+    let (_temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+
+    assert!(diagnose(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn diagnose_finds_problems() {
+    let (_temp, paths) = setup_temp_paths();
+    let broken = Version::new(4, 1, 0);
+    install_broken(&paths, &broken);
+    let leftover = paths.version_extraction_dir(&Version::new(4, 2, 3));
+    fs::create_dir_all(&leftover).unwrap();
+    fs::write(
+        paths.config_file(),
+        "[default_version]\nmajor = 3\nminor = 13\npatch = 7\n",
+    )
+    .unwrap();
+
+    let problems = diagnose(&paths).unwrap();
+    assert_eq!(
+        problems,
+        vec![
+            Problem::BrokenVersion(broken),
+            Problem::LeftoverDirectory(leftover),
+            Problem::DefaultNotInstalled(Version::new(3, 13, 7)),
+        ]
+    );
+}
+
+#[test]
+fn diagnose_finds_classic_config_taking_precedence() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let etc_dir = paths.version_etc_dir(&version);
+    fs::create_dir_all(&etc_dir).unwrap();
+    fs::write(etc_dir.join("rabbitmq.conf"), "heartbeat = 30\n").unwrap();
+    assert!(diagnose(&paths).unwrap().is_empty());
+
+    fs::write(etc_dir.join("rabbitmq.config"), "[].\n").unwrap();
+    assert_eq!(
+        diagnose(&paths).unwrap(),
+        vec![Problem::ClassicConfigTakesPrecedence(version)]
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "RabbitMQ 4.2.3 has both rabbitmq.conf and the classic rabbitmq.config",
+        ));
+}
+
+#[test]
+fn status_marks_broken_versions() {
+    let (_temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+    install_broken(&paths, &Version::new(4, 1, 0));
+
+    let status = Status::collect(&paths).unwrap();
+    assert_eq!(status.broken, vec![Version::new(4, 1, 0)]);
+
+    let output = status.format();
+    assert!(output.contains("4.1.0 (broken)"));
+    assert!(!output.contains("4.2.3 (broken)"));
+}
+
+#[test]
+fn cli_doctor_no_problems() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["doctor"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No problems found"));
+}
+
+#[test]
+fn cli_doctor_reports_broken_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install_broken(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("RabbitMQ 4.2.3 is broken"))
+        .stdout(predicate::str::contains("frm repair 4.2.3"))
+        .stderr(predicate::str::contains("found 1 problem(s)"));
+}
+
+#[test]
+fn cli_list_marks_broken_versions() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+    install_broken(&paths, &Version::new(4, 1, 0));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.1.0 (broken)"))
+        .stdout(predicate::str::contains("4.2.3 (broken)").not());
+}
+
+#[test]
+fn cli_use_warns_about_broken_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install_broken(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("RabbitMQ 4.2.3 is broken"))
+        .stdout(predicate::str::contains("Warning").not());
+}
+
+#[test]
+fn cli_repair_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["repair", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_repair_requires_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["repair"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no version specified"));
+}
+
+#[cfg(unix)]
+#[test]
+fn permissions_fix_restores_executable_bit() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let version_dir = paths.version_dir(&version);
+    let escript = version_dir.join("escript");
+    fs::create_dir_all(&escript).unwrap();
+    fs::write(escript.join("rabbitmqctl"), "").unwrap();
+    set_mode(&escript.join("rabbitmqctl"), 0o644);
+    set_mode(&paths.version_server_script(&version), 0o600);
+
+    let expected = vec![
+        escript.join("rabbitmqctl"),
+        paths.version_server_script(&version),
+    ];
+    assert_eq!(
+        permissions::non_executable_files(&version_dir).unwrap(),
+        expected
+    );
+    assert_eq!(permissions::fix(&version_dir).unwrap(), expected);
+
+    assert_eq!(mode(&escript.join("rabbitmqctl")), 0o755);
+    assert_eq!(mode(&paths.version_server_script(&version)), 0o700);
+    assert!(
+        permissions::non_executable_files(&version_dir)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_doctor_reports_and_repair_fixes_non_executable_scripts() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    set_mode(&paths.version_server_script(&version), 0o644);
+
+    frm_cmd_with_dir(&temp)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "RabbitMQ 4.2.3 has scripts that are not executable: rabbitmq-server",
+        ))
+        .stdout(predicate::str::contains("frm repair 4.2.3"));
+
+    // fixed in place, without downloading the version again
+    frm_cmd_with_dir(&temp)
+        .args(["repair", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Restored the executable bit on 1 file(s)",
+        ))
+        .stdout(predicate::str::contains("Downloading").not());
+
+    assert_eq!(mode(&paths.version_server_script(&version)), 0o755);
+    frm_cmd_with_dir(&temp).args(["doctor"]).assert().success();
+}
+
+#[test]
+fn shadowing_installations_come_before_the_frm_sbin_directory() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    let system_sbin = temp.path().join("system").join("sbin");
+    fs::create_dir_all(&system_sbin).unwrap();
+    fs::write(system_sbin.join("rabbitmqctl"), "").unwrap();
+    let empty = temp.path().join("empty");
+    fs::create_dir_all(&empty).unwrap();
+
+    let path_var = env::join_paths([
+        empty.clone(),
+        system_sbin.clone(),
+        paths.version_sbin_dir(&version),
+    ])
+    .unwrap();
+    let problems = shadowing_installations(&paths, &path_var);
+    assert_eq!(
+        problems,
+        [Problem::ShadowingInstallation {
+            path: system_sbin.join("rabbitmqctl"),
+            package_manager: None,
+            shadowed: version.clone(),
+        }]
+    );
+    let message = problems[0].to_string();
+    assert!(message.contains("comes before RabbitMQ 4.2.3 in PATH"));
+    assert!(message.ends_with("or remove it"));
+
+    // later in PATH than the frm directory, or without a frm directory at all
+    let path_var =
+        env::join_paths([paths.version_sbin_dir(&version), system_sbin.clone()]).unwrap();
+    assert!(shadowing_installations(&paths, &path_var).is_empty());
+    let path_var = env::join_paths([system_sbin]).unwrap();
+    assert!(shadowing_installations(&paths, &path_var).is_empty());
+}
+
+#[test]
+fn package_managers_of_shadowing_installations() {
+    let of = |path: &str| PackageManager::of(Path::new(path));
+    assert_eq!(
+        of("/opt/homebrew/sbin/rabbitmqctl"),
+        Some(PackageManager::Homebrew)
+    );
+    assert_eq!(
+        of("/usr/local/Cellar/rabbitmq/4.1.0/sbin/rabbitmqctl"),
+        Some(PackageManager::Homebrew)
+    );
+    assert_eq!(
+        of("/opt/local/sbin/rabbitmqctl"),
+        Some(PackageManager::MacPorts)
+    );
+    assert_eq!(of("/usr/sbin/rabbitmqctl"), Some(PackageManager::System));
+    assert_eq!(of("/srv/tools/rabbitmqctl"), None);
+
+    let problem = Problem::ShadowingInstallation {
+        path: "/opt/homebrew/sbin/rabbitmqctl".into(),
+        package_manager: Some(PackageManager::Homebrew),
+        shadowed: Version::new(4, 2, 3),
+    };
+    let message = problem.to_string();
+    assert!(message.starts_with("/opt/homebrew/sbin/rabbitmqctl (installed with Homebrew)"));
+    assert!(message.ends_with("or remove it with: brew uninstall rabbitmq"));
+}
+
+#[test]
+fn cli_doctor_reports_shadowing_installations() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let system_sbin = temp.path().join("system");
+    fs::create_dir_all(&system_sbin).unwrap();
+    fs::write(system_sbin.join("rabbitmq-server"), "").unwrap();
+
+    let path_var =
+        env::join_paths([system_sbin.clone(), paths.version_sbin_dir(&version)]).unwrap();
+    frm_cmd_with_dir(&temp)
+        .env("PATH", path_var)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "rabbitmq-server comes before RabbitMQ 4.2.3 in PATH",
+        ));
+}
+
+#[test]
+fn cli_doctor_fix_ownership_without_root_owned_files() {
+    let (temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+
+    assert!(ownership::root_owned_files(temp.path()).unwrap().is_empty());
+    frm_cmd_with_dir(&temp)
+        .args(["doctor", "--fix-ownership"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No root-owned files found"));
+}
+
+/// Needs root to create files owned by another user
+#[cfg(unix)]
+#[test]
+fn cli_doctor_reports_and_fixes_root_owned_files() {
+    if !ownership::running_as_root() {
+        return;
+    }
+    let (temp, paths) = setup_temp_paths();
+    chown(temp.path(), Some(1000), Some(1000)).unwrap();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    let files = ownership::root_owned_files(temp.path()).unwrap();
+    assert!(files.contains(&paths.version_server_script(&version)));
+    assert!(
+        diagnose(&paths)
+            .unwrap()
+            .contains(&Problem::RootOwnedFiles {
+                base_dir: temp.path().to_path_buf(),
+                files,
+            })
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["status"])
+        .assert()
+        .stderr(predicate::str::contains("frm is running as root"));
+    frm_cmd_with_dir(&temp)
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("are owned by root"))
+        .stdout(predicate::str::contains("frm doctor --fix-ownership"))
+        .stderr(predicate::str::contains("frm is running as root").not());
+
+    frm_cmd_with_dir(&temp)
+        .args(["doctor", "--fix-ownership"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Gave"));
+    assert!(ownership::root_owned_files(temp.path()).unwrap().is_empty());
+    assert_eq!(
+        ownership::owner(&paths.version_server_script(&version)).unwrap(),
+        ownership::Owner {
+            uid: 1000,
+            gid: 1000
+        }
+    );
+    frm_cmd_with_dir(&temp).args(["doctor"]).assert().success();
+}
@@ -7,10 +7,15 @@
 // except according to those terms.
 
 use std::fs;
+use std::io::{Read, Write};
 
+use tar::Archive;
 use tempfile::TempDir;
+use tokio::sync::mpsc;
+use xz2::read::XzDecoder;
+use xz2::write::XzEncoder;
 
-use frm::download::copy_default_config;
+use frm::download::{ChannelReader, copy_default_config};
 use frm::paths::Paths;
 use frm::version::Version;
 
@@ -248,3 +253,84 @@ fn copy_default_config_user_overrides_enabled_plugins() {
         "[rabbitmq_shovel,rabbitmq_federation]."
     );
 }
+
+fn tar_xz(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, name, *content).unwrap();
+    }
+    let tar = builder.into_inner().unwrap();
+    let mut encoder = XzEncoder::new(Vec::new(), 6);
+    encoder.write_all(&tar).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[tokio::test]
+async fn channel_reader_reads_chunks_in_order() {
+    let (tx, rx) = mpsc::channel::<Vec<u8>>(2);
+    let reader = tokio::task::spawn_blocking(move || {
+        let mut out = String::new();
+        ChannelReader::new(rx).read_to_string(&mut out).unwrap();
+        out
+    });
+
+    for chunk in ["abc", "", "defgh", "i"] {
+        tx.send(chunk.as_bytes().to_vec()).await.unwrap();
+    }
+    drop(tx);
+
+    assert_eq!(reader.await.unwrap(), "abcdefghi");
+}
+
+#[tokio::test]
+async fn channel_reader_streams_a_tar_xz_archive() {
+    let (temp, _) = setup_temp_paths();
+    let archive = tar_xz(&[
+        ("rabbitmq_server-4.2.3/sbin/rabbitmq-server", b"#!/bin/sh\n"),
+        ("rabbitmq_server-4.2.3/plugins/README", &[7u8; 100_000]),
+    ]);
+
+    let (tx, rx) = mpsc::channel::<Vec<u8>>(4);
+    let dest = temp.path().to_path_buf();
+    let extractor = tokio::task::spawn_blocking(move || {
+        Archive::new(XzDecoder::new(ChannelReader::new(rx))).unpack(&dest)
+    });
+
+    for chunk in archive.chunks(1024) {
+        if tx.send(chunk.to_vec()).await.is_err() {
+            break;
+        }
+    }
+    drop(tx);
+    extractor.await.unwrap().unwrap();
+
+    let dir = temp.path().join("rabbitmq_server-4.2.3");
+    assert_eq!(
+        fs::read_to_string(dir.join("sbin").join("rabbitmq-server")).unwrap(),
+        "#!/bin/sh\n"
+    );
+    assert_eq!(
+        fs::read(dir.join("plugins").join("README")).unwrap().len(),
+        100_000
+    );
+}
+
+#[tokio::test]
+async fn channel_reader_reports_truncated_archives() {
+    let (temp, _) = setup_temp_paths();
+    let archive = tar_xz(&[("rabbitmq_server-4.2.3/plugins/README", &[7u8; 100_000])]);
+
+    let (tx, rx) = mpsc::channel::<Vec<u8>>(4);
+    let dest = temp.path().to_path_buf();
+    let extractor = tokio::task::spawn_blocking(move || {
+        Archive::new(XzDecoder::new(ChannelReader::new(rx))).unpack(&dest)
+    });
+
+    tx.send(archive[..archive.len() / 2].to_vec()).await.ok();
+    drop(tx);
+    assert!(extractor.await.unwrap().is_err());
+}
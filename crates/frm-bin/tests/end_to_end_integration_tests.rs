@@ -282,7 +282,7 @@ fn end_to_end_default_and_list() {
         )));
 
     frm_cmd_with_dir(&temp)
-        .args(["releases", "list"])
+        .args(["releases", "list", "--plain"])
         .assert()
         .success()
         .stdout(predicate::str::contains(format!("[*] {}", TEST_GA_VERSION)));
@@ -522,7 +522,7 @@ fn end_to_end_full_workflow() {
         .success();
 
     frm_cmd_with_dir(&temp)
-        .args(["releases", "list"])
+        .args(["releases", "list", "--plain"])
         .assert()
         .success()
         .stdout(predicate::str::contains(format!("[*] {}", TEST_GA_VERSION)))
@@ -943,7 +943,7 @@ fn end_to_end_scenario_upgrade_workflow() {
         )));
 
     frm_cmd_with_dir(&temp)
-        .args(["releases", "list"])
+        .args(["releases", "list", "--plain"])
         .assert()
         .success()
         .stdout(predicate::str::contains(format!("[*] {}", TEST_GA_VERSION)))
@@ -0,0 +1,259 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::envs;
+use frm::errors::Error;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn install_with_config(dir: &TempDir, version: &str) -> Paths {
+    let paths = install_in(dir.path(), version);
+    let etc = paths.version_etc_dir(&version.parse().unwrap());
+    fs::create_dir_all(etc.join("conf.d")).unwrap();
+    fs::write(etc.join("rabbitmq.conf"), "listeners.tcp.default = 5673\n").unwrap();
+    fs::write(etc.join("enabled_plugins"), "[rabbitmq_management].\n").unwrap();
+    paths
+}
+
+#[test]
+fn envs_validate_name() {
+    assert!(envs::validate_name("perf").is_ok());
+    assert!(envs::validate_name("perf-test_2").is_ok());
+    assert!(envs::validate_name("").is_err());
+    assert!(envs::validate_name("-perf").is_err());
+    assert!(envs::validate_name("../perf").is_err());
+    assert!(envs::validate_name("perf env").is_err());
+}
+
+#[test]
+fn envs_create_clones_etc() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_with_config(&temp, "4.2.3");
+    let version = Version::new(4, 2, 3);
+
+    envs::create(&paths, &version, "perf").unwrap();
+
+    let etc = paths.version_env_etc_dir(&version, "perf");
+    assert_eq!(
+        fs::read_to_string(etc.join("rabbitmq.conf")).unwrap(),
+        "listeners.tcp.default = 5673\n"
+    );
+    assert!(etc.join("enabled_plugins").exists());
+    assert!(etc.join("conf.d").is_dir());
+    assert!(paths.version_env_data_dir(&version, "perf").is_dir());
+    assert!(paths.version_env_log_dir(&version, "perf").is_dir());
+}
+
+#[test]
+fn envs_create_twice_fails() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_with_config(&temp, "4.2.3");
+    let version = Version::new(4, 2, 3);
+
+    envs::create(&paths, &version, "perf").unwrap();
+    let result = envs::create(&paths, &version, "perf");
+    assert!(matches!(result, Err(Error::EnvAlreadyExists { .. })));
+}
+
+#[test]
+fn envs_create_requires_installed_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+
+    let result = envs::create(&paths, &Version::new(4, 2, 3), "perf");
+    assert!(matches!(result, Err(Error::VersionNotInstalled(_))));
+}
+
+#[test]
+fn envs_list_and_delete() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_with_config(&temp, "4.2.3");
+    let version = Version::new(4, 2, 3);
+
+    assert!(envs::list(&paths, &version).unwrap().is_empty());
+    envs::create(&paths, &version, "perf").unwrap();
+    envs::create(&paths, &version, "dev").unwrap();
+    assert_eq!(envs::list(&paths, &version).unwrap(), vec!["dev", "perf"]);
+
+    envs::delete(&paths, &version, "perf").unwrap();
+    assert_eq!(envs::list(&paths, &version).unwrap(), vec!["dev"]);
+    assert!(matches!(
+        envs::delete(&paths, &version, "perf"),
+        Err(Error::EnvNotFound { .. })
+    ));
+}
+
+#[test]
+fn envs_node_env_vars() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_with_config(&temp, "4.2.3");
+    let version = Version::new(4, 2, 3);
+
+    let default = envs::node_env_vars(&paths, &version, None).unwrap();
+    assert_eq!(
+        default,
+        vec![("RABBITMQ_CONFIG_FILES", paths.version_confd_dir(&version))]
+    );
+
+    let perf = envs::node_env_vars(&paths, &version, Some("perf")).unwrap();
+    let get = |name: &str| perf.iter().find(|(k, _)| *k == name).unwrap().1.clone();
+    assert_eq!(
+        get("RABBITMQ_CONFIG_FILE"),
+        paths
+            .version_env_etc_dir(&version, "perf")
+            .join("rabbitmq.conf")
+    );
+    assert_eq!(
+        get("RABBITMQ_MNESIA_BASE"),
+        paths.version_env_data_dir(&version, "perf")
+    );
+    assert_eq!(
+        get("RABBITMQ_LOG_BASE"),
+        paths.version_env_log_dir(&version, "perf")
+    );
+}
+
+#[test]
+fn cli_envs_create_list_delete() {
+    let temp = TempDir::new().unwrap();
+    install_with_config(&temp, "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "create", "perf", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Created environment 'perf' for RabbitMQ 4.2.3",
+        ))
+        .stdout(predicate::str::contains("--env perf"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "list", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("perf"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "delete", "perf", "-V", "4.2.3", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Deleted environment 'perf'"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "list", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No environments"));
+}
+
+#[test]
+fn cli_envs_create_invalid_name() {
+    let temp = TempDir::new().unwrap();
+    install_with_config(&temp, "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "create", "a/b", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid environment name"));
+}
+
+#[test]
+fn cli_fg_node_unknown_env() {
+    let temp = TempDir::new().unwrap();
+    install_with_config(&temp, "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["fg", "node", "-V", "4.2.3", "--env", "perf"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "environment 'perf' does not exist for version 4.2.3",
+        ));
+}
+
+#[test]
+fn fresh_node_is_deleted_on_drop() {
+    let version = Version::new(4, 2, 3);
+    let fresh = envs::FreshNode::create(&version).unwrap();
+    let dir = fresh.dir.clone();
+    assert!(dir.join("data").is_dir());
+    assert!(dir.join("log").is_dir());
+    assert!(fresh.node_name.starts_with("rabbit-fresh-"));
+
+    let vars = fresh.env_vars();
+    assert!(vars.contains(&("RABBITMQ_MNESIA_BASE", dir.join("data").into_os_string())));
+    assert!(vars.contains(&("RABBITMQ_NODENAME", fresh.node_name.clone().into())));
+
+    drop(fresh);
+    assert!(!dir.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_fg_node_fresh() {
+    let temp = TempDir::new().unwrap();
+    let paths = install_with_config(&temp, "4.2.3");
+    let script = paths.version_server_script(&Version::new(4, 2, 3));
+    fs::write(
+        &script,
+        "#!/bin/sh\n\
+         test -d \"$RABBITMQ_MNESIA_BASE\" || exit 3\n\
+         echo \"node=$RABBITMQ_NODENAME data=$RABBITMQ_MNESIA_BASE\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let output = frm_cmd_with_dir(&temp)
+        .args(["fg", "node", "-V", "4.2.3", "--fresh"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("node=rabbit-fresh-"))
+        .stdout(predicate::str::contains(
+            "Deleted the temporary data directory",
+        ))
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    let data_dir = stdout
+        .split("data=")
+        .nth(1)
+        .and_then(|s| s.lines().next())
+        .unwrap();
+    assert!(!Path::new(data_dir).exists());
+    // the version's own data directory is left alone
+    assert!(!paths.version_data_dir(&Version::new(4, 2, 3)).exists());
+
+    fs::write(&script, "#!/bin/sh\nexit 7\n").unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["fg", "node", "-V", "4.2.3", "--fresh"])
+        .assert()
+        .code(7);
+}
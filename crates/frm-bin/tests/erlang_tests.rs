@@ -0,0 +1,194 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::io::Write;
+
+use assert_cmd::Command;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::common::erlang::{
+    beam_compiler_version, built_with_otp_release, otp_release_of_compiler, otp_version_in_range,
+};
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path())
+        .env("ASDF_DATA_DIR", dir.path().join("asdf"))
+        .env("MISE_DATA_DIR", dir.path().join("mise"))
+        // keep an Erlang on the machine running the tests out of the report
+        .env("PATH", "");
+    cmd
+}
+
+fn chunk(id: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut chunk = id.to_vec();
+    chunk.extend((data.len() as u32).to_be_bytes());
+    chunk.extend(data);
+    chunk.resize(chunk.len().div_ceil(4) * 4, 0);
+    chunk
+}
+
+/// A BEAM file with an atom table and the compile info a compiler of the given version produces
+fn beam(compiler_version: &str) -> Vec<u8> {
+    // [{version, "8.5.4"}, {options, []}] in the external term format
+    let mut compile_info = vec![131, 108, 0, 0, 0, 2, 104, 2, 119, 7];
+    compile_info.extend(b"version");
+    compile_info.push(107);
+    compile_info.extend((compiler_version.len() as u16).to_be_bytes());
+    compile_info.extend(compiler_version.as_bytes());
+    compile_info.extend([104, 2, 119, 7]);
+    compile_info.extend(b"options");
+    compile_info.extend([106, 106]);
+
+    let mut chunks = chunk(
+        b"AtU8",
+        &[0, 0, 0, 1, 6, b'r', b'a', b'b', b'b', b'i', b't'],
+    );
+    chunks.extend(chunk(b"CInf", &compile_info));
+
+    let mut beam = b"FOR1".to_vec();
+    beam.extend((chunks.len() as u32 + 4).to_be_bytes());
+    beam.extend(b"BEAM");
+    beam.extend(chunks);
+    beam
+}
+
+fn install_with_rabbit_beam(dir: &TempDir, version: &str, compiler_version: &str) {
+    install_in(dir.path(), version);
+    let ebin = dir
+        .path()
+        .join("versions")
+        .join(version)
+        .join("plugins")
+        .join(format!("rabbit-{}", version))
+        .join("ebin");
+    fs::create_dir_all(&ebin).unwrap();
+    fs::write(ebin.join("rabbit.beam"), beam(compiler_version)).unwrap();
+}
+
+fn install_asdf_erlang(dir: &TempDir, version: &str) {
+    let bin = dir
+        .path()
+        .join("asdf")
+        .join("installs")
+        .join("erlang")
+        .join(version)
+        .join("bin");
+    fs::create_dir_all(&bin).unwrap();
+    fs::write(bin.join("erl"), "#!/bin/sh\n").unwrap();
+}
+
+#[test]
+fn beam_compiler_version_from_compile_info() {
+    assert_eq!(beam_compiler_version(&beam("8.5.4")), Some("8.5.4".into()));
+}
+
+#[test]
+fn beam_compiler_version_of_compressed_beam() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&beam("9.0.1")).unwrap();
+    let compressed = encoder.finish().unwrap();
+    assert_eq!(beam_compiler_version(&compressed), Some("9.0.1".into()));
+}
+
+#[test]
+fn beam_compiler_version_of_non_beam_files() {
+    assert_eq!(beam_compiler_version(b"not a BEAM file"), None);
+    assert_eq!(beam_compiler_version(b"FOR1\0\0\0\x04BEAM"), None);
+}
+
+#[test]
+fn otp_releases_of_compiler_versions() {
+    assert_eq!(otp_release_of_compiler("8.5.4"), Some(27));
+    assert_eq!(otp_release_of_compiler("8.4"), Some(26));
+    assert_eq!(otp_release_of_compiler("9.0.1"), Some(28));
+    assert_eq!(otp_release_of_compiler("8.50"), None);
+    assert_eq!(otp_release_of_compiler("6.0"), None);
+}
+
+#[test]
+fn otp_versions_in_supported_ranges() {
+    assert!(otp_version_in_range("27.3.4", "26.2", "27.x"));
+    assert!(otp_version_in_range("26.2", "26.2", "27.x"));
+    assert!(otp_version_in_range("26.2.5.1", "25.0", "26.2.x"));
+    assert!(!otp_version_in_range("26.1.2", "26.2", "27.x"));
+    assert!(!otp_version_in_range("28.0", "26.2", "27.x"));
+    assert!(!otp_version_in_range("26.3", "25.0", "26.2.x"));
+    assert!(!otp_version_in_range("master", "26.2", "27.x"));
+}
+
+#[test]
+fn built_with_otp_release_of_installed_version() {
+    let temp = TempDir::new().unwrap();
+    install_with_rabbit_beam(&temp, "4.2.3", "8.5.4");
+    let version_dir = temp.path().join("versions").join("4.2.3");
+    assert_eq!(built_with_otp_release(&version_dir), Some(27));
+
+    fs::create_dir_all(temp.path().join("empty")).unwrap();
+    assert_eq!(built_with_otp_release(&temp.path().join("empty")), None);
+}
+
+#[test]
+fn cli_erlang_report() {
+    let temp = TempDir::new().unwrap();
+    install_with_rabbit_beam(&temp, "4.2.3", "8.5.4");
+    install_with_rabbit_beam(&temp, "3.13.7", "8.3.2");
+    install_asdf_erlang(&temp, "26.2.5");
+    install_asdf_erlang(&temp, "27.3.4");
+
+    frm_cmd_with_dir(&temp)
+        .args(["erlang", "report"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Local Erlang/OTP:"))
+        .stdout(predicate::str::contains("27.3.4 (asdf, "))
+        .stdout(predicate::str::contains("OTP 27"))
+        .stdout(predicate::str::contains("27.0 - 28.x"))
+        .stdout(predicate::str::contains("compatible with 27.3.4"))
+        .stdout(predicate::str::contains("compatible with 26.2.5"));
+}
+
+#[test]
+fn cli_erlang_report_requires_the_release_a_version_was_built_with() {
+    let temp = TempDir::new().unwrap();
+    // built with OTP 27 but 26.2 is in the supported range
+    install_with_rabbit_beam(&temp, "4.1.4", "8.5.4");
+    install_asdf_erlang(&temp, "26.2.5");
+
+    frm_cmd_with_dir(&temp)
+        .args(["erlang", "report"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "no compatible Erlang: needs 27.0 - 27.x",
+        ));
+}
+
+#[test]
+fn cli_erlang_report_without_local_erlang() {
+    let temp = TempDir::new().unwrap();
+    install_with_rabbit_beam(&temp, "4.2.3", "8.5.4");
+
+    frm_cmd_with_dir(&temp)
+        .args(["erlang", "report"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No Erlang/OTP found"))
+        .stdout(predicate::str::contains(
+            "no compatible Erlang: needs 27.0 - 28.x",
+        ));
+}
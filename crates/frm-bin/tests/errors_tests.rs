@@ -8,9 +8,15 @@
 
 use std::io;
 
-use bel7_cli::{ExitCode, ExitCodeProvider};
+use std::collections::HashSet;
+use std::time::Duration;
 
-use frm::errors::Error;
+use bel7_cli::{ExitCode, ExitCodeExt, ExitCodeProvider};
+use tempfile::TempDir;
+
+use frm::errors::{Error, FailureClass};
+use frm::metadata;
+use frm::paths::Paths;
 use frm::version::Version;
 
 #[test]
@@ -34,7 +40,7 @@ fn exit_code_invalid_version() {
 #[test]
 fn exit_code_download_failed() {
     let err = Error::DownloadFailed("network error".into());
-    assert_eq!(err.exit_code(), ExitCode::Unavailable);
+    assert_eq!(err.exit_code(), ExitCode::Protocol);
 }
 
 #[test]
@@ -70,7 +76,7 @@ fn exit_code_file_not_found() {
 #[test]
 fn exit_code_command_failed() {
     let err = Error::CommandFailed("segfault".into());
-    assert_eq!(err.exit_code(), ExitCode::Software);
+    assert_eq!(err.exit_code(), ExitCode::OsErr);
 }
 
 #[test]
@@ -207,8 +213,8 @@ fn error_display_command_failed() {
 #[test]
 fn exit_code_tanzu_version_mismatch() {
     let err = Error::TanzuVersionMismatch {
-        expected: Version::new(4, 2, 3),
-        detected: Version::new(4, 2, 4),
+        expected: Box::new(Version::new(4, 2, 3)),
+        detected: Box::new(Version::new(4, 2, 4)),
     };
     assert_eq!(err.exit_code(), ExitCode::DataErr);
 }
@@ -216,11 +222,83 @@ fn exit_code_tanzu_version_mismatch() {
 #[test]
 fn error_display_tanzu_version_mismatch() {
     let err = Error::TanzuVersionMismatch {
-        expected: Version::new(4, 2, 3),
-        detected: Version::new(4, 2, 4),
+        expected: Box::new(Version::new(4, 2, 3)),
+        detected: Box::new(Version::new(4, 2, 4)),
     };
     assert_eq!(
         err.to_string(),
         "version mismatch: expected 4.2.3, detected 4.2.4 in tarball filename"
     );
 }
+
+#[test]
+fn failure_classes_have_distinct_exit_codes_and_names() {
+    let codes: HashSet<i32> = FailureClass::ALL
+        .iter()
+        .map(|c| c.exit_code().to_i32())
+        .collect();
+    let names: HashSet<&str> = FailureClass::ALL.iter().map(|c| c.name()).collect();
+    assert_eq!(codes.len(), FailureClass::ALL.len());
+    assert_eq!(names.len(), FailureClass::ALL.len());
+}
+
+#[test]
+fn failure_classes_of_errors() {
+    let version = Version::new(4, 2, 3);
+    assert_eq!(
+        Error::VersionNotInstalled(version.clone()).class(),
+        FailureClass::NotFound
+    );
+    assert_eq!(
+        Error::DownloadFailed("timeout".into()).class(),
+        FailureClass::Network
+    );
+    assert_eq!(
+        Error::MissingPath {
+            version: version.clone(),
+            kind: "logs".into(),
+            path: "/tmp/logs".into(),
+        }
+        .class(),
+        FailureClass::MissingPath
+    );
+    assert_eq!(
+        Error::SignatureVerificationFailed("bad signature".into()).class(),
+        FailureClass::Validation
+    );
+    assert_eq!(
+        Error::CommandFailed("exit code 1".into()).class(),
+        FailureClass::ChildProcess
+    );
+    assert_eq!(
+        Error::Locked("metadata/.lock".into()).class(),
+        FailureClass::Locked
+    );
+    assert_eq!(
+        Error::RootRequired("changing file owners".into()).class(),
+        FailureClass::Usage
+    );
+}
+
+#[test]
+fn exit_code_locked() {
+    let err = Error::Locked("metadata/.lock".into());
+    assert_eq!(err.exit_code(), ExitCode::TempFail);
+    assert_eq!(
+        err.to_string(),
+        "metadata/.lock is locked by another frm process"
+    );
+}
+
+#[test]
+fn metadata_lock_times_out_while_held() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+
+    let held = metadata::lock(&paths).unwrap();
+    let err = metadata::lock_with_timeout(&paths, Duration::from_millis(100)).unwrap_err();
+    assert!(matches!(err, Error::Locked(_)));
+
+    drop(held);
+    assert!(metadata::lock_with_timeout(&paths, Duration::from_millis(100)).is_ok());
+}
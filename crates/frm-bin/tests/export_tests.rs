@@ -0,0 +1,181 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::dockerfile;
+use frm::version::Version;
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn install_with_conf_and_log(dir: &TempDir, version: &str) {
+    let paths = install_in(dir.path(), version);
+    let version: Version = version.parse().unwrap();
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        "listeners.tcp.default = 5673\n",
+    )
+    .unwrap();
+    let log = paths
+        .version_dir(&version)
+        .join("var")
+        .join("log")
+        .join("rabbitmq");
+    fs::create_dir_all(&log).unwrap();
+    fs::write(log.join("rabbit@host.log"), "log").unwrap();
+}
+
+#[test]
+fn dockerfile_content() {
+    let content = dockerfile(&Version::new(4, 2, 3), "erlang:27", 5672);
+
+    assert!(content.contains("FROM erlang:27\n"));
+    assert!(content.contains("COPY rabbitmq /opt/rabbitmq\n"));
+    assert!(content.contains("RABBITMQ_HOME=/opt/rabbitmq"));
+    assert!(content.contains("PATH=/opt/rabbitmq/sbin:$PATH"));
+    assert!(content.contains("EXPOSE 5672 15672\n"));
+    assert!(content.contains("CMD [\"rabbitmq-server\"]"));
+}
+
+#[test]
+fn cli_export_docker_writes_build_context() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf_and_log(&temp, "4.2.3");
+    let output = temp.path().join("context");
+
+    frm_cmd_with_dir(&temp)
+        .args(["export", "docker", "-V", "4.2.3", "-o"])
+        .arg(&output)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported RabbitMQ 4.2.3"))
+        .stdout(predicate::str::contains(
+            "docker build -t frm-rabbitmq:4.2.3",
+        ));
+
+    let dockerfile = fs::read_to_string(output.join("Dockerfile")).unwrap();
+    assert!(dockerfile.contains("EXPOSE 5673 15672"));
+    assert!(output.join("rabbitmq/sbin/rabbitmq-server").exists());
+    assert!(output.join("rabbitmq/etc/rabbitmq/rabbitmq.conf").exists());
+    assert!(!output.join("rabbitmq/var").exists());
+}
+
+#[test]
+fn cli_export_docker_uses_env_configuration() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf_and_log(&temp, "4.2.3");
+    let output = temp.path().join("context");
+
+    frm_cmd_with_dir(&temp)
+        .args(["envs", "create", "perf", "-V", "4.2.3"])
+        .assert()
+        .success();
+    fs::write(
+        temp.path()
+            .join("versions/4.2.3/envs/perf/etc/rabbitmq/rabbitmq.conf"),
+        "listeners.tcp.default = 5674\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["export", "docker", "-V", "4.2.3", "--env", "perf", "-o"])
+        .arg(&output)
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(output.join("rabbitmq/etc/rabbitmq/rabbitmq.conf")).unwrap(),
+        "listeners.tcp.default = 5674\n"
+    );
+    assert!(!output.join("rabbitmq/envs").exists());
+    assert!(
+        fs::read_to_string(output.join("Dockerfile"))
+            .unwrap()
+            .contains("EXPOSE 5674 15672")
+    );
+}
+
+#[test]
+fn cli_export_docker_refuses_non_empty_output() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf_and_log(&temp, "4.2.3");
+    let output = temp.path().join("context");
+    fs::create_dir_all(&output).unwrap();
+    fs::write(output.join("file"), "").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["export", "docker", "-V", "4.2.3", "-o"])
+        .arg(&output)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not empty"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_export_docker_build_with_engine() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf_and_log(&temp, "4.2.3");
+    let output = temp.path().join("context");
+    let engine = temp.path().join("fake-engine");
+    let calls = temp.path().join("calls");
+    fs::write(
+        &engine,
+        format!("#!/bin/sh\necho \"$@\" > {}\n", calls.display()),
+    )
+    .unwrap();
+    fs::set_permissions(&engine, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "export",
+            "docker",
+            "-V",
+            "4.2.3",
+            "--tag",
+            "ci/rabbitmq:test",
+        ])
+        .arg("-o")
+        .arg(&output)
+        .arg("--build")
+        .arg(&engine)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Built image ci/rabbitmq:test"));
+
+    assert_eq!(
+        fs::read_to_string(calls).unwrap().trim(),
+        format!("build -t ci/rabbitmq:test {}", output.display())
+    );
+}
+
+#[test]
+fn cli_export_docker_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["export", "docker", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tempfile::TempDir;
+
+use frm::Error;
+use frm::feed_cache::{self, CachedFeed, FEED_CACHE_TTL};
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn cached(url: &str, fetched_at: u64) -> CachedFeed {
+    CachedFeed {
+        url: url.to_string(),
+        etag: Some("\"abc\"".to_string()),
+        fetched_at,
+        body: "[]".to_string(),
+    }
+}
+
+/// Accepts a single HTTP request, sends its head over the channel, and responds with `response`
+fn fake_server(response: &'static str) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/releases", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buf = [0u8; 4096];
+        let n = stream.read(&mut buf).unwrap();
+        tx.send(String::from_utf8_lossy(&buf[..n]).to_lowercase())
+            .unwrap();
+        stream.write_all(response.as_bytes()).unwrap();
+    });
+    (url, rx)
+}
+
+fn unreachable_url() -> String {
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    format!("http://127.0.0.1:{}/releases", port)
+}
+
+#[test]
+fn freshness() {
+    let feed = cached("https://example.com", 1_000);
+    assert!(feed.is_fresh(1_000, FEED_CACHE_TTL));
+    assert!(feed.is_fresh(1_000 + FEED_CACHE_TTL.as_secs() - 1, FEED_CACHE_TTL));
+    assert!(!feed.is_fresh(1_000 + FEED_CACHE_TTL.as_secs(), FEED_CACHE_TTL));
+    assert!(!feed.is_fresh(1_000, Duration::ZERO));
+    // clock skew
+    assert!(!feed.is_fresh(500, FEED_CACHE_TTL));
+}
+
+#[test]
+fn save_and_load() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("cache").join("feed.json");
+    assert_eq!(CachedFeed::load(&path), None);
+
+    let feed = cached("https://example.com", 1_000);
+    feed.save(&path).unwrap();
+    assert_eq!(CachedFeed::load(&path), Some(feed));
+
+    fs::write(&path, "not json").unwrap();
+    assert_eq!(CachedFeed::load(&path), None);
+}
+
+#[tokio::test]
+async fn fresh_cache_is_used_without_a_request() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("feed.json");
+    let url = unreachable_url();
+    cached(&url, now()).save(&path).unwrap();
+
+    let body = feed_cache::fetch(&reqwest::Client::new(), &url, &path, FEED_CACHE_TTL)
+        .await
+        .unwrap();
+    assert_eq!(body, "[]");
+}
+
+#[tokio::test]
+async fn stale_cache_is_revalidated_with_etag() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("feed.json");
+    let (url, requests) = fake_server("HTTP/1.1 304 Not Modified\r\nContent-Length: 0\r\n\r\n");
+    cached(&url, 1_000).save(&path).unwrap();
+
+    let body = feed_cache::fetch(&reqwest::Client::new(), &url, &path, FEED_CACHE_TTL)
+        .await
+        .unwrap();
+    assert_eq!(body, "[]");
+    assert!(requests.recv().unwrap().contains("if-none-match: \"abc\""));
+    assert!(CachedFeed::load(&path).unwrap().fetched_at > 1_000);
+}
+
+#[tokio::test]
+async fn fetched_feed_is_cached() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("feed.json");
+    let (url, _requests) = fake_server(
+        "HTTP/1.1 200 OK\r\nETag: \"def\"\r\nContent-Length: 9\r\nConnection: close\r\n\r\n[{\"a\":1}]",
+    );
+
+    let body = feed_cache::fetch(&reqwest::Client::new(), &url, &path, FEED_CACHE_TTL)
+        .await
+        .unwrap();
+    assert_eq!(body, "[{\"a\":1}]");
+    let feed = CachedFeed::load(&path).unwrap();
+    assert_eq!(feed.etag.as_deref(), Some("\"def\""));
+    assert_eq!(feed.url, url);
+    assert_eq!(feed.body, body);
+}
+
+#[tokio::test]
+async fn stale_cache_is_used_when_offline() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("feed.json");
+    let url = unreachable_url();
+    let client = reqwest::Client::new();
+
+    let err = feed_cache::fetch(&client, &url, &path, FEED_CACHE_TTL)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::DownloadFailed(_)));
+
+    cached(&url, 1_000).save(&path).unwrap();
+    let body = feed_cache::fetch(&client, &url, &path, FEED_CACHE_TTL)
+        .await
+        .unwrap();
+    assert_eq!(body, "[]");
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+#[test]
+fn cli_health_requires_an_installed_version() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["health", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_health_rejects_unknown_formats() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["health", "-V", "4.2.3", "--format", "yaml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'yaml'"));
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::process;
+
+    use predicates::prelude::*;
+    use tempfile::TempDir;
+
+    use frm::paths::Paths;
+    use frm::version::Version;
+
+    use super::frm_cmd;
+
+    /// Installs 4.2.3 with a running node and a rabbitmq-diagnostics that appends its
+    /// arguments to calls.log. check_alarms fails when `alarm` is set.
+    fn install(temp: &TempDir, alarm: bool) -> Paths {
+        let paths = Paths::with_base_dir(temp.path().to_path_buf());
+        let version = Version::new(4, 2, 3);
+        let sbin = paths.version_sbin_dir(&version);
+        fs::create_dir_all(&sbin).unwrap();
+        let failing = if alarm { "check_alarms" } else { "none" };
+        let script = sbin.join("rabbitmq-diagnostics");
+        fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> '{}'\ncase \"$*\" in\n  *{}*) echo 'Error: memory alarm in effect' >&2; exit 69;;\nesac\n",
+                temp.path().join("calls.log").display(),
+                failing
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        fs::create_dir_all(paths.version_etc_dir(&version)).unwrap();
+        fs::write(
+            paths.version_etc_dir(&version).join("rabbitmq.conf"),
+            "listeners.tcp.default = 5673\n",
+        )
+        .unwrap();
+        let data_dir = paths.version_data_dir(&version);
+        fs::create_dir_all(&data_dir).unwrap();
+        // the test process stands in for the node
+        fs::write(
+            data_dir.join("rabbit@localhost.pid"),
+            process::id().to_string(),
+        )
+        .unwrap();
+        paths
+    }
+
+    #[test]
+    fn cli_health_runs_every_check() {
+        let temp = TempDir::new().unwrap();
+        install(&temp, false);
+
+        frm_cmd(&temp)
+            .args(["health", "-V", "4.2.3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("check_port_listener 5673"))
+            .stdout(predicate::str::contains(
+                "rabbit@localhost of RabbitMQ 4.2.3 passed all 5 checks",
+            ));
+
+        let calls = fs::read_to_string(temp.path().join("calls.log")).unwrap();
+        let prefix = "-n rabbit@localhost --timeout 10 --quiet";
+        assert_eq!(
+            calls,
+            [
+                "ping",
+                "check_running",
+                "check_port_listener 5673",
+                "check_virtual_hosts",
+                "check_alarms"
+            ]
+            .iter()
+            .map(|check| format!("{} {}\n", prefix, check))
+            .collect::<String>()
+        );
+    }
+
+    #[test]
+    fn cli_health_reports_failed_checks() {
+        let temp = TempDir::new().unwrap();
+        install(&temp, true);
+
+        frm_cmd(&temp)
+            .args(["health", "-V", "4.2.3"])
+            .assert()
+            .code(69)
+            .stdout(predicate::str::contains("memory alarm in effect"))
+            .stderr(predicate::str::contains(
+                "1 of 5 health check(s) failed for rabbit@localhost",
+            ));
+    }
+
+    #[test]
+    fn cli_health_json_output() {
+        let temp = TempDir::new().unwrap();
+        install(&temp, true);
+
+        let output = frm_cmd(&temp)
+            .args(["health", "-V", "4.2.3", "--format", "json"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(report["version"], "4.2.3");
+        assert_eq!(report["node"], "rabbit@localhost");
+        assert_eq!(report["healthy"], false);
+        let checks = report["checks"].as_array().unwrap();
+        assert_eq!(checks.len(), 5);
+        assert_eq!(checks[0]["check"], "ping");
+        assert_eq!(checks[0]["passed"], true);
+        assert_eq!(checks[4]["check"], "check_alarms");
+        assert_eq!(checks[4]["passed"], false);
+        assert_eq!(checks[4]["output"], "Error: memory alarm in effect");
+    }
+
+    #[test]
+    fn cli_health_requires_a_running_node() {
+        let temp = TempDir::new().unwrap();
+        let paths = install(&temp, false);
+        fs::remove_dir_all(paths.version_data_dir(&Version::new(4, 2, 3))).unwrap();
+
+        frm_cmd(&temp)
+            .args(["health", "-V", "4.2.3"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("no running node"));
+    }
+}
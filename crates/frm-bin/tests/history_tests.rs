@@ -0,0 +1,354 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[cfg(unix)]
+use std::ffi::OsStr;
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::errors::Error;
+use frm::history::{self, HistoryEntry, HistoryQuery, Outcome};
+use frm::paths::Paths;
+use frm::version::Version;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn enable_history(dir: &TempDir) {
+    fs::write(dir.path().join("config.toml"), "record_history = true\n").unwrap();
+}
+
+fn entry_at(timestamp: u64, version: Option<&str>) -> HistoryEntry {
+    let mut entry = HistoryEntry::new(
+        "releases use",
+        vec!["releases".into(), "use".into()],
+        version.map(String::from),
+    );
+    entry.timestamp = timestamp;
+    entry
+}
+
+#[test]
+fn history_load_nonexistent() {
+    let (_temp, paths) = setup_temp_paths();
+    assert!(history::load(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn history_append_and_load() {
+    let (_temp, paths) = setup_temp_paths();
+
+    let first = entry_at(100, Some("4.2.3")).with_outcome(&Ok(()));
+    let second =
+        entry_at(200, None).with_outcome(&Err(Error::VersionNotInstalled(Version::new(4, 0, 0))));
+    history::append(&paths, &first).unwrap();
+    history::append(&paths, &second).unwrap();
+
+    let loaded = history::load(&paths).unwrap();
+    assert_eq!(loaded, vec![first, second]);
+    assert_eq!(loaded[0].outcome, Outcome::Ok);
+    assert_eq!(loaded[1].outcome, Outcome::Failed);
+    assert_eq!(
+        loaded[1].error.as_deref(),
+        Some("version 4.0.0 is not installed")
+    );
+}
+
+#[test]
+fn history_file_is_json_lines() {
+    let (_temp, paths) = setup_temp_paths();
+    history::append(&paths, &entry_at(1, Some("4.2.3"))).unwrap();
+    history::append(&paths, &entry_at(2, Some("4.2.3"))).unwrap();
+
+    let content = fs::read_to_string(paths.history_file()).unwrap();
+    assert_eq!(content.lines().count(), 2);
+    for line in content.lines() {
+        let _: serde_json::Value = serde_json::from_str(line).unwrap();
+    }
+}
+
+#[test]
+fn history_load_skips_malformed_lines() {
+    let (_temp, paths) = setup_temp_paths();
+    history::append(&paths, &entry_at(1, Some("4.2.3"))).unwrap();
+    let mut content = fs::read_to_string(paths.history_file()).unwrap();
+    content.push_str("{\"timestamp\": 2, \"comm\n");
+    fs::write(paths.history_file(), content).unwrap();
+
+    let loaded = history::load(&paths).unwrap();
+    assert_eq!(loaded.len(), 1);
+}
+
+#[test]
+fn history_query_filters_by_version() {
+    let entries = vec![
+        entry_at(1, Some("4.2.3")),
+        entry_at(2, Some("4.3.0-alpha.132057c7")),
+        entry_at(3, None),
+    ];
+    let query = HistoryQuery {
+        version: Some("4.3.0-alpha.132057c7".into()),
+        ..Default::default()
+    };
+
+    let result = query.apply(entries);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].timestamp, 2);
+}
+
+#[test]
+fn history_query_filters_by_time_range() {
+    let entries = vec![entry_at(10, None), entry_at(20, None), entry_at(30, None)];
+    let query = HistoryQuery {
+        since: Some(15),
+        until: Some(30),
+        ..Default::default()
+    };
+
+    let timestamps: Vec<u64> = query.apply(entries).iter().map(|e| e.timestamp).collect();
+    assert_eq!(timestamps, vec![20, 30]);
+}
+
+#[test]
+fn history_query_limit_keeps_most_recent() {
+    let entries = vec![entry_at(10, None), entry_at(20, None), entry_at(30, None)];
+    let query = HistoryQuery {
+        limit: Some(2),
+        ..Default::default()
+    };
+
+    let timestamps: Vec<u64> = query.apply(entries).iter().map(|e| e.timestamp).collect();
+    assert_eq!(timestamps, vec![20, 30]);
+}
+
+#[test]
+fn cli_history_not_recorded_by_default() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list"])
+        .assert()
+        .success();
+
+    assert!(!temp.path().join("history.jsonl").exists());
+
+    frm_cmd_with_dir(&temp)
+        .args(["history"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("History recording is disabled"));
+}
+
+#[test]
+fn cli_history_records_successful_command() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3"])
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].command, "releases path");
+    assert_eq!(entries[0].version.as_deref(), Some("4.2.3"));
+    assert_eq!(entries[0].outcome, Outcome::Ok);
+}
+
+#[test]
+fn cli_history_records_failed_command() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3"])
+        .assert()
+        .failure();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].outcome, Outcome::Failed);
+    assert!(entries[0].error.as_ref().unwrap().contains("not installed"));
+}
+
+#[test]
+fn cli_history_records_process_replacing_command_failing_before_exec_once() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+
+    frm_cmd_with_dir(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.2.3"])
+        .assert()
+        .failure();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].command, "cli");
+    assert_eq!(entries[0].outcome, Outcome::Failed);
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_history_records_process_replacing_command_as_started() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let sbin = paths.version_sbin_dir(&Version::new(4, 2, 3));
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    let ctl = sbin.join("rabbitmqctl");
+    fs::write(&ctl, "#!/bin/sh\nexit 0\n").unwrap();
+    fs::set_permissions(&ctl, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["cli", "rabbitmqctl", "-V", "4.2.3"])
+        .assert()
+        .success();
+
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].command, "cli");
+    assert_eq!(entries[0].outcome, Outcome::Started);
+}
+
+#[test]
+fn cli_history_resolves_latest() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    fs::create_dir_all(temp.path().join("versions").join("4.1.0")).unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "latest"])
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries[0].version.as_deref(), Some("4.2.3"));
+}
+
+#[test]
+fn cli_history_shows_entries() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["history"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm default 4.2.3"))
+        .stdout(predicate::str::contains("ok"));
+}
+
+#[test]
+fn cli_history_is_not_recorded_itself() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+
+    frm_cmd_with_dir(&temp).args(["history"]).assert().success();
+
+    assert!(!temp.path().join("history.jsonl").exists());
+}
+
+#[test]
+fn cli_history_filters_by_version() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    fs::create_dir_all(temp.path().join("versions").join("4.1.0")).unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.1.0"])
+        .assert()
+        .success();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["history", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm releases path -V 4.2.3"))
+        .stdout(predicate::str::contains("4.1.0").not());
+}
+
+#[test]
+fn cli_history_invalid_since() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["history", "--since", "not a date at all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid date/time"));
+}
+
+#[cfg(unix)]
+#[test]
+fn history_file_is_only_readable_by_owner() {
+    let (_temp, paths) = setup_temp_paths();
+    history::append(&paths, &entry_at(1, None)).unwrap();
+
+    let mode = fs::metadata(paths.history_file())
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_history_records_non_utf8_arguments() {
+    let temp = TempDir::new().unwrap();
+    enable_history(&temp);
+    let archive = temp.path().join(OsStr::from_bytes(b"\xff.tar.gz"));
+
+    frm_cmd_with_dir(&temp)
+        .arg("import")
+        .arg(&archive)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("panicked").not());
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let entries = history::load(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].command, "import");
+    assert!(entries[0].args[1].ends_with("\u{FFFD}.tar.gz"));
+}
@@ -0,0 +1,215 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn read_json(path: &Path) -> Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn task_labels(tasks: &Value) -> Vec<String> {
+    tasks["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["label"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn cli_ide_vscode_writes_settings_and_tasks_for_the_default_version() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(frm_dir.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    frm_cmd_with_dir(&frm_dir)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "--dir"])
+        .arg(project.path())
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("using the default version 4.2.3"));
+
+    let settings = read_json(&project.path().join(".vscode").join("settings.json"));
+    let env = settings
+        .as_object()
+        .unwrap()
+        .iter()
+        .find(|(k, _)| k.starts_with("terminal.integrated.env."))
+        .map(|(_, v)| v.clone())
+        .unwrap();
+    let sbin = paths.version_sbin_dir(&version).display().to_string();
+    assert!(env["PATH"].as_str().unwrap().starts_with(&sbin));
+    assert!(env["PATH"].as_str().unwrap().ends_with("${env:PATH}"));
+
+    let tasks = read_json(&project.path().join(".vscode").join("tasks.json"));
+    assert_eq!(tasks["version"], "2.0.0");
+    assert!(task_labels(&tasks).contains(&"frm: run broker".to_string()));
+    let run = &tasks["tasks"][0];
+    assert_eq!(run["command"], "frm");
+    assert!(run["args"].as_array().unwrap().contains(&"4.2.3".into()));
+}
+
+#[test]
+fn cli_ide_vscode_keeps_other_settings_and_tasks() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(frm_dir.path().to_path_buf());
+    let (old, new) = (Version::new(4, 1, 0), Version::new(4, 2, 3));
+    install(&paths, &old);
+    install(&paths, &new);
+    let vscode_dir = project.path().join(".vscode");
+    fs::create_dir_all(&vscode_dir).unwrap();
+    fs::write(vscode_dir.join("settings.json"), r#"{"editor.tabSize": 2}"#).unwrap();
+    fs::write(
+        vscode_dir.join("tasks.json"),
+        r#"{"version": "2.0.0", "tasks": [{"label": "make"}]}"#,
+    )
+    .unwrap();
+
+    for v in ["4.1.0", "4.2.3"] {
+        frm_cmd_with_dir(&frm_dir)
+            .args(["ide", "vscode", "-V", v, "--dir"])
+            .arg(project.path())
+            .assert()
+            .success();
+    }
+
+    let settings = read_json(&vscode_dir.join("settings.json"));
+    assert_eq!(settings["editor.tabSize"], 2);
+    let tasks = read_json(&vscode_dir.join("tasks.json"));
+    let labels = task_labels(&tasks);
+    assert_eq!(labels.iter().filter(|l| *l == "make").count(), 1);
+    // regenerating replaces frm's tasks instead of adding another set
+    assert_eq!(labels.iter().filter(|l| *l == "frm: run broker").count(), 1);
+    assert!(
+        !fs::read_to_string(vscode_dir.join("tasks.json"))
+            .unwrap()
+            .contains("4.1.0")
+    );
+}
+
+#[test]
+fn cli_ide_vscode_refuses_to_overwrite_unparseable_files() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(frm_dir.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+    let vscode_dir = project.path().join(".vscode");
+    fs::create_dir_all(&vscode_dir).unwrap();
+    let content = "{\n  // comments are not JSON\n  \"editor.tabSize\": 2\n}\n";
+    fs::write(vscode_dir.join("settings.json"), content).unwrap();
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "-V", "4.2.3", "--dir"])
+        .arg(project.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--print"));
+
+    assert_eq!(
+        fs::read_to_string(vscode_dir.join("settings.json")).unwrap(),
+        content
+    );
+    assert!(!vscode_dir.join("tasks.json").exists());
+}
+
+#[test]
+fn cli_ide_vscode_print_does_not_write_files() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(frm_dir.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "-V", "4.2.3", "--print", "--dir"])
+        .arg(project.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("// .vscode/settings.json"))
+        .stdout(predicate::str::contains("// .vscode/tasks.json"))
+        .stdout(predicate::str::contains("frm: run broker"));
+
+    assert!(!project.path().join(".vscode").exists());
+}
+
+#[test]
+fn cli_ide_vscode_requires_an_installed_version() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "-V", "4.2.3", "--dir"])
+        .arg(project.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+
+    assert!(!project.path().join(".vscode").exists());
+}
+
+#[test]
+fn cli_ide_vscode_does_not_overwrite_files_it_cannot_read() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(frm_dir.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+    let vscode_dir = project.path().join(".vscode");
+    fs::create_dir_all(&vscode_dir).unwrap();
+    // not UTF-8
+    let content = b"{\"editor.fontFamily\": \"\xff\xfe\"}\n";
+    fs::write(vscode_dir.join("tasks.json"), content).unwrap();
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "-V", "4.2.3", "--dir"])
+        .arg(project.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be read"));
+
+    assert_eq!(fs::read(vscode_dir.join("tasks.json")).unwrap(), content);
+    assert!(!vscode_dir.join("settings.json").exists());
+}
+
+#[test]
+fn cli_ide_vscode_requires_a_version_without_a_default() {
+    let frm_dir = TempDir::new().unwrap();
+    let project = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&frm_dir)
+        .args(["ide", "vscode", "--dir"])
+        .arg(project.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no version specified"));
+}
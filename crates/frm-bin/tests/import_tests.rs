@@ -0,0 +1,178 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::detect_imported_version;
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn manual_install(parent: &Path, dir_name: &str) -> PathBuf {
+    let dir = parent.join(dir_name);
+    let sbin = dir.join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    dir
+}
+
+#[test]
+fn detect_version_from_dir_name() {
+    let temp = TempDir::new().unwrap();
+    let dir = manual_install(temp.path(), "rabbitmq_server-4.1.6");
+    assert_eq!(
+        detect_imported_version(&dir).unwrap(),
+        Version::new(4, 1, 6)
+    );
+}
+
+#[test]
+fn detect_version_from_plugins() {
+    let temp = TempDir::new().unwrap();
+    let dir = manual_install(temp.path(), "rabbitmq");
+    let plugins = dir.join("plugins");
+    fs::create_dir_all(plugins.join("rabbit_common-4.1.6")).unwrap();
+    fs::create_dir_all(plugins.join("rabbit-4.1.6")).unwrap();
+    assert_eq!(
+        detect_imported_version(&dir).unwrap(),
+        Version::new(4, 1, 6)
+    );
+
+    let other = manual_install(temp.path(), "old");
+    fs::create_dir_all(other.join("plugins")).unwrap();
+    fs::write(other.join("plugins").join("rabbit-3.13.7.ez"), "").unwrap();
+    assert_eq!(
+        detect_imported_version(&other).unwrap(),
+        Version::new(3, 13, 7)
+    );
+}
+
+#[test]
+fn detect_version_fails_without_hints() {
+    let temp = TempDir::new().unwrap();
+    let dir = manual_install(temp.path(), "rabbitmq");
+    assert!(detect_imported_version(&dir).is_err());
+}
+
+#[test]
+fn cli_import_copies_installation() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let source = manual_install(external.path(), "rabbitmq_server-4.1.6");
+    let etc = source.join("etc").join("rabbitmq");
+    fs::create_dir_all(&etc).unwrap();
+    fs::write(etc.join("rabbitmq.conf"), "listeners.tcp.default = 5673\n").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("RabbitMQ 4.1.6 imported"));
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 1, 6);
+    assert!(paths.version_server_script(&version).exists());
+    assert!(!paths.version_linked(&version));
+    assert_eq!(
+        fs::read_to_string(paths.version_etc_dir(&version).join("rabbitmq.conf")).unwrap(),
+        "listeners.tcp.default = 5673\n"
+    );
+
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert!(metadata.installed_at.is_some());
+    assert_eq!(
+        metadata.imported_from,
+        Some(source.canonicalize().unwrap().display().to_string())
+    );
+
+    // the source is left intact
+    assert!(source.join("sbin").join("rabbitmq-server").exists());
+}
+
+#[test]
+fn cli_import_adds_default_config_when_missing() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let source = manual_install(external.path(), "rabbitmq_server-4.1.6");
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap()])
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    assert!(
+        paths
+            .version_etc_dir(&Version::new(4, 1, 6))
+            .join("rabbitmq.conf")
+            .exists()
+    );
+}
+
+#[test]
+fn cli_import_with_name() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let source = manual_install(external.path(), "rabbitmq");
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use --name"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap(), "--name", "4.0.9"])
+        .assert()
+        .success();
+
+    assert!(temp.path().join("versions").join("4.0.9").exists());
+}
+
+#[test]
+fn cli_import_rejects_installed_version() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let source = manual_install(external.path(), "rabbitmq_server-4.1.6");
+    fs::create_dir_all(temp.path().join("versions").join("4.1.6")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already installed"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_import_link() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let source = manual_install(external.path(), "rabbitmq_server-4.1.6");
+
+    frm_cmd_with_dir(&temp)
+        .args(["import", source.to_str().unwrap(), "--link"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Linked RabbitMQ 4.1.6"));
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    assert!(paths.version_linked(&Version::new(4, 1, 6)));
+}
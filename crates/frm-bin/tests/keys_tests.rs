@@ -0,0 +1,159 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::keyring;
+use frm::paths::Paths;
+
+const KEY: &str =
+    "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nmQINBF...\n-----END PGP PUBLIC KEY BLOCK-----\n";
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn key_file(temp: &TempDir, content: &str) -> PathBuf {
+    let path = temp.path().join("acme-builds.asc");
+    fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn cli_keys_list_includes_the_rabbitmq_key() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["keys", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "rabbitmq: https://github.com/rabbitmq/signing-keys/",
+        ))
+        .stdout(predicate::str::contains("(built-in, not downloaded yet)"));
+}
+
+#[test]
+fn cli_keys_add_list_remove() {
+    let temp = TempDir::new().unwrap();
+    let source = key_file(&temp, KEY);
+
+    frm_cmd(&temp)
+        .args(["keys", "add", "acme"])
+        .arg(&source)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added signing key 'acme'"));
+    assert_eq!(
+        fs::read_to_string(temp.path().join("keys").join("acme.asc")).unwrap(),
+        KEY
+    );
+
+    frm_cmd(&temp)
+        .args(["keys", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "acme: {}\n",
+            source.canonicalize().unwrap().display()
+        )));
+
+    frm_cmd(&temp)
+        .args(["keys", "add", "acme"])
+        .arg(&source)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already in the keyring"));
+
+    frm_cmd(&temp)
+        .args(["keys", "remove", "acme", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed signing key 'acme'"));
+    assert!(!temp.path().join("keys").join("acme.asc").exists());
+
+    frm_cmd(&temp)
+        .args(["keys", "remove", "acme", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'acme' is not in the keyring"));
+}
+
+#[test]
+fn cli_keys_add_rejects_invalid_keys_and_names() {
+    let temp = TempDir::new().unwrap();
+    let not_a_key = key_file(&temp, "hello\n");
+
+    frm_cmd(&temp)
+        .args(["keys", "add", "acme"])
+        .arg(&not_a_key)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is not an ASCII-armored PGP public key",
+        ));
+    frm_cmd(&temp)
+        .args(["keys", "add", "acme", "/nonexistent/key.asc"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("file not found"));
+    frm_cmd(&temp)
+        .args(["keys", "add", "acme/x"])
+        .arg(&not_a_key)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid key name"));
+    frm_cmd(&temp)
+        .args(["keys", "add", "rabbitmq"])
+        .arg(&not_a_key)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already in the keyring"));
+    assert!(!temp.path().join("keys").join("acme.asc").exists());
+
+    frm_cmd(&temp)
+        .args(["keys", "remove", "rabbitmq", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be removed"));
+}
+
+#[tokio::test]
+async fn refresh_reads_the_source_again() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().join("frm"));
+    let client = reqwest::Client::new();
+    let source = key_file(&temp, KEY);
+
+    keyring::add(&client, &paths, "acme", source.to_str().unwrap())
+        .await
+        .unwrap();
+    let key = keyring::list(&paths)
+        .unwrap()
+        .into_iter()
+        .find(|key| key.name == "acme")
+        .unwrap();
+    assert!(!key.built_in && key.downloaded());
+
+    let updated = KEY.replace("mQINBF", "mQINBG");
+    fs::write(&source, &updated).unwrap();
+    keyring::refresh(&client, &paths, &key).await.unwrap();
+    assert_eq!(fs::read_to_string(&key.path).unwrap(), updated);
+
+    // a source that no longer has a key leaves the current copy alone
+    fs::write(&source, "gone\n").unwrap();
+    assert!(keyring::refresh(&client, &paths, &key).await.is_err());
+    assert_eq!(fs::read_to_string(&key.path).unwrap(), updated);
+}
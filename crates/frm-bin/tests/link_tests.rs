@@ -0,0 +1,178 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::infer_linked_version;
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn external_build(parent: &Path, dir_name: &str) -> PathBuf {
+    let dir = parent.join(dir_name);
+    let sbin = dir.join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    dir
+}
+
+#[test]
+fn infer_linked_version_from_dir_name() {
+    assert_eq!(
+        infer_linked_version(Path::new("/src/rabbitmq_server-4.3.0+dev")).unwrap(),
+        "4.3.0+dev".parse::<Version>().unwrap()
+    );
+    assert_eq!(
+        infer_linked_version(Path::new("/src/4.2.3")).unwrap(),
+        Version::new(4, 2, 3)
+    );
+    assert!(infer_linked_version(Path::new("/src/rabbitmq-server")).is_err());
+}
+
+#[test]
+fn cli_link_and_unlink() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let build = external_build(external.path(), "rabbitmq_server-4.3.0+dev");
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", build.to_str().unwrap(), "--name", "4.3.0+dev.local"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Linked RabbitMQ 4.3.0+dev.local"));
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version: Version = "4.3.0+dev.local".parse().unwrap();
+    assert!(paths.version_linked(&version));
+    assert!(paths.version_server_script(&version).exists());
+    assert!(paths.installed_versions().unwrap().contains(&version));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.3.0+dev.local (linked)"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.3.0+dev.local"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["unlink", "4.3.0+dev.local"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Unlinked RabbitMQ 4.3.0+dev.local",
+        ));
+
+    assert!(!paths.version_installed(&version));
+    assert!(build.join("sbin").join("rabbitmq-server").exists());
+}
+
+#[test]
+fn cli_link_infers_version() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let build = external_build(external.path(), "rabbitmq_server-4.3.0+dev");
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", build.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Linked RabbitMQ 4.3.0+dev"));
+
+    assert!(temp.path().join("versions").join("4.3.0+dev").exists());
+}
+
+#[test]
+fn cli_link_requires_name_when_it_cannot_be_inferred() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let build = external_build(external.path(), "rabbitmq-server");
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", build.to_str().unwrap()])
+        .assert()
+        .failure()
+        .code(64)
+        .stderr(predicate::str::contains("use --name"));
+}
+
+#[test]
+fn cli_link_rejects_non_rabbitmq_dir() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", external.path().to_str().unwrap(), "--name", "4.3.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("sbin/rabbitmq-server not found"));
+}
+
+#[test]
+fn cli_link_rejects_existing_version() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let build = external_build(external.path(), "build");
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", build.to_str().unwrap(), "--name", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already installed"));
+}
+
+#[test]
+fn cli_unlink_rejects_installed_version() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions").join("4.2.3")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["unlink", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a linked version"));
+
+    assert!(temp.path().join("versions").join("4.2.3").exists());
+}
+
+#[test]
+fn cli_uninstall_refuses_linked_version() {
+    let temp = TempDir::new().unwrap();
+    let external = TempDir::new().unwrap();
+    let build = external_build(external.path(), "build");
+
+    frm_cmd_with_dir(&temp)
+        .args(["link", build.to_str().unwrap(), "--name", "4.3.0+dev"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.3.0+dev", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("frm unlink 4.3.0+dev"));
+
+    assert!(build.join("sbin").join("rabbitmq-server").exists());
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::logging::filter_directives;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+#[test]
+fn filter_directives_precedence() {
+    assert_eq!(filter_directives(false, None), None);
+    assert_eq!(filter_directives(false, Some("  ")), None);
+    assert_eq!(filter_directives(true, None), Some("frm=debug".to_string()));
+    assert_eq!(
+        filter_directives(false, Some("trace")),
+        Some("trace".to_string())
+    );
+    assert_eq!(
+        filter_directives(true, Some("frm=trace,reqwest=debug")),
+        Some("frm=trace,reqwest=debug".to_string())
+    );
+}
+
+#[test]
+fn debug_flag_logs_to_stderr() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "path", "-V", "4.2.3", "--debug"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("command=\"releases path\""))
+        .stderr(predicate::str::contains("resolved=\"4.2.3\""))
+        .stderr(predicate::str::contains("elapsed_ms="))
+        .stdout(predicate::str::contains("DEBUG").not());
+}
+
+#[test]
+fn frm_log_enables_logging() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .env("FRM_LOG", "debug")
+        .arg("status")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("command=\"status\""));
+}
+
+#[test]
+fn no_logging_by_default() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .arg("status")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("DEBUG").not());
+}
@@ -0,0 +1,345 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::TailAmount;
+use frm::commands::logs::{Follower, LogLocation, tail_file};
+use frm::common::env_conf;
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn setup() -> (TempDir, Paths, Version) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.version_sbin_dir(&version)).unwrap();
+    fs::create_dir_all(paths.version_confd_dir(&version)).unwrap();
+    (temp, paths, version)
+}
+
+fn tail(path: &Path, amount: TailAmount) -> Vec<u8> {
+    let mut out = Vec::new();
+    tail_file(path, amount, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn tail_file_lines() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+
+    fs::write(&path, "one\ntwo\nthree\n").unwrap();
+    assert_eq!(tail(&path, TailAmount::Lines(2)), b"two\nthree\n");
+    assert_eq!(tail(&path, TailAmount::Lines(10)), b"one\ntwo\nthree\n");
+    assert_eq!(tail(&path, TailAmount::Lines(0)), b"");
+
+    // no newline at the end
+    fs::write(&path, "one\ntwo\nthree").unwrap();
+    assert_eq!(tail(&path, TailAmount::Lines(1)), b"three");
+
+    fs::write(&path, "").unwrap();
+    assert_eq!(tail(&path, TailAmount::Lines(5)), b"");
+}
+
+#[test]
+fn tail_file_lines_across_chunks() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    let content: String = (0..50_000).map(|i| format!("line {}\n", i)).collect();
+    fs::write(&path, &content).unwrap();
+
+    let out = String::from_utf8(tail(&path, TailAmount::Lines(20_000))).unwrap();
+    assert_eq!(out.lines().count(), 20_000);
+    assert!(out.starts_with("line 30000\n"));
+    assert!(out.ends_with("line 49999\n"));
+}
+
+#[test]
+fn tail_file_replaces_invalid_utf8_in_lines() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, b"ok\nbad \xff\xfe bytes\n").unwrap();
+
+    assert_eq!(
+        String::from_utf8(tail(&path, TailAmount::Lines(1))).unwrap(),
+        "bad \u{fffd}\u{fffd} bytes\n"
+    );
+}
+
+#[test]
+fn tail_file_keeps_characters_split_between_chunks() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    // a 3-byte character right at the 64 KiB boundary
+    let mut content = "a".repeat(64 * 1024 - 1);
+    content.push_str("€ after\n");
+    fs::write(&path, &content).unwrap();
+
+    assert_eq!(
+        String::from_utf8(tail(&path, TailAmount::Lines(1))).unwrap(),
+        content
+    );
+}
+
+#[test]
+fn tail_file_bytes_are_copied_as_is() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, b"line\n\xffend\n").unwrap();
+
+    assert_eq!(tail(&path, TailAmount::Bytes(5)), b"\xffend\n");
+    assert_eq!(tail(&path, TailAmount::Bytes(100)), b"line\n\xffend\n");
+}
+
+fn append(path: &Path, content: &str) {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+}
+
+fn poll(follower: &mut Follower) -> String {
+    let mut out = Vec::new();
+    follower.poll(&mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn follower_prints_appended_lines() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, "old\n").unwrap();
+
+    let end = tail_file(&path, TailAmount::Lines(1), &mut Vec::new()).unwrap();
+    let mut follower = Follower::new(&path, end);
+    assert_eq!(poll(&mut follower), "");
+
+    append(&path, "new 1\n");
+    append(&path, "new 2\n");
+    assert_eq!(poll(&mut follower), "new 1\nnew 2\n");
+    assert_eq!(poll(&mut follower), "");
+}
+
+#[test]
+fn follower_starts_over_after_truncation() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, "a long line before truncation\n").unwrap();
+
+    let mut follower = Follower::new(&path, fs::metadata(&path).unwrap().len());
+    fs::write(&path, "short\n").unwrap();
+    assert_eq!(poll(&mut follower), "short\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn follower_reopens_rotated_file() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, "before\n").unwrap();
+    let mut follower = Follower::new(&path, fs::metadata(&path).unwrap().len());
+
+    // written just before the rotation, then the file is renamed and replaced
+    append(&path, "last line of the old file\n");
+    fs::rename(&path, temp.path().join("rabbit@frm.log.0")).unwrap();
+    fs::write(&path, "first line of the new file\n").unwrap();
+
+    assert_eq!(
+        poll(&mut follower),
+        "last line of the old file\nfirst line of the new file\n"
+    );
+
+    append(&path, "more\n");
+    assert_eq!(poll(&mut follower), "more\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn follower_waits_for_a_rotated_file_to_be_recreated() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("rabbit@frm.log");
+    fs::write(&path, "before\n").unwrap();
+    let mut follower = Follower::new(&path, fs::metadata(&path).unwrap().len());
+
+    fs::rename(&path, temp.path().join("rabbit@frm.log.0")).unwrap();
+    assert_eq!(poll(&mut follower), "");
+
+    fs::write(&path, "recreated\n").unwrap();
+    assert_eq!(poll(&mut follower), "recreated\n");
+}
+
+#[test]
+fn env_conf_parse() {
+    let vars = env_conf::parse(
+        "# comment\n\
+         NODENAME=rabbit@frm\n\
+         export RABBITMQ_LOG_BASE=\"/var/log/frm\"\n\
+         USE_LONGNAME='true' # trailing comment\n\
+         CONFIG_FILE=/etc/rabbitmq/rabbitmq # with comment\n\
+         if [ -z \"$X\" ]; then\n",
+    );
+    assert_eq!(vars.get("NODENAME").unwrap(), "rabbit@frm");
+    assert_eq!(vars.get("LOG_BASE").unwrap(), "/var/log/frm");
+    assert_eq!(vars.get("USE_LONGNAME").unwrap(), "true");
+    assert_eq!(vars.get("CONFIG_FILE").unwrap(), "/etc/rabbitmq/rabbitmq");
+    assert_eq!(vars.len(), 4);
+}
+
+#[test]
+fn log_location_defaults() {
+    let (_temp, paths, version) = setup();
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(location.dir, paths.version_var_log_dir(&version));
+    assert_eq!(location.file, None);
+    assert!(!location.file_logging_disabled);
+}
+
+#[test]
+fn log_location_from_rabbitmq_conf() {
+    let (temp, paths, version) = setup();
+    let log_dir = temp.path().join("custom-logs");
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        format!("log.dir = {}\nlog.file = main.log\n", log_dir.display()),
+    )
+    .unwrap();
+
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(location.dir, log_dir);
+    assert_eq!(location.file, Some(log_dir.join("main.log")));
+
+    // conf.d files override rabbitmq.conf
+    fs::write(
+        paths.version_confd_dir(&version).join("90-logging.conf"),
+        "log.file = other.log\n",
+    )
+    .unwrap();
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(location.file, Some(log_dir.join("other.log")));
+
+    fs::write(
+        paths.version_confd_dir(&version).join("90-logging.conf"),
+        "log.file = false\n",
+    )
+    .unwrap();
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert!(location.file_logging_disabled);
+    assert!(
+        location
+            .find()
+            .unwrap_err()
+            .to_string()
+            .contains("log.file = false")
+    );
+}
+
+#[test]
+fn log_location_from_rabbitmq_env_conf() {
+    let (temp, paths, version) = setup();
+    let log_base = temp.path().join("log-base");
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq-env.conf"),
+        format!("NODENAME=hare@localhost\nLOG_BASE={}\n", log_base.display()),
+    )
+    .unwrap();
+
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(location.dir, log_base);
+    assert_eq!(location.file, Some(log_base.join("hare@localhost.log")));
+
+    // the node name template of frm takes precedence
+    fs::write(
+        paths.config_file(),
+        "node_name_template = \"rabbit-{version}@localhost\"\n",
+    )
+    .unwrap();
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(
+        location.file,
+        Some(log_base.join("rabbit-4.2.3@localhost.log"))
+    );
+}
+
+#[test]
+fn log_location_of_environment() {
+    let (_temp, paths, version) = setup();
+    fs::create_dir_all(paths.version_env_etc_dir(&version, "perf")).unwrap();
+
+    let location = LogLocation::resolve(&paths, &version, Some("perf")).unwrap();
+    assert_eq!(location.dir, paths.version_env_log_dir(&version, "perf"));
+}
+
+#[test]
+fn find_globs_node_log_files() {
+    let (_temp, paths, version) = setup();
+    let log_dir = paths.version_var_log_dir(&version);
+    fs::create_dir_all(&log_dir).unwrap();
+    fs::write(log_dir.join("hare@host_upgrade.log"), "").unwrap();
+    fs::write(log_dir.join("crash.log"), "").unwrap();
+    fs::write(log_dir.join("hare@host.log"), "").unwrap();
+
+    let location = LogLocation::resolve(&paths, &version, None).unwrap();
+    assert_eq!(location.find().unwrap(), log_dir.join("hare@host.log"));
+}
+
+#[test]
+fn cli_logs_path_follows_configuration() {
+    let (temp, paths, version) = setup();
+    let log_dir = temp.path().join("custom-logs");
+    fs::create_dir_all(&log_dir).unwrap();
+    fs::write(log_dir.join("main.log"), "first line\nsecond line\n").unwrap();
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        format!("log.dir = {}\nlog.file = main.log\n", log_dir.display()),
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "logs", "path", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("custom-logs"))
+        .stdout(predicate::str::contains("main.log"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "logs", "tail", "-V", "4.2.3", "-n", "1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("second line"))
+        .stdout(predicate::str::contains("first line").not());
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "logs", "tail", "-V", "4.2.3", "--bytes", "5"])
+        .assert()
+        .success()
+        .stdout("line\n");
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "releases", "logs", "tail", "-V", "4.2.3", "-n", "1", "-c", "5",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
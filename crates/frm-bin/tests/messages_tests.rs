@@ -0,0 +1,89 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::messages::{Message, terse_line};
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn catalog_messages() {
+    let version: Version = "4.2.3".parse().unwrap();
+    assert_eq!(
+        Message::Downloading(&version).to_string(),
+        "Downloading RabbitMQ 4.2.3"
+    );
+    assert_eq!(
+        Message::Repaired(&version).to_string(),
+        "RabbitMQ 4.2.3 repaired"
+    );
+    assert_eq!(
+        Message::RemovedAlphas(2).to_string(),
+        "Removed 2 alpha version(s)"
+    );
+    assert_eq!(
+        Message::NoVersionsInstalled.to_string(),
+        "No versions installed"
+    );
+}
+
+#[test]
+fn terse_lines_are_prefixed_with_the_kind() {
+    assert_eq!(
+        terse_line("info", Message::CopyingDefaultConfig),
+        "info: Copying default configuration"
+    );
+}
+
+#[test]
+fn terse_flag_prefixes_warnings_and_hints() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["--terse", "releases", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "warning: No stable RabbitMQ releases installed\n",
+        ))
+        .stdout(predicate::str::contains(
+            "hint: Install a release with: frm releases install <version>\n",
+        ))
+        .stdout(predicate::str::contains("!").not());
+}
+
+#[test]
+fn terse_flag_prefixes_errors() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--target-free", "lots", "--terse"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::starts_with("error: invalid size: lots"));
+}
+
+#[test]
+fn without_terse_flag_messages_are_decorated() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "! No stable RabbitMQ releases installed",
+        ))
+        .stdout(predicate::str::contains("hint:").not());
+}
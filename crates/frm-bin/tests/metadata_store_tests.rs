@@ -0,0 +1,240 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+#[cfg(unix)]
+use std::process;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::metadata_store::{self, StoreKind};
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+const NODE: &str = "rabbit@localhost";
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn mnesia_node(base: &Path, node: &str) {
+    let dir = base.join(node);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("schema.DAT"), vec![0u8; 2048]).unwrap();
+    fs::write(dir.join("rabbit_queue.DCD"), vec![0u8; 1024]).unwrap();
+    fs::write(dir.join("LATEST.LOG"), vec![0u8; 512]).unwrap();
+    // message store files are not metadata
+    fs::create_dir_all(dir.join("msg_stores")).unwrap();
+    fs::write(dir.join("msg_stores").join("0.rdq"), vec![0u8; 4096]).unwrap();
+}
+
+fn khepri_store(base: &Path, node: &str) {
+    let dir = base.join(node).join("coordination").join(node);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("00000001.wal"), vec![0u8; 3000]).unwrap();
+}
+
+#[test]
+fn parse_feature_flags_reads_an_erlang_list() {
+    assert_eq!(
+        metadata_store::parse_feature_flags(
+            "[classic_mirrored_queue_version,\n khepri_db,'quorum_queue'].\n"
+        ),
+        [
+            "classic_mirrored_queue_version",
+            "khepri_db",
+            "quorum_queue"
+        ]
+    );
+    assert!(metadata_store::parse_feature_flags("[].").is_empty());
+}
+
+#[test]
+fn inspect_a_mnesia_node() {
+    let temp = TempDir::new().unwrap();
+    mnesia_node(temp.path(), NODE);
+    khepri_store(temp.path(), NODE);
+    fs::write(
+        metadata_store::feature_flags_file(temp.path(), NODE),
+        "[quorum_queue,stream_queue].",
+    )
+    .unwrap();
+
+    let nodes = metadata_store::inspect(temp.path()).unwrap();
+    assert_eq!(nodes.len(), 1);
+    let node = &nodes[0];
+    assert_eq!(node.node, NODE);
+    assert_eq!(node.store, StoreKind::Mnesia);
+    assert_eq!(node.khepri_db_enabled(), Some(false));
+    assert_eq!(node.mnesia_bytes, 2048 + 1024 + 512);
+    assert_eq!(node.khepri_bytes, 3000);
+}
+
+#[test]
+fn inspect_a_khepri_node() {
+    let temp = TempDir::new().unwrap();
+    khepri_store(temp.path(), NODE);
+    fs::write(
+        metadata_store::feature_flags_file(temp.path(), NODE),
+        "[khepri_db,quorum_queue].",
+    )
+    .unwrap();
+
+    let node = metadata_store::inspect_node(temp.path(), NODE).unwrap();
+    assert_eq!(node.store, StoreKind::Khepri);
+    assert_eq!(node.khepri_db_enabled(), Some(true));
+    assert_eq!(node.mnesia_bytes, 0);
+}
+
+#[test]
+fn inspect_without_a_feature_flags_file() {
+    let temp = TempDir::new().unwrap();
+    mnesia_node(temp.path(), "rabbit@a");
+    fs::create_dir_all(temp.path().join("rabbit@b")).unwrap();
+    fs::create_dir_all(temp.path().join("rabbit@a-plugins-expand")).unwrap();
+    fs::create_dir_all(temp.path().join("not-a-node")).unwrap();
+
+    let nodes = metadata_store::inspect(temp.path()).unwrap();
+    let kinds: Vec<(&str, StoreKind)> = nodes.iter().map(|n| (n.node.as_str(), n.store)).collect();
+    assert_eq!(
+        kinds,
+        [
+            ("rabbit@a", StoreKind::Mnesia),
+            ("rabbit@b", StoreKind::Uninitialized)
+        ]
+    );
+    assert_eq!(nodes[0].khepri_db_enabled(), None);
+}
+
+#[test]
+fn inspect_a_missing_directory() {
+    let temp = TempDir::new().unwrap();
+    assert!(
+        metadata_store::inspect(&temp.path().join("missing"))
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn cli_metadata_status_text() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let base = paths.version_data_dir(&version);
+    khepri_store(&base, NODE);
+    fs::write(
+        metadata_store::feature_flags_file(&base, NODE),
+        "[khepri_db].",
+    )
+    .unwrap();
+
+    frm_cmd(&temp)
+        .args(["metadata", "status", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Node:            rabbit@localhost",
+        ))
+        .stdout(predicate::str::contains("Metadata store:  Khepri"))
+        .stdout(predicate::str::contains("1 enabled, khepri_db enabled"))
+        .stdout(predicate::str::contains("Running:         no"));
+}
+
+#[test]
+fn cli_metadata_status_json() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    mnesia_node(&paths.version_data_dir(&version), NODE);
+
+    let output = frm_cmd(&temp)
+        .args(["metadata", "status", "-V", "4.2.3", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let nodes: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(nodes[0]["node"], NODE);
+    assert_eq!(nodes[0]["store"], "mnesia");
+    assert_eq!(nodes[0]["mnesia_bytes"], 2048 + 1024 + 512);
+    assert_eq!(nodes[0]["running"], false);
+    assert!(nodes[0]["enabled_feature_flags"].is_null());
+}
+
+#[test]
+fn cli_metadata_status_without_nodes() {
+    let (temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd(&temp)
+        .args(["metadata", "status", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No node data directories"));
+    frm_cmd(&temp)
+        .args(["metadata", "status", "-V", "4.1.4"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_metadata_status_queries_running_nodes() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let base = paths.version_data_dir(&version);
+    mnesia_node(&base, NODE);
+    fs::write(
+        metadata_store::feature_flags_file(&base, NODE),
+        "[quorum_queue].",
+    )
+    .unwrap();
+    // the test process stands in for the node
+    fs::write(
+        base.join(format!("{}.pid", NODE)),
+        process::id().to_string(),
+    )
+    .unwrap();
+    let ctl = paths.version_sbin_dir(&version).join("rabbitmqctl");
+    fs::write(
+        &ctl,
+        "#!/bin/sh\necho '[{\"name\":\"quorum_queue\",\"state\":\"enabled\"},{\"name\":\"khepri_db\",\"state\":\"state_changing\"}]'\n",
+    )
+    .unwrap();
+    fs::set_permissions(&ctl, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd(&temp)
+        .args(["metadata", "status", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Metadata store:  Mnesia"))
+        .stdout(predicate::str::contains(
+            "khepri_db is state_changing on the running node",
+        ))
+        .stdout(predicate::str::contains("Running:         yes"));
+}
@@ -0,0 +1,142 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::config::{Config, render_node_name};
+use frm::envs;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn configure_template(paths: &Paths, template: &str) {
+    fs::write(
+        paths.config_file(),
+        format!("node_name_template = \"{}\"\n", template),
+    )
+    .unwrap();
+}
+
+#[test]
+fn render_node_name_placeholders() {
+    let version = Version::new(4, 2, 3);
+    assert_eq!(
+        render_node_name("rabbit-{version}@localhost", &version, None),
+        "rabbit-4.2.3@localhost"
+    );
+    assert_eq!(
+        render_node_name("rabbit-{version}-{env}@localhost", &version, Some("perf")),
+        "rabbit-4.2.3-perf@localhost"
+    );
+    assert_eq!(
+        render_node_name("rabbit-{env}", &version, None),
+        "rabbit-default"
+    );
+}
+
+#[test]
+fn config_node_name() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+
+    assert_eq!(
+        Config::load(&paths).unwrap().node_name(&version, None),
+        None
+    );
+    assert!(
+        envs::node_name_env_vars(&paths, &version, None)
+            .unwrap()
+            .is_empty()
+    );
+
+    configure_template(&paths, "rabbit-{version}@localhost");
+    assert_eq!(
+        Config::load(&paths).unwrap().node_name(&version, None),
+        Some("rabbit-4.2.3@localhost".to_string())
+    );
+    assert_eq!(
+        envs::node_name_env_vars(&paths, &version, None).unwrap(),
+        vec![("RABBITMQ_NODENAME", "rabbit-4.2.3@localhost".to_string())]
+    );
+}
+
+#[test]
+fn cli_logs_path_uses_node_name() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let log_dir = paths.version_var_log_dir(&version);
+    fs::create_dir_all(&log_dir).unwrap();
+    fs::write(log_dir.join("rabbit@localhost.log"), "").unwrap();
+    fs::write(log_dir.join("rabbit-4.2.3@localhost.log"), "").unwrap();
+    configure_template(&paths, "rabbit-{version}@localhost");
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "logs", "path", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rabbit-4.2.3@localhost.log"));
+
+    fs::remove_file(log_dir.join("rabbit-4.2.3@localhost.log")).unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "logs", "path", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("rabbit-4.2.3@localhost.log"));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_node_name_passed_to_nodes_and_cli_tools() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let sbin = paths.version_sbin_dir(&version);
+    for tool in ["rabbitmq-server", "rabbitmqctl"] {
+        fs::write(
+            sbin.join(tool),
+            "#!/bin/sh\necho \"node=$RABBITMQ_NODENAME\"\n",
+        )
+        .unwrap();
+        fs::set_permissions(sbin.join(tool), fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    configure_template(&paths, "rabbit-{version}@localhost");
+
+    frm_cmd_with_dir(&temp)
+        .env_remove("RABBITMQ_NODENAME")
+        .args(["fg", "node", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("node=rabbit-4.2.3@localhost"));
+
+    frm_cmd_with_dir(&temp)
+        .env_remove("RABBITMQ_NODENAME")
+        .args(["cli", "rabbitmqctl", "-V", "4.2.3", "--", "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("node=rabbit-4.2.3@localhost"));
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::collections::HashMap;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::paths_layout;
+use frm::envs;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path());
+    cmd
+}
+
+#[test]
+fn layout_without_a_version_has_global_paths_only() {
+    let (temp, paths) = setup_temp_paths();
+    let layout: HashMap<_, _> = paths_layout(&paths, None, None).into_iter().collect();
+
+    assert_eq!(layout["base"], temp.path());
+    assert_eq!(layout["cache"], paths.cache_dir());
+    assert_eq!(layout["versions"], paths.versions_dir());
+    assert!(!layout.contains_key("shared"));
+    assert!(!layout.contains_key("install"));
+}
+
+#[test]
+fn layout_of_a_version() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let layout: HashMap<_, _> = paths_layout(&paths, Some(&version), None)
+        .into_iter()
+        .collect();
+
+    assert_eq!(layout["install"], paths.version_dir(&version));
+    assert_eq!(layout["etc"], paths.version_etc_dir(&version));
+    assert_eq!(layout["data"], paths.version_data_dir(&version));
+    assert_eq!(layout["run"], paths.version_data_dir(&version));
+    assert_eq!(layout["logs"], paths.version_var_log_dir(&version));
+    assert_eq!(
+        layout["rabbitmq.conf"],
+        paths.version_etc_dir(&version).join("rabbitmq.conf")
+    );
+    assert!(layout["archive"].starts_with(paths.downloads_dir()));
+}
+
+#[test]
+fn layout_of_an_environment() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let layout: HashMap<_, _> = paths_layout(&paths, Some(&version), Some("tls"))
+        .into_iter()
+        .collect();
+
+    assert_eq!(layout["install"], paths.version_dir(&version));
+    assert_eq!(layout["etc"], paths.version_env_etc_dir(&version, "tls"));
+    assert_eq!(layout["data"], paths.version_env_data_dir(&version, "tls"));
+    assert_eq!(layout["logs"], paths.version_env_log_dir(&version, "tls"));
+}
+
+#[test]
+fn layout_includes_the_shared_dir() {
+    let (temp, paths) = setup_temp_paths();
+    let shared = TempDir::new().unwrap();
+    let paths = paths.with_shared_dir(shared.path().to_path_buf());
+    let layout: HashMap<_, _> = paths_layout(&paths, None, None).into_iter().collect();
+
+    assert_eq!(layout["base"], temp.path());
+    assert_eq!(layout["shared"], shared.path());
+}
+
+#[test]
+fn cli_paths_show_json() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let output = frm_cmd(&temp)
+        .args(["paths", "show", "-V", "4.2.3", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let object: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(&output.stdout).unwrap();
+    for key in ["install", "etc", "data", "logs", "run", "cache"] {
+        assert!(object.contains_key(key), "missing {}", key);
+    }
+    assert_eq!(
+        object["install"],
+        paths.version_dir(&version).display().to_string()
+    );
+}
+
+#[test]
+fn cli_paths_show_text_marks_existing_paths() {
+    let (temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+    frm_cmd(&temp)
+        .args(["paths", "show", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exists"))
+        .stdout(predicate::str::contains("sbin"))
+        .stdout(predicate::str::contains("yes"))
+        .stdout(predicate::str::contains("no"));
+}
+
+#[test]
+fn cli_paths_show_rejects_a_missing_environment() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    frm_cmd(&temp)
+        .args(["paths", "show", "-V", "4.2.3", "--env", "tls"])
+        .assert()
+        .failure();
+
+    envs::create(&paths, &version, "tls").unwrap();
+    frm_cmd(&temp)
+        .args(["paths", "show", "-V", "4.2.3", "--env", "tls"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            paths
+                .version_env_log_dir(&version, "tls")
+                .display()
+                .to_string(),
+        ));
+}
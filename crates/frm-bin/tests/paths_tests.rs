@@ -75,6 +75,20 @@ fn paths_version_var_log_dir() {
     assert!(log_dir.to_string_lossy().contains("var/log"));
 }
 
+#[test]
+fn paths_version_run_dir() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let run_dir = paths.version_run_dir(&version);
+    assert!(run_dir.starts_with(paths.version_state_dir(&version)));
+    assert!(run_dir.to_string_lossy().contains("var/run"));
+    assert!(
+        paths
+            .version_env_run_dir(&version, "blue")
+            .starts_with(paths.version_env_dir(&version, "blue"))
+    );
+}
+
 #[test]
 fn paths_etc_dir() {
     let (_temp, paths) = setup_temp_paths();
@@ -89,6 +103,14 @@ fn paths_downloads_dir() {
     assert!(downloads_dir.ends_with("downloads"));
 }
 
+#[test]
+fn paths_version_archive_file() {
+    let (_temp, paths) = setup_temp_paths();
+    let archive = paths.version_archive_file(&Version::new(4, 2, 3));
+    assert_eq!(archive.parent().unwrap(), paths.downloads_dir());
+    assert!(archive.ends_with("rabbitmq-server-generic-unix-4.2.3.tar.xz"));
+}
+
 #[test]
 fn paths_config_file() {
     let (_temp, paths) = setup_temp_paths();
@@ -0,0 +1,87 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::net::TcpListener;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::perf_test_args;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn perf_test_args_adds_uri_and_time() {
+    assert_eq!(
+        perf_test_args(5673, &args(&["--producers", "2"])),
+        args(&[
+            "--uri",
+            "amqp://localhost:5673",
+            "--time",
+            "30",
+            "--producers",
+            "2"
+        ])
+    );
+}
+
+#[test]
+fn perf_test_args_respects_user_uri_and_limits() {
+    let user = args(&["--uri=amqp://other:5672", "-C", "1000"]);
+    assert_eq!(perf_test_args(5672, &user), user);
+
+    let user = args(&["-h", "amqp://other:5672", "--time", "5"]);
+    assert_eq!(perf_test_args(5672, &user), user);
+}
+
+#[test]
+fn cli_perf_run_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["perf", "run", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_perf_run_node_not_running() {
+    let temp = TempDir::new().unwrap();
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let etc = temp.path().join("versions/4.2.3/etc/rabbitmq");
+    fs::create_dir_all(&etc).unwrap();
+    fs::write(
+        etc.join("rabbitmq.conf"),
+        format!("listeners.tcp.default = {}\n", port),
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["perf", "run", "-V", "4.2.3", "--", "--producers", "1"])
+        .assert()
+        .code(69)
+        .stderr(predicate::str::contains("could not connect to localhost"));
+
+    assert!(!temp.path().join("tools").exists());
+}
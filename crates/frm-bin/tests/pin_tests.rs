@@ -0,0 +1,252 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install_in;
+
+const ALPHA: &str = "4.3.0-alpha.132057c7";
+const OTHER_ALPHA: &str = "4.3.0-alpha.abcd1234";
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn paths_for(dir: &TempDir) -> Paths {
+    Paths::with_base_dir(dir.path().to_path_buf())
+}
+
+#[test]
+fn metadata_load_defaults_when_missing() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+
+    let metadata = VersionMetadata::load(&paths, &Version::new(4, 2, 3)).unwrap();
+    assert_eq!(metadata, VersionMetadata::default());
+    assert!(!metadata.pinned);
+}
+
+#[test]
+fn metadata_save_and_load() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    let version = Version::new(4, 2, 3);
+
+    let metadata = VersionMetadata {
+        pinned: true,
+        ..Default::default()
+    };
+    metadata.save(&paths, &version).unwrap();
+
+    assert!(paths.version_metadata_file(&version).exists());
+    assert!(VersionMetadata::is_pinned(&paths, &version).unwrap());
+    assert!(!VersionMetadata::is_pinned(&paths, &Version::new(4, 2, 4)).unwrap());
+}
+
+#[test]
+fn metadata_remove() {
+    let temp = TempDir::new().unwrap();
+    let paths = paths_for(&temp);
+    let version = Version::new(4, 2, 3);
+
+    VersionMetadata {
+        pinned: true,
+        ..Default::default()
+    }
+    .save(&paths, &version)
+    .unwrap();
+    VersionMetadata::remove(&paths, &version).unwrap();
+
+    assert!(!paths.version_metadata_file(&version).exists());
+    VersionMetadata::remove(&paths, &version).unwrap();
+}
+
+#[test]
+fn cli_releases_pin_and_unpin() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "pin", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Pinned RabbitMQ 4.2.3"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3 (pinned)"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "unpin", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Unpinned RabbitMQ 4.2.3"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "list", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(pinned)").not());
+}
+
+#[test]
+fn cli_releases_pin_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "pin", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_releases_pin_rejects_alpha() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "pin", ALPHA])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected a non-alpha version"));
+}
+
+#[test]
+fn cli_alphas_pin_latest() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", "latest"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "list", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!("{} (pinned)", ALPHA)));
+}
+
+#[test]
+fn cli_alphas_pin_twice_is_a_no_op() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", ALPHA])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", ALPHA])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already pinned"));
+}
+
+#[test]
+fn cli_alphas_prune_keeps_pinned() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+    install_in(temp.path(), OTHER_ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", ALPHA])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "prune"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Keeping pinned RabbitMQ {}",
+            ALPHA
+        )))
+        .stdout(predicate::str::contains("Removed 1 alpha version(s)"));
+
+    assert!(temp.path().join("versions").join(ALPHA).exists());
+    assert!(!temp.path().join("versions").join(OTHER_ALPHA).exists());
+}
+
+#[test]
+fn cli_alphas_prune_all_pinned() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", ALPHA])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "prune"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "All installed alpha versions are pinned",
+        ));
+
+    assert!(temp.path().join("versions").join(ALPHA).exists());
+}
+
+#[test]
+fn cli_alphas_clean_keeps_pinned() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), ALPHA);
+    install_in(temp.path(), OTHER_ALPHA);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "pin", ALPHA])
+        .assert()
+        .success();
+
+    // No timestamps recorded: both alphas count as older than any cutoff
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "clean", "--older-than", "yesterday"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 alpha version(s)"));
+
+    assert!(temp.path().join("versions").join(ALPHA).exists());
+    assert!(!temp.path().join("versions").join(OTHER_ALPHA).exists());
+}
+
+#[test]
+fn cli_uninstall_removes_metadata() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+    let paths = paths_for(&temp);
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "pin", "4.2.3"])
+        .assert()
+        .success();
+    assert!(paths.version_metadata_file(&Version::new(4, 2, 3)).exists());
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3"])
+        .assert()
+        .success();
+    assert!(!paths.version_metadata_file(&Version::new(4, 2, 3)).exists());
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::time::Duration;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::amqp;
+use frm::errors::Error;
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn install_with_conf(dir: &TempDir, version: &str, conf: &str) {
+    let paths = install_in(dir.path(), version);
+    let etc = paths.version_etc_dir(&version.parse().unwrap());
+    fs::write(etc.join("rabbitmq.conf"), conf).unwrap();
+}
+
+fn long_str(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(&(s.len() as u32).to_be_bytes());
+    buf.extend_from_slice(s.as_bytes());
+}
+
+fn table_entry(buf: &mut Vec<u8>, key: &str, value: &str) {
+    buf.push(key.len() as u8);
+    buf.extend_from_slice(key.as_bytes());
+    buf.push(b'S');
+    long_str(buf, value);
+}
+
+fn connection_start_frame(version: &str) -> Vec<u8> {
+    let mut table = Vec::new();
+    // a nested table and a boolean to check that non-string values are skipped
+    table.extend_from_slice(b"\x0ccapabilities");
+    table.push(b'F');
+    let mut capabilities = Vec::new();
+    capabilities.extend_from_slice(b"\x0fpublisher_confirms");
+    capabilities.extend_from_slice(b"t\x01");
+    table.extend_from_slice(&(capabilities.len() as u32).to_be_bytes());
+    table.extend_from_slice(&capabilities);
+    table_entry(&mut table, "cluster_name", "rabbit@test");
+    table_entry(&mut table, "product", "RabbitMQ");
+    table_entry(&mut table, "version", version);
+
+    let mut payload = Vec::new();
+    payload.extend_from_slice(&10u16.to_be_bytes());
+    payload.extend_from_slice(&10u16.to_be_bytes());
+    payload.extend_from_slice(&[0, 9]);
+    payload.extend_from_slice(&(table.len() as u32).to_be_bytes());
+    payload.extend_from_slice(&table);
+    long_str(&mut payload, "PLAIN AMQPLAIN");
+    long_str(&mut payload, "en_US");
+
+    let mut frame = vec![1, 0, 0];
+    frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&payload);
+    frame.push(0xCE);
+    frame
+}
+
+/// Accepts a single connection and responds to the protocol header with `response`
+fn fake_server(response: Vec<u8>) -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut header = [0u8; 8];
+        stream.read_exact(&mut header).unwrap();
+        assert_eq!(&header, b"AMQP\x00\x00\x09\x01");
+        stream.write_all(&response).unwrap();
+    });
+    port
+}
+
+fn unused_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+#[test]
+fn amqp_handshake_reads_server_properties() {
+    let port = fake_server(connection_start_frame("4.2.3"));
+
+    let info = amqp::handshake("127.0.0.1", port, Duration::from_secs(5)).unwrap();
+    assert_eq!(info.protocol, (0, 9));
+    assert_eq!(info.product.as_deref(), Some("RabbitMQ"));
+    assert_eq!(info.version.as_deref(), Some("4.2.3"));
+    assert_eq!(info.cluster_name.as_deref(), Some("rabbit@test"));
+    assert_eq!(info.mechanisms, vec!["PLAIN", "AMQPLAIN"]);
+    assert_eq!(info.address.port(), port);
+}
+
+#[test]
+fn amqp_handshake_rejects_protocol_mismatch() {
+    let port = fake_server(b"AMQP\x00\x00\x09\x01".to_vec());
+
+    let result = amqp::handshake("127.0.0.1", port, Duration::from_secs(5));
+    assert!(matches!(result, Err(Error::PingFailed { .. })));
+}
+
+#[test]
+fn amqp_handshake_rejects_non_amqp_listener() {
+    let port = fake_server(b"HTTP/1.1 400 Bad Request\r\n\r\n".to_vec());
+
+    let result = amqp::handshake("127.0.0.1", port, Duration::from_secs(5));
+    assert!(matches!(result, Err(Error::PingFailed { .. })));
+}
+
+#[test]
+fn amqp_handshake_connection_refused() {
+    let port = unused_port();
+
+    let result = amqp::handshake("127.0.0.1", port, Duration::from_secs(5));
+    assert!(matches!(result, Err(Error::PingFailed { .. })));
+}
+
+#[test]
+fn cli_ping_uses_configured_listener() {
+    let temp = TempDir::new().unwrap();
+    let port = fake_server(connection_start_frame("4.2.3"));
+    install_with_conf(
+        &temp,
+        "4.2.3",
+        &format!("listeners.tcp.default = 127.0.0.1:{}\n", port),
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["ping", "-V", "4.2.3", "--host", "127.0.0.1"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Connected to RabbitMQ 4.2.3"))
+        .stdout(predicate::str::contains("Cluster name: rabbit@test"));
+}
+
+#[test]
+fn cli_ping_warns_about_other_version() {
+    let temp = TempDir::new().unwrap();
+    let port = fake_server(connection_start_frame("4.1.0"));
+    install_with_conf(&temp, "4.2.3", "");
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "ping",
+            "-V",
+            "4.2.3",
+            "--host",
+            "127.0.0.1",
+            "--port",
+            &port.to_string(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("a different node is listening"));
+}
+
+#[test]
+fn cli_ping_nothing_listening() {
+    let temp = TempDir::new().unwrap();
+    let port = unused_port();
+    install_with_conf(
+        &temp,
+        "4.2.3",
+        &format!("listeners.tcp.default = {}\n", port),
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["ping", "-V", "4.2.3", "--host", "127.0.0.1"])
+        .assert()
+        .code(69)
+        .stderr(predicate::str::contains("could not connect to 127.0.0.1"));
+}
+
+#[test]
+fn cli_ping_not_installed() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["ping", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::plugins;
+
+fn write_executable(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+fn path_var(dirs: &[&Path]) -> OsString {
+    env::join_paths(dirs).unwrap()
+}
+
+#[allow(deprecated)]
+fn frm_cmd(frm_dir: &TempDir, bin_dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", frm_dir.path())
+        .env(
+            "PATH",
+            path_var(&[bin_dir, Path::new("/bin"), Path::new("/usr/bin")]),
+        )
+        .env_remove("FRM_LOG")
+        .env_remove("RABBITMQ_HOME")
+        .env_remove("FRM_ACTIVE_SBIN");
+    cmd
+}
+
+#[test]
+fn find_returns_the_first_executable_in_path() {
+    let first = TempDir::new().unwrap();
+    let second = TempDir::new().unwrap();
+    write_executable(&second.path().join("frm-k8s"), "#!/bin/sh\n");
+    // not executable, so skipped
+    fs::write(first.path().join("frm-k8s"), "#!/bin/sh\n").unwrap();
+
+    let path_var = path_var(&[first.path(), second.path()]);
+    assert_eq!(
+        plugins::find("k8s", &path_var),
+        Some(second.path().join("frm-k8s"))
+    );
+    assert_eq!(plugins::find("helm", &path_var), None);
+    assert_eq!(plugins::find("../frm-k8s", &path_var), None);
+}
+
+#[test]
+fn list_returns_plugins_by_name_first_one_wins() {
+    let first = TempDir::new().unwrap();
+    let second = TempDir::new().unwrap();
+    write_executable(&first.path().join("frm-k8s"), "#!/bin/sh\n");
+    write_executable(&second.path().join("frm-k8s"), "#!/bin/sh\n");
+    write_executable(&second.path().join("frm-bench"), "#!/bin/sh\n");
+    write_executable(&second.path().join("frm-"), "#!/bin/sh\n");
+    write_executable(&second.path().join("other"), "#!/bin/sh\n");
+
+    let plugins = plugins::list(&path_var(&[first.path(), second.path()]));
+    let names: Vec<&str> = plugins.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["bench", "k8s"]);
+    assert_eq!(plugins[1].path, first.path().join("frm-k8s"));
+}
+
+#[test]
+fn cli_runs_external_subcommands_with_frm_variables() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+    write_executable(
+        &bin_dir.path().join("frm-hello"),
+        "#!/bin/sh\necho \"$FRM_DIR|$* \"\n[ -n \"$FRM_BIN\" ] || exit 9\n",
+    );
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .args(["hello", "-V", "4.2.3", "--flag"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{}|-V 4.2.3 --flag",
+            frm_dir.path().display()
+        )));
+}
+
+#[test]
+fn cli_passes_the_default_version_to_external_subcommands() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+    let sbin = frm_dir.path().join("versions").join("4.2.3").join("sbin");
+    write_executable(&sbin.join("rabbitmq-server"), "#!/bin/sh\n");
+    fs::write(
+        frm_dir.path().join("config.toml"),
+        "default_version = \"4.2.3\"\n",
+    )
+    .unwrap();
+    write_executable(
+        &bin_dir.path().join("frm-hello"),
+        "#!/bin/sh\necho \"$FRM_VERSION $FRM_SBIN_DIR\"\n",
+    );
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .arg("hello")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "4.2.3 {}",
+            sbin.display()
+        )));
+}
+
+#[test]
+fn cli_exits_with_the_external_subcommand_exit_code() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+    write_executable(&bin_dir.path().join("frm-fail"), "#!/bin/sh\nexit 4\n");
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .arg("fail")
+        .assert()
+        .code(4);
+}
+
+#[test]
+fn cli_built_in_commands_take_precedence() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+    write_executable(&bin_dir.path().join("frm-status"), "#!/bin/sh\nexit 4\n");
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .arg("status")
+        .assert()
+        .success();
+}
+
+#[test]
+fn cli_unknown_commands_suggest_the_nearest_built_in_one() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .arg("relases")
+        .assert()
+        .code(64)
+        .stderr(predicate::str::contains("no frm-relases executable"))
+        .stderr(predicate::str::contains("Did you mean 'releases'?"));
+}
+
+#[test]
+fn cli_completes_external_subcommands() {
+    let frm_dir = TempDir::new().unwrap();
+    let bin_dir = TempDir::new().unwrap();
+    write_executable(&bin_dir.path().join("frm-k8s"), "#!/bin/sh\n");
+
+    frm_cmd(&frm_dir, bin_dir.path())
+        .args(["__complete", "k"])
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match("(?m)^k8s\tplugin at").unwrap());
+}
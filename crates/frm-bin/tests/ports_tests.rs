@@ -0,0 +1,178 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::net::TcpListener;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::ports::{self, Listener};
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn settings(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn find<'a>(listeners: &'a [Listener], protocol: &str) -> Vec<&'a Listener> {
+    listeners
+        .iter()
+        .filter(|l| l.protocol == protocol)
+        .collect()
+}
+
+#[test]
+fn listeners_defaults() {
+    let listeners = ports::listeners(&settings(&[]), &settings(&[]), None).unwrap();
+
+    let amqp = find(&listeners, "AMQP");
+    assert_eq!(amqp.len(), 1);
+    assert_eq!(amqp[0].port, 5672);
+    assert_eq!(amqp[0].source, None);
+    assert!(amqp[0].enabled);
+
+    let management = find(&listeners, "Management")[0];
+    assert_eq!(management.port, 15672);
+    assert!(!management.enabled);
+
+    assert!(find(&listeners, "AMQPS").is_empty());
+    assert_eq!(find(&listeners, "Erlang distribution")[0].port, 25672);
+}
+
+#[test]
+fn listeners_from_settings() {
+    let conf = settings(&[
+        ("listeners.tcp.default", "127.0.0.1:5673"),
+        ("listeners.tcp.other", "5674"),
+        ("listeners.ssl.default", "5671"),
+        ("management.tcp.port", "15673"),
+        ("mqtt.listeners.tcp", "none"),
+    ]);
+    let plugins = vec!["rabbitmq_management".to_string()];
+    let listeners = ports::listeners(&conf, &settings(&[]), Some(&plugins)).unwrap();
+
+    let amqp: Vec<u16> = find(&listeners, "AMQP").iter().map(|l| l.port).collect();
+    assert_eq!(amqp, vec![5673, 5674]);
+    assert_eq!(
+        find(&listeners, "AMQP")[0].source.as_deref(),
+        Some("listeners.tcp.default")
+    );
+    assert_eq!(find(&listeners, "AMQPS")[0].port, 5671);
+
+    let management = find(&listeners, "Management")[0];
+    assert_eq!(management.port, 15673);
+    assert!(management.enabled);
+
+    assert!(find(&listeners, "MQTT").is_empty());
+    // the distribution port is derived from the (first) AMQP port
+    assert_eq!(find(&listeners, "Erlang distribution")[0].port, 25673);
+}
+
+#[test]
+fn listeners_dist_port_from_env_conf() {
+    let env = settings(&[("DIST_PORT", "25999")]);
+    let listeners = ports::listeners(&settings(&[]), &env, None).unwrap();
+    let dist = find(&listeners, "Erlang distribution")[0];
+    assert_eq!(dist.port, 25999);
+    assert_eq!(dist.source.as_deref(), Some("RABBITMQ_DIST_PORT"));
+}
+
+#[test]
+fn listeners_reject_invalid_ports() {
+    let conf = settings(&[("stomp.listeners.tcp.1", "nope")]);
+    let err = ports::listeners(&conf, &settings(&[]), None).unwrap_err();
+    assert!(err.to_string().contains("stomp.listeners.tcp.1"));
+}
+
+#[test]
+fn parse_listener_port() {
+    assert_eq!(ports::parse_listener_port("5672"), Some(5672));
+    assert_eq!(ports::parse_listener_port("127.0.0.1:5673"), Some(5673));
+    assert_eq!(ports::parse_listener_port("::1:5674"), Some(5674));
+    assert_eq!(ports::parse_listener_port("none"), None);
+}
+
+#[test]
+fn port_in_use() {
+    let listener = TcpListener::bind("0.0.0.0:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    assert_eq!(ports::port_in_use(port), Some(true));
+    drop(listener);
+    assert_eq!(ports::port_in_use(port), Some(false));
+}
+
+#[test]
+fn ports_report_flags_conflicts() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.version_sbin_dir(&version)).unwrap();
+    fs::create_dir_all(paths.version_confd_dir(&version)).unwrap();
+
+    let taken = TcpListener::bind("0.0.0.0:0").unwrap();
+    let taken_port = taken.local_addr().unwrap().port();
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        format!("listeners.tcp.default = {}\n", taken_port),
+    )
+    .unwrap();
+    // conf.d overrides rabbitmq.conf
+    fs::write(
+        paths.version_confd_dir(&version).join("20-management.conf"),
+        "management.tcp.port = 25999\n",
+    )
+    .unwrap();
+    fs::write(
+        paths.version_etc_dir(&version).join("enabled_plugins"),
+        "[rabbitmq_management].\n",
+    )
+    .unwrap();
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq-env.conf"),
+        "DIST_PORT=25999\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["ports", "report", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("listeners.tcp.default"))
+        .stdout(predicate::str::contains(format!(
+            "port {} (AMQP) is already in use",
+            taken_port
+        )))
+        .stdout(predicate::str::contains(
+            "Management and Erlang distribution are both configured to use port 25999",
+        ))
+        .stdout(predicate::str::contains("rabbitmq_mqtt not enabled"));
+}
+
+#[test]
+fn ports_report_requires_installed_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["ports", "report", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use assert_cmd::Command;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::profile::{Phase, format_report};
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn create_test_tarball(temp_dir: &TempDir, name: &str, inner_dir: &str) -> PathBuf {
+    let tarball_path = temp_dir.path().join(name);
+    let file = fs::File::create(&tarball_path).unwrap();
+    let encoder = GzEncoder::new(file, Compression::default());
+    let mut archive = tar::Builder::new(encoder);
+
+    let sbin_path = temp_dir.path().join("tarball_content").join("sbin");
+    fs::create_dir_all(&sbin_path).unwrap();
+    fs::write(sbin_path.join("rabbitmqctl"), "#!/bin/sh\necho test\n").unwrap();
+    fs::write(
+        sbin_path.join("rabbitmq-server"),
+        "#!/bin/sh\necho server\n",
+    )
+    .unwrap();
+
+    archive
+        .append_dir_all(inner_dir, temp_dir.path().join("tarball_content"))
+        .unwrap();
+    archive.finish().unwrap();
+
+    tarball_path
+}
+
+#[test]
+fn format_report_lists_phases_other_and_total() {
+    let timings = [
+        (Phase::Download, Duration::from_millis(3000)),
+        (Phase::Extract, Duration::from_millis(500)),
+    ];
+    let report = format_report(&timings, Duration::from_millis(4000));
+
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "Timing breakdown:");
+    assert!(lines[1].contains("download") && lines[1].contains("3.000s"));
+    assert!(lines[1].contains("75.0%"));
+    assert!(lines[2].contains("extract") && lines[2].contains("0.500s"));
+    assert!(lines[3].contains("other") && lines[3].contains("0.500s"));
+    assert!(lines[4].contains("total") && lines[4].contains("4.000s"));
+}
+
+#[test]
+fn format_report_without_phases_has_only_the_total() {
+    let report = format_report(&[], Duration::from_millis(20));
+    assert!(!report.contains("other"));
+    assert!(report.contains("total"));
+}
+
+#[test]
+fn phase_names() {
+    let names: Vec<&str> = Phase::ALL.iter().map(|p| p.name()).collect();
+    assert_eq!(
+        names,
+        ["resolve", "download", "verify", "extract", "post-install"]
+    );
+}
+
+#[test]
+fn profile_flag_reports_tanzu_install_phases() {
+    let temp = TempDir::new().unwrap();
+    let tarball = create_test_tarball(
+        &temp,
+        "tanzu-rabbitmq-x86_64-4.2.3.tar.gz",
+        "rabbitmq_server-4.2.3",
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["--profile", "tanzu", "install", "-V", "4.2.3"])
+        .arg("--local-tanzu-rabbitmq-tarball-path")
+        .arg(&tarball)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Timing breakdown:"))
+        .stderr(predicate::str::contains("extract"))
+        .stderr(predicate::str::contains("verify"))
+        .stderr(predicate::str::contains("post-install"))
+        .stderr(predicate::str::contains("total"))
+        .stdout(predicate::str::contains("Timing breakdown").not());
+}
+
+#[test]
+fn without_profile_flag_there_is_no_report() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .arg("status")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Timing breakdown").not());
+}
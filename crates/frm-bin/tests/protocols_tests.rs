@@ -0,0 +1,278 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(unix)]
+use std::process;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn install_with_plugins(paths: &Paths, version: &Version) {
+    install(paths, version);
+    let etc = paths.version_etc_dir(version);
+    fs::write(etc.join("enabled_plugins"), "[rabbitmq_management].\n").unwrap();
+}
+
+fn read_etc(paths: &Paths, version: &Version, file: &str) -> String {
+    fs::read_to_string(paths.version_etc_dir(version).join(file)).unwrap()
+}
+
+#[test]
+fn protocols_enable_mqtt() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "mqtt", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "set mqtt.listeners.tcp.default = 1883",
+        ))
+        .stdout(predicate::str::contains(
+            "MQTT is enabled for RabbitMQ 4.2.3 on port 1883",
+        ))
+        .stdout(predicate::str::contains(
+            "Start the node with: frm bg start -V 4.2.3",
+        ));
+
+    assert_eq!(
+        read_etc(&paths, &version, "enabled_plugins"),
+        "[rabbitmq_management,rabbitmq_mqtt].\n"
+    );
+    assert!(
+        read_etc(&paths, &version, "rabbitmq.conf").contains("mqtt.listeners.tcp.default = 1883")
+    );
+
+    // running it again changes nothing
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "mqtt", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rabbitmq_mqtt is already enabled"))
+        .stdout(predicate::str::contains(
+            "mqtt.listeners.tcp.default is already 1883",
+        ));
+}
+
+#[test]
+fn protocols_enable_stomp_on_another_port() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+
+    frm_cmd(&temp)
+        .args([
+            "protocols",
+            "enable",
+            "stomp",
+            "-V",
+            "4.2.3",
+            "--port",
+            "61614",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "STOMP is enabled for RabbitMQ 4.2.3 on port 61614",
+        ));
+    assert!(
+        read_etc(&paths, &version, "rabbitmq.conf").contains("stomp.listeners.tcp.default = 61614")
+    );
+}
+
+#[test]
+fn protocols_enable_amqp10_is_built_into_4_x() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "amqp10", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "AMQP 1.0 is built into RabbitMQ 4.2.3, no plugin to enable",
+        ))
+        .stdout(predicate::str::contains(
+            "AMQP 1.0 is enabled for RabbitMQ 4.2.3 on port 5672",
+        ));
+    assert_eq!(
+        read_etc(&paths, &version, "enabled_plugins"),
+        "[rabbitmq_management].\n"
+    );
+}
+
+#[test]
+fn protocols_enable_amqp10_uses_a_plugin_before_4_0() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(3, 13, 7);
+    install_with_plugins(&paths, &version);
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "amqp10", "-V", "3.13.7"])
+        .assert()
+        .success();
+    assert_eq!(
+        read_etc(&paths, &version, "enabled_plugins"),
+        "[rabbitmq_management,rabbitmq_amqp1_0].\n"
+    );
+}
+
+#[test]
+fn protocols_enable_keeps_an_existing_listener_key() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        "mqtt.listeners.tcp.1 = 1885\n",
+    )
+    .unwrap();
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "mqtt", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "mqtt.listeners.tcp.1 is already 1885",
+        ))
+        .stdout(predicate::str::contains("on port 1885"));
+    assert_eq!(
+        read_etc(&paths, &version, "rabbitmq.conf"),
+        "mqtt.listeners.tcp.1 = 1885\n"
+    );
+}
+
+#[test]
+fn protocols_enable_a_disabled_listener() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+    fs::write(
+        paths.version_etc_dir(&version).join("rabbitmq.conf"),
+        "stomp.listeners.tcp = none\n",
+    )
+    .unwrap();
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "stomp", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass --port to enable it"));
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "stomp", "-V", "4.2.3", "-p", "61613"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "removed stomp.listeners.tcp = none",
+        ));
+    let conf = read_etc(&paths, &version, "rabbitmq.conf");
+    assert!(!conf.contains("none"));
+    assert!(conf.contains("stomp.listeners.tcp.default = 61613"));
+}
+
+#[test]
+fn protocols_enable_warns_about_port_conflicts() {
+    let (temp, paths) = setup_temp_paths();
+    install_with_plugins(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd(&temp)
+        .args([
+            "protocols",
+            "enable",
+            "mqtt",
+            "-V",
+            "4.2.3",
+            "--port",
+            "5672",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "port 5672 is also used by the AMQP listener",
+        ));
+}
+
+#[test]
+fn protocols_enable_requires_an_installed_version() {
+    let (temp, _paths) = setup_temp_paths();
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "mqtt", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "amqp091", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'amqp091'"));
+}
+
+#[cfg(unix)]
+#[test]
+fn protocols_enable_on_a_running_node() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install_with_plugins(&paths, &version);
+    let data_dir = paths.version_data_dir(&version);
+    fs::create_dir_all(&data_dir).unwrap();
+    // the test process stands in for the node
+    fs::write(
+        data_dir.join("rabbit@localhost.pid"),
+        process::id().to_string(),
+    )
+    .unwrap();
+    let log = temp.path().join("calls.log");
+    let plugins = paths.version_sbin_dir(&version).join("rabbitmq-plugins");
+    fs::write(
+        &plugins,
+        format!("#!/bin/sh\necho \"$*\" >> '{}'\n", log.display()),
+    )
+    .unwrap();
+    fs::set_permissions(&plugins, fs::Permissions::from_mode(0o755)).unwrap();
+
+    frm_cmd(&temp)
+        .args(["protocols", "enable", "mqtt", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Restart the node for the listener change to take effect",
+        ));
+    assert_eq!(
+        fs::read_to_string(&log).unwrap(),
+        "-n rabbit@localhost enable rabbitmq_mqtt\n"
+    );
+}
@@ -0,0 +1,177 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io::Write;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tempfile::TempDir;
+use xz2::write::XzEncoder;
+
+use frm::metadata::{SignatureCheck, VersionMetadata};
+use frm::paths::Paths;
+use frm::provenance;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn tar_xz(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, content) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o755);
+        header.set_cksum();
+        builder.append_data(&mut header, name, *content).unwrap();
+    }
+    let tar = builder.into_inner().unwrap();
+    let mut encoder = XzEncoder::new(Vec::new(), 6);
+    encoder.write_all(&tar).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Installs 4.2.3 from an archive in the downloads directory, without network access,
+/// and returns the archive's checksum
+fn install_from_downloaded_archive(temp: &TempDir) -> String {
+    let version = Version::new(4, 2, 3);
+    let archive = tar_xz(&[("rabbitmq_server-4.2.3/sbin/rabbitmq-server", b"#!/bin/sh\n")]);
+    let downloads = temp.path().join("downloads");
+    fs::create_dir_all(&downloads).unwrap();
+    fs::write(downloads.join(version.archive_name()), &archive).unwrap();
+
+    frm_cmd(temp)
+        .args(["releases", "install", "4.2.3"])
+        .assert()
+        .success();
+    provenance::sha256_hex(Sha256::digest(&archive))
+}
+
+#[test]
+fn sha256_file_matches_known_digest() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("abc");
+    fs::write(&file, "abc").unwrap();
+    assert_eq!(
+        provenance::sha256_file(&file).unwrap(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn cli_install_records_provenance() {
+    let temp = TempDir::new().unwrap();
+    let sha256 = install_from_downloaded_archive(&temp);
+
+    let output = frm_cmd(&temp)
+        .args(["releases", "provenance", "4.2.3", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let record: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(record["name"], "rabbitmq-server");
+    assert_eq!(record["version"], "4.2.3");
+    assert_eq!(record["sha256"], sha256.as_str());
+    assert!(
+        record["source"]
+            .as_str()
+            .unwrap()
+            .starts_with("https://github.com/rabbitmq/rabbitmq-server/releases/download/")
+    );
+    assert_eq!(record["signature"]["status"], "unknown");
+    assert_eq!(
+        record["installed_by"],
+        format!("frm {}", env!("CARGO_PKG_VERSION"))
+    );
+
+    frm_cmd(&temp)
+        .args(["releases", "provenance", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "SHA-256:      {}\n",
+            sha256
+        )))
+        .stdout(predicate::str::contains("Signature:    unknown\n"));
+}
+
+#[test]
+fn cli_provenance_spdx_lite() {
+    let temp = TempDir::new().unwrap();
+    let sha256 = install_from_downloaded_archive(&temp);
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    VersionMetadata::update(&paths, &Version::new(4, 2, 3), |m| {
+        m.signature = Some(SignatureCheck {
+            verified: true,
+            checked_at: 1_760_000_000,
+        })
+    })
+    .unwrap();
+
+    frm_cmd(&temp)
+        .args(["releases", "provenance", "4.2.3", "--format", "spdx-lite"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("SPDXVersion: SPDX-2.3\n"))
+        .stdout(predicate::str::contains("PackageName: rabbitmq-server\n"))
+        .stdout(predicate::str::contains("PackageVersion: 4.2.3\n"))
+        .stdout(predicate::str::contains(format!(
+            "PackageChecksum: SHA256: {}\n",
+            sha256
+        )))
+        .stdout(predicate::str::contains(
+            "PackageComment: <text>Signature: verified, checked at 2025-10-09T08:53:20Z.",
+        ))
+        .stdout(predicate::str::contains(
+            "Relationship: SPDXRef-DOCUMENT DESCRIBES SPDXRef-Package-rabbitmq-server\n",
+        ));
+}
+
+#[test]
+fn cli_provenance_of_versions_without_a_record() {
+    let temp = TempDir::new().unwrap();
+    let sbin = temp.path().join("versions").join("4.1.5").join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+
+    frm_cmd(&temp)
+        .args(["releases", "provenance", "4.1.5"])
+        .assert()
+        .code(66)
+        .stderr(predicate::str::contains(
+            "RabbitMQ 4.1.5 has no provenance record",
+        ));
+    frm_cmd(&temp)
+        .args(["releases", "provenance", "4.0.9"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn uninstalling_removes_the_provenance_record() {
+    let temp = TempDir::new().unwrap();
+    install_from_downloaded_archive(&temp);
+    frm_cmd(&temp)
+        .args(["releases", "uninstall", "4.2.3", "--yes"])
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    assert_eq!(
+        provenance::Provenance::load(&paths, &Version::new(4, 2, 3)).unwrap(),
+        None
+    );
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn provider_install(data_dir: &Path, version_dir: &str) -> PathBuf {
+    let dir = data_dir.join("installs").join("rabbitmq").join(version_dir);
+    let sbin = dir.join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    dir
+}
+
+#[test]
+fn cli_use_falls_back_to_asdf() {
+    let temp = TempDir::new().unwrap();
+    let asdf = TempDir::new().unwrap();
+    let install = provider_install(asdf.path(), "4.1.2");
+
+    frm_cmd_with_dir(&temp)
+        .env("ASDF_DATA_DIR", asdf.path())
+        .args([
+            "releases",
+            "use",
+            "4.1.2",
+            "--provider",
+            "asdf",
+            "--shell",
+            "bash",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("versions/4.1.2/sbin"))
+        .stderr(predicate::str::contains("installed by asdf"));
+
+    let link = temp.path().join("versions").join("4.1.2");
+    assert!(
+        fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+    assert_eq!(
+        fs::canonicalize(&link).unwrap(),
+        fs::canonicalize(&install).unwrap()
+    );
+
+    // the provider's installation cannot be uninstalled through frm
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.1.2", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("frm unlink 4.1.2"));
+}
+
+#[test]
+fn cli_use_prefers_versions_installed_by_frm() {
+    let temp = TempDir::new().unwrap();
+    let asdf = TempDir::new().unwrap();
+    provider_install(asdf.path(), "4.1.2");
+    let sbin = temp.path().join("versions").join("4.1.2").join("sbin");
+    fs::create_dir_all(&sbin).unwrap();
+    fs::write(sbin.join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .env("ASDF_DATA_DIR", asdf.path())
+        .args([
+            "releases",
+            "use",
+            "4.1.2",
+            "--provider",
+            "asdf",
+            "--shell",
+            "bash",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("asdf").not());
+
+    assert!(
+        !fs::symlink_metadata(temp.path().join("versions").join("4.1.2"))
+            .unwrap()
+            .file_type()
+            .is_symlink()
+    );
+}
+
+#[test]
+fn cli_use_mise_extracted_layout() {
+    let temp = TempDir::new().unwrap();
+    let mise = TempDir::new().unwrap();
+    provider_install(mise.path(), "4.2.3/rabbitmq_server-4.2.3");
+
+    frm_cmd_with_dir(&temp)
+        .env("MISE_DATA_DIR", mise.path())
+        .args([
+            "releases",
+            "use",
+            "4.2.3",
+            "--provider",
+            "mise",
+            "--shell",
+            "bash",
+        ])
+        .assert()
+        .success();
+
+    assert!(
+        temp.path()
+            .join("versions")
+            .join("4.2.3")
+            .join("sbin")
+            .join("rabbitmq-server")
+            .exists()
+    );
+}
+
+#[test]
+fn cli_use_provider_missing_version() {
+    let temp = TempDir::new().unwrap();
+    let asdf = TempDir::new().unwrap();
+    provider_install(asdf.path(), "4.0.9");
+
+    frm_cmd_with_dir(&temp)
+        .env("ASDF_DATA_DIR", asdf.path())
+        .args(["releases", "use", "4.1.2", "--provider", "asdf"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed by asdf"))
+        .stderr(predicate::str::contains("4.0.9"));
+}
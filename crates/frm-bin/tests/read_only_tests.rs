@@ -0,0 +1,183 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::cli::{READ_ONLY_COMMANDS, SOMETIMES_WRITING_COMMANDS, WRITING_COMMANDS, command_names};
+use frm::paths::Paths;
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path())
+        .env("FRM_READ_ONLY", "1")
+        .env_remove("FRM_LOG");
+    cmd
+}
+
+fn install_with_conf(temp: &TempDir, version: &str) {
+    let paths = install_in(temp.path(), version);
+    let etc = paths.version_etc_dir(&version.parse().unwrap());
+    fs::write(etc.join("rabbitmq.conf"), "listeners.tcp.default = 5673\n").unwrap();
+}
+
+/// Everything under the frm directory, to check that nothing was written
+fn snapshot(temp: &TempDir) -> Vec<String> {
+    let mut entries = Vec::new();
+    let mut dirs = vec![temp.path().to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path.clone());
+            }
+            entries.push(path.display().to_string());
+        }
+    }
+    entries.sort();
+    entries
+}
+
+#[test]
+fn reading_commands_work_in_a_read_only_frm_dir() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf(&temp, "4.2.3");
+    let before = snapshot(&temp);
+
+    frm_cmd(&temp)
+        .args(["releases", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3"));
+    frm_cmd(&temp)
+        .args(["releases", "use", "4.2.3", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export RABBITMQ_HOME="));
+    frm_cmd(&temp)
+        .args(["conf", "get-key", "listeners.tcp.default", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("5673"));
+    frm_cmd(&temp)
+        .args(["releases", "path", "-V", "4.2.3"])
+        .assert()
+        .success();
+
+    assert_eq!(snapshot(&temp), before);
+}
+
+#[test]
+fn writing_commands_fail_early_in_a_read_only_frm_dir() {
+    let temp = TempDir::new().unwrap();
+    install_with_conf(&temp, "4.2.3");
+    let before = snapshot(&temp);
+
+    frm_cmd(&temp)
+        .args(["releases", "install", "4.1.0"])
+        .assert()
+        .code(74)
+        .stderr(predicate::str::contains(format!(
+            "{} is read-only, and 'frm releases install' needs to write to it",
+            temp.path().display()
+        )))
+        .stdout(predicate::str::contains(
+            "point FRM_DIR to a writable directory",
+        ));
+    frm_cmd(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .code(74)
+        .stderr(predicate::str::contains("'frm default' needs to write"));
+    frm_cmd(&temp)
+        .args([
+            "conf",
+            "set-key",
+            "listeners.tcp.default",
+            "5674",
+            "-V",
+            "4.2.3",
+        ])
+        .assert()
+        .code(74);
+
+    let installation = TempDir::new().unwrap();
+    let source = installation.path().join("rabbitmq_server-4.1.6");
+    fs::create_dir_all(source.join("sbin")).unwrap();
+    fs::write(source.join("sbin").join("rabbitmq-server"), "#!/bin/sh\n").unwrap();
+    frm_cmd(&temp)
+        .arg("import")
+        .arg(&source)
+        .assert()
+        .code(74)
+        .stderr(predicate::str::contains("'frm import' needs to write"));
+
+    assert_eq!(snapshot(&temp), before);
+}
+
+#[test]
+fn every_command_is_classified_as_writing_or_read_only() {
+    let classified: Vec<&str> = WRITING_COMMANDS
+        .iter()
+        .chain(SOMETIMES_WRITING_COMMANDS)
+        .chain(READ_ONLY_COMMANDS)
+        .copied()
+        .collect();
+    let names = command_names();
+
+    for name in &names {
+        let count = classified.iter().filter(|c| *c == name).count();
+        assert_eq!(
+            count, 1,
+            "'{}' must be listed in exactly one of WRITING_COMMANDS, SOMETIMES_WRITING_COMMANDS, \
+            and READ_ONLY_COMMANDS in cli.rs",
+            name
+        );
+    }
+    for name in classified {
+        assert!(
+            names.iter().any(|n| n == name),
+            "'{}' is classified but is not a command",
+            name
+        );
+    }
+}
+
+#[test]
+fn writable_frm_dir_is_detected() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().join("frm"));
+    assert!(paths.writable());
+    // the probe file is removed
+    assert_eq!(fs::read_dir(temp.path().join("frm")).unwrap().count(), 0);
+}
+
+#[cfg(unix)]
+#[test]
+fn frm_dir_without_write_permission_is_read_only() {
+    // permissions do not apply to root
+    if frm::ownership::running_as_root() {
+        return;
+    }
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    fs::set_permissions(temp.path(), fs::Permissions::from_mode(0o555)).unwrap();
+    let writable = paths.writable();
+    fs::set_permissions(temp.path(), fs::Permissions::from_mode(0o755)).unwrap();
+    assert!(!writable);
+}
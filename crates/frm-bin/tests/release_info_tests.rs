@@ -0,0 +1,124 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+
+use tempfile::TempDir;
+
+use frm::commands::{ReleaseInfo, release_text};
+use frm::history::{self, HistoryEntry};
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+fn install_with_config(paths: &Paths, version: &Version) {
+    install(paths, version);
+    let etc = paths.version_etc_dir(version);
+    fs::create_dir_all(etc.join("conf.d")).unwrap();
+    fs::write(
+        etc.join("rabbitmq.conf"),
+        "listeners.tcp.default = 5673\nlog.console = true\n",
+    )
+    .unwrap();
+    fs::write(
+        etc.join("conf.d/10-mqtt.conf"),
+        "mqtt.listeners.tcp.default = 1884\n",
+    )
+    .unwrap();
+    fs::write(
+        etc.join("enabled_plugins"),
+        "[rabbitmq_management,rabbitmq_prometheus].\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn release_info_of_an_installed_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_config(&paths, &version);
+    VersionMetadata::update(&paths, &version, |m| m.installed_at = Some(1_770_000_000)).unwrap();
+
+    let info = ReleaseInfo::collect(&paths, &version, Some(false)).unwrap();
+    assert_eq!(
+        info.path,
+        Some(paths.version_dir(&version).display().to_string())
+    );
+    assert_eq!(info.config_keys, 3);
+    assert_eq!(
+        info.plugins,
+        Some(vec![
+            "rabbitmq_management".to_string(),
+            "rabbitmq_prometheus".to_string()
+        ])
+    );
+    assert_eq!(info.installed_at, Some(1_770_000_000));
+    assert_eq!(info.signature, None);
+    assert_eq!(info.last_used, None);
+
+    let text = release_text(&info);
+    assert!(text.starts_with("Version:      4.2.3\nFlavor:       GA\nInstalled:    yes\n"));
+    assert!(text.contains("Signature:    unknown\n"));
+    assert!(text.contains("EOL:          no\n"));
+    assert!(text.contains("Config keys:  3\n"));
+    assert!(text.contains("Plugins:      rabbitmq_management, rabbitmq_prometheus\n"));
+    assert!(text.contains("Last used:    -\n"));
+}
+
+#[test]
+fn release_info_of_a_version_that_is_not_installed() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+
+    let info = ReleaseInfo::collect(&paths, &Version::new(3, 12, 1), Some(true)).unwrap();
+    assert_eq!(
+        release_text(&info),
+        "Version:      3.12.1\n\
+        Flavor:       GA\n\
+        Installed:    no\n\
+        Erlang:       25.0 - 26.2.x\n\
+        EOL:          yes\n"
+    );
+}
+
+#[test]
+fn release_info_last_used_comes_from_history() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_config(&paths, &version);
+
+    for (timestamp, v) in [(100, "4.2.3"), (300, "4.1.0"), (200, "4.2.3")] {
+        let mut entry = HistoryEntry::new("bg start", Vec::new(), Some(v.to_string()));
+        entry.timestamp = timestamp;
+        history::append(&paths, &entry).unwrap();
+    }
+
+    let info = ReleaseInfo::collect(&paths, &version, None).unwrap();
+    assert_eq!(info.last_used, Some(200));
+    assert!(release_text(&info).contains("EOL:          unknown\n"));
+}
+
+#[test]
+fn release_info_last_used_falls_back_to_node_logs() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_config(&paths, &version);
+    let log_dir = paths.version_var_log_dir(&version);
+    fs::create_dir_all(&log_dir).unwrap();
+    fs::write(log_dir.join("rabbit@localhost.log"), "started\n").unwrap();
+
+    let info = ReleaseInfo::collect(&paths, &version, None).unwrap();
+    assert!(info.last_used.is_some());
+}
@@ -6,7 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use frm::releases::{parse_version_from_release_name, parse_version_from_tag};
+use frm::commands::{alpha_text, format_commit};
+use frm::releases::{
+    AlphaRelease, CommitComparison, GitHubCommit, alpha_commit, parse_version_from_release_name,
+    parse_version_from_tag,
+};
 use frm::version::Version;
 
 #[test]
@@ -122,6 +126,18 @@ fn parse_version_from_tag_beta() {
     assert_eq!(v.unwrap().to_string(), "4.2.0-beta.1");
 }
 
+#[test]
+fn parse_version_from_tag_legacy_3x() {
+    assert_eq!(
+        parse_version_from_tag("v3.13.7"),
+        Some(Version::new(3, 13, 7))
+    );
+    assert_eq!(
+        parse_version_from_tag("rabbitmq_v3_6_16"),
+        Some(Version::new(3, 6, 16))
+    );
+}
+
 #[test]
 fn parse_version_from_tag_no_prefix() {
     let v = parse_version_from_tag("4.2.3");
@@ -133,3 +149,127 @@ fn parse_version_from_tag_invalid() {
     assert!(parse_version_from_tag("").is_none());
     assert!(parse_version_from_tag("vinvalid").is_none());
 }
+
+#[test]
+fn alpha_commit_extracts_sha() {
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    assert_eq!(alpha_commit(&version), Some("132057c7"));
+}
+
+#[test]
+fn alpha_commit_rejects_non_alphas() {
+    assert_eq!(alpha_commit(&Version::new(4, 2, 3)), None);
+    let rc: Version = "4.2.0-rc.1".parse().unwrap();
+    assert_eq!(alpha_commit(&rc), None);
+    let numbered: Version = "4.3.0-alpha.1".parse().unwrap();
+    assert_eq!(alpha_commit(&numbered), None);
+}
+
+#[test]
+fn commit_comparison_deserialization_and_formatting() {
+    let json = r#"{
+        "status": "ahead",
+        "ahead_by": 2,
+        "behind_by": 0,
+        "total_commits": 2,
+        "commits": [
+            {
+                "sha": "0123456789abcdef0123456789abcdef01234567",
+                "commit": {
+                    "message": "Fix a thing\n\nLonger description",
+                    "author": {"name": "Jane Doe", "email": "jane@example.com", "date": "2026-02-01T10:00:00Z"}
+                }
+            },
+            {
+                "sha": "fedcba9876543210fedcba9876543210fedcba98",
+                "commit": {"message": "Bump a dependency", "author": null}
+            }
+        ]
+    }"#;
+
+    let comparison: CommitComparison = serde_json::from_str(json).unwrap();
+    assert_eq!(comparison.status, "ahead");
+    assert_eq!(comparison.total_commits, 2);
+    assert_eq!(
+        format_commit(&comparison.commits[0]),
+        "01234567 Fix a thing (Jane Doe, 2026-02-01)"
+    );
+    assert_eq!(
+        format_commit(&comparison.commits[1]),
+        "fedcba98 Bump a dependency"
+    );
+}
+
+fn commit(message: &str) -> GitHubCommit {
+    serde_json::from_value(serde_json::json!({
+        "sha": "132057c7e3c2a8cbf1a9e2f27d0a44b1f7d3a8e0",
+        "commit": {
+            "message": message,
+            "author": {"name": "Jane Doe", "date": "2026-02-01T10:00:00Z"}
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn github_commit_pull_requests() {
+    assert_eq!(
+        commit("Merge pull request #14321 from rabbitmq/some-branch").pull_requests(),
+        vec![14321]
+    );
+    assert_eq!(commit("Fix a leak (#14322)").pull_requests(), vec![14322]);
+    assert!(
+        commit("Fix a leak\n\nCloses #14000")
+            .pull_requests()
+            .is_empty()
+    );
+    assert!(commit("Use # as a separator").pull_requests().is_empty());
+}
+
+#[test]
+fn alpha_text_lists_the_commit_and_links() {
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    let release = AlphaRelease {
+        version: version.clone(),
+        tag: "alphas.1769940000000".to_string(),
+        published_at: "2026-02-01T12:00:00Z".to_string(),
+    };
+    let text = alpha_text(&version, Some(&release), &commit("Fix a leak (#14322)"));
+
+    assert_eq!(
+        text,
+        "Version:      4.3.0-alpha.132057c7\n\
+        Published at: 2026-02-01T12:00:00Z\n\
+        Release:      https://github.com/rabbitmq/server-packages/releases/tag/alphas.1769940000000\n\
+        Commit:       132057c7e3c2a8cbf1a9e2f27d0a44b1f7d3a8e0\n\
+        Commit date:  2026-02-01T10:00:00Z\n\
+        Author:       Jane Doe\n\
+        Message:      Fix a leak (#14322)\n\
+        Commit URL:   https://github.com/rabbitmq/rabbitmq-server/commit/132057c7e3c2a8cbf1a9e2f27d0a44b1f7d3a8e0\n\
+        Pull request: https://github.com/rabbitmq/rabbitmq-server/pull/14322\n"
+    );
+}
+
+#[test]
+fn alpha_text_without_a_catalog_entry() {
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    let text = alpha_text(&version, None, &commit("Bump a dependency"));
+
+    assert!(!text.contains("Release:"));
+    assert!(!text.contains("Pull request:"));
+    assert!(text.contains("Message:      Bump a dependency\n"));
+}
+
+#[test]
+fn cli_alphas_info_requires_an_alpha_version() {
+    let temp = tempfile::TempDir::new().unwrap();
+
+    #[allow(deprecated)]
+    assert_cmd::Command::cargo_bin("frm")
+        .unwrap()
+        .env("FRM_DIR", temp.path())
+        .args(["alphas", "info", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("4.2.3"));
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::process;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::resolve_node_name;
+use frm::errors::Error;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn install_with_diagnostics(paths: &Paths, version: &Version) {
+    install(paths, version);
+    let diagnostics = paths.version_sbin_dir(version).join("rabbitmq-diagnostics");
+    fs::write(
+        &diagnostics,
+        "#!/bin/sh\necho \"rabbitmq-diagnostics $*\"\n",
+    )
+    .unwrap();
+    fs::set_permissions(&diagnostics, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+/// Writes a PID file of a "running" node: the PID of the test process
+fn write_pid_file(paths: &Paths, version: &Version, node: &str) {
+    let data_dir = paths.version_data_dir(version);
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(
+        data_dir.join(format!("{}.pid", node)),
+        process::id().to_string(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn resolve_node_name_from_pid_file() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_diagnostics(&paths, &version);
+
+    assert!(matches!(
+        resolve_node_name(&paths, &version),
+        Err(Error::NoRunningNode(_))
+    ));
+
+    // a stale PID file is ignored
+    fs::create_dir_all(paths.version_data_dir(&version)).unwrap();
+    fs::write(
+        paths.version_data_dir(&version).join("rabbit@stale.pid"),
+        "999999999",
+    )
+    .unwrap();
+    write_pid_file(&paths, &version, "rabbit@testhost");
+    assert_eq!(
+        resolve_node_name(&paths, &version).unwrap(),
+        "rabbit@testhost"
+    );
+
+    write_pid_file(&paths, &version, "rabbit-2@testhost");
+    assert!(matches!(
+        resolve_node_name(&paths, &version),
+        Err(Error::AmbiguousNode { .. })
+    ));
+}
+
+#[test]
+fn cli_remote_shell() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_diagnostics(&paths, &version);
+    write_pid_file(&paths, &version, "rabbit@testhost");
+
+    frm_cmd_with_dir(&temp)
+        .args(["remote-shell", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "rabbitmq-diagnostics remote_shell -n rabbit@testhost",
+        ));
+
+    frm_cmd_with_dir(&temp)
+        .args(["remote-shell", "-V", "4.2.3", "--node", "rabbit@elsewhere"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("remote_shell -n rabbit@elsewhere"));
+}
+
+#[test]
+fn cli_remote_shell_without_running_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install_with_diagnostics(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["remote-shell", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no running node of RabbitMQ 4.2.3",
+        ))
+        .stderr(predicate::str::contains("frm bg start -V 4.2.3"));
+}
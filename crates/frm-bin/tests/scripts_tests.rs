@@ -0,0 +1,201 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+mod common;
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::Error;
+use frm::envs;
+use frm::paths::Paths;
+use frm::scripts;
+use frm::version::Version;
+
+use common::install;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+fn write_executable(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[test]
+fn list_returns_executable_scripts_with_descriptions() {
+    let (_temp, paths) = setup_temp_paths();
+    let dir = paths.scripts_dir();
+    write_executable(
+        &dir.join("seed-queues"),
+        "#!/bin/sh\n# Declares the queues the tests expect\necho\n",
+    );
+    write_executable(&dir.join("bare"), "#!/bin/sh\necho\n");
+    write_executable(&dir.join(".hidden"), "#!/bin/sh\n");
+    fs::write(dir.join("README.md"), "# Not a script\n").unwrap();
+
+    let scripts = scripts::list(&paths).unwrap();
+    let names: Vec<&str> = scripts.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["bare", "seed-queues"]);
+    assert_eq!(scripts[0].description, None);
+    assert_eq!(
+        scripts[1].description.as_deref(),
+        Some("Declares the queues the tests expect")
+    );
+}
+
+#[test]
+fn list_without_a_scripts_dir_is_empty() {
+    let (_temp, paths) = setup_temp_paths();
+    assert!(scripts::list(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn description_skips_the_shebang_and_blank_comments() {
+    let (temp, _paths) = setup_temp_paths();
+    let script = temp.path().join("script");
+    fs::write(&script, "#!/usr/bin/env python3\n#\n#   Rotates users  \n").unwrap();
+    assert_eq!(
+        scripts::description(&script).as_deref(),
+        Some("Rotates users")
+    );
+
+    fs::write(&script, "#!/bin/sh\nset -e\n").unwrap();
+    assert_eq!(scripts::description(&script), None);
+}
+
+#[test]
+fn find_reports_a_missing_script() {
+    let (_temp, paths) = setup_temp_paths();
+    let err = scripts::find(&paths, "nope").unwrap_err();
+    assert!(matches!(err, Error::ScriptNotFound { ref name, .. } if name == "nope"));
+}
+
+#[test]
+fn env_vars_point_to_the_version_and_environment() {
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    envs::create(&paths, &version, "tls").unwrap();
+
+    let vars = scripts::env_vars(&paths, &version, Some("tls")).unwrap();
+    let get = |name: &str| {
+        vars.iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.clone())
+            .unwrap_or_else(|| panic!("{} is not set", name))
+    };
+    assert_eq!(get("FRM_DIR"), temp.path().display().to_string());
+    assert_eq!(get("FRM_VERSION"), "4.2.3");
+    assert_eq!(get("FRM_ENV"), "tls");
+    assert_eq!(
+        get("FRM_ETC_DIR"),
+        paths
+            .version_env_etc_dir(&version, "tls")
+            .display()
+            .to_string()
+    );
+    assert_eq!(
+        get("RABBITMQ_MNESIA_BASE"),
+        paths
+            .version_env_data_dir(&version, "tls")
+            .display()
+            .to_string()
+    );
+    assert!(get("PATH").starts_with(&paths.version_sbin_dir(&version).display().to_string()));
+}
+
+#[test]
+fn cli_run_script_passes_arguments_and_environment() {
+    let (temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+    write_executable(
+        &paths.scripts_dir().join("show"),
+        "#!/bin/sh\n# Shows what it got\necho \"$FRM_VERSION $FRM_SBIN_DIR $*\"\n",
+    );
+
+    frm_cmd(&temp)
+        .args(["run-script", "show", "-V", "4.2.3", "--", "a", "b"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "4.2.3 {} a b",
+            paths.version_sbin_dir(&Version::new(4, 2, 3)).display()
+        )));
+}
+
+#[test]
+fn cli_run_script_exits_with_the_script_exit_code() {
+    let (temp, paths) = setup_temp_paths();
+    install(&paths, &Version::new(4, 2, 3));
+    write_executable(&paths.scripts_dir().join("fail"), "#!/bin/sh\nexit 3\n");
+
+    frm_cmd(&temp)
+        .args(["run-script", "fail", "-V", "4.2.3"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn cli_run_script_list() {
+    let (temp, paths) = setup_temp_paths();
+    frm_cmd(&temp)
+        .args(["run-script", "--list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No scripts found"));
+
+    write_executable(
+        &paths.scripts_dir().join("seed-queues"),
+        "#!/bin/sh\n# Declares the queues the tests expect\n",
+    );
+    frm_cmd(&temp)
+        .args(["run-script", "--list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "seed-queues  Declares the queues the tests expect",
+        ));
+}
+
+#[test]
+fn cli_run_script_rejects_unknown_scripts_and_versions() {
+    let (temp, paths) = setup_temp_paths();
+    write_executable(&paths.scripts_dir().join("seed"), "#!/bin/sh\n");
+
+    frm_cmd(&temp)
+        .args(["run-script", "nope", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no executable script named 'nope'",
+        ));
+    frm_cmd(&temp)
+        .args(["run-script", "seed", "-V", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
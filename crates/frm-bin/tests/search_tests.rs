@@ -0,0 +1,165 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::common::urls::{RABBITMQ_SERVER_API_URL, SERVER_PACKAGES_API_URL};
+use frm::feed_cache::CachedFeed;
+use frm::paths::Paths;
+
+use common::install_in;
+
+const SERVER_RELEASES: &str = r#"[
+  {"tag_name": "v4.2.3", "name": "RabbitMQ 4.2.3", "published_at": "2026-01-20T00:00:00Z",
+   "assets": [{"name": "rabbitmq-server-generic-unix-4.2.3.tar.xz"},
+              {"name": "rabbitmq-server-generic-unix-4.2.3.tar.xz.asc"}]},
+  {"tag_name": "v4.2.2", "name": "RabbitMQ 4.2.2", "published_at": "2025-12-20T00:00:00Z",
+   "assets": [{"name": "rabbitmq-server-generic-unix-4.2.2.tar.xz"}]},
+  {"tag_name": "v4.2.0-rc.1", "name": "RabbitMQ 4.2.0-rc.1", "published_at": "2025-10-01T00:00:00Z",
+   "assets": []},
+  {"tag_name": "v4.1.4", "name": "RabbitMQ 4.1.4", "published_at": "2025-11-01T00:00:00Z",
+   "assets": []},
+  {"tag_name": "v4.0.9", "name": "RabbitMQ 4.0.9", "published_at": "2025-06-01T00:00:00Z",
+   "assets": []},
+  {"tag_name": "v4.20.0", "name": "RabbitMQ 4.20.0", "published_at": "2026-02-01T00:00:00Z",
+   "assets": []}
+]"#;
+
+const ALPHA_RELEASES: &str = r#"[
+  {"tag_name": "alphas.1", "name": "RabbitMQ 4.3.0-alpha.132057c7", "published_at": "2026-01-10T00:00:00Z"}
+]"#;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// Fresh cached feeds, so that nothing is fetched from GitHub
+fn cache_feeds(dir: &TempDir) {
+    let paths = Paths::with_base_dir(dir.path().to_path_buf());
+    let fetched_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    for (url, body, file) in [
+        (
+            RABBITMQ_SERVER_API_URL,
+            SERVER_RELEASES,
+            paths.server_releases_feed_cache_file(),
+        ),
+        (
+            SERVER_PACKAGES_API_URL,
+            ALPHA_RELEASES,
+            paths.server_packages_feed_cache_file(),
+        ),
+    ] {
+        CachedFeed {
+            url: format!("{}?per_page=100", url),
+            etag: None,
+            fetched_at,
+            body: body.to_string(),
+        }
+        .save(&file)
+        .unwrap();
+    }
+}
+
+#[test]
+fn search_marks_installed_and_signed_versions() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+    install_in(temp.path(), "4.2.2");
+
+    frm_cmd_with_dir(&temp)
+        .args(["search", "4.2", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3 GA signed\n"))
+        .stdout(predicate::str::contains("4.2.2 GA installed\n"))
+        .stdout(predicate::str::contains("4.2.0-rc.1 rc"))
+        .stdout(predicate::str::contains("4.20.0").not())
+        .stdout(predicate::str::contains("4.1.4").not());
+}
+
+#[test]
+fn search_lists_newest_first() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+
+    let output = frm_cmd_with_dir(&temp)
+        .args(["search", "4.2", "--plain"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let versions: Vec<&str> = stdout
+        .lines()
+        .filter_map(|l| l.split_whitespace().next())
+        .collect();
+    assert_eq!(versions, ["4.2.3", "4.2.2", "4.2.0-rc.1"]);
+}
+
+#[test]
+fn search_marks_eol_series() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+
+    // 4.20 and 4.2 are the two most recent series
+    frm_cmd_with_dir(&temp)
+        .args(["search", "4", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.1.4 GA EOL"))
+        .stdout(predicate::str::contains("4.0.9 GA EOL"))
+        .stdout(predicate::str::contains("4.2.3 GA signed\n"));
+}
+
+#[test]
+fn search_marks_installed_versions_missing_from_github_as_yanked() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+    install_in(temp.path(), "4.2.1");
+
+    frm_cmd_with_dir(&temp)
+        .args(["search", "4.2.1", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.1 GA installed yanked"));
+}
+
+#[test]
+fn search_includes_alphas() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+
+    frm_cmd_with_dir(&temp)
+        .args(["search", "4.3.0-alpha"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.3.0-alpha.132057c7"))
+        .stdout(predicate::str::contains("alpha"));
+}
+
+#[test]
+fn search_without_matches() {
+    let temp = TempDir::new().unwrap();
+    cache_feeds(&temp);
+
+    frm_cmd_with_dir(&temp)
+        .args(["search", "3.8"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No versions matching '3.8'"));
+}
@@ -0,0 +1,270 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::envs;
+use frm::paths::Paths;
+use frm::shared;
+use frm::version::Version;
+
+use common::install_in;
+
+/// A user's frm directory and a shared one with 4.2.3 and 4.1.4 provisioned
+struct Dirs {
+    user: TempDir,
+    shared: TempDir,
+}
+
+impl Dirs {
+    fn new() -> Self {
+        let dirs = Self {
+            user: TempDir::new().unwrap(),
+            shared: TempDir::new().unwrap(),
+        };
+        install_with_conf(dirs.shared.path(), "4.2.3");
+        install_with_conf(dirs.shared.path(), "4.1.4");
+        dirs
+    }
+
+    fn paths(&self) -> Paths {
+        Paths::with_base_dir(self.user.path().to_path_buf())
+            .with_shared_dir(self.shared.path().to_path_buf())
+    }
+
+    #[allow(deprecated)]
+    fn frm_cmd(&self) -> Command {
+        let mut cmd = Command::cargo_bin("frm").unwrap();
+        cmd.env("FRM_DIR", self.user.path())
+            .env("FRM_SHARED_DIR", self.shared.path());
+        cmd
+    }
+}
+
+fn install_with_conf(base_dir: &Path, version: &str) {
+    let paths = install_in(base_dir, version);
+    let etc_dir = paths.version_etc_dir(&version.parse().unwrap());
+    fs::write(etc_dir.join("rabbitmq.conf"), "log.console = true\n").unwrap();
+}
+
+#[test]
+fn shared_versions_are_listed_with_own_ones() {
+    let dirs = Dirs::new();
+    install_with_conf(dirs.user.path(), "4.2.3");
+    install_with_conf(dirs.user.path(), "4.0.9");
+
+    let paths = dirs.paths();
+    let versions: Vec<String> = paths
+        .installed_versions()
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(versions, ["4.0.9", "4.1.4", "4.2.3"]);
+}
+
+#[test]
+fn own_versions_take_precedence_over_shared_ones() {
+    let dirs = Dirs::new();
+    install_with_conf(dirs.user.path(), "4.2.3");
+    let paths = dirs.paths();
+
+    let own = Version::new(4, 2, 3);
+    assert!(!paths.version_shared(&own));
+    assert_eq!(paths.version_dir(&own), paths.versions_dir().join("4.2.3"));
+    assert_eq!(paths.version_state_dir(&own), paths.version_dir(&own));
+
+    let shared = Version::new(4, 1, 4);
+    assert!(paths.version_shared(&shared));
+    assert_eq!(
+        paths.version_dir(&shared),
+        dirs.shared.path().join("versions").join("4.1.4")
+    );
+    assert!(paths.version_installed(&shared));
+    assert!(!paths.version_broken(&shared));
+
+    // new versions are installed into the user's directory
+    let missing = Version::new(3, 13, 7);
+    assert!(!paths.version_shared(&missing));
+    assert_eq!(
+        paths.version_dir(&missing),
+        paths.versions_dir().join("3.13.7")
+    );
+}
+
+#[test]
+fn shared_version_state_lives_in_the_user_directory() {
+    let dirs = Dirs::new();
+    let paths = dirs.paths();
+    let version = Version::new(4, 2, 3);
+
+    let state_dir = dirs.user.path().join("shared-versions").join("4.2.3");
+    assert_eq!(paths.version_state_dir(&version), state_dir);
+    assert_eq!(
+        paths.version_etc_dir(&version),
+        state_dir.join("etc").join("rabbitmq")
+    );
+    assert!(paths.version_var_log_dir(&version).starts_with(&state_dir));
+    assert!(paths.version_data_dir(&version).starts_with(&state_dir));
+    assert!(
+        paths
+            .version_env_dir(&version, "tls")
+            .starts_with(&state_dir)
+    );
+}
+
+#[test]
+fn prepare_copies_the_provisioned_configuration() {
+    let dirs = Dirs::new();
+    let paths = dirs.paths();
+    let version = Version::new(4, 2, 3);
+
+    shared::prepare(&paths, &version).unwrap();
+    let etc_dir = paths.version_etc_dir(&version);
+    assert_eq!(
+        fs::read_to_string(etc_dir.join("rabbitmq.conf")).unwrap(),
+        "log.console = true\n"
+    );
+    assert!(etc_dir.join("enabled_plugins").exists());
+    assert!(paths.version_data_dir(&version).is_dir());
+    assert!(paths.version_var_log_dir(&version).is_dir());
+
+    // later changes are kept
+    fs::write(etc_dir.join("rabbitmq.conf"), "log.console = false\n").unwrap();
+    shared::prepare(&paths, &version).unwrap();
+    assert_eq!(
+        fs::read_to_string(etc_dir.join("rabbitmq.conf")).unwrap(),
+        "log.console = false\n"
+    );
+}
+
+#[test]
+fn shared_version_environments_are_per_user() {
+    let dirs = Dirs::new();
+    let paths = dirs.paths();
+    let version = Version::new(4, 2, 3);
+
+    let env_dir = envs::create(&paths, &version, "tls").unwrap();
+    assert!(env_dir.starts_with(dirs.user.path()));
+    assert_eq!(envs::list(&paths, &version).unwrap(), ["tls"]);
+
+    let vars = envs::node_env_vars(&paths, &version, None).unwrap();
+    assert!(
+        vars.iter()
+            .all(|(_, value)| value.starts_with(dirs.user.path()))
+    );
+    assert!(vars.iter().any(|(name, _)| *name == "RABBITMQ_MNESIA_BASE"));
+}
+
+#[test]
+fn cli_lists_shared_versions() {
+    let dirs = Dirs::new();
+    dirs.frm_cmd()
+        .args(["releases", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3"))
+        .stdout(predicate::str::contains("4.1.4"));
+}
+
+#[test]
+fn cli_does_not_modify_shared_versions() {
+    let dirs = Dirs::new();
+    dirs.frm_cmd()
+        .args(["releases", "uninstall", "4.2.3", "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "installed in the shared directory",
+        ));
+    assert!(dirs.shared.path().join("versions").join("4.2.3").exists());
+
+    dirs.frm_cmd()
+        .args(["repair", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "installed in the shared directory",
+        ));
+}
+
+#[test]
+fn cli_use_points_configuration_into_the_user_directory() {
+    let dirs = Dirs::new();
+    let state_dir = dirs.user.path().join("shared-versions").join("4.2.3");
+    dirs.frm_cmd()
+        .args(["releases", "use", "--version", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            dirs.shared
+                .path()
+                .join("versions")
+                .join("4.2.3")
+                .join("sbin")
+                .display()
+                .to_string(),
+        ))
+        .stdout(predicate::str::contains("RABBITMQ_MNESIA_BASE"))
+        .stdout(predicate::str::contains(
+            state_dir
+                .join("etc")
+                .join("rabbitmq")
+                .join("rabbitmq.conf")
+                .display()
+                .to_string(),
+        ));
+    assert!(
+        state_dir
+            .join("etc")
+            .join("rabbitmq")
+            .join("rabbitmq.conf")
+            .exists()
+    );
+}
+
+#[test]
+fn cli_conf_set_key_writes_to_the_user_directory() {
+    let dirs = Dirs::new();
+    dirs.frm_cmd()
+        .args(["conf", "set-key", "-V", "4.2.3", "heartbeat", "30"])
+        .assert()
+        .success();
+
+    let conf = dirs
+        .user
+        .path()
+        .join("shared-versions")
+        .join("4.2.3")
+        .join("etc")
+        .join("rabbitmq")
+        .join("rabbitmq.conf");
+    let content = fs::read_to_string(conf).unwrap();
+    assert!(content.contains("log.console = true"));
+    assert!(content.contains("heartbeat = 30"));
+    assert_eq!(
+        fs::read_to_string(
+            dirs.shared
+                .path()
+                .join("versions")
+                .join("4.2.3")
+                .join("etc")
+                .join("rabbitmq")
+                .join("rabbitmq.conf")
+        )
+        .unwrap(),
+        "log.console = true\n"
+    );
+}
@@ -9,7 +9,7 @@
 use tempfile::TempDir;
 
 use frm::paths::Paths;
-use frm::shell::Shell;
+use frm::shell::{Shell, activation_env_vars};
 use frm::version::Version;
 
 fn setup_temp_paths() -> (TempDir, Paths) {
@@ -133,8 +133,19 @@ fn shell_env_script_removes_old_paths_bash() {
     let version = Version::new(4, 2, 3);
     let script = Shell::Bash.env_script(&paths, &version);
 
-    assert!(script.contains("${PATH//*"));
-    assert!(script.contains(r"\/versions\/*/}"));
+    let base_dir = paths.base_dir().display().to_string();
+    assert!(script.contains("for __frm_entry in $PATH"));
+    assert!(script.contains(&format!("\"{}/versions/\"*) ;;", base_dir)));
+}
+
+#[test]
+fn shell_env_script_removes_old_paths_zsh() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let script = Shell::Zsh.env_script(&paths, &version);
+
+    let base_dir = paths.base_dir().display().to_string();
+    assert!(script.contains(&format!("${{path:#{}/versions/*}}", base_dir)));
 }
 
 #[test]
@@ -173,3 +184,144 @@ fn shell_init_script_no_unreplaced_placeholders() {
         );
     }
 }
+
+#[test]
+fn shell_env_script_sets_active_sbin_marker() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let sbin = paths.version_sbin_dir(&version).display().to_string();
+
+    for shell in [Shell::Bash, Shell::Zsh] {
+        let script = shell.env_script(&paths, &version);
+        assert!(script.contains(&format!("export FRM_ACTIVE_SBIN=\"{}\"", sbin)));
+    }
+    let script = Shell::Nu.env_script(&paths, &version);
+    assert!(script.contains(&format!("$env.FRM_ACTIVE_SBIN = \"{}\"", sbin)));
+}
+
+#[test]
+fn shell_scripts_define_frm_deactivate() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+
+    for shell in [Shell::Bash, Shell::Zsh] {
+        assert!(
+            shell
+                .env_script(&paths, &version)
+                .contains("frm-deactivate() {")
+        );
+        assert!(shell.init_script(&paths).contains("frm-deactivate() {"));
+    }
+    assert!(
+        Shell::Nu
+            .env_script(&paths, &version)
+            .contains("def --env frm-deactivate []")
+    );
+    assert!(
+        Shell::Nu
+            .init_script(&paths)
+            .contains("def --env frm-deactivate []")
+    );
+}
+
+#[test]
+fn shell_deactivate_script_invokes_function() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Nu] {
+        let script = shell.deactivate_script();
+        assert!(script.contains("FRM_ACTIVE_SBIN"));
+        assert!(script.ends_with("frm-deactivate\n"));
+    }
+}
+
+#[test]
+fn shell_sbin_only_env_script_leaves_rabbitmq_home_alone() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Nu] {
+        let script = shell.sbin_only_env_script(&paths, &version);
+        assert!(script.contains("FRM_ACTIVE_SBIN"));
+        assert!(!script.contains("export RABBITMQ_HOME"));
+        assert!(!script.contains("$env.RABBITMQ_HOME ="));
+    }
+}
+
+#[test]
+fn shell_export_var_serialization() {
+    assert_eq!(
+        Shell::Bash.export_var("RABBITMQ_HOME", "/opt/frm/versions/4.2.3"),
+        "export RABBITMQ_HOME=\"/opt/frm/versions/4.2.3\"\n"
+    );
+    assert_eq!(
+        Shell::Zsh.export_var("RABBITMQ_HOME", "/opt/frm/versions/4.2.3"),
+        "export RABBITMQ_HOME=\"/opt/frm/versions/4.2.3\"\n"
+    );
+    assert_eq!(
+        Shell::Nu.export_var("RABBITMQ_HOME", "/opt/frm/versions/4.2.3"),
+        "$env.RABBITMQ_HOME = \"/opt/frm/versions/4.2.3\"\n"
+    );
+}
+
+#[test]
+fn shell_export_var_escaping() {
+    assert_eq!(
+        Shell::Bash.export_var("X", r#"/a "b" $c `d` \e"#),
+        "export X=\"/a \\\"b\\\" \\$c \\`d\\` \\\\e\"\n"
+    );
+    assert_eq!(
+        Shell::Nu.export_var("X", r#"/a "b" \e"#),
+        "$env.X = \"/a \\\"b\\\" \\\\e\"\n"
+    );
+}
+
+#[test]
+fn shell_env_script_exports_rabbitmq_env_vars() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    let etc_dir = paths.version_etc_dir(&version);
+
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Nu] {
+        let script = shell.env_script(&paths, &version);
+        for (name, value) in activation_env_vars(&paths, &version) {
+            assert!(
+                script.contains(&shell.export_var(name, &value)),
+                "{shell} env_script does not set {name}"
+            );
+        }
+    }
+
+    let vars = activation_env_vars(&paths, &version);
+    let value_of = |name: &str| vars.iter().find(|(n, _)| *n == name).unwrap().1.clone();
+    assert_eq!(
+        value_of("RABBITMQ_CONFIG_FILE"),
+        etc_dir.join("rabbitmq.conf").display().to_string()
+    );
+    assert_eq!(
+        value_of("RABBITMQ_ENABLED_PLUGINS_FILE"),
+        etc_dir.join("enabled_plugins").display().to_string()
+    );
+    assert_eq!(
+        value_of("RABBITMQ_LOG_BASE"),
+        paths.version_var_log_dir(&version).display().to_string()
+    );
+}
+
+#[test]
+fn shell_init_script_exports_rabbitmq_env_vars() {
+    let (_temp, paths) = setup_temp_paths();
+
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Nu] {
+        let script = shell.init_script(&paths);
+        for name in [
+            "RABBITMQ_HOME",
+            "RABBITMQ_CONFIG_FILE",
+            "RABBITMQ_ENABLED_PLUGINS_FILE",
+            "RABBITMQ_LOG_BASE",
+        ] {
+            assert!(
+                script.contains(name),
+                "{shell} init_script does not set {name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,234 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &Path) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir);
+    cmd
+}
+
+/// A fake installation with a configuration file and node data
+fn install_with_data(paths: &Paths, version: &Version) {
+    install(paths, version);
+    fs::write(
+        paths.version_etc_dir(version).join("rabbitmq.conf"),
+        "listeners.tcp.default = 5673\n",
+    )
+    .unwrap();
+    let data = paths.version_dir(version).join("var").join("lib");
+    fs::create_dir_all(&data).unwrap();
+    fs::write(data.join("node.data"), "data").unwrap();
+}
+
+fn source_dir() -> (TempDir, Paths, Version) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 1, 6);
+    install_with_data(&paths, &version);
+    fs::write(paths.config_file(), "default_version = \"4.1.6\"\n").unwrap();
+    (temp, paths, version)
+}
+
+#[test]
+fn export_and_import_state_without_versions() {
+    let (source, _, version) = source_dir();
+    let archive_dir = TempDir::new().unwrap();
+    let archive = archive_dir.path().join("frm-state.tar.zst");
+
+    frm_cmd_with_dir(source.path())
+        .args(["export", "state", "--file"])
+        .arg(&archive)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--include-versions"));
+    assert!(archive.is_file());
+
+    let target = TempDir::new().unwrap();
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "--no-download", "--file"])
+        .arg(&archive)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm releases install 4.1.6"));
+
+    let paths = Paths::with_base_dir(target.path().to_path_buf());
+    assert!(!paths.version_installed(&version));
+    assert_eq!(
+        fs::read_to_string(paths.config_file()).unwrap(),
+        "default_version = \"4.1.6\"\n"
+    );
+}
+
+#[test]
+fn export_and_import_state_with_versions() {
+    let (source, _, version) = source_dir();
+    let archive_dir = TempDir::new().unwrap();
+    let archive = archive_dir.path().join("frm-state.tar.gz");
+
+    frm_cmd_with_dir(source.path())
+        .args(["export", "state", "--include-versions", "-f"])
+        .arg(&archive)
+        .assert()
+        .success();
+
+    let target = TempDir::new().unwrap();
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "-f"])
+        .arg(&archive)
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(target.path().to_path_buf());
+    assert!(paths.version_installed(&version));
+    assert_eq!(
+        fs::read_to_string(paths.version_etc_dir(&version).join("rabbitmq.conf")).unwrap(),
+        "listeners.tcp.default = 5673\n"
+    );
+    assert!(!paths.version_dir(&version).join("var").exists());
+    assert!(!target.path().join(".state-import").exists());
+}
+
+#[test]
+fn import_state_detects_the_format_of_a_renamed_archive() {
+    let (source, _, version) = source_dir();
+    let archive_dir = TempDir::new().unwrap();
+    let exported = archive_dir.path().join("frm-state.tar.gz");
+    let renamed = archive_dir.path().join("frm-state");
+
+    frm_cmd_with_dir(source.path())
+        .args(["export", "state", "--include-versions", "-f"])
+        .arg(&exported)
+        .assert()
+        .success();
+    fs::rename(&exported, &renamed).unwrap();
+
+    let target = TempDir::new().unwrap();
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "-f"])
+        .arg(&renamed)
+        .assert()
+        .success();
+
+    let paths = Paths::with_base_dir(target.path().to_path_buf());
+    assert!(paths.version_installed(&version));
+}
+
+#[test]
+fn import_state_keeps_existing_files_without_force() {
+    let (source, _, version) = source_dir();
+    let archive_dir = TempDir::new().unwrap();
+    let archive = archive_dir.path().join("frm-state.tar");
+
+    frm_cmd_with_dir(source.path())
+        .args(["export", "state", "-f"])
+        .arg(&archive)
+        .assert()
+        .success();
+
+    let target = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(target.path().to_path_buf());
+    install_with_data(&paths, &version);
+    let conf = paths.version_etc_dir(&version).join("rabbitmq.conf");
+    fs::write(&conf, "# local\n").unwrap();
+
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "-f"])
+        .arg(&archive)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--force"));
+    assert_eq!(fs::read_to_string(&conf).unwrap(), "# local\n");
+
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "--force", "-f"])
+        .arg(&archive)
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(&conf).unwrap(),
+        "listeners.tcp.default = 5673\n"
+    );
+}
+
+#[test]
+fn import_state_rejects_invalid_archives() {
+    let temp = TempDir::new().unwrap();
+    let archive = temp.path().join("bogus.tar.zst");
+    fs::write(&archive, "not an archive").unwrap();
+
+    frm_cmd_with_dir(temp.path())
+        .args(["import", "state", "-f"])
+        .arg(&archive)
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("invalid state archive"));
+
+    frm_cmd_with_dir(temp.path())
+        .args(["import", "state", "-f"])
+        .arg(temp.path().join("missing.tar.zst"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("does not exist"));
+}
+
+#[test]
+fn import_state_reports_versions_that_could_not_be_installed() {
+    let (source, source_paths, _) = source_dir();
+    install_with_data(&source_paths, &Version::new(4, 2, 3));
+    let archive_dir = TempDir::new().unwrap();
+    let archive = archive_dir.path().join("frm-state.tar.zst");
+
+    frm_cmd_with_dir(source.path())
+        .args(["export", "state", "--file"])
+        .arg(&archive)
+        .assert()
+        .success();
+
+    // denylisted versions fail to install before anything is downloaded
+    let target = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(target.path().to_path_buf());
+    fs::write(
+        paths.denylist_file(),
+        "[[release]]\nversion = \"4.1.6\"\nreason = \"broken\"\n\n\
+        [[release]]\nversion = \"4.2.3\"\nreason = \"broken\"\n",
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(target.path())
+        .args(["import", "state", "--file"])
+        .arg(&archive)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Could not install RabbitMQ 4.1.6"))
+        .stdout(predicate::str::contains("Could not install RabbitMQ 4.2.3"))
+        .stdout(predicate::str::contains("Imported the state").not())
+        .stderr(predicate::str::contains(
+            "these versions could not be installed: 4.1.6, 4.2.3",
+        ));
+
+    assert_eq!(
+        fs::read_to_string(paths.config_file()).unwrap(),
+        "default_version = \"4.1.6\"\n"
+    );
+}
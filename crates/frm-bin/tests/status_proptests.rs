@@ -124,6 +124,7 @@ proptest! {
             default: None,
             releases: releases.clone(),
             alphas: vec![],
+            broken: vec![],
         };
 
         let output = status.format();
@@ -156,6 +157,7 @@ proptest! {
             default: None,
             releases: releases.clone(),
             alphas: vec![],
+            broken: vec![],
         };
 
         let output = status.format();
@@ -194,6 +196,7 @@ proptest! {
             default: Some(default.clone()),
             releases: releases.clone(),
             alphas: vec![],
+            broken: vec![],
         };
 
         let output = status.format();
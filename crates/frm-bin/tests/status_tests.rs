@@ -145,6 +145,7 @@ fn format_empty_status() {
         default: None,
         releases: vec![],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -159,6 +160,7 @@ fn format_with_default_only() {
         default: Some(v.clone()),
         releases: vec![v.clone()],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -174,6 +176,7 @@ fn format_with_active_only() {
         default: None,
         releases: vec![v.clone()],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -189,6 +192,7 @@ fn format_active_equals_default() {
         default: Some(v.clone()),
         releases: vec![v.clone()],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -206,6 +210,7 @@ fn format_active_differs_from_default() {
         default: Some(default.clone()),
         releases: vec![default.clone(), active.clone()],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -225,6 +230,7 @@ fn format_versions_listed_newest_first() {
         default: None,
         releases: vec![v1.clone(), v2.clone(), v3.clone()],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -247,6 +253,7 @@ fn format_releases_before_alphas() {
         default: None,
         releases: vec![ga.clone()],
         alphas: vec![alpha.clone()],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -266,6 +273,7 @@ fn format_no_header_lines_when_no_active_or_default() {
         default: None,
         releases: vec![v],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -283,6 +291,7 @@ fn format_default_not_in_list() {
         default: Some(default),
         releases: vec![installed],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -300,6 +309,7 @@ fn format_active_not_in_list() {
         default: None,
         releases: vec![installed],
         alphas: vec![],
+        broken: vec![],
     };
 
     let output = status.format();
@@ -0,0 +1,341 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::stream::{self, SmokeTestOptions};
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+struct FakeServer {
+    port: u16,
+    /// The command keys the client sent, in order
+    handle: JoinHandle<Vec<u16>>,
+}
+
+/// Serves one connection with just enough of the stream protocol for a smoke test:
+/// every request succeeds, except authentication when `reject_auth` is set
+fn fake_server(reject_auth: bool) -> FakeServer {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = thread::spawn(move || {
+        loop {
+            let (mut socket, _) = listener.accept().unwrap();
+            let keys = serve(&mut socket, reject_auth);
+            // frm checks that the port is open with a connection of its own first
+            if !keys.is_empty() {
+                return keys;
+            }
+        }
+    });
+    FakeServer { port, handle }
+}
+
+fn serve(socket: &mut TcpStream, reject_auth: bool) -> Vec<u16> {
+    let mut keys = Vec::new();
+    while let Some((key, body)) = read_frame(socket) {
+        keys.push(key);
+        let corr = || body[..4].to_vec();
+        match key {
+            // peer properties
+            0x0011 => {
+                let mut props = 2u32.to_be_bytes().to_vec();
+                for s in ["product", "RabbitMQ", "version", "4.2.1"] {
+                    props.extend(string(s));
+                }
+                respond(socket, key, &corr(), 1, &props);
+            }
+            // SASL handshake
+            0x0012 => {
+                let mut mechanisms = 1u32.to_be_bytes().to_vec();
+                mechanisms.extend(string("PLAIN"));
+                respond(socket, key, &corr(), 1, &mechanisms);
+            }
+            // SASL authenticate
+            0x0013 => {
+                if reject_auth {
+                    respond(socket, key, &corr(), 8, &[]);
+                    continue;
+                }
+                assert!(body.ends_with(b"\0guest\0guest"));
+                respond(socket, key, &corr(), 1, &[]);
+                let mut tune = 1_048_576u32.to_be_bytes().to_vec();
+                tune.extend(60u32.to_be_bytes());
+                write_frame(socket, 0x0014, &tune);
+            }
+            // the client's tune and credit need no response
+            0x0014 | 0x0009 => {}
+            // open
+            0x0015 => respond(socket, key, &corr(), 1, &0u32.to_be_bytes()),
+            // publish: confirm the message, then deliver it in a chunk of its own
+            0x0002 => {
+                let id = &body[5..13];
+                let message = &body[17..];
+                let mut confirm = vec![1];
+                confirm.extend(1u32.to_be_bytes());
+                confirm.extend(id);
+                write_frame(socket, 0x0003, &confirm);
+                write_frame(socket, 0x0008, &deliver(id, message));
+            }
+            // close
+            0x0016 => {
+                respond(socket, key, &corr(), 1, &[]);
+                break;
+            }
+            // create, delete, declare_publisher, subscribe
+            _ => respond(socket, key, &corr(), 1, &[]),
+        }
+    }
+    keys
+}
+
+fn read_frame(socket: &mut TcpStream) -> Option<(u16, Vec<u8>)> {
+    let mut size = [0u8; 4];
+    socket.read_exact(&mut size).ok()?;
+    let mut frame = vec![0u8; u32::from_be_bytes(size) as usize];
+    socket.read_exact(&mut frame).ok()?;
+    Some((
+        u16::from_be_bytes([frame[0], frame[1]]),
+        frame[4..].to_vec(),
+    ))
+}
+
+fn write_frame(socket: &mut TcpStream, key: u16, body: &[u8]) {
+    let mut frame = ((body.len() + 4) as u32).to_be_bytes().to_vec();
+    frame.extend(key.to_be_bytes());
+    frame.extend(1u16.to_be_bytes());
+    frame.extend(body);
+    socket.write_all(&frame).unwrap();
+}
+
+fn respond(socket: &mut TcpStream, key: u16, corr: &[u8], code: u16, rest: &[u8]) {
+    let mut body = corr.to_vec();
+    body.extend(code.to_be_bytes());
+    body.extend(rest);
+    write_frame(socket, key | 0x8000, &body);
+}
+
+fn string(s: &str) -> Vec<u8> {
+    let mut bytes = (s.len() as u16).to_be_bytes().to_vec();
+    bytes.extend(s.as_bytes());
+    bytes
+}
+
+fn deliver(offset: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut data = (message.len() as u32).to_be_bytes().to_vec();
+    data.extend(message);
+
+    // subscription ID, then the chunk header
+    let mut body = vec![1, 0x50, 0];
+    body.extend(1u16.to_be_bytes());
+    body.extend(1u32.to_be_bytes());
+    body.extend(0i64.to_be_bytes());
+    body.extend(1u64.to_be_bytes());
+    body.extend(offset);
+    body.extend(0u32.to_be_bytes());
+    body.extend((data.len() as u32).to_be_bytes());
+    body.extend(0u32.to_be_bytes());
+    body.extend(0u32.to_be_bytes());
+    body.extend(data);
+    body
+}
+
+fn smoke_test_options(port: u16) -> SmokeTestOptions<'static> {
+    SmokeTestOptions {
+        host: "127.0.0.1",
+        port,
+        username: "guest",
+        password: "guest",
+        virtual_host: "/",
+        count: 5,
+        timeout: Duration::from_secs(5),
+    }
+}
+
+#[test]
+fn message_index_recognizes_smoke_test_messages() {
+    assert_eq!(stream::message_index(&stream::message_body(0)), Some(0));
+    assert_eq!(stream::message_index(&stream::message_body(42)), Some(42));
+    assert_eq!(stream::message_index(b"\x00\x53\x75\xa0\x05hello"), None);
+    assert_eq!(stream::message_index(b""), None);
+}
+
+#[test]
+fn summarize_latencies() {
+    let ms = Duration::from_millis;
+    let summary = stream::summarize(&[ms(3), ms(1), ms(9), ms(2), ms(4)]).unwrap();
+    assert_eq!(summary.min, ms(1));
+    assert_eq!(summary.median, ms(3));
+    assert_eq!(summary.max, ms(9));
+    assert!(stream::summarize(&[]).is_none());
+}
+
+#[test]
+fn smoke_test_publishes_and_consumes() {
+    let server = fake_server(false);
+
+    let report = stream::smoke_test(&smoke_test_options(server.port)).unwrap();
+    assert_eq!(report.product.as_deref(), Some("RabbitMQ"));
+    assert_eq!(report.version.as_deref(), Some("4.2.1"));
+    assert_eq!(report.confirm_latencies.len(), 5);
+    assert_eq!(report.delivery_latencies.len(), 5);
+
+    let keys = server.handle.join().unwrap();
+    // create, declare_publisher, subscribe, 5 publishes, 5 credits, delete, close
+    let after_open: Vec<u16> = keys
+        .iter()
+        .skip_while(|k| **k != 0x0015)
+        .skip(1)
+        .copied()
+        .collect();
+    assert_eq!(&after_open[..3], [0x000d, 0x0001, 0x0007]);
+    assert_eq!(after_open.iter().filter(|k| **k == 0x0002).count(), 5);
+    assert_eq!(after_open.iter().filter(|k| **k == 0x0009).count(), 5);
+    assert_eq!(&after_open[after_open.len() - 2..], [0x000e, 0x0016]);
+}
+
+#[test]
+fn smoke_test_reports_authentication_failures() {
+    let server = fake_server(true);
+
+    let err = stream::smoke_test(&smoke_test_options(server.port)).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("sasl_authenticate was refused: authentication failure"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn cli_smoke_stream_requires_an_installed_version() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd(&temp)
+        .args(["smoke", "stream", "-V", "4.2.1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use std::process;
+
+    use predicates::prelude::*;
+    use tempfile::TempDir;
+
+    use frm::paths::Paths;
+    use frm::version::Version;
+
+    use super::{fake_server, frm_cmd};
+
+    fn write_script(path: &Path, content: &str) {
+        fs::write(path, content).unwrap();
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    /// Installs 4.2.1 with CLI tools that append their arguments to calls.log
+    fn install(temp: &TempDir) -> Paths {
+        let paths = Paths::with_base_dir(temp.path().to_path_buf());
+        let version = Version::new(4, 2, 1);
+        let sbin = paths.version_sbin_dir(&version);
+        fs::create_dir_all(&sbin).unwrap();
+        fs::create_dir_all(paths.version_etc_dir(&version)).unwrap();
+        let log = temp.path().join("calls.log");
+        for tool in ["rabbitmq-server", "rabbitmq-plugins"] {
+            write_script(
+                &sbin.join(tool),
+                &format!("#!/bin/sh\necho \"{} $*\" >> '{}'\n", tool, log.display()),
+            );
+        }
+        fs::write(
+            paths.version_etc_dir(&version).join("enabled_plugins"),
+            "[rabbitmq_management].\n",
+        )
+        .unwrap();
+        paths
+    }
+
+    #[test]
+    fn cli_smoke_stream_enables_the_plugin_on_a_running_node() {
+        let temp = TempDir::new().unwrap();
+        let paths = install(&temp);
+        let data_dir = paths.version_data_dir(&Version::new(4, 2, 1));
+        fs::create_dir_all(&data_dir).unwrap();
+        // the test process stands in for the node
+        fs::write(
+            data_dir.join("rabbit@localhost.pid"),
+            process::id().to_string(),
+        )
+        .unwrap();
+        let server = fake_server(false);
+
+        frm_cmd(&temp)
+            .args(["smoke", "stream", "-V", "4.2.1", "--host", "127.0.0.1"])
+            .args(["--port", &server.port.to_string(), "--count", "3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Published, confirmed, and consumed 3 message(s) on RabbitMQ 4.2.1",
+            ))
+            .stdout(predicate::str::contains("Publish to consume:"))
+            .stdout(predicate::str::contains("was started for the test").not());
+        server.handle.join().unwrap();
+
+        let calls = fs::read_to_string(temp.path().join("calls.log")).unwrap();
+        assert_eq!(
+            calls,
+            "rabbitmq-plugins -n rabbit@localhost enable rabbitmq_stream\n"
+        );
+    }
+
+    #[test]
+    fn cli_smoke_stream_starts_a_stopped_node() {
+        let temp = TempDir::new().unwrap();
+        let paths = install(&temp);
+        let server = fake_server(false);
+
+        frm_cmd(&temp)
+            .args(["smoke", "stream", "-V", "4.2.1", "--host", "127.0.0.1"])
+            .args(["--port", &server.port.to_string()])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Starting RabbitMQ 4.2.1"))
+            .stdout(predicate::str::contains(
+                "was started for the test, stop it with: frm bg stop -V 4.2.1",
+            ));
+        server.handle.join().unwrap();
+
+        let calls = fs::read_to_string(temp.path().join("calls.log")).unwrap();
+        assert_eq!(calls, "rabbitmq-server -detached\n");
+        assert_eq!(
+            fs::read_to_string(
+                paths
+                    .version_etc_dir(&Version::new(4, 2, 1))
+                    .join("enabled_plugins")
+            )
+            .unwrap(),
+            "[rabbitmq_management,rabbitmq_stream].\n"
+        );
+    }
+}
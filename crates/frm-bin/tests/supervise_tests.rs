@@ -0,0 +1,133 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// Installs a version whose rabbitmq-server crashes `crashes` times, then exits with code 0
+fn install_crashing_server(paths: &Paths, version: &Version, crashes: u32) {
+    let sbin = paths.version_sbin_dir(version);
+    fs::create_dir_all(&sbin).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(version)).unwrap();
+    let counter = paths.base_dir().join("starts");
+    let server = sbin.join("rabbitmq-server");
+    fs::write(
+        &server,
+        format!(
+            "#!/bin/sh\necho started >> {counter}\n\
+            [ $(wc -l < {counter}) -gt {crashes} ] && exit 0\nexit 3\n",
+            counter = counter.display(),
+            crashes = crashes
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&server, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+fn starts(paths: &Paths) -> usize {
+    fs::read_to_string(paths.base_dir().join("starts"))
+        .map(|s| s.lines().count())
+        .unwrap_or(0)
+}
+
+#[test]
+fn supervise_restarts_a_crashed_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_crashing_server(&paths, &version, 2);
+
+    frm_cmd_with_dir(&temp)
+        .args(["__supervise", "-V", "4.2.3", "--max-restarts", "3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "the node exited with code 3, restart 1 of 3 in 1s",
+        ))
+        .stdout(predicate::str::contains(
+            "the node exited with code 3, restart 2 of 3 in 2s",
+        ))
+        .stdout(predicate::str::contains("the node stopped, exiting"));
+    assert_eq!(starts(&paths), 3);
+}
+
+#[test]
+fn supervise_gives_up_after_max_restarts() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_crashing_server(&paths, &version, 10);
+
+    frm_cmd_with_dir(&temp)
+        .args(["__supervise", "-V", "4.2.3", "--max-restarts", "1"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "giving up after 1 restarts in a row",
+        ))
+        .stderr(predicate::str::contains("crashed 2 times in a row"));
+    assert_eq!(starts(&paths), 2);
+}
+
+#[test]
+fn cli_bg_start_supervise() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_crashing_server(&paths, &version, 1);
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "start", "-V", "4.2.3", "--supervise"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("under a supervisor"));
+
+    let run_log = paths.version_run_dir(&version).join("bg-start.log");
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut log = String::new();
+    while Instant::now() < deadline {
+        log = fs::read_to_string(&run_log).unwrap_or_default();
+        if log.contains("the node stopped") {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    assert!(log.starts_with("# supervisor restarting the node up to 5 times in a row"));
+    assert!(log.contains("restart 1 of 5"), "{}", log);
+    assert!(log.contains("the node stopped, exiting"), "{}", log);
+    assert_eq!(starts(&paths), 2);
+}
+
+#[test]
+fn cli_bg_start_max_restarts_requires_supervise() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["bg", "start", "-V", "4.2.3", "--max-restarts", "3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--supervise"));
+}
@@ -0,0 +1,238 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::Error;
+use frm::manifest::{Manifest, SyncAction, format_enabled_plugins, parse_enabled_plugins};
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// A fake installation with an empty configuration file and the management plugin enabled
+fn install_with_plugins(paths: &Paths, version: &Version) {
+    install(paths, version);
+    let etc = paths.version_etc_dir(version);
+    fs::write(etc.join("rabbitmq.conf"), "").unwrap();
+    fs::write(etc.join("enabled_plugins"), "[rabbitmq_management].\n").unwrap();
+}
+
+const MANIFEST: &str = r#"
+versions = ["4.2.3", "4.1.6"]
+default = "4.2.3"
+plugins = ["rabbitmq_management", "rabbitmq_shovel"]
+prune = true
+
+[conf]
+"listeners.tcp.default" = 5673
+"#;
+
+#[test]
+fn parse_manifest() {
+    let manifest = Manifest::parse(MANIFEST).unwrap();
+    assert_eq!(
+        manifest.versions,
+        vec![Version::new(4, 2, 3), Version::new(4, 1, 6)]
+    );
+    assert_eq!(manifest.default, Some(Version::new(4, 2, 3)));
+    assert_eq!(manifest.plugins.len(), 2);
+    assert!(manifest.prune);
+    assert_eq!(manifest.conf.len(), 1);
+
+    assert_eq!(Manifest::parse("").unwrap(), Manifest::default());
+}
+
+#[test]
+fn parse_manifest_rejects_invalid_input() {
+    let unlisted_default = "versions = [\"4.2.3\"]\ndefault = \"4.1.6\"\n";
+    assert!(matches!(
+        Manifest::parse(unlisted_default),
+        Err(Error::InvalidManifest(msg)) if msg.contains("4.1.6")
+    ));
+
+    let unknown_key = "versions = [\"4.2.3\"]\n[conf]\n\"listners.tcp.default\" = 5673\n";
+    assert!(matches!(
+        Manifest::parse(unknown_key),
+        Err(Error::InvalidManifest(msg)) if msg.contains("listners.tcp.default")
+    ));
+
+    assert!(matches!(
+        Manifest::parse("version = [\"4.2.3\"]\n"),
+        Err(Error::InvalidManifest(_))
+    ));
+    assert!(matches!(
+        Manifest::parse("versions = [\"not a version\"]\n"),
+        Err(Error::InvalidManifest(_))
+    ));
+}
+
+#[test]
+fn enabled_plugins_round_trip() {
+    let plugins = vec![
+        "rabbitmq_management".to_string(),
+        "rabbitmq_shovel".to_string(),
+    ];
+    let formatted = format_enabled_plugins(&plugins);
+    assert_eq!(formatted, "[rabbitmq_management,rabbitmq_shovel].\n");
+    assert_eq!(parse_enabled_plugins(&formatted), Some(plugins));
+    assert_eq!(parse_enabled_plugins("[]."), Some(vec![]));
+    assert_eq!(
+        parse_enabled_plugins("[rabbitmq_management, rabbitmq_stream]. "),
+        Some(vec![
+            "rabbitmq_management".to_string(),
+            "rabbitmq_stream".to_string()
+        ])
+    );
+    assert_eq!(parse_enabled_plugins("rabbitmq_management"), None);
+}
+
+#[test]
+fn plan_lists_changes_in_order() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let installed = Version::new(4, 2, 3);
+    let stale = Version::new(3, 13, 7);
+    let pinned = Version::new(3, 12, 14);
+    install_with_plugins(&paths, &installed);
+    install_with_plugins(&paths, &stale);
+    install_with_plugins(&paths, &pinned);
+    VersionMetadata::update(&paths, &pinned, |m| m.pinned = true).unwrap();
+
+    let manifest = Manifest::parse(MANIFEST).unwrap();
+    let actions = manifest.plan(&paths).unwrap();
+    let missing = Version::new(4, 1, 6);
+    let plugins = manifest.plugins.clone();
+    assert_eq!(
+        actions,
+        vec![
+            SyncAction::SetPlugins {
+                version: installed.clone(),
+                plugins: plugins.clone(),
+            },
+            SyncAction::SetConfKey {
+                version: installed.clone(),
+                key: "listeners.tcp.default".to_string(),
+                value: "5673".to_string(),
+            },
+            SyncAction::Install(missing.clone()),
+            SyncAction::SetPlugins {
+                version: missing.clone(),
+                plugins,
+            },
+            SyncAction::SetConfKey {
+                version: missing,
+                key: "listeners.tcp.default".to_string(),
+                value: "5673".to_string(),
+            },
+            SyncAction::SetDefault(installed),
+            SyncAction::Uninstall(stale),
+        ]
+    );
+}
+
+#[test]
+fn sync_dry_run_makes_no_changes() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install_with_plugins(&paths, &Version::new(4, 2, 3));
+    let manifest = temp.path().join("frm.manifest.toml");
+    fs::write(&manifest, MANIFEST).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["sync", "--dry-run", "--file"])
+        .arg(&manifest)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+ install 4.1.6"))
+        .stdout(predicate::str::contains(
+            "~ 4.2.3: listeners.tcp.default = 5673",
+        ))
+        .stdout(predicate::str::contains("~ default = 4.2.3"));
+
+    assert!(!paths.default_file().exists());
+    assert_eq!(
+        fs::read_to_string(
+            paths
+                .version_etc_dir(&Version::new(4, 2, 3))
+                .join("rabbitmq.conf")
+        )
+        .unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn sync_configures_installed_versions() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    let stale = Version::new(3, 13, 7);
+    install_with_plugins(&paths, &version);
+    install_with_plugins(&paths, &stale);
+    let manifest = temp.path().join("frm.manifest.toml");
+    fs::write(
+        &manifest,
+        MANIFEST.replace("[\"4.2.3\", \"4.1.6\"]", "[\"4.2.3\"]"),
+    )
+    .unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["sync", "-f"])
+        .arg(&manifest)
+        .assert()
+        .success();
+
+    let etc = paths.version_etc_dir(&version);
+    assert_eq!(
+        fs::read_to_string(etc.join("enabled_plugins")).unwrap(),
+        "[rabbitmq_management,rabbitmq_shovel].\n"
+    );
+    assert!(
+        fs::read_to_string(etc.join("rabbitmq.conf"))
+            .unwrap()
+            .contains("listeners.tcp.default = 5673")
+    );
+    assert_eq!(
+        fs::read_to_string(paths.default_file()).unwrap().trim(),
+        "4.2.3"
+    );
+    assert!(!paths.version_installed(&stale));
+
+    frm_cmd_with_dir(&temp)
+        .args(["sync", "--dry-run", "-f"])
+        .arg(&manifest)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Already in sync"));
+}
+
+#[test]
+fn sync_requires_a_manifest() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .current_dir(temp.path())
+        .arg("sync")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("frm.manifest.toml"));
+}
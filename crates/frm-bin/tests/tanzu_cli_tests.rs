@@ -78,7 +78,8 @@ fn cli_tanzu_install_help() {
         .stdout(predicate::str::contains("--version"))
         .stdout(predicate::str::contains(".tar.xz"))
         .stdout(predicate::str::contains(".tar.gz"))
-        .stdout(predicate::str::contains(".tgz"));
+        .stdout(predicate::str::contains(".tgz"))
+        .stdout(predicate::str::contains(".tar.zst"));
 }
 
 #[test]
@@ -356,10 +357,10 @@ fn cli_tanzu_install_records_timestamp() {
         .assert()
         .success();
 
-    let timestamps_path = temp.path().join("version_timestamps.json");
-    assert!(timestamps_path.exists());
-    let content = fs::read_to_string(&timestamps_path).unwrap();
-    assert!(content.contains("4.2.3"));
+    let metadata_path = temp.path().join("metadata").join("4.2.3.toml");
+    assert!(metadata_path.exists());
+    let content = fs::read_to_string(&metadata_path).unwrap();
+    assert!(content.contains("installed_at"));
 }
 
 #[test]
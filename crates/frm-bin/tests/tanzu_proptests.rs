@@ -10,7 +10,8 @@ use std::path::PathBuf;
 
 use proptest::prelude::*;
 
-use frm::tanzu::{CompressionFormat, extract_version_from_tarball_name};
+use frm::archive::ArchiveFormat;
+use frm::tanzu::extract_version_from_tarball_name;
 use frm::version::{Prerelease, Version};
 
 fn arb_version() -> impl Strategy<Value = Version> {
@@ -50,32 +51,32 @@ fn arb_extension() -> impl Strategy<Value = &'static str> {
 
 proptest! {
     #[test]
-    fn compression_format_consistent_tar_xz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tar\\.xz") {
+    fn archive_format_consistent_tar_xz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tar\\.xz") {
         let path = PathBuf::from(&name);
-        let format = CompressionFormat::from_path(&path);
-        prop_assert_eq!(format, Some(CompressionFormat::Xz));
+        let format = ArchiveFormat::from_path(&path);
+        prop_assert_eq!(format, Some(ArchiveFormat::Xz));
     }
 
     #[test]
-    fn compression_format_consistent_tar_gz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tar\\.gz") {
+    fn archive_format_consistent_tar_gz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tar\\.gz") {
         let path = PathBuf::from(&name);
-        let format = CompressionFormat::from_path(&path);
-        prop_assert_eq!(format, Some(CompressionFormat::Gzip));
+        let format = ArchiveFormat::from_path(&path);
+        prop_assert_eq!(format, Some(ArchiveFormat::Gzip));
     }
 
     #[test]
-    fn compression_format_consistent_tgz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tgz") {
+    fn archive_format_consistent_tgz(name in "[a-z]+-[a-z]+-[0-9]+\\.[0-9]+\\.[0-9]+\\.tgz") {
         let path = PathBuf::from(&name);
-        let format = CompressionFormat::from_path(&path);
-        prop_assert_eq!(format, Some(CompressionFormat::Gzip));
+        let format = ArchiveFormat::from_path(&path);
+        prop_assert_eq!(format, Some(ArchiveFormat::Gzip));
     }
 
     #[test]
-    fn compression_format_none_for_other(ext in "[a-z]{3,5}") {
-        if ext != "tgz" {
+    fn archive_format_none_for_other(ext in "[a-z]{3,5}") {
+        if !["tgz", "txz", "tzst"].contains(&ext.as_str()) {
             let name = format!("archive.{}", ext);
             let path = PathBuf::from(&name);
-            let format = CompressionFormat::from_path(&path);
+            let format = ArchiveFormat::from_path(&path);
             prop_assert_eq!(format, None);
         }
     }
@@ -13,10 +13,10 @@ use flate2::Compression;
 use flate2::write::GzEncoder;
 use tempfile::TempDir;
 
+use frm::archive::ArchiveFormat;
+use frm::errors::Error;
 use frm::paths::Paths;
-use frm::tanzu::{
-    CompressionFormat, extract_tarball, extract_version_from_tarball_name, verify_extracted_version,
-};
+use frm::tanzu::{extract_tarball, extract_version_from_tarball_name, verify_extracted_version};
 use frm::version::{Prerelease, Version};
 
 fn create_test_tarball_gz(temp_dir: &TempDir, name: &str, inner_dir: &str) -> PathBuf {
@@ -29,6 +29,11 @@ fn create_test_tarball_gz(temp_dir: &TempDir, name: &str, inner_dir: &str) -> Pa
     let sbin_path = inner_path.join("sbin");
     fs::create_dir_all(&sbin_path).unwrap();
     fs::write(sbin_path.join("rabbitmqctl"), "#!/bin/bash\necho test\n").unwrap();
+    fs::write(
+        sbin_path.join("rabbitmq-server"),
+        "#!/bin/bash\necho test\n",
+    )
+    .unwrap();
 
     archive.append_dir_all(inner_dir, &inner_path).unwrap();
     archive.finish().unwrap();
@@ -37,42 +42,33 @@ fn create_test_tarball_gz(temp_dir: &TempDir, name: &str, inner_dir: &str) -> Pa
 }
 
 #[test]
-fn compression_format_from_tar_xz() {
+fn archive_format_from_tar_xz() {
     let path = PathBuf::from("tanzu-rabbitmq-aarch64-4.2.3.tar.xz");
-    assert_eq!(
-        CompressionFormat::from_path(&path),
-        Some(CompressionFormat::Xz)
-    );
+    assert_eq!(ArchiveFormat::from_path(&path), Some(ArchiveFormat::Xz));
 }
 
 #[test]
-fn compression_format_from_tar_gz() {
+fn archive_format_from_tar_gz() {
     let path = PathBuf::from("tanzu-rabbitmq-x86_64-4.2.3.tar.gz");
-    assert_eq!(
-        CompressionFormat::from_path(&path),
-        Some(CompressionFormat::Gzip)
-    );
+    assert_eq!(ArchiveFormat::from_path(&path), Some(ArchiveFormat::Gzip));
 }
 
 #[test]
-fn compression_format_from_tgz() {
+fn archive_format_from_tgz() {
     let path = PathBuf::from("rabbitmq-server-4.2.3.tgz");
-    assert_eq!(
-        CompressionFormat::from_path(&path),
-        Some(CompressionFormat::Gzip)
-    );
+    assert_eq!(ArchiveFormat::from_path(&path), Some(ArchiveFormat::Gzip));
 }
 
 #[test]
-fn compression_format_unsupported() {
+fn archive_format_unsupported() {
     let path = PathBuf::from("archive.zip");
-    assert_eq!(CompressionFormat::from_path(&path), None);
+    assert_eq!(ArchiveFormat::from_path(&path), None);
 }
 
 #[test]
-fn compression_format_no_extension() {
+fn archive_format_no_extension() {
     let path = PathBuf::from("archive");
-    assert_eq!(CompressionFormat::from_path(&path), None);
+    assert_eq!(ArchiveFormat::from_path(&path), None);
 }
 
 #[test]
@@ -253,6 +249,30 @@ fn extract_tarball_with_non_rabbitmq_dir_name() {
     assert!(paths.version_sbin_dir(&version).exists());
 }
 
+#[test]
+fn extract_tarball_without_a_distribution_lists_its_contents() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    paths.ensure_dirs().unwrap();
+
+    let tarball = temp.path().join("tanzu-rabbitmq-4.2.3.tar.gz");
+    let encoder = GzEncoder::new(fs::File::create(&tarball).unwrap(), Compression::default());
+    let mut archive = tar::Builder::new(encoder);
+    let docs = temp.path().join("docs");
+    fs::create_dir_all(&docs).unwrap();
+    fs::write(docs.join("README"), "not a distribution").unwrap();
+    archive.append_dir_all("docs", &docs).unwrap();
+    archive.into_inner().unwrap().finish().unwrap();
+    let version = Version::new(4, 2, 3);
+
+    let err = extract_tarball(&tarball, &version, &paths).unwrap_err();
+
+    assert!(matches!(err, Error::UnexpectedArchiveLayout { .. }));
+    assert!(err.to_string().contains("docs/README"));
+    assert!(!paths.version_dir(&version).exists());
+    assert!(!paths.version_extraction_dir(&version).exists());
+}
+
 #[test]
 fn extract_version_version_at_start() {
     let path = PathBuf::from("4.2.3.tar.gz");
@@ -261,9 +281,9 @@ fn extract_version_version_at_start() {
 }
 
 #[test]
-fn compression_format_case_sensitive() {
+fn archive_format_case_sensitive() {
     let path = PathBuf::from("archive.TAR.XZ");
-    assert_eq!(CompressionFormat::from_path(&path), None);
+    assert_eq!(ArchiveFormat::from_path(&path), None);
 }
 
 #[test]
@@ -318,3 +338,34 @@ fn verify_extracted_version_missing_sbin() {
     let err = result.unwrap_err();
     assert!(err.to_string().contains("sbin"));
 }
+
+#[test]
+fn extract_tarball_supports_zstd() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    paths.ensure_dirs().unwrap();
+
+    let gz = create_test_tarball_gz(&temp, "test.tar.gz", "rabbitmq_server-4.2.3");
+    let tar = flate2::read::GzDecoder::new(fs::File::open(&gz).unwrap());
+    let zst = temp.path().join("tanzu-rabbitmq-4.2.3.tar.zst");
+    fs::write(&zst, zstd::encode_all(tar, 3).unwrap()).unwrap();
+    let version = Version::new(4, 2, 3);
+
+    extract_tarball(&zst, &version, &paths).unwrap();
+    assert!(paths.version_sbin_dir(&version).exists());
+}
+
+#[test]
+fn extract_tarball_detects_format_from_contents() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    paths.ensure_dirs().unwrap();
+
+    let gz = create_test_tarball_gz(&temp, "test.tar.gz", "rabbitmq_server-4.2.3");
+    let misnamed = temp.path().join("tanzu-rabbitmq-4.2.3.tar.xz");
+    fs::rename(&gz, &misnamed).unwrap();
+    let version = Version::new(4, 2, 3);
+
+    extract_tarball(&misnamed, &version, &paths).unwrap();
+    assert!(paths.version_sbin_dir(&version).exists());
+}
@@ -7,9 +7,12 @@
 // except according to those terms.
 
 use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
 
 use tempfile::TempDir;
 
+use frm::metadata::VersionMetadata;
 use frm::paths::Paths;
 use frm::timestamps::Timestamps;
 use frm::version::Version;
@@ -56,6 +59,7 @@ fn timestamps_load_nonexistent() {
     let (_temp, paths) = setup_temp_paths();
     let timestamps = Timestamps::load(&paths).unwrap();
     assert!(timestamps.get(&Version::new(4, 2, 3)).is_none());
+    assert!(!paths.timestamps_file().exists());
 }
 
 #[test]
@@ -77,29 +81,100 @@ fn timestamps_save_and_load() {
 }
 
 #[test]
-fn timestamps_save_creates_file() {
+fn timestamps_save_writes_version_metadata() {
     let (temp, paths) = setup_temp_paths();
     fs::create_dir_all(temp.path()).unwrap();
+    let version = Version::new(4, 2, 3);
 
     let mut timestamps = Timestamps::default();
-    timestamps.record(&Version::new(4, 2, 3));
+    timestamps.record(&version);
     timestamps.save(&paths).unwrap();
 
-    assert!(paths.timestamps_file().exists());
+    assert!(paths.version_metadata_file(&version).exists());
+    assert!(!paths.timestamps_file().exists());
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert_eq!(metadata.installed_at, timestamps.get(&version));
+}
+
+#[test]
+fn timestamps_save_only_writes_changed_versions() {
+    let (temp, paths) = setup_temp_paths();
+    fs::create_dir_all(temp.path()).unwrap();
+    let version1 = Version::new(4, 2, 3);
+    let version2 = Version::new(4, 1, 0);
+
+    // Two processes load the timestamps before either of them saves
+    let mut first = Timestamps::load(&paths).unwrap();
+    let mut second = Timestamps::load(&paths).unwrap();
+    first.record(&version1);
+    second.record(&version2);
+    first.save(&paths).unwrap();
+    second.save(&paths).unwrap();
+
+    let loaded = Timestamps::load(&paths).unwrap();
+    assert!(loaded.get(&version1).is_some());
+    assert!(loaded.get(&version2).is_some());
 }
 
 #[test]
-fn timestamps_file_is_json() {
+fn timestamps_remove_preserves_other_metadata() {
     let (temp, paths) = setup_temp_paths();
     fs::create_dir_all(temp.path()).unwrap();
+    let version = Version::new(4, 2, 3);
+    VersionMetadata::update(&paths, &version, |m| m.pinned = true).unwrap();
+
+    let mut timestamps = Timestamps::load(&paths).unwrap();
+    timestamps.record(&version);
+    timestamps.save(&paths).unwrap();
+    timestamps.remove(&version);
+    timestamps.save(&paths).unwrap();
+
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert!(metadata.pinned);
+    assert!(metadata.installed_at.is_none());
+}
+
+#[test]
+fn timestamps_remove_deletes_empty_metadata_file() {
+    let (temp, paths) = setup_temp_paths();
+    fs::create_dir_all(temp.path()).unwrap();
+    let version = Version::new(4, 2, 3);
 
     let mut timestamps = Timestamps::default();
-    timestamps.record(&Version::new(4, 2, 3));
+    timestamps.record(&version);
+    timestamps.save(&paths).unwrap();
+    timestamps.remove(&version);
     timestamps.save(&paths).unwrap();
 
-    let content = fs::read_to_string(paths.timestamps_file()).unwrap();
-    assert!(content.contains("4.2.3"));
-    let _: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert!(!paths.version_metadata_file(&version).exists());
+}
+
+#[test]
+fn timestamps_migrates_legacy_file() {
+    let (temp, paths) = setup_temp_paths();
+    fs::create_dir_all(temp.path()).unwrap();
+    let pinned = Version::new(4, 1, 0);
+    VersionMetadata::update(&paths, &pinned, |m| {
+        m.pinned = true;
+        m.installed_at = Some(5);
+    })
+    .unwrap();
+    fs::write(
+        paths.timestamps_file(),
+        r#"{"4.2.3": 1700000000, "4.3.0-alpha.132057c7": 1700000100, "4.1.0": 1, "garbage": 2}"#,
+    )
+    .unwrap();
+
+    let timestamps = Timestamps::load(&paths).unwrap();
+    assert_eq!(timestamps.get(&Version::new(4, 2, 3)), Some(1700000000));
+    let alpha = "4.3.0-alpha.132057c7".parse::<Version>().unwrap();
+    assert_eq!(timestamps.get(&alpha), Some(1700000100));
+    // Existing metadata takes precedence
+    assert_eq!(timestamps.get(&pinned), Some(5));
+    assert!(VersionMetadata::is_pinned(&paths, &pinned).unwrap());
+
+    assert!(!paths.timestamps_file().exists());
+    assert!(paths.version_metadata_file(&Version::new(4, 2, 3)).exists());
 }
 
 #[test]
@@ -161,4 +236,25 @@ fn timestamps_load_empty_file() {
 
     let timestamps = Timestamps::load(&paths).unwrap();
     assert!(timestamps.get(&Version::new(4, 2, 3)).is_none());
+    assert!(!paths.timestamps_file().exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn timestamps_load_reads_legacy_file_in_a_read_only_frm_dir() {
+    // permissions do not apply to root
+    if frm::ownership::running_as_root() {
+        return;
+    }
+    let (temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    fs::write(paths.timestamps_file(), r#"{"4.2.3": 1700000000}"#).unwrap();
+
+    fs::set_permissions(temp.path(), fs::Permissions::from_mode(0o555)).unwrap();
+    let timestamps = Timestamps::load(&paths);
+    fs::set_permissions(temp.path(), fs::Permissions::from_mode(0o755)).unwrap();
+
+    assert_eq!(timestamps.unwrap().get(&version), Some(1700000000));
+    assert!(paths.timestamps_file().exists());
+    assert!(!paths.version_metadata_file(&version).exists());
 }
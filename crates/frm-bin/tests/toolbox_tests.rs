@@ -0,0 +1,190 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::paths::Paths;
+use frm::toolbox::{self, Tool};
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn install_tool(paths: &Paths, tool: Tool, version: &str, content: &str) {
+    let path = toolbox::tool_path(paths, tool, version);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, content).unwrap();
+    #[cfg(unix)]
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[test]
+fn toolbox_tool_path_layout() {
+    let paths = Paths::with_base_dir("/frm".into());
+
+    assert_eq!(
+        toolbox::tool_path(&paths, Tool::PerfTest, "2.23.0"),
+        paths
+            .tools_dir()
+            .join("perf-test")
+            .join("2.23.0")
+            .join("perf-test-2.23.0.jar")
+    );
+    assert!(
+        toolbox::tool_path(&paths, Tool::RabbitMQAdmin, "2.10.0")
+            .starts_with(paths.tool_version_dir("rabbitmqadmin", "2.10.0"))
+    );
+}
+
+#[test]
+fn toolbox_asset_names() {
+    assert_eq!(
+        Tool::PerfTest.asset_name("2.23.0").as_deref(),
+        Some("perf-test-2.23.0.jar")
+    );
+    if let Some(name) = Tool::RabbitMQAdmin.asset_name("2.10.0") {
+        assert!(name.starts_with("rabbitmqadmin-2.10.0-"));
+    }
+}
+
+#[test]
+fn toolbox_installed_versions_newest_first() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    assert!(
+        toolbox::installed_versions(&paths, Tool::PerfTest)
+            .unwrap()
+            .is_empty()
+    );
+
+    for v in ["2.9.0", "2.23.0", "2.21.1"] {
+        install_tool(&paths, Tool::PerfTest, v, "");
+    }
+    // an interrupted download leaves no JAR behind
+    fs::create_dir_all(paths.tool_version_dir("perf-test", "2.24.0")).unwrap();
+
+    assert_eq!(
+        toolbox::installed_versions(&paths, Tool::PerfTest).unwrap(),
+        vec!["2.23.0", "2.21.1", "2.9.0"]
+    );
+    assert_eq!(
+        toolbox::find_installed(&paths, Tool::PerfTest).unwrap(),
+        Some(toolbox::tool_path(&paths, Tool::PerfTest, "2.23.0"))
+    );
+    assert_eq!(
+        toolbox::find_installed(&paths, Tool::RabbitMQAdmin).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn cli_toolbox_list_and_uninstall() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+
+    frm_cmd_with_dir(&temp)
+        .args(["toolbox", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No tools installed"));
+
+    install_tool(&paths, Tool::RabbitMQAdmin, "2.10.0", "");
+    install_tool(&paths, Tool::PerfTest, "2.23.0", "");
+
+    frm_cmd_with_dir(&temp)
+        .args(["toolbox", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rabbitmqadmin 2.10.0"))
+        .stdout(predicate::str::contains("perf-test 2.23.0"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["toolbox", "uninstall", "perf-test", "2.23.0"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Uninstalled perf-test 2.23.0"));
+    assert!(!paths.tool_version_dir("perf-test", "2.23.0").exists());
+
+    frm_cmd_with_dir(&temp)
+        .args(["toolbox", "uninstall", "perf-test", "2.23.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not installed"));
+}
+
+#[test]
+fn cli_toolbox_install_unknown_tool() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["toolbox", "install", "rabbitmqadmin-v1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn cli_rabbitmqadmin_not_installed() {
+    let temp = TempDir::new().unwrap();
+    fs::create_dir_all(temp.path().join("versions/4.2.3/sbin")).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "cli",
+            "rabbitmqadmin",
+            "-V",
+            "4.2.3",
+            "--",
+            "show",
+            "overview",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "use 'frm toolbox install rabbitmqadmin' first",
+        ));
+}
+
+#[cfg(unix)]
+#[test]
+fn cli_rabbitmqadmin_uses_toolbox() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    fs::create_dir_all(temp.path().join("versions/4.2.3/sbin")).unwrap();
+    install_tool(
+        &paths,
+        Tool::RabbitMQAdmin,
+        "2.10.0",
+        "#!/bin/sh\necho \"toolbox rabbitmqadmin $@\"\n",
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args([
+            "cli",
+            "rabbitmqadmin",
+            "-V",
+            "4.2.3",
+            "--",
+            "show",
+            "overview",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "toolbox rabbitmqadmin show overview",
+        ));
+}
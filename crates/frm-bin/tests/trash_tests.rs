@@ -0,0 +1,273 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::errors::Error;
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::timestamps::Timestamps;
+use frm::trash;
+use frm::version::Version;
+
+use common::install;
+
+fn setup_temp_paths() -> (TempDir, Paths) {
+    let temp_dir = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp_dir.path().to_path_buf());
+    (temp_dir, paths)
+}
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn trash_list_empty() {
+    let (_temp, paths) = setup_temp_paths();
+    assert!(trash::list(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn trash_move_and_restore() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    let entry = trash::move_to_trash(&paths, &version).unwrap();
+    assert!(!paths.version_installed(&version));
+    assert!(entry.path.starts_with(paths.trash_dir()));
+    assert_eq!(entry.dir_name(), format!("{}-4.2.3", entry.trashed_at));
+    assert_eq!(trash::list(&paths).unwrap(), vec![entry.clone()]);
+
+    trash::restore(&paths, &entry).unwrap();
+    assert!(
+        paths
+            .version_sbin_dir(&version)
+            .join("rabbitmq-server")
+            .exists()
+    );
+    assert!(trash::list(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn trash_restore_keeps_metadata() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    VersionMetadata::update(&paths, &version, |m| {
+        m.installed_at = Some(1_700_000_000);
+        m.pinned = true;
+    })
+    .unwrap();
+
+    let entry = trash::move_to_trash(&paths, &version).unwrap();
+    assert!(!paths.version_metadata_file(&version).exists());
+    assert!(entry.metadata_file().exists());
+
+    trash::restore(&paths, &entry).unwrap();
+    let metadata = VersionMetadata::load(&paths, &version).unwrap();
+    assert_eq!(metadata.installed_at, Some(1_700_000_000));
+    assert!(metadata.pinned);
+    assert!(!entry.metadata_file().exists());
+}
+
+#[test]
+fn trash_delete_removes_metadata() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    VersionMetadata::update(&paths, &version, |m| m.installed_at = Some(1_700_000_000)).unwrap();
+
+    let entry = trash::move_to_trash(&paths, &version).unwrap();
+    trash::delete(&entry).unwrap();
+    assert!(!entry.path.exists());
+    assert!(!entry.metadata_file().exists());
+}
+
+#[test]
+fn trash_list_parses_alpha_versions() {
+    let (_temp, paths) = setup_temp_paths();
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    install(&paths, &version);
+
+    trash::move_to_trash(&paths, &version).unwrap();
+
+    let entries = trash::list(&paths).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].version, version);
+}
+
+#[test]
+fn trash_list_ignores_unrelated_entries() {
+    let (_temp, paths) = setup_temp_paths();
+    fs::create_dir_all(paths.trash_dir().join("not-a-trash-entry")).unwrap();
+    fs::write(paths.trash_dir().join("1700000000-4.2.3"), "").unwrap();
+
+    assert!(trash::list(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn trash_find_returns_most_recent() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.trash_dir().join("100-4.2.3")).unwrap();
+    fs::create_dir_all(paths.trash_dir().join("200-4.2.3")).unwrap();
+    fs::create_dir_all(paths.trash_dir().join("300-4.1.0")).unwrap();
+
+    let entry = trash::find(&paths, &version).unwrap().unwrap();
+    assert_eq!(entry.trashed_at, 200);
+    assert!(
+        trash::find(&paths, &Version::new(4, 0, 0))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn trash_restore_refuses_to_overwrite() {
+    let (_temp, paths) = setup_temp_paths();
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    let entry = trash::move_to_trash(&paths, &version).unwrap();
+    install(&paths, &version);
+
+    let result = trash::restore(&paths, &entry);
+    assert!(matches!(result, Err(Error::VersionAlreadyInstalled(_))));
+    assert!(entry.path.exists());
+}
+
+#[test]
+fn cli_uninstall_moves_to_trash() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("frm trash restore 4.2.3"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.2.3 (uninstalled"));
+}
+
+#[test]
+fn cli_trash_restore() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    install(&paths, &version);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "uninstall", "latest"])
+        .assert()
+        .success();
+    assert!(!paths.version_installed(&version));
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "restore", "4.3.0-alpha.132057c7"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Restored RabbitMQ 4.3.0-alpha.132057c7",
+        ));
+    assert!(paths.version_installed(&version));
+}
+
+#[test]
+fn cli_trash_restore_keeps_installed_at() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    VersionMetadata::update(&paths, &version, |m| m.installed_at = Some(1_700_000_000)).unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "--yes", "4.2.3"])
+        .assert()
+        .success();
+    assert_eq!(Timestamps::load(&paths).unwrap().get(&version), None);
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "restore", "4.2.3"])
+        .assert()
+        .success();
+    assert_eq!(
+        Timestamps::load(&paths).unwrap().get(&version),
+        Some(1_700_000_000)
+    );
+}
+
+#[test]
+fn cli_trash_restore_not_in_trash() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "restore", "4.2.3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not in the trash"));
+}
+
+#[test]
+fn cli_trash_empty() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "empty", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Permanently deleted 1 trashed version(s)",
+        ));
+
+    assert!(trash::list(&paths).unwrap().is_empty());
+}
+
+#[test]
+fn cli_trash_empty_older_than() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    fs::create_dir_all(paths.trash_dir().join("1000-4.1.0")).unwrap();
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "uninstall", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["trash", "empty", "--older-than", "yesterday"])
+        .assert()
+        .success();
+
+    let remaining = trash::list(&paths).unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].version, Version::new(4, 2, 3));
+}
@@ -0,0 +1,153 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::ui::ManagementListener;
+use frm::paths::Paths;
+use frm::version::Version;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn setup(conf: &str) -> (TempDir, Paths, Version) {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    fs::create_dir_all(paths.version_sbin_dir(&version)).unwrap();
+    fs::create_dir_all(paths.version_etc_dir(&version)).unwrap();
+    fs::write(paths.version_etc_dir(&version).join("rabbitmq.conf"), conf).unwrap();
+    (temp, paths, version)
+}
+
+/// Responds to one HTTP request with 200 OK
+fn serve_once() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        }
+    });
+    port
+}
+
+/// A port nothing listens on
+fn unused_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+#[test]
+fn listener_defaults() {
+    let (_temp, paths, version) = setup("");
+    let listener = ManagementListener::resolve(&paths, &version, None).unwrap();
+    assert_eq!(listener.url, "http://localhost:15672/");
+    assert_eq!(listener.default_user, None);
+    assert_eq!(
+        listener.guest_login_url().unwrap(),
+        "http://localhost:15672/#/login/guest/guest"
+    );
+}
+
+#[test]
+fn listener_from_rabbitmq_conf() {
+    let (_temp, paths, version) = setup(
+        "management.tcp.port = 15673\nmanagement.path_prefix = /admin\ndefault_user = hare\n",
+    );
+    let listener = ManagementListener::resolve(&paths, &version, None).unwrap();
+    assert_eq!(listener.url, "http://localhost:15673/admin/");
+    assert_eq!(listener.default_user.as_deref(), Some("hare"));
+    assert_eq!(listener.guest_login_url(), None);
+}
+
+#[test]
+fn listener_uses_tls_when_only_the_tls_port_is_set() {
+    let (_temp, paths, version) = setup("management.ssl.port = 15671\n");
+    let listener = ManagementListener::resolve(&paths, &version, None).unwrap();
+    assert_eq!(listener.url, "https://localhost:15671/");
+}
+
+#[test]
+fn listener_rejects_invalid_ports() {
+    let (_temp, paths, version) = setup("management.tcp.port = nope\n");
+    let err = ManagementListener::resolve(&paths, &version, None).unwrap_err();
+    assert!(err.to_string().contains("management.tcp.port"));
+}
+
+#[test]
+fn ui_open_print() {
+    let port = serve_once();
+    let (temp, _paths, _version) = setup(&format!("management.tcp.port = {}\n", port));
+
+    frm_cmd_with_dir(&temp)
+        .args(["ui", "open", "-V", "4.2.3", "--print"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "http://localhost:{}/\n",
+            port
+        )))
+        .stdout(predicate::str::contains("#/login/guest/guest"));
+}
+
+#[cfg(unix)]
+#[test]
+fn ui_open_uses_browser_env_var() {
+    let port = serve_once();
+    let (temp, _paths, _version) = setup(&format!("management.tcp.port = {}\n", port));
+
+    frm_cmd_with_dir(&temp)
+        .env("BROWSER", "echo")
+        .args(["ui", "open", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "Opened http://localhost:{}/",
+            port
+        )));
+}
+
+#[test]
+fn ui_open_fails_when_node_is_not_responding() {
+    let (temp, _paths, _version) = setup(&format!("management.tcp.port = {}\n", unused_port()));
+
+    frm_cmd_with_dir(&temp)
+        .args(["ui", "open", "-V", "4.2.3", "--print"])
+        .assert()
+        .code(69)
+        .stderr(predicate::str::contains("is not responding"));
+}
+
+#[test]
+fn ui_open_requires_installed_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["ui", "open", "-V", "4.2.3", "--print"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
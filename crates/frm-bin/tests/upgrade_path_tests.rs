@@ -0,0 +1,92 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::format_upgrade_path;
+use frm::version::Series;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+#[test]
+fn format_upgrade_path_includes_source_series() {
+    assert_eq!(
+        format_upgrade_path(Series::new(3, 12), &[Series::new(3, 13), Series::new(4, 0)]),
+        "3.12 → 3.13 → 4.0"
+    );
+}
+
+#[test]
+fn cli_upgrade_path_direct() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "upgrade-path", "4.1.4", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.1 → 4.2"))
+        .stdout(predicate::str::contains(
+            "can be upgraded to 4.2.3 directly",
+        ));
+}
+
+#[test]
+fn cli_upgrade_path_same_series() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "upgrade-path", "4.2.1", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("same release series"));
+}
+
+#[test]
+fn cli_upgrade_path_warns_about_skipped_series() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "upgrade-path", "3.12.14", "4.1.0"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("3.12 → 3.13 → 4.0 → 4.1"))
+        .stdout(predicate::str::contains("directly is not supported"))
+        .stdout(predicate::str::contains("3.13.x, 4.0.x"));
+}
+
+#[test]
+fn cli_upgrade_path_downgrade() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "upgrade-path", "4.2.3", "4.1.0"])
+        .assert()
+        .failure()
+        .code(64)
+        .stderr(predicate::str::contains(
+            "no supported upgrade path from 4.2.3 to 4.1.0",
+        ));
+}
+
+#[test]
+fn cli_upgrade_path_invalid_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["releases", "upgrade-path", "4.2", "4.3.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid version"));
+}
@@ -0,0 +1,248 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::{Verification, VerificationCheck};
+use frm::metadata::{SignatureCheck, VersionMetadata};
+use frm::paths::Paths;
+use frm::provenance::{self, Provenance};
+use frm::version::Version;
+
+use common::install;
+
+const ARCHIVE_CONTENT: &[u8] = b"not really a tarball";
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+fn write_script(path: &Path) {
+    fs::write(path, "#!/bin/sh\n").unwrap();
+    #[cfg(unix)]
+    fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+/// A complete installation whose archive is still in the downloads directory
+fn install_with_provenance(paths: &Paths, version: &Version) {
+    install(paths, version);
+    let sbin = paths.version_sbin_dir(version);
+    for script in [
+        "rabbitmq-server",
+        "rabbitmqctl",
+        "rabbitmq-plugins",
+        "rabbitmq-diagnostics",
+    ] {
+        write_script(&sbin.join(script));
+    }
+
+    let file_name = format!("rabbitmq-server-generic-unix-{}.tar.xz", version);
+    let archive = paths.downloads_dir().join(&file_name);
+    fs::create_dir_all(paths.downloads_dir()).unwrap();
+    fs::write(&archive, ARCHIVE_CONTENT).unwrap();
+    Provenance::new(
+        format!("https://example.com/{}", file_name),
+        &file_name,
+        provenance::sha256_file(&archive).unwrap(),
+    )
+    .record(paths, version)
+    .unwrap();
+}
+
+fn record_signature(paths: &Paths, version: &Version, verified: bool) {
+    VersionMetadata::update(paths, version, |m| {
+        m.signature = Some(SignatureCheck {
+            verified,
+            checked_at: 1_760_000_000,
+        })
+    })
+    .unwrap();
+}
+
+fn archive_path(paths: &Paths, version: &Version) -> PathBuf {
+    paths
+        .downloads_dir()
+        .join(Provenance::load(paths, version).unwrap().unwrap().file_name)
+}
+
+#[test]
+fn verification_passes_for_an_intact_installation() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_provenance(&paths, &version);
+    record_signature(&paths, &version, true);
+
+    let verification = Verification::collect(&paths, &version);
+    assert_eq!(
+        verification.layout,
+        VerificationCheck::Passed("complete".into())
+    );
+    assert_eq!(
+        verification.checksum,
+        VerificationCheck::Passed("matches".into())
+    );
+    assert_eq!(
+        verification.signature,
+        VerificationCheck::Passed("verified".into())
+    );
+    assert!(!verification.failed());
+}
+
+#[test]
+fn verification_fails_when_the_cached_archive_changed() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_provenance(&paths, &version);
+    fs::write(archive_path(&paths, &version), b"something else").unwrap();
+
+    let verification = Verification::collect(&paths, &version);
+    assert!(matches!(
+        verification.checksum,
+        VerificationCheck::Failed(_)
+    ));
+    assert!(verification.failed());
+}
+
+#[test]
+fn verification_skips_what_it_cannot_check() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_provenance(&paths, &version);
+    fs::remove_file(archive_path(&paths, &version)).unwrap();
+
+    let verification = Verification::collect(&paths, &version);
+    assert_eq!(
+        verification.checksum,
+        VerificationCheck::Skipped("archive not cached".into())
+    );
+    assert_eq!(
+        verification.signature,
+        VerificationCheck::Skipped("not checked".into())
+    );
+    assert!(!verification.failed());
+
+    let alpha: Version = "4.3.0-alpha.132057c7".parse().unwrap();
+    install_with_provenance(&paths, &alpha);
+    assert_eq!(
+        Verification::collect(&paths, &alpha).signature,
+        VerificationCheck::Skipped("not signed".into())
+    );
+}
+
+#[test]
+fn verification_fails_for_a_failed_signature_check_and_missing_scripts() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_provenance(&paths, &version);
+    record_signature(&paths, &version, false);
+    fs::remove_file(paths.version_sbin_dir(&version).join("rabbitmqctl")).unwrap();
+
+    let verification = Verification::collect(&paths, &version);
+    assert_eq!(
+        verification.layout,
+        VerificationCheck::Failed("missing rabbitmqctl".into())
+    );
+    assert!(matches!(
+        verification.signature,
+        VerificationCheck::Failed(_)
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn verification_fails_for_non_executable_scripts() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install_with_provenance(&paths, &version);
+    let script = paths.version_sbin_dir(&version).join("rabbitmq-plugins");
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o644)).unwrap();
+
+    assert_eq!(
+        Verification::collect(&paths, &version).layout,
+        VerificationCheck::Failed("1 file(s) not executable".into())
+    );
+}
+
+#[test]
+fn cli_verify_all_reports_every_installation() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let versions = [
+        Version::new(4, 0, 9),
+        Version::new(4, 1, 6),
+        Version::new(4, 2, 3),
+    ];
+    for version in &versions {
+        install_with_provenance(&paths, version);
+    }
+
+    frm_cmd_with_dir(&temp)
+        .args(["verify", "--all", "--jobs", "2"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("4.0.9"))
+        .stdout(predicate::str::contains("4.1.6"))
+        .stdout(predicate::str::contains("4.2.3"))
+        .stdout(predicate::str::contains("All 3 installation(s) verified"));
+
+    fs::write(archive_path(&paths, &versions[1]), b"corrupted").unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["verify", "--all"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("FAILED"))
+        .stderr(predicate::str::contains(
+            "1 of 3 installation(s) failed verification",
+        ));
+}
+
+#[test]
+fn cli_verify_a_single_version() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install_with_provenance(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["verify", "-V", "4.2.3"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("matches"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["verify", "-V", "4.1.0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not installed"));
+}
+
+#[test]
+fn cli_verify_all_conflicts_with_a_version() {
+    let temp = TempDir::new().unwrap();
+
+    frm_cmd_with_dir(&temp)
+        .args(["verify", "--all", "-V", "4.2.3"])
+        .assert()
+        .failure();
+}
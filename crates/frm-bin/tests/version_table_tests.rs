@@ -0,0 +1,187 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::process;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::{VersionRow, render_version_table};
+use frm::common::erlang::supported_erlang_versions;
+use frm::common::fs_utils::format_size;
+use frm::denylist::Denylist;
+use frm::metadata::VersionMetadata;
+use frm::node_state;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd.env_remove("RABBITMQ_HOME");
+    cmd.env_remove("FRM_ACTIVE_SBIN");
+    cmd
+}
+
+fn write_pid_file(paths: &Paths, version: &Version, pid: u32) {
+    let data_dir = paths.version_data_dir(version);
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(data_dir.join("rabbit@localhost.pid"), pid.to_string()).unwrap();
+}
+
+#[test]
+fn format_size_uses_binary_units() {
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(1536), "1.5 KiB");
+    assert_eq!(format_size(41 * 1024 * 1024), "41.0 MiB");
+}
+
+#[test]
+fn erlang_requirements() {
+    assert_eq!(
+        supported_erlang_versions(&Version::new(4, 0, 5)),
+        Some(("26.2", "27.x"))
+    );
+    assert_eq!(supported_erlang_versions(&Version::new(2, 8, 0)), None);
+}
+
+#[test]
+fn node_state_detects_running_node() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    assert!(!node_state::is_running(&paths, &version).unwrap());
+
+    write_pid_file(&paths, &version, process::id());
+    assert_eq!(
+        node_state::running_pids(&paths, &version).unwrap(),
+        vec![process::id()]
+    );
+}
+
+#[test]
+fn version_row_collect() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+    VersionMetadata::update(&paths, &version, |m| {
+        m.pinned = true;
+        m.installed_at = Some(1_760_000_000);
+    })
+    .unwrap();
+
+    let row = VersionRow::collect(
+        &paths,
+        &version,
+        Some(&version),
+        Some(&version),
+        &Denylist::default(),
+    )
+    .unwrap();
+    assert_eq!(row.version, "4.2.3");
+    assert_eq!(row.flavor, "GA");
+    assert_eq!(row.erlang, "27.0 - 28.x");
+    assert_eq!(row.flags, "active, default, pinned");
+    assert_eq!(row.state, "stopped");
+    assert!(row.installed.starts_with("2025-10-"));
+}
+
+#[test]
+fn render_has_headers_and_no_colors_when_disabled() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version = Version::new(4, 2, 3);
+    install(&paths, &version);
+
+    let row = VersionRow::collect(&paths, &version, None, None, &Denylist::default()).unwrap();
+    let table = render_version_table(vec![row], false, false);
+    for header in [
+        "Version",
+        "Flavor",
+        "Erlang",
+        "Size",
+        "Installed",
+        "Flags",
+        "State",
+    ] {
+        assert!(table.contains(header), "missing {} in {}", header, table);
+    }
+    assert!(!table.contains('\u{1b}'));
+    assert!(!table.contains("Signature"));
+}
+
+#[test]
+fn cli_releases_list_table() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 1, 0));
+    install(&paths, &Version::new(4, 2, 3));
+    write_pid_file(&paths, &Version::new(4, 2, 3), process::id());
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    let output = frm_cmd_with_dir(&temp)
+        .args(["releases", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = |v: &str| {
+        stdout
+            .lines()
+            .find(|l| l.contains(v))
+            .unwrap_or_else(|| panic!("no row for {} in {}", v, stdout))
+            .to_string()
+    };
+
+    assert!(stdout.contains("Flavor"));
+    assert!(line("4.2.3").contains("default"));
+    assert!(line("4.2.3").contains("running"));
+    assert!(line("4.1.0").contains("stopped"));
+    assert!(!line("4.1.0").contains("default"));
+}
+
+#[test]
+fn cli_status_table() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    install(&paths, &Version::new(4, 2, 3));
+
+    frm_cmd_with_dir(&temp)
+        .args(["default", "4.2.3"])
+        .assert()
+        .success();
+
+    frm_cmd_with_dir(&temp)
+        .args(["status", "--no-color"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default: 4.2.3"))
+        .stdout(predicate::str::contains("Erlang"))
+        .stdout(predicate::str::contains("default"));
+
+    frm_cmd_with_dir(&temp)
+        .args(["status", "--plain"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("⚪ 4.2.3"))
+        .stdout(predicate::str::contains("Erlang").not());
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::commands::{alpha_rotation_candidates, is_new_alpha, record_seen_alpha};
+use frm::common::urls::SERVER_PACKAGES_API_URL;
+use frm::feed_cache::CachedFeed;
+use frm::metadata::VersionMetadata;
+use frm::paths::Paths;
+use frm::version::Version;
+
+use common::install;
+
+const ALPHA: &str = "4.3.0-alpha.132057c7";
+
+#[allow(deprecated)]
+fn frm_cmd_with_dir(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", dir.path());
+    cmd
+}
+
+/// A fake installation recorded as installed at `installed_at`
+fn install_at(paths: &Paths, version: &Version, installed_at: u64) {
+    install(paths, version);
+    VersionMetadata::update(paths, version, |m| m.installed_at = Some(installed_at)).unwrap();
+}
+
+/// Seeds a fresh feed cache, so that no requests to GitHub are made
+fn seed_feed(paths: &Paths) {
+    let body = format!(
+        r#"[{{"tag_name": "alphas.1761000000000", "name": "RabbitMQ {}", "published_at": "2026-10-16T00:00:00Z"}}]"#,
+        ALPHA
+    );
+    let fetched_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    CachedFeed {
+        url: format!("{}?per_page=100", SERVER_PACKAGES_API_URL),
+        etag: None,
+        fetched_at,
+        body,
+    }
+    .save(&paths.server_packages_feed_cache_file())
+    .unwrap();
+}
+
+#[test]
+fn alpha_is_new_until_seen_or_installed() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let version: Version = ALPHA.parse().unwrap();
+
+    assert!(is_new_alpha(&paths, &version));
+    record_seen_alpha(&paths, &version).unwrap();
+    assert!(!is_new_alpha(&paths, &version));
+
+    let newer: Version = "4.3.0-alpha.abcd1234".parse().unwrap();
+    assert!(is_new_alpha(&paths, &newer));
+    install_at(&paths, &newer, 1);
+    assert!(!is_new_alpha(&paths, &newer));
+}
+
+#[test]
+fn rotation_keeps_most_recently_installed_and_pinned_alphas() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    let oldest: Version = "4.3.0-alpha.00000001".parse().unwrap();
+    let pinned: Version = "4.3.0-alpha.00000002".parse().unwrap();
+    let older: Version = "4.3.0-alpha.00000003".parse().unwrap();
+    let newest: Version = "4.3.0-alpha.00000004".parse().unwrap();
+    let ga = Version::new(4, 2, 3);
+    install_at(&paths, &oldest, 100);
+    install_at(&paths, &pinned, 150);
+    install_at(&paths, &older, 200);
+    install_at(&paths, &newest, 300);
+    install_at(&paths, &ga, 50);
+    VersionMetadata::update(&paths, &pinned, |m| m.pinned = true).unwrap();
+
+    assert_eq!(
+        alpha_rotation_candidates(&paths, 1).unwrap(),
+        vec![older.clone(), oldest.clone()]
+    );
+    assert_eq!(alpha_rotation_candidates(&paths, 2).unwrap(), vec![oldest]);
+    assert!(alpha_rotation_candidates(&paths, 3).unwrap().is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn watch_once_runs_hook_for_new_alpha() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    seed_feed(&paths);
+    let out = temp.path().join("hook.out");
+    let hook = format!(
+        "echo \"$FRM_ALPHA_VERSION $FRM_ALPHA_TAG\" > {}",
+        out.display()
+    );
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "watch", "--once", "--exec", &hook])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("New alpha release"));
+    assert_eq!(
+        fs::read_to_string(&out).unwrap().trim(),
+        format!("{} alphas.1761000000000", ALPHA)
+    );
+
+    fs::remove_file(&out).unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "watch", "--once", "--exec", &hook])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No new alpha releases"));
+    assert!(!out.exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn watch_once_fails_when_hook_fails() {
+    let temp = TempDir::new().unwrap();
+    let paths = Paths::with_base_dir(temp.path().to_path_buf());
+    seed_feed(&paths);
+
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "watch", "--once", "--exec", "exit 3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("exited with code 3"));
+    // not recorded, so that the next check tries again
+    assert!(is_new_alpha(&paths, &ALPHA.parse().unwrap()));
+}
+
+#[test]
+fn watch_validates_arguments() {
+    let temp = TempDir::new().unwrap();
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "watch", "--once", "--keep", "2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--install"));
+    frm_cmd_with_dir(&temp)
+        .args(["alphas", "watch", "--interval", "0"])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,232 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+mod common;
+
+use std::fs;
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+use frm::version::{Series, Version};
+use frm::workspace::{self, VersionSpec, Workspace};
+
+use common::install_in;
+
+#[allow(deprecated)]
+fn frm_cmd(temp: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("frm").unwrap();
+    cmd.env("FRM_DIR", temp.path()).env_remove("FRM_LOG");
+    cmd
+}
+
+#[test]
+fn version_spec_parses_series_and_versions() {
+    assert_eq!(
+        "4.1.x".parse::<VersionSpec>().unwrap(),
+        VersionSpec::Series(Series::new(4, 1))
+    );
+    assert_eq!(
+        "4.1".parse::<VersionSpec>().unwrap(),
+        VersionSpec::Series(Series::new(4, 1))
+    );
+    assert_eq!(
+        "4.2.3".parse::<VersionSpec>().unwrap(),
+        VersionSpec::Exact(Version::new(4, 2, 3))
+    );
+    assert!("4.x".parse::<VersionSpec>().is_err());
+    assert_eq!("4.1".parse::<VersionSpec>().unwrap().to_string(), "4.1.x");
+}
+
+#[test]
+fn workspace_resolves_series_to_the_highest_installed_version() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.2");
+    install_in(temp.path(), "4.1.5");
+    let paths = install_in(temp.path(), "4.2.3");
+
+    let definition = Workspace {
+        versions: vec!["4.2.x".into(), "4.1.x".into(), "4.1.5".into()],
+        env: None,
+    };
+    assert_eq!(
+        definition.resolve(&paths).unwrap(),
+        vec![Version::new(4, 1, 5), Version::new(4, 2, 3)]
+    );
+
+    let missing = Workspace {
+        versions: vec!["4.0.x".into()],
+        env: None,
+    };
+    assert!(
+        missing
+            .resolve(&paths)
+            .unwrap_err()
+            .to_string()
+            .contains("no installed version matches 4.0.x")
+    );
+}
+
+#[test]
+fn write_shims_covers_every_version_and_series() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.5");
+    let paths = install_in(temp.path(), "4.2.3");
+    let versions = [Version::new(4, 1, 5), Version::new(4, 2, 3)];
+
+    let bin_dir = workspace::write_shims(&paths, "ci", &versions, None).unwrap();
+    for name in [
+        "rabbitmqctl-4.1.5",
+        "rabbitmqctl-4.1",
+        "rabbitmqctl-4.2",
+        "rabbitmq-diagnostics-4.2.3",
+    ] {
+        assert!(bin_dir.join(name).is_file(), "{} is missing", name);
+    }
+    let default = fs::read_to_string(bin_dir.join("rabbitmqctl")).unwrap();
+    assert!(default.contains("versions/4.2.3/sbin/rabbitmqctl' \"$@\""));
+    assert!(default.contains("export RABBITMQ_HOME="));
+}
+
+#[test]
+fn cli_workspace_create_list_delete() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.5");
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd(&temp)
+        .args([
+            "workspace",
+            "create",
+            "client-tests",
+            "--versions",
+            "4.1.x,4.2.3",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Created workspace 'client-tests' with 4.1.x, 4.2.3",
+        ))
+        .stdout(predicate::str::contains("4.1.x is currently 4.1.5"));
+
+    frm_cmd(&temp)
+        .args(["workspace", "create", "client-tests", "--versions", "4.2.x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    frm_cmd(&temp)
+        .args(["workspace", "list"])
+        .assert()
+        .success()
+        .stdout("client-tests: 4.1.x, 4.2.3\n");
+
+    frm_cmd(&temp)
+        .args(["workspace", "delete", "client-tests", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Deleted workspace 'client-tests'"));
+    assert!(temp.path().join("versions").join("4.1.5").exists());
+
+    frm_cmd(&temp)
+        .args(["workspace", "enter", "client-tests"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "workspace 'client-tests' does not exist",
+        ));
+}
+
+#[test]
+fn cli_workspace_create_requires_installed_versions_and_envs() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.2.3");
+
+    frm_cmd(&temp)
+        .args(["workspace", "create", "ci", "--versions", "4.0.x,4.2.x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no installed version matches 4.0.x",
+        ));
+
+    frm_cmd(&temp)
+        .args([
+            "workspace",
+            "create",
+            "ci",
+            "--versions",
+            "4.2.x",
+            "--env",
+            "tls",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "environment 'tls' does not exist for version 4.2.3",
+        ));
+
+    frm_cmd(&temp)
+        .args(["workspace", "create", "ci/x", "--versions", "4.2.x"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid workspace name"));
+    assert!(!temp.path().join("workspaces").join("ci").exists());
+}
+
+#[test]
+fn cli_workspace_enter_exports_the_workspace() {
+    let temp = TempDir::new().unwrap();
+    install_in(temp.path(), "4.1.5");
+    install_in(temp.path(), "4.2.3");
+    frm_cmd(&temp)
+        .args(["envs", "create", "tls", "-V", "4.1.5"])
+        .assert()
+        .success();
+    frm_cmd(&temp)
+        .args(["envs", "create", "tls", "-V", "4.2.3"])
+        .assert()
+        .success();
+    frm_cmd(&temp)
+        .args([
+            "workspace",
+            "create",
+            "client-tests",
+            "--versions",
+            "4.1.x,4.2.x",
+        ])
+        .args(["--env", "tls"])
+        .assert()
+        .success();
+
+    let bin_dir = temp
+        .path()
+        .join("workspaces")
+        .join("client-tests")
+        .join("bin");
+    frm_cmd(&temp)
+        .args(["workspace", "enter", "client-tests", "--shell", "bash"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "export PATH=\"{}",
+            bin_dir.display()
+        )))
+        .stdout(predicate::str::contains(
+            "export FRM_WORKSPACE=\"client-tests\"",
+        ))
+        .stdout(predicate::str::contains(
+            "export FRM_WORKSPACE_VERSIONS=\"4.1.5 4.2.3\"",
+        ))
+        .stdout(predicate::str::contains("export FRM_ENV=\"tls\""))
+        .stdout(predicate::str::contains("frm-deactivate()"));
+
+    let shim = fs::read_to_string(bin_dir.join("rabbitmqctl-4.1")).unwrap();
+    assert!(shim.contains("versions/4.1.5/envs/tls/etc/rabbitmq/rabbitmq.conf"));
+}
@@ -111,6 +111,17 @@ fn parse_line(line: &str, line_num: usize) -> Result<Line> {
     }
 }
 
+fn segments_match(key: &[&str], pattern: &[&str]) -> bool {
+    match (pattern.split_first(), key.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), Some(_)) => (1..=key.len()).any(|n| segments_match(&key[n..], rest)),
+        (Some((p, rest)), Some((k, key_rest))) => {
+            (*p == "*" || p == k) && segments_match(key_rest, rest)
+        }
+        _ => false,
+    }
+}
+
 impl RabbitMQConf {
     /// Create an empty configuration
     pub fn new() -> Self {
@@ -154,34 +165,30 @@ impl RabbitMQConf {
         }
     }
 
-    /// Get all keys matching a pattern (with `*` as wildcard for a single segment)
+    /// Get all keys matching a pattern: `*` matches a single segment,
+    /// `**` matches one or more segments (`log.**` matches every `log.` key)
     pub fn get_matching(&self, pattern: &str) -> Vec<(&str, &str)> {
         let pattern_parts: Vec<&str> = pattern.split('.').collect();
 
-        self.key_index
-            .iter()
-            .filter_map(|(key, idx)| {
+        self.entries()
+            .filter(|(key, _)| {
                 let key_parts: Vec<&str> = key.split('.').collect();
-
-                if key_parts.len() != pattern_parts.len() {
-                    return None;
-                }
-
-                for (k, p) in key_parts.iter().zip(pattern_parts.iter()) {
-                    if *p != "*" && k != p {
-                        return None;
-                    }
-                }
-
-                if let Line::Setting { value, .. } = &self.lines[*idx] {
-                    Some((key.as_str(), value.as_str()))
-                } else {
-                    None
-                }
+                segments_match(&key_parts, &pattern_parts)
             })
             .collect()
     }
 
+    /// All settings as key-value pairs, ordered by key
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.key_index.iter().filter_map(|(key, idx)| {
+            if let Line::Setting { value, .. } = &self.lines[*idx] {
+                Some((key.as_str(), value.as_str()))
+            } else {
+                None
+            }
+        })
+    }
+
     /// Check if a pattern contains wildcards
     pub fn is_pattern(key: &str) -> bool {
         key.contains('*')
@@ -0,0 +1,683 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing and syntax validation of files in the Erlang term format read with
+//! `file:consult/1`: `advanced.config`, the classic `rabbitmq.config`, and `enabled_plugins`.
+//!
+//! A malformed file is only reported by a node when it boots, and not always clearly,
+//! so this module checks what a node would trip on: unbalanced brackets, the dot that
+//! ends the term, unterminated strings and quoted atoms, and identifiers that start with
+//! an uppercase letter, which Erlang reads as variables rather than atoms.
+//! Term values, e.g. whether a setting exists, are not checked.
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::Result;
+use crate::errors::Error;
+
+/// An Erlang term as written in a configuration file
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    Atom(String),
+    /// Integers, including character literals such as `$a`
+    Integer(i128),
+    Float(f64),
+    /// A string, that is, a list of characters
+    String(String),
+    /// The text of a binary such as `<<"guest">>`. Size and type specifiers are not applied.
+    Binary(String),
+    Tuple(Vec<Term>),
+    List(Vec<Term>),
+    /// `[Head | Tail]` where `Tail` is not a list
+    ImproperList(Vec<Term>, Box<Term>),
+    Map(Vec<(Term, Term)>),
+}
+
+impl Term {
+    pub fn as_atom(&self) -> Option<&str> {
+        match self {
+            Term::Atom(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// The text of a string or a binary
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Term::String(s) | Term::Binary(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Term::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Term]> {
+        match self {
+            Term::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// The key and value of a `{Key, Value}` tuple with an atom key
+    pub fn as_pair(&self) -> Option<(&str, &Term)> {
+        match self {
+            Term::Tuple(items) => match items.as_slice() {
+                [Term::Atom(key), value] => Some((key, value)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Formats the term in Erlang syntax
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Atom(a) => {
+                let bare = a.starts_with(|c: char| c.is_ascii_lowercase())
+                    && a.chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '@');
+                if bare {
+                    f.write_str(a)
+                } else {
+                    write!(f, "'{}'", escape(a, '\''))
+                }
+            }
+            Term::Integer(n) => write!(f, "{}", n),
+            Term::Float(x) => {
+                // Erlang floats need a decimal point, even with an exponent
+                let s = format!("{:?}", x);
+                match s.split_once('e') {
+                    Some((mantissa, exponent)) if !mantissa.contains('.') => {
+                        write!(f, "{}.0e{}", mantissa, exponent)
+                    }
+                    _ => f.write_str(&s),
+                }
+            }
+            Term::String(s) => write!(f, "\"{}\"", escape(s, '"')),
+            Term::Binary(s) => write!(f, "<<\"{}\">>", escape(s, '"')),
+            Term::Tuple(items) => write!(f, "{{{}}}", join(items)),
+            Term::List(items) => write!(f, "[{}]", join(items)),
+            Term::ImproperList(items, tail) => write!(f, "[{} | {}]", join(items), tail),
+            Term::Map(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| format!("{} => {}", k, v))
+                    .collect();
+                write!(f, "#{{{}}}", entries.join(", "))
+            }
+        }
+    }
+}
+
+fn join(items: &[Term]) -> String {
+    items
+        .iter()
+        .map(|t| t.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn escape(s: &str, quote: char) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if c == quote => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// `{ } [ ] , | . => #{ << >> : / -`
+    Punct(&'static str),
+    Atom(String),
+    String(String),
+    Integer(i128),
+    Float(f64),
+    Variable(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Punct(p) => write!(f, "'{}'", p),
+            Token::Atom(_) => write!(f, "an atom"),
+            Token::String(_) => write!(f, "a string"),
+            Token::Integer(_) | Token::Float(_) => write!(f, "a number"),
+            Token::Variable(name) => write!(f, "'{}'", name),
+        }
+    }
+}
+
+/// Parses `content` as a single list term ending with a dot, as RabbitMQ expects of
+/// `advanced.config`, `rabbitmq.config`, and `enabled_plugins`. A file with only comments
+/// and whitespace is an empty list.
+pub fn parse(content: &str) -> Result<Term> {
+    let tokens = Lexer::new(content).tokenize()?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: end_position(content),
+    };
+    parser.file()
+}
+
+/// Checks that `content` is a single list term ending with a dot, see [`parse`]
+pub fn validate(content: &str) -> Result<()> {
+    parse(content).map(|_| ())
+}
+
+fn end_position(content: &str) -> Position {
+    let line = content.lines().count().max(1);
+    let column = content.lines().last().map_or(0, |l| l.chars().count()) + 1;
+    Position { line, column }
+}
+
+fn error(at: Position, message: impl Into<String>) -> Error {
+    Error::TermSyntaxError {
+        line: at.line,
+        column: at.column,
+        message: message.into(),
+    }
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            chars: content.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn position(&self) -> Position {
+        Position {
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn bump_while(&mut self, f: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !f(c) {
+                break;
+            }
+            taken.push(c);
+            self.bump();
+        }
+        taken
+    }
+
+    fn tokenize(mut self) -> Result<Vec<(Token, Position)>> {
+        let mut tokens = Vec::new();
+        while let Some(&c) = self.chars.peek() {
+            let at = self.position();
+            let token = match c {
+                c if c.is_whitespace() => {
+                    self.bump();
+                    continue;
+                }
+                '%' => {
+                    self.bump_while(|c| c != '\n');
+                    continue;
+                }
+                '"' => Token::String(self.quoted('"', at, "string")?),
+                '\'' => Token::Atom(self.quoted('\'', at, "quoted atom")?),
+                '$' => Token::Integer(self.char_literal(at)?),
+                c if c.is_ascii_digit() => self.number(at)?,
+                c if c.is_lowercase() => Token::Atom(self.bump_while(is_identifier_char)),
+                c if c.is_uppercase() || c == '_' => {
+                    Token::Variable(self.bump_while(is_identifier_char))
+                }
+                _ => Token::Punct(self.punct(at)?),
+            };
+            tokens.push((token, at));
+        }
+        Ok(tokens)
+    }
+
+    fn quoted(&mut self, quote: char, at: Position, what: &str) -> Result<String> {
+        let unterminated = || error(at, format!("{} is not terminated with {}", what, quote));
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => text.push(self.escape().ok_or_else(unterminated)?),
+                Some(c) if c == quote => return Ok(text),
+                Some(c) => text.push(c),
+                None => return Err(unterminated()),
+            }
+        }
+    }
+
+    fn char_literal(&mut self, at: Position) -> Result<i128> {
+        self.bump();
+        let c = match self.bump() {
+            Some('\\') => self.escape(),
+            c => c,
+        };
+        c.map(|c| c as i128)
+            .ok_or_else(|| error(at, "character literal is not complete"))
+    }
+
+    /// The character after a backslash in a string, quoted atom, or character literal
+    fn escape(&mut self) -> Option<char> {
+        let c = self.bump()?;
+        let escaped = match c {
+            'b' => '\u{8}',
+            'd' => '\u{7f}',
+            'e' => '\u{1b}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            's' => ' ',
+            't' => '\t',
+            'v' => '\u{b}',
+            '^' => char::from_u32(self.bump()? as u32 % 32)?,
+            'x' => {
+                let digits = if self.chars.peek() == Some(&'{') {
+                    self.bump();
+                    let digits = self.bump_while(|c| c != '}');
+                    self.bump();
+                    digits
+                } else {
+                    let mut digits = String::new();
+                    for _ in 0..2 {
+                        digits.push(self.bump()?);
+                    }
+                    digits
+                };
+                char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?
+            }
+            '0'..='7' => {
+                let mut digits = c.to_string();
+                while digits.len() < 3 && self.chars.peek().is_some_and(|c| ('0'..='7').contains(c))
+                {
+                    digits.push(self.bump()?);
+                }
+                char::from_u32(u32::from_str_radix(&digits, 8).ok()?)?
+            }
+            c => c,
+        };
+        Some(escaped)
+    }
+
+    /// Integers, `16#ff`-style integers in other bases, and floats
+    fn number(&mut self, at: Position) -> Result<Token> {
+        let invalid = |text: &str| error(at, format!("'{}' is not a valid number", text));
+        let digits = self.bump_while(|c| c.is_ascii_digit() || c == '_');
+        let integer = digits.replace('_', "");
+        if self.chars.peek() == Some(&'#') {
+            self.bump();
+            let value = self.bump_while(|c| c.is_ascii_alphanumeric() || c == '_');
+            let text = format!("{}#{}", digits, value);
+            let radix = integer
+                .parse::<u32>()
+                .ok()
+                .filter(|r| (2..=36).contains(r))
+                .ok_or_else(|| invalid(&text))?;
+            return i128::from_str_radix(&value.replace('_', ""), radix)
+                .map(Token::Integer)
+                .map_err(|_| invalid(&text));
+        }
+        // a dot followed by a digit is a decimal point, otherwise it ends the term
+        let mut lookahead = self.chars.clone();
+        if lookahead.next() == Some('.') && lookahead.next().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            let mut text = format!(
+                "{}.{}",
+                integer,
+                self.bump_while(|c| c.is_ascii_digit() || c == '_')
+            );
+            if matches!(self.chars.peek(), Some('e' | 'E')) {
+                self.bump();
+                text.push('e');
+                if let Some(sign @ ('+' | '-')) = self.chars.peek().copied() {
+                    self.bump();
+                    text.push(sign);
+                }
+                text.push_str(&self.bump_while(|c| c.is_ascii_digit()));
+            }
+            let text = text.replace('_', "");
+            return text
+                .parse::<f64>()
+                .map(Token::Float)
+                .map_err(|_| invalid(&text));
+        }
+        integer
+            .parse::<i128>()
+            .map(Token::Integer)
+            .map_err(|_| invalid(&digits))
+    }
+
+    fn punct(&mut self, at: Position) -> Result<&'static str> {
+        let c = self.bump().unwrap_or_default();
+        let next = self.chars.peek().copied();
+        let punct = match (c, next) {
+            ('=', Some('>')) => "=>",
+            ('#', Some('{')) => "#{",
+            ('<', Some('<')) => "<<",
+            ('>', Some('>')) => ">>",
+            ('{', _) => return Ok("{"),
+            ('}', _) => return Ok("}"),
+            ('[', _) => return Ok("["),
+            (']', _) => return Ok("]"),
+            (',', _) => return Ok(","),
+            ('|', _) => return Ok("|"),
+            ('.', _) => return Ok("."),
+            (':', _) => return Ok(":"),
+            ('/', _) => return Ok("/"),
+            ('-', _) => return Ok("-"),
+            _ => return Err(error(at, format!("unexpected character '{}'", c))),
+        };
+        self.bump();
+        Ok(punct)
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '@'
+}
+
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    pos: usize,
+    end: Position,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, Position)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<(Token, Position)> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_is(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some((Token::Punct(p), _)) if *p == punct)
+    }
+
+    fn file(&mut self) -> Result<Term> {
+        let Some((first, start)) = self.peek().cloned() else {
+            return Ok(Term::List(Vec::new()));
+        };
+        if first != Token::Punct("[") {
+            return Err(error(
+                start,
+                format!("expected a list, e.g. [{{rabbit, [...]}}], found {}", first),
+            ));
+        }
+        let term = self.term()?;
+        match self.next() {
+            Some((Token::Punct("."), _)) => {}
+            Some((token, at)) => {
+                return Err(error(
+                    at,
+                    format!("expected '.' after the list, found {}", token),
+                ));
+            }
+            None => {
+                return Err(error(
+                    self.end,
+                    format!("missing '.' after the list that starts at {}", start),
+                ));
+            }
+        }
+        match self.next() {
+            None => Ok(term),
+            Some((_, at)) => Err(error(
+                at,
+                "only one list is allowed, the previous one ended with '.'",
+            )),
+        }
+    }
+
+    fn term(&mut self) -> Result<Term> {
+        let Some((token, at)) = self.next() else {
+            return Err(error(
+                self.end,
+                "expected a term, found the end of the file",
+            ));
+        };
+        match token {
+            Token::Atom(a) => Ok(Term::Atom(a)),
+            Token::Integer(n) => Ok(Term::Integer(n)),
+            Token::Float(x) => Ok(Term::Float(x)),
+            // adjacent strings are concatenated
+            Token::String(mut s) => {
+                while let Some((Token::String(next), _)) = self.peek() {
+                    s.push_str(next);
+                    self.pos += 1;
+                }
+                Ok(Term::String(s))
+            }
+            Token::Variable(name) => Err(error(
+                at,
+                format!(
+                    "'{0}' starts with an uppercase letter or '_', which makes it a variable: \
+                    quote it to make it an atom ('{0}') or a string (\"{0}\")",
+                    name
+                ),
+            )),
+            Token::Punct("-") => match self.next() {
+                Some((Token::Integer(n), _)) => Ok(Term::Integer(-n)),
+                Some((Token::Float(x), _)) => Ok(Term::Float(-x)),
+                _ => Err(error(at, "'-' must be followed by a number")),
+            },
+            Token::Punct("{") => Ok(Term::Tuple(self.sequence("{", at, "}", Self::term)?)),
+            Token::Punct("#{") => Ok(Term::Map(self.sequence(
+                "#{",
+                at,
+                "}",
+                Self::association,
+            )?)),
+            Token::Punct("<<") => Ok(Term::Binary(
+                self.sequence("<<", at, ">>", Self::binary_element)?
+                    .concat(),
+            )),
+            Token::Punct("[") => self.list(at),
+            other => Err(error(at, format!("expected a term, found {}", other))),
+        }
+    }
+
+    /// Terms separated by commas up to `close`, with the opening token already consumed
+    fn sequence<T>(
+        &mut self,
+        open: &str,
+        opened_at: Position,
+        close: &'static str,
+        element: fn(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut elements = Vec::new();
+        if self.peek_is(close) {
+            self.pos += 1;
+            return Ok(elements);
+        }
+        loop {
+            if self.peek().is_none() {
+                return Err(self.not_closed(open, opened_at, close, None));
+            }
+            elements.push(element(self)?);
+            match self.next() {
+                Some((Token::Punct(","), _)) => continue,
+                Some((Token::Punct(p), _)) if p == close => return Ok(elements),
+                other => return Err(self.not_closed(open, opened_at, close, other)),
+            }
+        }
+    }
+
+    fn list(&mut self, opened_at: Position) -> Result<Term> {
+        let mut items = Vec::new();
+        if self.peek_is("]") {
+            self.pos += 1;
+            return Ok(Term::List(items));
+        }
+        loop {
+            if self.peek().is_none() {
+                return Err(self.not_closed("[", opened_at, "]", None));
+            }
+            items.push(self.term()?);
+            match self.next() {
+                Some((Token::Punct(","), _)) => continue,
+                Some((Token::Punct("]"), _)) => return Ok(Term::List(items)),
+                Some((Token::Punct("|"), _)) => {
+                    let tail = self.term()?;
+                    return match self.next() {
+                        Some((Token::Punct("]"), _)) => Ok(match tail {
+                            Term::List(rest) => {
+                                items.extend(rest);
+                                Term::List(items)
+                            }
+                            tail => Term::ImproperList(items, Box::new(tail)),
+                        }),
+                        other => Err(self.not_closed("[", opened_at, "]", other)),
+                    };
+                }
+                other => return Err(self.not_closed("[", opened_at, "]", other)),
+            }
+        }
+    }
+
+    fn association(&mut self) -> Result<(Term, Term)> {
+        let key = self.term()?;
+        match self.next() {
+            Some((Token::Punct("=>"), _)) => Ok((key, self.term()?)),
+            Some((token, at)) => Err(error(
+                at,
+                format!("expected '=>' in a map, found {}", token),
+            )),
+            None => Err(error(self.end, "expected '=>' in a map")),
+        }
+    }
+
+    /// `<<"text">>`, `<<1, 2>>`, with optional `:Size` and `/Type` suffixes
+    fn binary_element(&mut self) -> Result<String> {
+        let text = match self.next() {
+            Some((Token::String(mut s), _)) => {
+                while let Some((Token::String(next), _)) = self.peek() {
+                    s.push_str(next);
+                    self.pos += 1;
+                }
+                s
+            }
+            Some((Token::Integer(n), _)) => u32::try_from(n)
+                .ok()
+                .and_then(char::from_u32)
+                .map(String::from)
+                .unwrap_or_default(),
+            Some((token, at)) => {
+                return Err(error(
+                    at,
+                    format!("expected a string or a number in a binary, found {}", token),
+                ));
+            }
+            None => return Err(error(self.end, "expected a string or a number in a binary")),
+        };
+        if self.peek_is(":") {
+            self.pos += 1;
+            self.term()?;
+        }
+        if self.peek_is("/") {
+            self.pos += 1;
+            loop {
+                match self.next() {
+                    Some((Token::Atom(_), _)) => {}
+                    _ => return Err(error(self.end, "expected a type after '/' in a binary")),
+                }
+                if !self.peek_is("-") {
+                    break;
+                }
+                self.pos += 1;
+            }
+        }
+        Ok(text)
+    }
+
+    fn not_closed(
+        &self,
+        open: &str,
+        opened_at: Position,
+        close: &str,
+        found: Option<(Token, Position)>,
+    ) -> Error {
+        match found {
+            Some((Token::Punct(p @ ("}" | "]" | ">>")), at)) => error(
+                at,
+                format!(
+                    "'{}' does not match '{}' at {}, expected '{}'",
+                    p, open, opened_at, close
+                ),
+            ),
+            Some((Token::Punct("."), at)) => error(
+                at,
+                format!("'{}' at {} is not closed before '.'", open, opened_at),
+            ),
+            Some((token, at)) => error(
+                at,
+                format!(
+                    "expected ',' or '{}' (to close '{}' at {}), found {}",
+                    close, open, opened_at, token
+                ),
+            ),
+            None => error(
+                self.end,
+                format!("'{}' at {} is not closed", open, opened_at),
+            ),
+        }
+    }
+}
@@ -24,6 +24,13 @@ pub enum Error {
     #[error("parse error at line {line}: {message}")]
     ParseError { line: usize, message: String },
 
+    #[error("syntax error at line {line}, column {column}: {message}")]
+    TermSyntaxError {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
     #[error("I/O error: {0}")]
     Io(#[from] io::Error),
 }
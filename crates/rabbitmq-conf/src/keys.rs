@@ -449,6 +449,11 @@ pub fn is_valid_key_format(key: &str) -> bool {
     true
 }
 
+/// All known key patterns, grouped by area, with `*` for wildcard segments
+pub fn known_key_patterns() -> &'static [&'static str] {
+    KNOWN_KEY_PATTERNS
+}
+
 /// Check if a key matches any known cuttlefish schema pattern
 pub fn is_known_key(key: &str) -> bool {
     KNOWN_KEY_PATTERNS
@@ -493,3 +498,71 @@ pub fn suggest_similar_keys(key: &str) -> Vec<&'static str> {
         .copied()
         .collect()
 }
+
+/// Conventional names for wildcard segments, used when completing keys
+static WILDCARD_EXAMPLES: &[(&str, &str)] = &[
+    ("listeners.tcp", "default"),
+    ("listeners.ssl", "default"),
+    ("loopback_users", "guest"),
+    ("auth_backends", "1"),
+    ("auth_mechanisms", "1"),
+    ("cluster_formation.classic_config.nodes", "1"),
+    ("ssl_options.versions", "1"),
+];
+
+/// The conventional name of the wildcard segment after `parent`, e.g. `default` for `listeners.tcp`
+pub fn wildcard_example(parent: &str) -> Option<&'static str> {
+    WILDCARD_EXAMPLES
+        .iter()
+        .find(|(p, _)| *p == parent)
+        .map(|(_, example)| *example)
+}
+
+/// Known keys that start with `prefix`, sorted.
+///
+/// Wildcard segments take the value typed for them, e.g. `default_users.alice.` completes
+/// to `default_users.alice.password` and others. A wildcard segment that is not typed yet
+/// takes a conventional name, e.g. `listeners.tcp.default`, or when there is none,
+/// the key is cut short before it, e.g. `default_users.`, to be completed further.
+pub fn complete_key(prefix: &str) -> Vec<String> {
+    let typed: Vec<&str> = prefix.split('.').collect();
+    let (partial, complete) = typed.split_last().unwrap_or((&"", &[]));
+
+    let mut candidates: Vec<String> = KNOWN_KEY_PATTERNS
+        .iter()
+        .filter_map(|pattern| complete_pattern(pattern, complete, partial))
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+fn complete_pattern(pattern: &str, complete: &[&str], partial: &str) -> Option<String> {
+    let segments: Vec<&str> = pattern.split('.').collect();
+    if segments.len() <= complete.len() {
+        return None;
+    }
+
+    let mut key: Vec<&str> = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.iter().enumerate() {
+        let value = match (complete.get(i), *segment) {
+            (Some(typed), "*") if !typed.is_empty() => *typed,
+            (Some(typed), literal) if *typed == literal => literal,
+            (Some(_), _) => return None,
+            (None, "*") if i == complete.len() && !partial.is_empty() => partial,
+            (None, "*") => {
+                let parent = segments[..i].join(".");
+                match wildcard_example(&parent) {
+                    Some(example) => example,
+                    None => return Some(format!("{}.", parent)).filter(|_| i > complete.len()),
+                }
+            }
+            (None, literal) if i == complete.len() && !literal.starts_with(partial) => {
+                return None;
+            }
+            (None, literal) => literal,
+        };
+        key.push(value);
+    }
+    Some(key.join("."))
+}
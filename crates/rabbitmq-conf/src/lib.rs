@@ -12,6 +12,7 @@
 //! configuration files in the cuttlefish format.
 
 pub mod conf;
+pub mod erlang_terms;
 pub mod errors;
 pub mod keys;
 
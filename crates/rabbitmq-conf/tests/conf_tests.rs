@@ -356,11 +356,57 @@ fn get_matching_exact_match() {
     assert_eq!(matches[0], ("heartbeat", "60"));
 }
 
+#[test]
+fn get_matching_deep_wildcard() {
+    let content = "log.file = rabbit.log\nlog.file.level = info\nlog.console.formatter.json.field_map = x\nloopback_users.guest = false\nlog = unused\n";
+    let conf = RabbitMQConf::parse(content).unwrap();
+    let keys: Vec<&str> = conf
+        .get_matching("log.**")
+        .iter()
+        .map(|(k, _)| *k)
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            "log.console.formatter.json.field_map",
+            "log.file",
+            "log.file.level"
+        ]
+    );
+}
+
+#[test]
+fn get_matching_deep_wildcard_in_the_middle() {
+    let content = "log.console.level = warning\nlog.file.level = info\nlog.console.formatter.level = x\nlog.level = debug\n";
+    let conf = RabbitMQConf::parse(content).unwrap();
+    let keys: Vec<&str> = conf
+        .get_matching("log.**.level")
+        .iter()
+        .map(|(k, _)| *k)
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            "log.console.formatter.level",
+            "log.console.level",
+            "log.file.level"
+        ]
+    );
+}
+
+#[test]
+fn entries_are_ordered_by_key() {
+    let conf = RabbitMQConf::parse("b = 2\n# comment\na = 1\n").unwrap();
+    let entries: Vec<(&str, &str)> = conf.entries().collect();
+    assert_eq!(entries, vec![("a", "1"), ("b", "2")]);
+}
+
 #[test]
 fn is_pattern_with_wildcard() {
     assert!(RabbitMQConf::is_pattern("listeners.tcp.*"));
     assert!(RabbitMQConf::is_pattern("*.level"));
     assert!(RabbitMQConf::is_pattern("log.*.level"));
+    assert!(RabbitMQConf::is_pattern("log.**"));
 }
 
 #[test]
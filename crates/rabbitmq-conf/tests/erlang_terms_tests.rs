@@ -0,0 +1,163 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rabbitmq_conf::Error;
+use rabbitmq_conf::erlang_terms::{Term, parse, validate};
+
+fn error_at(content: &str) -> (usize, usize, String) {
+    match validate(content) {
+        Err(Error::TermSyntaxError {
+            line,
+            column,
+            message,
+        }) => (line, column, message),
+        other => panic!("expected a syntax error, got {:?}", other),
+    }
+}
+
+#[test]
+fn validate_accepts_typical_files() {
+    let advanced = r#"
+%% a comment with unbalanced ] and { in it
+[
+  {rabbit, [
+    {tcp_listeners, [{"127.0.0.1", 5672}, {"::1", 5672}]},
+    {default_user, <<"guest">>},
+    {vm_memory_high_watermark, 0.4},
+    {disk_free_limit, {mem_relative, 1.5e0}},
+    {channel_max, 16#ff},
+    {delimiter, $,},
+    {log_levels, [{connection, 'Info'}, {'Weird Atom', -1}]},
+    {map, #{key => [a | b], <<1:8, "x"/utf8>> => node@host}}
+  ]},
+  {rabbitmq_management, []}
+].
+"#;
+    validate(advanced).unwrap();
+    validate("[rabbitmq_management,rabbitmq_shovel].").unwrap();
+    validate("[].\n").unwrap();
+}
+
+#[test]
+fn validate_accepts_empty_files() {
+    validate("").unwrap();
+    validate("  \n%% nothing here\n").unwrap();
+}
+
+#[test]
+fn validate_requires_the_final_dot() {
+    let (line, _, message) = error_at("[\n  {rabbit, []}\n]\n");
+    assert_eq!(line, 3);
+    assert!(message.contains("missing '.'"), "{}", message);
+
+    let (_, _, message) = error_at("[a]. [b].");
+    assert!(message.contains("only one list"), "{}", message);
+}
+
+#[test]
+fn validate_reports_mismatched_brackets() {
+    let (line, column, message) = error_at("[\n  {rabbit, [{a, 1}}\n].");
+    assert_eq!((line, column), (2, 19));
+    assert!(
+        message.contains("'}' does not match '[' at line 2, column 12"),
+        "{}",
+        message
+    );
+
+    let (_, _, message) = error_at("[{rabbit, []].");
+    assert!(message.contains("does not match '{'"), "{}", message);
+
+    let (_, _, message) = error_at("[{rabbit, []}.");
+    assert!(message.contains("is not closed before '.'"), "{}", message);
+
+    let (_, _, message) = error_at("[{rabbit, [");
+    assert!(message.contains("is not closed"), "{}", message);
+}
+
+#[test]
+fn validate_reports_missing_commas() {
+    let (line, column, message) = error_at("[{rabbit, []} {ra, []}].");
+    assert_eq!((line, column), (1, 15));
+    assert!(message.contains("expected ','"), "{}", message);
+}
+
+#[test]
+fn validate_rejects_variables() {
+    let (_, column, message) = error_at("[{rabbit, [{log_level, Info}]}].");
+    assert_eq!(column, 24);
+    assert!(message.contains("'Info'"), "{}", message);
+    assert!(message.contains("variable"), "{}", message);
+
+    let (_, _, message) = error_at("[_ignored].");
+    assert!(message.contains("'_ignored'"), "{}", message);
+}
+
+#[test]
+fn validate_reports_unterminated_strings_and_atoms() {
+    let (line, column, message) = error_at("[{rabbit, [{default_user, \"guest}]}].");
+    assert_eq!((line, column), (1, 27));
+    assert!(message.contains("string"), "{}", message);
+
+    let (_, _, message) = error_at("[{'rabbit, []}].");
+    assert!(message.contains("quoted atom"), "{}", message);
+}
+
+#[test]
+fn validate_requires_a_list() {
+    let (_, _, message) = error_at("{rabbit, []}.");
+    assert!(message.contains("expected a list"), "{}", message);
+}
+
+#[test]
+fn validate_rejects_unexpected_characters() {
+    let (_, column, message) = error_at("[{rabbit, [{a, 1 + 2}]}].");
+    assert_eq!(column, 18);
+    assert!(message.contains("'+'"), "{}", message);
+}
+
+#[test]
+fn parse_builds_terms() {
+    let term = parse(
+        r#"[{rabbit, [{default_user, <<"gu" "est">>}, {heartbeat, 16#1e}, {ratio, -0.5},
+          {name, "a\"b\n"}, {sep, $\t}, {tags, [a | [b]]}, {odd, [a | b]}]}]."#,
+    )
+    .unwrap();
+    let rabbit = match &term {
+        Term::List(apps) => apps[0].as_pair().unwrap(),
+        other => panic!("expected a list, got {:?}", other),
+    };
+    assert_eq!(rabbit.0, "rabbit");
+    let settings = rabbit.1.as_list().unwrap();
+    assert_eq!(settings[0].as_pair().unwrap().1.as_text(), Some("guest"));
+    assert_eq!(settings[1].as_pair().unwrap().1.as_integer(), Some(30));
+    assert_eq!(settings[2].as_pair().unwrap().1, &Term::Float(-0.5));
+    assert_eq!(settings[3].as_pair().unwrap().1.as_text(), Some("a\"b\n"));
+    assert_eq!(settings[4].as_pair().unwrap().1.as_integer(), Some(9));
+    assert_eq!(
+        settings[5].as_pair().unwrap().1,
+        &Term::List(vec![Term::Atom("a".into()), Term::Atom("b".into())])
+    );
+    assert!(matches!(
+        settings[6].as_pair().unwrap().1,
+        Term::ImproperList(..)
+    ));
+
+    assert_eq!(parse("%% empty\n").unwrap(), Term::List(vec![]));
+}
+
+#[test]
+fn display_formats_terms_in_erlang_syntax() {
+    let content = r#"[{rabbit, [{'Weird atom', "it's \"quoted\""}, {log, <<"x">>}, {n, 1.0e20}, {m, #{a => [1 | b]}}]}]"#;
+    let term = parse(&format!("{}.", content)).unwrap();
+    let formatted = term.to_string();
+    assert_eq!(
+        formatted,
+        r#"[{rabbit, [{'Weird atom', "it's \"quoted\""}, {log, <<"x">>}, {n, 1.0e20}, {m, #{a => [1 | b]}}]}]"#
+    );
+    assert_eq!(parse(&format!("{}.", formatted)).unwrap(), term);
+}
@@ -113,3 +113,55 @@ fn suggest_similar_keys_unknown() {
     let suggestions = keys::suggest_similar_keys("zzz_unknown");
     assert!(suggestions.is_empty());
 }
+
+#[test]
+fn complete_key_by_prefix() {
+    assert_eq!(
+        keys::complete_key("lis"),
+        [
+            "listeners.ssl",
+            "listeners.ssl.default",
+            "listeners.tcp",
+            "listeners.tcp.default",
+        ]
+    );
+    assert_eq!(keys::complete_key("listeners.tcp.d"), ["listeners.tcp.d"]);
+}
+
+#[test]
+fn complete_key_substitutes_typed_wildcard_segments() {
+    let keys = keys::complete_key("default_users.alice.");
+    assert!(keys.contains(&"default_users.alice.password".to_string()));
+    assert!(keys.contains(&"default_users.alice.tags".to_string()));
+    assert!(keys.iter().all(|k| k.starts_with("default_users.alice.")));
+}
+
+#[test]
+fn complete_key_stops_before_wildcards_without_a_conventional_name() {
+    let keys = keys::complete_key("default_u");
+    assert!(keys.contains(&"default_users.".to_string()));
+    assert!(keys.contains(&"default_user_tags.".to_string()));
+    assert!(keys.contains(&"default_user".to_string()));
+    assert!(keys::complete_key("default_users.").is_empty());
+}
+
+#[test]
+fn complete_key_unknown_prefix() {
+    assert!(keys::complete_key("no_such_key").is_empty());
+}
+
+#[test]
+fn complete_key_empty_prefix_lists_top_level_keys() {
+    let keys = keys::complete_key("");
+    assert!(keys.contains(&"heartbeat".to_string()));
+    assert!(keys.contains(&"listeners.tcp.default".to_string()));
+}
+
+#[test]
+fn known_key_patterns_are_valid_and_known() {
+    let patterns = keys::known_key_patterns();
+    assert!(patterns.contains(&"listeners.tcp.*"));
+    assert!(patterns.iter().all(|p| keys::is_known_key(p)));
+    assert_eq!(keys::wildcard_example("listeners.tcp"), Some("default"));
+    assert_eq!(keys::wildcard_example("default_users"), None);
+}
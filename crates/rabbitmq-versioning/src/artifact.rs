@@ -0,0 +1,145 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Release artifacts: their download URLs and file names.
+//!
+//! ```
+//! use rabbitmq_versioning::{Artifact, Version};
+//!
+//! let v: Version = "4.2.3".parse().unwrap();
+//! let archive = Artifact::generic_unix(&v);
+//! assert_eq!(archive.file_name(), "rabbitmq-server-generic-unix-4.2.3.tar.xz");
+//!
+//! let signature = archive.detached_signature();
+//! assert_eq!(signature.file_name(), "rabbitmq-server-generic-unix-4.2.3.tar.xz.asc");
+//! ```
+
+use std::fmt;
+
+use crate::version::Version;
+
+const RABBITMQ_SERVER_RELEASES_URL: &str =
+    "https://github.com/rabbitmq/rabbitmq-server/releases/download";
+const SERVER_PACKAGES_RELEASES_URL: &str =
+    "https://github.com/rabbitmq/server-packages/releases/download";
+
+const CHECKSUMS_FILE_NAME: &str = "SHA256SUMS";
+const SIGNATURE_EXTENSION: &str = "asc";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArtifactKind {
+    /// The generic binary build for UNIX-like systems, `.tar.xz`
+    GenericUnix,
+    /// The Windows installer-free build, `.zip`
+    WindowsZip,
+    /// A detached PGP signature of another artifact, `.asc`
+    Signature,
+    /// The SHA-256 checksums of all artifacts of a release
+    Checksums,
+}
+
+/// A downloadable file of a RabbitMQ release
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Artifact {
+    kind: ArtifactKind,
+    url: String,
+    file_name: String,
+}
+
+impl Artifact {
+    /// The generic UNIX archive, e.g. `rabbitmq-server-generic-unix-4.2.3.tar.xz`
+    pub fn generic_unix(version: &Version) -> Self {
+        Self::in_rabbitmq_server_release(
+            ArtifactKind::GenericUnix,
+            version,
+            format!(
+                "rabbitmq-server-generic-unix-{}.tar.xz",
+                version.without_build()
+            ),
+        )
+    }
+
+    /// The Windows archive, e.g. `rabbitmq-server-windows-4.2.3.zip`
+    pub fn windows_zip(version: &Version) -> Self {
+        Self::in_rabbitmq_server_release(
+            ArtifactKind::WindowsZip,
+            version,
+            format!("rabbitmq-server-windows-{}.zip", version.without_build()),
+        )
+    }
+
+    /// The signature of the generic UNIX archive
+    pub fn signature(version: &Version) -> Self {
+        Self::generic_unix(version).detached_signature()
+    }
+
+    /// The checksums file of a release
+    pub fn checksums(version: &Version) -> Self {
+        Self::in_rabbitmq_server_release(
+            ArtifactKind::Checksums,
+            version,
+            CHECKSUMS_FILE_NAME.to_string(),
+        )
+    }
+
+    fn in_rabbitmq_server_release(
+        kind: ArtifactKind,
+        version: &Version,
+        file_name: String,
+    ) -> Self {
+        let url = format!(
+            "{}/{}/{}",
+            RABBITMQ_SERVER_RELEASES_URL,
+            version.release_tag(),
+            file_name
+        );
+        Artifact {
+            kind,
+            url,
+            file_name,
+        }
+    }
+
+    /// The same artifact, published as a `rabbitmq/server-packages` release
+    /// with the given tag. This is where alpha builds are distributed.
+    pub fn in_server_packages_release(self, tag: &str) -> Self {
+        let url = format!(
+            "{}/{}/{}",
+            SERVER_PACKAGES_RELEASES_URL, tag, self.file_name
+        );
+        Artifact { url, ..self }
+    }
+
+    /// The detached signature of this artifact, published next to it
+    pub fn detached_signature(&self) -> Self {
+        Artifact {
+            kind: ArtifactKind::Signature,
+            url: format!("{}.{}", self.url, SIGNATURE_EXTENSION),
+            file_name: format!("{}.{}", self.file_name, SIGNATURE_EXTENSION),
+        }
+    }
+
+    pub fn kind(&self) -> ArtifactKind {
+        self.kind
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The file name the artifact is expected to be saved as
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+}
+
+impl fmt::Display for Artifact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url)
+    }
+}
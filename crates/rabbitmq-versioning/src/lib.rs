@@ -29,12 +29,18 @@
 //! assert!(v < alpha.base_version());
 //! ```
 
+pub mod artifact;
 pub mod errors;
 pub mod prerelease;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod upgrade;
 pub mod version;
 
+pub use artifact::{Artifact, ArtifactKind};
 pub use errors::Error;
 pub use prerelease::Prerelease;
+pub use upgrade::{Series, is_direct_upgrade_supported, upgrade_path};
 pub use version::Version;
 
 pub type Result<T> = std::result::Result<T, Error>;
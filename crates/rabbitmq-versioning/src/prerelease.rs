@@ -8,12 +8,11 @@
 
 use std::cmp::Ordering;
 use std::fmt;
-
-use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 use crate::errors::Error;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Prerelease {
     Alpha(String),
     Beta(String),
@@ -71,6 +70,14 @@ impl Prerelease {
     }
 }
 
+impl FromStr for Prerelease {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Prerelease::parse(s, s)
+    }
+}
+
 impl fmt::Display for Prerelease {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -0,0 +1,213 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! `serde` support, available with the `serde` feature.
+//!
+//! [`Version`] and [`Prerelease`] serialize as their string forms (`"4.2.3"`, `"4.3.0-alpha.1"`,
+//! `"alpha.1"`), which is what most formats and JSON schemas expect.
+//!
+//! Deserialization accepts the string form, the [`structured`] form,
+//! and the struct form used by earlier versions of this crate.
+//!
+//! Fields that should be serialized as objects with one property per component
+//! can use [`structured`]:
+//!
+//! ```
+//! use rabbitmq_versioning::Version;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Node {
+//!     #[serde(with = "rabbitmq_versioning::serialization::structured")]
+//!     version: Version,
+//! }
+//!
+//! let node = Node { version: "4.3.0-alpha.1".parse().unwrap() };
+//! let json = serde_json::to_string(&node).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"version":{"major":4,"minor":3,"patch":0,"prerelease":{"kind":"alpha","identifier":"1"}}}"#
+//! );
+//! ```
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::prerelease::Prerelease;
+use crate::version::{Version, parse_build};
+
+/// A JSON Schema for the string form of [`Version`]
+pub const VERSION_JSON_SCHEMA: &str = r#"{"type":"string","pattern":"^[0-9]+\\.[0-9]+\\.[0-9]+(-(alpha|beta|rc)\\.[0-9A-Za-z]+)?(\\+[0-9A-Za-z-]+(\\.[0-9A-Za-z-]+)*)?$"}"#;
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match VersionRepr::deserialize(deserializer)? {
+            VersionRepr::Text(s) => s.parse().map_err(de::Error::custom),
+            VersionRepr::Structured(v) => v.try_into().map_err(de::Error::custom),
+        }
+    }
+}
+
+impl Serialize for Prerelease {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Prerelease {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PrereleaseRepr::deserialize(deserializer)?
+            .try_into()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// (De)serializes a [`Version`] as an object with `major`, `minor`, `patch`,
+/// an optional `prerelease` with `kind` and `identifier`, and an optional `build`.
+///
+/// Meant to be used with `#[serde(with = "rabbitmq_versioning::serialization::structured")]`.
+pub mod structured {
+    use serde::de::Deserializer;
+    use serde::ser::Serializer;
+    use serde::{Deserialize, Serialize};
+
+    use super::StructuredVersion;
+    use crate::version::Version;
+
+    /// A JSON Schema for the structured form of [`Version`]
+    pub const JSON_SCHEMA: &str = r#"{"type":"object","required":["major","minor","patch"],"properties":{"major":{"type":"integer","minimum":0},"minor":{"type":"integer","minimum":0},"patch":{"type":"integer","minimum":0},"prerelease":{"type":"object","required":["kind","identifier"],"properties":{"kind":{"enum":["alpha","beta","rc"]},"identifier":{"type":"string"}}},"build":{"type":"string"}}}"#;
+
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        StructuredVersion::from(version).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        Version::deserialize(deserializer)
+    }
+}
+
+#[derive(Serialize)]
+struct StructuredVersion<'a> {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<StructuredPrerelease<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct StructuredPrerelease<'a> {
+    kind: PrereleaseKind,
+    identifier: &'a str,
+}
+
+impl<'a> From<&'a Version> for StructuredVersion<'a> {
+    fn from(v: &'a Version) -> Self {
+        StructuredVersion {
+            major: v.major,
+            minor: v.minor,
+            patch: v.patch,
+            prerelease: v.prerelease.as_ref().map(|p| StructuredPrerelease {
+                kind: match p {
+                    Prerelease::Alpha(_) => PrereleaseKind::Alpha,
+                    Prerelease::Beta(_) => PrereleaseKind::Beta,
+                    Prerelease::Rc(_) => PrereleaseKind::Rc,
+                },
+                identifier: p.identifier(),
+            }),
+            build: v.build.as_deref(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PrereleaseKind {
+    Alpha,
+    Beta,
+    Rc,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum VersionRepr {
+    Text(String),
+    Structured(VersionFields),
+}
+
+#[derive(Deserialize)]
+struct VersionFields {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    #[serde(default)]
+    prerelease: Option<PrereleaseRepr>,
+    #[serde(default)]
+    build: Option<String>,
+}
+
+impl TryFrom<VersionFields> for Version {
+    type Error = crate::Error;
+
+    fn try_from(fields: VersionFields) -> Result<Self, Self::Error> {
+        let build = fields.build.map(|b| parse_build(&b, &b)).transpose()?;
+        Ok(Version {
+            major: fields.major,
+            minor: fields.minor,
+            patch: fields.patch,
+            prerelease: fields.prerelease.map(Prerelease::try_from).transpose()?,
+            build,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PrereleaseRepr {
+    Text(String),
+    Structured {
+        kind: PrereleaseKind,
+        identifier: String,
+    },
+    /// The externally tagged enum form, e.g. `{"Alpha": "1"}`, used before 0.21
+    Legacy(LegacyPrerelease),
+}
+
+#[derive(Deserialize)]
+enum LegacyPrerelease {
+    Alpha(String),
+    Beta(String),
+    Rc(String),
+}
+
+impl TryFrom<PrereleaseRepr> for Prerelease {
+    type Error = crate::Error;
+
+    fn try_from(repr: PrereleaseRepr) -> Result<Self, Self::Error> {
+        match repr {
+            PrereleaseRepr::Text(s) => s.parse(),
+            PrereleaseRepr::Structured { kind, identifier } => Ok(match kind {
+                PrereleaseKind::Alpha => Prerelease::Alpha(identifier),
+                PrereleaseKind::Beta => Prerelease::Beta(identifier),
+                PrereleaseKind::Rc => Prerelease::Rc(identifier),
+            }),
+            PrereleaseRepr::Legacy(LegacyPrerelease::Alpha(s)) => Ok(Prerelease::Alpha(s)),
+            PrereleaseRepr::Legacy(LegacyPrerelease::Beta(s)) => Ok(Prerelease::Beta(s)),
+            PrereleaseRepr::Legacy(LegacyPrerelease::Rc(s)) => Ok(Prerelease::Rc(s)),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Supported upgrade paths between release series,
+//! see https://www.rabbitmq.com/docs/upgrade
+//!
+//! ```
+//! use rabbitmq_versioning::{Series, Version, upgrade_path};
+//!
+//! let from: Version = "3.12.14".parse().unwrap();
+//! let to: Version = "4.1.0".parse().unwrap();
+//! let path = upgrade_path(&from, &to).unwrap();
+//! assert_eq!(path, vec![Series::new(3, 13), Series::new(4, 0), Series::new(4, 1)]);
+//! ```
+
+use std::fmt;
+
+use crate::version::Version;
+
+/// A release series, e.g. `4.2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Series {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl Series {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Series { major, minor }
+    }
+}
+
+impl fmt::Display for Series {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl From<&Version> for Series {
+    fn from(v: &Version) -> Self {
+        Series::new(v.major, v.minor)
+    }
+}
+
+/// Release series in order. A node can be upgraded from one series
+/// to the next one; skipping a series is not supported.
+const UPGRADE_SEQUENCE: &[Series] = &[
+    Series::new(3, 6),
+    Series::new(3, 7),
+    Series::new(3, 8),
+    Series::new(3, 9),
+    Series::new(3, 10),
+    Series::new(3, 11),
+    Series::new(3, 12),
+    Series::new(3, 13),
+    Series::new(4, 0),
+    Series::new(4, 1),
+    Series::new(4, 2),
+    Series::new(4, 3),
+];
+
+/// The series to upgrade through, in order, to get from one version to another.
+/// The last element is the series of `to`; the series of `from` is not included.
+///
+/// Returns an empty path for upgrades within a series, and `None`
+/// for downgrades and series this crate does not know about.
+pub fn upgrade_path(from: &Version, to: &Version) -> Option<Vec<Series>> {
+    let (from_series, to_series) = (Series::from(from), Series::from(to));
+    if from_series == to_series {
+        return (from.cmp_precedence(to).is_le()).then(Vec::new);
+    }
+
+    let start = UPGRADE_SEQUENCE.iter().position(|s| *s == from_series)?;
+    let end = UPGRADE_SEQUENCE.iter().position(|s| *s == to_series)?;
+    if end < start {
+        return None;
+    }
+
+    Some(UPGRADE_SEQUENCE[start + 1..=end].to_vec())
+}
+
+/// Whether a node can be upgraded from one version to another in a single step
+pub fn is_direct_upgrade_supported(from: &Version, to: &Version) -> bool {
+    upgrade_path(from, to).is_some_and(|path| path.len() <= 1)
+}
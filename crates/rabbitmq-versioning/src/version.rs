@@ -10,20 +10,25 @@ use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
-
+use crate::artifact::Artifact;
 use crate::errors::Error;
 use crate::prerelease::Prerelease;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Version {
     pub major: u32,
     pub minor: u32,
     pub patch: u32,
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub prerelease: Option<Prerelease>,
+    /// Build metadata, e.g. `some.build` in `4.2.1+some.build`.
+    /// Ignored by [`Version::cmp_precedence`] and when generating artifact URLs.
+    pub build: Option<String>,
 }
 
+/// RabbitMQ versions before this one were tagged as `rabbitmq_v3_6_16`
+const FIRST_V_PREFIXED_TAG: (u32, u32, u32) = (3, 7, 0);
+const LEGACY_TAG_PREFIX: &str = "rabbitmq_v";
+
 impl Version {
     pub fn new(major: u32, minor: u32, patch: u32) -> Self {
         Self {
@@ -31,6 +36,7 @@ impl Version {
             minor,
             patch,
             prerelease: None,
+            build: None,
         }
     }
 
@@ -40,9 +46,15 @@ impl Version {
             minor,
             patch,
             prerelease: Some(prerelease),
+            build: None,
         }
     }
 
+    pub fn with_build(mut self, build: impl Into<String>) -> Self {
+        self.build = Some(build.into());
+        self
+    }
+
     pub fn dir_name(&self) -> String {
         self.to_string()
     }
@@ -71,32 +83,76 @@ impl Version {
         self.is_alpha()
     }
 
+    /// The Git tag of this version in the `rabbitmq-server` repository
+    pub fn release_tag(&self) -> String {
+        if self.prerelease.is_none() && (self.major, self.minor, self.patch) < FIRST_V_PREFIXED_TAG
+        {
+            format!(
+                "{}{}_{}_{}",
+                LEGACY_TAG_PREFIX, self.major, self.minor, self.patch
+            )
+        } else {
+            format!("v{}", self.without_build())
+        }
+    }
+
     pub fn download_url(&self) -> String {
-        format!(
-            "https://github.com/rabbitmq/rabbitmq-server/releases/download/v{v}/rabbitmq-server-generic-unix-{v}.tar.xz",
-            v = self
-        )
+        Artifact::generic_unix(self).url().to_string()
     }
 
     pub fn download_url_with_tag(&self, tag: &str) -> String {
-        format!(
-            "https://github.com/rabbitmq/server-packages/releases/download/{tag}/rabbitmq-server-generic-unix-{v}.tar.xz",
-            tag = tag,
-            v = self
-        )
+        Artifact::generic_unix(self)
+            .in_server_packages_release(tag)
+            .url()
+            .to_string()
     }
 
     pub fn archive_name(&self) -> String {
-        format!("rabbitmq-server-generic-unix-{}.tar.xz", self)
+        Artifact::generic_unix(self).file_name().to_string()
     }
 
     pub fn extracted_dir_name(&self) -> String {
-        format!("rabbitmq_server-{}", self)
+        format!("rabbitmq_server-{}", self.without_build())
     }
 
     pub fn base_version(&self) -> Version {
         Version::new(self.major, self.minor, self.patch)
     }
+
+    pub fn without_build(&self) -> Version {
+        Version {
+            build: None,
+            ..self.clone()
+        }
+    }
+
+    /// Compares versions the way semantic versioning defines precedence,
+    /// that is, ignoring build metadata. `4.2.1+a` and `4.2.1+b` have the same precedence.
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        let base =
+            (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch));
+        if base != Ordering::Equal {
+            return base;
+        }
+
+        match (&self.prerelease, &other.prerelease) {
+            (None, None) => Ordering::Equal,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(a), Some(b)) => a.cmp(b),
+        }
+    }
+}
+
+pub(crate) fn parse_build(build: &str, full_version: &str) -> Result<String, Error> {
+    let valid = build
+        .split('.')
+        .all(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'));
+    if valid {
+        Ok(build.to_string())
+    } else {
+        Err(Error::InvalidVersion(full_version.to_string()))
+    }
 }
 
 impl fmt::Display for Version {
@@ -105,6 +161,9 @@ impl fmt::Display for Version {
         if let Some(ref pre) = self.prerelease {
             write!(f, "-{}", pre)?;
         }
+        if let Some(ref build) = self.build {
+            write!(f, "+{}", build)?;
+        }
         Ok(())
     }
 }
@@ -113,14 +172,24 @@ impl FromStr for Version {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim().trim_start_matches('v');
+        let s = s.trim();
+        // RabbitMQ 3.6.x and older: rabbitmq_v3_6_16
+        if let Some(legacy) = s.strip_prefix(LEGACY_TAG_PREFIX) {
+            return legacy.replace('_', ".").parse();
+        }
+        let s = s.trim_start_matches('v');
 
-        let (version_part, prerelease) = if let Some(idx) = s.find('-') {
-            let (ver, pre) = s.split_at(idx);
+        let (s_without_build, build) = match s.split_once('+') {
+            Some((rest, build)) => (rest, Some(parse_build(build, s)?)),
+            None => (s, None),
+        };
+
+        let (version_part, prerelease) = if let Some(idx) = s_without_build.find('-') {
+            let (ver, pre) = s_without_build.split_at(idx);
             let pre = &pre[1..];
             (ver, Some(Prerelease::parse(pre, s)?))
         } else {
-            (s, None)
+            (s_without_build, None)
         };
 
         let parts: Vec<&str> = version_part.split('.').collect();
@@ -144,24 +213,18 @@ impl FromStr for Version {
             minor,
             patch,
             prerelease,
+            build,
         })
     }
 }
 
+/// Orders by [`Version::cmp_precedence`]. Versions with the same precedence
+/// are then ordered by build metadata, so that the ordering is consistent with `Eq`:
+/// a version without build metadata comes first.
 impl Ord for Version {
     fn cmp(&self, other: &Self) -> Ordering {
-        let base =
-            (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch));
-        if base != Ordering::Equal {
-            return base;
-        }
-
-        match (&self.prerelease, &other.prerelease) {
-            (None, None) => Ordering::Equal,
-            (Some(_), None) => Ordering::Less,
-            (None, Some(_)) => Ordering::Greater,
-            (Some(a), Some(b)) => a.cmp(b),
-        }
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
     }
 }
 
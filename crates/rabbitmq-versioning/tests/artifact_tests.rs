@@ -0,0 +1,106 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rabbitmq_versioning::{Artifact, ArtifactKind, Version};
+
+fn v(s: &str) -> Version {
+    s.parse().unwrap()
+}
+
+#[test]
+fn generic_unix_artifact() {
+    let a = Artifact::generic_unix(&v("4.2.3"));
+    assert_eq!(a.kind(), ArtifactKind::GenericUnix);
+    assert_eq!(a.file_name(), "rabbitmq-server-generic-unix-4.2.3.tar.xz");
+    assert_eq!(
+        a.url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/v4.2.3/rabbitmq-server-generic-unix-4.2.3.tar.xz"
+    );
+    assert_eq!(a.to_string(), a.url());
+}
+
+#[test]
+fn windows_zip_artifact() {
+    let a = Artifact::windows_zip(&v("4.2.3"));
+    assert_eq!(a.kind(), ArtifactKind::WindowsZip);
+    assert_eq!(a.file_name(), "rabbitmq-server-windows-4.2.3.zip");
+    assert_eq!(
+        a.url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/v4.2.3/rabbitmq-server-windows-4.2.3.zip"
+    );
+}
+
+#[test]
+fn signature_artifact() {
+    let a = Artifact::signature(&v("4.2.3"));
+    assert_eq!(a.kind(), ArtifactKind::Signature);
+    assert_eq!(
+        a.file_name(),
+        "rabbitmq-server-generic-unix-4.2.3.tar.xz.asc"
+    );
+    assert_eq!(
+        a.url(),
+        format!("{}.asc", Artifact::generic_unix(&v("4.2.3")).url())
+    );
+
+    let zip_signature = Artifact::windows_zip(&v("4.2.3")).detached_signature();
+    assert_eq!(
+        zip_signature.file_name(),
+        "rabbitmq-server-windows-4.2.3.zip.asc"
+    );
+}
+
+#[test]
+fn checksums_artifact() {
+    let a = Artifact::checksums(&v("4.1.0"));
+    assert_eq!(a.kind(), ArtifactKind::Checksums);
+    assert_eq!(a.file_name(), "SHA256SUMS");
+    assert_eq!(
+        a.url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/v4.1.0/SHA256SUMS"
+    );
+}
+
+#[test]
+fn server_packages_artifact() {
+    let a = Artifact::generic_unix(&v("4.3.0-alpha.132057c7"))
+        .in_server_packages_release("alphas.1757000000000");
+    assert_eq!(
+        a.url(),
+        "https://github.com/rabbitmq/server-packages/releases/download/alphas.1757000000000/rabbitmq-server-generic-unix-4.3.0-alpha.132057c7.tar.xz"
+    );
+    assert_eq!(
+        a.file_name(),
+        "rabbitmq-server-generic-unix-4.3.0-alpha.132057c7.tar.xz"
+    );
+}
+
+#[test]
+fn artifacts_of_legacy_versions() {
+    let a = Artifact::generic_unix(&v("rabbitmq_v3_6_16"));
+    assert_eq!(
+        a.url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/rabbitmq_v3_6_16/rabbitmq-server-generic-unix-3.6.16.tar.xz"
+    );
+}
+
+#[test]
+fn artifacts_ignore_build_metadata() {
+    assert_eq!(
+        Artifact::windows_zip(&v("4.2.1+some.build")),
+        Artifact::windows_zip(&v("4.2.1"))
+    );
+}
+
+#[test]
+fn version_helpers_match_artifacts() {
+    let version = v("4.2.3");
+    let a = Artifact::generic_unix(&version);
+    assert_eq!(version.download_url(), a.url());
+    assert_eq!(version.archive_name(), a.file_name());
+}
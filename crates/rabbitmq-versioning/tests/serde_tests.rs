@@ -0,0 +1,154 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg(feature = "serde")]
+
+use rabbitmq_versioning::serialization::{self, structured};
+use rabbitmq_versioning::{Prerelease, Version};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Structured {
+    #[serde(with = "structured")]
+    version: Version,
+}
+
+#[test]
+fn version_serde_roundtrip_ga() {
+    let v = Version::new(4, 2, 3);
+    let json = serde_json::to_string(&v).unwrap();
+    let parsed: Version = serde_json::from_str(&json).unwrap();
+    assert_eq!(v, parsed);
+}
+
+#[test]
+fn version_serde_roundtrip_prerelease() {
+    let v = Version::with_prerelease(4, 2, 4, Prerelease::alpha("1"));
+    let json = serde_json::to_string(&v).unwrap();
+    let parsed: Version = serde_json::from_str(&json).unwrap();
+    assert_eq!(v, parsed);
+}
+
+#[test]
+fn version_serializes_as_string() {
+    assert_eq!(
+        serde_json::to_string(&Version::new(4, 2, 3)).unwrap(),
+        "\"4.2.3\""
+    );
+    assert_eq!(
+        serde_json::to_string(&Version::with_prerelease(4, 3, 0, Prerelease::rc("2"))).unwrap(),
+        "\"4.3.0-rc.2\""
+    );
+}
+
+#[test]
+fn version_deserialize_accepts_v_prefix() {
+    let v: Version = serde_json::from_str("\"v4.2.3\"").unwrap();
+    assert_eq!(v, Version::new(4, 2, 3));
+}
+
+#[test]
+fn version_deserialize_rejects_invalid_string() {
+    let err = serde_json::from_str::<Version>("\"4.2\"").unwrap_err();
+    assert!(err.to_string().contains("invalid version"));
+}
+
+#[test]
+fn version_deserialize_legacy_struct_form() {
+    let v: Version = serde_json::from_str(
+        r#"{"major":4,"minor":3,"patch":0,"prerelease":{"Alpha":"132057c7"}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        v,
+        Version::with_prerelease(4, 3, 0, Prerelease::alpha("132057c7"))
+    );
+
+    let v: Version = serde_json::from_str(r#"{"major":4,"minor":2,"patch":3}"#).unwrap();
+    assert_eq!(v, Version::new(4, 2, 3));
+}
+
+#[test]
+fn version_deserialize_legacy_toml() {
+    #[derive(Deserialize)]
+    struct Config {
+        default_version: Version,
+    }
+
+    let config: Config =
+        toml::from_str("[default_version]\nmajor = 4\nminor = 2\npatch = 3\n").unwrap();
+    assert_eq!(config.default_version, Version::new(4, 2, 3));
+
+    let config: Config = toml::from_str("default_version = \"4.2.3\"\n").unwrap();
+    assert_eq!(config.default_version, Version::new(4, 2, 3));
+}
+
+#[test]
+fn prerelease_serde_roundtrip() {
+    let alpha = Prerelease::alpha("1");
+    let json = serde_json::to_string(&alpha).unwrap();
+    assert_eq!(json, "\"alpha.1\"");
+    let parsed: Prerelease = serde_json::from_str(&json).unwrap();
+    assert_eq!(alpha, parsed);
+}
+
+#[test]
+fn prerelease_deserialize_rejects_unknown_kind() {
+    assert!(serde_json::from_str::<Prerelease>("\"gamma.1\"").is_err());
+}
+
+#[test]
+fn structured_form_ga() {
+    let s = Structured {
+        version: Version::new(4, 2, 3),
+    };
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, r#"{"version":{"major":4,"minor":2,"patch":3}}"#);
+    assert_eq!(serde_json::from_str::<Structured>(&json).unwrap(), s);
+}
+
+#[test]
+fn structured_form_prerelease() {
+    let s = Structured {
+        version: Version::with_prerelease(4, 3, 0, Prerelease::beta("5")),
+    };
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":{"major":4,"minor":3,"patch":0,"prerelease":{"kind":"beta","identifier":"5"}}}"#
+    );
+    assert_eq!(serde_json::from_str::<Structured>(&json).unwrap(), s);
+}
+
+#[test]
+fn structured_form_accepts_string() {
+    let s: Structured = serde_json::from_str(r#"{"version":"4.2.3"}"#).unwrap();
+    assert_eq!(s.version, Version::new(4, 2, 3));
+}
+
+#[test]
+fn json_schemas_are_valid_json() {
+    let _: serde_json::Value = serde_json::from_str(serialization::VERSION_JSON_SCHEMA).unwrap();
+    let _: serde_json::Value = serde_json::from_str(structured::JSON_SCHEMA).unwrap();
+}
+
+#[test]
+fn build_metadata_serde() {
+    let v: Version = "4.2.1+some.build".parse().unwrap();
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "\"4.2.1+some.build\"");
+    assert_eq!(serde_json::from_str::<Version>(&json).unwrap(), v);
+
+    let s = Structured { version: v };
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(
+        json,
+        r#"{"version":{"major":4,"minor":2,"patch":1,"build":"some.build"}}"#
+    );
+    assert_eq!(serde_json::from_str::<Structured>(&json).unwrap(), s);
+}
@@ -0,0 +1,67 @@
+// Copyright (c) 2025-2026 Michael S. Klishin and Contributors
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use rabbitmq_versioning::{Series, Version, is_direct_upgrade_supported, upgrade_path};
+
+fn v(s: &str) -> Version {
+    s.parse().unwrap()
+}
+
+#[test]
+fn upgrade_path_within_series() {
+    assert_eq!(upgrade_path(&v("4.2.1"), &v("4.2.3")), Some(vec![]));
+    assert_eq!(upgrade_path(&v("4.2.3"), &v("4.2.3")), Some(vec![]));
+    assert!(is_direct_upgrade_supported(&v("4.2.1"), &v("4.2.3")));
+}
+
+#[test]
+fn upgrade_path_to_next_series() {
+    assert_eq!(
+        upgrade_path(&v("4.1.4"), &v("4.2.0")),
+        Some(vec![Series::new(4, 2)])
+    );
+    assert!(is_direct_upgrade_supported(&v("3.13.7"), &v("4.0.5")));
+}
+
+#[test]
+fn upgrade_path_across_several_series() {
+    let path = upgrade_path(&v("3.12.14"), &v("4.1.0")).unwrap();
+    assert_eq!(
+        path,
+        vec![Series::new(3, 13), Series::new(4, 0), Series::new(4, 1)]
+    );
+    assert!(!is_direct_upgrade_supported(&v("3.12.14"), &v("4.1.0")));
+}
+
+#[test]
+fn upgrade_path_to_prerelease() {
+    assert_eq!(
+        upgrade_path(&v("4.2.3"), &v("4.3.0-alpha.132057c7")),
+        Some(vec![Series::new(4, 3)])
+    );
+}
+
+#[test]
+fn upgrade_path_downgrades() {
+    assert_eq!(upgrade_path(&v("4.2.3"), &v("4.1.0")), None);
+    assert_eq!(upgrade_path(&v("4.2.3"), &v("4.2.1")), None);
+    assert!(!is_direct_upgrade_supported(&v("4.2.3"), &v("4.1.0")));
+}
+
+#[test]
+fn upgrade_path_unknown_series() {
+    assert_eq!(upgrade_path(&v("2.8.7"), &v("4.2.3")), None);
+    assert_eq!(upgrade_path(&v("4.2.3"), &v("5.0.0")), None);
+}
+
+#[test]
+fn series_display_and_ordering() {
+    assert_eq!(Series::new(3, 13).to_string(), "3.13");
+    assert!(Series::new(3, 13) < Series::new(4, 0));
+    assert_eq!(Series::from(&v("4.2.3")), Series::new(4, 2));
+}
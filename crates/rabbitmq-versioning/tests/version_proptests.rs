@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use proptest::prelude::*;
@@ -232,4 +233,21 @@ proptest! {
         prop_assert!(!rc.is_distributed_via_server_packages_repository());
         prop_assert!(!ga.is_distributed_via_server_packages_repository());
     }
+
+    #[test]
+    fn build_metadata_roundtrip(major in 0u32..100, minor in 0u32..100, patch in 0u32..100, build in "[a-z0-9]{1,8}(\\.[a-z0-9]{1,8}){0,2}") {
+        let version = Version::new(major, minor, patch).with_build(build.clone());
+        let parsed: Version = version.to_string().parse().unwrap();
+        prop_assert_eq!(parsed.build.as_deref(), Some(build.as_str()));
+        prop_assert_eq!(version, parsed);
+    }
+
+    #[test]
+    fn build_metadata_does_not_affect_precedence(major in 0u32..100, minor in 0u32..100, patch in 0u32..100, a in "[a-z0-9]{1,8}", b in "[a-z0-9]{1,8}") {
+        let va = Version::new(major, minor, patch).with_build(a);
+        let vb = Version::new(major, minor, patch).with_build(b);
+        prop_assert_eq!(va.cmp_precedence(&vb), Ordering::Equal);
+        prop_assert!(va.clone() < Version::new(major, minor, patch + 1));
+        prop_assert_eq!(va.download_url(), vb.download_url());
+    }
 }
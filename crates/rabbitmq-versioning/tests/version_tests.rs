@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::cmp::Ordering;
 use std::collections::HashSet;
 
 use rabbitmq_versioning::{Error, Prerelease, Version};
@@ -543,34 +544,81 @@ fn prerelease_string_identifier_ordering() {
 }
 
 #[test]
-fn version_serde_roundtrip_ga() {
-    let v = Version::new(4, 2, 3);
-    let json = serde_json::to_string(&v).unwrap();
-    let parsed: Version = serde_json::from_str(&json).unwrap();
-    assert_eq!(v, parsed);
+fn parse_build_metadata() {
+    let v = "4.2.1+some.build".parse::<Version>().unwrap();
+    assert_eq!(v.base_version(), Version::new(4, 2, 1));
+    assert_eq!(v.build.as_deref(), Some("some.build"));
+    assert!(v.is_ga());
+    assert_eq!(v.to_string(), "4.2.1+some.build");
 }
 
 #[test]
-fn version_serde_roundtrip_prerelease() {
-    let v = Version::with_prerelease(4, 2, 4, Prerelease::alpha("1"));
-    let json = serde_json::to_string(&v).unwrap();
-    let parsed: Version = serde_json::from_str(&json).unwrap();
-    assert_eq!(v, parsed);
+fn parse_prerelease_with_build_metadata() {
+    let v = "4.3.0-rc.1+20260101.abc-def".parse::<Version>().unwrap();
+    assert_eq!(v.prerelease, Some(Prerelease::rc("1")));
+    assert_eq!(v.build.as_deref(), Some("20260101.abc-def"));
+    assert_eq!(v.to_string(), "4.3.0-rc.1+20260101.abc-def");
 }
 
 #[test]
-fn version_serde_skips_none_prerelease() {
-    let v = Version::new(4, 2, 3);
-    let json = serde_json::to_string(&v).unwrap();
-    assert!(!json.contains("prerelease"));
+fn parse_invalid_build_metadata() {
+    assert!("4.2.1+".parse::<Version>().is_err());
+    assert!("4.2.1+a..b".parse::<Version>().is_err());
+    assert!("4.2.1+a_b".parse::<Version>().is_err());
+    assert!("4.2.1+a+b".parse::<Version>().is_err());
 }
 
 #[test]
-fn prerelease_serde_roundtrip() {
-    let alpha = Prerelease::alpha("1");
-    let json = serde_json::to_string(&alpha).unwrap();
-    let parsed: Prerelease = serde_json::from_str(&json).unwrap();
-    assert_eq!(alpha, parsed);
+fn build_metadata_is_ignored_for_precedence() {
+    let plain = Version::new(4, 2, 1);
+    let a = "4.2.1+a".parse::<Version>().unwrap();
+    let b = "4.2.1+b".parse::<Version>().unwrap();
+
+    assert_eq!(a.cmp_precedence(&b), Ordering::Equal);
+    assert_eq!(a.cmp_precedence(&plain), Ordering::Equal);
+    assert!(a < Version::new(4, 2, 2));
+    assert!(a > Version::new(4, 2, 0));
+    assert!("4.2.1-rc.1+a".parse::<Version>().unwrap() < plain);
+    // Consistent with Eq: same precedence, different build metadata
+    assert_ne!(a, b);
+    assert!(plain < a && a < b);
+}
+
+#[test]
+fn build_metadata_is_not_part_of_artifact_names() {
+    let v = "4.2.1+some.build".parse::<Version>().unwrap();
+    assert_eq!(
+        v.archive_name(),
+        "rabbitmq-server-generic-unix-4.2.1.tar.xz"
+    );
+    assert_eq!(v.extracted_dir_name(), "rabbitmq_server-4.2.1");
+    assert_eq!(v.download_url(), Version::new(4, 2, 1).download_url());
+    assert_eq!(v.dir_name(), "4.2.1+some.build");
+    assert_eq!(v.without_build(), Version::new(4, 2, 1));
+}
+
+#[test]
+fn release_tag_3x() {
+    let v = "v3.13.7".parse::<Version>().unwrap();
+    assert_eq!(v, Version::new(3, 13, 7));
+    assert_eq!(v.release_tag(), "v3.13.7");
+    assert_eq!(
+        v.download_url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/v3.13.7/rabbitmq-server-generic-unix-3.13.7.tar.xz"
+    );
+}
+
+#[test]
+fn parse_legacy_3_6_tag() {
+    let v = "rabbitmq_v3_6_16".parse::<Version>().unwrap();
+    assert_eq!(v, Version::new(3, 6, 16));
+    assert_eq!(v.release_tag(), "rabbitmq_v3_6_16");
+    assert_eq!(
+        v.download_url(),
+        "https://github.com/rabbitmq/rabbitmq-server/releases/download/rabbitmq_v3_6_16/rabbitmq-server-generic-unix-3.6.16.tar.xz"
+    );
+    assert_eq!(Version::new(3, 7, 0).release_tag(), "v3.7.0");
+    assert!("rabbitmq_v3_6".parse::<Version>().is_err());
 }
 
 #[test]